@@ -0,0 +1,104 @@
+//! Throughput of building and signing the canonical payload of a request,
+//! which every signed call of the SDK goes through.
+//!
+//! ```sh
+//! cargo bench --bench signing
+//! ```
+//!
+//! Each iteration is one request, so the reported throughput is in signed
+//! requests per second. At 1k requests per second, a sign has a budget of
+//! 1ms on a single core.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use privy_rs::{
+    AuthorizationContext, IntoKey, Method, PrivateKey, WalletApiRequestSignatureInput,
+    format_request_for_authorization_signature, generate_authorization_signatures,
+};
+use serde_json::json;
+
+const APP_ID: &str = "cmf418pa801bxl40b5rcgjvd9";
+const URL: &str = "https://api.privy.io/v1/wallets/o5zuf7fbygwze9l9gaxyc0bm/rpc";
+const PRIVATE_KEY_PEM: &str = include_str!("../tests/test_private_key.pem");
+
+/// An `eth_sendTransaction` rpc body, the most common signed request.
+fn rpc_body() -> serde_json::Value {
+    json!({
+        "method": "eth_sendTransaction",
+        "caip2": "eip155:8453",
+        "chain_type": "ethereum",
+        "params": {
+            "transaction": {
+                "to": "0x742d35Cc6634C0532925a3b844Bc454e4438f44e",
+                "value": "0x2386f26fc10000",
+                "data": format!("0xa9059cbb{}", "00".repeat(64)),
+                "chain_id": 8453,
+                "gas_limit": "0x5208",
+            }
+        }
+    })
+}
+
+fn canonicalize(c: &mut Criterion) {
+    let body = rpc_body();
+
+    let mut group = c.benchmark_group("canonicalize");
+    group.throughput(Throughput::Elements(1));
+    // the payload built from owned values, as the SDK used to, for comparison
+    group.bench_function("owned", |b| {
+        b.iter(|| {
+            let mut headers = serde_json::Map::new();
+            headers.insert("privy-app-id".into(), APP_ID.into());
+            headers.insert("privy-idempotency-key".into(), "idempotency-key".into());
+            WalletApiRequestSignatureInput::new(Method::POST, URL.to_string())
+                .headers(headers.into())
+                .body(body.clone())
+                .canonicalize()
+                .unwrap()
+        });
+    });
+    group.bench_function("borrowed", |b| {
+        b.iter(|| {
+            format_request_for_authorization_signature(
+                APP_ID,
+                Method::POST,
+                URL.to_string(),
+                &body,
+                Some("idempotency-key".to_string()),
+            )
+            .unwrap()
+        });
+    });
+    group.finish();
+}
+
+fn sign(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let key = runtime
+        .block_on(PrivateKey::new(PRIVATE_KEY_PEM.to_string()).get_key())
+        .unwrap();
+    let body = rpc_body();
+
+    let mut group = c.benchmark_group("sign");
+    group.throughput(Throughput::Elements(1));
+    for signers in [1, 3] {
+        let ctx = (0..signers).fold(AuthorizationContext::new(), |ctx, _| ctx.push(key.clone()));
+        group.bench_with_input(BenchmarkId::new("rpc", signers), &ctx, |b, ctx| {
+            b.to_async(&runtime).iter(|| {
+                generate_authorization_signatures(
+                    ctx,
+                    APP_ID,
+                    Method::POST,
+                    URL.to_string(),
+                    &body,
+                    Some("idempotency-key".to_string()),
+                )
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, canonicalize, sign);
+criterion_main!(benches);
@@ -324,8 +324,8 @@ fn generate_resource_code(
             client: Client,
             #[allow(dead_code)]
             app_id: String,
-            #[allow(dead_code)]
             base_url: String,
+            shared: std::sync::Arc<crate::client::ClientShared>,
         }
     };
 
@@ -354,7 +354,35 @@ fn generate_resource_code(
         impl #client_ident {
             /// Create a new client instance
             pub fn new(client: Client, app_id: String, base_url: String) -> Self {
-                Self { client, app_id, base_url }
+                Self {
+                    client,
+                    app_id,
+                    base_url,
+                    shared: Default::default(),
+                }
+            }
+
+            /// Behave like the client this was created from: share its
+            /// options, hooks and trackers
+            pub(crate) fn with_shared(mut self, shared: std::sync::Arc<crate::client::ClientShared>) -> Self {
+                self.shared = shared;
+                self
+            }
+
+            /// Send the headers in `options` with the requests of this
+            /// subclient, on top of those of the client it was created from.
+            /// See the [`request_options`](crate::request_options) module for
+            /// details.
+            #[must_use]
+            pub fn with_request_options(mut self, options: crate::request_options::RequestOptions) -> Self {
+                let shared = std::sync::Arc::make_mut(&mut self.shared);
+                shared.request_options = shared.request_options.merged(&options);
+                self.client = Client::new_with_client(
+                    &self.base_url,
+                    crate::generated::ClientInfo::client(&self.client).clone(),
+                    shared.middleware.request_hook_with(shared.request_options.headers().clone()),
+                );
+                self
             }
 
             #(#impl_methods)*
@@ -428,9 +456,111 @@ fn generate_method_impl(
             quote! {}
         };
 
-        // Check if the original method is async
+        // The HTTP method and path of the endpoint, for request hooks, see
+        // `crate::middleware`.
+        let (http_method, path) = method
+            .endpoint
+            .split_once(' ')
+            .map(|(verb, path)| (verb.to_uppercase(), path.to_string()))
+            .unwrap_or_default();
+
+        // Whether the call can be retried after a 5xx, see `crate::retry`: reads,
+        // puts and deletes always, other calls only with an idempotency key.
+        let idempotent = match method.endpoint.split_once(' ') {
+            Some((verb, _)) if ["get", "put", "delete"].contains(&verb.to_lowercase().as_str()) => {
+                quote! { true }
+            }
+            _ if param_names
+                .iter()
+                .any(|name| *name == "privy_idempotency_key") =>
+            {
+                quote! { privy_idempotency_key.is_some() }
+            }
+            _ => quote! { false },
+        };
+
+        // The wallet and idempotency key of the call, for its `privy.request` span,
+        // see `crate::telemetry`.
+        let wallet_id = if sig.inputs.iter().any(|input| {
+            matches!(input, syn::FnArg::Typed(typed)
+                if matches!(&*typed.pat, syn::Pat::Ident(ident) if ident.ident == "wallet_id")
+                    && matches!(&*typed.ty, syn::Type::Reference(_)))
+        }) {
+            quote! { Some(wallet_id) }
+        } else {
+            quote! { None }
+        };
+        let idempotency_key = if param_names
+            .iter()
+            .any(|name| *name == "privy_idempotency_key")
+        {
+            quote! { privy_idempotency_key }
+        } else {
+            quote! { None }
+        };
+
+        // Check if the original method is async. Errors from async (network) calls
+        // have their response bodies captured, see `crate::errors::enrich_api_error`,
+        // pass through the client's request hooks, see `crate::middleware`, are
+        // retried if the client has a retry policy, see `crate::retry`, and
+        // have their latency recorded, see `crate::latency`, all in a span, see
+        // `crate::telemetry`. Failures an outage would
+        // explain carry any ongoing Privy incidents, see `crate::service_status`. With `strict-validation`,
+        // responses that fail to deserialize are checked against the spec, see
+        // `crate::validation`.
+        //
+        // In dry run mode, calls that change anything are not sent at all.
+        // Only the wallet rpc and raw sign wrappers synthesize a response,
+        // every other mutating call fails here, see
+        // `PrivyClientOptions::dry_run`.
+        let dry_run = if is_read_only(&http_method, &path) {
+            quote! {}
+        } else {
+            quote! {
+                if self.shared.dry_run {
+                    tracing::info!(operation = #generated_method_name, "dry run: skipping call");
+                    return Err(Error::InvalidRequest(format!(
+                        "{} is not supported in dry run mode, only wallet rpc and raw sign calls are",
+                        #generated_method_name
+                    )));
+                }
+            }
+        };
         let call_expr = if sig.asyncness.is_some() {
-            quote! { self.client.#generated_method_ident(#(#param_names),*).await }
+            quote! {
+                #dry_run
+                let request = crate::middleware::RequestInfo {
+                    operation: #generated_method_name,
+                    method: #http_method,
+                    path: #path,
+                };
+                crate::telemetry::request(request.clone(), #wallet_id, #idempotency_key, async move {
+                    let request = &request;
+                    let started = std::time::Instant::now();
+                    let result = crate::retry::run(
+                        self.shared.retry.as_ref(),
+                        #generated_method_name,
+                        #idempotent,
+                        move || async move {
+                            let attempt_started = std::time::Instant::now();
+                            let call = self.client.#generated_method_ident(#(#param_names),*);
+                            let result = match crate::middleware::scope(request.clone(), call).await {
+                                Ok(value) => Ok(value),
+                                Err(error) => Err(crate::errors::enrich_api_error(error, #idempotency_key).await),
+                            };
+                            self.shared.middleware.after(request, attempt_started.elapsed(), &result);
+                            result
+                        },
+                    )
+                    .await;
+                    let result = result.map_err(|error| self.shared.service_status.annotate(error));
+                    #[cfg(feature = "strict-validation")]
+                    let result = result.map_err(|error| crate::validation::check_response(#generated_method_name, error));
+                    self.shared.latency.record(#generated_method_name, started.elapsed(), &result);
+                    result
+                })
+                .await
+            }
         } else {
             quote! { self.client.#generated_method_ident(#(#param_names),*) }
         };
@@ -452,6 +582,26 @@ fn generate_method_impl(
     }
 }
 
+/// Whether a call leaves everything as it was, so that it is still sent in
+/// dry run mode: reads, and the `POST` endpoints that look up or search
+/// users, fetch fiat statuses and KYC links, and exchange user JWTs for the
+/// signatures dry run still makes.
+fn is_read_only(http_method: &str, path: &str) -> bool {
+    match http_method {
+        "GET" => true,
+        "POST" => {
+            (path.starts_with("/v1/users/") && !path.starts_with("/v1/users/{user_id}"))
+                || matches!(
+                    path,
+                    "/v1/users/{user_id}/fiat/status"
+                        | "/v1/users/{user_id}/fiat/kyc_link"
+                        | "/v1/wallets/authenticate"
+                )
+        }
+        _ => false,
+    }
+}
+
 /// Generate an accessor method for a subresource
 fn generate_subresource_accessor(
     subresource_name: &str,
@@ -470,6 +620,7 @@ fn generate_subresource_accessor(
         #[doc = #msg]
         pub fn #method_name(&self) -> #client_ident {
             #client_ident::new(self.client.clone(), self.app_id.clone(), self.base_url.clone())
+                .with_shared(self.shared.clone())
         }
     }
 }
@@ -488,6 +639,7 @@ fn generate_main_client_extension(resources: &[ResourceConfig]) -> proc_macro2::
             #[doc = #msg]
             pub fn #method_name(&self) -> #client_ident {
                 #client_ident::new(self.client.clone(), self.app_id.clone(), self.base_url.clone())
+                    .with_shared(self.shared.clone())
             }
         });
     }
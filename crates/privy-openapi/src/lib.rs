@@ -74,7 +74,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AbiParameter {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub components:
@@ -159,7 +159,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct AbiSchema(pub ::std::vec::Vec<AbiSchemaItem>);
     impl ::std::ops::Deref for AbiSchema {
@@ -239,7 +239,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AbiSchemaItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub anonymous: ::std::option::Option<bool>,
@@ -482,7 +482,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AccountBalanceParams {
         ///When set to true, returns balances from testnet chains instead of
         /// mainnets.
@@ -637,7 +637,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AccountBalanceResponse {
         ///The individual asset balances, each computed across all supported
         /// chains.
@@ -753,7 +753,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AccountResponse {
         ///An optional display name for the account.
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -804,7 +804,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AccountWallet {
         ///The on-chain address of the wallet.
         pub address: ::std::string::String,
@@ -846,7 +846,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AccountWalletConfigurationItem {
         pub chain_type: FirstClassChainType,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -877,7 +877,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct AccountWalletIds(pub ::std::vec::Vec<::std::string::String>);
     impl ::std::ops::Deref for AccountWalletIds {
@@ -922,7 +922,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct AccountWalletsConfiguration(pub ::std::vec::Vec<AccountWalletConfigurationItem>);
     impl ::std::ops::Deref for AccountWalletsConfiguration {
@@ -986,7 +986,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AccountsDashboardListResponse {
         ///The list of accounts, with balances included for dashboard display.
         pub data: ::std::vec::Vec<AssetAccountWithBalance>,
@@ -1032,7 +1032,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AccountsListResponse {
         ///The list of accounts.
         pub data: ::std::vec::Vec<AccountResponse>,
@@ -1086,7 +1086,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ActionRequestBodyCondition {
         pub field: ::std::string::String,
         pub field_source: ActionRequestBodyConditionFieldSource,
@@ -1195,7 +1195,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct AdditionalSignerInput(pub ::std::vec::Vec<AdditionalSignerItemInput>);
     impl ::std::ops::Deref for AdditionalSignerInput {
@@ -1249,7 +1249,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AdditionalSignerItemInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub override_policy_ids: ::std::option::Option<PolicyInput>,
@@ -1282,7 +1282,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum Address {
         Variant0(::std::string::String),
@@ -1377,7 +1377,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct Aggregation {
         ///Optional conditions to filter events before aggregation.
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
@@ -1444,7 +1444,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AggregationCondition {
         pub field: AggregationConditionField,
         pub field_source: AggregationConditionFieldSource,
@@ -1651,7 +1651,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AggregationGroupBy {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub abi: ::std::option::Option<AbiSchema>,
@@ -1727,7 +1727,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AggregationInput {
         ///Optional conditions to filter events before aggregation.
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
@@ -1964,7 +1964,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AggregationMetric {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub abi: ::std::option::Option<AbiSchema>,
@@ -2182,7 +2182,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(tag = "type", content = "seconds")]
     pub enum AggregationWindow {
         #[serde(rename = "rolling")]
@@ -2224,7 +2224,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AlchemyPaymasterContext {
         pub policy_id: ::uuid::Uuid,
     }
@@ -2257,7 +2257,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AllowlistDeletionResponse {
         pub message: ::std::string::String,
     }
@@ -2304,7 +2304,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AllowlistEntry {
         #[serde(
             rename = "acceptedAt",
@@ -2447,7 +2447,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AnalyticsEventInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub client_id: ::std::option::Option<::uuid::Uuid>,
@@ -2848,7 +2848,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AppResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub accent_color: ::std::option::Option<::std::string::String>,
@@ -2955,7 +2955,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AppResponseAllowlistConfig {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub cta_link: ::std::option::Option<::std::string::String>,
@@ -3014,7 +3014,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AppResponseCustomOauthProvidersItem {
         pub enabled: bool,
         pub provider: CustomOAuthProviderId,
@@ -3325,7 +3325,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AssetAccountWithBalance {
         pub balance: BalanceResponse,
         ///An optional display name for the account.
@@ -3364,7 +3364,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AuthenticateJwtInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub mode: ::std::option::Option<AuthenticateModeOption>,
@@ -3405,7 +3405,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AuthenticateMode {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub mode: ::std::option::Option<AuthenticateModeOption>,
@@ -3552,7 +3552,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AuthenticateSiweInput {
         #[serde(
             rename = "chainId",
@@ -3874,7 +3874,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AuthenticateSiwsInput {
         #[serde(
             rename = "connectorType",
@@ -4209,7 +4209,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AuthenticatedUser {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub identity_token: ::std::option::Option<AuthenticatedUserIdentityToken>,
@@ -4691,7 +4691,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AuthorizationKeyDashboardResponse {
         pub created_at: f64,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -4742,7 +4742,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct AuthorizationKeyResponse {
         pub created_at: f64,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -4893,7 +4893,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BalanceAsset {
         ///The amount of the asset held, denominated in the unit of the asset
         /// itself, with 1 decimal of precision.
@@ -4954,7 +4954,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BalanceAssetByChain {
         ///The amount of the asset held on this chain, denominated in the unit
         /// of the asset itself.
@@ -5016,7 +5016,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BalanceResponse {
         ///The individual asset balances, each computed across all supported
         /// chains.
@@ -5069,7 +5069,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BaseActionResult {
         ///Display name of the key quorum that authorized execution
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -5104,7 +5104,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum BaseActionResultResponseBody {
         PolicyRuleResponse(PolicyRuleResponse),
@@ -5211,7 +5211,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BaseIntentResponse {
         ///Detailed authorization information including key quorum members,
         /// thresholds, and signature status
@@ -5364,7 +5364,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum BitcoinCaip2 {
         Variant0(BitcoinCaip2Variant0),
@@ -5567,7 +5567,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct Bps(pub i64);
     impl ::std::ops::Deref for Bps {
@@ -5680,7 +5680,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeBrlFiatVirtualAccountDepositInstructions {
         pub account_holder_name: ::std::string::String,
         pub asset: BridgeBrlFiatVirtualAccountDepositInstructionsAsset,
@@ -5920,7 +5920,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeCryptoDepositMetadata {
         pub drain_id: ::std::string::String,
         ///The crypto address of the liquidation address that received the
@@ -6138,7 +6138,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeCryptoTransferMetadata {
         pub method: BridgeCryptoTransferMetadataMethod,
         ///The wallet address that sent the transfer.
@@ -6475,7 +6475,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeEurFiatVirtualAccountDepositInstructions {
         pub account_holder_name: ::std::string::String,
         pub asset: BridgeEurFiatVirtualAccountDepositInstructionsAsset,
@@ -6714,7 +6714,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeFiatCustomerResponse {
         pub has_accepted_terms: bool,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -6850,7 +6850,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeFiatDepositMetadata {
         pub activity_id: ::std::string::String,
         pub method: BridgeFiatDepositMetadataMethod,
@@ -7049,7 +7049,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeFiatRejectionReason {
         pub reason: ::std::string::String,
     }
@@ -7095,7 +7095,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeFiatTransferMetadata {
         pub method: BridgeFiatTransferMetadataMethod,
         pub transfer_id: ::std::string::String,
@@ -7302,7 +7302,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum BridgeFiatVirtualAccountDepositInstructions {
         UsdFiatVirtualAccountDepositInstructions(BridgeUsdFiatVirtualAccountDepositInstructions),
@@ -7390,7 +7390,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeFiatVirtualAccountDestination {
         pub address: ::std::string::String,
         pub asset: BridgeDestinationAsset,
@@ -7437,7 +7437,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeFiatVirtualAccountRequest {
         pub destination: BridgeFiatVirtualAccountDestination,
         pub provider: BridgeFiatVirtualAccountRequestProvider,
@@ -7566,7 +7566,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeFiatVirtualAccountResponse {
         pub deposit_instructions: BridgeFiatVirtualAccountDepositInstructions,
         pub destination: BridgeFiatVirtualAccountDestination,
@@ -7681,7 +7681,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeFiatVirtualAccountSource {
         pub asset: BridgeSourceAsset,
     }
@@ -7747,7 +7747,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeGbpFiatVirtualAccountDepositInstructions {
         pub account_holder_name: ::std::string::String,
         pub account_number: ::std::string::String,
@@ -7973,7 +7973,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum BridgeMetadata {
         CryptoDepositMetadata(BridgeCryptoDepositMetadata),
@@ -8084,7 +8084,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeMxnFiatVirtualAccountDepositInstructions {
         pub account_holder_name: ::std::string::String,
         pub asset: BridgeMxnFiatVirtualAccountDepositInstructionsAsset,
@@ -8405,7 +8405,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeRefundMetadata {
         pub drain_id: ::std::string::String,
         pub liquidation_address_id: ::std::string::String,
@@ -8630,7 +8630,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeSandboxFiatCustomerResponse {
         pub has_accepted_terms: bool,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -8761,7 +8761,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeSandboxFiatVirtualAccountRequest {
         pub destination: BridgeFiatVirtualAccountDestination,
         pub provider: BridgeSandboxFiatVirtualAccountRequestProvider,
@@ -8897,7 +8897,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeSandboxFiatVirtualAccountResponse {
         pub deposit_instructions: BridgeFiatVirtualAccountDepositInstructions,
         pub destination: BridgeFiatVirtualAccountDestination,
@@ -9138,7 +9138,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeStaticMemoDepositMetadata {
         pub method: BridgeStaticMemoDepositMetadataMethod,
         pub static_memo_event_id: ::std::string::String,
@@ -9351,7 +9351,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeTransferRefundMetadata {
         pub method: BridgeTransferRefundMetadataMethod,
         ///The original transfer transaction hash (if available).
@@ -9586,7 +9586,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct BridgeUsdFiatVirtualAccountDepositInstructions {
         pub asset: BridgeUsdFiatVirtualAccountDepositInstructionsAsset,
         pub bank_account_number: ::std::string::String,
@@ -9987,7 +9987,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CancelCustomOrderResponse {
         pub data: KrakenEmbedCancelCustomOrderResponse,
     }
@@ -10251,7 +10251,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CoinbaseOnRampEthereumAddress {
         pub address: ::std::string::String,
         pub blockchains: ::std::vec::Vec<CoinbaseBlockchain>,
@@ -10296,7 +10296,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CoinbaseOnRampInitEthereumInput {
         pub addresses: [CoinbaseOnRampEthereumAddress; 1usize],
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
@@ -10330,7 +10330,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum CoinbaseOnRampInitInput {
         EthereumInput(CoinbaseOnRampInitEthereumInput),
@@ -10389,7 +10389,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CoinbaseOnRampInitResponse {
         pub app_id: ::std::string::String,
         pub channel_id: ::std::string::String,
@@ -10436,7 +10436,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CoinbaseOnRampInitSolanaInput {
         pub addresses: [CoinbaseOnRampSolanaAddress; 1usize],
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
@@ -10478,7 +10478,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CoinbaseOnRampSolanaAddress {
         pub address: ::std::string::String,
         pub blockchains: ::std::vec::Vec<CoinbaseBlockchain>,
@@ -10604,7 +10604,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CoinbaseOnRampStatusResponse {
         pub status: CoinbaseOnRampStatus,
     }
@@ -10872,7 +10872,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ConditionSet {
         pub created_at: f64,
         ///Unique ID of the created condition set. This will be the primary
@@ -10923,7 +10923,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ConditionSetAuthorizationHeaders {
         ///ID of your Privy app.
         #[serde(rename = "privy-app-id")]
@@ -11091,7 +11091,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ConditionSetItem {
         ///Unique ID of the condition set this item belongs to.
         pub condition_set_id: ConditionSetItemConditionSetId,
@@ -11311,7 +11311,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ConditionSetItemRequestParams {
         pub condition_set_id: ConditionSetItemRequestParamsConditionSetId,
         pub condition_set_item_id: ConditionSetItemRequestParamsConditionSetItemId,
@@ -11529,7 +11529,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ConditionSetItemValueInput {
         pub value: ConditionSetItemValueInputValue,
     }
@@ -11637,7 +11637,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct ConditionSetItems(pub ::std::vec::Vec<ConditionSetItem>);
     impl ::std::ops::Deref for ConditionSetItems {
@@ -11685,7 +11685,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct ConditionSetItemsRequestBody(pub ::std::vec::Vec<ConditionSetItemValueInput>);
     impl ::std::ops::Deref for ConditionSetItemsRequestBody {
@@ -11747,7 +11747,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ConditionSetItemsResponse {
         ///List of condition set items.
         pub items: ::std::vec::Vec<ConditionSetItem>,
@@ -11898,7 +11898,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ConditionSetRequestBody {
         ///Name to assign to condition set.
         pub name: ConditionSetRequestBodyName,
@@ -12023,7 +12023,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ConditionSetRequestParams {
         pub condition_set_id: ConditionSetRequestParamsConditionSetId,
     }
@@ -12146,7 +12146,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum ConditionValue {
         String(::std::string::String),
@@ -12194,7 +12194,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ConfigureAppForFiatOnOffRampingBody {
         pub api_key: ConfigureAppForFiatOnOffRampingBodyApiKey,
         pub provider: OnrampProvider,
@@ -12317,7 +12317,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateAccountFromWalletIdsInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub display_name: ::std::option::Option<AccountDisplayName>,
@@ -12364,7 +12364,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateAccountFromWalletsConfigurationInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub display_name: ::std::option::Option<AccountDisplayName>,
@@ -12400,7 +12400,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum CreateAccountInput {
         WalletsConfigurationInput(CreateAccountFromWalletsConfigurationInput),
@@ -12530,7 +12530,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateCustomOrderResponse {
         pub data: KrakenEmbedCreateCustomOrderResponse,
     }
@@ -13887,7 +13887,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateKrakenUserBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub city_of_birth: ::std::option::Option<::std::string::String>,
@@ -14041,7 +14041,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum CreateKrakenUserBodyClientIdentifier {
         Variant0 {
@@ -15990,7 +15990,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateKrakenUserBodyFullName {
         pub first_name: ::std::string::String,
         pub last_name: ::std::string::String,
@@ -17942,7 +17942,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateKrakenUserBodyResidence {
         pub city: ::std::string::String,
         pub country: CreateKrakenUserBodyResidenceCountry,
@@ -19630,7 +19630,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateKrakenUserBodyTaxIdsItem {
         pub id: ::std::string::String,
         pub issuing_country: CreateKrakenUserBodyTaxIdsItemIssuingCountry,
@@ -21080,7 +21080,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateKrakenUserResponse {
         pub data: CreateKrakenUserResponseData,
     }
@@ -21124,7 +21124,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateKrakenUserResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -21170,7 +21170,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateKrakenUserResponseDataResult {
         pub user: CreateKrakenUserResponseDataResultUser,
     }
@@ -21291,7 +21291,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateLinkAuthIntentInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -21339,7 +21339,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateLinkAuthIntentResponse {
         pub data: CreateLinkAuthIntentResponseData,
     }
@@ -21367,7 +21367,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum CreateLinkAuthIntentResponseData {
         Created(LinkAuthIntentCreated),
@@ -21432,7 +21432,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateOrUpdateFiatCustomerRequestInput {
         pub has_accepted_terms: bool,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -21563,7 +21563,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateOrganizationSecretResponse {
         ///ISO 8601 creation timestamp
         pub created_at: ::std::string::String,
@@ -21663,7 +21663,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreatePolicyBody {
         pub chain_type: WalletChainType,
         ///Name to assign to policy.
@@ -21807,7 +21807,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreatePolicyBodyRulesItem {
         pub action: PolicyAction,
         pub conditions: ::std::vec::Vec<PolicyCondition>,
@@ -22019,7 +22019,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreatePolicyUpdateIntentBody {
         ///Name to assign to policy.
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -22250,7 +22250,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateStripeOnrampSessionInput {
         pub environment: FiatOnrampEnvironment,
         pub session: OnrampSessionParams,
@@ -22398,7 +22398,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateStripeOnrampSessionResponse {
         pub id: ::std::string::String,
         pub session_id: ::std::string::String,
@@ -22512,7 +22512,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub custom_metadata: ::std::option::Option<CustomMetadata>,
@@ -22592,7 +22592,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserBodyWalletsItem {
         ///Additional signers for the wallet.
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
@@ -22648,7 +22648,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserBodyWalletsItemAdditionalSignersItem {
         ///The array of policy IDs that will be applied to wallet requests. If
         /// specified, this will override the base policy IDs set on the wallet.
@@ -23102,7 +23102,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserFiatAccountBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub account: ::std::option::Option<CreateUserFiatAccountBodyAccount>,
@@ -23161,7 +23161,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserFiatAccountBodyAccount {
         pub account_number: CreateUserFiatAccountBodyAccountAccountNumber,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -23572,7 +23572,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserFiatAccountBodyAddress {
         pub city: CreateUserFiatAccountBodyAddressCity,
         pub country: CreateUserFiatAccountBodyAddressCountry,
@@ -24311,7 +24311,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserFiatAccountBodyIban {
         pub account_number: CreateUserFiatAccountBodyIbanAccountNumber,
         pub bic: CreateUserFiatAccountBodyIbanBic,
@@ -24766,7 +24766,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserFiatAccountBodySwift {
         pub account: CreateUserFiatAccountBodySwiftAccount,
         pub address: CreateUserFiatAccountBodySwiftAddress,
@@ -24811,7 +24811,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserFiatAccountBodySwiftAccount {
         pub account_number: CreateUserFiatAccountBodySwiftAccountAccountNumber,
         pub bic: CreateUserFiatAccountBodySwiftAccountBic,
@@ -25132,7 +25132,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserFiatAccountBodySwiftAddress {
         pub city: CreateUserFiatAccountBodySwiftAddressCity,
         pub country: CreateUserFiatAccountBodySwiftAddressCountry,
@@ -25995,7 +25995,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserFiatAccountResponse {
         pub account_type: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -26035,7 +26035,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserFiatTosBody {
         pub provider: OnrampProvider,
     }
@@ -26095,7 +26095,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(tag = "status", content = "url")]
     pub enum CreateUserFiatTosResponse {
         #[serde(rename = "completed")]
@@ -26131,7 +26131,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateUserWalletBody {
         pub wallets: ::std::vec::Vec<WalletCreationInput>,
     }
@@ -26200,7 +26200,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CreateWalletBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub additional_signers: ::std::option::Option<AdditionalSignerInput>,
@@ -26446,7 +26446,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CrossAppConnection {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub provider_app_custom_api_url: ::std::option::Option<::std::string::String>,
@@ -26501,7 +26501,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CrossAppConnectionsResponse {
         pub connections: ::std::vec::Vec<CrossAppConnection>,
         ///Indicates that this response contains only publicly accessible data,
@@ -26623,7 +26623,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CrossAppEmbeddedWallet {
         pub address: ::std::string::String,
     }
@@ -26655,7 +26655,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CrossAppSmartWallet {
         pub address: ::std::string::String,
     }
@@ -26781,7 +26781,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct Currency {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub asset: ::std::option::Option<CurrencyAsset>,
@@ -26830,7 +26830,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CurrencyAmount {
         ///Currency code
         pub currency: CurrencyAmountCurrency,
@@ -27309,7 +27309,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CustodialWallet {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub additional_signers: ::std::option::Option<WalletAdditionalSigner>,
@@ -27464,7 +27464,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CustodialWalletCreateInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub additional_signers: ::std::option::Option<AdditionalSignerInput>,
@@ -27760,7 +27760,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CustomJwtAuthenticateRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub mode: ::std::option::Option<AuthenticateModeOption>,
@@ -27801,7 +27801,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CustomJwtLinkRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub token: ::std::option::Option<::std::string::String>,
@@ -27847,7 +27847,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct CustomMetadata(
         pub ::std::collections::HashMap<::std::string::String, CustomMetadataValue>,
@@ -27907,7 +27907,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum CustomMetadataValue {
         String(::std::string::String),
@@ -28066,7 +28066,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct CustomTokenTransferSource {
         ///Amount as a decimal string in the token's standard unit (e.g. "1.5"
         /// for 1.5 USDC, "0.01" for 0.01 ETH). Not in the smallest on-chain
@@ -28196,7 +28196,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum CustomTokenTransferSourceAssetAddress {
         Variant0(::std::string::String),
@@ -28870,7 +28870,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct DeveloperFee {
         ///Amount in USD (in decimals).
         pub amount: ::std::string::String,
@@ -28906,7 +28906,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum DeveloperFeeRecipient {
         Variant0(::std::string::String),
@@ -29083,7 +29083,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct DeviceAuthorizationResponse {
         ///A long-lived code used by the device to poll for authorization
         /// status.
@@ -29226,7 +29226,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct DeviceVerifyRequestBody {
         pub action: DeviceVerifyAction,
         ///The user code displayed on the CLI device.
@@ -29262,7 +29262,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct DeviceVerifyResponse {
         ///Whether the action was processed successfully.
         pub success: bool,
@@ -29314,7 +29314,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EarnAsset {
         ///Token contract address.
         pub address: ::std::string::String,
@@ -29449,7 +29449,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EarnDepositActionResponse {
         ///Human-readable decimal amount of asset deposited (e.g. "1.5"). Only
         /// present when the token is known in the asset registry.
@@ -29627,7 +29627,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EarnDepositRequestBody {
         ///Human-readable decimal amount to deposit (e.g. "1.5" for 1.5 USDC).
         /// Exactly one of `amount` or `raw_amount` must be provided.
@@ -29923,7 +29923,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EarnIncentiveClaimActionResponse {
         ///EVM chain name (e.g. "base", "ethereum").
         pub chain: ::std::string::String,
@@ -30063,7 +30063,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EarnIncentiveClaimRequestBody {
         ///The blockchain network on which to perform the incentive claim.
         /// Supported chains include: 'ethereum', 'base', 'arbitrum', 'polygon',
@@ -30134,7 +30134,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EarnIncentiveRewardEntry {
         ///Total amount already claimed, in smallest unit.
         pub amount_claimed: EarnIncentiveRewardEntryAmountClaimed,
@@ -30374,7 +30374,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EarnIncentiveRewardsQuery {
         ///Chain name to fetch rewards for (e.g. "base", "ethereum").
         pub chain: ::std::string::String,
@@ -30427,7 +30427,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EarnIncentiveRewardsResponse {
         ///Reward tokens with their claimed and unclaimed amounts.
         pub rewards: ::std::vec::Vec<EarnIncentiveRewardEntry>,
@@ -30477,7 +30477,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EarnIncetiveClaimRewardEntry {
         ///Claimable amount in base units.
         pub amount: ::std::string::String,
@@ -30615,7 +30615,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EarnWithdrawActionResponse {
         ///Human-readable decimal amount of asset withdrawn (e.g. "1.5"). Only
         /// present when the token is known in the asset registry.
@@ -30793,7 +30793,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EarnWithdrawRequestBody {
         ///Human-readable decimal amount to withdraw (e.g. "1.5" for 1.5 USDC).
         /// Exactly one of `amount` or `raw_amount` must be provided.
@@ -31096,7 +31096,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EmailDomainInviteInput {
         #[serde(rename = "type")]
         pub type_: EmailDomainInviteInputType,
@@ -31217,7 +31217,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EmailInviteInput {
         #[serde(rename = "type")]
         pub type_: EmailInviteInputType,
@@ -31330,7 +31330,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EmbeddedWalletChainConfig {
         pub create_on_login: EmbeddedWalletCreateOnLogin,
     }
@@ -31370,7 +31370,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EmbeddedWalletConfigSchema {
         pub create_on_login: EmbeddedWalletCreateOnLogin,
         pub ethereum: EmbeddedWalletChainConfig,
@@ -31505,7 +31505,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EmbeddedWalletCreationInput {
         ///Wallets to create.
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
@@ -31568,7 +31568,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EmbeddedWalletInputSchema {
         pub create_on_login: EmbeddedWalletCreateOnLogin,
         pub ethereum: EmbeddedWalletChainConfig,
@@ -31816,7 +31816,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EncryptedAuthorizationKey {
         pub ciphertext: ::std::string::String,
         pub encapsulated_key: ::std::string::String,
@@ -31948,7 +31948,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EncryptedBoundAuthenticateResponse {
         pub bindings: ::std::vec::Vec<UserSigningKeyBinding>,
         pub encrypted_authorization_key: EncryptedAuthorizationKey,
@@ -32005,7 +32005,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct Ethereum7702AuthorizationCondition {
         pub field: Ethereum7702AuthorizationConditionField,
         pub field_source: Ethereum7702AuthorizationConditionFieldSource,
@@ -32230,7 +32230,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumCalldataCondition {
         pub abi: AbiSchema,
         pub field: ::std::string::String,
@@ -32351,7 +32351,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumEarnPositionQuery {
         ///The vault ID to get position for.
         pub vault_id: ::std::string::String,
@@ -32421,7 +32421,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumEarnPositionResponse {
         pub asset: EarnAsset,
         ///Current asset value in the vault (realtime from ERC-4626), in
@@ -32613,7 +32613,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumEarnVaultDetailsResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub app_apy: ::std::option::Option<f64>,
@@ -32682,7 +32682,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumPersonalSignRpcInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<::std::string::String>,
@@ -32897,7 +32897,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumPersonalSignRpcInputParams {
         pub encoding: EthereumPersonalSignRpcInputParamsEncoding,
         pub message: EthereumPersonalSignRpcInputParamsMessage,
@@ -33117,7 +33117,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumPersonalSignRpcResponse {
         pub data: EthereumPersonalSignRpcResponseData,
         pub method: EthereumPersonalSignRpcResponseMethod,
@@ -33157,7 +33157,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumPersonalSignRpcResponseData {
         pub encoding: EthereumPersonalSignRpcResponseDataEncoding,
         pub signature: ::std::string::String,
@@ -33374,7 +33374,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum EthereumRpcInput {
         SignTransactionRpcInput(EthereumSignTransactionRpcInput),
@@ -33482,7 +33482,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum EthereumRpcResponse {
         PersonalSignRpcResponse(EthereumPersonalSignRpcResponse),
@@ -33589,7 +33589,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSecp256k1SignRpcInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<::std::string::String>,
@@ -33787,7 +33787,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSecp256k1SignRpcInputParams {
         pub hash: Hex,
     }
@@ -33828,7 +33828,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSecp256k1SignRpcResponse {
         pub data: EthereumSecp256k1SignRpcResponseData,
         pub method: EthereumSecp256k1SignRpcResponseMethod,
@@ -33869,7 +33869,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSecp256k1SignRpcResponseData {
         pub encoding: EthereumSecp256k1SignRpcResponseDataEncoding,
         pub signature: Hex,
@@ -34073,7 +34073,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSendCallsCall {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub data: ::std::option::Option<Hex>,
@@ -34140,7 +34140,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSendCallsRpcInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<::std::string::String>,
@@ -34347,7 +34347,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSendCallsRpcInputParams {
         pub calls: ::std::vec::Vec<EthereumSendCallsCall>,
     }
@@ -34386,7 +34386,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSendCallsRpcResponse {
         pub data: EthereumSendCallsRpcResponseData,
         pub method: EthereumSendCallsRpcResponseMethod,
@@ -34423,7 +34423,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSendCallsRpcResponseData {
         pub caip2: Caip2,
         pub transaction_id: ::std::string::String,
@@ -34572,7 +34572,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSendTransactionRpcInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<::std::string::String>,
@@ -34777,7 +34777,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSendTransactionRpcInputParams {
         pub transaction: UnsignedEthereumTransaction,
     }
@@ -34908,7 +34908,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSendTransactionRpcResponse {
         pub data: EthereumSendTransactionRpcResponseData,
         pub method: EthereumSendTransactionRpcResponseMethod,
@@ -34960,7 +34960,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSendTransactionRpcResponseData {
         pub caip2: Caip2,
         pub hash: ::std::string::String,
@@ -35104,7 +35104,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSign7702Authorization {
         pub chain_id: Quantity,
         pub contract: ::std::string::String,
@@ -35162,7 +35162,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSign7702AuthorizationRpcInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<::std::string::String>,
@@ -35384,7 +35384,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSign7702AuthorizationRpcInputParams {
         pub chain_id: Quantity,
         pub contract: ::std::string::String,
@@ -35515,7 +35515,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSign7702AuthorizationRpcResponse {
         pub data: EthereumSign7702AuthorizationRpcResponseData,
         pub method: EthereumSign7702AuthorizationRpcResponseMethod,
@@ -35552,7 +35552,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSign7702AuthorizationRpcResponseData {
         pub authorization: EthereumSign7702Authorization,
     }
@@ -35690,7 +35690,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSignTransactionRpcInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<::std::string::String>,
@@ -35888,7 +35888,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSignTransactionRpcInputParams {
         pub transaction: UnsignedEthereumTransaction,
     }
@@ -35930,7 +35930,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSignTransactionRpcResponse {
         pub data: EthereumSignTransactionRpcResponseData,
         pub method: EthereumSignTransactionRpcResponseMethod,
@@ -35973,7 +35973,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSignTransactionRpcResponseData {
         pub encoding: EthereumSignTransactionRpcResponseDataEncoding,
         pub signed_transaction: ::std::string::String,
@@ -36191,7 +36191,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSignTypedDataRpcInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<::std::string::String>,
@@ -36389,7 +36389,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSignTypedDataRpcInputParams {
         pub typed_data: EthereumTypedDataInput,
     }
@@ -36430,7 +36430,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSignTypedDataRpcResponse {
         pub data: EthereumSignTypedDataRpcResponseData,
         pub method: EthereumSignTypedDataRpcResponseMethod,
@@ -36471,7 +36471,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSignTypedDataRpcResponseData {
         pub encoding: EthereumSignTypedDataRpcResponseDataEncoding,
         pub signature: ::std::string::String,
@@ -36689,7 +36689,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSignUserOperationRpcInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<::std::string::String>,
@@ -36899,7 +36899,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSignUserOperationRpcInputParams {
         pub chain_id: Quantity,
         pub contract: ::std::string::String,
@@ -36944,7 +36944,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSignUserOperationRpcResponse {
         pub data: EthereumSignUserOperationRpcResponseData,
         pub method: EthereumSignUserOperationRpcResponseMethod,
@@ -36987,7 +36987,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumSignUserOperationRpcResponseData {
         pub encoding: EthereumSignUserOperationRpcResponseDataEncoding,
         pub signature: ::std::string::String,
@@ -37209,7 +37209,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumTransactionCondition {
         pub field: EthereumTransactionConditionField,
         pub field_source: EthereumTransactionConditionFieldSource,
@@ -37436,7 +37436,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumTypedDataDomainCondition {
         pub field: EthereumTypedDataDomainConditionField,
         pub field_source: EthereumTypedDataDomainConditionFieldSource,
@@ -37664,7 +37664,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumTypedDataInput {
         pub domain: TypedDataDomainInputParams,
         pub message: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
@@ -37733,7 +37733,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumTypedDataMessageCondition {
         pub field: ::std::string::String,
         pub field_source: EthereumTypedDataMessageConditionFieldSource,
@@ -37855,7 +37855,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumTypedDataMessageConditionTypedData {
         pub primary_type: ::std::string::String,
         pub types: TypedDataTypesInputParams,
@@ -37896,7 +37896,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumVaultDetailsInput {
         ///The Privy vault ID.
         pub vault_id: ::std::string::String,
@@ -37988,7 +37988,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumVaultDetailsResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub app_apy: ::std::option::Option<f64>,
@@ -38088,7 +38088,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumVaultPosition {
         pub asset: EthereumVaultPositionAsset,
         ///Current asset value in the vault (realtime from ERC4626), in
@@ -38132,7 +38132,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumVaultPositionAsset {
         ///Token contract address.
         pub address: ::std::string::String,
@@ -38212,7 +38212,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumVaultResponse {
         ///The address of the underlying asset token (e.g., USDC).
         pub asset_address: ::std::string::String,
@@ -38262,7 +38262,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumYieldClaimIdInput {
         ///The yield claim transaction ID.
         pub id: ::std::string::String,
@@ -38301,7 +38301,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumYieldClaimInput {
         pub caip2: EvmCaip2ChainId,
     }
@@ -38390,7 +38390,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumYieldClaimResponse {
         pub caip2: EvmCaip2ChainId,
         pub created_at: f64,
@@ -38564,7 +38564,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumYieldClaimReward {
         ///Amount claimed in the smallest unit.
         pub amount: ::std::string::String,
@@ -38618,7 +38618,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumYieldDepositInput {
         ///The amount of the underlying asset to deposit, in the smallest unit
         /// (e.g., wei for ETH, 6 decimals for USDC). Must be a non-negative
@@ -38803,7 +38803,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumYieldPositionResponse {
         pub asset: EthereumYieldPositionResponseAsset,
         ///Current asset value in the vault (realtime from ERC4626), in
@@ -38847,7 +38847,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumYieldPositionResponseAsset {
         ///Token contract address.
         pub address: ::std::string::String,
@@ -38890,7 +38890,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumYieldPositionsInput {
         ///The vault ID to get position for.
         pub vault_id: ::std::string::String,
@@ -39016,7 +39016,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumYieldSweepIdInput {
         ///The yield sweep ID.
         pub id: ::std::string::String,
@@ -39104,7 +39104,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumYieldSweepResponse {
         ///The amount of underlying assets involved. Set after the sweep is
         /// confirmed on-chain.
@@ -39350,7 +39350,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EthereumYieldWithdrawInput {
         ///The amount of the underlying asset to withdraw, in the smallest unit
         /// (e.g., wei for ETH, 6 decimals for USDC). Must be a non-negative
@@ -39866,7 +39866,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EvmTransactionWalletActionStep {
         ///CAIP-2 chain identifier of the transaction, containing the chain ID.
         pub caip2: ::std::string::String,
@@ -40033,7 +40033,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct EvmUserOperationWalletActionStep {
         ///Transaction hash of the bundle in which this user operation was
         /// included. Null until included by a bundler.
@@ -40389,7 +40389,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ExchangeStripeTokensInput {
         pub auth_intent_id: ExchangeStripeTokensInputAuthIntentId,
         pub crypto_customer_id: ExchangeStripeTokensInputCryptoCustomerId,
@@ -40588,7 +40588,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ExchangeStripeTokensResponse {
         pub crypto_customer_id: ::std::string::String,
     }
@@ -40623,7 +40623,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ExecuteQuoteBody {
         pub user_id: ExecuteQuoteBodyUserId,
     }
@@ -40795,7 +40795,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ExecuteQuoteResponse {
         pub data: ExecuteQuoteResponseData,
     }
@@ -40867,7 +40867,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ExecuteQuoteResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -40941,7 +40941,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ExecuteQuoteResponseDataResult {
         pub quote_id: ::std::string::String,
         pub status: ExecuteQuoteResponseDataResultStatus,
@@ -40988,7 +40988,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub enum ExecuteQuoteResponseDataResultStatus {
         #[serde(rename = "accepted")]
         Accepted,
@@ -41044,7 +41044,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ExportPrivateKeyRpcInput {
         pub address: ::std::string::String,
         pub method: ExportPrivateKeyRpcInputMethod,
@@ -41164,7 +41164,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ExportPrivateKeyRpcResponse {
         pub data: PrivateKeyExportInput,
         pub method: ExportPrivateKeyRpcResponseMethod,
@@ -41287,7 +41287,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ExportSeedPhraseRpcInput {
         pub address: ::std::string::String,
         pub method: ExportSeedPhraseRpcInputMethod,
@@ -41407,7 +41407,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ExportSeedPhraseRpcResponse {
         pub data: SeedPhraseExportResponse,
         pub method: ExportSeedPhraseRpcResponseMethod,
@@ -41910,7 +41910,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ExternalTransactionWalletActionStep {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub failure_reason: ::std::option::Option<FailureReason>,
@@ -42143,7 +42143,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FailureReason {
         ///Additional error details, if available.
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -42194,7 +42194,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterAuthenticateInput {
         pub channel_token: ::std::string::String,
         pub fid: f64,
@@ -42246,7 +42246,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterAuthenticateRequestBody {
         pub channel_token: ::std::string::String,
         pub fid: f64,
@@ -42288,7 +42288,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterConnectInitResponse {
         pub channel_token: ::std::string::String,
         pub connect_uri: ::std::string::String,
@@ -42326,7 +42326,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterConnectInitResponseBody {
         pub channel_token: ::std::string::String,
         pub connect_uri: ::std::string::String,
@@ -42397,7 +42397,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterConnectStatusCompletedResponse {
         pub bio: ::std::string::String,
         pub display_name: ::std::string::String,
@@ -42476,7 +42476,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterConnectStatusCompletedResponseBody {
         pub bio: ::std::string::String,
         pub display_name: ::std::string::String,
@@ -42694,7 +42694,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterConnectStatusPendingResponse {
         pub nonce: ::std::string::String,
         pub state: FarcasterConnectStatusPendingResponseState,
@@ -42737,7 +42737,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterConnectStatusPendingResponseBody {
         pub nonce: ::std::string::String,
         pub state: FarcasterConnectStatusPendingResponseBodyState,
@@ -42940,7 +42940,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterInitInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub redirect_url: ::std::option::Option<::std::string::String>,
@@ -42992,7 +42992,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterInitRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub redirect_url: ::std::option::Option<::std::string::String>,
@@ -43051,7 +43051,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterLinkInput {
         pub channel_token: ::std::string::String,
         pub fid: f64,
@@ -43098,7 +43098,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterLinkRequestBody {
         pub channel_token: ::std::string::String,
         pub fid: f64,
@@ -43145,7 +43145,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterSignerApproved {
         pub fid: ::std::string::String,
         pub public_key: ::std::string::String,
@@ -43271,7 +43271,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterSignerInitPendingApproval {
         pub public_key: ::std::string::String,
         pub signer_approval_url: ::std::string::String,
@@ -43390,7 +43390,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterSignerInitRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub deadline: ::std::option::Option<::std::string::String>,
@@ -43427,7 +43427,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum FarcasterSignerInitResponseBody {
         InitPendingApproval(FarcasterSignerInitPendingApproval),
@@ -43492,7 +43492,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterSignerRevoked {
         pub fid: ::std::string::String,
         pub public_key: ::std::string::String,
@@ -43614,7 +43614,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterSignerStatusPendingApproval {
         pub public_key: ::std::string::String,
         pub status: FarcasterSignerStatusPendingApprovalStatus,
@@ -43735,7 +43735,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum FarcasterSignerStatusResponseBody {
         StatusPendingApproval(FarcasterSignerStatusPendingApproval),
@@ -43790,7 +43790,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterUnlinkInput {
         pub fid: f64,
     }
@@ -43822,7 +43822,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterUnlinkRequestBody {
         pub fid: f64,
     }
@@ -43865,7 +43865,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterV2AuthenticateInput {
         pub fid: f64,
         pub message: ::std::string::String,
@@ -43914,7 +43914,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterV2AuthenticateRequestBody {
         pub fid: f64,
         pub message: ::std::string::String,
@@ -43945,7 +43945,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct FarcasterV2InitInput(
         pub ::serde_json::Map<::std::string::String, ::serde_json::Value>,
@@ -43994,7 +43994,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct FarcasterV2InitRequestBody(
         pub ::serde_json::Map<::std::string::String, ::serde_json::Value>,
@@ -44053,7 +44053,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterV2InitResponse {
         pub expires_at: ::std::string::String,
         pub nonce: ::std::string::String,
@@ -44091,7 +44091,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FarcasterV2InitResponseBody {
         pub expires_at: ::std::string::String,
         pub nonce: ::std::string::String,
@@ -44128,7 +44128,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct FeeConfiguration(pub TotalFeeConfigurationBps);
     impl ::std::ops::Deref for FeeConfiguration {
@@ -44185,7 +44185,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum FeeLineItem {
         RelayerFee(RelayerFee),
@@ -44512,7 +44512,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum FiatCustomerResponse {
         FiatCustomerResponse(BridgeFiatCustomerResponse),
@@ -44574,7 +44574,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FiatOnrampDestination {
         pub address: ::std::string::String,
         pub asset: CryptoCurrencyCode,
@@ -44831,7 +44831,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FiatOnrampProviderError {
         pub error: ::std::string::String,
         pub provider: FiatOnrampProvider,
@@ -44890,7 +44890,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FiatOnrampQuote {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub destination_currency_code: ::std::option::Option<::std::string::String>,
@@ -44942,7 +44942,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FiatOnrampSource {
         pub amount: FiatAmount,
         pub asset: FiatCurrencyCode,
@@ -44999,7 +44999,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FiatOnrampStripeSdkSessionResponse {
         pub network: ::std::string::String,
         pub publishable_key: ::std::string::String,
@@ -45251,7 +45251,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FiatOnrampUrlSessionResponse {
         pub session_id: ::std::string::String,
         #[serde(rename = "type")]
@@ -45480,7 +45480,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum FiatVirtualAccountRequest {
         Variant0(BridgeFiatVirtualAccountRequest),
@@ -45551,7 +45551,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum FiatVirtualAccountResponse {
         FiatVirtualAccountResponse(BridgeFiatVirtualAccountResponse),
@@ -45711,7 +45711,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FundingConfigResponseSchema {
         pub cross_chain_bridging_enabled: bool,
         pub default_recommended_amount: ::std::string::String,
@@ -45844,7 +45844,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FundingOption {
         pub method: ::std::string::String,
         pub provider: ::std::string::String,
@@ -45961,7 +45961,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FundsDepositedWebhookPayload {
         ///The amount transferred, as a stringified bigint.
         pub amount: ::std::string::String,
@@ -46020,7 +46020,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FundsDepositedWebhookPayloadBlock {
         pub number: f64,
         pub timestamp: f64,
@@ -46208,7 +46208,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FundsWithdrawnWebhookPayload {
         ///The amount transferred, as a stringified bigint.
         pub amount: ::std::string::String,
@@ -46265,7 +46265,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct FundsWithdrawnWebhookPayloadBlock {
         pub number: f64,
         pub timestamp: f64,
@@ -46403,7 +46403,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct Gas {
         ///Gas cost in the gas token as a human-readable decimal string (e.g.
         /// "0.0001").
@@ -46556,7 +46556,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GasSpendRequestBody {
         pub end_timestamp: f64,
         pub start_timestamp: f64,
@@ -46611,7 +46611,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GasSpendResponseBody {
         pub currency: GasSpendCurrency,
         ///Total Privy credits charged as a decimal string.
@@ -46991,7 +46991,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetByWalletAddressRequestBody {
         pub address: Address,
     }
@@ -47448,7 +47448,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetCustomOrderHistoryResponse {
         pub data: KrakenEmbedGetCustomOrderHistoryResponse,
     }
@@ -47477,7 +47477,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetCustomOrderResponse {
         pub data: KrakenEmbedGetCustomOrderResponse,
     }
@@ -47602,7 +47602,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetFiatCustomerRequestInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub kyc_redirect_url: ::std::option::Option<::std::string::String>,
@@ -47658,7 +47658,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetFiatOnrampQuotesInput {
         pub destination: FiatOnrampDestination,
         pub environment: FiatOnrampEnvironment,
@@ -47715,7 +47715,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetFiatOnrampQuotesResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub provider_errors: ::std::vec::Vec<FiatOnrampProviderError>,
@@ -47760,7 +47760,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetFiatOnrampTransactionStatusInput {
         pub provider: FiatOnrampProvider,
         pub session_id: ::std::string::String,
@@ -47815,7 +47815,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetFiatOnrampTransactionStatusResponse {
         pub raw_status: ::std::string::String,
         pub session_id: ::std::string::String,
@@ -47889,7 +47889,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetFiatOnrampUrlInput {
         pub destination: FiatOnrampDestination,
         pub payment_method: ::std::string::String,
@@ -47929,7 +47929,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum GetFiatOnrampUrlResponse {
         UrlSessionResponse(FiatOnrampUrlSessionResponse),
@@ -48395,7 +48395,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetKrakenEmbedAssetsResponse {
         pub data: GetKrakenEmbedAssetsResponseData,
     }
@@ -48549,7 +48549,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetKrakenEmbedAssetsResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -48705,7 +48705,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetKrakenEmbedAssetsResponseDataResult {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub ath_date: ::std::option::Option<::std::string::String>,
@@ -49208,7 +49208,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetKrakenEmbedAssetsResponseDataResultLinks {
         pub logo: GetKrakenEmbedAssetsResponseDataResultLinksLogo,
         #[serde(rename = "self")]
@@ -49254,7 +49254,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetKrakenEmbedAssetsResponseDataResultLinksLogo {
         pub height: u64,
         pub href: ::std::string::String,
@@ -49296,7 +49296,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetKrakenEmbedAssetsResponseDataResultLinksSelf {
         pub href: ::std::string::String,
         #[serde(
@@ -50462,7 +50462,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetKrakenUserResponse {
         pub data: GetKrakenUserResponseData,
     }
@@ -51105,7 +51105,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetKrakenUserResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -51750,7 +51750,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetKrakenUserResponseDataResult {
         pub created_at: ::chrono::DateTime<::chrono::offset::Utc>,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -52355,7 +52355,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(tag = "state")]
     pub enum GetKrakenUserResponseDataResultStatus {
         #[serde(rename = "ok")]
@@ -52645,7 +52645,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(tag = "action_type")]
     pub enum GetKrakenUserResponseDataResultStatusRequiredActionsItem {
         #[serde(rename = "verification")]
@@ -52858,7 +52858,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(tag = "type")]
     pub enum GetKrakenUserResponseDataResultStatusRequiredActionsItemDetailsType {
         #[serde(rename = "terms_of_service")]
@@ -53410,7 +53410,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioDetailsResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -53558,7 +53558,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioDetailsResponseResult {
         pub assets: ::std::vec::Vec<GetMasterAccountPortfolioDetailsResponseResultAssetsItem>,
         pub currency: GetMasterAccountPortfolioDetailsResponseResultCurrency,
@@ -53672,7 +53672,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioDetailsResponseResultAssetsItem {
         pub asset: GetMasterAccountPortfolioDetailsResponseResultAssetsItemAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -55262,7 +55262,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum GetMasterAccountPortfolioHistoryIncludeAssets {
         Variant0(::std::vec::Vec<GetMasterAccountPortfolioHistoryIncludeAssetsVariant0Item>),
@@ -55925,7 +55925,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioHistoryResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -56048,7 +56048,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioHistoryResponseResult {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub currency: ::std::option::Option<GetMasterAccountPortfolioHistoryResponseResultCurrency>,
@@ -56217,7 +56217,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioHistoryResponseResultHistoryItem {
         #[serde(
             default,
@@ -56270,7 +56270,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioHistoryResponseResultHistoryItemAssetsValue {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub balance: ::std::option::Option<
@@ -57321,7 +57321,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsRefIdsItem {
         pub ref_id: GetMasterAccountPortfolioTransactionsRefIdsItemRefId,
         #[serde(rename = "type")]
@@ -58104,7 +58104,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -58710,7 +58710,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResult {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub next_cursor: ::std::option::Option<::std::string::String>,
@@ -58744,7 +58744,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultStats {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub transactions_seen: ::std::option::Option<u64>,
@@ -59321,7 +59321,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItem {
         pub details: GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetails,
         pub id: ::std::string::String,
@@ -59486,7 +59486,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetails {
         Variant0 { # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] parent_transaction : :: std :: option :: Option < :: std :: string :: String > , quote_id : :: std :: string :: String , trade_type : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant0TradeType , # [serde (rename = "type")] type_ : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant0Type , } , Variant1 { failure_reason : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1FailureReason , receive_asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1ReceiveAsset , spend_asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1SpendAsset , trade_type : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1TradeType , # [serde (rename = "type")] type_ : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1Type , } , Variant2 { # [serde (rename = "type")] type_ : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant2Type , } , Variant3 (:: std :: string :: String) , }
@@ -59710,7 +59710,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1ReceiveAsset { pub asset : :: std :: string :: String , pub class : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1ReceiveAssetClass , }
     impl :: std :: convert :: From < & GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1ReceiveAsset > for GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1ReceiveAsset { fn from (value : & GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1ReceiveAsset) -> Self { value . clone () } }
     ///`GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1ReceiveAssetClass`
@@ -59775,7 +59775,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1SpendAsset { pub asset : :: std :: string :: String , pub class : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1SpendAssetClass , }
     impl :: std :: convert :: From < & GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1SpendAsset > for GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1SpendAsset { fn from (value : & GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1SpendAsset) -> Self { value . clone () } }
     ///`GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemDetailsVariant1SpendAssetClass`
@@ -60245,7 +60245,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceive {
         pub amount:
             GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveAmount,
@@ -60320,7 +60320,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveAmount { pub amount : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveAmountAmount , pub asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveAmountAsset , # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] pub asset_class : :: std :: option :: Option < GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveAmountAssetClass > , }
     impl
         ::std::convert::From<
@@ -60631,7 +60631,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveBalance { pub amount : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveBalanceAmount , pub asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveBalanceAsset , # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] pub asset_class : :: std :: option :: Option < GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveBalanceAssetClass > , }
     impl
         ::std::convert::From<
@@ -60930,7 +60930,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveFee {
         pub amount:
             GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveFeeAmount,
@@ -61305,7 +61305,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveQuotedAmount { pub amount : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveQuotedAmountAmount , pub asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveQuotedAmountAsset , # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] pub asset_class : :: std :: option :: Option < GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveQuotedAmountAssetClass > , }
     impl
         ::std::convert::From<
@@ -61439,7 +61439,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveQuotedFee { pub amount : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveQuotedFeeAmount , pub asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveQuotedFeeAsset , # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] pub asset_class : :: std :: option :: Option < GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveQuotedFeeAssetClass > , }
     impl
         ::std::convert::From<
@@ -61633,7 +61633,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveQuotedTotal { pub amount : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveQuotedTotalAmount , pub asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveQuotedTotalAsset , # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] pub asset_class : :: std :: option :: Option < GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveQuotedTotalAssetClass > , }
     impl
         ::std::convert::From<
@@ -61767,7 +61767,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveTotal { pub amount : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveTotalAmount , pub asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveTotalAsset , # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] pub asset_class : :: std :: option :: Option < GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemReceiveTotalAssetClass > , }
     impl
         ::std::convert::From<
@@ -62242,7 +62242,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpend {
         pub amount: GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendAmount,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -62316,7 +62316,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendAmount { pub amount : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendAmountAmount , pub asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendAmountAsset , # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] pub asset_class : :: std :: option :: Option < GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendAmountAssetClass > , }
     impl
         ::std::convert::From<
@@ -62681,7 +62681,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendBalance { pub amount : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendBalanceAmount , pub asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendBalanceAsset , # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] pub asset_class : :: std :: option :: Option < GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendBalanceAssetClass > , }
     impl
         ::std::convert::From<
@@ -62990,7 +62990,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendFee {
         pub amount:
             GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendFeeAmount,
@@ -63364,7 +63364,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendQuotedAmount { pub amount : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendQuotedAmountAmount , pub asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendQuotedAmountAsset , # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] pub asset_class : :: std :: option :: Option < GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendQuotedAmountAssetClass > , }
     impl
         ::std::convert::From<
@@ -63497,7 +63497,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendQuotedFee { pub amount : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendQuotedFeeAmount , pub asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendQuotedFeeAsset , # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] pub asset_class : :: std :: option :: Option < GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendQuotedFeeAssetClass > , }
     impl
         ::std::convert::From<
@@ -63796,7 +63796,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendQuotedTotal { pub amount : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendQuotedTotalAmount , pub asset : GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendQuotedTotalAsset , # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] pub asset_class : :: std :: option :: Option < GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendQuotedTotalAssetClass > , }
     impl
         ::std::convert::From<
@@ -63990,7 +63990,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendTotal {
         pub amount:
             GetMasterAccountPortfolioTransactionsResponseResultTransactionsItemSpendTotalAmount,
@@ -65155,7 +65155,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioDetailsResponse {
         pub data: GetPortfolioDetailsResponseData,
     }
@@ -65299,7 +65299,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioDetailsResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -65445,7 +65445,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioDetailsResponseDataResult {
         pub assets: ::std::vec::Vec<GetPortfolioDetailsResponseDataResultAssetsItem>,
         pub currency: GetPortfolioDetailsResponseDataResultCurrency,
@@ -65559,7 +65559,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioDetailsResponseDataResultAssetsItem {
         pub asset: GetPortfolioDetailsResponseDataResultAssetsItemAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -67372,7 +67372,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsRefIdsItem {
         pub ref_id: GetPortfolioTransactionsRefIdsItemRefId,
         #[serde(rename = "type")]
@@ -68235,7 +68235,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponse {
         pub data: GetPortfolioTransactionsResponseData,
     }
@@ -68837,7 +68837,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -69443,7 +69443,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResult {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub next_cursor: ::std::option::Option<::std::string::String>,
@@ -69477,7 +69477,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultStats {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub transactions_seen: ::std::option::Option<u64>,
@@ -70054,7 +70054,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItem {
         pub details: GetPortfolioTransactionsResponseDataResultTransactionsItemDetails,
         pub id: ::std::string::String,
@@ -70215,7 +70215,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum GetPortfolioTransactionsResponseDataResultTransactionsItemDetails {
         Variant0 { # [serde (default , skip_serializing_if = "::std::option::Option::is_none")] parent_transaction : :: std :: option :: Option < :: std :: string :: String > , quote_id : :: std :: string :: String , trade_type : GetPortfolioTransactionsResponseDataResultTransactionsItemDetailsVariant0TradeType , # [serde (rename = "type")] type_ : GetPortfolioTransactionsResponseDataResultTransactionsItemDetailsVariant0Type , } , Variant1 { failure_reason : GetPortfolioTransactionsResponseDataResultTransactionsItemDetailsVariant1FailureReason , receive_asset : GetPortfolioTransactionsResponseDataResultTransactionsItemDetailsVariant1ReceiveAsset , spend_asset : GetPortfolioTransactionsResponseDataResultTransactionsItemDetailsVariant1SpendAsset , trade_type : GetPortfolioTransactionsResponseDataResultTransactionsItemDetailsVariant1TradeType , # [serde (rename = "type")] type_ : GetPortfolioTransactionsResponseDataResultTransactionsItemDetailsVariant1Type , } , Variant2 { # [serde (rename = "type")] type_ : GetPortfolioTransactionsResponseDataResultTransactionsItemDetailsVariant2Type , } , Variant3 (:: std :: string :: String) , }
@@ -70561,7 +70561,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemDetailsVariant1ReceiveAsset { pub asset : :: std :: string :: String , pub class : GetPortfolioTransactionsResponseDataResultTransactionsItemDetailsVariant1ReceiveAssetClass , }
     impl
         ::std::convert::From<
@@ -70638,7 +70638,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemDetailsVariant1SpendAsset { pub asset : :: std :: string :: String , pub class : GetPortfolioTransactionsResponseDataResultTransactionsItemDetailsVariant1SpendAssetClass , }
     impl
         ::std::convert::From<
@@ -71237,7 +71237,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemReceive {
         pub amount: GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveAmount,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -71307,7 +71307,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveAmount {
         pub amount: GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveAmountAmount,
         pub asset: GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveAmountAsset,
@@ -71680,7 +71680,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveBalance {
         pub amount: GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveBalanceAmount,
         pub asset: GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveBalanceAsset,
@@ -72053,7 +72053,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveFee {
         pub amount: GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveFeeAmount,
         pub asset: GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveFeeAsset,
@@ -72424,7 +72424,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveQuotedAmount {
         pub amount:
             GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveQuotedAmountAmount,
@@ -72799,7 +72799,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveQuotedFee {
         pub amount:
             GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveQuotedFeeAmount,
@@ -73173,7 +73173,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveQuotedTotal {
         pub amount:
             GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveQuotedTotalAmount,
@@ -73548,7 +73548,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveTotal {
         pub amount: GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveTotalAmount,
         pub asset: GetPortfolioTransactionsResponseDataResultTransactionsItemReceiveTotalAsset,
@@ -74087,7 +74087,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemSpend {
         pub amount: GetPortfolioTransactionsResponseDataResultTransactionsItemSpendAmount,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -74157,7 +74157,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemSpendAmount {
         pub amount: GetPortfolioTransactionsResponseDataResultTransactionsItemSpendAmountAmount,
         pub asset: GetPortfolioTransactionsResponseDataResultTransactionsItemSpendAmountAsset,
@@ -74529,7 +74529,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemSpendBalance {
         pub amount: GetPortfolioTransactionsResponseDataResultTransactionsItemSpendBalanceAmount,
         pub asset: GetPortfolioTransactionsResponseDataResultTransactionsItemSpendBalanceAsset,
@@ -74902,7 +74902,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemSpendFee {
         pub amount: GetPortfolioTransactionsResponseDataResultTransactionsItemSpendFeeAmount,
         pub asset: GetPortfolioTransactionsResponseDataResultTransactionsItemSpendFeeAsset,
@@ -75271,7 +75271,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemSpendQuotedAmount {
         pub amount:
             GetPortfolioTransactionsResponseDataResultTransactionsItemSpendQuotedAmountAmount,
@@ -75645,7 +75645,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemSpendQuotedFee {
         pub amount: GetPortfolioTransactionsResponseDataResultTransactionsItemSpendQuotedFeeAmount,
         pub asset: GetPortfolioTransactionsResponseDataResultTransactionsItemSpendQuotedFeeAsset,
@@ -76018,7 +76018,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemSpendQuotedTotal {
         pub amount:
             GetPortfolioTransactionsResponseDataResultTransactionsItemSpendQuotedTotalAmount,
@@ -76392,7 +76392,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetPortfolioTransactionsResponseDataResultTransactionsItemSpendTotal {
         pub amount: GetPortfolioTransactionsResponseDataResultTransactionsItemSpendTotalAmount,
         pub asset: GetPortfolioTransactionsResponseDataResultTransactionsItemSpendTotalAsset,
@@ -77222,7 +77222,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum GetProtfolioHistoryForUserIncludeAssets {
         Variant0(::std::vec::Vec<GetProtfolioHistoryForUserIncludeAssetsVariant0Item>),
@@ -77929,7 +77929,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetProtfolioHistoryForUserResponse {
         pub data: GetProtfolioHistoryForUserResponseData,
     }
@@ -78050,7 +78050,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetProtfolioHistoryForUserResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -78173,7 +78173,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetProtfolioHistoryForUserResponseDataResult {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub currency: ::std::option::Option<GetProtfolioHistoryForUserResponseDataResultCurrency>,
@@ -78341,7 +78341,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetProtfolioHistoryForUserResponseDataResultHistoryItem {
         #[serde(
             default,
@@ -78393,7 +78393,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetProtfolioHistoryForUserResponseDataResultHistoryItemAssetsValue {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub balance: ::std::option::Option<
@@ -79419,7 +79419,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetQuoteResponse {
         pub data: GetQuoteResponseData,
     }
@@ -79735,7 +79735,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetQuoteResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -80053,7 +80053,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetQuoteResponseDataResult {
         pub expires: ::chrono::DateTime<::chrono::offset::Utc>,
         pub quote_id: ::std::string::String,
@@ -80125,7 +80125,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetQuoteResponseDataResultQuotedReceive {
         pub asset: GetQuoteResponseDataResultQuotedReceiveAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -80656,7 +80656,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetQuoteResponseDataResultQuotedSpend {
         pub asset: GetQuoteResponseDataResultQuotedSpendAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -81176,7 +81176,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetQuoteResponseDataResultQuotedUnitPrice {
         pub asset: GetQuoteResponseDataResultQuotedUnitPriceAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -81691,7 +81691,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetQuoteResponseDataResultReceive {
         pub asset: GetQuoteResponseDataResultReceiveAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -82206,7 +82206,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetQuoteResponseDataResultSpend {
         pub asset: GetQuoteResponseDataResultSpendAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -82702,7 +82702,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub enum GetQuoteResponseDataResultStatus {
         #[serde(rename = "accepted")]
         Accepted,
@@ -82853,7 +82853,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetQuoteResponseDataResultUnitPrice {
         pub asset: GetQuoteResponseDataResultUnitPriceAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -83594,7 +83594,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetStripeCryptoCustomerResponse {
         pub data: GetStripeCryptoCustomerResponseData,
     }
@@ -83625,7 +83625,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum GetStripeCryptoCustomerResponseData {
         Active(StripeCryptoCustomerActive),
@@ -83713,7 +83713,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetUserFiatAccountsResponse {
         pub accounts: ::std::vec::Vec<GetUserFiatAccountsResponseAccountsItem>,
     }
@@ -83756,7 +83756,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetUserFiatAccountsResponseAccountsItem {
         pub account_type: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -83820,7 +83820,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetUserFiatKycLinkBody {
         pub email: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
@@ -84097,7 +84097,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetUserFiatKycLinkResponse {
         pub created_at: ::std::string::String,
         pub customer_id: ::std::string::String,
@@ -84259,7 +84259,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetUserFiatKycLinkResponseRejectionReasonsItem {
         pub created_at: ::std::string::String,
         pub developer_reason: ::std::string::String,
@@ -84390,7 +84390,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetUserFiatKycStatusResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub provider_user_id: ::std::option::Option<::std::string::String>,
@@ -84509,7 +84509,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetUsersResponse {
         pub data: ::std::vec::Vec<User>,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -84548,7 +84548,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
     #[serde(untagged)]
     pub enum GetWalletBalanceAsset {
         WalletEthereumAsset(WalletEthereumAsset),
@@ -84645,7 +84645,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum GetWalletBalanceChain {
         String(GetWalletBalanceChainString),
@@ -85176,7 +85176,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetWalletBalanceResponse {
         pub balances: ::std::vec::Vec<GetWalletBalanceResponseBalancesItem>,
     }
@@ -85255,7 +85255,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetWalletBalanceResponseBalancesItem {
         pub asset: GetWalletBalanceResponseBalancesItemAsset,
         pub chain: GetWalletBalanceResponseBalancesItemChain,
@@ -85293,7 +85293,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum GetWalletBalanceResponseBalancesItemAsset {
         WalletEthereumAsset(WalletEthereumAsset),
@@ -85527,7 +85527,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum GetWalletBalanceToken {
         String(::std::string::String),
@@ -85745,7 +85745,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GetWalletsResponse {
         pub data: ::std::vec::Vec<Wallet>,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -85781,7 +85781,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct GuestAuthenticateRequestBody {
         pub guest_credential: GuestAuthenticateRequestBodyGuestCredential,
     }
@@ -85927,7 +85927,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct HdInitInput {
         ///The address of the wallet to import.
         pub address: ::std::string::String,
@@ -86171,7 +86171,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct HdSubmitInput {
         ///The address of the wallet to import.
         pub address: ::std::string::String,
@@ -86573,7 +86573,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct HpkeImportConfig {
         ///Additional Authenticated Data (AAD) used during encryption. Should
         /// be base64-encoded bytes.
@@ -86815,7 +86815,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateMasterAccountWithdrawalBody {
         pub amount: InitiateMasterAccountWithdrawalBodyAmount,
         pub asset: InitiateMasterAccountWithdrawalBodyAsset,
@@ -87124,7 +87124,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateMasterAccountWithdrawalResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -87170,7 +87170,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateMasterAccountWithdrawalResponseResult {
         pub reference_id: ::std::string::String,
     }
@@ -87837,7 +87837,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(tag = "provider", content = "data")]
     pub enum InitiateUserFiatKycBody {
         #[serde(rename = "bridge")]
@@ -88095,7 +88095,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatKycBodyBridgeDocumentsItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub description:
@@ -88505,7 +88505,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatKycBodyBridgeIdentifyingInformationItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub description: ::std::option::Option<::std::string::String>,
@@ -88660,7 +88660,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatKycBodyBridgeKycScreen {
         pub result: InitiateUserFiatKycBodyBridgeKycScreenResult,
         pub screened_at: InitiateUserFiatKycBodyBridgeKycScreenScreenedAt,
@@ -89137,7 +89137,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatKycBodyBridgeOfacScreen {
         pub result: InitiateUserFiatKycBodyBridgeOfacScreenResult,
         pub screened_at: InitiateUserFiatKycBodyBridgeOfacScreenScreenedAt,
@@ -89458,7 +89458,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatKycBodyBridgeResidentialAddress {
         pub city: InitiateUserFiatKycBodyBridgeResidentialAddressCity,
         pub country: InitiateUserFiatKycBodyBridgeResidentialAddressCountry,
@@ -90145,7 +90145,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatKycBodyBridgeSandboxDocumentsItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub description:
@@ -90568,7 +90568,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatKycBodyBridgeSandboxIdentifyingInformationItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub description: ::std::option::Option<::std::string::String>,
@@ -90731,7 +90731,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatKycBodyBridgeSandboxKycScreen {
         pub result: InitiateUserFiatKycBodyBridgeSandboxKycScreenResult,
         pub screened_at: InitiateUserFiatKycBodyBridgeSandboxKycScreenScreenedAt,
@@ -91224,7 +91224,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatKycBodyBridgeSandboxOfacScreen {
         pub result: InitiateUserFiatKycBodyBridgeSandboxOfacScreenResult,
         pub screened_at: InitiateUserFiatKycBodyBridgeSandboxOfacScreenScreenedAt,
@@ -91545,7 +91545,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatKycBodyBridgeSandboxResidentialAddress {
         pub city: InitiateUserFiatKycBodyBridgeSandboxResidentialAddressCity,
         pub country: InitiateUserFiatKycBodyBridgeSandboxResidentialAddressCountry,
@@ -92560,7 +92560,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatKycBodyBridgeSandboxTransliteratedResidentialAddress {
         pub city: InitiateUserFiatKycBodyBridgeSandboxTransliteratedResidentialAddressCity,
         pub country: InitiateUserFiatKycBodyBridgeSandboxTransliteratedResidentialAddressCountry,
@@ -93733,7 +93733,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatKycBodyBridgeTransliteratedResidentialAddress {
         pub city: InitiateUserFiatKycBodyBridgeTransliteratedResidentialAddressCity,
         pub country: InitiateUserFiatKycBodyBridgeTransliteratedResidentialAddressCountry,
@@ -94544,7 +94544,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatOfframpBody {
         pub amount: InitiateUserFiatOfframpBodyAmount,
         pub destination: InitiateUserFiatOfframpBodyDestination,
@@ -94668,7 +94668,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatOfframpBodyDestination {
         pub currency: FiatCurrency,
         pub external_account_id: ::uuid::Uuid,
@@ -94709,7 +94709,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatOfframpBodySource {
         pub chain: OnrampChain,
         pub currency: OnrampAsset,
@@ -94798,7 +94798,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatOnrampBody {
         pub amount: InitiateUserFiatOnrampBodyAmount,
         pub destination: InitiateUserFiatOnrampBodyDestination,
@@ -94919,7 +94919,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatOnrampBodyDestination {
         pub chain: OnrampChain,
         pub currency: OnrampAsset,
@@ -94956,7 +94956,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct InitiateUserFiatOnrampBodySource {
         pub currency: FiatCurrency,
         pub payment_rail: FiatPaymentRail,
@@ -95003,7 +95003,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct IntentAuthorization {
         ///Display name of the key quorum
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -95085,7 +95085,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(tag = "type")]
     pub enum IntentAuthorizationKeyQuorumMember {
         ///User member
@@ -95224,7 +95224,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(tag = "type")]
     pub enum IntentAuthorizationMember {
         ///User member
@@ -95294,7 +95294,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct IntentAuthorizeInput {
         ///Signature authorizing the intent.
         pub signature: ::std::string::String,
@@ -95383,7 +95383,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct IntentAuthorizedWebhookPayload {
         pub authorized_at: f64,
         pub created_at: f64,
@@ -95520,7 +95520,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct IntentBinding {
         #[serde(rename = "intentId")]
         pub intent_id: ::std::string::String,
@@ -95677,7 +95677,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct IntentCreatedWebhookPayload {
         ///Key quorums that can authorize this intent.
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
@@ -95814,7 +95814,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct IntentCreationHeaders {
         ///ID of your Privy app.
         #[serde(rename = "privy-app-id")]
@@ -95911,7 +95911,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct IntentExecutedWebhookPayload {
         pub action_result: BaseActionResult,
         pub created_at: f64,
@@ -96094,7 +96094,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct IntentFailedWebhookPayload {
         pub action_result: BaseActionResult,
         pub created_at: f64,
@@ -96263,7 +96263,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct IntentRejectedWebhookPayload {
         pub created_at: f64,
         ///Display name of the user who created the intent.
@@ -96402,7 +96402,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum IntentResponse {
         RpcIntentResponse(RpcIntentResponse),
@@ -96761,7 +96761,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KeyQuorum {
         pub authorization_keys: ::std::vec::Vec<KeyQuorumAuthorizationKeysItem>,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -96816,7 +96816,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KeyQuorumAuthorizationHeaders {
         ///ID of your Privy app.
         #[serde(rename = "privy-app-id")]
@@ -96867,7 +96867,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KeyQuorumAuthorizationKeysItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub display_name: ::std::option::Option<KeyQuorumAuthorizationKeysItemDisplayName>,
@@ -97023,7 +97023,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KeyQuorumCreateRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub authorization_threshold: ::std::option::Option<f64>,
@@ -97399,7 +97399,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KeyQuorumIntentResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub action_result: ::std::option::Option<BaseActionResult>,
@@ -97558,7 +97558,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KeyQuorumIntentResponseRequestDetails {
         pub body: KeyQuorumUpdateRequestBody,
         pub method: KeyQuorumIntentResponseRequestDetailsMethod,
@@ -97707,7 +97707,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KeyQuorumUpdateRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub authorization_threshold: ::std::option::Option<f64>,
@@ -97851,7 +97851,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCancelCustomOrderInput {
         pub user_id: KrakenEmbedCancelCustomOrderInputUserId,
     }
@@ -97970,7 +97970,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCancelCustomOrderPath {
         pub order_id: KrakenEmbedCancelCustomOrderPathOrderId,
     }
@@ -98099,7 +98099,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCancelCustomOrderResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -98149,7 +98149,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCancelCustomOrderResult {
         pub order: KrakenEmbedCustomOrder,
     }
@@ -98197,7 +98197,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCreateCustomOrderInput {
         pub action: KrakenEmbedCustomOrderAction,
         pub name: ::std::string::String,
@@ -98331,7 +98331,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCreateCustomOrderResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -98381,7 +98381,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCreateCustomOrderResult {
         pub order: KrakenEmbedCustomOrder,
     }
@@ -98425,7 +98425,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCurrentDayPnl {
         pub pnl: KrakenEmbedCurrentDayPnlPnl,
         pub since: ::chrono::DateTime<::chrono::offset::Utc>,
@@ -98577,7 +98577,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCustomOrder {
         pub action: KrakenEmbedCustomOrderAction,
         pub created_at: ::chrono::DateTime<::chrono::offset::Utc>,
@@ -98637,7 +98637,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCustomOrderAction {
         pub amount: KrakenEmbedCustomOrderAmount,
         pub fee_bps: KrakenEmbedCustomOrderActionFeeBps,
@@ -98942,7 +98942,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCustomOrderAmount {
         pub amount: KrakenEmbedCustomOrderAmountAmount,
         pub asset: ::std::string::String,
@@ -99185,7 +99185,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCustomOrderCancelledWebhookPayload {
         ///ISO 8601 timestamp of when the custom order was cancelled.
         pub cancelled_at: ::std::string::String,
@@ -99338,7 +99338,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCustomOrderExecutedWebhookPayload {
         ///The ID of the executed custom order.
         pub custom_order_id: ::std::string::String,
@@ -99497,7 +99497,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCustomOrderExecutionFailedWebhookPayload {
         ///The ID of the custom order that failed to execute.
         pub custom_order_id: ::std::string::String,
@@ -99672,7 +99672,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCustomOrderOccurrence {
         pub created_at: ::chrono::DateTime<::chrono::offset::Utc>,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -99716,7 +99716,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCustomOrderOccurrenceExecutedAction {
         pub quote_id: ::std::string::String,
     }
@@ -99846,7 +99846,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCustomOrderOccurrenceTrigger {
         #[serde(rename = "type")]
         pub type_: KrakenEmbedCustomOrderOccurrenceTriggerType,
@@ -99981,7 +99981,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCustomOrderQuoteAsset {
         pub asset: ::std::string::String,
     }
@@ -100021,7 +100021,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCustomOrderStatus {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub reason: ::std::option::Option<::serde_json::Value>,
@@ -100178,7 +100178,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedCustomOrderTrigger {
         pub base_asset: ::std::string::String,
         pub condition: KrakenEmbedCustomOrderTriggerCondition,
@@ -100483,7 +100483,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedEarnAmount {
         pub converted: KrakenEmbedEarnAmountConverted,
         pub native: KrakenEmbedEarnAmountNative,
@@ -100712,7 +100712,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedEarnAprEstimate {
         pub high: KrakenEmbedEarnAprEstimateHigh,
         pub low: KrakenEmbedEarnAprEstimateLow,
@@ -100945,7 +100945,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedEarnAsset {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub apr_estimate: ::std::option::Option<KrakenEmbedEarnAprEstimate>,
@@ -101007,7 +101007,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedEarnUserAllocation {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub total_allocated: ::std::option::Option<KrakenEmbedEarnAmount>,
@@ -101132,7 +101132,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetAssetListQueryParamsSchema {
         #[serde(
             rename = "filter[assets]",
@@ -101317,7 +101317,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum KrakenEmbedGetAssetListQueryParamsSchemaFilterPlatformStatusesItem {
         Variant0(KrakenEmbedGetAssetListQueryParamsSchemaFilterPlatformStatusesItemVariant0),
@@ -101849,7 +101849,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetCustomOrderHistoryQueryParams {
         pub order_id: KrakenEmbedGetCustomOrderHistoryQueryParamsOrderId,
         pub user_id: KrakenEmbedGetCustomOrderHistoryQueryParamsUserId,
@@ -102077,7 +102077,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetCustomOrderHistoryResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -102130,7 +102130,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetCustomOrderHistoryResult {
         pub history: ::std::vec::Vec<KrakenEmbedCustomOrderOccurrence>,
     }
@@ -102171,7 +102171,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetCustomOrderQueryParams {
         pub order_id: KrakenEmbedGetCustomOrderQueryParamsOrderId,
         pub user_id: KrakenEmbedGetCustomOrderQueryParamsUserId,
@@ -102391,7 +102391,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetCustomOrderResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -102441,7 +102441,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetCustomOrderResult {
         pub order: KrakenEmbedCustomOrder,
     }
@@ -102486,7 +102486,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetEarnAssetsKrakenResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -102546,7 +102546,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetEarnAssetsQueryParams {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub assets: ::std::vec::Vec<KrakenEmbedGetEarnAssetsQueryParamsAssetsItem>,
@@ -102859,7 +102859,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetEarnAssetsResponse {
         pub data: KrakenEmbedGetEarnAssetsKrakenResponse,
     }
@@ -102894,7 +102894,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetEarnAssetsResult {
         pub assets: ::std::collections::HashMap<::std::string::String, KrakenEmbedEarnAsset>,
     }
@@ -102939,7 +102939,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetEarnSummaryKrakenResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -102990,7 +102990,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetEarnSummaryQueryParams {
         pub currency: KrakenEmbedGetEarnSummaryQueryParamsCurrency,
     }
@@ -103115,7 +103115,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetEarnSummaryResponse {
         pub data: KrakenEmbedGetEarnSummaryKrakenResponse,
     }
@@ -103195,7 +103195,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetEarnSummaryResult {
         pub auto_earn_eligible: bool,
         pub auto_earn_enabled: bool,
@@ -103558,7 +103558,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetPortfolioDetailsQueryParamsSchema {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub quote: ::std::option::Option<KrakenEmbedGetPortfolioDetailsQueryParamsSchemaQuote>,
@@ -103701,7 +103701,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetPortfolioSummaryQueryParams {
         #[serde(
             rename = "include[current_day_pnl]",
@@ -104035,7 +104035,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetPortfolioSummaryResponse {
         pub data: KrakenEmbedGetPortfolioSummaryResponseData,
     }
@@ -104142,7 +104142,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetPortfolioSummaryResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::std::string::String>,
@@ -104247,7 +104247,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetPortfolioSummaryResponseDataResult {
         pub available_balance: KrakenEmbedGetPortfolioSummaryResponseDataResultAvailableBalance,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -104591,7 +104591,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum KrakenEmbedGetPortfolioSummaryResponseDataResultCurrentDayPnl {
         Variant0(KrakenEmbedCurrentDayPnl),
@@ -105146,7 +105146,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetPortfolioTransactionsQueryParamsSchema {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub assets: ::std::vec::Vec<KrakenEmbedGetPortfolioTransactionsQueryParamsSchemaAssetsItem>,
@@ -105412,7 +105412,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetPortfolioTransactionsQueryParamsSchemaRefIdsItem {
         pub ref_id: KrakenEmbedGetPortfolioTransactionsQueryParamsSchemaRefIdsItemRefId,
         #[serde(rename = "type")]
@@ -105933,7 +105933,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedGetQuoteQueryParams {
         ///The ID of the Privy user.
         pub user_id: KrakenEmbedGetQuoteQueryParamsUserId,
@@ -106074,7 +106074,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedListCustomOrdersQueryParams {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub cursor: ::std::option::Option<::std::string::String>,
@@ -106117,7 +106117,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum KrakenEmbedListCustomOrdersQueryParamsStatuses {
         Array(::std::vec::Vec<KrakenEmbedListCustomOrdersQueryParamsStatusesArrayItem>),
@@ -106362,7 +106362,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedListCustomOrdersResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -106415,7 +106415,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedListCustomOrdersResult {
         pub orders: ::std::vec::Vec<KrakenEmbedCustomOrder>,
     }
@@ -106477,7 +106477,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedQuoteCancelledWebhookPayload {
         ///ISO 8601 timestamp of when the quote was cancelled.
         pub cancelled_at: ::std::string::String,
@@ -106628,7 +106628,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedQuoteExecutedWebhookPayload {
         ///ISO 8601 timestamp of when the quote was executed.
         pub executed_at: ::std::string::String,
@@ -106780,7 +106780,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedQuoteExecutionFailedWebhookPayload {
         ///ISO 8601 timestamp of when the quote execution failed.
         pub failed_at: ::std::string::String,
@@ -107228,7 +107228,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedStartAddressMetadata {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<KrakenEmbedStartAddressMetadataAddress>,
@@ -107557,7 +107557,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedStartAddressMetadataAddress {
         pub city: ::std::string::String,
         pub country: KrakenEmbedStartAddressMetadataAddressCountry,
@@ -109184,7 +109184,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedStartAddressVerificationUrlInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub debug: ::std::option::Option<KrakenEmbedStartVerificationDebug>,
@@ -109325,7 +109325,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedStartIdentityInfo {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub date_of_birth: ::std::option::Option<::chrono::naive::NaiveDate>,
@@ -109373,7 +109373,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedStartIdentityInfoFullName {
         pub first_name: ::std::string::String,
         pub last_name: ::std::string::String,
@@ -109967,7 +109967,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedStartIdentityMetadata {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub document_number: ::std::option::Option<::std::string::String>,
@@ -112939,7 +112939,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedStartIdentityVerificationUrlInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub back_url: ::std::option::Option<::std::string::String>,
@@ -113095,7 +113095,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedStartLivenessVerificationUrlInput {
         pub center_url: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -113221,7 +113221,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedStartVerificationDebug {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub outcome: ::std::option::Option<KrakenEmbedStartVerificationDebugOutcome>,
@@ -113350,7 +113350,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum KrakenEmbedStartVerificationUrlInput {
         IdentityVerificationUrlInput(KrakenEmbedStartIdentityVerificationUrlInput),
@@ -113415,7 +113415,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedStartVerificationUrlResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub result: ::std::option::Option<KrakenEmbedStartVerificationUrlResult>,
@@ -113459,7 +113459,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedStartVerificationUrlResult {
         pub verification_id: ::std::string::String,
     }
@@ -113501,7 +113501,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedToggleAutoEarnKrakenResponse {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -113551,7 +113551,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedToggleAutoEarnQueryParams {
         pub want_enabled: bool,
     }
@@ -113588,7 +113588,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedToggleAutoEarnResponse {
         pub data: KrakenEmbedToggleAutoEarnKrakenResponse,
     }
@@ -113641,7 +113641,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedUpcomingReward {
         pub accumulated_amount: KrakenEmbedEarnAmount,
         pub asset: ::std::string::String,
@@ -113693,7 +113693,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedUserClosedWebhookPayload {
         ///ISO 8601 timestamp of when the user was closed.
         pub closed_at: ::std::string::String,
@@ -113829,7 +113829,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedUserDisabledWebhookPayload {
         ///ISO 8601 timestamp of when the user was disabled.
         pub disabled_at: ::std::string::String,
@@ -113965,7 +113965,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct KrakenEmbedUserVerifiedWebhookPayload {
         ///The type of webhook event.
         #[serde(rename = "type")]
@@ -114093,7 +114093,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkAuthIntentCreated {
         ///The Link auth intent ID.
         pub id: ::std::string::String,
@@ -114210,7 +114210,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkAuthIntentNoAccount {
         pub status: LinkAuthIntentNoAccountStatus,
     }
@@ -114318,7 +114318,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkJwtInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub token: ::std::option::Option<::std::string::String>,
@@ -114439,7 +114439,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum LinkedAccount {
         Email(LinkedAccountEmail),
@@ -114679,7 +114679,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountAppleInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -114899,7 +114899,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountAppleOauth {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -115036,7 +115036,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountAuthorizationKey {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub first_verified_at: ::std::option::Option<f64>,
@@ -115170,7 +115170,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountBaseWallet {
         pub address: ::std::string::String,
         pub chain_type: LinkedAccountBaseWalletChainType,
@@ -115447,7 +115447,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountBitcoinSegwitEmbeddedWallet {
         pub address: ::std::string::String,
         pub chain_id: ::std::string::String,
@@ -115989,7 +115989,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountBitcoinTaprootEmbeddedWallet {
         pub address: ::std::string::String,
         pub chain_id: ::std::string::String,
@@ -116492,7 +116492,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountCrossApp {
         pub embedded_wallets: ::std::vec::Vec<CrossAppEmbeddedWallet>,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -116684,7 +116684,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountCurveSigningEmbeddedWallet {
         pub address: ::std::string::String,
         pub chain_id: ::std::string::String,
@@ -117086,7 +117086,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountCustomJwt {
         pub custom_user_id: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -117134,7 +117134,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountCustomJwtInput {
         pub custom_user_id: LinkedAccountCustomJwtInputCustomUserId,
         #[serde(rename = "type")]
@@ -117439,7 +117439,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountCustomOauth {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -117504,7 +117504,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountDiscordInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -117817,7 +117817,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountDiscordOauth {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -117956,7 +117956,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountEmail {
         pub address: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -118003,7 +118003,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountEmailInput {
         pub address: ::std::string::String,
         #[serde(rename = "type")]
@@ -118207,7 +118207,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum LinkedAccountEmbeddedWallet {
         EthereumEmbeddedWallet(LinkedAccountEthereumEmbeddedWallet),
@@ -118292,7 +118292,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum LinkedAccountEmbeddedWalletWithId {
         Variant0 {
@@ -120876,7 +120876,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountEthereum {
         pub address: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -121071,7 +121071,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountEthereumEmbeddedWallet {
         pub address: ::std::string::String,
         pub chain_id: ::std::string::String,
@@ -121734,7 +121734,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountFarcaster {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub bio: ::std::option::Option<::std::string::String>,
@@ -121819,7 +121819,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountFarcasterInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub bio: ::std::option::Option<LinkedAccountFarcasterInputBio>,
@@ -122461,7 +122461,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountGithubInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -122779,7 +122779,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountGithubOauth {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -122920,7 +122920,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountGoogleInput {
         pub email: ::std::string::String,
         pub name: ::std::string::String,
@@ -123144,7 +123144,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountGoogleOauth {
         pub email: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -123318,7 +123318,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum LinkedAccountInput {
         WalletInput(LinkedAccountWalletInput),
@@ -123489,7 +123489,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountInstagramInput {
         pub subject: LinkedAccountInstagramInputSubject,
         #[serde(rename = "type")]
@@ -123801,7 +123801,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountInstagramOauth {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub first_verified_at: ::std::option::Option<f64>,
@@ -123942,7 +123942,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountLineInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -124257,7 +124257,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountLineOauth {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -124399,7 +124399,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountLinkedInInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -124635,7 +124635,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountLinkedInOauth {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -124795,7 +124795,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountPasskey {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub authenticator_name: ::std::option::Option<::std::string::String>,
@@ -124874,7 +124874,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountPasskeyInput {
         pub credential_device_type: LinkedAccountPasskeyInputCredentialDeviceType,
         pub credential_id: LinkedAccountPasskeyInputCredentialId,
@@ -125446,7 +125446,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountPhone {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub first_verified_at: ::std::option::Option<f64>,
@@ -125495,7 +125495,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountPhoneInput {
         pub number: ::std::string::String,
         #[serde(rename = "type")]
@@ -125711,7 +125711,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountSmartWallet {
         pub address: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -125869,7 +125869,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountSolana {
         pub address: ::std::string::String,
         pub chain_type: LinkedAccountSolanaChainType,
@@ -126064,7 +126064,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountSolanaEmbeddedWallet {
         pub address: ::std::string::String,
         pub chain_id: ::std::string::String,
@@ -126699,7 +126699,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountSpotifyInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -126839,7 +126839,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountSpotifyOauth {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -126990,7 +126990,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountTelegram {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub first_name: ::std::option::Option<::std::string::String>,
@@ -127062,7 +127062,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountTelegramInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub first_name: ::std::option::Option<LinkedAccountTelegramInputFirstName>,
@@ -127699,7 +127699,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountTiktokInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub name: ::std::option::Option<LinkedAccountTiktokInputName>,
@@ -128095,7 +128095,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountTiktokOauth {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub first_verified_at: ::std::option::Option<f64>,
@@ -128229,7 +128229,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountTwitchInput {
         pub subject: LinkedAccountTwitchInputSubject,
         #[serde(rename = "type")]
@@ -128532,7 +128532,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountTwitchOauth {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub first_verified_at: ::std::option::Option<f64>,
@@ -128675,7 +128675,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountTwitterInput {
         pub name: LinkedAccountTwitterInputName,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -129075,7 +129075,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountTwitterOauth {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub first_verified_at: ::std::option::Option<f64>,
@@ -129321,7 +129321,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum LinkedAccountType {
         Variant0(LinkedAccountTypeVariant0),
@@ -131188,7 +131188,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LinkedAccountWalletInput {
         pub address: LinkedAccountWalletInputAddress,
         pub chain_type: LinkedAccountWalletInputChainType,
@@ -131219,7 +131219,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum LinkedAccountWalletInputAddress {
         Variant0(::std::string::String),
@@ -131428,7 +131428,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum LinkedMfaMethod {
         SmsMfaMethod(SmsMfaMethod),
@@ -131854,7 +131854,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetRatesResponse {
         pub data: ListAssetRatesResponseData,
     }
@@ -131971,7 +131971,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetRatesResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -132090,7 +132090,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetRatesResponseDataResult {
         pub meta: ListAssetRatesResponseDataResultMeta,
         pub rates: ::std::vec::Vec<ListAssetRatesResponseDataResultRatesItem>,
@@ -132164,7 +132164,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetRatesResponseDataResultMeta {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub page_newest_rate:
@@ -132225,7 +132225,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetRatesResponseDataResultMetaPageNewestRate {
         pub price: ListAssetRatesResponseDataResultMetaPageNewestRatePrice,
         pub timestamp: ::chrono::DateTime<::chrono::offset::Utc>,
@@ -132366,7 +132366,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetRatesResponseDataResultMetaPageOldestRate {
         pub price: ListAssetRatesResponseDataResultMetaPageOldestRatePrice,
         pub timestamp: ::chrono::DateTime<::chrono::offset::Utc>,
@@ -132709,7 +132709,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetRatesResponseDataResultRatesItem {
         pub price: ListAssetRatesResponseDataResultRatesItemPrice,
         pub timestamp: ::chrono::DateTime<::chrono::offset::Utc>,
@@ -132929,7 +132929,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum ListAssetsFilterPlatformStatusesItem {
         Variant0(ListAssetsFilterPlatformStatusesItemVariant0),
@@ -133604,7 +133604,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetsResponse {
         pub data: ListAssetsResponseData,
     }
@@ -133921,7 +133921,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetsResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -134240,7 +134240,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetsResponseDataResult {
         pub assets: ::std::vec::Vec<ListAssetsResponseDataResultAssetsItem>,
         pub links: ListAssetsResponseDataResultLinks,
@@ -134492,7 +134492,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetsResponseDataResultAssetsItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub ath_date: ::std::option::Option<::std::string::String>,
@@ -135606,7 +135606,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetsResponseDataResultAssetsItemLinks {
         pub logo: ListAssetsResponseDataResultAssetsItemLinksLogo,
         #[serde(rename = "self")]
@@ -135652,7 +135652,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetsResponseDataResultAssetsItemLinksLogo {
         pub height: u64,
         pub href: ::std::string::String,
@@ -135694,7 +135694,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetsResponseDataResultAssetsItemLinksSelf {
         pub href: ::std::string::String,
         #[serde(
@@ -136043,7 +136043,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum ListAssetsResponseDataResultAssetsItemPlatformStatus {
         Variant0(ListAssetsResponseDataResultAssetsItemPlatformStatusVariant0),
@@ -136507,7 +136507,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum ListAssetsResponseDataResultAssetsItemUserStatus {
         Variant0(ListAssetsResponseDataResultAssetsItemUserStatusVariant0),
@@ -136765,7 +136765,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetsResponseDataResultLinks {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub first: ::std::option::Option<::std::string::String>,
@@ -136831,7 +136831,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListAssetsResponseDataResultMeta {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub page_number: ::std::option::Option<u64>,
@@ -137047,7 +137047,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListCustomOrdersResponse {
         pub data: KrakenEmbedListCustomOrdersResponse,
     }
@@ -137494,7 +137494,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListIntentsResponse {
         pub data: ::std::vec::Vec<IntentResponse>,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -137701,7 +137701,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListStripeConsumerWalletsResponse {
         pub data: ::std::vec::Vec<StripeConsumerWallet>,
     }
@@ -137738,7 +137738,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ListStripePaymentTokensResponse {
         pub data: ::std::vec::Vec<StripePaymentToken>,
     }
@@ -137767,7 +137767,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LookUpUserByCustomAuthIdBody {
         pub custom_user_id: ::std::string::String,
     }
@@ -137796,7 +137796,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LookUpUserByDiscordUsernameBody {
         pub username: ::std::string::String,
     }
@@ -137826,7 +137826,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LookUpUserByEmailBody {
         pub address: ::std::string::String,
     }
@@ -137855,7 +137855,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LookUpUserByFarcasterIdBody {
         pub fid: f64,
     }
@@ -137884,7 +137884,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LookUpUserByGithubUsernameBody {
         pub username: ::std::string::String,
     }
@@ -137913,7 +137913,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LookUpUserByPhoneNumberBody {
         pub number: ::std::string::String,
     }
@@ -137942,7 +137942,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LookUpUserBySmartWalletAddressBody {
         pub address: ::std::string::String,
     }
@@ -137973,7 +137973,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LookUpUserByTelegramUserIdBody {
         pub telegram_user_id: ::std::string::String,
     }
@@ -138002,7 +138002,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LookUpUserByTelegramUsernameBody {
         pub username: ::std::string::String,
     }
@@ -138031,7 +138031,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LookUpUserByTwitterSubjectBody {
         pub subject: ::std::string::String,
     }
@@ -138060,7 +138060,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LookUpUserByTwitterUsernameBody {
         pub username: ::std::string::String,
     }
@@ -138096,7 +138096,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct LookUpUserByWalletAddressBody {
         pub address: LookUpUserByWalletAddressBodyAddress,
     }
@@ -138124,7 +138124,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum LookUpUserByWalletAddressBodyAddress {
         Variant0(::std::string::String),
@@ -138186,7 +138186,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaDisabledWebhookPayload {
         ///The MFA method that was disabled.
         pub method: MfaDisabledWebhookPayloadMethod,
@@ -138413,7 +138413,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaEnabledWebhookPayload {
         ///The MFA method that was enabled.
         pub method: MfaEnabledWebhookPayloadMethod,
@@ -138628,7 +138628,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaPasskeyEnrollmentRequestBody {
         pub credential_ids: ::std::vec::Vec<::std::string::String>,
         #[serde(default = "defaults::default_bool::<true>")]
@@ -138660,7 +138660,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaPasskeyInitRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub relying_party: ::std::option::Option<::std::string::String>,
@@ -138706,7 +138706,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaPasskeyInitResponseBody {
         pub options: PasskeyAuthenticatorVerifyOptions,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -138744,7 +138744,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaPasskeyVerifyRequestBody {
         pub authenticator_response: PasskeyAuthenticatorVerifyResponse,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -138784,7 +138784,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaSmsEnrollRequestBody {
         pub code: MfaSmsEnrollRequestBodyCode,
         #[serde(rename = "phoneNumber")]
@@ -138910,7 +138910,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaSmsInitEnrollInput {
         pub action: MfaSmsInitEnrollInputAction,
         #[serde(rename = "phoneNumber")]
@@ -139022,7 +139022,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum MfaSmsInitRequestBody {
         VerifyInput(MfaSmsInitVerifyInput),
@@ -139071,7 +139071,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaSmsInitVerifyInput {
         pub action: MfaSmsInitVerifyInputAction,
     }
@@ -139184,7 +139184,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaSmsVerifyRequestBody {
         pub code: MfaSmsVerifyRequestBodyCode,
     }
@@ -139305,7 +139305,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaTotpInitResponseBody {
         #[serde(rename = "totpAuthUrl")]
         pub totp_auth_url: ::std::string::String,
@@ -139342,7 +139342,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaTotpInput {
         pub code: MfaTotpInputCode,
     }
@@ -139459,7 +139459,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MfaVerifyResponseBody {
         pub token: ::std::string::String,
     }
@@ -139771,7 +139771,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MoonpayFiatOnRampEthereumConfig {
         #[serde(
             rename = "currencyCode",
@@ -139845,7 +139845,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MoonpayFiatOnRampEthereumInput {
         pub address: ::std::string::String,
         pub config: MoonpayFiatOnRampEthereumConfig,
@@ -139888,7 +139888,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MoonpayFiatOnRampSolanaConfig {
         #[serde(
             rename = "currencyCode",
@@ -139961,7 +139961,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MoonpayFiatOnRampSolanaInput {
         pub address: ::std::string::String,
         pub config: MoonpayFiatOnRampSolanaConfig,
@@ -139992,7 +139992,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MoonpayOnRampSandboxConfig {
         #[serde(rename = "useSandbox", default)]
         pub use_sandbox: bool,
@@ -140039,7 +140039,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum MoonpayOnRampSignInput {
         Variant0 {
@@ -140087,7 +140087,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MoonpayOnRampSignResponse {
         #[serde(rename = "externalTransactionId")]
         pub external_transaction_id: ::std::string::String,
@@ -140331,7 +140331,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct MoonpayUiConfig {
         #[serde(
             rename = "accentColor",
@@ -140490,7 +140490,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct NamedTokenTransferSource {
         ///Amount as a decimal string in the token's standard unit (e.g. "1.5"
         /// for 1.5 USDC, "0.01" for 0.01 ETH). Not in the smallest on-chain
@@ -140620,7 +140620,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthAuthenticateRecoveryResponse {
         pub access_token: ::std::string::String,
     }
@@ -140669,7 +140669,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthAuthenticateRequestBody {
         pub authorization_code: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -140805,7 +140805,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthAuthorizationCodeRequestBody {
         pub code_challenge: ::std::string::String,
         pub redirect_to: ::std::string::String,
@@ -140842,7 +140842,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthCallbackICloudExpoInput {
         #[serde(rename = "ckWebAuthToken")]
         pub ck_web_auth_token: ::std::string::String,
@@ -140988,7 +140988,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthGrant {
         ///When the grant was first created.
         pub created_at: ::chrono::DateTime<::chrono::offset::Utc>,
@@ -141124,7 +141124,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthGrantListResponse {
         ///Active grants.
         pub data: ::std::vec::Vec<OAuthGrant>,
@@ -141166,7 +141166,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthGrantRevokeResponse {
         ///Whether the revocation was processed.
         pub success: bool,
@@ -141201,7 +141201,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthInitICloudRecoveryInput {
         pub client_type: ICloudClientType,
     }
@@ -141242,7 +141242,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthInitRecoveryInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub code_challenge: ::std::option::Option<::std::string::String>,
@@ -141293,7 +141293,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthInitRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub code_challenge: ::std::option::Option<::std::string::String>,
@@ -141332,7 +141332,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthInitResponseBody {
         pub url: ::std::string::String,
     }
@@ -141376,7 +141376,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthLinkRequestBody {
         pub authorization_code: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -141502,7 +141502,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthLinkResponseBody {
         pub created_at: f64,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -141547,7 +141547,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum OAuthProviderId {
         ExternalOAuthProviderId(ExternalOAuthProviderId),
@@ -141634,7 +141634,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthTokenAuthorizationCodeRequestBody {
         ///The client ID. Alternative to Basic auth header.
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -141781,7 +141781,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthTokenDeviceCodePendingError {
         ///The error code indicating why the token request failed.
         pub error: OAuthTokenDeviceCodePendingErrorError,
@@ -141927,7 +141927,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthTokenDeviceCodeRequestBody {
         ///The device code received from the device authorization endpoint.
         pub device_code: ::std::string::String,
@@ -142152,7 +142152,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthTokenRefreshTokenRequestBody {
         pub grant_type: OAuthTokenRefreshTokenRequestBodyGrantType,
         ///The refresh token to exchange for a new access token.
@@ -142273,7 +142273,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum OAuthTokenRequestBody {
         AuthorizationCodeRequestBody(OAuthTokenAuthorizationCodeRequestBody),
@@ -142344,7 +142344,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthTokenSuccessResponse {
         ///The issued access token.
         pub access_token: ::std::string::String,
@@ -142483,7 +142483,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthTokens {
         pub access_token: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -142528,7 +142528,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthTransferRequestBody {
         pub nonce: ::std::string::String,
         #[serde(rename = "userInfo")]
@@ -142592,7 +142592,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthTransferUserInfo {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub email: ::std::option::Option<::std::string::String>,
@@ -142653,7 +142653,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthTransferUserInfoMeta {
         #[serde(
             rename = "providerAppId",
@@ -142702,7 +142702,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthUnlinkRequestBody {
         pub provider: OAuthProviderId,
         pub subject: ::std::string::String,
@@ -142735,7 +142735,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthVerifyRequestBody {
         pub prat: ::std::string::String,
     }
@@ -142767,7 +142767,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OAuthVerifyResponseBody {
         pub verified: bool,
     }
@@ -142815,7 +142815,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OfframpDepositInstructions {
         pub amount: ::std::string::String,
         pub chain: OnrampChain,
@@ -142859,7 +142859,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OfframpResponse {
         pub deposit_instructions: OfframpDepositInstructions,
         pub id: ::std::string::String,
@@ -143115,7 +143115,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OnrampDepositInstructions {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub account_holder_name: ::std::option::Option<::std::string::String>,
@@ -143176,7 +143176,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OnrampKycResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub provider_user_id: ::std::option::Option<::std::string::String>,
@@ -143436,7 +143436,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OnrampResponse {
         pub deposit_instructions: OnrampDepositInstructions,
         pub id: ::std::string::String,
@@ -143476,7 +143476,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OnrampSessionFees {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub network_fee_amount: ::std::option::Option<::std::string::String>,
@@ -143551,7 +143551,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OnrampSessionParams {
         pub crypto_customer_id: OnrampSessionParamsCryptoCustomerId,
         pub destination_currency: CryptoCurrencyCode,
@@ -144041,7 +144041,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OnrampSessionTransactionDetails {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub destination_amount: ::std::option::Option<::std::string::String>,
@@ -144221,7 +144221,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OptionalRefreshTokenInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub refresh_token: ::std::option::Option<::std::string::String>,
@@ -144265,7 +144265,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OrganizationSecretIdInput {
         ///The organization secret ID.
         pub secret_id: OrganizationSecretIdInputSecretId,
@@ -144403,7 +144403,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OrganizationSecretView {
         ///ISO 8601 creation timestamp
         pub created_at: ::std::string::String,
@@ -144451,7 +144451,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OrganizationSecretsListResponse {
         pub data: ::std::vec::Vec<OrganizationSecretView>,
     }
@@ -144491,7 +144491,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OutputWithPreviousTransactionData {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub output: ::std::option::Option<TokenOutput>,
@@ -144602,7 +144602,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum OwnerInput {
         Variant0(OwnerInputUser),
@@ -144650,7 +144650,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OwnerInputPublicKey {
         pub public_key: P256PublicKey,
     }
@@ -144682,7 +144682,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct OwnerInputUser {
         pub user_id: ::std::string::String,
     }
@@ -144791,7 +144791,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyAssertionResponse {
         pub authenticator_data: ::std::string::String,
         pub client_data_json: ::std::string::String,
@@ -144848,7 +144848,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyAttestationResponse {
         pub attestation_object: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -144897,7 +144897,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyAuthenticateInput {
         pub authenticator_response: PasskeyAuthenticatorVerifyResponse,
         pub challenge: ::std::string::String,
@@ -144968,7 +144968,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyAuthenticatorEnrollmentOptions {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub attestation: ::std::option::Option<::std::string::String>,
@@ -145040,7 +145040,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyAuthenticatorEnrollmentResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub authenticator_attachment: ::std::option::Option<::std::string::String>,
@@ -145169,7 +145169,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyAuthenticatorSelection {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub authenticator_attachment: ::std::option::Option<::std::string::String>,
@@ -145239,7 +145239,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyAuthenticatorVerifyOptions {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub allow_credentials: ::std::vec::Vec<PasskeyCredentialDescriptor>,
@@ -145308,7 +145308,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyAuthenticatorVerifyResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub authenticator_attachment: ::std::option::Option<::std::string::String>,
@@ -145432,7 +145432,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyClientExtensionResults {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub app_id: ::std::option::Option<bool>,
@@ -145476,7 +145476,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyCredPropsResult {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub rk: ::std::option::Option<bool>,
@@ -145529,7 +145529,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyCredentialDescriptor {
         pub id: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
@@ -145568,7 +145568,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyEnrollmentExtensions {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub app_id: ::std::option::Option<::std::string::String>,
@@ -145616,7 +145616,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyInitInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub relying_party: ::std::option::Option<::std::string::String>,
@@ -145665,7 +145665,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyLinkInput {
         pub authenticator_response: PasskeyAuthenticatorEnrollmentResponse,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -145706,7 +145706,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyMfaMethod {
         #[serde(rename = "type")]
         pub type_: PasskeyMfaMethodType,
@@ -145828,7 +145828,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyPubKeyCredParam {
         pub alg: f64,
         #[serde(rename = "type")]
@@ -145946,7 +145946,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyRegisterInput {
         pub authenticator_response: PasskeyAuthenticatorEnrollmentResponse,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -145983,7 +145983,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyRelyingParty {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub id: ::std::option::Option<::std::string::String>,
@@ -146025,7 +146025,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyUser {
         pub display_name: ::std::string::String,
         pub id: ::std::string::String,
@@ -146062,7 +146062,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasskeyVerifyExtensions {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub app_id: ::std::option::Option<::std::string::String>,
@@ -146121,7 +146121,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasswordlessAuthenticateRequestBody {
         pub code: PasswordlessAuthenticateRequestBodyCode,
         pub email: ::std::string::String,
@@ -146250,7 +146250,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasswordlessInitRequestBody {
         pub email: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -146291,7 +146291,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasswordlessLinkRequestBody {
         pub code: PasswordlessLinkRequestBodyCode,
         pub email: ::std::string::String,
@@ -146420,7 +146420,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasswordlessSmsAuthenticateRequestBody {
         pub code: PasswordlessSmsAuthenticateRequestBodyCode,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -146551,7 +146551,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasswordlessSmsInitRequestBody {
         #[serde(rename = "phoneNumber")]
         pub phone_number: ::std::string::String,
@@ -146593,7 +146593,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasswordlessSmsLinkRequestBody {
         pub code: PasswordlessSmsLinkRequestBodyCode,
         #[serde(rename = "phoneNumber")]
@@ -146720,7 +146720,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasswordlessSmsTransferRequestBody {
         pub nonce: ::std::string::String,
         #[serde(rename = "phoneNumber")]
@@ -146757,7 +146757,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasswordlessSmsUnlinkRequestBody {
         #[serde(rename = "phoneNumber")]
         pub phone_number: ::std::string::String,
@@ -146801,7 +146801,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasswordlessSmsUpdateRequestBody {
         pub code: PasswordlessSmsUpdateRequestBodyCode,
         pub new_phone_number: ::std::string::String,
@@ -146928,7 +146928,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasswordlessTransferRequestBody {
         pub email: ::std::string::String,
         pub nonce: ::std::string::String,
@@ -146963,7 +146963,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasswordlessUnlinkRequestBody {
         pub address: ::std::string::String,
     }
@@ -147007,7 +147007,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PasswordlessUpdateRequestBody {
         pub code: PasswordlessUpdateRequestBodyCode,
         #[serde(rename = "newAddress")]
@@ -147131,7 +147131,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PatchUsersCustomMetadata {
         pub custom_metadata: CustomMetadata,
     }
@@ -147170,7 +147170,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PhoneInviteInput {
         #[serde(rename = "type")]
         pub type_: PhoneInviteInputType,
@@ -147357,7 +147357,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct Policy {
         pub chain_type: WalletChainType,
         pub created_at: f64,
@@ -147500,7 +147500,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PolicyAuthorizationHeaders {
         ///ID of your Privy app.
         #[serde(rename = "privy-app-id")]
@@ -147594,7 +147594,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum PolicyCondition {
         EthereumTransactionCondition(EthereumTransactionCondition),
@@ -147826,7 +147826,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct PolicyInput(pub ::std::vec::Vec<::std::string::String>);
     impl ::std::ops::Deref for PolicyInput {
@@ -147910,7 +147910,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PolicyIntentRequestDetails {
         pub body: PolicyIntentRequestDetailsBody,
         pub method: PolicyIntentRequestDetailsMethod,
@@ -147953,7 +147953,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PolicyIntentRequestDetailsBody {
         ///Name to assign to policy.
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -148359,7 +148359,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PolicyIntentResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub action_result: ::std::option::Option<BaseActionResult>,
@@ -148533,7 +148533,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PolicyIntentResponseRequestDetails {
         pub body: PolicyIntentResponseRequestDetailsBody,
         pub method: PolicyIntentResponseRequestDetailsMethod,
@@ -148578,7 +148578,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PolicyIntentResponseRequestDetailsBody {
         ///Name to assign to policy.
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -149045,7 +149045,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PolicyRequestBody {
         pub policy_id: PolicyRequestBodyPolicyId,
     }
@@ -149180,7 +149180,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PolicyRuleRequestBody {
         pub action: PolicyAction,
         pub conditions: ::std::vec::Vec<PolicyCondition>,
@@ -149310,7 +149310,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PolicyRuleRequestParams {
         pub policy_id: PolicyRuleRequestParamsPolicyId,
         pub rule_id: PolicyRuleRequestParamsRuleId,
@@ -149552,7 +149552,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PolicyRuleResponse {
         pub action: PolicyAction,
         pub conditions: ::std::vec::Vec<PolicyCondition>,
@@ -149766,7 +149766,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PrivateKeyExportInput {
         pub encryption_type: HpkeEncryption,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -149813,7 +149813,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PrivateKeyExportResponse {
         pub ciphertext: ::std::string::String,
         pub encapsulated_key: ::std::string::String,
@@ -149874,7 +149874,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PrivateKeyExportWebhookPayload {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub export_source: ::std::option::Option<PrivateKeyExportWebhookPayloadExportSource>,
@@ -150098,7 +150098,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PrivateKeyInitInput {
         ///The address of the wallet to import.
         pub address: ::std::string::String,
@@ -150243,7 +150243,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PrivateKeySubmitInput {
         ///The address of the wallet to import.
         pub address: ::std::string::String,
@@ -150392,7 +150392,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct PrivyFee {
         ///Amount in USD (in decimals).
         pub amount: ::std::string::String,
@@ -150428,7 +150428,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum PrivyFeeRecipient {
         Variant0(::std::string::String),
@@ -150626,7 +150626,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum Quantity {
         Hex(Hex),
@@ -150738,7 +150738,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RawBoundAuthenticateResponse {
         pub authorization_key: ::std::string::String,
         pub bindings: ::std::vec::Vec<UserSigningKeyBinding>,
@@ -150982,7 +150982,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RawSignBytesParams {
         ///The bytes to hash and sign.
         pub bytes: RawSignBytesParamsBytes,
@@ -151107,7 +151107,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RawSignHashParams {
         pub hash: Hex,
     }
@@ -151143,7 +151143,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RawSignInput {
         pub params: RawSignInputParams,
     }
@@ -151174,7 +151174,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum RawSignInputParams {
         HashParams(RawSignHashParams),
@@ -151227,7 +151227,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RawSignResponse {
         pub data: RawSignResponseData,
         pub method: RawSignResponseMethod,
@@ -151267,7 +151267,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RawSignResponseData {
         pub encoding: RawSignResponseDataEncoding,
         pub signature: Hex,
@@ -151462,7 +151462,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum RecipientPublicKey {
         Variant0(RecipientPublicKeyVariant0),
@@ -151727,7 +151727,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RecoveryConfigurationICloudInput {
         pub client_type: ICloudClientType,
     }
@@ -151769,7 +151769,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RecoveryConfigurationICloudResponse {
         pub api_token: ::std::string::String,
         pub container_identifier: ::std::string::String,
@@ -151802,7 +151802,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RecoveryKeyMaterialInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub chain_type: ::std::option::Option<::std::string::String>,
@@ -151863,7 +151863,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RecoveryKeyMaterialResponse {
         pub file_id: ::std::string::String,
         pub icloud_record_name: ::std::string::String,
@@ -152007,7 +152007,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RefreshStripeQuoteResponse {
         pub quote: StripeQuote,
     }
@@ -152067,7 +152067,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RelayerFee {
         ///Amount in USD (in decimals).
         pub amount: ::std::string::String,
@@ -152103,7 +152103,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum RelayerFeeRecipient {
         Variant0(::std::string::String),
@@ -152309,7 +152309,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RequestQuoteBody {
         pub amount: RequestQuoteBodyAmount,
         pub fee_bps: RequestQuoteBodyFeeBps,
@@ -152361,7 +152361,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RequestQuoteBodyAmount {
         pub amount: RequestQuoteBodyAmountAmount,
         pub asset: RequestQuoteBodyAmountAsset,
@@ -152737,7 +152737,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RequestQuoteBodyQuote {
         pub asset: RequestQuoteBodyQuoteAsset,
     }
@@ -153504,7 +153504,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RequestQuoteResponse {
         pub data: RequestQuoteResponseData,
     }
@@ -153817,7 +153817,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RequestQuoteResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -154132,7 +154132,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RequestQuoteResponseDataResult {
         pub expires: ::chrono::DateTime<::chrono::offset::Utc>,
         pub quote_id: ::std::string::String,
@@ -154202,7 +154202,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RequestQuoteResponseDataResultQuotedReceive {
         pub asset: RequestQuoteResponseDataResultQuotedReceiveAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -154742,7 +154742,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RequestQuoteResponseDataResultQuotedSpend {
         pub asset: RequestQuoteResponseDataResultQuotedSpendAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -155276,7 +155276,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RequestQuoteResponseDataResultQuotedUnitPrice {
         pub asset: RequestQuoteResponseDataResultQuotedUnitPriceAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -155801,7 +155801,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RequestQuoteResponseDataResultReceive {
         pub asset: RequestQuoteResponseDataResultReceiveAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -156324,7 +156324,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RequestQuoteResponseDataResultSpend {
         pub asset: RequestQuoteResponseDataResultSpendAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -156830,7 +156830,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub enum RequestQuoteResponseDataResultStatus {
         #[serde(rename = "accepted")]
         Accepted,
@@ -156981,7 +156981,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RequestQuoteResponseDataResultUnitPrice {
         pub asset: RequestQuoteResponseDataResultUnitPriceAsset,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -157472,7 +157472,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ResponsePasskeyInitAuthenticate {
         pub options: PasskeyAuthenticatorVerifyOptions,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -157511,7 +157511,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ResponsePasskeyInitLink {
         pub options: PasskeyAuthenticatorEnrollmentOptions,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -157551,7 +157551,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct ResponsePasskeyInitRegister {
         pub options: PasskeyAuthenticatorEnrollmentOptions,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -157683,7 +157683,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RpcIntentRequestDetails {
         pub body: WalletRpcRequestBody,
         pub method: RpcIntentRequestDetailsMethod,
@@ -157993,7 +157993,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RpcIntentResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub action_result: ::std::option::Option<BaseActionResult>,
@@ -158146,7 +158146,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RpcIntentResponseRequestDetails {
         pub body: WalletRpcRequestBody,
         pub method: RpcIntentResponseRequestDetailsMethod,
@@ -158328,7 +158328,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RuleDeleteIntentResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub action_result: ::std::option::Option<BaseActionResult>,
@@ -158488,7 +158488,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RuleIntentCreateRequestDetails {
         pub body: PolicyRuleRequestBody,
         pub method: RuleIntentCreateRequestDetailsMethod,
@@ -158708,7 +158708,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RuleIntentDeleteRequestDetails {
         #[serde(default, skip_serializing_if = "::serde_json::Map::is_empty")]
         pub body: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
@@ -158917,7 +158917,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum RuleIntentRequestDetails {
         CreateRequestDetails(RuleIntentCreateRequestDetails),
@@ -159043,7 +159043,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RuleIntentResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub action_result: ::std::option::Option<BaseActionResult>,
@@ -159203,7 +159203,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RuleIntentUpdateRequestDetails {
         pub body: PolicyRuleRequestBody,
         pub method: RuleIntentUpdateRequestDetailsMethod,
@@ -159473,7 +159473,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct RuleMutateIntentResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub action_result: ::std::option::Option<BaseActionResult>,
@@ -159655,7 +159655,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum SearchUsersBody {
         Variant0 {
@@ -159694,7 +159694,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum SearchUsersBodyVariant1WalletAddressesItem {
         Variant0(::std::string::String),
@@ -159749,7 +159749,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SeedPhraseExportInput {
         pub encryption_type: HpkeEncryption,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -159796,7 +159796,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SeedPhraseExportResponse {
         pub ciphertext: ::std::string::String,
         pub encapsulated_key: ::std::string::String,
@@ -159857,7 +159857,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SeedPhraseExportWebhookPayload {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub export_source: ::std::option::Option<SeedPhraseExportWebhookPayloadExportSource>,
@@ -160170,7 +160170,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiweAddressInput {
         pub address: ::std::string::String,
     }
@@ -160221,7 +160221,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiweAuthenticateRequestBody {
         #[serde(
             rename = "chainId",
@@ -160527,7 +160527,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiweInitInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<::std::string::String>,
@@ -160571,7 +160571,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiweInitRequestBody {
         pub address: ::std::string::String,
     }
@@ -160611,7 +160611,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiweInitResponseBody {
         pub address: ::std::string::String,
         pub expires_at: ::std::string::String,
@@ -160661,7 +160661,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiweInput {
         #[serde(
             rename = "chainId",
@@ -160971,7 +160971,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiweLinkRequestBody {
         #[serde(
             rename = "chainId",
@@ -161279,7 +161279,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiweLinkSmartWalletRequestBody {
         pub message: ::std::string::String,
         pub signature: ::std::string::String,
@@ -161323,7 +161323,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiweNonce {
         pub address: ::std::string::String,
         pub expires_at: ::std::string::String,
@@ -161357,7 +161357,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiweUnlinkRequestBody {
         pub address: ::std::string::String,
     }
@@ -161389,7 +161389,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiwsAddressInput {
         pub address: ::std::string::String,
     }
@@ -161443,7 +161443,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiwsAuthenticateRequestBody {
         #[serde(
             rename = "connectorType",
@@ -161749,7 +161749,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiwsInitInput {
         pub address: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -161783,7 +161783,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiwsInitRequestBody {
         pub address: ::std::string::String,
     }
@@ -161823,7 +161823,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiwsInitResponseBody {
         pub address: ::std::string::String,
         pub expires_at: ::std::string::String,
@@ -161876,7 +161876,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiwsInput {
         #[serde(
             rename = "connectorType",
@@ -162188,7 +162188,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiwsLinkRequestBody {
         #[serde(
             rename = "connectorType",
@@ -162491,7 +162491,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiwsNonce {
         pub address: ::std::string::String,
         pub expires_at: ::std::string::String,
@@ -162525,7 +162525,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SiwsUnlinkRequestBody {
         pub address: ::std::string::String,
     }
@@ -162556,7 +162556,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum SmartWalletConfiguration {
         Disabled(SmartWalletConfigurationDisabled),
@@ -162606,7 +162606,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SmartWalletConfigurationDisabled {
         pub enabled: bool,
     }
@@ -162657,7 +162657,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SmartWalletConfigurationEnabled {
         pub configured_networks: ::std::vec::Vec<SmartWalletNetworkConfiguration>,
         pub enabled: bool,
@@ -162693,7 +162693,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum SmartWalletConfigurationInput {
         Disabled(SmartWalletConfigurationDisabled),
@@ -162759,7 +162759,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SmartWalletConfigurationInputEnabled {
         pub configured_networks: ::std::vec::Vec<SmartWalletNetworkConfigurationInput>,
         pub enabled: bool,
@@ -162815,7 +162815,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SmartWalletNetworkConfiguration {
         pub bundler_url: ::std::string::String,
         pub chain_id: SmartWalletNetworkConfigurationChainId,
@@ -162958,7 +162958,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SmartWalletNetworkConfigurationInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub bundler_url: ::std::option::Option<::std::string::String>,
@@ -163102,7 +163102,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SmartWalletSiweInput {
         pub message: ::std::string::String,
         pub signature: ::std::string::String,
@@ -163257,7 +163257,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SmsMfaMethod {
         #[serde(rename = "type")]
         pub type_: SmsMfaMethodType,
@@ -163568,7 +163568,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaProgramInstructionCondition {
         pub field: SolanaProgramInstructionConditionField,
         pub field_source: SolanaProgramInstructionConditionFieldSource,
@@ -163771,7 +163771,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum SolanaRpcInput {
         TransactionRpcInput(SolanaSignTransactionRpcInput),
@@ -163828,7 +163828,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum SolanaRpcResponse {
         MessageRpcResponse(SolanaSignMessageRpcResponse),
@@ -163919,7 +163919,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSignAndSendTransactionRpcInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<::std::string::String>,
@@ -164140,7 +164140,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSignAndSendTransactionRpcInputParams {
         pub encoding: SolanaSignAndSendTransactionRpcInputParamsEncoding,
         pub transaction: SolanaSignAndSendTransactionRpcInputParamsTransaction,
@@ -164449,7 +164449,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSignAndSendTransactionRpcResponse {
         pub data: SolanaSignAndSendTransactionRpcResponseData,
         pub method: SolanaSignAndSendTransactionRpcResponseMethod,
@@ -164499,7 +164499,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSignAndSendTransactionRpcResponseData {
         pub caip2: Caip2,
         pub hash: ::std::string::String,
@@ -164642,7 +164642,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSignMessageRpcInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<::std::string::String>,
@@ -164847,7 +164847,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSignMessageRpcInputParams {
         pub encoding: SolanaSignMessageRpcInputParamsEncoding,
         pub message: SolanaSignMessageRpcInputParamsMessage,
@@ -165049,7 +165049,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSignMessageRpcResponse {
         pub data: SolanaSignMessageRpcResponseData,
         pub method: SolanaSignMessageRpcResponseMethod,
@@ -165089,7 +165089,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSignMessageRpcResponseData {
         pub encoding: SolanaSignMessageRpcResponseDataEncoding,
         pub signature: ::std::string::String,
@@ -165300,7 +165300,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSignTransactionRpcInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub address: ::std::option::Option<::std::string::String>,
@@ -165505,7 +165505,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSignTransactionRpcInputParams {
         pub encoding: SolanaSignTransactionRpcInputParamsEncoding,
         pub transaction: SolanaSignTransactionRpcInputParamsTransaction,
@@ -165719,7 +165719,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSignTransactionRpcResponse {
         pub data: SolanaSignTransactionRpcResponseData,
         pub method: SolanaSignTransactionRpcResponseMethod,
@@ -165760,7 +165760,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSignTransactionRpcResponseData {
         pub encoding: SolanaSignTransactionRpcResponseDataEncoding,
         pub signed_transaction: ::std::string::String,
@@ -165982,7 +165982,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaSystemProgramInstructionCondition {
         pub field: SolanaSystemProgramInstructionConditionField,
         pub field_source: SolanaSystemProgramInstructionConditionFieldSource,
@@ -166248,7 +166248,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SolanaTokenProgramInstructionCondition {
         pub field: SolanaTokenProgramInstructionConditionField,
         pub field_source: SolanaTokenProgramInstructionConditionFieldSource,
@@ -166663,7 +166663,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkBalance {
         pub balance: ::std::string::String,
         pub token_balances: ::std::collections::HashMap<::std::string::String, SparkTokenBalance>,
@@ -166714,7 +166714,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkClaimStaticDepositRpcInput {
         pub method: SparkClaimStaticDepositRpcInputMethod,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -166840,7 +166840,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkClaimStaticDepositRpcInputParams {
         pub credit_amount_sats: f64,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -166885,7 +166885,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkClaimStaticDepositRpcResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub data: ::std::option::Option<SparkClaimStaticDepositRpcResponseData>,
@@ -166922,7 +166922,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkClaimStaticDepositRpcResponseData {
         pub transfer_id: ::std::string::String,
     }
@@ -167053,7 +167053,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkCreateLightningInvoiceRpcInput {
         pub method: SparkCreateLightningInvoiceRpcInputMethod,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -167186,7 +167186,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkCreateLightningInvoiceRpcInputParams {
         pub amount_sats: f64,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -167237,7 +167237,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkCreateLightningInvoiceRpcResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub data: ::std::option::Option<SparkLightningReceiveRequest>,
@@ -167369,7 +167369,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkGetBalanceRpcInput {
         pub method: SparkGetBalanceRpcInputMethod,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -167488,7 +167488,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkGetBalanceRpcResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub data: ::std::option::Option<SparkBalance>,
@@ -167620,7 +167620,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkGetClaimStaticDepositQuoteRpcInput {
         pub method: SparkGetClaimStaticDepositQuoteRpcInputMethod,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -167745,7 +167745,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkGetClaimStaticDepositQuoteRpcInputParams {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub output_index: ::std::option::Option<f64>,
@@ -167790,7 +167790,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkGetClaimStaticDepositQuoteRpcResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub data: ::std::option::Option<SparkGetClaimStaticDepositQuoteRpcResponseData>,
@@ -167844,7 +167844,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkGetClaimStaticDepositQuoteRpcResponseData {
         pub credit_amount_sats: f64,
         pub network: ::std::string::String,
@@ -167979,7 +167979,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkGetStaticDepositAddressRpcInput {
         pub method: SparkGetStaticDepositAddressRpcInputMethod,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -168104,7 +168104,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkGetStaticDepositAddressRpcResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub data: ::std::option::Option<SparkGetStaticDepositAddressRpcResponseData>,
@@ -168142,7 +168142,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkGetStaticDepositAddressRpcResponseData {
         pub address: ::std::string::String,
     }
@@ -168263,7 +168263,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkLightningFee {
         pub original_unit: ::std::string::String,
         pub original_value: f64,
@@ -168328,7 +168328,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkLightningReceiveRequest {
         pub created_at: ::std::string::String,
         pub id: ::std::string::String,
@@ -168411,7 +168411,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkLightningSendRequest {
         pub created_at: ::std::string::String,
         pub encoded_invoice: ::std::string::String,
@@ -168648,7 +168648,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkPayLightningInvoiceRpcInput {
         pub method: SparkPayLightningInvoiceRpcInputMethod,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -168773,7 +168773,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkPayLightningInvoiceRpcInputParams {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub amount_sats_to_send: ::std::option::Option<f64>,
@@ -168825,7 +168825,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkPayLightningInvoiceRpcResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub data: ::std::option::Option<SparkPayLightningInvoiceRpcResponseData>,
@@ -168857,7 +168857,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum SparkPayLightningInvoiceRpcResponseData {
         Transfer(SparkTransfer),
@@ -169005,7 +169005,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum SparkRpcInput {
         TransferRpcInput(SparkTransferRpcInput),
@@ -169125,7 +169125,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum SparkRpcResponse {
         TransferRpcResponse(SparkTransferRpcResponse),
@@ -169239,7 +169239,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkSignMessageWithIdentityKeyRpcInput {
         pub method: SparkSignMessageWithIdentityKeyRpcInputMethod,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -169364,7 +169364,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkSignMessageWithIdentityKeyRpcInputParams {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub compact: ::std::option::Option<bool>,
@@ -169409,7 +169409,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkSignMessageWithIdentityKeyRpcResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub data: ::std::option::Option<SparkSignMessageWithIdentityKeyRpcResponseData>,
@@ -169447,7 +169447,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkSignMessageWithIdentityKeyRpcResponseData {
         pub signature: ::std::string::String,
     }
@@ -169586,7 +169586,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkSigningKeyshare {
         pub owner_identifiers: ::std::vec::Vec<::std::string::String>,
         pub public_key: ::std::string::String,
@@ -169627,7 +169627,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkTokenBalance {
         pub balance: ::std::string::String,
         pub token_metadata: SparkUserTokenMetadata,
@@ -169700,7 +169700,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkTransfer {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub created_time: ::std::option::Option<::std::string::String>,
@@ -169757,7 +169757,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkTransferLeaf {
         pub intermediate_refund_tx: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -169810,7 +169810,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkTransferRpcInput {
         pub method: SparkTransferRpcInputMethod,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -169928,7 +169928,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkTransferRpcInputParams {
         pub amount_sats: f64,
         pub receiver_spark_address: ::std::string::String,
@@ -169967,7 +169967,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkTransferRpcResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub data: ::std::option::Option<SparkTransfer>,
@@ -170097,7 +170097,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkTransferTokensRpcInput {
         pub method: SparkTransferTokensRpcInputMethod,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -170228,7 +170228,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkTransferTokensRpcInputParams {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub output_selection_strategy: ::std::option::Option<SparkOutputSelectionStrategy>,
@@ -170274,7 +170274,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkTransferTokensRpcResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub data: ::std::option::Option<SparkTransferTokensRpcResponseData>,
@@ -170308,7 +170308,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkTransferTokensRpcResponseData {
         pub id: ::std::string::String,
     }
@@ -170441,7 +170441,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkUserTokenMetadata {
         pub decimals: f64,
         pub max_supply: ::std::string::String,
@@ -170520,7 +170520,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SparkWalletLeaf {
         pub id: ::std::string::String,
         pub network: SparkNetwork,
@@ -170563,7 +170563,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct StartVerificationFromUrlResponse {
         pub data: KrakenEmbedStartVerificationUrlResponse,
     }
@@ -170603,7 +170603,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct StripeConsumerWallet {
         pub id: ::std::string::String,
         pub network: ::std::string::String,
@@ -170651,7 +170651,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct StripeCryptoCustomerActive {
         pub crypto_customer_id: ::std::string::String,
         pub status: StripeCryptoCustomerActiveStatus,
@@ -170773,7 +170773,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct StripeCryptoCustomerExpired {
         pub crypto_customer_id: ::std::string::String,
         pub status: StripeCryptoCustomerExpiredStatus,
@@ -170888,7 +170888,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct StripeCryptoCustomerNone {
         pub status: StripeCryptoCustomerNoneStatus,
     }
@@ -171002,7 +171002,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct StripeOnrampCheckoutResponse {
         pub client_secret: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -171132,7 +171132,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct StripePaymentToken {
         pub id: ::std::string::String,
         #[serde(rename = "type")]
@@ -171166,7 +171166,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct StripeQuote {
         pub expires_at: f64,
     }
@@ -171195,7 +171195,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct StripeTransactionDetails {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub last_error: ::std::option::Option<::std::string::String>,
@@ -171240,7 +171240,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct StripeVerification {
         pub name: ::std::string::String,
         pub status: ::std::string::String,
@@ -172812,7 +172812,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(tag = "type")]
     pub enum SubmitUserVerificationFromUrlBody {
         #[serde(rename = "identity_document")]
@@ -173478,7 +173478,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SubmitUserVerificationFromUrlBodyMetadata {
         pub document_number: ::std::string::String,
         pub document_type: SubmitUserVerificationFromUrlBodyMetadataDocumentType,
@@ -173805,7 +173805,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SubmitUserVerificationFromUrlBodyMetadataAddress {
         pub city: ::std::string::String,
         pub country: SubmitUserVerificationFromUrlBodyMetadataAddressCountry,
@@ -176744,7 +176744,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SubmitUserVerificationFromUrlBodyMetadataIdentity {
         pub date_of_birth: ::chrono::naive::NaiveDate,
         pub full_name: SubmitUserVerificationFromUrlBodyMetadataIdentityFullName,
@@ -176783,7 +176783,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SubmitUserVerificationFromUrlBodyMetadataIdentityFullName {
         pub first_name: ::std::string::String,
         pub last_name: ::std::string::String,
@@ -179614,7 +179614,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SubmitUserVerificationFromUrlBodyMetadataNegativeNews {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub expiration_date: ::std::option::Option<::chrono::naive::NaiveDate>,
@@ -179760,7 +179760,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SubmitUserVerificationFromUrlBodyMetadataPep {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub expiration_date: ::std::option::Option<::chrono::naive::NaiveDate>,
@@ -179906,7 +179906,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SubmitUserVerificationFromUrlBodyMetadataSanctions {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub expiration_date: ::std::option::Option<::chrono::naive::NaiveDate>,
@@ -180266,7 +180266,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SubmitUserVerificationFromUrlResponse {
         pub data: SubmitUserVerificationFromUrlResponseData,
     }
@@ -180305,7 +180305,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SubmitUserVerificationFromUrlResponseData {
         pub result: SubmitUserVerificationFromUrlResponseDataResult,
     }
@@ -180336,7 +180336,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SubmitUserVerificationFromUrlResponseDataResult {
         pub verification_id: ::std::string::String,
     }
@@ -180370,7 +180370,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SuccessResponse {
         pub success: bool,
     }
@@ -180529,7 +180529,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SuiTransactionCommandCondition {
         pub field: SuiTransactionCommandConditionField,
         pub field_source: SuiTransactionCommandConditionFieldSource,
@@ -180727,7 +180727,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum SuiTransactionCommandConditionValue {
         SuiCommandName(SuiCommandName),
@@ -180888,7 +180888,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SuiTransferObjectsCommandCondition {
         pub field: SuiTransferObjectsCommandField,
         pub field_source: SuiTransferObjectsCommandConditionFieldSource,
@@ -181126,7 +181126,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SvmTransactionWalletActionStep {
         ///CAIP-2 chain identifier for the Solana network.
         pub caip2: ::std::string::String,
@@ -181488,7 +181488,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SwapActionResponse {
         ///CAIP-2 chain identifier for the swap.
         pub caip2: ::std::string::String,
@@ -181656,7 +181656,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SwapDestination {
         ///Token contract address to buy, or "native" for the chain's native
         /// token.
@@ -181713,7 +181713,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SwapQuoteDestination {
         ///Token contract address to buy, or "native" for the chain's native
         /// token.
@@ -181813,7 +181813,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SwapQuoteRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub amount_type: ::std::option::Option<AmountType>,
@@ -182023,7 +182023,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SwapQuoteResponse {
         ///Chain identifier.
         pub caip2: ::std::string::String,
@@ -182138,7 +182138,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SwapRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub amount_type: ::std::option::Option<AmountType>,
@@ -182283,7 +182283,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SwapSource {
         ///Token contract address to sell, or "native" for the chain's native
         /// token.
@@ -182338,7 +182338,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct SystemCondition {
         pub field: SystemConditionField,
         pub field_source: SystemConditionFieldSource,
@@ -182543,7 +182543,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TelegramAuthConfigSchema {
         pub bot_id: ::std::string::String,
         pub bot_name: ::std::string::String,
@@ -182599,7 +182599,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TelegramAuthResult {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub auth_date: ::std::option::Option<f64>,
@@ -182648,7 +182648,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TelegramAuthenticateInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub captcha_token: ::std::option::Option<::std::string::String>,
@@ -182704,7 +182704,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TelegramAuthenticateRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub captcha_token: ::std::option::Option<::std::string::String>,
@@ -182760,7 +182760,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TelegramLinkRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub captcha_token: ::std::option::Option<::std::string::String>,
@@ -182810,7 +182810,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TelegramUnlinkInput {
         pub telegram_user_id: ::std::string::String,
     }
@@ -182842,7 +182842,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TelegramUnlinkRequestBody {
         pub telegram_user_id: ::std::string::String,
     }
@@ -182898,7 +182898,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TelegramWebAppData {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub auth_date: ::std::option::Option<f64>,
@@ -182957,7 +182957,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TempoAaAuthorization {
         pub chain_id: Quantity,
         pub contract: ::std::string::String,
@@ -182998,7 +182998,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TempoCall {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub data: ::std::option::Option<Hex>,
@@ -183056,7 +183056,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TempoFeePayerSignature {
         pub r: Hex,
         pub s: Hex,
@@ -183092,7 +183092,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum TempoFeePayerSignatureYParity {
         Variant0(TempoFeePayerSignatureYParityVariant0),
@@ -183130,7 +183130,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct TempoFeePayerSignatureYParityVariant0(f64);
     impl ::std::ops::Deref for TempoFeePayerSignatureYParityVariant0 {
@@ -183188,7 +183188,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct TempoFeePayerSignatureYParityVariant1(f64);
     impl ::std::ops::Deref for TempoFeePayerSignatureYParityVariant1 {
@@ -183271,7 +183271,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TempoTransactionCondition {
         pub field: TempoTransactionConditionField,
         pub field_source: TempoTransactionConditionFieldSource,
@@ -183509,7 +183509,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TestAccount {
         pub created_at: ::std::string::String,
         pub email: ::std::string::String,
@@ -183549,7 +183549,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TestAccountsResponse {
         pub data: ::std::vec::Vec<TestAccount>,
     }
@@ -183603,7 +183603,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TokenOutput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub id: ::std::option::Option<::std::string::String>,
@@ -183677,7 +183677,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TokenTransferDestination {
         ///Recipient address (hex for EVM, base58 for Solana, base58check for
         /// Tron)
@@ -183720,7 +183720,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum TokenTransferDestinationAddress {
         Variant0(::std::string::String),
@@ -183780,7 +183780,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum TokenTransferSource {
         NamedTokenTransferSource(NamedTokenTransferSource),
@@ -183843,7 +183843,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TotalFeeConfigurationBps {
         ///Discriminator: total fee specified in BPS.
         #[serde(rename = "type")]
@@ -183966,7 +183966,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TotpMfaMethod {
         #[serde(rename = "type")]
         pub type_: TotpMfaMethodType,
@@ -184128,7 +184128,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct Transaction {
         pub caip2: ::std::string::String,
         pub created_at: f64,
@@ -184204,7 +184204,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionBroadcastedWebhookPayload {
         ///The CAIP-2 chain identifier (e.g., eip155:1 for Ethereum mainnet).
         pub caip2: ::std::string::String,
@@ -184363,7 +184363,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionConfirmedWebhookPayload {
         ///The CAIP-2 chain identifier (e.g., eip155:1 for Ethereum mainnet).
         pub caip2: ::std::string::String,
@@ -184491,7 +184491,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum TransactionDetail {
         SentTransactionDetail(TransferSentTransactionDetail),
@@ -184570,7 +184570,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionExecutionRevertedWebhookPayload {
         ///The CAIP-2 chain identifier (e.g., eip155:1 for Ethereum mainnet).
         pub caip2: ::std::string::String,
@@ -184733,7 +184733,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionFailedWebhookPayload {
         ///The CAIP-2 chain identifier (e.g., eip155:1 for Ethereum mainnet).
         pub caip2: ::std::string::String,
@@ -184862,7 +184862,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionList {
         pub transactions: ::std::vec::Vec<Transaction>,
     }
@@ -184927,7 +184927,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionProviderErrorWebhookPayload {
         ///The CAIP-2 chain identifier (e.g., eip155:1 for Ethereum mainnet).
         pub caip2: ::std::string::String,
@@ -185088,7 +185088,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionReplacedWebhookPayload {
         ///The CAIP-2 chain identifier (e.g., eip155:1 for Ethereum mainnet).
         pub caip2: ::std::string::String,
@@ -185231,7 +185231,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningAssetDiff {
         pub asset: TransactionScanningAssetInfo,
         #[serde(rename = "in")]
@@ -185276,7 +185276,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningAssetInfo {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub decimals: ::std::option::Option<f64>,
@@ -185333,7 +185333,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningAssetValue {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub usd_price: ::std::option::Option<::std::string::String>,
@@ -185383,7 +185383,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningCalldata {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub function_declaration: ::std::option::Option<::std::string::String>,
@@ -185426,7 +185426,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningExposure {
         pub asset: TransactionScanningAssetInfo,
         pub spenders:
@@ -185461,7 +185461,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningMetadata {
         pub domain: ::std::string::String,
     }
@@ -185514,7 +185514,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningParams {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub block_tag: ::std::option::Option<::std::string::String>,
@@ -185587,7 +185587,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningRequestBody {
         pub chain_id: ::std::string::String,
         pub metadata: TransactionScanningMetadata,
@@ -185625,7 +185625,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningResponseBody {
         pub simulation: TransactionScanningSimulationResult,
         pub validation: TransactionScanningValidationResult,
@@ -185665,7 +185665,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningRpcRequest {
         pub method: ::std::string::String,
         pub params: ::std::vec::Vec<::serde_json::Value>,
@@ -185706,7 +185706,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningSimulationErrorResult {
         pub error: ::std::string::String,
         pub status: TransactionScanningSimulationErrorResultStatus,
@@ -185825,7 +185825,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum TransactionScanningSimulationResult {
         ErrorResult(TransactionScanningSimulationErrorResult),
@@ -185897,7 +185897,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningSimulationSuccessResult {
         pub assets_diffs: ::std::vec::Vec<TransactionScanningAssetDiff>,
         pub exposures: ::std::vec::Vec<TransactionScanningExposure>,
@@ -186025,7 +186025,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningValidationErrorResult {
         pub error: ::std::string::String,
         pub status: TransactionScanningValidationErrorResultStatus,
@@ -186144,7 +186144,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum TransactionScanningValidationResult {
         ErrorResult(TransactionScanningValidationErrorResult),
@@ -186203,7 +186203,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionScanningValidationSuccessResult {
         pub result_type: ::std::string::String,
         pub status: TransactionScanningValidationSuccessResultStatus,
@@ -186480,7 +186480,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransactionStillPendingWebhookPayload {
         ///The CAIP-2 chain identifier (e.g., eip155:1 for Ethereum mainnet).
         pub caip2: ::std::string::String,
@@ -186817,7 +186817,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferActionResponse {
         ///ISO 8601 timestamp of when the wallet action was created.
         pub created_at: ::chrono::DateTime<::chrono::offset::Utc>,
@@ -186998,7 +186998,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferFarcasterInput {
         pub farcaster_embedded_address: ::std::string::String,
         pub farcaster_id: ::std::string::String,
@@ -187047,7 +187047,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferIntentRequestDetails {
         pub body: TransferRequestBody,
         pub method: TransferIntentRequestDetailsMethod,
@@ -187316,7 +187316,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferIntentResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub action_result: ::std::option::Option<BaseActionResult>,
@@ -187470,7 +187470,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferIntentResponseRequestDetails {
         pub body: TransferRequestBody,
         pub method: TransferIntentResponseRequestDetailsMethod,
@@ -187629,7 +187629,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferQuoteRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub amount_type: ::std::option::Option<AmountType>,
@@ -187745,7 +187745,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferQuoteResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub amount_type: ::std::option::Option<AmountType>,
@@ -187860,7 +187860,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferReceivedTransactionDetail {
         pub asset: TransferReceivedTransactionDetailAsset,
         pub chain: TransferReceivedTransactionDetailChain,
@@ -187906,7 +187906,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum TransferReceivedTransactionDetailAsset {
         WalletEthereumAsset(WalletEthereumAsset),
@@ -188251,7 +188251,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub amount_type: ::std::option::Option<AmountType>,
@@ -188364,7 +188364,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferSentTransactionDetail {
         pub asset: TransferSentTransactionDetailAsset,
         pub chain: TransferSentTransactionDetailChain,
@@ -188408,7 +188408,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum TransferSentTransactionDetailAsset {
         WalletEthereumAsset(WalletEthereumAsset),
@@ -188729,7 +188729,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferSiweInput {
         pub address: ::std::string::String,
         #[serde(
@@ -189037,7 +189037,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferSiwsInput {
         pub address: ::std::string::String,
         #[serde(
@@ -189252,7 +189252,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TransferTelegramInput {
         pub nonce: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -189487,7 +189487,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TronCalldataCondition {
         pub abi: AbiSchema,
         pub field: ::std::string::String,
@@ -189632,7 +189632,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TronTransactionCondition {
         ///Supported TRON transaction fields in format
         /// "TransactionType.field_name"
@@ -189860,7 +189860,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct TypedDataDomainInputParams(
         pub ::serde_json::Map<::std::string::String, ::serde_json::Value>,
@@ -189920,7 +189920,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct TypedDataTypeFieldInput {
         pub name: ::std::string::String,
         #[serde(rename = "type")]
@@ -189952,7 +189952,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct TypedDataTypesInputParams(
         pub  ::std::collections::HashMap<
@@ -190035,7 +190035,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UnlinkPasskeyInput {
         pub credential_id: ::std::string::String,
         #[serde(default = "defaults::default_bool::<true>")]
@@ -190073,7 +190073,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UnlinkUserLinkedAccountBody {
         pub handle: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -190111,7 +190111,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum UnsignedEthereumTransaction {
         StandardEthereumTransaction(UnsignedStandardEthereumTransaction),
@@ -190218,7 +190218,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UnsignedStandardEthereumTransaction {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub authorization_list: ::std::vec::Vec<EthereumSign7702Authorization>,
@@ -190312,7 +190312,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum UnsignedStandardEthereumTransactionType {
         Variant0(UnsignedStandardEthereumTransactionTypeVariant0),
@@ -190372,7 +190372,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct UnsignedStandardEthereumTransactionTypeVariant0(f64);
     impl ::std::ops::Deref for UnsignedStandardEthereumTransactionTypeVariant0 {
@@ -190430,7 +190430,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct UnsignedStandardEthereumTransactionTypeVariant1(f64);
     impl ::std::ops::Deref for UnsignedStandardEthereumTransactionTypeVariant1 {
@@ -190488,7 +190488,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct UnsignedStandardEthereumTransactionTypeVariant2(f64);
     impl ::std::ops::Deref for UnsignedStandardEthereumTransactionTypeVariant2 {
@@ -190546,7 +190546,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct UnsignedStandardEthereumTransactionTypeVariant3(f64);
     impl ::std::ops::Deref for UnsignedStandardEthereumTransactionTypeVariant3 {
@@ -190685,7 +190685,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UnsignedTempoTransaction {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub aa_authorization_list: ::std::vec::Vec<TempoAaAuthorization>,
@@ -190749,7 +190749,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UnsignedTempoTransactionAccessListItem {
         pub address: ::std::string::String,
         pub storage_keys: ::std::vec::Vec<Hex>,
@@ -190776,7 +190776,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct UnsignedTempoTransactionType(f64);
     impl ::std::ops::Deref for UnsignedTempoTransactionType {
@@ -190927,7 +190927,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateAccountFromWalletIdsInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub display_name: ::std::option::Option<AccountDisplayName>,
@@ -190964,7 +190964,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateAccountFromWalletsConfigurationInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub display_name: ::std::option::Option<AccountDisplayName>,
@@ -191010,7 +191010,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum UpdateAccountInput {
         WalletsConfigurationInput(UpdateAccountFromWalletsConfigurationInput),
@@ -191251,7 +191251,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateConditionSetRequestBody {
         ///Name to assign to condition set.
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -191483,7 +191483,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateOrganizationSecretSigningKeyInput {
         ///The organization secret ID.
         pub secret_id: UpdateOrganizationSecretSigningKeyInputSecretId,
@@ -191621,7 +191621,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdatePolicyBody {
         ///Name to assign to policy.
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -192017,7 +192017,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserCustomMetadataBody {
         pub custom_metadata: CustomMetadata,
     }
@@ -192682,7 +192682,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(tag = "provider", content = "data")]
     pub enum UpdateUserFiatKycStatusBody {
         #[serde(rename = "bridge")]
@@ -192945,7 +192945,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserFiatKycStatusBodyBridgeDocumentsItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub description:
@@ -193357,7 +193357,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserFiatKycStatusBodyBridgeIdentifyingInformationItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub description: ::std::option::Option<::std::string::String>,
@@ -193520,7 +193520,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserFiatKycStatusBodyBridgeKycScreen {
         pub result: UpdateUserFiatKycStatusBodyBridgeKycScreenResult,
         pub screened_at: UpdateUserFiatKycStatusBodyBridgeKycScreenScreenedAt,
@@ -194005,7 +194005,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserFiatKycStatusBodyBridgeOfacScreen {
         pub result: UpdateUserFiatKycStatusBodyBridgeOfacScreenResult,
         pub screened_at: UpdateUserFiatKycStatusBodyBridgeOfacScreenScreenedAt,
@@ -194326,7 +194326,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserFiatKycStatusBodyBridgeResidentialAddress {
         pub city: UpdateUserFiatKycStatusBodyBridgeResidentialAddressCity,
         pub country: UpdateUserFiatKycStatusBodyBridgeResidentialAddressCountry,
@@ -195037,7 +195037,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserFiatKycStatusBodyBridgeSandboxDocumentsItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub description:
@@ -195466,7 +195466,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserFiatKycStatusBodyBridgeSandboxIdentifyingInformationItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub description: ::std::option::Option<::std::string::String>,
@@ -195631,7 +195631,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserFiatKycStatusBodyBridgeSandboxKycScreen {
         pub result: UpdateUserFiatKycStatusBodyBridgeSandboxKycScreenResult,
         pub screened_at: UpdateUserFiatKycStatusBodyBridgeSandboxKycScreenScreenedAt,
@@ -196128,7 +196128,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserFiatKycStatusBodyBridgeSandboxOfacScreen {
         pub result: UpdateUserFiatKycStatusBodyBridgeSandboxOfacScreenResult,
         pub screened_at: UpdateUserFiatKycStatusBodyBridgeSandboxOfacScreenScreenedAt,
@@ -196457,7 +196457,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserFiatKycStatusBodyBridgeSandboxResidentialAddress {
         pub city: UpdateUserFiatKycStatusBodyBridgeSandboxResidentialAddressCity,
         pub country: UpdateUserFiatKycStatusBodyBridgeSandboxResidentialAddressCountry,
@@ -197512,7 +197512,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserFiatKycStatusBodyBridgeSandboxTransliteratedResidentialAddress {
         pub city: UpdateUserFiatKycStatusBodyBridgeSandboxTransliteratedResidentialAddressCity,
         pub country:
@@ -198692,7 +198692,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserFiatKycStatusBodyBridgeTransliteratedResidentialAddress {
         pub city: UpdateUserFiatKycStatusBodyBridgeTransliteratedResidentialAddressCity,
         pub country: UpdateUserFiatKycStatusBodyBridgeTransliteratedResidentialAddressCountry,
@@ -199458,7 +199458,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserInstagramUsernameBody {
         pub username: ::std::string::String,
     }
@@ -199487,7 +199487,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserSpotifySubjectBody {
         pub subject: ::std::string::String,
     }
@@ -199516,7 +199516,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UpdateUserTwitchUsernameBody {
         pub username: ::std::string::String,
     }
@@ -199635,7 +199635,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct User {
         pub created_at: f64,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -199688,7 +199688,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserAuthenticatedWebhookPayload {
         pub account: LinkedAccount,
         ///The type of webhook event.
@@ -199852,7 +199852,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserBatchCreateInput {
         pub users: ::std::vec::Vec<UserBatchCreateInputUsersItem>,
     }
@@ -199917,7 +199917,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserBatchCreateInputUsersItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub create_embedded_wallet: ::std::option::Option<bool>,
@@ -199974,7 +199974,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserCreatedWebhookPayload {
         ///The type of webhook event.
         #[serde(rename = "type")]
@@ -200090,7 +200090,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserFiatStatusesBody {
         pub provider: OnrampProvider,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -200347,7 +200347,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserFiatStatusesResponse {
         pub transactions: ::std::vec::Vec<UserFiatStatusesResponseTransactionsItem>,
     }
@@ -200508,7 +200508,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(tag = "type")]
     pub enum UserFiatStatusesResponseTransactionsItem {
         #[serde(rename = "onramp")]
@@ -200570,7 +200570,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserFiatStatusesResponseTransactionsItemDestination {
         pub address: ::std::string::String,
         pub chain: ::std::string::String,
@@ -200608,7 +200608,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserFiatStatusesResponseTransactionsItemReceipt {
         pub final_amount: ::std::string::String,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -200650,7 +200650,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum UserInviteInput {
         EmailInviteInput(EmailInviteInput),
@@ -200722,7 +200722,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserLinkedAccountWebhookPayload {
         pub account: LinkedAccount,
         ///The type of webhook event.
@@ -200887,7 +200887,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserOperationCompletedWebhookPayload {
         pub actual_gas_cost: ::std::string::String,
         pub actual_gas_used: ::std::string::String,
@@ -201055,7 +201055,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserOperationInput {
         pub call_data: Hex,
         pub call_gas_limit: Hex,
@@ -201191,7 +201191,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct UserSigningKeyBinding(pub IntentBinding);
     impl ::std::ops::Deref for UserSigningKeyBinding {
@@ -201273,7 +201273,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserTransferredAccountWebhookPayload {
         pub account: LinkedAccount,
         #[serde(rename = "deletedUser")]
@@ -201313,7 +201313,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserTransferredAccountWebhookPayloadFromUser {
         pub id: ::std::string::String,
     }
@@ -201439,7 +201439,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserUnlinkedAccountWebhookPayload {
         pub account: LinkedAccount,
         ///The type of webhook event.
@@ -201569,7 +201569,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserUpdatedAccountWebhookPayload {
         pub account: LinkedAccount,
         ///The type of webhook event.
@@ -201697,7 +201697,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserWalletCreatedWebhookPayload {
         ///The type of webhook event.
         #[serde(rename = "type")]
@@ -201817,7 +201817,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct UserWithIdentityToken {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub identity_token: ::std::option::Option<UserWithIdentityTokenIdentityToken>,
@@ -202016,7 +202016,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct Wallet {
         pub additional_signers: WalletAdditionalSigner,
         ///Address of the wallet.
@@ -202150,7 +202150,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionEarnDepositCreatedWebhookPayload {
         pub action_type: WalletActionType,
         ///Human-readable decimal amount of asset deposited (e.g. "1.5"). Only
@@ -202470,7 +202470,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionEarnDepositFailedWebhookPayload {
         pub action_type: WalletActionType,
         ///Human-readable decimal amount of asset deposited (e.g. "1.5"). Only
@@ -202793,7 +202793,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionEarnDepositRejectedWebhookPayload {
         pub action_type: WalletActionType,
         ///Human-readable decimal amount of asset deposited (e.g. "1.5"). Only
@@ -203116,7 +203116,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionEarnDepositSucceededWebhookPayload {
         pub action_type: WalletActionType,
         ///Human-readable decimal amount of asset deposited (e.g. "1.5"). Only
@@ -203399,7 +203399,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionEarnIncentiveClaimCreatedWebhookPayload {
         pub action_type: WalletActionType,
         ///EVM chain name (e.g. "base", "ethereum").
@@ -203675,7 +203675,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionEarnIncentiveClaimFailedWebhookPayload {
         pub action_type: WalletActionType,
         ///EVM chain name (e.g. "base", "ethereum").
@@ -203955,7 +203955,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionEarnIncentiveClaimRejectedWebhookPayload {
         pub action_type: WalletActionType,
         ///EVM chain name (e.g. "base", "ethereum").
@@ -204230,7 +204230,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionEarnIncentiveClaimSucceededWebhookPayload {
         pub action_type: WalletActionType,
         ///EVM chain name (e.g. "base", "ethereum").
@@ -204524,7 +204524,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionEarnWithdrawCreatedWebhookPayload {
         pub action_type: WalletActionType,
         ///Human-readable decimal amount of asset withdrawn (e.g. "1.5"). Only
@@ -204844,7 +204844,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionEarnWithdrawFailedWebhookPayload {
         pub action_type: WalletActionType,
         ///Human-readable decimal amount of asset withdrawn (e.g. "1.5"). Only
@@ -205167,7 +205167,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionEarnWithdrawRejectedWebhookPayload {
         pub action_type: WalletActionType,
         ///Human-readable decimal amount of asset withdrawn (e.g. "1.5"). Only
@@ -205492,7 +205492,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionEarnWithdrawSucceededWebhookPayload {
         pub action_type: WalletActionType,
         ///Human-readable decimal amount of asset withdrawn (e.g. "1.5"). Only
@@ -205741,7 +205741,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletActionResponse {
         SwapActionResponse(SwapActionResponse),
@@ -205911,7 +205911,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletActionStep {
         EvmTransactionWalletActionStep(EvmTransactionWalletActionStep),
@@ -206116,7 +206116,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionSwapCreatedWebhookPayload {
         pub action_type: WalletActionType,
         ///Chain identifier.
@@ -206395,7 +206395,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionSwapFailedWebhookPayload {
         pub action_type: WalletActionType,
         ///Chain identifier.
@@ -206675,7 +206675,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionSwapRejectedWebhookPayload {
         pub action_type: WalletActionType,
         ///Chain identifier.
@@ -206959,7 +206959,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionSwapSucceededWebhookPayload {
         pub action_type: WalletActionType,
         ///Chain identifier.
@@ -207248,7 +207248,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionTransferCreatedWebhookPayload {
         pub action_type: WalletActionType,
         ///Recipient address.
@@ -207557,7 +207557,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionTransferFailedWebhookPayload {
         pub action_type: WalletActionType,
         ///Recipient address.
@@ -207869,7 +207869,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionTransferRejectedWebhookPayload {
         pub action_type: WalletActionType,
         ///Recipient address.
@@ -208178,7 +208178,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletActionTransferSucceededWebhookPayload {
         pub action_type: WalletActionType,
         ///Recipient address.
@@ -208512,7 +208512,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(transparent)]
     pub struct WalletAdditionalSigner(pub ::std::vec::Vec<WalletAdditionalSignerItem>);
     impl ::std::ops::Deref for WalletAdditionalSigner {
@@ -208566,7 +208566,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletAdditionalSignerItem {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub override_policy_ids: ::std::option::Option<PolicyInput>,
@@ -208609,7 +208609,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletApiRegisterAuthorizationKeyInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub display_name: ::std::option::Option<::std::string::String>,
@@ -208648,7 +208648,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletApiRevokeAuthorizationKeyInput {
         pub id: ::std::string::String,
     }
@@ -208681,7 +208681,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
     #[serde(untagged)]
     pub enum WalletAsset {
         EthereumAsset(WalletEthereumAsset),
@@ -208798,7 +208798,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletAuthenticateBoundEncryptedRequestBody {
         ///Bindings that scope the USK. The key can only authorize the bound
         /// values.
@@ -208925,7 +208925,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletAuthenticateBoundRequestBody {
         EncryptedRequestBody(WalletAuthenticateBoundEncryptedRequestBody),
@@ -208988,7 +208988,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletAuthenticateBoundUnencryptedRequestBody {
         ///Bindings that scope the USK. The key can only authorize the bound
         /// values.
@@ -209028,7 +209028,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletAuthenticateIntentsResponse {
         EncryptedBoundAuthenticateResponse(EncryptedBoundAuthenticateResponse),
@@ -209095,7 +209095,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletAuthenticateRequestBody {
         ///The encryption type for the authentication response. Currently only
         /// supports HPKE.
@@ -209341,7 +209341,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletAuthenticateWithJwtResponse {
         WithEncryption {
@@ -209396,7 +209396,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletAuthorizationHeaders {
         ///ID of your Privy app.
         #[serde(rename = "privy-app-id")]
@@ -209465,7 +209465,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletBatchCreateInput {
         ///Array of wallet creation requests. Minimum 1, maximum 100.
         pub wallets: ::std::vec::Vec<WalletBatchItemInput>,
@@ -209532,7 +209532,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletBatchCreateResponse {
         ///Array of results for each wallet creation request, in the same order
         /// as input.
@@ -209622,7 +209622,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletBatchCreateResult {
         Success {
@@ -209709,7 +209709,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletBatchItemInput {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub additional_signers: ::std::option::Option<AdditionalSignerInput>,
@@ -210183,7 +210183,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletCreateWalletsWithRecoveryResponse {
         ///The ID of the created user.
         pub recovery_user_id: ::std::string::String,
@@ -210238,7 +210238,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletCreationAdditionalSignerItem {
         ///The array of policy IDs that will be applied to wallet requests. If
         /// specified, this will override the base policy IDs set on the wallet.
@@ -210395,7 +210395,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletCreationInput {
         ///Additional signers for the wallet.
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
@@ -210530,7 +210530,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletCustodian {
         ///The custodian responsible for the wallet.
         pub provider: ::std::string::String,
@@ -210786,7 +210786,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletExportRequestBody {
         pub encryption_type: HpkeEncryption,
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
@@ -210845,7 +210845,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletExportResponseBody {
         ///The encrypted private key.
         pub ciphertext: ::std::string::String,
@@ -210887,7 +210887,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletFundsAsset {
         NativeTokenAsset(WalletFundsNativeTokenAsset),
@@ -210954,7 +210954,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletFundsErc20Asset {
         pub address: ::std::string::String,
         #[serde(rename = "type")]
@@ -211074,7 +211074,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletFundsNativeTokenAsset {
         pub address: ::std::string::String,
         #[serde(rename = "type")]
@@ -211194,7 +211194,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletFundsSacAsset {
         pub address: ::std::string::String,
         #[serde(rename = "type")]
@@ -211314,7 +211314,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletFundsSplAsset {
         pub mint: ::std::string::String,
         #[serde(rename = "type")]
@@ -211431,7 +211431,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletImportInitBody {
         HdInitInput(HdInitInput),
@@ -211486,7 +211486,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletImportInitResponse {
         pub encryption_public_key: ::std::string::String,
         pub encryption_type: HpkeEncryption,
@@ -211533,7 +211533,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletImportInitializationResponse {
         ///The base64-encoded encryption public key to encrypt the wallet
         /// entropy with.
@@ -211625,7 +211625,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletImportSubmissionRequest {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub additional_signers: ::std::option::Option<AdditionalSignerInput>,
@@ -211847,7 +211847,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletImportSubmissionRequestWallet {
         HdSubmitInput(HdSubmitInput),
@@ -212178,7 +212178,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletIntentResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub action_result: ::std::option::Option<BaseActionResult>,
@@ -212359,7 +212359,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletIntentResponseRequestDetails {
         pub body: WalletIntentResponseRequestDetailsBody,
         pub method: WalletIntentResponseRequestDetailsMethod,
@@ -212411,7 +212411,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletIntentResponseRequestDetailsBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub additional_signers: ::std::option::Option<AdditionalSignerInput>,
@@ -212654,7 +212654,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletInviteInput {
         #[serde(rename = "type")]
         pub type_: WalletInviteInputType,
@@ -212763,7 +212763,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletInviteInputValue {
         Variant0(::std::string::String),
@@ -212825,7 +212825,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletRecoveredWebhookPayload {
         ///The type of webhook event.
         #[serde(rename = "type")]
@@ -212977,7 +212977,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletRecoverySetupWebhookPayload {
         ///The recovery method that was set up.
         pub method: WalletRecoverySetupWebhookPayloadMethod,
@@ -213210,7 +213210,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletRevokeResponse {
         pub message: ::std::string::String,
     }
@@ -213305,7 +213305,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletRpcRequestBody {
         EthereumSignTransactionRpcInput(EthereumSignTransactionRpcInput),
@@ -213578,7 +213578,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletRpcResponse {
         EthereumPersonalSignRpcResponse(EthereumPersonalSignRpcResponse),
@@ -213867,7 +213867,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, Eq, Hash, PartialEq)]
     #[serde(untagged)]
     pub enum WalletTransactionsAsset {
         WalletEthereumAsset(WalletEthereumAsset),
@@ -214189,7 +214189,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletTransactionsResponse {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub next_cursor: ::std::option::Option<::std::string::String>,
@@ -214278,7 +214278,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletTransactionsResponseTransactionsItem {
         pub caip2: ::std::string::String,
         pub created_at: f64,
@@ -214330,7 +214330,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletTransactionsResponseTransactionsItemDetails {
         Variant0 {
@@ -214457,7 +214457,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletTransactionsResponseTransactionsItemDetailsVariant0Asset {
         Variant0(WalletTransactionsResponseTransactionsItemDetailsVariant0AssetVariant0),
@@ -214553,7 +214553,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletTransactionsResponseTransactionsItemDetailsVariant0AssetVariant0 {
         WalletEthereumAsset(WalletEthereumAsset),
@@ -214812,7 +214812,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletTransactionsResponseTransactionsItemDetailsVariant0AssetVariant1 {
         WalletSolanaAsset(WalletSolanaAsset),
@@ -215034,7 +215034,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletTransactionsResponseTransactionsItemDetailsVariant0AssetVariant2 {
         WalletEthereumAsset(WalletEthereumAsset),
@@ -215426,7 +215426,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletTransactionsResponseTransactionsItemDetailsVariant1Asset {
         Variant0(WalletTransactionsResponseTransactionsItemDetailsVariant1AssetVariant0),
@@ -215522,7 +215522,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletTransactionsResponseTransactionsItemDetailsVariant1AssetVariant0 {
         WalletEthereumAsset(WalletEthereumAsset),
@@ -215781,7 +215781,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletTransactionsResponseTransactionsItemDetailsVariant1AssetVariant1 {
         WalletSolanaAsset(WalletSolanaAsset),
@@ -216003,7 +216003,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletTransactionsResponseTransactionsItemDetailsVariant1AssetVariant2 {
         WalletEthereumAsset(WalletEthereumAsset),
@@ -216453,7 +216453,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WalletTransactionsToken {
         TransactionTokenAddressInput(TransactionTokenAddressInput),
@@ -216539,7 +216539,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WalletUpdateRequestBody {
         #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
         pub additional_signers: ::std::option::Option<AdditionalSignerInput>,
@@ -216939,7 +216939,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     #[serde(untagged)]
     pub enum WebhookPayload {
         UserCreatedWebhookPayload(UserCreatedWebhookPayload),
@@ -217363,7 +217363,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WithEncryptionEncryptedAuthorizationKey {
         ///The encrypted authorization key corresponding to the user's current
         /// authentication session.
@@ -217510,7 +217510,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WithdrawFundsBody {
         pub amount: WithdrawFundsBodyAmount,
         pub asset: WithdrawFundsBodyAsset,
@@ -217830,7 +217830,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WithdrawFundsResponse {
         pub data: WithdrawFundsResponseData,
     }
@@ -217872,7 +217872,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WithdrawFundsResponseData {
         #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
         pub error: ::std::vec::Vec<::serde_json::Value>,
@@ -217916,7 +217916,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct WithdrawFundsResponseDataResult {
         pub reference_id: ::std::string::String,
     }
@@ -217960,7 +217960,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct YieldAuthorizationHeaders {
         ///ID of your Privy app.
         #[serde(rename = "privy-app-id")]
@@ -218049,7 +218049,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct YieldClaimConfirmedWebhookPayload {
         pub caip2: ::std::string::String,
         pub rewards: ::std::vec::Vec<YieldClaimConfirmedWebhookPayloadRewardsItem>,
@@ -218094,7 +218094,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct YieldClaimConfirmedWebhookPayloadRewardsItem {
         pub amount: ::std::string::String,
         pub token_address: ::std::string::String,
@@ -218239,7 +218239,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct YieldDepositConfirmedWebhookPayload {
         pub assets: ::std::string::String,
         pub caip2: ::std::string::String,
@@ -218395,7 +218395,7 @@ pub mod types {
     ///}
     /// ```
     /// </details>
-    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug)]
+    #[derive(:: serde :: Deserialize, :: serde :: Serialize, Clone, Debug, PartialEq)]
     pub struct YieldWithdrawConfirmedWebhookPayload {
         pub assets: ::std::string::String,
         pub caip2: ::std::string::String,
@@ -218526,6 +218526,7 @@ pub mod types {
 pub struct Client {
     pub(crate) baseurl: String,
     pub(crate) client: reqwest::Client,
+    pub(crate) inner: RequestHook,
 }
 
 impl Client {
@@ -218534,7 +218535,7 @@ impl Client {
     /// `baseurl` is the base URL provided to the internal
     /// `reqwest::Client`, and should include a scheme and hostname,
     /// as well as port and a path stem if applicable.
-    pub fn new(baseurl: &str) -> Self {
+    pub fn new(baseurl: &str, inner: RequestHook) -> Self {
         #[cfg(not(target_arch = "wasm32"))]
         let client = {
             let dur = ::std::time::Duration::from_secs(15u64);
@@ -218544,7 +218545,7 @@ impl Client {
         };
         #[cfg(target_arch = "wasm32")]
         let client = reqwest::ClientBuilder::new();
-        Self::new_with_client(baseurl, client.build().unwrap())
+        Self::new_with_client(baseurl, client.build().unwrap(), inner)
     }
 
     /// Construct a new client with an existing `reqwest::Client`,
@@ -218553,15 +218554,16 @@ impl Client {
     /// `baseurl` is the base URL provided to the internal
     /// `reqwest::Client`, and should include a scheme and hostname,
     /// as well as port and a path stem if applicable.
-    pub fn new_with_client(baseurl: &str, client: reqwest::Client) -> Self {
+    pub fn new_with_client(baseurl: &str, client: reqwest::Client, inner: RequestHook) -> Self {
         Self {
             baseurl: baseurl.to_string(),
             client,
+            inner,
         }
     }
 }
 
-impl ClientInfo<()> for Client {
+impl ClientInfo<RequestHook> for Client {
     fn api_version() -> &'static str {
         "0.0.1"
     }
@@ -218574,12 +218576,12 @@ impl ClientInfo<()> for Client {
         &self.client
     }
 
-    fn inner(&self) -> &() {
-        &()
+    fn inner(&self) -> &RequestHook {
+        &self.inner
     }
 }
 
-impl ClientHooks<()> for &Client {}
+impl ClientHooks<RequestHook> for &Client {}
 #[allow(clippy::all)]
 impl Client {
     ///Get all wallets
@@ -218630,6 +218632,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_wallets",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -218674,6 +218680,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_wallet",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -218715,6 +218725,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_wallets_batch",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -218759,6 +218773,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_custodial_wallets",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -218821,6 +218839,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "wallet_rpc",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -218863,6 +218885,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_wallet",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -218920,6 +218946,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_wallet",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -218982,6 +219012,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "raw_sign",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219035,6 +219069,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_wallet_balance",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219089,6 +219127,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "wallet_transactions",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219130,6 +219172,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "authenticate",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219171,6 +219217,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "authenticate_intents",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219210,6 +219260,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "wallet_import_init",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219249,6 +219303,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "wallet_import_submit",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219306,6 +219364,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "export_wallet",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219346,6 +219408,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_wallet_by_address",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219387,6 +219453,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_users",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219427,6 +219497,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_user",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219469,6 +219543,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_user",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219503,6 +219581,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "delete_user",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219547,6 +219629,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_user_custom_metadata",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219593,6 +219679,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "patch_user_custom_metadata",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219632,6 +219722,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "look_up_user_by_email",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219671,6 +219765,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "look_up_user_by_custom_auth_id",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219710,6 +219808,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "look_up_user_by_wallet_address",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219749,6 +219851,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "look_up_user_by_farcaster_id",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219788,6 +219894,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "look_up_user_by_phone_number",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219827,6 +219937,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "look_up_user_by_smart_wallet_address",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219866,6 +219980,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "look_up_user_by_discord_username",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219905,6 +220023,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "look_up_user_by_github_username",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219944,6 +220066,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "look_up_user_by_twitter_username",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -219983,6 +220109,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "look_up_user_by_twitter_subject",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220022,6 +220152,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "look_up_user_by_telegram_user_id",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220061,6 +220195,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "look_up_user_by_telegram_username",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220100,6 +220238,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_user_instagram_username",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220139,6 +220281,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_user_twitch_username",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220178,6 +220324,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_user_spotify_subject",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220217,6 +220367,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "search_users",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220261,6 +220415,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_user_wallet",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220306,6 +220464,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "add_or_update_user_linked_account",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220350,6 +220512,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "unlink_user_linked_account",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220394,6 +220560,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_policy",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220436,6 +220606,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_policy",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220491,6 +220665,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "delete_policy",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220548,6 +220726,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_policy",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220605,6 +220787,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_rule",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220649,6 +220835,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_rule",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220706,6 +220896,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "delete_rule",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220765,6 +220959,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_rule",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220805,6 +221003,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_condition_set",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220847,6 +221049,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_condition_set",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220902,6 +221108,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "delete_condition_set",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -220959,6 +221169,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_condition_set",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221008,6 +221222,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_condition_set_items",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221067,6 +221285,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_condition_set_items",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221125,6 +221347,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_condition_set_items",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221171,6 +221397,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_condition_set_item",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221230,6 +221460,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "delete_condition_set_item",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221272,6 +221506,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_transaction",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221314,6 +221552,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_transaction_by_reference_id",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221356,6 +221598,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_key_quorum",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221411,6 +221657,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "delete_key_quorum",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221468,6 +221718,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_key_quorum",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221507,6 +221761,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_key_quorum",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221551,6 +221809,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_user_fiat_tos",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221596,6 +221858,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_user_fiat_kyc_status",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221641,6 +221907,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "initiate_user_fiat_kyc",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221686,6 +221956,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_user_fiat_kyc_status",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221730,6 +222004,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_user_fiat_kyc_link",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221775,6 +222053,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_user_fiat_accounts",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221820,6 +222102,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_user_fiat_account",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221864,6 +222150,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "initiate_user_fiat_offramp",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221909,6 +222199,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "initiate_user_fiat_onramp",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221954,6 +222248,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "configure_app_for_fiat_on_off_ramping",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -221998,6 +222296,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "user_fiat_statuses",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222069,6 +222371,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "list_intents",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222113,6 +222419,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_intent",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222164,6 +222474,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_rpc_intent",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222215,6 +222529,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_transfer_intent",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222265,6 +222583,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_wallet_update_intent",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222315,6 +222637,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_policy_update_intent",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222365,6 +222691,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_policy_rule_intent",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222416,6 +222746,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_policy_rule_delete_intent",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222469,6 +222803,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_policy_rule_update_intent",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222519,6 +222857,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_key_quorum_update_intent",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222564,6 +222906,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "authorize_intent",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222609,6 +222955,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_aggregation",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222651,6 +223001,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_aggregation",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222693,6 +223047,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "delete_aggregation",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222735,6 +223093,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_app",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222778,6 +223140,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "list_allowlist_entries",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222823,6 +223189,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_allowlist_entry",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222868,6 +223238,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "delete_allowlist_entry",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222910,6 +223284,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_test_credentials",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -222963,6 +223341,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_gas_spend",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223006,6 +223388,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "list_organization_secrets",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223049,6 +223435,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_organization_secret",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223094,6 +223484,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "revoke_organization_secret",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223139,6 +223533,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_organization_secret_signing_key",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223181,6 +223579,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "device_verify",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223222,6 +223624,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "request_quote",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223266,6 +223672,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_quote",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223311,6 +223721,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "execute_quote",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223354,6 +223768,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_kraken_user",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223399,6 +223817,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_kraken_user",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223447,6 +223869,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "submit_user_verification",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223496,6 +223922,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "submit_user_verification_from_url",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223572,6 +224002,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "list_assets",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223619,6 +224053,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_kraken_embed_assets",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223673,6 +224111,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "list_asset_rates",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223718,6 +224160,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_portfolio_details",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223768,6 +224214,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_portfolio_summary",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223815,6 +224265,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_master_account_portfolio_summary",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223855,6 +224309,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_master_account_portfolio_details",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223926,6 +224384,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_master_account_portfolio_history",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -223997,6 +224459,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_master_account_portfolio_transactions",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224040,6 +224506,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "initiate_master_account_withdrawal",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224116,6 +224586,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_protfolio_history_for_user",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224184,6 +224658,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_portfolio_transactions",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224224,6 +224702,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "withdraw_funds",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224272,6 +224754,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "start_verification_from_url",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224315,6 +224801,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "list_custom_orders",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224355,6 +224845,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_custom_order",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224399,6 +224893,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_custom_order",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224445,6 +224943,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "cancel_custom_order",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224490,6 +224992,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_custom_order_history",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224536,6 +225042,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_earn_summary",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224580,6 +225090,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "list_earn_assets",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224628,6 +225142,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "toggle_auto_earn",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224693,6 +225211,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "ethereum_yield_deposit",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224757,6 +225279,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "ethereum_yield_withdraw",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224802,6 +225328,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_ethereum_yield_position",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224845,6 +225375,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_ethereum_yield_vault",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224887,6 +225421,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_ethereum_yield_sweep",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224950,6 +225488,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "ethereum_yield_claim",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -224992,6 +225534,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_ethereum_yield_claim",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225035,6 +225581,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "list_accounts",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225079,6 +225629,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "create_account",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225121,6 +225675,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_account",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225166,6 +225724,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "update_account",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225214,6 +225776,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_account_balance",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225266,6 +225832,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "swap_quote",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225323,6 +225893,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "swap_tokens",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225376,6 +225950,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_wallet_action",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225438,6 +226016,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "transfer",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225497,6 +226079,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "transfer_quote",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225560,6 +226146,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "ethereum_earn_deposit",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225623,6 +226213,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "ethereum_earn_withdraw",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225669,6 +226263,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_ethereum_earn_incentive_rewards",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225732,6 +226330,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "ethereum_earn_incentive_claim",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225777,6 +226379,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_ethereum_earn_position",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225820,6 +226426,10 @@ impl Client {
         let info = OperationInfo {
             operation_id: "get_ethereum_earn_vault_details",
         };
+        match (RequestHook::run)(&self.inner, &mut request).await {
+            Ok(_) => (),
+            Err(e) => return Err(Error::Custom(e.to_string())),
+        }
         self.pre(&mut request, &info).await?;
         let result = self.exec(request, &info).await;
         self.post(&result, &info).await?;
@@ -225836,3 +226446,35 @@ pub mod prelude {
     #[allow(unused_imports)]
     pub use super::Client;
 }
+
+/// A hook the client runs on every request just before it is sent, which can
+/// change the request, or stop it from being sent by returning an error.
+/// `RequestHook::default()` leaves requests as they are.
+#[derive(Clone, Default)]
+pub struct RequestHook(Option<std::sync::Arc<RequestHookFn>>);
+
+type RequestHookFn = dyn Fn(&mut reqwest::Request) -> Result<(), String> + Send + Sync;
+
+impl RequestHook {
+    /// Run `hook` on every request.
+    pub fn new(
+        hook: impl Fn(&mut reqwest::Request) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        Self(Some(std::sync::Arc::new(hook)))
+    }
+
+    async fn run(&self, request: &mut reqwest::Request) -> Result<(), String> {
+        match &self.0 {
+            Some(hook) => hook(request),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Debug for RequestHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RequestHook")
+            .field(&self.0.is_some())
+            .finish()
+    }
+}
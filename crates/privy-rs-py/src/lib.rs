@@ -0,0 +1,323 @@
+//! Python bindings for the Privy SDK for Rust.
+//!
+//! These bindings expose the client, authorization contexts and the
+//! Ethereum and Solana services to Python, so scripts can reuse the same
+//! request canonicalization and signing as the Rust SDK. Calls block on a
+//! shared tokio runtime with the GIL released, and API responses are
+//! returned as plain Python objects.
+//!
+//! ```python
+//! from privy_rs import AuthorizationContext, PrivyClient
+//!
+//! client = PrivyClient.from_env()
+//! ctx = AuthorizationContext().push_private_key(open("private_key.pem").read())
+//! response = client.ethereum().sign_message("wallet_id", "hello", ctx)
+//! print(response["data"]["signature"])
+//! ```
+
+use std::{future::Future, sync::LazyLock};
+
+use pyo3::{create_exception, exceptions::PyException, prelude::*};
+
+/// The runtime that drives all requests. Python threads calling into the
+/// bindings release the GIL while they wait, so calls from several threads
+/// run concurrently.
+static RUNTIME: LazyLock<tokio::runtime::Runtime> = LazyLock::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("failed to start tokio runtime")
+});
+
+/// Run a future to completion on the shared runtime without holding the GIL.
+fn block_on<F>(py: Python<'_>, future: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    py.allow_threads(|| RUNTIME.block_on(future))
+}
+
+create_exception!(
+    privy_rs,
+    PrivyError,
+    PyException,
+    "Raised when a Privy API call or signing operation fails."
+);
+
+/// Convert an error into a `PrivyError`, including its sources in the
+/// message since Python has no equivalent of the source chain.
+fn to_py_err(error: impl std::error::Error) -> PyErr {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(e) = source {
+        message = format!("{message}: {e}");
+        source = e.source();
+    }
+    PrivyError::new_err(message)
+}
+
+/// Convert a serializable response into native Python objects by
+/// round-tripping through the `json` module.
+fn to_py_object<'py>(
+    py: Python<'py>,
+    value: &impl serde::Serialize,
+) -> PyResult<Bound<'py, PyAny>> {
+    let json = serde_json::to_string(value).map_err(to_py_err)?;
+    py.import("json")?.call_method1("loads", (json,))
+}
+
+/// Parse a request body passed from Python as a dict or list, by
+/// round-tripping through the `json` module.
+fn from_py_object<T: serde::de::DeserializeOwned>(value: &Bound<'_, PyAny>) -> PyResult<T> {
+    let json: String = value
+        .py()
+        .import("json")?
+        .call_method1("dumps", (value,))?
+        .extract()?;
+    serde_json::from_str(&json).map_err(to_py_err)
+}
+
+/// The client used to interact with the Privy API.
+#[pyclass(frozen)]
+#[derive(Clone)]
+struct PrivyClient(privy_rs::PrivyClient);
+
+#[pymethods]
+impl PrivyClient {
+    #[new]
+    fn new(app_id: String, app_secret: String) -> PyResult<Self> {
+        privy_rs::PrivyClient::new(app_id, app_secret)
+            .map(Self)
+            .map_err(to_py_err)
+    }
+
+    /// Create a client from the `PRIVY_APP_ID`, `PRIVY_APP_SECRET` and
+    /// (optionally) `PRIVY_BASE_URL` environment variables.
+    #[staticmethod]
+    fn from_env() -> PyResult<Self> {
+        privy_rs::PrivyClient::new_from_env()
+            .map(Self)
+            .map_err(to_py_err)
+    }
+
+    /// Fetch a wallet by id.
+    fn get_wallet<'py>(&self, py: Python<'py>, wallet_id: &str) -> PyResult<Bound<'py, PyAny>> {
+        let wallet = block_on(py, self.0.wallets().get(wallet_id)).map_err(to_py_err)?;
+        to_py_object(py, &wallet.into_inner())
+    }
+
+    /// Ethereum-specific wallet operations.
+    fn ethereum(&self) -> EthereumService {
+        EthereumService(self.0.clone())
+    }
+
+    /// Solana-specific wallet operations.
+    fn solana(&self) -> SolanaService {
+        SolanaService(self.0.clone())
+    }
+}
+
+/// The set of keys used to authorize requests on behalf of wallet owners.
+#[pyclass(frozen)]
+struct AuthorizationContext(privy_rs::AuthorizationContext);
+
+#[pymethods]
+impl AuthorizationContext {
+    #[new]
+    fn new() -> Self {
+        Self(privy_rs::AuthorizationContext::new())
+    }
+
+    /// Return a new context that also signs with the given PEM-encoded
+    /// P-256 private key.
+    fn push_private_key(&self, pem: String) -> Self {
+        Self(self.0.clone().push(privy_rs::PrivateKey::new(pem)))
+    }
+
+    /// Return a new context that also signs with the authorization key of
+    /// the user identified by the given JWT.
+    fn push_jwt(&self, client: &PrivyClient, jwt: String) -> Self {
+        Self(
+            self.0
+                .clone()
+                .push(privy_rs::JwtUser(client.0.clone(), jwt)),
+        )
+    }
+}
+
+/// Ethereum-specific wallet operations. Obtain one from `PrivyClient.ethereum()`.
+#[pyclass(frozen)]
+struct EthereumService(privy_rs::PrivyClient);
+
+#[pymethods]
+impl EthereumService {
+    /// Sign a UTF-8 message with `personal_sign`.
+    #[pyo3(signature = (wallet_id, message, ctx, idempotency_key=None))]
+    fn sign_message<'py>(
+        &self,
+        py: Python<'py>,
+        wallet_id: &str,
+        message: &str,
+        ctx: &AuthorizationContext,
+        idempotency_key: Option<&str>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let ethereum = self.0.wallets().ethereum();
+        let response = block_on(
+            py,
+            ethereum.sign_message(wallet_id, message, &ctx.0, idempotency_key),
+        )
+        .map_err(to_py_err)?;
+        to_py_object(py, &response.into_inner())
+    }
+
+    /// Sign and broadcast a transaction, given as a dict in the shape of the
+    /// API's `transaction` parameter.
+    #[pyo3(signature = (wallet_id, caip2, transaction, ctx, idempotency_key=None))]
+    fn send_transaction<'py>(
+        &self,
+        py: Python<'py>,
+        wallet_id: &str,
+        caip2: &str,
+        transaction: &Bound<'py, PyAny>,
+        ctx: &AuthorizationContext,
+        idempotency_key: Option<&str>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let transaction = from_py_object(transaction)?;
+        let ethereum = self.0.wallets().ethereum();
+        let response = block_on(
+            py,
+            ethereum.send_transaction(wallet_id, caip2, transaction, &ctx.0, idempotency_key),
+        )
+        .map_err(to_py_err)?;
+        to_py_object(py, &response.into_inner())
+    }
+}
+
+/// Solana-specific wallet operations. Obtain one from `PrivyClient.solana()`.
+#[pyclass(frozen)]
+struct SolanaService(privy_rs::PrivyClient);
+
+#[pymethods]
+impl SolanaService {
+    /// Sign a base64-encoded message.
+    #[pyo3(signature = (wallet_id, message, ctx, idempotency_key=None))]
+    fn sign_message<'py>(
+        &self,
+        py: Python<'py>,
+        wallet_id: &str,
+        message: &str,
+        ctx: &AuthorizationContext,
+        idempotency_key: Option<&str>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let solana = self.0.wallets().solana();
+        let response = block_on(
+            py,
+            solana.sign_message(wallet_id, message, &ctx.0, idempotency_key),
+        )
+        .map_err(to_py_err)?;
+        to_py_object(py, &response.into_inner())
+    }
+
+    /// Sign and broadcast a base64-encoded serialized transaction.
+    #[pyo3(signature = (wallet_id, caip2, transaction, ctx, idempotency_key=None))]
+    fn sign_and_send_transaction<'py>(
+        &self,
+        py: Python<'py>,
+        wallet_id: &str,
+        caip2: &str,
+        transaction: &str,
+        ctx: &AuthorizationContext,
+        idempotency_key: Option<&str>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let solana = self.0.wallets().solana();
+        let response = block_on(
+            py,
+            solana.sign_and_send_transaction(
+                wallet_id,
+                caip2,
+                transaction,
+                &ctx.0,
+                idempotency_key,
+            ),
+        )
+        .map_err(to_py_err)?;
+        to_py_object(py, &response.into_inner())
+    }
+}
+
+fn parse_method(method: &str) -> PyResult<privy_rs::Method> {
+    match method {
+        "POST" => Ok(privy_rs::Method::POST),
+        "PUT" => Ok(privy_rs::Method::PUT),
+        "PATCH" => Ok(privy_rs::Method::PATCH),
+        "DELETE" => Ok(privy_rs::Method::DELETE),
+        _ => Err(PrivyError::new_err(format!("unsupported method: {method}"))),
+    }
+}
+
+/// Build the canonical request that authorization signatures are computed over.
+#[pyfunction]
+#[pyo3(signature = (app_id, method, url, body, idempotency_key=None))]
+fn format_request_for_authorization_signature(
+    app_id: &str,
+    method: &str,
+    url: String,
+    body: &Bound<'_, PyAny>,
+    idempotency_key: Option<String>,
+) -> PyResult<String> {
+    let body: serde_json::Value = from_py_object(body)?;
+    privy_rs::format_request_for_authorization_signature(
+        app_id,
+        parse_method(method)?,
+        url,
+        body,
+        idempotency_key,
+    )
+    .map_err(to_py_err)
+}
+
+/// Sign a request with every key in the context, returning the value of the
+/// `privy-authorization-signature` header.
+#[pyfunction]
+#[pyo3(signature = (ctx, app_id, method, url, body, idempotency_key=None))]
+fn generate_authorization_signatures(
+    py: Python<'_>,
+    ctx: &AuthorizationContext,
+    app_id: &str,
+    method: &str,
+    url: String,
+    body: &Bound<'_, PyAny>,
+    idempotency_key: Option<String>,
+) -> PyResult<String> {
+    let method = parse_method(method)?;
+    let body: serde_json::Value = from_py_object(body)?;
+    block_on(
+        py,
+        privy_rs::generate_authorization_signatures(
+            &ctx.0,
+            app_id,
+            method,
+            url,
+            body,
+            idempotency_key,
+        ),
+    )
+    .map_err(to_py_err)
+}
+
+#[pymodule]
+#[pyo3(name = "privy_rs")]
+fn privy_rs_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("PrivyError", m.py().get_type::<PrivyError>())?;
+    m.add_class::<PrivyClient>()?;
+    m.add_class::<AuthorizationContext>()?;
+    m.add_class::<EthereumService>()?;
+    m.add_class::<SolanaService>()?;
+    m.add_function(wrap_pyfunction!(
+        format_request_for_authorization_signature,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(generate_authorization_signatures, m)?)?;
+    Ok(())
+}
@@ -13,7 +13,7 @@
 //! ```
 
 use anyhow::Result;
-use privy_rs::{AuthorizationContext, PrivateKey, PrivyClient};
+use privy_rs::{AuthorizationContext, PrivateKey, PrivyClient, WalletId};
 
 use alloy_consensus::TxLegacy;
 use alloy_network::TxSignerSync;
@@ -31,7 +31,11 @@ async fn main() -> Result<()> {
     let ctx = AuthorizationContext::new().push(PrivateKey::new(private_key));
 
     println!("Creating Alloy signer");
-    let signer = client.wallets().ethereum().alloy(&wallet_id, &ctx).await?;
+    let signer = client
+        .wallets()
+        .ethereum()
+        .alloy(WalletId::new(&wallet_id), &ctx)
+        .await?;
 
     println!("Address: {:?}", TxSignerSync::address(&signer));
     println!("Chain ID: {:?}\n", signer.chain_id_sync());
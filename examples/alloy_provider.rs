@@ -0,0 +1,82 @@
+//! Alloy Provider Example
+//!
+//! This example demonstrates how to plug a Privy wallet into an Alloy provider
+//! stack. It shows how to:
+//! - Create an Alloy signer backed by a Privy wallet
+//! - Wrap it in an `EthereumWallet`, the type Alloy providers use for signing
+//! - Sign an EIP-1559 transaction through the wallet and encode it for broadcast
+//!
+//! With `alloy-provider` in your own project, the same wallet can be attached
+//! with `ProviderBuilder::new().wallet(wallet).connect_http(rpc_url)`, after
+//! which `send_transaction` signs through Privy transparently.
+//!
+//! ## Required Environment Variables
+//! - `PRIVY_APP_ID`: Your Privy app ID
+//! - `PRIVY_APP_SECRET`: Your Privy app secret
+//! - `PRIVY_WALLET_ID`: The Ethereum wallet ID to use
+//! - `private_key.pem`: The wallet owner's private key, in the working directory
+//!
+//! ## Usage
+//! ```bash
+//! cargo run --example alloy_provider --features alloy
+//! ```
+
+use alloy_consensus::{TxEip1559, TypedTransaction};
+use alloy_network::{Ethereum, EthereumWallet, NetworkWallet, eip2718::Encodable2718};
+use alloy_primitives::{U256, address, bytes};
+use anyhow::Result;
+use privy_rs::{AuthorizationContext, PrivateKey, PrivyClient, WalletId};
+use tracing::Instrument;
+use tracing_subscriber::EnvFilter;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
+    let wallet_id =
+        std::env::var("PRIVY_WALLET_ID").expect("PRIVY_WALLET_ID environment variable not set");
+    let private_key = std::fs::read_to_string("private_key.pem")?;
+
+    let client = PrivyClient::new_from_env()?;
+    let ctx = AuthorizationContext::new().push(PrivateKey::new(private_key));
+
+    tracing::info!("initialized privy client from environment");
+
+    let signer = client
+        .wallets()
+        .ethereum()
+        .alloy(WalletId::new(&wallet_id), &ctx)
+        .await?;
+    let wallet = EthereumWallet::new(signer);
+    let from = NetworkWallet::<Ethereum>::default_signer_address(&wallet);
+
+    tracing::info!("Wallet address: {}", from);
+
+    let tx = TypedTransaction::Eip1559(TxEip1559 {
+        chain_id: 11155111, // sepolia
+        nonce: 0,
+        gas_limit: 21_000,
+        max_fee_per_gas: 20_000_000_000,
+        max_priority_fee_per_gas: 1_000_000_000,
+        to: address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045").into(),
+        value: U256::from(1),
+        access_list: Default::default(),
+        input: bytes!(),
+    });
+
+    let envelope = NetworkWallet::<Ethereum>::sign_transaction_from(&wallet, from, tx)
+        .instrument(tracing::info_span!("alloy_sign", %wallet_id))
+        .await?;
+
+    tracing::info!("Signed transaction hash: {}", envelope.tx_hash());
+    tracing::info!(
+        "Raw transaction: 0x{}",
+        hex::encode(envelope.encoded_2718())
+    );
+
+    Ok(())
+}
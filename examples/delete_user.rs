@@ -17,7 +17,7 @@
 //! ```
 
 use anyhow::Result;
-use privy_rs::PrivyClient;
+use privy_rs::{PrivyClient, UserId};
 use tracing_subscriber::EnvFilter;
 
 #[tokio::main]
@@ -33,7 +33,10 @@ async fn main() -> Result<()> {
 
     tracing::info!("initialized privy client from environment");
 
-    let user = client.users().delete("cmf56qacr01qpl90brxql83lx").await?;
+    let user = client
+        .users()
+        .delete(UserId::new("cmf56qacr01qpl90brxql83lx"))
+        .await?;
 
     tracing::info!("deleted user: {:?}", user);
 
@@ -0,0 +1,99 @@
+//! Ethereum Send Example
+//!
+//! This example demonstrates how to send a transaction from an Ethereum wallet.
+//! It shows how to:
+//! - Initialize a Privy client with app credentials
+//! - Build an unsigned transaction and configure it with `SendTransactionOptions`
+//! - Sign and broadcast the transaction through Privy
+//! - Link to the transaction on a block explorer
+//!
+//! ## Required Environment Variables
+//! - `PRIVY_APP_ID`: Your Privy app ID
+//! - `PRIVY_APP_SECRET`: Your Privy app secret
+//! - `PRIVY_WALLET_ID`: The Ethereum wallet ID to send from
+//! - `PRIVY_RECIPIENT`: The address to send to (optional, defaults to a burn address)
+//! - `PRIVY_AUTHORIZATION_KEY_FILE`: The path to the wallet owner's private key
+//!
+//! ## Usage
+//! ```bash
+//! cargo run --example ethereum_send
+//! ```
+
+use anyhow::Result;
+use privy_rs::{
+    PrivyClient, WalletId,
+    ethereum::SendTransactionOptions,
+    explorer::explorer_url_for_tx,
+    generated::types::{Quantity, UnsignedStandardEthereumTransaction, WalletRpcResponse},
+};
+use tracing::Instrument;
+use tracing_subscriber::EnvFilter;
+
+/// Sepolia testnet
+const CAIP2: &str = "eip155:11155111";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
+    let wallet_id =
+        std::env::var("PRIVY_WALLET_ID").expect("PRIVY_WALLET_ID environment variable not set");
+    let recipient = std::env::var("PRIVY_RECIPIENT")
+        .unwrap_or_else(|_| "0x000000000000000000000000000000000000dEaD".to_string());
+
+    let (client, ctx) = PrivyClient::quickstart()?;
+
+    tracing::info!("initialized privy client from environment");
+
+    let transaction = UnsignedStandardEthereumTransaction {
+        to: Some(recipient),
+        value: Some(Quantity::Integer(1)), // 1 wei
+        chain_id: None,
+        data: None,
+        from: None,
+        gas_limit: None,
+        gas_price: None,
+        max_fee_per_gas: None,
+        max_priority_fee_per_gas: None,
+        nonce: None,
+        type_: None,
+        authorization_list: vec![],
+    };
+
+    // ask Privy to pay for gas so the wallet only needs to hold the value
+    let options = SendTransactionOptions::new().with_sponsor(true);
+
+    let span = tracing::info_span!("ethereum_send", %wallet_id, caip2 = CAIP2);
+    let response = client
+        .wallets()
+        .ethereum()
+        .send_transaction_with_options(
+            WalletId::new(&wallet_id),
+            CAIP2,
+            transaction.into(),
+            &ctx,
+            None,
+            &options,
+        )
+        .instrument(span)
+        .await?;
+
+    match response.into_inner() {
+        WalletRpcResponse::EthereumSendTransactionRpcResponse(response) => {
+            let hash = response.data.hash;
+            tracing::info!("Sent transaction {}", hash);
+            if let Some(url) = explorer_url_for_tx(CAIP2, &hash) {
+                tracing::info!("View it at {}", url);
+            }
+        }
+        other => {
+            return Err(anyhow::anyhow!("unexpected rpc response: {other:?}"));
+        }
+    }
+
+    Ok(())
+}
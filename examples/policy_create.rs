@@ -0,0 +1,105 @@
+//! Policy Create Example
+//!
+//! This example demonstrates how to restrict what a wallet can do with a policy.
+//! It shows how to:
+//! - Initialize a Privy client with app credentials
+//! - Create a policy that caps the value of Ethereum transactions
+//! - Create a wallet governed by the policy
+//!
+//! ## Required Environment Variables
+//! - `PRIVY_APP_ID`: Your Privy app ID
+//! - `PRIVY_APP_SECRET`: Your Privy app secret
+//!
+//! ## Usage
+//! ```bash
+//! cargo run --example policy_create
+//! ```
+
+use anyhow::Result;
+use privy_rs::{
+    PrivyClient,
+    generated::types::{
+        ConditionOperator, ConditionValue, CreatePolicyBody, CreatePolicyBodyName,
+        CreatePolicyBodyRulesItem, CreatePolicyBodyRulesItemName, CreatePolicyBodyVersion,
+        CreateWalletBody, EthereumTransactionCondition, EthereumTransactionConditionField,
+        EthereumTransactionConditionFieldSource, PolicyAction, PolicyCondition, PolicyInput,
+        PolicyMethod, WalletChainType,
+    },
+};
+use tracing::Instrument;
+use tracing_subscriber::EnvFilter;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
+    let client = PrivyClient::new_from_env()?;
+
+    tracing::info!("initialized privy client from environment");
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)?
+        .as_secs();
+
+    // Step 1: Create a policy allowing transactions worth at most 0.01 ETH
+    let policy_body = CreatePolicyBody {
+        chain_type: WalletChainType::Ethereum,
+        name: CreatePolicyBodyName::try_from(format!("Value cap {timestamp}").as_str())?,
+        owner: None,
+        owner_id: None,
+        rules: vec![CreatePolicyBodyRulesItem {
+            action: PolicyAction::Allow,
+            conditions: vec![PolicyCondition::EthereumTransactionCondition(
+                EthereumTransactionCondition {
+                    field: EthereumTransactionConditionField::Value,
+                    field_source: EthereumTransactionConditionFieldSource::EthereumTransaction,
+                    operator: ConditionOperator::Lte,
+                    value: ConditionValue::String("10000000000000000".to_string()),
+                },
+            )],
+            id: None,
+            method: PolicyMethod::EthSendTransaction,
+            name: CreatePolicyBodyRulesItemName::try_from("cap-value")?,
+        }],
+        version: CreatePolicyBodyVersion::try_from("1.0")?,
+    };
+
+    let policy = client
+        .policies()
+        .create(None, &policy_body)
+        .instrument(tracing::info_span!("create_policy"))
+        .await?
+        .into_inner();
+
+    tracing::info!("Created policy with ID: {}", policy.id.as_str());
+    tracing::info!("Policy rules: {:?}", policy.rules);
+
+    // Step 2: Create a wallet that is governed by the policy
+    let create_body = CreateWalletBody {
+        chain_type: WalletChainType::Ethereum,
+        additional_signers: None,
+        display_name: None,
+        external_id: None,
+        owner: None,
+        owner_id: None,
+        policy_ids: Some(PolicyInput(vec![policy.id.to_string()])),
+    };
+
+    let wallet = client
+        .wallets()
+        .create(None, &create_body)
+        .instrument(tracing::info_span!(
+            "create_wallet",
+            policy_id = policy.id.as_str()
+        ))
+        .await?;
+
+    tracing::info!("Created wallet with ID: {}", wallet.id);
+    tracing::info!("Wallet policies: {:?}", wallet.policy_ids);
+
+    Ok(())
+}
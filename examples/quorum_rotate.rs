@@ -0,0 +1,121 @@
+//! Quorum Rotate Example
+//!
+//! This example demonstrates how to rotate the keys of a key quorum.
+//! It shows how to:
+//! - Create a 1-of-1 key quorum from a freshly generated P-256 key
+//! - Replace the quorum's key with a new one, authorized by the old key
+//! - Verify that only the new key can make further changes
+//!
+//! Rotating keys in place keeps the quorum ID stable, so wallets owned by
+//! the quorum do not need to be updated.
+//!
+//! ## Required Environment Variables
+//! - `PRIVY_APP_ID`: Your Privy app ID
+//! - `PRIVY_APP_SECRET`: Your Privy app secret
+//!
+//! ## Usage
+//! ```bash
+//! cargo run --example quorum_rotate
+//! ```
+
+use anyhow::Result;
+use p256::{NistP256, elliptic_curve::SecretKey};
+use privy_rs::{
+    AuthorizationContext, PrivateKey, PrivyClient,
+    generated::types::{
+        KeyQuorumCreateRequestBody, KeyQuorumCreateRequestBodyDisplayName, KeyQuorumId,
+        KeyQuorumUpdateRequestBody,
+    },
+};
+use tracing::Instrument;
+use tracing_subscriber::EnvFilter;
+
+fn context_for(key: &SecretKey<NistP256>) -> Result<AuthorizationContext> {
+    let pem = key.to_sec1_pem(der::pem::LineEnding::LF)?;
+    Ok(AuthorizationContext::new().push(PrivateKey::new(pem.as_str().to_owned())))
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
+    let client = PrivyClient::new_from_env()?;
+
+    tracing::info!("initialized privy client from environment");
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)?
+        .as_secs();
+
+    let mut rng = rand::thread_rng();
+    let old_key = SecretKey::<NistP256>::random(&mut rng);
+    let new_key = SecretKey::<NistP256>::random(&mut rng);
+
+    // Step 1: Create a quorum controlled by the old key
+    let quorum = client
+        .key_quorums()
+        .create(&KeyQuorumCreateRequestBody {
+            authorization_threshold: Some(1.0),
+            display_name: Some(KeyQuorumCreateRequestBodyDisplayName::try_from(
+                format!("Rotate Example {timestamp}").as_str(),
+            )?),
+            key_quorum_ids: vec![],
+            public_keys: vec![old_key.public_key().to_string()],
+            user_ids: vec![],
+        })
+        .instrument(tracing::info_span!("create_quorum"))
+        .await?
+        .into_inner();
+
+    tracing::info!("Created key quorum with ID: {}", quorum.id);
+
+    // Step 2: Swap in the new key, signing the update with the old one
+    let quorum_id = KeyQuorumId::from(quorum.id.clone());
+    let rotate_body = KeyQuorumUpdateRequestBody {
+        authorization_threshold: Some(1.0),
+        display_name: None,
+        key_quorum_ids: vec![],
+        public_keys: vec![new_key.public_key().to_string()],
+        user_ids: vec![],
+    };
+
+    let rotated = client
+        .key_quorums()
+        .update(&quorum_id, &context_for(&old_key)?, &rotate_body)
+        .instrument(tracing::info_span!("rotate_quorum", quorum_id = %quorum.id))
+        .await?
+        .into_inner();
+
+    tracing::info!(
+        "Rotated key quorum, now has {} key(s)",
+        rotated.authorization_keys.len()
+    );
+
+    // Step 3: The old key no longer controls the quorum, the new one does
+    let old_key_result = client
+        .key_quorums()
+        .update(&quorum_id, &context_for(&old_key)?, &rotate_body)
+        .await;
+
+    match old_key_result {
+        Err(err) => tracing::info!("✓ Old key was rejected as expected: {:?}", err),
+        Ok(_) => {
+            return Err(anyhow::anyhow!(
+                "Old key should no longer be able to update the quorum"
+            ));
+        }
+    }
+
+    client
+        .key_quorums()
+        .update(&quorum_id, &context_for(&new_key)?, &rotate_body)
+        .await?;
+
+    tracing::info!("✓ New key controls the quorum");
+
+    Ok(())
+}
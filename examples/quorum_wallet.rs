@@ -23,7 +23,7 @@
 use anyhow::Result;
 use p256::elliptic_curve::SecretKey;
 use privy_rs::{
-    AuthorizationContext, PrivateKey, PrivyClient,
+    AuthorizationContext, PrivateKey, PrivyClient, WalletId,
     generated::types::{
         CreateWalletBody, KeyQuorumCreateRequestBody, KeyQuorumCreateRequestBodyDisplayName,
         WalletChainType,
@@ -123,7 +123,10 @@ async fn main() -> Result<()> {
             .to_owned(),
     ));
 
-    let single_key_result = client.wallets().export(&wallet.id, &single_key_ctx).await;
+    let single_key_result = client
+        .wallets()
+        .export(WalletId::new(&wallet.id), &single_key_ctx)
+        .await;
 
     match single_key_result {
         Err(err) => {
@@ -150,12 +153,18 @@ async fn main() -> Result<()> {
             .to_owned(),
     ));
 
-    let two_key_result = client.wallets().export(&wallet.id, &two_key_ctx).await;
+    let two_key_result = client
+        .wallets()
+        .export(WalletId::new(&wallet.id), &two_key_ctx)
+        .await;
 
     match two_key_result {
         Ok(export_result) => {
             tracing::info!("✓ Two key authorization succeeded as expected");
-            tracing::info!("Exported private key length: {} bytes", export_result.len());
+            tracing::info!(
+                "Exported private key length: {} bytes",
+                export_result.expose().len()
+            );
         }
         Err(err) => {
             tracing::error!("✗ Two key authorization failed unexpectedly: {:?}", err);
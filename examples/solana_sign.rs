@@ -0,0 +1,66 @@
+//! Solana Sign Example
+//!
+//! This example demonstrates how to sign a message with a Solana wallet.
+//! It shows how to:
+//! - Initialize a Privy client with app credentials
+//! - Sign an arbitrary message using the Solana service
+//! - Decode the returned signature
+//!
+//! ## Required Environment Variables
+//! - `PRIVY_APP_ID`: Your Privy app ID
+//! - `PRIVY_APP_SECRET`: Your Privy app secret
+//! - `PRIVY_WALLET_ID`: The Solana wallet ID to sign with
+//! - `PRIVY_AUTHORIZATION_KEY_FILE`: The path to the wallet owner's private key
+//!
+//! ## Usage
+//! ```bash
+//! cargo run --example solana_sign
+//! ```
+
+use anyhow::Result;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use privy_rs::{PrivyClient, WalletId, generated::types::WalletRpcResponse};
+use tracing::Instrument;
+use tracing_subscriber::EnvFilter;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
+    let wallet_id =
+        std::env::var("PRIVY_WALLET_ID").expect("PRIVY_WALLET_ID environment variable not set");
+
+    let (client, ctx) = PrivyClient::quickstart()?;
+
+    tracing::info!("initialized privy client from environment");
+
+    // Solana messages are signed as-is, without any prefix
+    let message = STANDARD.encode("Hello, Solana!");
+
+    let response = client
+        .wallets()
+        .solana()
+        .sign_message(WalletId::new(&wallet_id), &message, &ctx, None)
+        .instrument(tracing::info_span!("solana_sign", %wallet_id))
+        .await?;
+
+    match response.into_inner() {
+        WalletRpcResponse::SolanaSignMessageRpcResponse(response) => {
+            let signature = STANDARD.decode(&response.data.signature)?;
+            tracing::info!(
+                "Signature ({} bytes): {}",
+                signature.len(),
+                hex::encode(signature)
+            );
+        }
+        other => {
+            return Err(anyhow::anyhow!("unexpected rpc response: {other:?}"));
+        }
+    }
+
+    Ok(())
+}
@@ -20,7 +20,7 @@
 use anyhow::Result;
 use privy_rs::{
     AuthorizationContext, IntoKey, JwtUser, PrivateKey, PrivyApiError, PrivyClient,
-    PrivySignedApiError,
+    PrivySignedApiError, WalletId,
     generated::types::{OwnerInput, OwnerInputPublicKey, P256PublicKey, WalletUpdateRequestBody},
 };
 use tracing_subscriber::EnvFilter;
@@ -60,7 +60,7 @@ async fn main() -> Result<()> {
 
     let wallet = match wallets_client
         .update(
-            &wallet_id,
+            WalletId::new(&wallet_id),
             &ctx,
             &WalletUpdateRequestBody {
                 owner: Some(OwnerInput::Variant1(OwnerInputPublicKey {
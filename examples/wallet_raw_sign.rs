@@ -19,6 +19,7 @@
 use anyhow::Result;
 use privy_rs::{
     AuthorizationContext, JwtUser, PrivateKey, PrivyApiError, PrivyClient, PrivySignedApiError,
+    WalletId,
     generated::types::{RawSignHashParams, RawSignInput, RawSignInputParams},
 };
 use tracing_subscriber::EnvFilter;
@@ -52,7 +53,7 @@ async fn main() -> Result<()> {
     let raw_sign_response = match client
         .wallets()
         .raw_sign(
-            &wallet_id,
+            WalletId::new(&wallet_id),
             &ctx,
             None, // No idempotency key
             &RawSignInput {
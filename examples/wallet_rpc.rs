@@ -20,6 +20,7 @@ use anyhow::Result;
 use base64::{Engine, engine::general_purpose::STANDARD};
 use privy_rs::{
     AuthorizationContext, JwtUser, PrivateKey, PrivyApiError, PrivyClient, PrivySignedApiError,
+    WalletId,
     generated::types::{
         SolanaSignMessageRpcInput, SolanaSignMessageRpcInputMethod,
         SolanaSignMessageRpcInputParams, SolanaSignMessageRpcInputParamsEncoding,
@@ -57,7 +58,7 @@ async fn main() -> Result<()> {
     let rpc_response = match client
         .wallets()
         .rpc(
-            &wallet_id,
+            WalletId::new(&wallet_id),
             &ctx,
             None, // No idempotency key
             &WalletRpcRequestBody::SolanaSignMessageRpcInput(SolanaSignMessageRpcInput {
@@ -0,0 +1,46 @@
+#!/usr/bin/env bash
+# Add derives cargo-progenitor has no option for to the generated types in
+# crates/privy-openapi, so application code can compare them:
+#
+# - `PartialEq` on every (de)serializable type, such as `Wallet` and
+#   `Policy`, which hold floats and so can not be `Eq`
+# - `Eq` and `Hash` on the asset enums listed below, so they can key maps.
+#   String enums such as `WalletChainType` and newtypes such as `PolicyId`
+#   already derive both.
+#
+# Run by `mise gen-openapi` after generating the crate. Running it again is
+# a no-op.
+set -euo pipefail
+
+FILE=${1:-crates/privy-openapi/src/lib.rs}
+HASHABLE="WalletAsset|WalletTransactionsAsset|GetWalletBalanceAsset"
+
+trap 'rm -f "$FILE.tmp"' EXIT
+
+# Fail if the derive lists or the asset enums can not be found, so that a
+# change in the output of progenitor does not silently drop the derives.
+HASHABLE=$HASHABLE perl -0777 -pe '
+  # add `$derive` to a derive list, keeping it sorted like progenitor does
+  sub add {
+    my ($derives, $derive) = @_;
+    my @derives = grep { length } map { s/^\s+|\s+$//gr } split /,/, $derives;
+    return $derives if grep { $_ eq $derive } @derives;
+    return join ", ", sort @derives, $derive;
+  }
+  my %hashed;
+  s{#\[derive\(([^)]*::\s*serde\s*::\s*(?:Des|S)erialize[^)]*)\)\](\s*(?:#\[[^\]]*\]\s*)*pub (?:enum|struct) (\w+))}{
+    my ($derives, $rest, $name) = ($1, $2, $3);
+    $derives = add($derives, "PartialEq");
+    if ($name =~ /^(?:$ENV{HASHABLE})$/) {
+      $derives = add(add($derives, "Eq"), "Hash");
+      $hashed{$name} = 1;
+    }
+    "#[derive($derives)]$rest"
+  }ge or die "add-derives.sh: no serde derive lists found, has the progenitor output changed?\n";
+  for my $name (split /\|/, $ENV{HASHABLE}) {
+    $hashed{$name} or die "add-derives.sh: no derive list found for $name\n";
+  }
+' "$FILE" > "$FILE.tmp"
+mv "$FILE.tmp" "$FILE"
+
+rustfmt --edition 2021 "$FILE"
@@ -0,0 +1,82 @@
+#!/usr/bin/env bash
+# Run a `RequestHook` on every request of the generated client in
+# crates/privy-openapi just before it is sent, so that the request hooks of
+# privy-rs can change outgoing requests, see `privy_rs::middleware`.
+#
+# This is the code progenitor generates with `with_inner_type` and
+# `with_pre_hook_async`, which cargo-progenitor has no option for: the
+# client holds a `RequestHook` as its inner value, taken by `Client::new`
+# and `Client::new_with_client`, and runs it after building each request.
+# An error from the hook fails the call with `Error::Custom`.
+#
+# Run by `mise gen-openapi` after generating the crate. Running it again is
+# a no-op.
+set -euo pipefail
+
+FILE=${1:-crates/privy-openapi/src/lib.rs}
+
+if grep -q '^pub struct RequestHook' "$FILE"; then
+  exit 0
+fi
+
+trap 'rm -f "$FILE.tmp"' EXIT
+
+# Each substitution must match, so that a change in the output of
+# progenitor fails here rather than leaving requests without the hook.
+perl -0777 -pe '
+  sub anchor { die "add-request-hook.sh: no match for $_[0], has the progenitor output changed?\n" }
+  s{(pub struct Client \{\n\s*pub\(crate\) baseurl: String,\n\s*pub\(crate\) client: reqwest::Client,\n)}{$1    pub(crate) inner: RequestHook,\n} or anchor("the Client struct");
+  s{pub fn new\(baseurl: &str\) -> Self}{pub fn new(baseurl: &str, inner: RequestHook) -> Self} or anchor("Client::new");
+  s{Self::new_with_client\(baseurl, client\.build\(\)\.unwrap\(\)\)}{Self::new_with_client(baseurl, client.build().unwrap(), inner)} or anchor("the call to Client::new_with_client");
+  s#pub fn new_with_client\(baseurl: &str, client: reqwest::Client\) -> Self \{(\s*Self \{\s*baseurl: baseurl\.to_string\(\),\s*client,)#pub fn new_with_client(baseurl: &str, client: reqwest::Client, inner: RequestHook) -> Self {$1 inner,# or anchor("Client::new_with_client");
+  s{impl ClientInfo<\(\)> for Client}{impl ClientInfo<RequestHook> for Client} or anchor("impl ClientInfo");
+  s{fn inner\(&self\) -> &\(\) \{\s*&\(\)\s*\}}{fn inner(&self) -> &RequestHook { &self.inner }} or anchor("ClientInfo::inner");
+  s{impl ClientHooks<\(\)> for &Client \{\}}{impl ClientHooks<RequestHook> for &Client {}} or anchor("impl ClientHooks");
+  s{(\n(\s*)self\.pre\(&mut request, &info\)\.await\?;)}{\n$2match (RequestHook::run)(&self.inner, &mut request).await { Ok(_) => (), Err(e) => return Err(Error::Custom(e.to_string())), }$1}g or anchor("the pre hook of the requests");
+' "$FILE" > "$FILE.tmp"
+
+# every operation must run the hook
+operations=$(grep -c 'let info = OperationInfo {' "$FILE.tmp" || true)
+hooks=$(grep -c 'RequestHook::run' "$FILE.tmp" || true)
+if [ "$operations" -eq 0 ] || [ "$hooks" -ne "$operations" ]; then
+  echo "add-request-hook.sh: $hooks of $operations operations run the request hook" >&2
+  exit 1
+fi
+mv "$FILE.tmp" "$FILE"
+
+cat >> "$FILE" <<'EOF'
+
+/// A hook the client runs on every request just before it is sent, which can
+/// change the request, or stop it from being sent by returning an error.
+/// `RequestHook::default()` leaves requests as they are.
+#[derive(Clone, Default)]
+pub struct RequestHook(Option<std::sync::Arc<RequestHookFn>>);
+
+type RequestHookFn = dyn Fn(&mut reqwest::Request) -> Result<(), String> + Send + Sync;
+
+impl RequestHook {
+    /// Run `hook` on every request.
+    pub fn new(
+        hook: impl Fn(&mut reqwest::Request) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        Self(Some(std::sync::Arc::new(hook)))
+    }
+
+    async fn run(&self, request: &mut reqwest::Request) -> Result<(), String> {
+        match &self.0 {
+            Some(hook) => hook(request),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Debug for RequestHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RequestHook")
+            .field(&self.0.is_some())
+            .finish()
+    }
+}
+EOF
+
+rustfmt --edition 2021 "$FILE"
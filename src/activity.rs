@@ -0,0 +1,347 @@
+//! Wallet activity summaries.
+//!
+//! This module contains the report types produced by
+//! `WalletsClient::activity_summary`, which aggregates a wallet's
+//! transaction history into numbers that are convenient for dashboards.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    time::{Duration, SystemTime},
+};
+
+use crate::generated::types::{
+    WalletTransactionsResponseTransactionsItem, WalletTransactionsResponseTransactionsItemDetails,
+};
+
+/// The window of time a summary covers. Transactions are included if
+/// they were created at or after `start` and strictly before `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActivityPeriod {
+    /// The inclusive start of the period.
+    pub start: SystemTime,
+    /// The exclusive end of the period.
+    pub end: SystemTime,
+}
+
+impl ActivityPeriod {
+    /// Creates a new period between `start` and `end`.
+    pub fn new(start: SystemTime, end: SystemTime) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates a period covering the given duration up until now.
+    pub fn last(duration: Duration) -> Self {
+        let end = SystemTime::now();
+        Self {
+            start: end.checked_sub(duration).unwrap_or(SystemTime::UNIX_EPOCH),
+            end,
+        }
+    }
+
+    /// Returns whether a privy timestamp, in milliseconds since the unix
+    /// epoch, falls inside this period.
+    pub(crate) fn contains_millis(&self, millis: f64) -> bool {
+        match time_from_millis(millis) {
+            Some(at) => self.start <= at && at < self.end,
+            None => false,
+        }
+    }
+
+    /// Returns whether a privy timestamp, in milliseconds since the unix
+    /// epoch, is before the start of this period.
+    pub(crate) fn starts_after_millis(&self, millis: f64) -> bool {
+        time_from_millis(millis).is_some_and(|at| at < self.start)
+    }
+}
+
+/// The time of a privy timestamp in milliseconds since the unix epoch, or
+/// `None` if it is negative, not finite or too large for a `SystemTime`.
+fn time_from_millis(millis: f64) -> Option<SystemTime> {
+    let since_epoch = Duration::try_from_secs_f64(millis / 1000.0).ok()?;
+    SystemTime::UNIX_EPOCH.checked_add(since_epoch)
+}
+
+/// The total volume moved for a single asset, in the asset's base units
+/// (for example wei or lamports).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetVolume {
+    /// The number of decimals used to convert the raw values to display units.
+    pub decimals: u32,
+    /// The total raw amount sent from the wallet.
+    pub sent: u128,
+    /// The total raw amount received by the wallet.
+    pub received: u128,
+}
+
+/// An aggregated report of a wallet's activity over an `ActivityPeriod`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalletActivitySummary {
+    /// The wallet this summary describes.
+    pub wallet_id: String,
+    /// The period this summary covers.
+    pub period: ActivityPeriod,
+    /// The number of transactions in the period.
+    pub transaction_count: usize,
+    /// The volume moved per asset, keyed by asset name (e.g. `eth`, `usdc`).
+    pub volume_by_asset: BTreeMap<String, AssetVolume>,
+    /// The distinct addresses this wallet sent to or received from.
+    pub counterparties: BTreeSet<String>,
+}
+
+impl WalletActivitySummary {
+    pub(crate) fn new(wallet_id: String, period: ActivityPeriod) -> Self {
+        Self {
+            wallet_id,
+            period,
+            transaction_count: 0,
+            volume_by_asset: BTreeMap::new(),
+            counterparties: BTreeSet::new(),
+        }
+    }
+
+    /// The number of distinct counterparties in the period.
+    pub fn unique_counterparties(&self) -> usize {
+        self.counterparties.len()
+    }
+
+    /// Fold a single transaction into the summary. Transactions outside
+    /// of the summary's period are ignored.
+    pub(crate) fn record(&mut self, transaction: &WalletTransactionsResponseTransactionsItem) {
+        if !self.period.contains_millis(transaction.created_at) {
+            return;
+        }
+
+        self.transaction_count += 1;
+
+        let (asset, raw_value, decimals, counterparty, sent) = match &transaction.details {
+            WalletTransactionsResponseTransactionsItemDetails::Variant0 {
+                asset,
+                raw_value,
+                raw_value_decimals,
+                recipient,
+                ..
+            } => (
+                asset_name(asset),
+                raw_value,
+                raw_value_decimals,
+                recipient,
+                true,
+            ),
+            WalletTransactionsResponseTransactionsItemDetails::Variant1 {
+                asset,
+                raw_value,
+                raw_value_decimals,
+                sender,
+                ..
+            } => (
+                asset_name(asset),
+                raw_value,
+                raw_value_decimals,
+                sender,
+                false,
+            ),
+        };
+
+        self.counterparties.insert(counterparty.clone());
+
+        let Ok(amount) = raw_value.parse::<u128>() else {
+            tracing::warn!(
+                "skipping unparseable raw value {} for transaction {}",
+                raw_value,
+                transaction.privy_transaction_id
+            );
+            return;
+        };
+
+        let volume = self.volume_by_asset.entry(asset).or_default();
+        volume.decimals = *decimals as u32;
+        if sent {
+            volume.sent = volume.sent.saturating_add(amount);
+        } else {
+            volume.received = volume.received.saturating_add(amount);
+        }
+    }
+}
+
+/// The asset enums in the transaction details are untagged unions of
+/// several generated enums, so we go via their serialized form to get a
+/// stable name.
+pub(crate) fn asset_name<T: serde::Serialize>(asset: &T) -> String {
+    match serde_json::to_value(asset) {
+        Ok(serde_json::Value::String(s)) => s,
+        Ok(other) => other.to_string(),
+        Err(_) => "unknown".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn transaction(
+        created_at_ms: f64,
+        kind: &str,
+        asset: &str,
+        raw_value: &str,
+        counterparty: &str,
+    ) -> WalletTransactionsResponseTransactionsItem {
+        let (sender, recipient) = match kind {
+            "transfer_sent" => ("0xme", counterparty),
+            _ => (counterparty, "0xme"),
+        };
+        serde_json::from_value(json!({
+            "caip2": "eip155:8453",
+            "created_at": created_at_ms,
+            "privy_transaction_id": "tx",
+            "status": "confirmed",
+            "wallet_id": "wallet",
+            "details": {
+                "type": kind,
+                "asset": asset,
+                "chain": "base",
+                "display_values": {},
+                "raw_value": raw_value,
+                "raw_value_decimals": 18,
+                "sender": sender,
+                "recipient": recipient,
+            }
+        }))
+        .unwrap()
+    }
+
+    fn period() -> ActivityPeriod {
+        ActivityPeriod::new(
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_000),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(2_000),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_summary_stops_paging_before_the_period() {
+        use httpmock::prelude::*;
+
+        use crate::{
+            PrivyClient, WalletId,
+            client::PrivyClientOptions,
+            generated::types::{WalletEthereumAsset, WalletTransactionsChain},
+        };
+
+        let server = MockServer::start_async().await;
+        let first = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/v1/wallets/wallet/transactions")
+                    .query_param_missing("cursor");
+                then.status(200).json_body(json!({
+                    "transactions": [
+                        transaction(1_500_000.0, "transfer_sent", "usdc", "10", "0xa"),
+                        transaction(500_000.0, "transfer_sent", "usdc", "20", "0xb"),
+                    ],
+                    "next_cursor": "older",
+                }));
+            })
+            .await;
+        let older = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/v1/wallets/wallet/transactions")
+                    .query_param("cursor", "older");
+                then.status(200)
+                    .json_body(json!({ "transactions": [], "next_cursor": null }));
+            })
+            .await;
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let summary = client
+            .wallets()
+            .activity_summary(
+                WalletId::new("wallet"),
+                WalletTransactionsChain::Base,
+                &WalletEthereumAsset::Usdc.into(),
+                period(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(summary.transaction_count, 1);
+        first.assert_async().await;
+        older.assert_calls_async(0).await;
+    }
+
+    #[test]
+    fn test_period_bounds() {
+        let period = period();
+        assert!(period.contains_millis(1_000_000.0), "start is inclusive");
+        assert!(period.contains_millis(1_999_999.0));
+        assert!(!period.contains_millis(2_000_000.0), "end is exclusive");
+        assert!(!period.contains_millis(999_999.0));
+        assert!(!period.contains_millis(f64::NAN));
+    }
+
+    #[test]
+    fn test_out_of_range_timestamps_are_outside_the_period() {
+        let period = period();
+        for millis in [f64::MAX, 1e30, -1.0, f64::INFINITY] {
+            assert!(!period.contains_millis(millis), "{millis}");
+            assert!(!period.starts_after_millis(millis), "{millis}");
+        }
+    }
+
+    #[test]
+    fn test_summary_aggregates_volume_and_counterparties() {
+        let mut summary = WalletActivitySummary::new("wallet".to_string(), period());
+        for tx in [
+            transaction(1_100_000.0, "transfer_sent", "eth", "100", "0xa"),
+            transaction(1_200_000.0, "transfer_sent", "eth", "50", "0xb"),
+            transaction(1_300_000.0, "transfer_received", "eth", "25", "0xa"),
+            transaction(1_400_000.0, "transfer_received", "usdc", "7", "0xc"),
+        ] {
+            summary.record(&tx);
+        }
+
+        assert_eq!(summary.transaction_count, 4);
+        assert_eq!(summary.unique_counterparties(), 3);
+        assert_eq!(
+            summary.volume_by_asset["eth"],
+            AssetVolume {
+                decimals: 18,
+                sent: 150,
+                received: 25
+            }
+        );
+        assert_eq!(summary.volume_by_asset["usdc"].received, 7);
+    }
+
+    #[test]
+    fn test_summary_ignores_transactions_outside_period() {
+        let mut summary = WalletActivitySummary::new("wallet".to_string(), period());
+        summary.record(&transaction(
+            500_000.0,
+            "transfer_sent",
+            "eth",
+            "100",
+            "0xa",
+        ));
+        summary.record(&transaction(
+            3_000_000.0,
+            "transfer_sent",
+            "eth",
+            "100",
+            "0xa",
+        ));
+
+        assert_eq!(summary.transaction_count, 0);
+        assert!(summary.volume_by_asset.is_empty());
+        assert!(summary.counterparties.is_empty());
+    }
+}
@@ -0,0 +1,202 @@
+//! Checking addresses against the chain they are used on.
+//!
+//! The API rejects a malformed address with a generic 400, after the
+//! request was signed and sent. The helpers of this crate that take an
+//! address and a chain, such as `PrivyClient::send` and
+//! `WalletsClient::import`, check the address first and fail with an
+//! [`InvalidAddressForChain`] that says what is wrong with it:
+//!
+//! ```
+//! use privy_rs::address::validate_address;
+//!
+//! assert!(validate_address("base", "0x742d35Cc6634C0532925a3b844Bc454e4438f44e").is_ok());
+//! assert!(validate_address("solana", "0x742d35Cc6634C0532925a3b844Bc454e4438f44e").is_err());
+//! // chains without a known address format are left to the API
+//! assert!(validate_address("bitcoin-segwit", "bc1q...").is_ok());
+//! ```
+//!
+//! EVM addresses in mixed case must match their EIP-55 checksum; this
+//! needs the `ethereum` feature, without which only their format is
+//! checked.
+//!
+//! [`InvalidAddressForChain`]: crate::InvalidAddressForChain
+
+use sha2::{Digest, Sha256};
+
+use crate::InvalidAddressForChain;
+
+/// Networks that use EVM addresses, by the first part of their name, so
+/// that testnets like `base-sepolia` are included.
+const EVM_NETWORKS: &[&str] = &[
+    "ethereum",
+    "sepolia",
+    "holesky",
+    "base",
+    "arbitrum",
+    "optimism",
+    "polygon",
+    "avalanche",
+    "bsc",
+    "linea",
+    "zksync",
+    "celo",
+    "blast",
+    "scroll",
+    "zora",
+    "tempo",
+];
+
+/// The address format of a chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressFormat {
+    /// `0x` followed by 20 bytes in hex, with an EIP-55 checksum if in
+    /// mixed case.
+    Evm,
+    /// A 32 byte public key in base58.
+    Solana,
+    /// A 21 byte address starting with `0x41` in base58check, or in hex.
+    Tron,
+}
+
+impl AddressFormat {
+    /// The address format of `chain`, which may be a network name as used
+    /// by transfers (`base`, `solana-devnet`), a chain type (`ethereum`,
+    /// `tron`) or a CAIP-2 id (`eip155:8453`). `None` for chains whose
+    /// format is not known here.
+    #[must_use]
+    pub fn of_chain(chain: &str) -> Option<Self> {
+        let family = chain
+            .split([':', '-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match family.as_str() {
+            "eip155" => Some(Self::Evm),
+            "solana" => Some(Self::Solana),
+            "tron" => Some(Self::Tron),
+            family if EVM_NETWORKS.contains(&family) => Some(Self::Evm),
+            _ => None,
+        }
+    }
+
+    /// Check that `address` is in this format, returning why it is not.
+    fn check(self, address: &str) -> Result<(), &'static str> {
+        match self {
+            Self::Evm => check_evm(address),
+            Self::Solana => match bs58::decode(address).into_vec() {
+                Ok(bytes) if bytes.len() == 32 => Ok(()),
+                Ok(_) => Err("expected a 32 byte base58 public key"),
+                Err(_) => Err("not valid base58"),
+            },
+            Self::Tron => check_tron(address),
+        }
+    }
+}
+
+/// Check that `address` is a valid address on `chain`. Addresses on chains
+/// whose format is not known, see `AddressFormat::of_chain`, are accepted.
+///
+/// # Errors
+/// If `address` is not in the address format of `chain`.
+pub fn validate_address(chain: &str, address: &str) -> Result<(), InvalidAddressForChain> {
+    let Some(format) = AddressFormat::of_chain(chain) else {
+        return Ok(());
+    };
+    format
+        .check(address)
+        .map_err(|reason| InvalidAddressForChain {
+            address: address.to_owned(),
+            chain: chain.to_owned(),
+            reason,
+        })
+}
+
+fn check_evm(address: &str) -> Result<(), &'static str> {
+    let hex = address
+        .strip_prefix("0x")
+        .ok_or("expected a 0x prefixed hex address")?;
+    if hex.len() != 40 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err("expected 20 bytes of hex after 0x");
+    }
+    let mixed_case =
+        hex.bytes().any(|b| b.is_ascii_lowercase()) && hex.bytes().any(|b| b.is_ascii_uppercase());
+    if mixed_case && !eip55_checksum_matches(hex) {
+        return Err("the EIP-55 checksum does not match");
+    }
+    Ok(())
+}
+
+/// Whether the case of the letters of `hex` matches the EIP-55 checksum:
+/// uppercase where the matching nibble of the Keccak-256 hash of the
+/// lowercase address is 8 or more.
+#[cfg(feature = "ethereum")]
+fn eip55_checksum_matches(hex: &str) -> bool {
+    use sha3::Keccak256;
+
+    let hash = Keccak256::digest(hex.to_ascii_lowercase().as_bytes());
+    hex.bytes().enumerate().all(|(i, b)| {
+        let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+        !b.is_ascii_alphabetic() || b.is_ascii_uppercase() == (nibble >= 8)
+    })
+}
+
+#[cfg(not(feature = "ethereum"))]
+fn eip55_checksum_matches(_hex: &str) -> bool {
+    true
+}
+
+fn check_tron(address: &str) -> Result<(), &'static str> {
+    if address.len() == 42 && address.starts_with("41") {
+        return match hex::decode(address) {
+            Ok(_) => Ok(()),
+            Err(_) => Err("not valid hex"),
+        };
+    }
+    let bytes = bs58::decode(address)
+        .into_vec()
+        .map_err(|_| "not valid base58")?;
+    let [payload @ .., c0, c1, c2, c3] = &bytes[..] else {
+        return Err("expected a 21 byte base58check address");
+    };
+    if payload.len() != 21 || payload[0] != 0x41 {
+        return Err("expected a 21 byte base58check address starting with T");
+    }
+    let checksum = Sha256::digest(Sha256::digest(payload));
+    if checksum[..4] != [*c0, *c1, *c2, *c3] {
+        return Err("the base58check checksum does not match");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("base", "0x742d35Cc6634C0532925a3b844Bc454e4438f44e" => Ok(()) ; "checksummed")]
+    #[test_case("eip155:1", "0x742d35cc6634c0532925a3b844bc454e4438f44e" => Ok(()) ; "lowercase")]
+    #[test_case("ethereum", "0x742D35CC6634C0532925A3B844BC454E4438F44E" => Ok(()) ; "uppercase")]
+    #[cfg_attr(feature = "ethereum", test_case("base-sepolia", "0x742d35Cc6634C0532925a3b844Bc454e4438F44e" => Err("the EIP-55 checksum does not match") ; "bad checksum"))]
+    #[test_case("polygon", "742d35Cc6634C0532925a3b844Bc454e4438f44e" => Err("expected a 0x prefixed hex address") ; "no prefix")]
+    #[test_case("arbitrum", "0x742d35" => Err("expected 20 bytes of hex after 0x") ; "too short")]
+    #[test_case("solana", "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM" => Ok(()) ; "solana")]
+    #[test_case("solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1", "0x742d35Cc6634C0532925a3b844Bc454e4438f44e" => Err("not valid base58") ; "evm on solana")]
+    #[test_case("solana-devnet", "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYt" => Err("expected a 32 byte base58 public key") ; "short solana")]
+    #[test_case("tron", "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t" => Ok(()) ; "tron")]
+    #[test_case("tron", "41a614f803b6fd780986a42c78ec9c7f77e6ded13c" => Ok(()) ; "tron hex")]
+    #[test_case("tron", "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6u" => Err("the base58check checksum does not match") ; "tron bad checksum")]
+    #[test_case("bitcoin-segwit", "anything" => Ok(()) ; "unknown chain")]
+    fn test_validate_address(chain: &str, address: &str) -> Result<(), &'static str> {
+        validate_address(chain, address).map_err(|e| e.reason)
+    }
+
+    #[test]
+    fn test_invalid_address_names_chain() {
+        let error = validate_address("base", "vitalik.eth").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "`vitalik.eth` is not a valid base address: expected a 0x prefixed hex address"
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! EIP-4844 blob transactions
+//!
+//! Privy's `eth_signTransaction` does not accept blob transactions (type 3),
+//! so they are signed the way `PrivyAlloyWallet` signs any transaction: the
+//! wallet signs the signature hash with `secp256k1_sign` and the signature is
+//! attached locally. The blobs never leave the process, only their versioned
+//! hashes, which are part of the signed payload.
+//!
+//! Because Privy never sees the sidecar, it is checked here before signing:
+//! there must be a blob, commitment and proof for every versioned hash, and
+//! each hash must be the one of its commitment. The KZG proofs themselves are
+//! verified by the node the transaction is submitted to.
+
+use alloy_consensus::{SignableTransaction, Signed, TxEip4844WithSidecar};
+use alloy_network::TxSigner;
+
+use super::PrivyAlloyWallet;
+use crate::BlobTransactionError;
+
+/// Check that the sidecar of `transaction` carries the blobs it commits to.
+#[allow(clippy::result_large_err)]
+pub(crate) fn validate_sidecar(
+    transaction: &TxEip4844WithSidecar,
+) -> Result<(), BlobTransactionError> {
+    let hashes = &transaction.tx.blob_versioned_hashes;
+    let sidecar = &transaction.sidecar;
+
+    if hashes.is_empty() {
+        return Err(BlobTransactionError::InvalidSidecar(
+            "a blob transaction needs at least one blob".to_string(),
+        ));
+    }
+    for (name, len) in [
+        ("blobs", sidecar.blobs.len()),
+        ("commitments", sidecar.commitments.len()),
+        ("proofs", sidecar.proofs.len()),
+    ] {
+        if len != hashes.len() {
+            return Err(BlobTransactionError::InvalidSidecar(format!(
+                "{} versioned hashes but {len} {name}",
+                hashes.len()
+            )));
+        }
+    }
+    if let Some(index) = hashes
+        .iter()
+        .zip(sidecar.versioned_hashes())
+        .position(|(expected, actual)| *expected != actual)
+    {
+        return Err(BlobTransactionError::InvalidSidecar(format!(
+            "versioned hash {index} does not match its commitment"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validate the sidecar of `transaction` and sign it with `signer`.
+pub(crate) async fn sign(
+    signer: &PrivyAlloyWallet,
+    mut transaction: TxEip4844WithSidecar,
+) -> Result<Signed<TxEip4844WithSidecar>, BlobTransactionError> {
+    validate_sidecar(&transaction)?;
+    let signature = signer.sign_transaction(&mut transaction).await?;
+    Ok(transaction.into_signed(signature))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{BlobTransactionSidecar, Bytes48, TxEip4844};
+    use alloy_primitives::B256;
+
+    use super::*;
+
+    fn transaction(commitments: usize, hashes: usize) -> TxEip4844WithSidecar {
+        let commitments: Vec<_> = (0..commitments)
+            .map(|i| Bytes48::repeat_byte(i as u8 + 1))
+            .collect();
+        let blobs = vec![Default::default(); commitments.len()];
+        let proofs = vec![Bytes48::ZERO; commitments.len()];
+        let sidecar = BlobTransactionSidecar::new(blobs, commitments, proofs);
+        let tx = TxEip4844 {
+            blob_versioned_hashes: sidecar.versioned_hashes().take(hashes).collect(),
+            ..Default::default()
+        };
+        TxEip4844WithSidecar::from_tx_and_sidecar(tx, sidecar)
+    }
+
+    #[test]
+    fn test_sidecar_must_match_the_versioned_hashes() {
+        assert!(validate_sidecar(&transaction(1, 1)).is_ok());
+
+        let message = |transaction| match validate_sidecar(&transaction) {
+            Err(BlobTransactionError::InvalidSidecar(message)) => message,
+            other => panic!("expected an invalid sidecar, got {other:?}"),
+        };
+        assert_eq!(
+            message(transaction(0, 0)),
+            "a blob transaction needs at least one blob"
+        );
+        assert_eq!(message(transaction(2, 1)), "1 versioned hashes but 2 blobs");
+
+        let mut swapped = transaction(1, 1);
+        swapped.tx.blob_versioned_hashes[0] = B256::repeat_byte(1);
+        assert_eq!(
+            message(swapped),
+            "versioned hash 0 does not match its commitment"
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Decoding signed transactions
+//!
+//! `EthereumService::sign_transaction` returns the signed transaction as a
+//! hex encoded EIP-2718 blob. Decoding it lets callers check what Privy
+//! signed before broadcasting it themselves, recover the signer with
+//! `SignerRecoverable::recover_signer`, and read the hash the transaction
+//! will have on chain.
+
+use alloy_consensus::TxEnvelope;
+use alloy_eips::eip2718::Decodable2718;
+use alloy_primitives::{B256, keccak256};
+
+use crate::DecodeTransactionError;
+
+/// Decode an EIP-2718 encoded signed transaction, such as the
+/// `signed_transaction` returned by `eth_signTransaction`. Legacy
+/// transactions, which are plain RLP, are accepted too.
+///
+/// ```
+/// # use privy_rs::alloy::decode_signed_transaction;
+/// # fn foo(raw: &[u8]) -> Result<(), privy_rs::DecodeTransactionError> {
+/// use alloy_consensus::Transaction;
+///
+/// let envelope = decode_signed_transaction(raw)?;
+/// println!("{:?} sends {} to {:?}", envelope.tx_hash(), envelope.value(), envelope.to());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+/// If `raw` is not a single encoded transaction.
+pub fn decode_signed_transaction(raw: &[u8]) -> Result<TxEnvelope, DecodeTransactionError> {
+    Ok(TxEnvelope::decode_2718_exact(raw)?)
+}
+
+/// Like [`decode_signed_transaction`], but for a hex string, with or
+/// without a `0x` prefix, as returned in the rpc response.
+///
+/// # Errors
+/// If `raw` is not hex, or not a single encoded transaction.
+pub fn decode_signed_transaction_hex(raw: &str) -> Result<TxEnvelope, DecodeTransactionError> {
+    let raw = raw.strip_prefix("0x").unwrap_or(raw);
+    decode_signed_transaction(&hex::decode(raw)?)
+}
+
+/// The hash of an EIP-2718 encoded signed transaction, which is the hash it
+/// is known by on chain. This does not decode the transaction, so it also
+/// works for types this SDK does not know.
+///
+/// Blob transactions must be passed without their sidecar, in the form
+/// they are signed in rather than the one they are broadcast in.
+#[must_use]
+pub fn tx_hash(raw: &[u8]) -> B256 {
+    keccak256(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{SignableTransaction, TxEip1559, TxLegacy};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{Address, Signature, U256};
+
+    use super::*;
+
+    #[test]
+    fn test_decode_signed_transaction_round_trips() {
+        let eip1559 = TxEip1559 {
+            chain_id: 1,
+            to: Address::repeat_byte(1).into(),
+            value: U256::from(100),
+            ..Default::default()
+        };
+        let legacy = TxLegacy {
+            chain_id: Some(1),
+            to: Address::repeat_byte(2).into(),
+            ..Default::default()
+        };
+
+        for envelope in [
+            TxEnvelope::from(eip1559.into_signed(Signature::test_signature())),
+            TxEnvelope::from(legacy.into_signed(Signature::test_signature())),
+        ] {
+            let raw = envelope.encoded_2718();
+            assert_eq!(decode_signed_transaction(&raw).unwrap(), envelope);
+            assert_eq!(
+                decode_signed_transaction_hex(&format!("0x{}", hex::encode(&raw))).unwrap(),
+                envelope
+            );
+            assert_eq!(tx_hash(&raw), *envelope.tx_hash());
+        }
+    }
+
+    #[test]
+    fn test_decode_signed_transaction_rejects_trailing_bytes() {
+        let envelope =
+            TxEnvelope::from(TxEip1559::default().into_signed(Signature::test_signature()));
+        let mut raw = envelope.encoded_2718();
+        raw.push(0);
+
+        assert!(matches!(
+            decode_signed_transaction(&raw),
+            Err(DecodeTransactionError::Decode(_))
+        ));
+        assert!(matches!(
+            decode_signed_transaction_hex("0xzz"),
+            Err(DecodeTransactionError::Hex(_))
+        ));
+    }
+}
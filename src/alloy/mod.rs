@@ -22,6 +22,9 @@
 //! # }
 //! ```
 
+pub(crate) mod blob;
+mod decode;
 mod signer;
 
+pub use decode::{decode_signed_transaction, decode_signed_transaction_hex, tx_hash};
 pub use signer::PrivyAlloyWallet;
@@ -1,9 +1,39 @@
+use std::{fmt::LowerHex, future::Future};
+
 use alloy_consensus::SignableTransaction;
 use alloy_network::{TxSigner, TxSignerSync};
 use alloy_primitives::{Address, B256, ChainId, Signature};
 use alloy_signer::{Result, Signer, SignerSync};
 
-use crate::{AuthorizationContext, subclients::WalletsClient};
+use crate::{
+    AuthorizationContext, TransactionRejected, WalletId,
+    generated::types::{
+        Quantity, UnsignedEthereumTransaction, UnsignedStandardEthereumTransaction,
+    },
+    interceptor::{InterceptedTransaction, TransactionRequest},
+    subclients::WalletsClient,
+};
+
+/// Run `future` to completion from synchronous code.
+fn block_on<T>(future: impl Future<Output = Result<T>>) -> Result<T> {
+    // todo: discuss implementation
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        tokio::task::block_in_place(|| handle.block_on(future))
+    } else {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| alloy_signer::Error::other(format!("Failed to build runtime: {e}")))?
+            .block_on(future)
+    }
+}
+
+fn quantity(value: impl LowerHex) -> Result<Quantity> {
+    format!("{value:#x}")
+        .parse()
+        .map(Quantity::Hex)
+        .map_err(alloy_signer::Error::other)
+}
 
 /// A Privy wallet that implements Alloy's signer traits
 ///
@@ -66,20 +96,78 @@ impl PrivyAlloyWallet {
     pub fn wallet_id(&self) -> &str {
         &self.wallet_id
     }
+
+    /// Describe `tx` for the client's interceptors, or `None` if it has
+    /// none.
+    fn transaction_request(
+        &self,
+        tx: &dyn SignableTransaction<Signature>,
+    ) -> Result<Option<TransactionRequest>> {
+        if self.wallets_client.interceptors().is_empty() {
+            return Ok(None);
+        }
+
+        let transaction = UnsignedStandardEthereumTransaction {
+            from: Some(self.address.to_string()),
+            to: tx.to().map(|to| to.to_string()),
+            value: Some(quantity(tx.value())?),
+            data: Some(
+                tx.input()
+                    .to_string()
+                    .parse()
+                    .map_err(alloy_signer::Error::other)?,
+            ),
+            chain_id: tx.chain_id().map(quantity).transpose()?,
+            nonce: Some(quantity(tx.nonce())?),
+            gas_limit: Some(quantity(tx.gas_limit())?),
+            gas_price: tx.gas_price().map(quantity).transpose()?,
+            max_fee_per_gas: tx
+                .is_dynamic_fee()
+                .then(|| quantity(tx.max_fee_per_gas()))
+                .transpose()?,
+            max_priority_fee_per_gas: tx.max_priority_fee_per_gas().map(quantity).transpose()?,
+            ..Default::default()
+        };
+
+        Ok(Some(TransactionRequest::new(
+            WalletId::new(&self.wallet_id),
+            tx.chain_id()
+                .or(self.chain_id)
+                .map(|id| format!("eip155:{id}")),
+            false,
+            InterceptedTransaction::Ethereum(Box::new(
+                UnsignedEthereumTransaction::StandardEthereumTransaction(transaction),
+            )),
+        )))
+    }
+
+    /// Run the client's interceptors on `request`. Alloy signs the hash of
+    /// the transaction, so interceptors can reject it but not change it.
+    async fn intercept(&self, mut request: TransactionRequest) -> Result<()> {
+        let snapshot = |request: &TransactionRequest| match &request.transaction {
+            InterceptedTransaction::Ethereum(tx) => serde_json::to_value(tx).ok(),
+            _ => None,
+        };
+
+        let before = snapshot(&request);
+        self.wallets_client
+            .interceptors()
+            .run(&mut request)
+            .await
+            .map_err(alloy_signer::Error::other)?;
+
+        if snapshot(&request) != before {
+            return Err(alloy_signer::Error::other(TransactionRejected::new(
+                "transactions signed through alloy cannot be modified by an interceptor",
+            )));
+        }
+        Ok(())
+    }
 }
 
 impl SignerSync for PrivyAlloyWallet {
     fn sign_hash_sync(&self, hash: &B256) -> Result<Signature> {
-        // todo: discuss implementation
-        if let Ok(handle) = tokio::runtime::Handle::try_current() {
-            tokio::task::block_in_place(|| handle.block_on(self.sign_hash(hash)))
-        } else {
-            tokio::runtime::Builder::new_current_thread()
-                .enable_all()
-                .build()
-                .map_err(|e| alloy_signer::Error::other(format!("Failed to build runtime: {e}")))?
-                .block_on(self.sign_hash(hash))
-        }
+        block_on(self.sign_hash(hash))
     }
 
     fn chain_id_sync(&self) -> Option<ChainId> {
@@ -96,9 +184,15 @@ impl TxSignerSync<Signature> for PrivyAlloyWallet {
         &self,
         tx: &mut dyn SignableTransaction<Signature>,
     ) -> Result<Signature> {
+        let request = self.transaction_request(tx)?;
         let sig_hash = tx.signature_hash();
 
-        self.sign_hash_sync(&sig_hash)
+        block_on(async {
+            if let Some(request) = request {
+                self.intercept(request).await?;
+            }
+            self.sign_hash(&sig_hash).await
+        })
     }
 }
 
@@ -111,7 +205,7 @@ impl Signer for PrivyAlloyWallet {
             .wallets_client
             .ethereum()
             .sign_secp256k1(
-                &self.wallet_id,
+                WalletId::new(&self.wallet_id),
                 &hash_hex,
                 &self.authorization_context,
                 Some(&hash_hex), // use digest as idempotency key
@@ -178,8 +272,12 @@ impl TxSigner<Signature> for PrivyAlloyWallet {
         &self,
         tx: &mut dyn SignableTransaction<Signature>,
     ) -> Result<Signature> {
+        let request = self.transaction_request(tx)?;
         let sig_hash = tx.signature_hash();
 
+        if let Some(request) = request {
+            self.intercept(request).await?;
+        }
         self.sign_hash(&sig_hash).await
     }
 }
@@ -272,4 +370,51 @@ mod tests {
         let wallet = create_test_wallet();
         assert_eq!(wallet.wallet_id(), "test_wallet_123");
     }
+
+    #[tokio::test]
+    async fn test_interceptors_can_reject_but_not_modify() {
+        use alloy_consensus::TxLegacy;
+        use alloy_primitives::TxKind;
+
+        use crate::interceptor::FnInterceptor;
+
+        let client = crate::PrivyClient::new("test_app_id".to_string(), "test_secret".to_string())
+            .expect("Failed to create test client")
+            .with_interceptor(FnInterceptor(|request: &mut TransactionRequest| {
+                let InterceptedTransaction::Ethereum(tx) = &mut request.transaction else {
+                    panic!("alloy transactions are ethereum transactions");
+                };
+                let UnsignedEthereumTransaction::StandardEthereumTransaction(tx) = &mut **tx else {
+                    panic!("alloy transactions are standard transactions");
+                };
+                assert_eq!(request.caip2.as_deref(), Some("eip155:1"));
+                if tx.to.is_none() {
+                    return Err(TransactionRejected::new("contract creation"));
+                }
+                tx.gas_limit = None;
+                Ok(())
+            }));
+        let wallet = PrivyAlloyWallet::new(
+            "test_wallet_123".to_string(),
+            address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045"),
+            client.wallets(),
+            AuthorizationContext::new(),
+        );
+
+        let mut create = TxLegacy {
+            chain_id: Some(1),
+            to: TxKind::Create,
+            ..Default::default()
+        };
+        let error = wallet.sign_transaction(&mut create).await.unwrap_err();
+        assert!(error.to_string().contains("contract creation"), "{error}");
+
+        let mut call = TxLegacy {
+            chain_id: Some(1),
+            to: TxKind::Call(Address::ZERO),
+            ..Default::default()
+        };
+        let error = wallet.sign_transaction(&mut call).await.unwrap_err();
+        assert!(error.to_string().contains("cannot be modified"), "{error}");
+    }
 }
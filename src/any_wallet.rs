@@ -0,0 +1,301 @@
+//! Dispatching on the chain of a wallet that is only known at runtime.
+//!
+//! [`PrivyClient::wallet_dyn`] fetches a wallet and pairs it with the
+//! service for its chain, so routing code can match on [`AnyWallet`]
+//! instead of looking up the wallet and switching on its `chain_type`
+//! itself.
+//!
+//! ```no_run
+//! # use privy_rs::{AuthorizationContext, PrivyClient, WalletId, any_wallet::AnyWallet};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let ctx = AuthorizationContext::new();
+//!
+//! match client.wallet_dyn(WalletId::new("wallet_id")).await? {
+//!     AnyWallet::Ethereum(wallet) => {
+//!         wallet.service().sign_message(wallet.id(), "hello", &ctx, None).await?;
+//!     }
+//!     AnyWallet::Solana(wallet) => {
+//!         wallet.service().sign_message(wallet.id(), "aGVsbG8=", &ctx, None).await?;
+//!     }
+//!     AnyWallet::Raw(wallet) => println!("no service for {}", wallet.chain_type),
+//!     AnyWallet::Unknown(wallet) => println!("unknown chain {}", wallet.chain_type),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! When the chain is known up front, [`TypedWallet::verified`] checks it
+//! as soon as the wallet is fetched, rather than failing later when the
+//! wallet is signed with:
+//!
+//! ```no_run
+//! # use privy_rs::{PrivyClient, WalletId, any_wallet::TypedWallet, ethereum::EthereumService};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let wallet = TypedWallet::<EthereumService>::verified(&client, WalletId::new("wallet_id")).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(feature = "ethereum")]
+use crate::ethereum::EthereumService;
+#[cfg(feature = "solana")]
+use crate::solana::SolanaService;
+#[cfg(feature = "alloy")]
+use crate::{AuthorizationContext, BlobTransactionError};
+use crate::{
+    PrivyApiError, PrivyClient, TypedWalletError,
+    chain::ChainType,
+    generated::types::{Wallet, WalletChainType},
+    ids::WalletId,
+    subclients::WalletsClient,
+};
+
+/// A service for the wallets of one chain, such as `EthereumService`.
+pub trait ChainService: Sized {
+    /// The chain type of the wallets this service works with.
+    const CHAIN_TYPE: WalletChainType;
+
+    /// The service for the wallets of `wallets`' client.
+    fn from_wallets(wallets: &WalletsClient) -> Self;
+}
+
+#[cfg(feature = "ethereum")]
+impl ChainService for EthereumService {
+    const CHAIN_TYPE: WalletChainType = WalletChainType::Ethereum;
+
+    fn from_wallets(wallets: &WalletsClient) -> Self {
+        wallets.ethereum()
+    }
+}
+
+#[cfg(feature = "solana")]
+impl ChainService for SolanaService {
+    const CHAIN_TYPE: WalletChainType = WalletChainType::Solana;
+
+    fn from_wallets(wallets: &WalletsClient) -> Self {
+        wallets.solana()
+    }
+}
+
+/// A wallet together with the service for its chain.
+pub struct TypedWallet<S> {
+    wallet: Wallet,
+    service: S,
+}
+
+impl<S: ChainService> TypedWallet<S> {
+    /// Fetch a wallet, checking that it is on the chain of `S`.
+    ///
+    /// # Errors
+    /// Fails if the wallet cannot be fetched, or with
+    /// `TypedWalletError::ChainMismatch` if it is on another chain.
+    pub async fn verified(
+        client: &PrivyClient,
+        wallet_id: impl Into<WalletId>,
+    ) -> Result<Self, TypedWalletError> {
+        let wallets = client.wallets();
+        let wallet = match wallets.get_any(&wallet_id.into()).await? {
+            Ok(wallet) if wallet.chain_type == S::CHAIN_TYPE => wallet,
+            Ok(wallet) => {
+                return Err(TypedWalletError::ChainMismatch {
+                    expected: S::CHAIN_TYPE,
+                    actual: wallet.chain_type.into(),
+                });
+            }
+            Err(unknown) => {
+                return Err(TypedWalletError::ChainMismatch {
+                    expected: S::CHAIN_TYPE,
+                    actual: unknown.chain_type,
+                });
+            }
+        };
+
+        Ok(Self {
+            wallet,
+            service: S::from_wallets(&wallets),
+        })
+    }
+}
+
+impl<S> TypedWallet<S> {
+    /// The id of the wallet.
+    #[must_use]
+    pub fn id(&self) -> WalletId {
+        WalletId::new(&self.wallet.id)
+    }
+
+    /// The on-chain address of the wallet.
+    #[must_use]
+    pub fn address(&self) -> &str {
+        &self.wallet.address
+    }
+
+    /// The wallet as returned by the API.
+    #[must_use]
+    pub fn wallet(&self) -> &Wallet {
+        &self.wallet
+    }
+
+    /// The service for the wallet's chain.
+    #[must_use]
+    pub fn service(&self) -> &S {
+        &self.service
+    }
+
+    /// Consume this, returning the wallet and the service.
+    #[must_use]
+    pub fn into_parts(self) -> (Wallet, S) {
+        (self.wallet, self.service)
+    }
+}
+
+#[cfg(feature = "alloy")]
+impl TypedWallet<EthereumService> {
+    /// Sign an EIP-4844 blob transaction with this wallet, see
+    /// `EthereumService::sign_blob_transaction`. The address of the wallet
+    /// is known, so unlike that method this makes no extra request.
+    ///
+    /// # Errors
+    /// Fails if the sidecar does not match the transaction, or if Privy does
+    /// not sign.
+    pub async fn sign_blob_transaction(
+        &self,
+        transaction: alloy_consensus::TxEip4844WithSidecar,
+        authorization_context: &AuthorizationContext,
+    ) -> Result<alloy_consensus::Signed<alloy_consensus::TxEip4844WithSidecar>, BlobTransactionError>
+    {
+        let signer = self
+            .service
+            .alloy_at(&self.id(), self.address(), authorization_context)?;
+        crate::alloy::blob::sign(&signer, transaction).await
+    }
+}
+
+/// A wallet on a chain type that the bundled spec does not list yet, so
+/// that it could not be deserialized into a `Wallet`. It can still be used
+/// through `WalletsClient::rpc` and `WalletsClient::raw_sign`.
+#[derive(Debug, Clone)]
+pub struct UnknownWallet {
+    /// The id of the wallet.
+    pub id: String,
+    /// The on-chain address of the wallet.
+    pub address: String,
+    /// The chain type of the wallet, a `ChainType::Other`.
+    pub chain_type: ChainType,
+    /// The wallet as returned by the API.
+    pub raw: serde_json::Value,
+}
+
+impl UnknownWallet {
+    /// Recover the wallet from a response that failed to deserialize
+    /// because of its chain type, rather than for any other reason.
+    pub(crate) fn from_error(error: &PrivyApiError) -> Option<Self> {
+        let PrivyApiError::InvalidResponsePayload(bytes, _) = error else {
+            return None;
+        };
+        let raw: serde_json::Value = serde_json::from_slice(bytes).ok()?;
+        let chain_type: ChainType = serde_json::from_value(raw["chain_type"].clone()).ok()?;
+        if chain_type.known().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            id: raw["id"].as_str()?.to_string(),
+            address: raw["address"].as_str()?.to_string(),
+            chain_type,
+            raw,
+        })
+    }
+}
+
+/// A wallet whose chain was determined at runtime.
+#[allow(clippy::large_enum_variant)]
+pub enum AnyWallet {
+    /// An ethereum wallet.
+    #[cfg(feature = "ethereum")]
+    Ethereum(TypedWallet<EthereumService>),
+    /// A solana wallet.
+    #[cfg(feature = "solana")]
+    Solana(TypedWallet<SolanaService>),
+    /// A wallet on a chain without a dedicated service, or whose service is
+    /// not enabled, which can still be used through `WalletsClient::rpc`
+    /// and `WalletsClient::raw_sign`.
+    Raw(Wallet),
+    /// A wallet on a chain type the bundled spec does not list yet.
+    Unknown(UnknownWallet),
+}
+
+impl AnyWallet {
+    /// The wallet as returned by the API, or `None` for an `Unknown`
+    /// wallet, whose response could not be deserialized.
+    #[must_use]
+    pub fn wallet(&self) -> Option<&Wallet> {
+        match self {
+            #[cfg(feature = "ethereum")]
+            Self::Ethereum(wallet) => Some(wallet.wallet()),
+            #[cfg(feature = "solana")]
+            Self::Solana(wallet) => Some(wallet.wallet()),
+            Self::Raw(wallet) => Some(wallet),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// The id of the wallet.
+    #[must_use]
+    pub fn id(&self) -> WalletId {
+        match self {
+            Self::Unknown(wallet) => WalletId::new(&wallet.id),
+            _ => WalletId::new(&self.known_wallet().id),
+        }
+    }
+
+    /// The chain type of the wallet.
+    #[must_use]
+    pub fn chain_type(&self) -> ChainType {
+        match self {
+            Self::Unknown(wallet) => wallet.chain_type.clone(),
+            _ => self.known_wallet().chain_type.into(),
+        }
+    }
+
+    fn known_wallet(&self) -> &Wallet {
+        self.wallet()
+            .expect("only unknown wallets have no `Wallet`")
+    }
+}
+
+impl PrivyClient {
+    /// Fetch a wallet and pair it with the service for its chain.
+    ///
+    /// Wallets on chain types that the bundled spec does not list yet are
+    /// returned as `AnyWallet::Unknown` rather than failing to deserialize.
+    ///
+    /// # Errors
+    /// Fails if the wallet cannot be fetched.
+    pub async fn wallet_dyn(
+        &self,
+        wallet_id: impl Into<WalletId>,
+    ) -> Result<AnyWallet, PrivyApiError> {
+        let wallets = self.wallets();
+        let wallet = match wallets.get_any(&wallet_id.into()).await? {
+            Ok(wallet) => wallet,
+            Err(unknown) => return Ok(AnyWallet::Unknown(unknown)),
+        };
+
+        Ok(match wallet.chain_type {
+            #[cfg(feature = "ethereum")]
+            WalletChainType::Ethereum => AnyWallet::Ethereum(TypedWallet {
+                wallet,
+                service: wallets.ethereum(),
+            }),
+            #[cfg(feature = "solana")]
+            WalletChainType::Solana => AnyWallet::Solana(TypedWallet {
+                wallet,
+                service: wallets.solana(),
+            }),
+            _ => AnyWallet::Raw(wallet),
+        })
+    }
+}
@@ -0,0 +1,6 @@
+//! Generates Kotlin and Swift bindings for the `uniffi` feature. See
+//! `privy_rs::mobile` for usage.
+
+fn main() {
+    uniffi::uniffi_bindgen_main()
+}
@@ -0,0 +1,86 @@
+//! Chain types that tolerate values this SDK does not know about yet.
+//!
+//! The generated types only accept the values in the bundled spec, so a
+//! wallet on a chain Privy added later fails to deserialize. The helpers
+//! of this crate use [`ChainType`] instead, which keeps such values as
+//! [`ChainType::Other`]:
+//!
+//! ```
+//! # use privy_rs::{chain::ChainType, generated::types::WalletChainType};
+//! let known: ChainType = "ethereum".parse().unwrap();
+//! assert_eq!(known, ChainType::Known(WalletChainType::Ethereum));
+//!
+//! let new: ChainType = "dogecoin".parse().unwrap();
+//! assert_eq!(new, ChainType::Other("dogecoin".to_string()));
+//! assert_eq!(new.to_string(), "dogecoin");
+//! ```
+
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use crate::generated::types::WalletChainType;
+
+/// The chain type of a wallet, see the [module docs](self).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChainType {
+    /// A chain type in the bundled spec.
+    Known(WalletChainType),
+    /// A chain type the bundled spec does not list, as returned by the API.
+    Other(String),
+}
+
+impl ChainType {
+    /// The known chain type, or `None` for `Other`.
+    #[must_use]
+    pub fn known(&self) -> Option<WalletChainType> {
+        match self {
+            ChainType::Known(chain_type) => Some(*chain_type),
+            ChainType::Other(_) => None,
+        }
+    }
+}
+
+impl From<WalletChainType> for ChainType {
+    fn from(chain_type: WalletChainType) -> Self {
+        ChainType::Known(chain_type)
+    }
+}
+
+impl FromStr for ChainType {
+    type Err = Infallible;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Ok(name
+            .parse()
+            .map_or_else(|_| ChainType::Other(name.to_string()), ChainType::Known))
+    }
+}
+
+/// The chain type as the API names it, e.g. `bitcoin-segwit`.
+impl fmt::Display for ChainType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainType::Known(chain_type) => chain_type.fmt(f),
+            ChainType::Other(name) => f.write_str(name),
+        }
+    }
+}
+
+impl PartialEq<WalletChainType> for ChainType {
+    fn eq(&self, other: &WalletChainType) -> bool {
+        self.known() == Some(*other)
+    }
+}
+
+impl serde::Serialize for ChainType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ChainType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(name.parse().unwrap_or_else(|never| match never {}))
+    }
+}
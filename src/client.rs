@@ -2,22 +2,49 @@
 //!
 //! This module contains the `PrivyClient` with typed wallet support.
 
-use std::{num::NonZeroUsize, time::Duration};
+use std::{
+    num::NonZeroUsize,
+    str::FromStr,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
 
 use reqwest::header::{CONTENT_TYPE, HeaderValue};
 
-use crate::{PrivyCreateError, generated::Client, get_auth_header, jwt_exchange::JwtExchange};
+use crate::{
+    AuthorizationContext, PrivateKey, PrivyApiError, PrivyCreateError,
+    connection::ConnectionOptions,
+    generated::{Client, ClientInfo},
+    get_auth_header,
+    jwt_exchange::JwtExchange,
+    request_options::RequestOptions,
+};
 
 const DEFAULT_BASE_URL: &str = "https://api.privy.io";
-const APP_ID_ENV_VAR: &str = "PRIVY_TEST_APP_ID";
-const APP_SECRET_ENV_VAR: &str = "PRIVY_TEST_APP_SECRET";
-const BASE_URL_ENV_VAR: &str = "PRIVY_TEST_URL";
+pub(crate) const APP_ID_ENV_VAR: &str = "PRIVY_TEST_APP_ID";
+pub(crate) const APP_SECRET_ENV_VAR: &str = "PRIVY_TEST_APP_SECRET";
+const BASE_URL_ENV_VAR: &str = "PRIVY_BASE_URL";
+pub(crate) const TEST_URL_ENV_VAR: &str = "PRIVY_TEST_URL";
+pub(crate) const CACHE_SIZE_ENV_VAR: &str = "PRIVY_CACHE_SIZE";
+pub(crate) const DRY_RUN_ENV_VAR: &str = "PRIVY_DRY_RUN";
+const TIMEOUT_ENV_VAR: &str = "PRIVY_TIMEOUT_MS";
+const CONNECT_TIMEOUT_ENV_VAR: &str = "PRIVY_CONNECT_TIMEOUT_MS";
+const RETRY_MAX_ATTEMPTS_ENV_VAR: &str = "PRIVY_RETRY_MAX_ATTEMPTS";
+const RETRY_BASE_DELAY_ENV_VAR: &str = "PRIVY_RETRY_BASE_DELAY_MS";
+const RETRY_MAX_DELAY_ENV_VAR: &str = "PRIVY_RETRY_MAX_DELAY_MS";
+const AUTHORIZATION_KEY_ENV_VAR: &str = "PRIVY_AUTHORIZATION_KEY";
+const AUTHORIZATION_KEY_FILE_ENV_VAR: &str = "PRIVY_AUTHORIZATION_KEY_FILE";
+const CLIENT_VERSION: &str = concat!("rust:", env!("CARGO_PKG_VERSION"));
+
+/// The process-wide client, see `PrivyClient::init_global`.
+static GLOBAL: OnceLock<PrivyClient> = OnceLock::new();
+const DEFAULT_SLOW_CALL_THRESHOLD: Duration = Duration::from_secs(5);
 
 /// Privy client for interacting with the Privy API.
 ///
 /// This provides access to global operations like user and wallet management.
 /// For wallet-specific operations, use `TypedWallet<T>` instances created via
-/// the `wallet()` method.
+/// `TypedWallet::verified` or `PrivyClient::wallet_dyn`.
 ///
 /// # Errors
 ///
@@ -26,21 +53,103 @@ const BASE_URL_ENV_VAR: &str = "PRIVY_TEST_URL";
 #[derive(Clone, Debug)]
 pub struct PrivyClient {
     pub(crate) app_id: String,
-    #[allow(dead_code)]
-    pub(crate) app_secret: String,
+    pub(crate) app_secret: crate::SecretString,
     pub(crate) base_url: String,
     pub(crate) client: Client,
+    pub(crate) resolver: Option<crate::dns::PinnedResolver>,
+    pub(crate) connection: ConnectionOptions,
+    pub(crate) lifetime: crate::maintenance::Lifetime,
+    pub(crate) shared: Arc<ClientShared>,
+    pub(crate) verification_key: crate::identity::VerificationKey,
+    #[cfg(feature = "config")]
+    pub(crate) config_provenance: Option<Arc<crate::config_sources::ConfigProvenance>>,
+    pub(crate) default_authorization: Option<AuthorizationContext>,
 
     /// A store of all jwt operations for this client
     pub jwt_exchange: JwtExchange,
 }
 
+/// What a client shares with the subclients created from it, such as
+/// `PrivyClient::wallets`, so that their calls behave like its own.
+#[derive(Clone, Debug)]
+pub(crate) struct ClientShared {
+    /// See `PrivyClientOptions::dry_run`.
+    pub(crate) dry_run: bool,
+    /// See `PrivyClient::with_interceptor`.
+    pub(crate) interceptors: crate::interceptor::Interceptors,
+    /// See `KeyQuorumsClient::create_named`.
+    pub(crate) key_quorum_names: crate::subclients::KeyQuorumNames,
+    /// See `PrivyClient::latency`.
+    pub(crate) latency: crate::latency::LatencyTracker,
+    /// See `PrivyClientOptions::with_middleware`.
+    pub(crate) middleware: crate::middleware::Middleware,
+    /// See `PrivyClient::with_request_options`.
+    pub(crate) request_options: RequestOptions,
+    /// See `PrivyClient::with_response_sink`.
+    pub(crate) response_sinks: crate::response_sink::ResponseSinks,
+    /// See `PrivyClientOptions::retry`.
+    pub(crate) retry: Option<crate::retry::RetryPolicy>,
+    /// See `PrivyClientOptions::service_status`.
+    pub(crate) service_status: crate::service_status::StatusTracker,
+    /// See `PrivyClient::with_transaction_transport`.
+    pub(crate) transport: crate::transport::Transport,
+    /// See `PrivyClient::with_wallet_hook`.
+    pub(crate) wallet_hooks: crate::wallet_hooks::WalletHooks,
+}
+
+impl Default for ClientShared {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            interceptors: Default::default(),
+            key_quorum_names: Default::default(),
+            latency: crate::latency::LatencyTracker::new(None),
+            middleware: Default::default(),
+            request_options: Default::default(),
+            response_sinks: Default::default(),
+            retry: None,
+            service_status: Default::default(),
+            transport: Default::default(),
+            wallet_hooks: Default::default(),
+        }
+    }
+}
+
 /// Options for configuring a `PrivyClient`
 pub struct PrivyClientOptions {
     /// The maximum number of cached JWT secret keys to store
     pub cache_size: NonZeroUsize,
     /// The base url to use when making requests
     pub base_url: String,
+    /// Log calls that change anything instead of sending them. Wallet rpc
+    /// and raw sign calls return synthesized success responses with
+    /// deterministic fake signatures and transaction hashes, while every
+    /// other mutating call, such as creating, updating or exporting a
+    /// wallet, a policy, a key quorum or a user, fails with an
+    /// `InvalidRequest` error. Authorization signatures are still
+    /// generated, so misconfigured keys are caught. Reads, including user
+    /// lookups and searches, are sent as normal.
+    pub dry_run: bool,
+    /// Pin hosts to fixed IP addresses instead of resolving them through
+    /// DNS. See [`PinnedResolver`](crate::dns::PinnedResolver).
+    pub resolver: Option<crate::dns::PinnedResolver>,
+    /// Warn when the average latency of an API endpoint rises above this,
+    /// see the [`latency`](crate::latency) module. `None` disables the
+    /// warnings; latencies are tracked either way.
+    pub slow_call_threshold: Option<Duration>,
+    /// How connections to the API are opened and kept, and how long
+    /// requests may take, see the [`connection`](crate::connection) module.
+    pub connection: ConnectionOptions,
+    /// Retry rate limited and failed API calls, see the
+    /// [`retry`](crate::retry) module. `None` disables retries.
+    pub retry: Option<crate::retry::RetryPolicy>,
+    /// Hooks around every request, see the
+    /// [`middleware`](crate::middleware) module.
+    pub middleware: crate::middleware::Middleware,
+    /// Poll Privy's status page, and note ongoing incidents on failed
+    /// calls, see the [`service_status`](crate::service_status) module.
+    /// `None` disables polling.
+    pub service_status: Option<crate::service_status::ServiceStatusOptions>,
 }
 
 impl Default for PrivyClientOptions {
@@ -48,10 +157,173 @@ impl Default for PrivyClientOptions {
         Self {
             cache_size: NonZeroUsize::new(1000).expect("non-zero"),
             base_url: String::from(DEFAULT_BASE_URL),
+            dry_run: false,
+            resolver: None,
+            slow_call_threshold: Some(DEFAULT_SLOW_CALL_THRESHOLD),
+            connection: ConnectionOptions::default(),
+            retry: None,
+            middleware: Default::default(),
+            service_status: None,
         }
     }
 }
 
+/// The configuration of a `PrivyClient`, as returned by
+/// `PrivyClient::options`, for logging it or routing between the clients of
+/// several apps. The app secret is masked.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ClientConfig {
+    /// The app id.
+    pub app_id: String,
+    /// The app secret masked but for its last four characters, such as
+    /// `****1a2b`, enough to tell secrets apart without revealing them.
+    pub app_secret: String,
+    /// The base url requests are made to.
+    pub base_url: String,
+    /// See `PrivyClientOptions::cache_size`.
+    pub cache_size: NonZeroUsize,
+    /// See `PrivyClientOptions::dry_run`.
+    pub dry_run: bool,
+    /// See `PrivyClientOptions::resolver`.
+    pub resolver: Option<crate::dns::PinnedResolver>,
+    /// See `PrivyClientOptions::slow_call_threshold`.
+    pub slow_call_threshold: Option<Duration>,
+    /// See `PrivyClientOptions::connection`.
+    pub connection: ConnectionOptions,
+    /// See `PrivyClientOptions::retry`.
+    pub retry: Option<crate::retry::RetryPolicy>,
+    /// See `PrivyClientOptions::service_status`.
+    pub service_status: Option<crate::service_status::ServiceStatusOptions>,
+}
+
+/// Mask all but the last four characters of `secret`, or all of it if it
+/// is too short for that to hide most of it.
+fn mask_secret(secret: &str) -> String {
+    let chars: Vec<char> = secret.chars().collect();
+    if chars.len() < 16 {
+        return "****".to_string();
+    }
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("****{tail}")
+}
+
+impl PrivyClientOptions {
+    /// Run `hook` around every request, after any hooks added before it.
+    /// See the [`middleware`](crate::middleware) module for details.
+    #[must_use]
+    pub fn with_middleware(
+        mut self,
+        hook: impl crate::middleware::RequestHook + Send + Sync + 'static,
+    ) -> Self {
+        self.middleware.push(hook);
+        self
+    }
+}
+
+/// Read the authorization key for `PrivyClient::quickstart`, checking that
+/// it parses so a bad key fails at startup rather than on the first request.
+fn authorization_key_from(
+    var: impl Fn(&str) -> Option<String>,
+) -> Result<PrivateKey, PrivyCreateError> {
+    let pem = match (
+        var(AUTHORIZATION_KEY_ENV_VAR),
+        var(AUTHORIZATION_KEY_FILE_ENV_VAR),
+    ) {
+        (Some(pem), _) => pem,
+        (None, Some(path)) => {
+            std::fs::read_to_string(path).map_err(PrivyCreateError::AuthorizationKeyFile)?
+        }
+        (None, None) => return Err(PrivyCreateError::MissingAuthorizationKey),
+    };
+    let key = PrivateKey::new(pem);
+    key.parse()
+        .map_err(|_| PrivyCreateError::InvalidAuthorizationKey)?;
+    Ok(key)
+}
+
+/// Parse the variable `name`, if it is set.
+fn parse_var<T: FromStr>(
+    var: &impl Fn(&str) -> Option<String>,
+    name: &'static str,
+) -> Result<Option<T>, PrivyCreateError> {
+    var(name)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| PrivyCreateError::InvalidEnvVar { var: name, value })
+        })
+        .transpose()
+}
+
+/// Read the options for `PrivyClient::new_from_env`.
+fn options_from_env(
+    var: &impl Fn(&str) -> Option<String>,
+) -> Result<PrivyClientOptions, PrivyCreateError> {
+    let millis = |name| parse_var(var, name).map(|ms| ms.map(Duration::from_millis));
+    let defaults = PrivyClientOptions::default();
+
+    let mut connection = defaults.connection;
+    if let Some(timeout) = millis(TIMEOUT_ENV_VAR)? {
+        connection.timeout = Some(timeout);
+    }
+    if let Some(connect_timeout) = millis(CONNECT_TIMEOUT_ENV_VAR)? {
+        connection.connect_timeout = connect_timeout;
+    }
+
+    let max_attempts = parse_var(var, RETRY_MAX_ATTEMPTS_ENV_VAR)?;
+    let base_delay = millis(RETRY_BASE_DELAY_ENV_VAR)?;
+    let max_delay = millis(RETRY_MAX_DELAY_ENV_VAR)?;
+    let retry = if max_attempts.is_some() || base_delay.is_some() || max_delay.is_some() {
+        let policy = crate::retry::RetryPolicy::default();
+        Some(crate::retry::RetryPolicy {
+            max_attempts: max_attempts.unwrap_or(policy.max_attempts),
+            base_delay: base_delay.unwrap_or(policy.base_delay),
+            max_delay: max_delay.unwrap_or(policy.max_delay),
+            ..policy
+        })
+    } else {
+        defaults.retry
+    };
+
+    Ok(PrivyClientOptions {
+        base_url: var(BASE_URL_ENV_VAR)
+            .or_else(|| var(TEST_URL_ENV_VAR))
+            .unwrap_or(defaults.base_url),
+        cache_size: parse_var(var, CACHE_SIZE_ENV_VAR)?.unwrap_or(defaults.cache_size),
+        dry_run: parse_var(var, DRY_RUN_ENV_VAR)?.unwrap_or(defaults.dry_run),
+        connection,
+        retry,
+        ..defaults
+    })
+}
+
+/// The HTTP client the generated client sends requests with, carrying the
+/// credentials and any extra headers as default headers.
+fn http_client(
+    app_id: &str,
+    app_secret: &str,
+    resolver: Option<&crate::dns::PinnedResolver>,
+    connection: &ConnectionOptions,
+    options: &RequestOptions,
+) -> Result<reqwest::Client, PrivyCreateError> {
+    let mut headers = options.headers().clone();
+    headers.insert(
+        reqwest::header::AUTHORIZATION,
+        HeaderValue::from_str(&get_auth_header(app_id, app_secret))?,
+    );
+    headers.insert("privy-app-id", HeaderValue::from_str(app_id)?);
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    headers.insert("privy-client", HeaderValue::from_static(CLIENT_VERSION));
+
+    let mut builder = reqwest::ClientBuilder::new().default_headers(headers);
+    builder = connection.apply(builder)?;
+    if let Some(resolver) = resolver {
+        builder = builder.dns_resolver(Arc::new(resolver.clone()));
+    }
+    Ok(builder.build()?)
+}
+
 impl PrivyClient {
     /// Create a new `PrivyClient`
     ///
@@ -73,25 +345,89 @@ impl PrivyClient {
         Self::new_with_options(app_id, app_secret, PrivyClientOptions::default())
     }
 
-    /// Create a new `PrivyClient` from environment variables
+    /// Create a new `PrivyClient` from environment variables, so that a
+    /// deployment can be configured without code changes.
+    ///
+    /// | variable                       | sets                                  |
+    /// |--------------------------------|---------------------------------------|
+    /// | `PRIVY_TEST_APP_ID`            | the app id, required                  |
+    /// | `PRIVY_TEST_APP_SECRET`        | the app secret, required              |
+    /// | `PRIVY_BASE_URL`               | `base_url`                            |
+    /// | `PRIVY_TEST_URL`               | `base_url`, below `PRIVY_BASE_URL`    |
+    /// | `PRIVY_CACHE_SIZE`             | `cache_size`                          |
+    /// | `PRIVY_DRY_RUN`                | `dry_run`, `true` or `false`          |
+    /// | `PRIVY_TIMEOUT_MS`             | `connection.timeout`                  |
+    /// | `PRIVY_CONNECT_TIMEOUT_MS`     | `connection.connect_timeout`          |
+    /// | `PRIVY_RETRY_MAX_ATTEMPTS`     | `retry.max_attempts`                  |
+    /// | `PRIVY_RETRY_BASE_DELAY_MS`    | `retry.base_delay`                    |
+    /// | `PRIVY_RETRY_MAX_DELAY_MS`     | `retry.max_delay`                     |
+    /// | `PRIVY_AUTHORIZATION_KEY`      | the default authorization key, a PEM  |
+    /// | `PRIVY_AUTHORIZATION_KEY_FILE` | its path, below `PRIVY_AUTHORIZATION_KEY` |
+    ///
+    /// Unset variables keep the defaults of `PrivyClientOptions`. Setting any
+    /// of the `PRIVY_RETRY_*` variables enables retries, with the defaults
+    /// of `RetryPolicy` for the others. The authorization key is parsed up
+    /// front, and is available from
+    /// [`PrivyClient::default_authorization_context`].
+    ///
+    /// To also read a config file, or override some of these in code, use
+    /// `PrivyClient::from_config_sources` instead.
     ///
     /// # Errors
-    /// This can fail for three reasons, either the `app_id` or `app_secret` are not
-    /// valid headers, or that the underlying http client could not be created, or
-    /// that the environment variables are not set.
+    /// This can fail if the app id or app secret is not set or not a valid
+    /// header, if a variable holds an invalid value, if the authorization
+    /// key can not be read or parsed, or if the underlying http client could
+    /// not be created.
     pub fn new_from_env() -> Result<Self, PrivyCreateError> {
-        let app_id = std::env::var(APP_ID_ENV_VAR).map_err(|_| PrivyCreateError::InvalidAppId)?;
-        let app_secret =
-            std::env::var(APP_SECRET_ENV_VAR).map_err(|_| PrivyCreateError::InvalidAppSecret)?;
-        Self::new_with_options(
-            app_id,
-            app_secret,
-            PrivyClientOptions {
-                base_url: std::env::var(BASE_URL_ENV_VAR)
-                    .unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
-                ..PrivyClientOptions::default()
-            },
-        )
+        Self::from_env_vars(|var| std::env::var(var).ok())
+    }
+
+    /// `new_from_env`, reading variables with `var`.
+    fn from_env_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self, PrivyCreateError> {
+        let app_id = var(APP_ID_ENV_VAR).ok_or(PrivyCreateError::InvalidAppId)?;
+        let app_secret = var(APP_SECRET_ENV_VAR).ok_or(PrivyCreateError::InvalidAppSecret)?;
+        let mut client = Self::new_with_options(app_id, app_secret, options_from_env(&var)?)?;
+        client.default_authorization = match authorization_key_from(&var) {
+            Ok(key) => Some(AuthorizationContext::new().push(key)),
+            Err(PrivyCreateError::MissingAuthorizationKey) => None,
+            Err(e) => return Err(e),
+        };
+        Ok(client)
+    }
+
+    /// Create a `PrivyClient` from environment variables, like
+    /// [`PrivyClient::new_from_env`], along with an `AuthorizationContext`
+    /// holding the PEM-encoded key in `PRIVY_AUTHORIZATION_KEY`, or in the
+    /// file named by `PRIVY_AUTHORIZATION_KEY_FILE`.
+    ///
+    /// ```no_run
+    /// # use privy_rs::{PrivyClient, WalletId};
+    /// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (client, ctx) = PrivyClient::quickstart()?;
+    /// let signature = client
+    ///     .wallets()
+    ///     .sign_message_auto(WalletId::new("wallet_id"), b"Hello, Privy!", &ctx)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Fails like `new_from_env`, or if neither variable is set.
+    pub fn quickstart() -> Result<(Self, AuthorizationContext), PrivyCreateError> {
+        let client = Self::new_from_env()?;
+        let ctx = client
+            .default_authorization
+            .clone()
+            .ok_or(PrivyCreateError::MissingAuthorizationKey)?;
+        Ok((client, ctx))
+    }
+
+    /// The authorization key read from the environment by
+    /// `PrivyClient::new_from_env`, if one was set.
+    #[must_use]
+    pub fn default_authorization_context(&self) -> Option<&AuthorizationContext> {
+        self.default_authorization.as_ref()
     }
 
     /// Create a new `PrivyClient` with a custom url
@@ -104,34 +440,169 @@ impl PrivyClient {
         app_secret: String,
         options: PrivyClientOptions,
     ) -> Result<Self, PrivyCreateError> {
-        let client_version = concat!("rust:", env!("CARGO_PKG_VERSION"));
+        tracing::debug!("Privy client version: {}", CLIENT_VERSION);
 
-        tracing::debug!("Privy client version: {}", client_version);
-
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::AUTHORIZATION,
-            HeaderValue::from_str(&get_auth_header(&app_id, &app_secret))?,
-        );
-        headers.insert("privy-app-id", HeaderValue::from_str(&app_id)?);
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        headers.insert("privy-client", HeaderValue::from_static(client_version));
-
-        let client_with_custom_defaults = reqwest::ClientBuilder::new()
-            .connect_timeout(Duration::from_secs(15))
-            .timeout(Duration::from_secs(15))
-            .default_headers(headers)
-            .build()?;
+        let request_options = RequestOptions::default();
+        let client = http_client(
+            &app_id,
+            &app_secret,
+            options.resolver.as_ref(),
+            &options.connection,
+            &request_options,
+        )?;
 
         Ok(Self {
             app_id,
-            app_secret,
-            client: Client::new_with_client(&options.base_url, client_with_custom_defaults),
+            app_secret: app_secret.into(),
+            client: Client::new_with_client(
+                &options.base_url,
+                client,
+                options.middleware.request_hook(),
+            ),
             base_url: options.base_url,
+            resolver: options.resolver,
+            connection: options.connection,
+            lifetime: Default::default(),
             jwt_exchange: JwtExchange::new(options.cache_size),
+            shared: Arc::new(ClientShared {
+                dry_run: options.dry_run,
+                latency: crate::latency::LatencyTracker::new(options.slow_call_threshold),
+                middleware: options.middleware,
+                request_options,
+                retry: options.retry,
+                service_status: crate::service_status::StatusTracker::new(options.service_status),
+                ..Default::default()
+            }),
+            verification_key: Default::default(),
+            #[cfg(feature = "config")]
+            config_provenance: None,
+            default_authorization: None,
         })
     }
 
+    /// Create a `PrivyClient` from explicit values, environment variables,
+    /// a `privy.toml` file and defaults, in that order of precedence. See
+    /// the [`config_sources`](crate::config_sources) module for details.
+    ///
+    /// # Feature Flag
+    /// Requires the `config` feature to be enabled.
+    ///
+    /// # Errors
+    /// Fails like `new_with_options`, if no source sets the app id or app
+    /// secret, if the config file can not be read or parsed, or if an
+    /// environment variable holds an invalid value.
+    #[cfg(feature = "config")]
+    pub fn from_config_sources(
+        sources: crate::config_sources::ConfigSources,
+    ) -> Result<Self, PrivyCreateError> {
+        let resolved = sources.resolve(|var| std::env::var(var).ok())?;
+        let mut client =
+            Self::new_with_options(resolved.app_id, resolved.app_secret, resolved.options)?;
+        client.config_provenance = Some(Arc::new(resolved.provenance));
+        Ok(client)
+    }
+
+    /// Where each config value of this client came from, if it was created
+    /// with `PrivyClient::from_config_sources`.
+    ///
+    /// # Feature Flag
+    /// Requires the `config` feature to be enabled.
+    #[cfg(feature = "config")]
+    pub fn config_provenance(&self) -> Option<&crate::config_sources::ConfigProvenance> {
+        self.config_provenance.as_deref()
+    }
+
+    /// Send the headers in `options` with every request of this client,
+    /// replacing any set before. See the
+    /// [`request_options`](crate::request_options) module for details.
+    ///
+    /// This builds a new HTTP client, so set the options once and reuse the
+    /// result rather than calling this per request. Options for the calls
+    /// of one subclient are set with its `with_request_options` instead.
+    ///
+    /// # Errors
+    /// If the HTTP client could not be created.
+    pub fn with_request_options(
+        mut self,
+        options: RequestOptions,
+    ) -> Result<Self, PrivyCreateError> {
+        let client = http_client(
+            &self.app_id,
+            self.app_secret.expose(),
+            self.resolver.as_ref(),
+            &self.connection,
+            &options,
+        )?;
+        self.client = Client::new_with_client(
+            &self.base_url,
+            client,
+            self.shared.middleware.request_hook(),
+        );
+        Arc::make_mut(&mut self.shared).request_options = options;
+        Ok(self)
+    }
+
+    /// Run every transaction signed through this client past `interceptor`
+    /// first, after any interceptors added before it. See the
+    /// [`interceptor`](crate::interceptor) module for details.
+    #[must_use]
+    pub fn with_interceptor(
+        mut self,
+        interceptor: impl crate::interceptor::TransactionInterceptor + Send + Sync + 'static,
+    ) -> Self {
+        Arc::make_mut(&mut self.shared)
+            .interceptors
+            .push(interceptor);
+        self
+    }
+
+    /// Record the response of every wallet action sent through this client
+    /// in `sink`, in addition to any sinks added before it. See the
+    /// [`response_sink`](crate::response_sink) module for details.
+    #[must_use]
+    pub fn with_response_sink(
+        mut self,
+        sink: impl crate::response_sink::ResponseSink + Send + Sync + 'static,
+    ) -> Self {
+        Arc::make_mut(&mut self.shared).response_sinks.push(sink);
+        self
+    }
+
+    /// Tell `hook` about every wallet created, imported, updated or
+    /// exported through this client, after any hooks added before it. See
+    /// the [`wallet_hooks`](crate::wallet_hooks) module for details.
+    #[must_use]
+    pub fn with_wallet_hook(
+        mut self,
+        hook: impl crate::wallet_hooks::WalletLifecycleHook + Send + Sync + 'static,
+    ) -> Self {
+        Arc::make_mut(&mut self.shared).wallet_hooks.push(hook);
+        self
+    }
+
+    /// Open up to `n` connections to the API ahead of the first requests,
+    /// and leave them idle in the pool for those requests to use, see the
+    /// [`connection`](crate::connection) module.
+    ///
+    /// This sends `n` concurrent `HEAD` requests to the base url. Over
+    /// HTTP/2, which the API negotiates when it can, concurrent requests
+    /// share a connection, so a single connection is opened whatever `n`.
+    /// Connections beyond `ConnectionOptions::pool_max_idle_per_host` are
+    /// closed once their request completes.
+    ///
+    /// # Errors
+    /// If a connection could not be opened. The status the API answers the
+    /// `HEAD` requests with does not matter.
+    pub async fn warm_connections(&self, n: usize) -> Result<(), PrivyApiError> {
+        let http = self.client.client();
+        let warm_ups = (0..n).map(|_| http.head(&self.base_url).send());
+        let responses = futures::future::try_join_all(warm_ups)
+            .await
+            .map_err(PrivyApiError::CommunicationError)?;
+        tracing::debug!("warmed up {} connections", responses.len());
+        Ok(())
+    }
+
     /// Returns a new [`Utils`] instance
     #[must_use]
     pub fn utils(&self) -> crate::utils::Utils {
@@ -141,12 +612,255 @@ impl PrivyClient {
     }
 
     /// Returns the app id for the client
+    #[must_use]
     pub fn app_id(&self) -> &str {
         &self.app_id
     }
 
     /// Returns the base url for the client
+    #[must_use]
     pub fn base_url(&self) -> &str {
         &self.base_url
     }
+
+    /// Returns the configuration of the client, with the app secret
+    /// masked, see `ClientConfig`.
+    ///
+    /// ```
+    /// # use privy_rs::PrivyClient;
+    /// # fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PrivyClient::new("app_id".into(), "privy_app_secret_1a2b".into())?;
+    /// let options = client.options();
+    /// assert_eq!(options.app_secret, "****1a2b");
+    /// tracing::info!(?options, "created the privy client");
+    /// # Ok(())
+    /// # }
+    /// # foo().unwrap();
+    /// ```
+    #[must_use]
+    pub fn options(&self) -> ClientConfig {
+        ClientConfig {
+            app_id: self.app_id.clone(),
+            app_secret: mask_secret(self.app_secret.expose()),
+            base_url: self.base_url.clone(),
+            cache_size: self.jwt_exchange.capacity(),
+            dry_run: self.shared.dry_run,
+            resolver: self.resolver.clone(),
+            slow_call_threshold: self.shared.latency.threshold(),
+            connection: self.connection.clone(),
+            retry: self.shared.retry.clone(),
+            service_status: self.shared.service_status.options().cloned(),
+        }
+    }
+
+    /// Make `client` the process-wide client returned by
+    /// `PrivyClient::global`, so that libraries built on this SDK can use
+    /// the client the application configured without it being passed to
+    /// them.
+    ///
+    /// The global client can be set once. Clones of a client share their
+    /// connection pool and JWT cache, so the application can keep using
+    /// its own handle alongside the global one.
+    ///
+    /// ```no_run
+    /// # use privy_rs::PrivyClient;
+    /// # fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// // at startup
+    /// PrivyClient::init_global(PrivyClient::new_from_env()?).ok();
+    ///
+    /// // in a library
+    /// let client = PrivyClient::global().ok_or("privy client not initialized")?;
+    /// let wallets = client.wallets();
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// If a global client was already set, giving `client` back, boxed.
+    pub fn init_global(client: PrivyClient) -> Result<(), Box<PrivyClient>> {
+        GLOBAL.set(client).map_err(Box::new)
+    }
+
+    /// The process-wide client set with `PrivyClient::init_global`, or
+    /// `None` if it was not set yet.
+    #[must_use]
+    pub fn global() -> Option<&'static PrivyClient> {
+        GLOBAL.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    const TEST_PRIVATE_KEY_PEM: &str = include_str!("../tests/test_private_key.pem");
+
+    fn env<'a>(vars: &'a HashMap<&str, String>) -> impl Fn(&str) -> Option<String> + 'a {
+        |var| vars.get(var).cloned()
+    }
+
+    #[test]
+    fn test_options_mask_the_app_secret() {
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "privy_app_secret_0123456789abcdef".to_string(),
+            PrivyClientOptions {
+                base_url: "http://localhost:1234".to_string(),
+                dry_run: true,
+                slow_call_threshold: None,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let options = client.options();
+        assert_eq!(options.app_id, "app_id");
+        assert_eq!(options.base_url, "http://localhost:1234");
+        assert_eq!(options.app_secret, "****cdef");
+        assert!(options.dry_run);
+        assert_eq!(options.slow_call_threshold, None);
+        assert_eq!(options.cache_size.get(), 1000);
+        assert!(!format!("{options:?}").contains("0123456789"));
+
+        assert_eq!(mask_secret("short"), "****");
+    }
+
+    #[test]
+    fn test_authorization_key_is_read_from_env_or_file() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), TEST_PRIVATE_KEY_PEM).unwrap();
+        let path = file.path().display().to_string();
+
+        let inline = HashMap::from([(AUTHORIZATION_KEY_ENV_VAR, TEST_PRIVATE_KEY_PEM.to_string())]);
+        assert!(authorization_key_from(env(&inline)).is_ok());
+
+        let from_file = HashMap::from([(AUTHORIZATION_KEY_FILE_ENV_VAR, path)]);
+        assert!(authorization_key_from(env(&from_file)).is_ok());
+
+        let missing = HashMap::from([(
+            AUTHORIZATION_KEY_FILE_ENV_VAR,
+            "/no/such/key.pem".to_string(),
+        )]);
+        assert!(matches!(
+            authorization_key_from(env(&missing)),
+            Err(PrivyCreateError::AuthorizationKeyFile(_))
+        ));
+
+        let invalid = HashMap::from([(AUTHORIZATION_KEY_ENV_VAR, "not a key".to_string())]);
+        assert!(matches!(
+            authorization_key_from(env(&invalid)),
+            Err(PrivyCreateError::InvalidAuthorizationKey)
+        ));
+
+        assert!(matches!(
+            authorization_key_from(env(&HashMap::new())),
+            Err(PrivyCreateError::MissingAuthorizationKey)
+        ));
+    }
+
+    #[test]
+    fn test_options_are_read_from_env() {
+        let vars = HashMap::from([
+            (APP_ID_ENV_VAR, "app_id".to_string()),
+            (APP_SECRET_ENV_VAR, "app_secret".to_string()),
+            (BASE_URL_ENV_VAR, "https://privy.internal".to_string()),
+            (TEST_URL_ENV_VAR, "https://ignored".to_string()),
+            (DRY_RUN_ENV_VAR, "true".to_string()),
+            (TIMEOUT_ENV_VAR, "2500".to_string()),
+            (RETRY_MAX_ATTEMPTS_ENV_VAR, "5".to_string()),
+            (AUTHORIZATION_KEY_ENV_VAR, TEST_PRIVATE_KEY_PEM.to_string()),
+        ]);
+        let client = PrivyClient::from_env_vars(env(&vars)).unwrap();
+        let options = client.options();
+        assert_eq!(options.base_url, "https://privy.internal");
+        assert!(options.dry_run);
+        assert_eq!(
+            options.connection.timeout,
+            Some(Duration::from_millis(2500))
+        );
+        let retry = options.retry.unwrap();
+        assert_eq!(retry.max_attempts, 5);
+        assert_eq!(
+            retry.base_delay,
+            crate::retry::RetryPolicy::default().base_delay
+        );
+        assert!(client.default_authorization_context().is_some());
+
+        let minimal = HashMap::from([
+            (APP_ID_ENV_VAR, "app_id".to_string()),
+            (APP_SECRET_ENV_VAR, "app_secret".to_string()),
+        ]);
+        let client = PrivyClient::from_env_vars(env(&minimal)).unwrap();
+        assert_eq!(client.base_url(), DEFAULT_BASE_URL);
+        assert!(client.options().retry.is_none());
+        assert!(client.default_authorization_context().is_none());
+
+        let mut invalid = minimal.clone();
+        invalid.insert(TIMEOUT_ENV_VAR, "30s".to_string());
+        assert!(matches!(
+            PrivyClient::from_env_vars(env(&invalid)),
+            Err(PrivyCreateError::InvalidEnvVar {
+                var: TIMEOUT_ENV_VAR,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_global_client_is_set_once() {
+        let client = |app_id: &str| PrivyClient::new(app_id.into(), "app_secret".into()).unwrap();
+        assert!(PrivyClient::global().is_none());
+
+        PrivyClient::init_global(client("first")).unwrap();
+        let rejected = PrivyClient::init_global(client("second")).unwrap_err();
+        assert_eq!(rejected.app_id(), "second");
+        assert_eq!(
+            PrivyClient::global().map(PrivyClient::app_id),
+            Some("first")
+        );
+
+        // every thread sees the same client
+        std::thread::spawn(|| assert_eq!(PrivyClient::global().unwrap().app_id(), "first"))
+            .join()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_warm_connections_opens_pooled_connections() {
+        let server = httpmock::MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(httpmock::Method::HEAD).path("/");
+                then.status(404);
+            })
+            .await;
+        let client = PrivyClient::new_with_options(
+            "app_id".into(),
+            "app_secret".into(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        client.warm_connections(3).await.unwrap();
+        assert_eq!(mock.calls_async().await, 3);
+
+        let unreachable = PrivyClient::new_with_options(
+            "app_id".into(),
+            "app_secret".into(),
+            PrivyClientOptions {
+                base_url: "http://127.0.0.1:1".into(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(matches!(
+            unreachable.warm_connections(1).await,
+            Err(PrivyApiError::CommunicationError(_))
+        ));
+    }
 }
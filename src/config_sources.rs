@@ -0,0 +1,422 @@
+//! Layered client configuration.
+//!
+//! `PrivyClient::from_config_sources` builds a client from several sources
+//! at once, taking each value from the first source that sets it:
+//!
+//! 1. values set explicitly on [`ConfigSources`],
+//! 2. environment variables, the ones in the table below, which
+//!    `PrivyClient::new_from_env` reads too,
+//! 3. a `privy.toml` file, in the working directory unless another path is
+//!    set with [`ConfigSources::file`],
+//! 4. the defaults of `PrivyClientOptions`.
+//!
+//! | value         | environment variable    | `privy.toml` key |
+//! |---------------|-------------------------|------------------|
+//! | app id        | `PRIVY_TEST_APP_ID`     | `app_id`         |
+//! | app secret    | `PRIVY_TEST_APP_SECRET` | `app_secret`     |
+//! | base url      | `PRIVY_TEST_URL`        | `base_url`       |
+//! | cache size    | `PRIVY_CACHE_SIZE`      | `cache_size`     |
+//! | dry run       | `PRIVY_DRY_RUN`         | `dry_run`        |
+//!
+//! The client remembers where each value came from, which helps when a
+//! value is not the one you expected:
+//!
+//! ```no_run
+//! # use privy_rs::{PrivyClient, config_sources::ConfigSources};
+//! # fn foo() -> Result<(), privy_rs::PrivyCreateError> {
+//! let client = PrivyClient::from_config_sources(ConfigSources::new().dry_run(true))?;
+//! if let Some(provenance) = client.config_provenance() {
+//!     // app_id: environment variable PRIVY_TEST_APP_ID
+//!     // base_url: privy.toml
+//!     // ...
+//!     println!("{provenance}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    PrivyCreateError,
+    client::{
+        APP_ID_ENV_VAR, APP_SECRET_ENV_VAR, CACHE_SIZE_ENV_VAR, DRY_RUN_ENV_VAR,
+        PrivyClientOptions, TEST_URL_ENV_VAR,
+    },
+};
+
+const DEFAULT_CONFIG_FILE: &str = "privy.toml";
+
+/// The sources to build a client from, holding the values set explicitly.
+/// See the [module docs](self).
+#[derive(Clone, Default)]
+pub struct ConfigSources {
+    app_id: Option<String>,
+    app_secret: Option<String>,
+    base_url: Option<String>,
+    cache_size: Option<NonZeroUsize>,
+    dry_run: Option<bool>,
+    file: Option<PathBuf>,
+}
+
+impl fmt::Debug for ConfigSources {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfigSources")
+            .field("app_id", &self.app_id)
+            .field(
+                "app_secret",
+                &self.app_secret.as_ref().map(|_| "<redacted>"),
+            )
+            .field("base_url", &self.base_url)
+            .field("cache_size", &self.cache_size)
+            .field("dry_run", &self.dry_run)
+            .field("file", &self.file)
+            .finish()
+    }
+}
+
+impl ConfigSources {
+    /// Sources with no explicit values, reading the environment and
+    /// `privy.toml` in the working directory, if it exists.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `app_id`, whatever the other sources say.
+    #[must_use]
+    pub fn app_id(mut self, app_id: impl Into<String>) -> Self {
+        self.app_id = Some(app_id.into());
+        self
+    }
+
+    /// Use `app_secret`, whatever the other sources say.
+    #[must_use]
+    pub fn app_secret(mut self, app_secret: impl Into<String>) -> Self {
+        self.app_secret = Some(app_secret.into());
+        self
+    }
+
+    /// Use `base_url`, whatever the other sources say.
+    #[must_use]
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Use `cache_size`, whatever the other sources say.
+    #[must_use]
+    pub fn cache_size(mut self, cache_size: NonZeroUsize) -> Self {
+        self.cache_size = Some(cache_size);
+        self
+    }
+
+    /// Use `dry_run`, whatever the other sources say.
+    #[must_use]
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = Some(dry_run);
+        self
+    }
+
+    /// Read the config file at `path` instead of `privy.toml`. Unlike the
+    /// default file, it is an error for this one not to exist.
+    #[must_use]
+    pub fn file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.file = Some(path.into());
+        self
+    }
+
+    /// Resolve every value, reading environment variables with `env`.
+    pub(crate) fn resolve(
+        self,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<ResolvedConfig, PrivyCreateError> {
+        let (file, path) = match &self.file {
+            Some(path) => (Some(FileConfig::read(path)?), path.clone()),
+            None => {
+                let path = PathBuf::from(DEFAULT_CONFIG_FILE);
+                let file = if path.exists() {
+                    Some(FileConfig::read(&path)?)
+                } else {
+                    None
+                };
+                (file, path)
+            }
+        };
+        let file = file.unwrap_or_default();
+        let layers = Layers {
+            env: &env,
+            path: &path,
+        };
+        let defaults = PrivyClientOptions::default();
+
+        let (app_id, app_id_source) = layers
+            .pick("app_id", self.app_id, APP_ID_ENV_VAR, file.app_id, None)?
+            .ok_or(PrivyCreateError::InvalidAppId)?;
+        let (app_secret, app_secret_source) = layers
+            .pick(
+                "app_secret",
+                self.app_secret,
+                APP_SECRET_ENV_VAR,
+                file.app_secret,
+                None,
+            )?
+            .ok_or(PrivyCreateError::InvalidAppSecret)?;
+        let (base_url, base_url_source) = layers
+            .pick(
+                "base_url",
+                self.base_url,
+                TEST_URL_ENV_VAR,
+                file.base_url,
+                Some(defaults.base_url.clone()),
+            )?
+            .expect("has a default");
+        let (cache_size, cache_size_source) = layers
+            .pick(
+                "cache_size",
+                self.cache_size,
+                CACHE_SIZE_ENV_VAR,
+                file.cache_size,
+                Some(defaults.cache_size),
+            )?
+            .expect("has a default");
+        let (dry_run, dry_run_source) = layers
+            .pick(
+                "dry_run",
+                self.dry_run,
+                DRY_RUN_ENV_VAR,
+                file.dry_run,
+                Some(defaults.dry_run),
+            )?
+            .expect("has a default");
+
+        Ok(ResolvedConfig {
+            app_id,
+            app_secret,
+            options: PrivyClientOptions {
+                base_url,
+                cache_size,
+                dry_run,
+                ..defaults
+            },
+            provenance: ConfigProvenance {
+                app_id: app_id_source,
+                app_secret: app_secret_source,
+                base_url: base_url_source,
+                cache_size: cache_size_source,
+                dry_run: dry_run_source,
+            },
+        })
+    }
+}
+
+/// The values read from a config file.
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileConfig {
+    app_id: Option<String>,
+    app_secret: Option<String>,
+    base_url: Option<String>,
+    cache_size: Option<NonZeroUsize>,
+    dry_run: Option<bool>,
+}
+
+impl FileConfig {
+    fn read(path: &Path) -> Result<Self, PrivyCreateError> {
+        let contents =
+            std::fs::read_to_string(path).map_err(|source| PrivyCreateError::ConfigFile {
+                path: path.to_path_buf(),
+                source: source.into(),
+            })?;
+        toml::from_str(&contents).map_err(|source| PrivyCreateError::ConfigFile {
+            path: path.to_path_buf(),
+            source: source.into(),
+        })
+    }
+}
+
+/// The sources below the explicit values.
+struct Layers<'a, E> {
+    env: &'a E,
+    path: &'a Path,
+}
+
+impl<E: Fn(&str) -> Option<String>> Layers<'_, E> {
+    /// The first of `explicit`, the variable `var`, `file` and `default`
+    /// that is set, and where it came from.
+    fn pick<T: FromStr>(
+        &self,
+        key: &'static str,
+        explicit: Option<T>,
+        var: &'static str,
+        file: Option<T>,
+        default: Option<T>,
+    ) -> Result<Option<(T, ConfigSource)>, PrivyCreateError> {
+        if let Some(value) = explicit {
+            return Ok(Some((value, ConfigSource::Explicit)));
+        }
+        if let Some(value) = (self.env)(var) {
+            let value = value
+                .parse()
+                .map_err(|_| PrivyCreateError::InvalidConfigValue {
+                    key,
+                    from: ConfigSource::Env(var),
+                })?;
+            return Ok(Some((value, ConfigSource::Env(var))));
+        }
+        if let Some(value) = file {
+            return Ok(Some((value, ConfigSource::File(self.path.to_path_buf()))));
+        }
+        Ok(default.map(|value| (value, ConfigSource::Default)))
+    }
+}
+
+/// The outcome of `ConfigSources::resolve`.
+pub(crate) struct ResolvedConfig {
+    pub(crate) app_id: String,
+    pub(crate) app_secret: String,
+    pub(crate) options: PrivyClientOptions,
+    pub(crate) provenance: ConfigProvenance,
+}
+
+/// Where a config value came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// Set on `ConfigSources`.
+    Explicit,
+    /// Read from this environment variable.
+    Env(&'static str),
+    /// Read from the config file at this path.
+    File(PathBuf),
+    /// The default of `PrivyClientOptions`.
+    Default,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::Explicit => write!(f, "set explicitly"),
+            ConfigSource::Env(var) => write!(f, "environment variable {var}"),
+            ConfigSource::File(path) => write!(f, "{}", path.display()),
+            ConfigSource::Default => write!(f, "default"),
+        }
+    }
+}
+
+/// Where each value of a client built by `PrivyClient::from_config_sources`
+/// came from. The values themselves are not included, so this is safe to
+/// log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProvenance {
+    /// The source of the app id.
+    pub app_id: ConfigSource,
+    /// The source of the app secret.
+    pub app_secret: ConfigSource,
+    /// The source of the base url.
+    pub base_url: ConfigSource,
+    /// The source of the JWT cache size.
+    pub cache_size: ConfigSource,
+    /// The source of the dry run flag.
+    pub dry_run: ConfigSource,
+}
+
+impl fmt::Display for ConfigProvenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "app_id: {}", self.app_id)?;
+        writeln!(f, "app_secret: {}", self.app_secret)?;
+        writeln!(f, "base_url: {}", self.base_url)?;
+        writeln!(f, "cache_size: {}", self.cache_size)?;
+        write!(f, "dry_run: {}", self.dry_run)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn env<'a>(vars: &'a HashMap<&str, &str>) -> impl Fn(&str) -> Option<String> + 'a {
+        |var| vars.get(var).map(|value| value.to_string())
+    }
+
+    #[test]
+    fn test_values_come_from_the_first_source_that_sets_them() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(
+            file.path(),
+            "app_id = \"file_app\"\napp_secret = \"file_secret\"\nbase_url = \"https://file\"\ncache_size = 5\n",
+        )
+        .unwrap();
+        let vars = HashMap::from([
+            (APP_SECRET_ENV_VAR, "env_secret"),
+            (TEST_URL_ENV_VAR, "https://env"),
+        ]);
+
+        let resolved = ConfigSources::new()
+            .file(file.path())
+            .base_url("https://explicit")
+            .resolve(env(&vars))
+            .unwrap();
+
+        assert_eq!(resolved.app_id, "file_app");
+        assert_eq!(resolved.app_secret, "env_secret");
+        assert_eq!(resolved.options.base_url, "https://explicit");
+        assert_eq!(resolved.options.cache_size.get(), 5);
+        assert!(!resolved.options.dry_run);
+        assert_eq!(
+            resolved.provenance,
+            ConfigProvenance {
+                app_id: ConfigSource::File(file.path().to_path_buf()),
+                app_secret: ConfigSource::Env(APP_SECRET_ENV_VAR),
+                base_url: ConfigSource::Explicit,
+                cache_size: ConfigSource::File(file.path().to_path_buf()),
+                dry_run: ConfigSource::Default,
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_sources_are_reported() {
+        let error = |sources: ConfigSources, vars: &HashMap<&str, &str>| {
+            sources
+                .resolve(env(vars))
+                .err()
+                .expect("resolving should fail")
+        };
+        let vars = HashMap::from([(APP_ID_ENV_VAR, "app"), (DRY_RUN_ENV_VAR, "maybe")]);
+
+        let missing = ConfigSources::new()
+            .app_secret("secret")
+            .file("/no/such/privy.toml");
+        assert!(matches!(
+            error(missing, &HashMap::new()),
+            PrivyCreateError::ConfigFile { .. }
+        ));
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "app_idd = \"typo\"\n").unwrap();
+        assert!(matches!(
+            error(ConfigSources::new().file(file.path()), &vars),
+            PrivyCreateError::ConfigFile { .. }
+        ));
+
+        assert!(matches!(
+            error(ConfigSources::new().app_secret("secret"), &vars),
+            PrivyCreateError::InvalidConfigValue {
+                key: "dry_run",
+                from: ConfigSource::Env(DRY_RUN_ENV_VAR)
+            }
+        ));
+
+        assert!(matches!(
+            error(ConfigSources::new().app_id("app"), &HashMap::new()),
+            PrivyCreateError::InvalidAppSecret
+        ));
+    }
+}
@@ -0,0 +1,438 @@
+//! Tuning the connections of a `PrivyClient`.
+//!
+//! Every request of a client goes over a pool of keep-alive connections.
+//! A request that finds no idle connection in the pool pays for a TCP and
+//! TLS handshake first, which can be several round trips. For latency
+//! sensitive signing, [`PrivyClient::warm_connections`] opens connections
+//! ahead of the first requests, and [`ConnectionOptions`], set as
+//! `PrivyClientOptions::connection`, keeps them open while idle:
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use privy_rs::{PrivyClient, client::PrivyClientOptions};
+//! # use privy_rs::connection::{ConnectionOptions, Http2KeepAlive};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_with_options(
+//!     "app_id".into(),
+//!     "app_secret".into(),
+//!     PrivyClientOptions {
+//!         connection: ConnectionOptions {
+//!             pool_idle_timeout: Some(Duration::from_secs(300)),
+//!             http2_keep_alive: Some(Http2KeepAlive::default()),
+//!             ..Default::default()
+//!         },
+//!         ..Default::default()
+//!     },
+//! )?;
+//! client.warm_connections(4).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Timeouts
+//!
+//! A request fails with `PrivyApiError::CommunicationError` if no
+//! connection could be opened within `ConnectionOptions::connect_timeout`,
+//! or the response did not arrive within `ConnectionOptions::timeout`,
+//! both 15 seconds by default. Signing paths that would rather fail fast
+//! and retry, see `PrivyClientOptions::retry`, can lower them, and
+//! `ConnectionOptions::read_timeout` catches a server that stops sending
+//! halfway through a response:
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use privy_rs::{client::PrivyClientOptions, connection::ConnectionOptions};
+//! let options = PrivyClientOptions {
+//!     connection: ConnectionOptions {
+//!         connect_timeout: Duration::from_secs(2),
+//!         timeout: Some(Duration::from_secs(5)),
+//!         read_timeout: Some(Duration::from_secs(1)),
+//!         ..Default::default()
+//!     },
+//!     ..Default::default()
+//! };
+//! ```
+//!
+//! # Proxies and private certificate authorities
+//!
+//! Deployments that reach the API through an HTTP proxy, or through a
+//! TLS inspecting proxy that re-signs connections with a private
+//! certificate authority, set `ConnectionOptions::proxy` and add the
+//! authority to `ConnectionOptions::root_certificates`:
+//!
+//! ```no_run
+//! # use privy_rs::{client::PrivyClientOptions, connection::{ConnectionOptions, RootCertificate}};
+//! # fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let options = PrivyClientOptions {
+//!     connection: ConnectionOptions {
+//!         proxy: Some(reqwest::Proxy::https("http://proxy.internal:3128")?),
+//!         root_certificates: RootCertificate::from_pem_bundle(&std::fs::read(
+//!             "/etc/ssl/corporate-ca.pem",
+//!         )?)?,
+//!         ..Default::default()
+//!     },
+//!     ..Default::default()
+//! };
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Without a proxy set, the proxies in the `HTTPS_PROXY` and `ALL_PROXY`
+//! environment variables are used. Root certificates are trusted on top of
+//! the default roots, not instead of them.
+//!
+//! # TLS session resumption
+//!
+//! With the `rustls` feature, requests are sent over rustls instead of the
+//! system TLS library, and connections opened after the first one resume
+//! its TLS session, skipping the certificate exchange. See
+//! `ConnectionOptions::tls_session_cache_size`. The system TLS library does
+//! not resume sessions.
+//!
+//! [`PrivyClient::warm_connections`]: crate::PrivyClient::warm_connections
+
+use std::{fmt, time::Duration};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+use crate::PrivyCreateError;
+
+/// How connections are opened and kept, see the [module docs](self).
+#[derive(Clone, Debug)]
+pub struct ConnectionOptions {
+    /// How long to wait for a connection to the API to open, including the
+    /// TLS handshake.
+    pub connect_timeout: Duration,
+    /// How long to wait for a whole request, from sending it to reading
+    /// the last of its response. `None` waits indefinitely.
+    pub timeout: Option<Duration>,
+    /// How long to wait for each read of the response, so a response that
+    /// stalls fails before `timeout` runs out. `None` disables it.
+    pub read_timeout: Option<Duration>,
+    /// The most idle connections kept open to the API.
+    pub pool_max_idle_per_host: usize,
+    /// How long an idle connection is kept open before it is closed. `None`
+    /// keeps idle connections until the server closes them.
+    pub pool_idle_timeout: Option<Duration>,
+    /// How long a connection is idle before TCP keep-alive probes are sent
+    /// on it. `None` disables them.
+    pub tcp_keepalive: Option<Duration>,
+    /// Send HTTP/2 pings on connections that negotiated HTTP/2, to keep
+    /// them open through proxies and load balancers that drop quiet
+    /// connections, and to notice dead ones before a request is sent on
+    /// them. `None` disables the pings.
+    pub http2_keep_alive: Option<Http2KeepAlive>,
+    /// The proxy to send every request through. `None` uses the proxies
+    /// set in the environment.
+    pub proxy: Option<reqwest::Proxy>,
+    /// Certificate authorities to trust besides the default roots, such as
+    /// the private authority of a TLS inspecting proxy.
+    pub root_certificates: Vec<RootCertificate>,
+    /// The most TLS sessions kept to resume connections with. `0` disables
+    /// resumption, so every connection does a full handshake.
+    ///
+    /// # Feature Flag
+    /// Requires the `rustls` feature to be enabled.
+    #[cfg(feature = "rustls")]
+    pub tls_session_cache_size: usize,
+    /// Resume TLS 1.2 sessions with session tickets, as well as with
+    /// session ids. TLS 1.3 sessions are always resumed with tickets.
+    ///
+    /// # Feature Flag
+    /// Requires the `rustls` feature to be enabled.
+    #[cfg(feature = "rustls")]
+    pub tls_session_tickets: bool,
+}
+
+impl Default for ConnectionOptions {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(15),
+            timeout: Some(Duration::from_secs(15)),
+            read_timeout: None,
+            pool_max_idle_per_host: usize::MAX,
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            tcp_keepalive: Some(Duration::from_secs(15)),
+            http2_keep_alive: None,
+            proxy: None,
+            root_certificates: Vec::new(),
+            #[cfg(feature = "rustls")]
+            tls_session_cache_size: 256,
+            #[cfg(feature = "rustls")]
+            tls_session_tickets: true,
+        }
+    }
+}
+
+/// HTTP/2 keep-alive pings, see `ConnectionOptions::http2_keep_alive`.
+#[derive(Clone, Debug)]
+pub struct Http2KeepAlive {
+    /// How often a connection is pinged.
+    pub interval: Duration,
+    /// How long to wait for the answer to a ping before the connection is
+    /// closed.
+    pub timeout: Duration,
+    /// Ping connections with no request in flight too. Without this, an
+    /// idle connection is only pinged once a request is sent on it.
+    pub while_idle: bool,
+}
+
+impl Default for Http2KeepAlive {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(10),
+            while_idle: true,
+        }
+    }
+}
+
+/// A certificate authority to trust, see
+/// `ConnectionOptions::root_certificates`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RootCertificate(Vec<u8>);
+
+impl fmt::Debug for RootCertificate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RootCertificate")
+            .field(&format_args!("{} bytes", self.0.len()))
+            .finish()
+    }
+}
+
+impl RootCertificate {
+    /// A certificate in DER.
+    #[must_use]
+    pub fn from_der(der: Vec<u8>) -> Self {
+        Self(der)
+    }
+
+    /// The certificates of a PEM bundle, such as a CA bundle file, in the
+    /// order they appear in it.
+    ///
+    /// # Errors
+    /// If the bundle holds no certificates, or one of them is not valid
+    /// base64.
+    pub fn from_pem_bundle(pem: &[u8]) -> Result<Vec<Self>, PrivyCreateError> {
+        const BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+        const END: &str = "-----END CERTIFICATE-----";
+
+        let pem = std::str::from_utf8(pem).map_err(|_| PrivyCreateError::InvalidCertificate)?;
+        let mut certificates = Vec::new();
+        let mut rest = pem;
+        while let Some(start) = rest.find(BEGIN) {
+            let body = &rest[start + BEGIN.len()..];
+            let end = body.find(END).ok_or(PrivyCreateError::InvalidCertificate)?;
+            let base64: String = body[..end].split_whitespace().collect();
+            let der = STANDARD
+                .decode(base64)
+                .map_err(|_| PrivyCreateError::InvalidCertificate)?;
+            certificates.push(Self(der));
+            rest = &body[end + END.len()..];
+        }
+        if certificates.is_empty() {
+            return Err(PrivyCreateError::InvalidCertificate);
+        }
+        Ok(certificates)
+    }
+}
+
+impl ConnectionOptions {
+    /// Configure `builder` to open and keep connections as set in these
+    /// options.
+    ///
+    /// # Errors
+    /// If one of the root certificates is not a valid certificate.
+    pub(crate) fn apply(
+        &self,
+        builder: reqwest::ClientBuilder,
+    ) -> Result<reqwest::ClientBuilder, PrivyCreateError> {
+        let mut builder = builder
+            .connect_timeout(self.connect_timeout)
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .tcp_keepalive(self.tcp_keepalive);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(read_timeout) = self.read_timeout {
+            builder = builder.read_timeout(read_timeout);
+        }
+        if let Some(keep_alive) = &self.http2_keep_alive {
+            builder = builder
+                .http2_keep_alive_interval(keep_alive.interval)
+                .http2_keep_alive_timeout(keep_alive.timeout)
+                .http2_keep_alive_while_idle(keep_alive.while_idle);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        #[cfg(feature = "rustls")]
+        {
+            builder = builder.use_preconfigured_tls(self.tls_config()?);
+        }
+        #[cfg(not(feature = "rustls"))]
+        for certificate in &self.root_certificates {
+            let certificate = reqwest::Certificate::from_der(&certificate.0)
+                .map_err(|_| PrivyCreateError::InvalidCertificate)?;
+            builder = builder.add_root_certificate(certificate);
+        }
+        Ok(builder)
+    }
+
+    /// The rustls config of the client, trusting the same roots reqwest
+    /// does by default, and the root certificates of these options.
+    #[cfg(feature = "rustls")]
+    fn tls_config(&self) -> Result<rustls::ClientConfig, PrivyCreateError> {
+        use rustls::client::{Resumption, Tls12Resumption};
+
+        let mut roots = rustls::RootCertStore {
+            roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+        };
+        for certificate in &self.root_certificates {
+            roots
+                .add(rustls::pki_types::CertificateDer::from(
+                    certificate.0.clone(),
+                ))
+                .map_err(|_| PrivyCreateError::InvalidCertificate)?;
+        }
+        let mut config = rustls::ClientConfig::builder_with_provider(std::sync::Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_safe_default_protocol_versions()
+        .expect("the ring provider supports the default protocol versions")
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+        config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+        config.resumption = if self.tls_session_cache_size == 0 {
+            Resumption::disabled()
+        } else {
+            Resumption::in_memory_sessions(self.tls_session_cache_size).tls12_resumption(
+                if self.tls_session_tickets {
+                    Tls12Resumption::SessionIdOrTickets
+                } else {
+                    Tls12Resumption::SessionIdOnly
+                },
+            )
+        };
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use super::*;
+    use crate::{PrivyApiError, PrivyClient, client::PrivyClientOptions};
+
+    #[tokio::test]
+    async fn test_requests_time_out() {
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.path("/v1/wallets/wallet_id");
+                then.status(404).delay(Duration::from_millis(500));
+            })
+            .await;
+
+        let client = |timeout| {
+            PrivyClient::new_with_options(
+                "app_id".to_string(),
+                "app_secret".to_string(),
+                PrivyClientOptions {
+                    base_url: server.base_url(),
+                    connection: ConnectionOptions {
+                        timeout,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+        };
+
+        let error = client(Some(Duration::from_millis(100)))
+            .wallets()
+            .get("wallet_id")
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&error, PrivyApiError::CommunicationError(e) if e.is_timeout()),
+            "{error:?}"
+        );
+
+        let error = client(None).wallets().get("wallet_id").await.unwrap_err();
+        assert_eq!(error.status(), Some(reqwest::StatusCode::NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn test_requests_go_through_the_proxy() {
+        let proxy = MockServer::start_async().await;
+        let mock = proxy
+            .mock_async(|when, then| {
+                when.path("/v1/wallets/wallet_id")
+                    .header("host", "api.privy.invalid");
+                then.status(404);
+            })
+            .await;
+
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: "http://api.privy.invalid".to_string(),
+                connection: ConnectionOptions {
+                    proxy: Some(reqwest::Proxy::http(proxy.base_url()).unwrap()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let error = client.wallets().get("wallet_id").await.unwrap_err();
+        assert_eq!(error.status(), Some(reqwest::StatusCode::NOT_FOUND));
+        assert_eq!(mock.calls_async().await, 1);
+    }
+
+    #[test]
+    fn test_root_certificates_from_pem_bundle() {
+        let bundle = "junk\n-----BEGIN CERTIFICATE-----\nAQID\nBA==\n-----END CERTIFICATE-----\n\
+                      -----BEGIN CERTIFICATE-----\nBQY=\n-----END CERTIFICATE-----\n";
+        assert_eq!(
+            RootCertificate::from_pem_bundle(bundle.as_bytes()).unwrap(),
+            [
+                RootCertificate::from_der(vec![1, 2, 3, 4]),
+                RootCertificate::from_der(vec![5, 6]),
+            ]
+        );
+        assert!(matches!(
+            RootCertificate::from_pem_bundle(b"no certificates here"),
+            Err(PrivyCreateError::InvalidCertificate)
+        ));
+        assert!(matches!(
+            RootCertificate::from_pem_bundle(
+                b"-----BEGIN CERTIFICATE-----\n!!\n-----END CERTIFICATE-----"
+            ),
+            Err(PrivyCreateError::InvalidCertificate)
+        ));
+    }
+
+    #[test]
+    fn test_invalid_root_certificates_fail_client_creation() {
+        let result = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                connection: ConnectionOptions {
+                    root_certificates: vec![RootCertificate::from_der(vec![1, 2, 3])],
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        assert!(matches!(result, Err(PrivyCreateError::InvalidCertificate)));
+    }
+}
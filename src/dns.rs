@@ -0,0 +1,224 @@
+//! Pinning API hosts to fixed IP addresses.
+//!
+//! Some deployments may only send traffic to approved IPs. A
+//! [`PinnedResolver`] set as `PrivyClientOptions::resolver` answers DNS
+//! lookups for pinned hosts with their pinned addresses instead of asking
+//! the system resolver, rotating between them on every lookup. Hosts that
+//! are not pinned are resolved as normal.
+//!
+//! With health checks running, either through
+//! [`PrivyClient::spawn_maintenance`](crate::PrivyClient::spawn_maintenance)
+//! or [`PinnedResolver::spawn_health_checks`], addresses that stop
+//! accepting connections are only handed out once every address of their
+//! host is unhealthy.
+//!
+//! ```no_run
+//! # use privy_rs::{PrivyClient, client::PrivyClientOptions, dns::PinnedResolver};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let resolver = PinnedResolver::new().pin(
+//!     "api.privy.io",
+//!     ["203.0.113.10".parse()?, "203.0.113.11".parse()?],
+//! );
+//!
+//! let client = PrivyClient::new_with_options(
+//!     "app_id".into(),
+//!     "app_secret".into(),
+//!     PrivyClientOptions {
+//!         resolver: Some(resolver),
+//!         ..Default::default()
+//!     },
+//! )?;
+//! let _maintenance = client.spawn_maintenance(tokio::runtime::Handle::current());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+/// The port health checks connect to unless configured otherwise.
+const DEFAULT_HEALTH_CHECK_PORT: u16 = 443;
+
+/// How long a health check waits for a connection before marking an
+/// address unhealthy.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+struct PinnedHost {
+    addrs: Vec<IpAddr>,
+    healthy: Vec<AtomicBool>,
+    next: AtomicUsize,
+}
+
+impl PinnedHost {
+    /// The addresses to try, starting from the next one in the rotation,
+    /// with healthy addresses first.
+    fn ordered(&self) -> Vec<IpAddr> {
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        let (healthy, unhealthy): (Vec<_>, Vec<_>) = (0..self.addrs.len())
+            .map(|i| (start + i) % self.addrs.len())
+            .partition(|&i| self.healthy[i].load(Ordering::Relaxed));
+
+        healthy
+            .into_iter()
+            .chain(unhealthy)
+            .map(|i| self.addrs[i])
+            .collect()
+    }
+}
+
+/// A DNS resolver that pins hosts to fixed IP addresses, see the
+/// [module docs](self) for details.
+///
+/// Cloning is cheap, and clones share health state.
+#[derive(Clone, Debug)]
+pub struct PinnedResolver {
+    hosts: HashMap<String, Arc<PinnedHost>>,
+    health_check_port: u16,
+}
+
+impl Default for PinnedResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PinnedResolver {
+    /// Create a resolver with no pinned hosts.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            hosts: HashMap::new(),
+            health_check_port: DEFAULT_HEALTH_CHECK_PORT,
+        }
+    }
+
+    /// Resolve `host` to `addrs` only. The port is always taken from the
+    /// request url. Addresses start out healthy.
+    #[must_use]
+    pub fn pin(mut self, host: impl Into<String>, addrs: impl IntoIterator<Item = IpAddr>) -> Self {
+        let addrs: Vec<IpAddr> = addrs.into_iter().collect();
+        let pinned = PinnedHost {
+            healthy: addrs.iter().map(|_| AtomicBool::new(true)).collect(),
+            addrs,
+            next: AtomicUsize::new(0),
+        };
+        self.hosts
+            .insert(host.into().to_ascii_lowercase(), Arc::new(pinned));
+        self
+    }
+
+    /// Set the port health checks connect to, 443 by default.
+    #[must_use]
+    pub fn with_health_check_port(mut self, port: u16) -> Self {
+        self.health_check_port = port;
+        self
+    }
+
+    /// Try to connect to every pinned address once, and record which
+    /// addresses accepted the connection.
+    pub async fn check_health(&self) {
+        let checks = self.hosts.values().flat_map(|host| {
+            host.addrs
+                .iter()
+                .enumerate()
+                .map(move |(i, addr)| async move {
+                    let addr = SocketAddr::new(*addr, self.health_check_port);
+                    let healthy = matches!(
+                        tokio::time::timeout(
+                            HEALTH_CHECK_TIMEOUT,
+                            tokio::net::TcpStream::connect(addr)
+                        )
+                        .await,
+                        Ok(Ok(_))
+                    );
+                    if host.healthy[i].swap(healthy, Ordering::Relaxed) != healthy {
+                        tracing::warn!(%addr, healthy, "pinned address health changed");
+                    }
+                })
+        });
+        futures::future::join_all(checks).await;
+    }
+
+    /// Run [`check_health`](Self::check_health) every `interval` on the
+    /// current tokio runtime, until the returned handle is aborted.
+    pub fn spawn_health_checks(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let resolver = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                resolver.check_health().await;
+            }
+        })
+    }
+}
+
+impl Resolve for PinnedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        if let Some(host) = self.hosts.get(&name.as_str().to_ascii_lowercase()) {
+            let addrs = host.ordered();
+            return Box::pin(async move {
+                let addrs: Addrs = Box::new(addrs.into_iter().map(|ip| SocketAddr::new(ip, 0)));
+                Ok(addrs)
+            });
+        }
+
+        let host = name.as_str().to_owned();
+        Box::pin(async move {
+            let addrs: Addrs = Box::new(tokio::net::lookup_host((host, 0)).await?);
+            Ok(addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(127, 0, 0, 1));
+    const SECOND: IpAddr = IpAddr::V4(std::net::Ipv4Addr::new(127, 0, 0, 2));
+
+    async fn resolve(resolver: &PinnedResolver, host: &str) -> Vec<IpAddr> {
+        resolver
+            .resolve(host.parse().unwrap())
+            .await
+            .unwrap()
+            .map(|addr| addr.ip())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_rotates_between_pinned_addresses() {
+        let resolver = PinnedResolver::new().pin("API.privy.io", [FIRST, SECOND]);
+
+        assert_eq!(resolve(&resolver, "api.privy.io").await, [FIRST, SECOND]);
+        assert_eq!(resolve(&resolver, "api.privy.io").await, [SECOND, FIRST]);
+        // other hosts go to the system resolver
+        assert!(!resolve(&resolver, "localhost").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unhealthy_addresses_are_tried_last() {
+        // only FIRST accepts connections on this port
+        let listener = tokio::net::TcpListener::bind((FIRST, 0)).await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let resolver = PinnedResolver::new()
+            .pin("api.privy.io", [SECOND, FIRST])
+            .with_health_check_port(port);
+        resolver.check_health().await;
+
+        assert_eq!(resolve(&resolver, "api.privy.io").await, [FIRST, SECOND]);
+        assert_eq!(resolve(&resolver, "api.privy.io").await, [FIRST, SECOND]);
+    }
+}
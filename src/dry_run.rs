@@ -0,0 +1,125 @@
+//! Synthesized responses for `PrivyClientOptions::dry_run`.
+//!
+//! Fake signatures and hashes are derived from a hash of the request, so
+//! the same request always produces the same response. They are the right
+//! length and encoding for the method, but are not valid signatures.
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+use crate::generated::types::{
+    RawSignInput, RawSignResponse, WalletRpcRequestBody, WalletRpcResponse,
+};
+
+/// Deterministically derive `len` bytes from a serializable request.
+fn fake_bytes(request: &impl serde::Serialize, len: usize) -> Vec<u8> {
+    let seed = serde_json::to_vec(request).unwrap_or_default();
+    let mut bytes = Vec::with_capacity(len);
+    let mut counter = 0u32;
+    while bytes.len() < len {
+        let mut hasher = Sha256::new();
+        hasher.update(&seed);
+        hasher.update(counter.to_be_bytes());
+        bytes.extend_from_slice(&hasher.finalize());
+        counter += 1;
+    }
+    bytes.truncate(len);
+    bytes
+}
+
+fn fake_hex(request: &impl serde::Serialize, len: usize) -> String {
+    format!("0x{}", hex::encode(fake_bytes(request, len)))
+}
+
+/// Build the response to a wallet rpc request without sending it, or
+/// `None` if the method has no synthesized response.
+pub(crate) fn rpc_response(body: &WalletRpcRequestBody) -> Option<WalletRpcResponse> {
+    let response = match body {
+        WalletRpcRequestBody::EthereumPersonalSignRpcInput(_) => json!({
+            "method": "personal_sign",
+            "data": { "signature": fake_hex(body, 65), "encoding": "hex" },
+        }),
+        WalletRpcRequestBody::EthereumSignTypedDataRpcInput(_) => json!({
+            "method": "eth_signTypedData_v4",
+            "data": { "signature": fake_hex(body, 65), "encoding": "hex" },
+        }),
+        WalletRpcRequestBody::EthereumSecp256k1SignRpcInput(_) => json!({
+            "method": "secp256k1_sign",
+            "data": { "signature": fake_hex(body, 65), "encoding": "hex" },
+        }),
+        WalletRpcRequestBody::EthereumSignTransactionRpcInput(_) => json!({
+            "method": "eth_signTransaction",
+            "data": { "signed_transaction": fake_hex(body, 110), "encoding": "rlp" },
+        }),
+        WalletRpcRequestBody::EthereumSendTransactionRpcInput(input) => json!({
+            "method": "eth_sendTransaction",
+            "data": { "caip2": input.caip2, "hash": fake_hex(body, 32) },
+        }),
+        WalletRpcRequestBody::SolanaSignMessageRpcInput(_) => json!({
+            "method": "signMessage",
+            "data": { "signature": STANDARD.encode(fake_bytes(body, 64)), "encoding": "base64" },
+        }),
+        WalletRpcRequestBody::SolanaSignTransactionRpcInput(input) => json!({
+            "method": "signTransaction",
+            "data": { "signed_transaction": input.params.transaction, "encoding": "base64" },
+        }),
+        WalletRpcRequestBody::SolanaSignAndSendTransactionRpcInput(input) => json!({
+            "method": "signAndSendTransaction",
+            "data": { "caip2": input.caip2, "hash": bs58::encode(fake_bytes(body, 64)).into_string() },
+        }),
+        _ => return None,
+    };
+
+    Some(serde_json::from_value(response).expect("synthesized response is valid"))
+}
+
+/// Build the response to a raw sign request without sending it.
+pub(crate) fn raw_sign_response(body: &RawSignInput) -> RawSignResponse {
+    serde_json::from_value(json!({
+        "method": "raw_sign",
+        "data": { "signature": fake_hex(body, 65), "encoding": "hex" },
+    }))
+    .expect("synthesized response is valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(json!({"method": "personal_sign", "params": {"message": "hi", "encoding": "utf-8"}}) ; "personal sign")]
+    #[test_case(json!({"method": "eth_sendTransaction", "caip2": "eip155:1", "params": {"transaction": {"to": "0xabc"}}}) ; "send transaction")]
+    #[test_case(json!({"method": "signMessage", "params": {"message": "aGk=", "encoding": "base64"}}) ; "solana sign message")]
+    #[test_case(json!({"method": "signAndSendTransaction", "caip2": "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1", "params": {"transaction": "AA==", "encoding": "base64"}}) ; "solana sign and send")]
+    fn test_rpc_response_is_deterministic(request: serde_json::Value) {
+        let body: WalletRpcRequestBody = serde_json::from_value(request).unwrap();
+        let first = serde_json::to_value(rpc_response(&body).unwrap()).unwrap();
+        let second = serde_json::to_value(rpc_response(&body).unwrap()).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(
+            first["method"],
+            serde_json::to_value(&body).unwrap()["method"]
+        );
+    }
+
+    #[test]
+    fn test_solana_transaction_hash_is_base58() {
+        let body: WalletRpcRequestBody = serde_json::from_value(json!({
+            "method": "signAndSendTransaction",
+            "caip2": "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1",
+            "params": {"transaction": "AA==", "encoding": "base64"},
+        }))
+        .unwrap();
+        let response = serde_json::to_value(rpc_response(&body).unwrap()).unwrap();
+        let hash = response["data"]["hash"].as_str().unwrap();
+        assert_eq!(bs58::decode(hash).into_vec().unwrap().len(), 64);
+    }
+
+    #[test]
+    fn test_fake_bytes_depend_on_request() {
+        assert_eq!(fake_bytes(&"a", 100).len(), 100);
+        assert_ne!(fake_bytes(&"a", 32), fake_bytes(&"b", 32));
+    }
+}
@@ -0,0 +1,314 @@
+//! ENS name resolution for Ethereum recipients.
+//!
+//! An `EnsResolver` resolves names like `vitalik.eth` to addresses by
+//! querying the ENS registry through an Ethereum JSON-RPC endpoint of your
+//! choosing. Attach one to an `EthereumService` to accept ENS names as the
+//! `to` of a transaction:
+//!
+//! ```rust,no_run
+//! # use privy_rs::{PrivyClient, ens::EnsResolver};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let resolver = EnsResolver::new("https://eth.llamarpc.com").with_strict(true);
+//! let ethereum = client.wallets().ethereum().with_ens(resolver);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Names are lowercased before hashing, but no further UTS-46 normalization
+//! is applied, so names should be passed in their normalized form.
+
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use sha3::{Digest, Keccak256};
+
+use crate::NameResolutionError;
+
+/// The ENS registry, deployed at the same address on mainnet and testnets.
+const ENS_REGISTRY: &str = "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e";
+
+/// `resolver(bytes32)`
+const RESOLVER_SELECTOR: &str = "0178b8bf";
+
+/// `addr(bytes32)`
+const ADDR_SELECTOR: &str = "3b3b57de";
+
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+type EnsCache = lru::LruCache<String, (Instant, Option<String>)>;
+
+/// Resolves ENS names to Ethereum addresses, caching the results.
+///
+/// Cloning an `EnsResolver` is cheap, and clones share the same cache.
+#[derive(Debug, Clone)]
+pub struct EnsResolver {
+    rpc_url: String,
+    http: reqwest::Client,
+    cache: Arc<Mutex<EnsCache>>,
+    cache_ttl: Duration,
+    strict: bool,
+}
+
+impl EnsResolver {
+    /// Create a new resolver that queries the given Ethereum JSON-RPC endpoint.
+    pub fn new(rpc_url: impl Into<String>) -> Self {
+        Self {
+            rpc_url: rpc_url.into(),
+            http: reqwest::Client::new(),
+            cache: Arc::new(Mutex::new(lru::LruCache::new(
+                NonZeroUsize::new(1000).expect("non-zero"),
+            ))),
+            cache_ttl: DEFAULT_CACHE_TTL,
+            strict: false,
+        }
+    }
+
+    /// In strict mode, sending to a name that cannot be resolved fails
+    /// before any request is made to Privy. Otherwise, the name is passed
+    /// through unchanged and left for the API to reject.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// How long resolved (and unresolvable) names are cached for. Defaults
+    /// to five minutes.
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Resolve an ENS name to a checksum-free, lowercase hex address.
+    /// Returns `None` if the name has no resolver or no address record.
+    ///
+    /// # Errors
+    /// Fails if the rpc endpoint could not be reached or returned an
+    /// unexpected response.
+    pub async fn resolve(&self, name: &str) -> Result<Option<String>, NameResolutionError> {
+        let name = name.to_lowercase();
+
+        {
+            let mut cache = self.cache.lock().expect("lock poisoned");
+            if let Some((resolved_at, address)) = cache.get(&name) {
+                if resolved_at.elapsed() < self.cache_ttl {
+                    return Ok(address.clone());
+                }
+                cache.pop(&name);
+            }
+        }
+
+        let node = namehash(&name);
+        let resolver = self.call(ENS_REGISTRY, RESOLVER_SELECTOR, &node).await?;
+        let address = match resolver {
+            Some(resolver) => self.call(&resolver, ADDR_SELECTOR, &node).await?,
+            None => None,
+        };
+
+        tracing::debug!("Resolved ENS name {} to {:?}", name, address);
+
+        self.cache
+            .lock()
+            .expect("lock poisoned")
+            .put(name, (Instant::now(), address.clone()));
+
+        Ok(address)
+    }
+
+    /// Resolve a transaction recipient. Values that are not ENS names are
+    /// returned as-is, and unresolvable names are rejected in strict mode.
+    pub(crate) async fn resolve_recipient(&self, to: &str) -> Result<String, NameResolutionError> {
+        if !is_ens_name(to) {
+            return Ok(to.to_owned());
+        }
+
+        match self.resolve(to).await {
+            Ok(Some(address)) => Ok(address),
+            Ok(None) if self.strict => Err(NameResolutionError::Unresolvable(to.to_owned())),
+            Err(e) if self.strict => Err(e),
+            Ok(None) | Err(_) => {
+                tracing::warn!("Unable to resolve ENS name {}, sending as-is", to);
+                Ok(to.to_owned())
+            }
+        }
+    }
+
+    /// Perform an `eth_call` of a function taking a single `bytes32` and
+    /// returning a single `address`, returning `None` for the zero address.
+    async fn call(
+        &self,
+        contract: &str,
+        selector: &str,
+        node: &[u8; 32],
+    ) -> Result<Option<String>, NameResolutionError> {
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_call",
+            "params": [
+                { "to": contract, "data": format!("0x{selector}{}", hex::encode(node)) },
+                "latest"
+            ],
+        });
+
+        let response: serde_json::Value = self
+            .http
+            .post(&self.rpc_url)
+            .json(&body)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        if let Some(error) = response.get("error") {
+            return Err(NameResolutionError::InvalidResponse(error.to_string()));
+        }
+
+        let result = response
+            .get("result")
+            .and_then(|r| r.as_str())
+            .ok_or_else(|| NameResolutionError::InvalidResponse(response.to_string()))?;
+
+        decode_address(result)
+    }
+}
+
+/// Whether a recipient looks like an ENS name rather than a hex address.
+fn is_ens_name(to: &str) -> bool {
+    !to.starts_with("0x") && to.contains('.')
+}
+
+/// Compute the ENS namehash of a (normalized) name, as defined in EIP-137.
+fn namehash(name: &str) -> [u8; 32] {
+    let mut node = [0u8; 32];
+    if name.is_empty() {
+        return node;
+    }
+
+    for label in name.rsplit('.') {
+        let label_hash = Keccak256::digest(label.as_bytes());
+        let mut hasher = Keccak256::new();
+        hasher.update(node);
+        hasher.update(label_hash);
+        node = hasher.finalize().into();
+    }
+
+    node
+}
+
+/// Decode an abi-encoded `address` return value.
+fn decode_address(result: &str) -> Result<Option<String>, NameResolutionError> {
+    let bytes = hex::decode(result.trim_start_matches("0x"))
+        .map_err(|e| NameResolutionError::InvalidResponse(e.to_string()))?;
+
+    // an empty result means the contract does not exist or does not implement the method
+    if bytes.is_empty() {
+        return Ok(None);
+    }
+
+    if bytes.len() != 32 {
+        return Err(NameResolutionError::InvalidResponse(format!(
+            "expected a 32 byte word, got {} bytes",
+            bytes.len()
+        )));
+    }
+
+    let address = &bytes[12..];
+    if address.iter().all(|b| *b == 0) {
+        return Ok(None);
+    }
+
+    Ok(Some(format!("0x{}", hex::encode(address))))
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("" => "0000000000000000000000000000000000000000000000000000000000000000" ; "empty name")]
+    #[test_case("eth" => "93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae" ; "tld")]
+    #[test_case("foo.eth" => "de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f" ; "second level")]
+    fn test_namehash(name: &str) -> String {
+        hex::encode(namehash(name))
+    }
+
+    #[test_case("vitalik.eth" => true ; "ens name")]
+    #[test_case("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045" => false ; "hex address")]
+    #[test_case("nodot" => false ; "no tld")]
+    fn test_is_ens_name(to: &str) -> bool {
+        is_ens_name(to)
+    }
+
+    fn word(address: &str) -> String {
+        format!("0x{:0>64}", address.trim_start_matches("0x"))
+    }
+
+    #[tokio::test]
+    async fn test_resolve_caches_result() {
+        let server = MockServer::start_async().await;
+        let resolver_address = "0x231b0ee14048e9dccd1d247744d114a4eb5e8e63";
+        let address = "0xd8da6bf26964af9d7eed9e03e53415d37aa96045";
+
+        let registry = server
+            .mock_async(|when, then| {
+                when.method(POST).body_includes(RESOLVER_SELECTOR);
+                then.status(200).json_body(serde_json::json!({
+                    "jsonrpc": "2.0", "id": 1, "result": word(resolver_address)
+                }));
+            })
+            .await;
+        let addr = server
+            .mock_async(|when, then| {
+                when.method(POST).body_includes(ADDR_SELECTOR);
+                then.status(200).json_body(serde_json::json!({
+                    "jsonrpc": "2.0", "id": 1, "result": word(address)
+                }));
+            })
+            .await;
+
+        let resolver = EnsResolver::new(server.base_url());
+        assert_eq!(
+            resolver.resolve("Vitalik.eth").await.unwrap().as_deref(),
+            Some(address)
+        );
+        assert_eq!(
+            resolver.resolve_recipient("vitalik.eth").await.unwrap(),
+            address
+        );
+
+        registry.assert_calls_async(1).await;
+        addr.assert_calls_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn test_strict_rejects_unresolvable_name() {
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST);
+                then.status(200).json_body(serde_json::json!({
+                    "jsonrpc": "2.0", "id": 1, "result": word("0x0")
+                }));
+            })
+            .await;
+
+        let lenient = EnsResolver::new(server.base_url());
+        assert_eq!(
+            lenient.resolve_recipient("nobody.eth").await.unwrap(),
+            "nobody.eth"
+        );
+
+        let strict = EnsResolver::new(server.base_url()).with_strict(true);
+        assert!(matches!(
+            strict.resolve_recipient("nobody.eth").await,
+            Err(NameResolutionError::Unresolvable(_))
+        ));
+    }
+}
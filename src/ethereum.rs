@@ -4,8 +4,10 @@
 //! message signing, transaction signing, typed data signing, and more. All methods
 //! are designed to work with Privy's embedded wallet infrastructure.
 
+#[cfg(feature = "alloy")]
+use crate::BlobTransactionError;
 use crate::{
-    AuthorizationContext, PrivySignedApiError,
+    AuthorizationContext, PreflightError, PrivySignedApiError, WalletId,
     generated::{
         Error, ResponseValue,
         types::{
@@ -19,10 +21,12 @@ use crate::{
             EthereumSign7702AuthorizationRpcInputParams, EthereumSignTransactionRpcInput,
             EthereumSignTransactionRpcInputMethod, EthereumSignTransactionRpcInputParams,
             EthereumSignTypedDataRpcInput, EthereumSignTypedDataRpcInputMethod,
-            EthereumSignTypedDataRpcInputParams, EthereumTypedDataInput, Hex,
-            UnsignedEthereumTransaction, WalletRpcRequestBody, WalletRpcResponse,
+            EthereumSignTypedDataRpcInputParams, EthereumTypedDataInput, Hex, Quantity,
+            UnsignedEthereumTransaction, UnsignedStandardEthereumTransaction, WalletRpcRequestBody,
+            WalletRpcResponse,
         },
     },
+    revert::RevertReason,
 };
 
 /// Options for sending an Ethereum transaction.
@@ -43,6 +47,15 @@ pub struct SendTransactionOptions {
     /// - `Some(false)` — explicitly disable gas sponsorship
     /// - `None` — use the server default
     pub sponsor: Option<bool>,
+    /// An Ethereum JSON-RPC endpoint to check the transaction with
+    /// `eth_call` before sending it. If the call reverts, the transaction is
+    /// not sent, and the revert reason is returned instead.
+    /// - `Some(url)` — check with `eth_call` on `url`
+    /// - `None` — send without checking
+    pub preflight_rpc_url: Option<String>,
+    /// Signatures of custom errors to decode preflight reverts with, such as
+    /// `InsufficientBalance(uint256,uint256)`.
+    pub custom_errors: Vec<String>,
 }
 
 impl SendTransactionOptions {
@@ -56,6 +69,95 @@ impl SendTransactionOptions {
         self.sponsor = Some(sponsor);
         self
     }
+
+    /// Checks the transaction with `eth_call` on the given rpc endpoint
+    /// before sending.
+    pub fn with_preflight(mut self, rpc_url: impl Into<String>) -> Self {
+        self.preflight_rpc_url = Some(rpc_url.into());
+        self
+    }
+
+    /// Adds custom error signatures to decode preflight reverts with. See
+    /// [`RevertReason::decode`](crate::revert::RevertReason::decode).
+    pub fn with_custom_errors(
+        mut self,
+        signatures: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.custom_errors
+            .extend(signatures.into_iter().map(Into::into));
+        self
+    }
+}
+
+fn quantity_hex(quantity: &Quantity) -> String {
+    match quantity {
+        Quantity::Hex(hex) => hex.to_string(),
+        Quantity::Integer(n) => format!("{n:#x}"),
+    }
+}
+
+/// Run `transaction` with `eth_call` from `from`, returning why it would
+/// fail if it would.
+async fn preflight(
+    rpc_url: &str,
+    from: &str,
+    transaction: &UnsignedStandardEthereumTransaction,
+    custom_errors: &[String],
+) -> Result<(), PreflightError> {
+    let mut call = serde_json::json!({ "from": from });
+    if let Some(to) = &transaction.to {
+        call["to"] = to.as_str().into();
+    }
+    if let Some(data) = &transaction.data {
+        call["data"] = data.to_string().into();
+    }
+    if let Some(value) = &transaction.value {
+        call["value"] = quantity_hex(value).into();
+    }
+    if let Some(gas) = &transaction.gas_limit {
+        call["gas"] = quantity_hex(gas).into();
+    }
+
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "eth_call",
+        "params": [call, "latest"],
+    });
+
+    let response: serde_json::Value = crate::utils::RPC_HTTP
+        .post(rpc_url)
+        .json(&body)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let Some(error) = response.get("error") else {
+        return if response.get("result").is_some() {
+            Ok(())
+        } else {
+            Err(PreflightError::InvalidResponse(response.to_string()))
+        };
+    };
+
+    // nodes return the revert data either directly or nested in an object
+    let data = error["data"]
+        .as_str()
+        .or_else(|| error["data"]["data"].as_str());
+    let message = error["message"].as_str().unwrap_or_default();
+
+    match data.map(|data| hex::decode(data.trim_start_matches("0x"))) {
+        Some(Ok(data)) => Err(PreflightError::Reverted(RevertReason::decode(
+            &data,
+            custom_errors,
+        ))),
+        _ if message.contains("revert") => {
+            Err(PreflightError::Reverted(RevertReason::Unknown(Vec::new())))
+        }
+        _ => Err(PreflightError::Failed(error.to_string())),
+    }
 }
 
 /// Service for Ethereum-specific wallet operations.
@@ -74,7 +176,7 @@ impl SendTransactionOptions {
 /// ```rust,no_run
 /// # use anyhow::Result;
 /// # async fn example() -> Result<()> {
-/// use privy_rs::{AuthorizationContext, generated::types::*};
+/// use privy_rs::{AuthorizationContext, WalletId, generated::types::*};
 /// # use privy_rs::PrivyClient;
 ///
 /// # let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
@@ -84,7 +186,7 @@ impl SendTransactionOptions {
 /// // Sign a UTF-8 message
 /// let result = ethereum_service
 ///     .sign_message(
-///         "wallet_id",
+///         WalletId::new("wallet_id"),
 ///         "Hello, Ethereum!",
 ///         &auth_ctx,
 ///         None, // no idempotency key
@@ -95,6 +197,9 @@ impl SendTransactionOptions {
 /// ```
 pub struct EthereumService {
     wallets_client: crate::subclients::WalletsClient,
+    context: Option<AuthorizationContext>,
+    #[cfg(feature = "ens")]
+    ens: Option<crate::ens::EnsResolver>,
 }
 
 impl EthereumService {
@@ -102,7 +207,50 @@ impl EthereumService {
     ///
     /// This is typically called internally by `WalletsClient::ethereum()`.
     pub(crate) fn new(wallets_client: crate::subclients::WalletsClient) -> Self {
-        Self { wallets_client }
+        Self {
+            wallets_client,
+            context: None,
+            #[cfg(feature = "ens")]
+            ens: None,
+        }
+    }
+
+    /// Bind a default authorization context, used by every call on this
+    /// service that is passed `None` in place of one.
+    ///
+    /// ```rust,no_run
+    /// # use privy_rs::{AuthorizationContext, PrivyClient, WalletId};
+    /// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PrivyClient::new_from_env()?;
+    /// let ctx = AuthorizationContext::new();
+    /// let other_ctx = AuthorizationContext::new();
+    ///
+    /// let ethereum = client.wallets().ethereum().with_context(ctx);
+    /// ethereum
+    ///     .sign_message(WalletId::new("wallet_id"), "hello", None, None)
+    ///     .await?;
+    /// // a call can still override it
+    /// ethereum
+    ///     .sign_message(WalletId::new("other_wallet_id"), "hello", &other_ctx, None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_context(mut self, ctx: AuthorizationContext) -> Self {
+        self.context = Some(ctx);
+        self
+    }
+
+    /// Resolve ENS names used as the `to` of transactions sent through
+    /// this service. See the [`ens`](crate::ens) module for details.
+    ///
+    /// # Feature Flag
+    /// Requires the `ens` feature to be enabled.
+    #[cfg(feature = "ens")]
+    pub fn with_ens(mut self, resolver: crate::ens::EnsResolver) -> Self {
+        self.ens = Some(resolver);
+        self
     }
 
     /// Signs a UTF-8 encoded message for an Ethereum wallet using the `personal_sign` method.
@@ -115,7 +263,8 @@ impl EthereumService {
     ///
     /// * `wallet_id` - The ID of the wallet to use for signing
     /// * `message` - The UTF-8 message string to be signed
-    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing
+    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing,
+    ///   or `None` to use the one bound with `with_context`
     /// * `idempotency_key` - Optional idempotency key for the request to prevent duplicate operations
     ///
     /// # Returns
@@ -127,7 +276,7 @@ impl EthereumService {
     /// ```rust,no_run
     /// # use anyhow::Result;
     /// # async fn example() -> Result<()> {
-    /// use privy_rs::{AuthorizationContext, generated::types::*};
+    /// use privy_rs::{AuthorizationContext, WalletId, generated::types::*};
     /// # use privy_rs::PrivyClient;
     ///
     /// # let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
@@ -136,7 +285,7 @@ impl EthereumService {
     ///
     /// let signature = ethereum_service
     ///     .sign_message(
-    ///         "clz2rqy4500061234abcd1234",
+    ///         WalletId::new("clz2rqy4500061234abcd1234"),
     ///         "Hello, Ethereum!",
     ///         &auth_ctx,
     ///         Some("unique-request-id-123"),
@@ -157,22 +306,26 @@ impl EthereumService {
     /// - The signing operation fails on the server
     pub async fn sign_message(
         &self,
-        wallet_id: &str,
+        wallet_id: impl Into<WalletId>,
         message: &str,
-        authorization_context: &AuthorizationContext,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
         idempotency_key: Option<&str>,
     ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
-        let rpc_body = WalletRpcRequestBody::EthereumPersonalSignRpcInput(EthereumPersonalSignRpcInput {
-            address: None,
-            chain_type: None,
-            method: EthereumPersonalSignRpcInputMethod::PersonalSign,
-            params: EthereumPersonalSignRpcInputParams {
-                encoding: EthereumPersonalSignRpcInputParamsEncoding::Utf8,
-                message: message.parse::<EthereumPersonalSignRpcInputParamsMessage>()
-                    .map_err(|e| Error::InvalidRequest(e.to_string()))?,
-            },
-            wallet_id: None,
-        });
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
+        let rpc_body =
+            WalletRpcRequestBody::EthereumPersonalSignRpcInput(EthereumPersonalSignRpcInput {
+                address: None,
+                chain_type: None,
+                method: EthereumPersonalSignRpcInputMethod::PersonalSign,
+                params: EthereumPersonalSignRpcInputParams {
+                    encoding: EthereumPersonalSignRpcInputParamsEncoding::Utf8,
+                    message: message
+                        .parse::<EthereumPersonalSignRpcInputParamsMessage>()
+                        .map_err(|e| Error::InvalidRequest(e.to_string()))?,
+                },
+                wallet_id: None,
+            });
 
         self.wallets_client
             .rpc(wallet_id, authorization_context, idempotency_key, &rpc_body)
@@ -188,7 +341,8 @@ impl EthereumService {
     ///
     /// * `wallet_id` - The ID of the wallet to use for signing
     /// * `message` - The message byte array to be signed
-    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing
+    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing,
+    ///   or `None` to use the one bound with `with_context`
     /// * `idempotency_key` - Optional idempotency key for the request
     ///
     /// # Returns
@@ -200,7 +354,7 @@ impl EthereumService {
     /// ```rust,no_run
     /// # use anyhow::Result;
     /// # async fn example() -> Result<()> {
-    /// use privy_rs::{AuthorizationContext, generated::types::*};
+    /// use privy_rs::{AuthorizationContext, WalletId, generated::types::*};
     /// # use privy_rs::PrivyClient;
     ///
     /// # let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
@@ -209,7 +363,7 @@ impl EthereumService {
     ///
     /// let message_bytes = b"Hello, bytes!";
     /// let signature = ethereum_service
-    ///     .sign_message_bytes("clz2rqy4500061234abcd1234", message_bytes, &auth_ctx, None)
+    ///     .sign_message_bytes(WalletId::new("clz2rqy4500061234abcd1234"), message_bytes, &auth_ctx, None)
     ///     .await?;
     ///
     /// println!("Byte message signed successfully");
@@ -218,24 +372,28 @@ impl EthereumService {
     /// ```
     pub async fn sign_message_bytes(
         &self,
-        wallet_id: &str,
+        wallet_id: impl Into<WalletId>,
         message: &[u8],
-        authorization_context: &AuthorizationContext,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
         idempotency_key: Option<&str>,
     ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
         let hex_message = format!("0x{}", hex::encode(message));
 
-        let rpc_body = WalletRpcRequestBody::EthereumPersonalSignRpcInput(EthereumPersonalSignRpcInput {
-            address: None,
-            chain_type: None,
-            method: EthereumPersonalSignRpcInputMethod::PersonalSign,
-            params: EthereumPersonalSignRpcInputParams {
-                encoding: EthereumPersonalSignRpcInputParamsEncoding::Hex,
-                message: hex_message.parse::<EthereumPersonalSignRpcInputParamsMessage>()
-                    .map_err(|e| Error::InvalidRequest(e.to_string()))?,
-            },
-            wallet_id: None,
-        });
+        let rpc_body =
+            WalletRpcRequestBody::EthereumPersonalSignRpcInput(EthereumPersonalSignRpcInput {
+                address: None,
+                chain_type: None,
+                method: EthereumPersonalSignRpcInputMethod::PersonalSign,
+                params: EthereumPersonalSignRpcInputParams {
+                    encoding: EthereumPersonalSignRpcInputParamsEncoding::Hex,
+                    message: hex_message
+                        .parse::<EthereumPersonalSignRpcInputParamsMessage>()
+                        .map_err(|e| Error::InvalidRequest(e.to_string()))?,
+                },
+                wallet_id: None,
+            });
 
         self.wallets_client
             .rpc(wallet_id, authorization_context, idempotency_key, &rpc_body)
@@ -252,7 +410,8 @@ impl EthereumService {
     ///
     /// * `wallet_id` - The ID of the wallet to use for signing
     /// * `hash` - The hash to sign (typically 32 bytes as hex string with 0x prefix)
-    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing
+    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing,
+    ///   or `None` to use the one bound with `with_context`
     /// * `idempotency_key` - Optional idempotency key for the request
     ///
     /// # Returns
@@ -264,7 +423,7 @@ impl EthereumService {
     /// ```rust,no_run
     /// # use anyhow::Result;
     /// # async fn example() -> Result<()> {
-    /// use privy_rs::{AuthorizationContext, generated::types::*};
+    /// use privy_rs::{AuthorizationContext, WalletId, generated::types::*};
     /// # use privy_rs::PrivyClient;
     ///
     /// # let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
@@ -274,7 +433,7 @@ impl EthereumService {
     /// // Pre-computed keccak256 hash
     /// let hash = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
     /// let signature = ethereum_service
-    ///     .sign_secp256k1("clz2rqy4500061234abcd1234", hash, &auth_ctx, None)
+    ///     .sign_secp256k1(WalletId::new("clz2rqy4500061234abcd1234"), hash, &auth_ctx, None)
     ///     .await?;
     ///
     /// println!("Hash signed with secp256k1");
@@ -288,11 +447,13 @@ impl EthereumService {
     /// or `sign_typed_data()` which handle the hashing automatically.
     pub async fn sign_secp256k1(
         &self,
-        wallet_id: &str,
+        wallet_id: impl Into<WalletId>,
         hash: &str,
-        authorization_context: &AuthorizationContext,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
         idempotency_key: Option<&str>,
     ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
         let rpc_body =
             WalletRpcRequestBody::EthereumSecp256k1SignRpcInput(EthereumSecp256k1SignRpcInput {
                 address: None,
@@ -320,7 +481,8 @@ impl EthereumService {
     ///
     /// * `wallet_id` - The ID of the wallet to use for signing
     /// * `params` - The parameters for the eth_sign7702Authorization RPC method including contract address, chain ID, and nonce
-    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing
+    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing,
+    ///   or `None` to use the one bound with `with_context`
     /// * `idempotency_key` - Optional idempotency key for the request
     ///
     /// # Returns
@@ -332,7 +494,7 @@ impl EthereumService {
     /// ```rust,no_run
     /// # use anyhow::Result;
     /// # async fn example() -> Result<()> {
-    /// use privy_rs::{AuthorizationContext, generated::types::*};
+    /// use privy_rs::{AuthorizationContext, WalletId, generated::types::*};
     /// # use privy_rs::PrivyClient;
     ///
     /// # let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
@@ -347,7 +509,7 @@ impl EthereumService {
     /// };
     ///
     /// let authorization = ethereum_service
-    ///     .sign_7702_authorization("clz2rqy4500061234abcd1234", params, &auth_ctx, None)
+    ///     .sign_7702_authorization(WalletId::new("clz2rqy4500061234abcd1234"), params, &auth_ctx, None)
     ///     .await?;
     ///
     /// println!("7702 authorization signed successfully");
@@ -356,11 +518,13 @@ impl EthereumService {
     /// ```
     pub async fn sign_7702_authorization(
         &self,
-        wallet_id: &str,
+        wallet_id: impl Into<WalletId>,
         params: EthereumSign7702AuthorizationRpcInputParams,
-        authorization_context: &AuthorizationContext,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
         idempotency_key: Option<&str>,
     ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
         let rpc_body = WalletRpcRequestBody::EthereumSign7702AuthorizationRpcInput(
             EthereumSign7702AuthorizationRpcInput {
                 address: None,
@@ -386,7 +550,8 @@ impl EthereumService {
     ///
     /// * `wallet_id` - The ID of the wallet to use for signing
     /// * `typed_data` - The typed data structure to be signed, conforming to EIP-712 format
-    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing
+    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing,
+    ///   or `None` to use the one bound with `with_context`
     /// * `idempotency_key` - Optional idempotency key for the request
     ///
     /// # Returns
@@ -398,7 +563,7 @@ impl EthereumService {
     /// ```rust,no_run
     /// # use anyhow::Result;
     /// # async fn example() -> Result<()> {
-    /// use privy_rs::{AuthorizationContext, generated::types::*};
+    /// use privy_rs::{AuthorizationContext, WalletId, generated::types::*};
     /// # use privy_rs::PrivyClient;
     ///
     /// # let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
@@ -414,7 +579,7 @@ impl EthereumService {
     /// };
     ///
     /// let signature = ethereum_service
-    ///     .sign_typed_data("clz2rqy4500061234abcd1234", typed_data, &auth_ctx, None)
+    ///     .sign_typed_data(WalletId::new("clz2rqy4500061234abcd1234"), typed_data, &auth_ctx, None)
     ///     .await?;
     ///
     /// println!("Typed data signed successfully");
@@ -429,11 +594,13 @@ impl EthereumService {
     /// specification of the required structure.
     pub async fn sign_typed_data(
         &self,
-        wallet_id: &str,
+        wallet_id: impl Into<WalletId>,
         typed_data: EthereumTypedDataInput,
-        authorization_context: &AuthorizationContext,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
         idempotency_key: Option<&str>,
     ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
         let rpc_body =
             WalletRpcRequestBody::EthereumSignTypedDataRpcInput(EthereumSignTypedDataRpcInput {
                 address: None,
@@ -457,19 +624,22 @@ impl EthereumService {
     ///
     /// * `wallet_id` - The ID of the wallet to use for signing
     /// * `transaction` - The transaction object to be signed including to, value, data, gas, etc.
-    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing
+    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing,
+    ///   or `None` to use the one bound with `with_context`
     /// * `idempotency_key` - Optional idempotency key for the request
     ///
     /// # Returns
     ///
     /// Returns a `ResponseValue<WalletRpcResponse>` containing the signed transaction data.
+    /// With the `alloy` feature, `privy_rs::alloy::decode_signed_transaction_hex`
+    /// decodes it to check what was signed and read its hash.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// # use anyhow::Result;
     /// # async fn example() -> Result<()> {
-    /// use privy_rs::{AuthorizationContext, generated::types::*};
+    /// use privy_rs::{AuthorizationContext, WalletId, generated::types::*};
     /// # use privy_rs::PrivyClient;
     ///
     /// # let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
@@ -492,7 +662,7 @@ impl EthereumService {
     /// };
     ///
     /// let signed_tx = ethereum_service
-    ///     .sign_transaction("clz2rqy4500061234abcd1234", transaction.into(), &auth_ctx, None)
+    ///     .sign_transaction(WalletId::new("clz2rqy4500061234abcd1234"), transaction.into(), &auth_ctx, None)
     ///     .await?;
     ///
     /// println!("Transaction signed successfully");
@@ -501,19 +671,22 @@ impl EthereumService {
     /// ```
     pub async fn sign_transaction(
         &self,
-        wallet_id: &str,
+        wallet_id: impl Into<WalletId>,
         transaction: UnsignedEthereumTransaction,
-        authorization_context: &AuthorizationContext,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
         idempotency_key: Option<&str>,
     ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
-        let rpc_body =
-            WalletRpcRequestBody::EthereumSignTransactionRpcInput(EthereumSignTransactionRpcInput {
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
+        let rpc_body = WalletRpcRequestBody::EthereumSignTransactionRpcInput(
+            EthereumSignTransactionRpcInput {
                 address: None,
                 chain_type: None,
                 method: EthereumSignTransactionRpcInputMethod::EthSignTransaction,
                 params: EthereumSignTransactionRpcInputParams { transaction },
                 wallet_id: None,
-            });
+            },
+        );
 
         self.wallets_client
             .rpc(wallet_id, authorization_context, idempotency_key, &rpc_body)
@@ -530,7 +703,8 @@ impl EthereumService {
     /// * `wallet_id` - The ID of the wallet used for the transaction
     /// * `caip2` - The CAIP-2 chain ID of the Ethereum network (e.g., "eip155:1" for Ethereum Mainnet, "eip155:11155111" for Sepolia)
     /// * `transaction` - The transaction object to be sent
-    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing
+    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing,
+    ///   or `None` to use the one bound with `with_context`
     /// * `idempotency_key` - Optional idempotency key for the request
     ///
     /// # Returns
@@ -542,7 +716,7 @@ impl EthereumService {
     /// ```rust,no_run
     /// # use anyhow::Result;
     /// # async fn example() -> Result<()> {
-    /// use privy_rs::{AuthorizationContext, generated::types::*};
+    /// use privy_rs::{AuthorizationContext, WalletId, generated::types::*};
     /// # use privy_rs::PrivyClient;
     ///
     /// # let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
@@ -566,7 +740,7 @@ impl EthereumService {
     ///
     /// let result = ethereum_service
     ///     .send_transaction(
-    ///         "clz2rqy4500061234abcd1234",
+    ///         WalletId::new("clz2rqy4500061234abcd1234"),
     ///         "eip155:1",
     ///         transaction.into(),
     ///         &auth_ctx,
@@ -585,14 +759,17 @@ impl EthereumService {
     /// - This method requires sufficient balance in the wallet to cover gas costs and transfer value
     /// - The transaction will be mined and included in a block if successful
     /// - Common CAIP-2 chain IDs: "eip155:1" (Ethereum), "eip155:137" (Polygon), "eip155:11155111" (Sepolia testnet)
+    /// - With the `ens` feature, ENS names in `to` are resolved if a resolver was attached with `with_ens`
     pub async fn send_transaction(
         &self,
-        wallet_id: &str,
+        wallet_id: impl Into<WalletId>,
         caip2: &str,
         transaction: UnsignedEthereumTransaction,
-        authorization_context: &AuthorizationContext,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
         idempotency_key: Option<&str>,
     ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
         self.send_transaction_with_options(
             wallet_id,
             caip2,
@@ -604,7 +781,8 @@ impl EthereumService {
         .await
     }
 
-    /// Signs and sends a transaction with additional options such as gas sponsorship.
+    /// Signs and sends a transaction with additional options such as gas sponsorship
+    /// or an `eth_call` preflight. Tempo transactions are never preflighted.
     ///
     /// This method is identical to [`send_transaction`](Self::send_transaction) but accepts
     /// a [`SendTransactionOptions`] struct for additional configuration.
@@ -614,7 +792,7 @@ impl EthereumService {
     /// ```rust,no_run
     /// # use anyhow::Result;
     /// # async fn example() -> Result<()> {
-    /// use privy_rs::{AuthorizationContext, ethereum::SendTransactionOptions, generated::types::*};
+    /// use privy_rs::{AuthorizationContext, WalletId, ethereum::SendTransactionOptions, generated::types::*};
     /// # use privy_rs::PrivyClient;
     ///
     /// # let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
@@ -633,7 +811,7 @@ impl EthereumService {
     ///
     /// let result = ethereum_service
     ///     .send_transaction_with_options(
-    ///         "wallet_id",
+    ///         WalletId::new("wallet_id"),
     ///         "eip155:1",
     ///         transaction,
     ///         &auth_ctx,
@@ -646,15 +824,58 @@ impl EthereumService {
     /// ```
     pub async fn send_transaction_with_options(
         &self,
-        wallet_id: &str,
+        wallet_id: impl Into<WalletId>,
         caip2: &str,
         transaction: UnsignedEthereumTransaction,
-        authorization_context: &AuthorizationContext,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
         idempotency_key: Option<&str>,
         options: &SendTransactionOptions,
     ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
-        let rpc_body =
-            WalletRpcRequestBody::EthereumSendTransactionRpcInput(EthereumSendTransactionRpcInput {
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
+        #[cfg(feature = "ens")]
+        let transaction = match (&self.ens, transaction) {
+            (
+                Some(ens),
+                UnsignedEthereumTransaction::StandardEthereumTransaction(mut transaction),
+            ) => {
+                if let Some(to) = &transaction.to {
+                    transaction.to = Some(
+                        ens.resolve_recipient(to)
+                            .await
+                            .map_err(|e| Error::InvalidRequest(e.to_string()))?,
+                    );
+                }
+                transaction.into()
+            }
+            (_, transaction) => transaction,
+        };
+
+        if let UnsignedEthereumTransaction::StandardEthereumTransaction(standard) = &transaction {
+            if let Some(to) = &standard.to {
+                crate::address::validate_address(caip2, to)?;
+            }
+        }
+
+        let wallet_id = wallet_id.into();
+        if let (Some(rpc_url), UnsignedEthereumTransaction::StandardEthereumTransaction(standard)) =
+            (&options.preflight_rpc_url, &transaction)
+        {
+            let from = match &standard.from {
+                Some(from) => from.clone(),
+                None => {
+                    self.wallets_client
+                        .get(&wallet_id)
+                        .await?
+                        .into_inner()
+                        .address
+                }
+            };
+            preflight(rpc_url, &from, standard, &options.custom_errors).await?;
+        }
+
+        let rpc_body = WalletRpcRequestBody::EthereumSendTransactionRpcInput(
+            EthereumSendTransactionRpcInput {
                 address: None,
                 caip2: caip2
                     .parse()
@@ -683,7 +904,7 @@ impl EthereumService {
     ///
     /// # Example
     /// ```no_run
-    /// use privy_rs::{PrivyClient, AuthorizationContext, PrivateKey};
+    /// use privy_rs::{AuthorizationContext, PrivateKey, PrivyClient, WalletId};
     /// use alloy_signer::SignerSync;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -691,20 +912,35 @@ impl EthereumService {
     /// let private_key = std::fs::read_to_string("private_key.pem")?;
     /// let ctx = AuthorizationContext::new().push(PrivateKey(private_key));
     ///
-    /// let signer = client.wallets().ethereum().alloy("wallet_id", &ctx).await?;
+    /// let signer = client.wallets().ethereum().alloy(WalletId::new("wallet_id"), &ctx).await?;
     /// # Ok(())
     /// # }
     /// ```
     #[cfg(feature = "alloy")]
     pub async fn alloy(
         &self,
-        wallet_id: &str,
-        authorization_context: &AuthorizationContext,
+        wallet_id: impl Into<WalletId>,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
     ) -> Result<crate::alloy::PrivyAlloyWallet, crate::PrivyApiError> {
-        let wallet_response = self.wallets_client.get(wallet_id).await?;
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
+        let wallet_id = wallet_id.into();
+        let wallet_response = self.wallets_client.get(&wallet_id).await?;
         let wallet = wallet_response.into_inner();
 
-        let address = wallet.address.parse().map_err(|e| {
+        self.alloy_at(&wallet_id, &wallet.address, authorization_context)
+    }
+
+    /// The alloy signer of a wallet whose address is already known.
+    #[cfg(feature = "alloy")]
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn alloy_at(
+        &self,
+        wallet_id: &WalletId,
+        address: &str,
+        authorization_context: &AuthorizationContext,
+    ) -> Result<crate::alloy::PrivyAlloyWallet, crate::PrivyApiError> {
+        let address = address.parse().map_err(|e| {
             crate::PrivyApiError::InvalidRequest(format!("Failed to parse wallet address: {e}"))
         })?;
 
@@ -715,4 +951,55 @@ impl EthereumService {
             authorization_context.clone(),
         ))
     }
+
+    /// Sign an EIP-4844 blob transaction.
+    ///
+    /// Privy's `eth_signTransaction` does not accept blob transactions, so
+    /// this signs the signature hash of the transaction with `secp256k1_sign`,
+    /// the way `PrivyAlloyWallet` signs, and attaches the signature. Only the
+    /// versioned hashes are signed, the sidecar is checked locally to carry a
+    /// blob, commitment and proof for each of them. The KZG proofs are left to
+    /// the node that receives the transaction.
+    ///
+    /// The result encodes, with its sidecar, to the network form that
+    /// `eth_sendRawTransaction` expects.
+    ///
+    /// # Feature Flag
+    /// Requires the `alloy` feature to be enabled.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use alloy_consensus::TxEip4844WithSidecar;
+    /// use privy_rs::{AuthorizationContext, PrivyClient, WalletId};
+    ///
+    /// # async fn example(transaction: TxEip4844WithSidecar) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PrivyClient::new_from_env()?;
+    /// let ctx = AuthorizationContext::new();
+    ///
+    /// let signed = client
+    ///     .wallets()
+    ///     .ethereum()
+    ///     .sign_blob_transaction(WalletId::new("wallet_id"), transaction, &ctx)
+    ///     .await?;
+    /// println!("blob transaction {}", signed.hash());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Fails if the sidecar does not match the transaction, if the wallet
+    /// cannot be fetched, or if Privy does not sign.
+    #[cfg(feature = "alloy")]
+    pub async fn sign_blob_transaction(
+        &self,
+        wallet_id: impl Into<WalletId>,
+        transaction: alloy_consensus::TxEip4844WithSidecar,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
+    ) -> Result<alloy_consensus::Signed<alloy_consensus::TxEip4844WithSidecar>, BlobTransactionError>
+    {
+        // fail before fetching the wallet
+        crate::alloy::blob::validate_sidecar(&transaction)?;
+        let signer = self.alloy(wallet_id, authorization_context).await?;
+        crate::alloy::blob::sign(&signer, transaction).await
+    }
 }
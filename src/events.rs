@@ -0,0 +1,291 @@
+//! A local bus of wallet events.
+//!
+//! An [`EventBus`] fans events out to every component that subscribed to
+//! it, so they do not each have to parse webhooks or poll the API on their
+//! own. Events come from one of two places:
+//!
+//! - the application's webhook handler, which verifies and parses Privy's
+//!   webhooks and hands the result to [`EventBus::publish`]
+//! - a polling fallback, [`EventBus::spawn_polling`], which watches the
+//!   transactions of a wallet and publishes the ones that are new or whose
+//!   status changed, for deployments that cannot receive webhooks
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use privy_rs::{PrivyClient, WalletId, events::{EventBus, PrivyEvent}, generated::types::*};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let bus = EventBus::new(256);
+//! let mut events = bus.subscribe();
+//!
+//! let polling = bus.spawn_polling(
+//!     tokio::runtime::Handle::current(),
+//!     &client.wallets(),
+//!     WalletId::new("wallet_id"),
+//!     WalletTransactionsChain::Base,
+//!     WalletTransactionsAsset::WalletEthereumAsset(WalletEthereumAsset::Usdc),
+//!     Duration::from_secs(10),
+//! );
+//!
+//! while let Ok(PrivyEvent::Transaction(transaction)) = events.recv().await {
+//!     println!("{} is {}", transaction.privy_transaction_id, transaction.status);
+//! }
+//! polling.shutdown().await;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Subscribers that fall more than the capacity of the bus behind miss the
+//! oldest events, and are told how many with `RecvError::Lagged`.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::{runtime::Handle, sync::broadcast};
+
+use crate::{
+    generated::types::{
+        WalletTransactionsAsset, WalletTransactionsChain,
+        WalletTransactionsResponseTransactionsItem,
+        WalletTransactionsResponseTransactionsItemStatus,
+    },
+    ids::WalletId,
+    maintenance::BackgroundTasks,
+    subclients::WalletsClient,
+};
+
+/// How many transactions each poll fetches, the latest ones first.
+const POLL_PAGE_SIZE: f64 = 100.0;
+
+/// An event published on an [`EventBus`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum PrivyEvent {
+    /// A transaction of a wallet was seen for the first time, or its status
+    /// changed.
+    Transaction(Box<WalletTransactionsResponseTransactionsItem>),
+}
+
+/// A broadcast channel of [`PrivyEvent`]s, see the [module docs](self).
+///
+/// Clones publish to and subscribe from the same channel.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<PrivyEvent>,
+}
+
+impl EventBus {
+    /// A bus that buffers up to `capacity` events for its slowest
+    /// subscriber.
+    ///
+    /// # Panics
+    /// If `capacity` is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            sender: broadcast::channel(capacity).0,
+        }
+    }
+
+    /// Receive every event published from now on.
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<PrivyEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Publish `event` to every subscriber, returning how many there are.
+    /// Events published while nobody is subscribed are dropped.
+    pub fn publish(&self, event: PrivyEvent) -> usize {
+        self.sender.send(event).unwrap_or(0)
+    }
+
+    /// Poll the latest transactions of a wallet every `interval` on
+    /// `runtime`, and publish the ones that were not seen before or whose
+    /// status changed since the last poll. Every transaction on the first
+    /// page is published when polling starts.
+    ///
+    /// Failed polls are logged and retried on the next tick. Polling stops
+    /// when the returned tasks are shut down or dropped.
+    pub fn spawn_polling(
+        &self,
+        runtime: Handle,
+        wallets: &WalletsClient,
+        wallet_id: impl Into<WalletId>,
+        chain: WalletTransactionsChain,
+        asset: WalletTransactionsAsset,
+        interval: Duration,
+    ) -> BackgroundTasks {
+        let poller = Arc::new(Poller {
+            bus: self.clone(),
+            wallets: wallets.clone(),
+            wallet_id: wallet_id.into(),
+            chain,
+            asset,
+            seen: Mutex::new(HashMap::new()),
+        });
+
+        let mut tasks = BackgroundTasks::new();
+        tasks.spawn(&runtime, "transaction polling", interval, move || {
+            let poller = poller.clone();
+            async move { poller.poll().await }
+        });
+        tasks
+    }
+}
+
+/// The state of one `EventBus::spawn_polling` loop.
+struct Poller {
+    bus: EventBus,
+    wallets: WalletsClient,
+    wallet_id: WalletId,
+    chain: WalletTransactionsChain,
+    asset: WalletTransactionsAsset,
+    /// The status of every transaction on the last page, by id. Only the
+    /// last page is kept, so this does not grow with the wallet's history.
+    seen: Mutex<HashMap<String, WalletTransactionsResponseTransactionsItemStatus>>,
+}
+
+impl Poller {
+    async fn poll(&self) {
+        let page = match self
+            .wallets
+            .transactions()
+            .get(
+                &self.wallet_id,
+                Some(&self.asset),
+                self.chain,
+                None,
+                Some(POLL_PAGE_SIZE),
+                None,
+                None,
+            )
+            .await
+        {
+            Ok(page) => page.into_inner(),
+            Err(e) => {
+                tracing::warn!(wallet_id = %self.wallet_id, "failed to poll transactions: {e}");
+                return;
+            }
+        };
+
+        let changed: Vec<_> = {
+            let mut seen = self.seen.lock().expect("lock poisoned");
+            let previous = std::mem::take(&mut *seen);
+            page.transactions
+                .into_iter()
+                .filter(|transaction| {
+                    seen.insert(transaction.privy_transaction_id.clone(), transaction.status);
+                    previous.get(&transaction.privy_transaction_id) != Some(&transaction.status)
+                })
+                .collect()
+        };
+
+        for transaction in changed {
+            self.bus
+                .publish(PrivyEvent::Transaction(Box::new(transaction)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use tokio::time::timeout;
+
+    use super::*;
+    use crate::{PrivyClient, client::PrivyClientOptions, generated::types::WalletEthereumAsset};
+
+    fn transaction(status: &str) -> serde_json::Value {
+        serde_json::json!({
+            "caip2": "eip155:8453",
+            "created_at": 1_741_834_854_578.0,
+            "details": {
+                "type": "transfer_sent",
+                "sender": "0xF1DBff66C993EE895C8cb176c30b07A559d76496",
+                "recipient": "0x742d35Cc6635C0532925a3b8c17d6d1E9C2F7ca0",
+                "chain": "base",
+                "asset": "usdc",
+                "raw_value": "1000000",
+                "raw_value_decimals": 6,
+                "display_values": { "usdc": "1" },
+            },
+            "privy_transaction_id": "tx_1",
+            "status": status,
+            "transaction_hash": "0x1234",
+            "wallet_id": "wallet_id",
+        })
+    }
+
+    #[tokio::test]
+    async fn test_polling_publishes_new_and_changed_transactions() {
+        let server = MockServer::start_async().await;
+        let page = |status: &'static str| {
+            let server = &server;
+            async move {
+                server
+                    .mock_async(|when, then| {
+                        when.method(GET).path("/v1/wallets/wallet_id/transactions");
+                        then.status(200).json_body(serde_json::json!({
+                            "transactions": [transaction(status)],
+                            "next_cursor": null,
+                        }));
+                    })
+                    .await
+            }
+        };
+
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let bus = EventBus::new(16);
+        let mut events = bus.subscribe();
+
+        let pending = page("pending").await;
+        let tasks = bus.spawn_polling(
+            Handle::current(),
+            &client.wallets(),
+            WalletId::new("wallet_id"),
+            WalletTransactionsChain::Base,
+            WalletTransactionsAsset::WalletEthereumAsset(WalletEthereumAsset::Usdc),
+            Duration::from_millis(10),
+        );
+
+        let status = |event: PrivyEvent| match event {
+            PrivyEvent::Transaction(transaction) => transaction.status,
+        };
+        let next = timeout(Duration::from_secs(5), events.recv())
+            .await
+            .unwrap();
+        assert_eq!(
+            status(next.unwrap()),
+            WalletTransactionsResponseTransactionsItemStatus::Pending
+        );
+
+        // unchanged transactions are not published again
+        while pending.calls_async().await < 3 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(events.try_recv().is_err());
+
+        pending.delete_async().await;
+        page("confirmed").await;
+        let next = timeout(Duration::from_secs(5), events.recv())
+            .await
+            .unwrap();
+        assert_eq!(
+            status(next.unwrap()),
+            WalletTransactionsResponseTransactionsItemStatus::Confirmed
+        );
+
+        tasks.shutdown().await;
+    }
+}
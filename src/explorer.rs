@@ -0,0 +1,158 @@
+//! Block explorer links for the networks supported by Privy.
+//!
+//! Networks are identified by their CAIP-2 chain id, the same identifier
+//! used throughout the Privy API, so there is no need to hard-code
+//! etherscan or solscan url templates in your application.
+//!
+//! ```rust
+//! use privy_rs::explorer::{explorer_url_for_address, explorer_url_for_tx};
+//!
+//! assert_eq!(
+//!     explorer_url_for_tx("eip155:8453", "0xabc").as_deref(),
+//!     Some("https://basescan.org/tx/0xabc")
+//! );
+//! assert_eq!(
+//!     explorer_url_for_address("solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1", "abc").as_deref(),
+//!     Some("https://solscan.io/account/abc?cluster=devnet")
+//! );
+//! ```
+
+/// A block explorer for a single network.
+struct Explorer {
+    /// The CAIP-2 identifier of the network.
+    caip2: &'static str,
+    /// The base url of the explorer, without a trailing slash.
+    base_url: &'static str,
+    /// The path segment used for address pages.
+    address_path: &'static str,
+    /// A query string appended to every link, used to select a cluster
+    /// on explorers that serve several networks from one domain.
+    query: &'static str,
+}
+
+const EXPLORERS: &[Explorer] = &[
+    Explorer {
+        caip2: "eip155:1",
+        base_url: "https://etherscan.io",
+        address_path: "address",
+        query: "",
+    },
+    Explorer {
+        caip2: "eip155:11155111",
+        base_url: "https://sepolia.etherscan.io",
+        address_path: "address",
+        query: "",
+    },
+    Explorer {
+        caip2: "eip155:8453",
+        base_url: "https://basescan.org",
+        address_path: "address",
+        query: "",
+    },
+    Explorer {
+        caip2: "eip155:84532",
+        base_url: "https://sepolia.basescan.org",
+        address_path: "address",
+        query: "",
+    },
+    Explorer {
+        caip2: "eip155:10",
+        base_url: "https://optimistic.etherscan.io",
+        address_path: "address",
+        query: "",
+    },
+    Explorer {
+        caip2: "eip155:11155420",
+        base_url: "https://sepolia-optimism.etherscan.io",
+        address_path: "address",
+        query: "",
+    },
+    Explorer {
+        caip2: "eip155:42161",
+        base_url: "https://arbiscan.io",
+        address_path: "address",
+        query: "",
+    },
+    Explorer {
+        caip2: "eip155:421614",
+        base_url: "https://sepolia.arbiscan.io",
+        address_path: "address",
+        query: "",
+    },
+    Explorer {
+        caip2: "eip155:137",
+        base_url: "https://polygonscan.com",
+        address_path: "address",
+        query: "",
+    },
+    Explorer {
+        caip2: "eip155:80002",
+        base_url: "https://amoy.polygonscan.com",
+        address_path: "address",
+        query: "",
+    },
+    Explorer {
+        caip2: "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp",
+        base_url: "https://solscan.io",
+        address_path: "account",
+        query: "",
+    },
+    Explorer {
+        caip2: "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1",
+        base_url: "https://solscan.io",
+        address_path: "account",
+        query: "?cluster=devnet",
+    },
+    Explorer {
+        caip2: "solana:4uhcVJyU9pJkvQyS88uRDiswHXSCkY3z",
+        base_url: "https://solscan.io",
+        address_path: "account",
+        query: "?cluster=testnet",
+    },
+];
+
+fn explorer(caip2: &str) -> Option<&'static Explorer> {
+    EXPLORERS.iter().find(|e| e.caip2 == caip2)
+}
+
+/// Returns a link to a transaction on the block explorer for the given
+/// network, or `None` if the network is not known.
+pub fn explorer_url_for_tx(caip2: &str, tx_hash: &str) -> Option<String> {
+    let explorer = explorer(caip2)?;
+    Some(format!(
+        "{}/tx/{}{}",
+        explorer.base_url, tx_hash, explorer.query
+    ))
+}
+
+/// Returns a link to an address (or Solana account) on the block explorer
+/// for the given network, or `None` if the network is not known.
+pub fn explorer_url_for_address(caip2: &str, address: &str) -> Option<String> {
+    let explorer = explorer(caip2)?;
+    Some(format!(
+        "{}/{}/{}{}",
+        explorer.base_url, explorer.address_path, address, explorer.query
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("eip155:1", "0xabc" => Some("https://etherscan.io/tx/0xabc".to_string()) ; "ethereum mainnet")]
+    #[test_case("eip155:84532", "0xabc" => Some("https://sepolia.basescan.org/tx/0xabc".to_string()) ; "base sepolia")]
+    #[test_case("solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1", "5sig" => Some("https://solscan.io/tx/5sig?cluster=devnet".to_string()) ; "solana devnet")]
+    #[test_case("eip155:999999", "0xabc" => None ; "unknown network")]
+    fn test_explorer_url_for_tx(caip2: &str, tx_hash: &str) -> Option<String> {
+        explorer_url_for_tx(caip2, tx_hash)
+    }
+
+    #[test_case("eip155:1", "0xabc" => Some("https://etherscan.io/address/0xabc".to_string()) ; "ethereum mainnet")]
+    #[test_case("solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp", "abc" => Some("https://solscan.io/account/abc".to_string()) ; "solana mainnet")]
+    #[test_case("solana:unknown", "abc" => None ; "unknown network")]
+    fn test_explorer_url_for_address(caip2: &str, address: &str) -> Option<String> {
+        explorer_url_for_address(caip2, address)
+    }
+}
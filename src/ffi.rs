@@ -0,0 +1,258 @@
+//! A C ABI for request canonicalization and signature encoding.
+//!
+//! Services written in other languages that hold their own authorization
+//! keys (for example in an HSM sidecar) still need to produce byte-for-byte
+//! the same canonical request as this crate, or their signatures will be
+//! rejected. Rather than reimplementing RFC-8785 canonicalization, they can
+//! link against this crate and call into it directly.
+//!
+//! The symbols are only exported when the crate is built as a C library,
+//! for example with `cargo rustc --release --features ffi --crate-type cdylib`.
+//!
+//! ```c
+//! char *canonical = NULL;
+//! int status = privy_format_request(
+//!     "app_id", "POST", "https://api.privy.io/v1/wallets/abc/rpc",
+//!     "{\"method\":\"personal_sign\"}", NULL, &canonical);
+//! if (status == PRIVY_FFI_OK) {
+//!     /* sign canonical with your own P-256 key ... */
+//!     privy_string_free(canonical);
+//! }
+//! ```
+//!
+//! All strings passed in must be valid, NUL-terminated UTF-8. Strings
+//! returned through out-pointers are owned by the caller and must be
+//! released with `privy_string_free`.
+
+use std::ffi::{CStr, CString, c_char};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+use crate::{Method, format_request_for_authorization_signature};
+
+/// The call succeeded and the out-pointer was written.
+pub const PRIVY_FFI_OK: i32 = 0;
+/// A required pointer was null.
+pub const PRIVY_FFI_NULL_POINTER: i32 = 1;
+/// A string argument was not valid UTF-8.
+pub const PRIVY_FFI_INVALID_UTF8: i32 = 2;
+/// The method was not one of `POST`, `PUT`, `PATCH` or `DELETE`.
+pub const PRIVY_FFI_INVALID_METHOD: i32 = 3;
+/// The body was not valid JSON.
+pub const PRIVY_FFI_INVALID_BODY: i32 = 4;
+/// The signature was not a valid 64 byte P-256 signature.
+pub const PRIVY_FFI_INVALID_SIGNATURE: i32 = 5;
+
+/// Read a required string argument.
+///
+/// # Safety
+/// `ptr` must be null or point to a NUL-terminated string.
+unsafe fn read_str<'a>(ptr: *const c_char) -> Result<&'a str, i32> {
+    unsafe { read_opt_str(ptr) }?.ok_or(PRIVY_FFI_NULL_POINTER)
+}
+
+/// Read an optional string argument, where null means `None`.
+///
+/// # Safety
+/// `ptr` must be null or point to a NUL-terminated string.
+unsafe fn read_opt_str<'a>(ptr: *const c_char) -> Result<Option<&'a str>, i32> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map(Some)
+        .map_err(|_| PRIVY_FFI_INVALID_UTF8)
+}
+
+/// Hand a string to the caller through an out-pointer.
+///
+/// # Safety
+/// `out` must be valid for writes.
+unsafe fn write_out(out: *mut *mut c_char, value: String) -> i32 {
+    // canonical json and base64 never contain interior NUL bytes
+    let Ok(value) = CString::new(value) else {
+        return PRIVY_FFI_INVALID_BODY;
+    };
+    unsafe { *out = value.into_raw() };
+    PRIVY_FFI_OK
+}
+
+fn parse_method(method: &str) -> Result<Method, i32> {
+    match method {
+        "POST" => Ok(Method::POST),
+        "PUT" => Ok(Method::PUT),
+        "PATCH" => Ok(Method::PATCH),
+        "DELETE" => Ok(Method::DELETE),
+        _ => Err(PRIVY_FFI_INVALID_METHOD),
+    }
+}
+
+/// Build the canonical request that authorization signatures are computed
+/// over. This is the C equivalent of
+/// [`format_request_for_authorization_signature`].
+///
+/// `body` is the JSON request body and may be null for requests without
+/// one. `idempotency_key` may be null if the request does not set the
+/// `privy-idempotency-key` header.
+///
+/// # Safety
+/// All non-null string pointers must point to NUL-terminated strings, and
+/// `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn privy_format_request(
+    app_id: *const c_char,
+    method: *const c_char,
+    url: *const c_char,
+    body: *const c_char,
+    idempotency_key: *const c_char,
+    out: *mut *mut c_char,
+) -> i32 {
+    if out.is_null() {
+        return PRIVY_FFI_NULL_POINTER;
+    }
+
+    let canonical = (|| {
+        let app_id = unsafe { read_str(app_id) }?;
+        let method = parse_method(unsafe { read_str(method) }?)?;
+        let url = unsafe { read_str(url) }?;
+        let body = match unsafe { read_opt_str(body) }? {
+            Some(body) => serde_json::from_str(body).map_err(|_| PRIVY_FFI_INVALID_BODY)?,
+            None => serde_json::Value::Null,
+        };
+        let idempotency_key = unsafe { read_opt_str(idempotency_key) }?;
+
+        format_request_for_authorization_signature(
+            app_id,
+            method,
+            url.to_owned(),
+            body,
+            idempotency_key.map(str::to_owned),
+        )
+        .map_err(|_| PRIVY_FFI_INVALID_BODY)
+    })();
+
+    match canonical {
+        Ok(canonical) => unsafe { write_out(out, canonical) },
+        Err(status) => status,
+    }
+}
+
+/// Encode a raw P-256 signature (the 32 byte `r` followed by the 32 byte
+/// `s`) as base64 DER, the format expected in the
+/// `privy-authorization-signature` header.
+///
+/// # Safety
+/// `signature` must be valid for reads of `len` bytes, and `out` must be
+/// valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn privy_signature_to_der_base64(
+    signature: *const u8,
+    len: usize,
+    out: *mut *mut c_char,
+) -> i32 {
+    if signature.is_null() || out.is_null() {
+        return PRIVY_FFI_NULL_POINTER;
+    }
+
+    let bytes = unsafe { std::slice::from_raw_parts(signature, len) };
+    match p256::ecdsa::Signature::from_slice(bytes) {
+        Ok(signature) => unsafe { write_out(out, STANDARD.encode(signature.to_der())) },
+        Err(_) => PRIVY_FFI_INVALID_SIGNATURE,
+    }
+}
+
+/// Free a string returned by this library. Passing null is a no-op.
+///
+/// # Safety
+/// `ptr` must be null or a pointer returned by this library that has not
+/// already been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn privy_string_free(ptr: *mut c_char) {
+    if !ptr.is_null() {
+        drop(unsafe { CString::from_raw(ptr) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use p256::ecdsa::{Signature, SigningKey, signature::Signer};
+
+    use super::*;
+
+    fn take(out: *mut c_char) -> String {
+        let value = unsafe { CStr::from_ptr(out) }
+            .to_str()
+            .expect("utf-8")
+            .to_owned();
+        unsafe { privy_string_free(out) };
+        value
+    }
+
+    #[test]
+    fn test_format_request_matches_rust() {
+        let body = serde_json::json!({"method": "personal_sign", "params": {"message": "hi"}});
+        let expected = format_request_for_authorization_signature(
+            "app",
+            Method::POST,
+            "https://api.privy.io/v1/wallets/w/rpc".to_owned(),
+            &body,
+            Some("key".to_owned()),
+        )
+        .expect("canonicalizes");
+
+        let body = CString::new(body.to_string()).expect("no nul");
+        let mut out = ptr::null_mut();
+        let status = unsafe {
+            privy_format_request(
+                c"app".as_ptr(),
+                c"POST".as_ptr(),
+                c"https://api.privy.io/v1/wallets/w/rpc".as_ptr(),
+                body.as_ptr(),
+                c"key".as_ptr(),
+                &mut out,
+            )
+        };
+
+        assert_eq!(status, PRIVY_FFI_OK);
+        assert_eq!(take(out), expected);
+    }
+
+    #[test]
+    fn test_format_request_rejects_bad_input() {
+        let mut out = ptr::null_mut();
+        let call = |method: &CStr, body: &CStr, out: &mut *mut c_char| unsafe {
+            privy_format_request(
+                c"app".as_ptr(),
+                method.as_ptr(),
+                c"https://api.privy.io".as_ptr(),
+                body.as_ptr(),
+                ptr::null(),
+                out,
+            )
+        };
+
+        assert_eq!(call(c"GET", c"{}", &mut out), PRIVY_FFI_INVALID_METHOD);
+        assert_eq!(call(c"POST", c"{", &mut out), PRIVY_FFI_INVALID_BODY);
+        assert!(out.is_null());
+    }
+
+    #[test]
+    fn test_signature_to_der_base64() {
+        let key = SigningKey::from_slice(&[7u8; 32]).expect("valid scalar");
+        let signature: Signature = key.sign(b"message");
+        let raw = signature.to_bytes();
+
+        let mut out = ptr::null_mut();
+        let status = unsafe { privy_signature_to_der_base64(raw.as_ptr(), raw.len(), &mut out) };
+
+        assert_eq!(status, PRIVY_FFI_OK);
+        assert_eq!(take(out), STANDARD.encode(signature.to_der()));
+
+        let status = unsafe { privy_signature_to_der_base64(raw.as_ptr(), 10, &mut out) };
+        assert_eq!(status, PRIVY_FFI_INVALID_SIGNATURE);
+    }
+}
@@ -0,0 +1,143 @@
+//! Entry points for fuzzing the security critical parsing paths.
+//!
+//! Each function takes the raw bytes of a fuzzer input, splits them into
+//! the inputs of the path it covers, and panics only if that path breaks
+//! one of its guarantees. Malformed inputs must be rejected with an error,
+//! never with a panic. The `cargo-fuzz` targets in `fuzz/` call these,
+//! see `fuzz/README.md`:
+//!
+//! ```sh
+//! cargo +nightly fuzz run canonicalization
+//! ```
+//!
+//! Inputs made of several parts separate them with newlines, the last part
+//! taking the rest of the input.
+
+use crate::{Method, PrivyHpke, format_request_for_authorization_signature};
+
+/// Split `data` into `N` newline separated UTF-8 parts, the last one
+/// taking the rest of the input.
+fn parts<const N: usize>(data: &[u8]) -> Option<[&str; N]> {
+    let data = std::str::from_utf8(data).ok()?;
+    let mut parts = data.splitn(N, '\n');
+    let parts: [&str; N] = std::array::from_fn(|_| parts.next().unwrap_or(""));
+    Some(parts)
+}
+
+/// Canonicalize a request whose app id, url, idempotency key and JSON
+/// body are read from `data`, checking that canonicalizing is idempotent:
+/// the canonical body, parsed back, canonicalizes to the same request.
+pub fn canonicalization(data: &[u8]) {
+    let Some([app_id, url, idempotency_key, body]) = parts::<4>(data) else {
+        return;
+    };
+    let Ok(body) = serde_json::from_str::<serde_json::Value>(body) else {
+        return;
+    };
+    let idempotency_key = (!idempotency_key.is_empty()).then(|| idempotency_key.to_string());
+
+    let canonical = format_request_for_authorization_signature(
+        app_id,
+        Method::POST,
+        url.to_string(),
+        &body,
+        idempotency_key.clone(),
+    )
+    .expect("a JSON value always serializes");
+
+    let parsed: serde_json::Value =
+        serde_json::from_str(&canonical).expect("the canonical request is JSON");
+    let again = format_request_for_authorization_signature(
+        app_id,
+        Method::POST,
+        url.to_string(),
+        &parsed["body"],
+        idempotency_key,
+    )
+    .expect("a JSON value always serializes");
+    assert_eq!(canonical, again, "canonicalization is not idempotent");
+}
+
+/// Decrypt an HPKE encapsulated key and ciphertext read from `data`, as
+/// they come in Privy's authenticate and export responses.
+pub fn hpke_decryption(data: &[u8]) {
+    let Some([encapsulated_key, ciphertext]) = parts::<2>(data) else {
+        return;
+    };
+    let _ = PrivyHpke::new().decrypt_p256(encapsulated_key, ciphertext);
+}
+
+/// Parse a `raw_sign` signature read from `data` on every curve.
+pub fn raw_signature(data: &[u8]) {
+    use crate::signature::{RawSignature, SignatureCurve};
+
+    let Ok(signature) = std::str::from_utf8(data) else {
+        return;
+    };
+    for curve in [
+        SignatureCurve::Secp256k1,
+        SignatureCurve::Ed25519,
+        SignatureCurve::P256,
+    ] {
+        if let Ok(parsed) = RawSignature::from_hex(signature, curve) {
+            assert_eq!(parsed.curve(), curve);
+        }
+    }
+}
+
+/// Verify a webhook whose `svix-id`, `svix-timestamp` and `svix-signature`
+/// headers and payload are read from `data`, checking that it is rejected:
+/// the fuzzer does not know the secret, so it can never sign a webhook.
+#[cfg(feature = "webhooks")]
+pub fn webhook_signature(data: &[u8]) {
+    use crate::webhooks::{WebhookHeaders, WebhookVerifier};
+
+    let Some([id, timestamp, signature, payload]) = parts::<4>(data) else {
+        return;
+    };
+    let headers = WebhookHeaders {
+        id: id.to_string(),
+        timestamp: timestamp.to_string(),
+        signature: signature.to_string(),
+    };
+    // with the default tolerance the timestamp is checked against the clock,
+    // without one only the signature is checked
+    let verifier = WebhookVerifier::new("whsec_MfKQ9r8GKYqrTwjUPD8ILPZIo2LaLaSw")
+        .expect("the secret is valid");
+    for verifier in [verifier.clone(), verifier.with_tolerance(None)] {
+        assert!(
+            verifier.verify(payload.as_bytes(), &headers).is_err(),
+            "a webhook signed without the secret was accepted"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_inputs_do_not_panic() {
+        let inputs: &[&[u8]] = &[
+            b"",
+            b"\n\n\n",
+            b"\xff\xfe",
+            b"app\nhttps://api.privy.io/v1/wallets\n\n{\"b\":1e400}",
+            b"app\nhttps://api.privy.io/v1/wallets\nkey\n{\"b\":[1.5,-0.0,18446744073709551616],\"a\":\"\\u0000\"}",
+            b"BA==\nAAAA",
+            b"not base64\n!!",
+            b"0x",
+            b"0xzz",
+            b"msg_1\n0\nv1,\nbody",
+            b"msg_1\n99999999999999999999\nv1,AAAA v2 ,,\nbody",
+            b"msg_1\n18446744073709551615\nv1,AAAA\nbody",
+        ];
+        for input in inputs {
+            canonicalization(input);
+            hpke_decryption(input);
+            raw_signature(input);
+            #[cfg(feature = "webhooks")]
+            webhook_signature(input);
+        }
+    }
+}
@@ -0,0 +1,473 @@
+//! Verifying Privy identity tokens without an API call.
+//!
+//! An identity token is a JWT Privy issues to a signed in user, holding the
+//! user's id and linked accounts, signed with the app's verification key.
+//! A backend can read the user from it rather than looking them up:
+//!
+//! ```no_run
+//! # use privy_rs::PrivyClient;
+//! # async fn foo(identity_token: &str) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let claims = client.verify_identity_token(identity_token).await?;
+//! for account in &claims.linked_accounts {
+//!     println!("{} has a {} account", claims.user_id, account.kind);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The token must be signed with `ES256` by the app's verification key,
+//! issued by `privy.io` for the client's app, and not expired. The key is
+//! fetched from `GET /v1/apps/{app_id}` on the first verification, and
+//! cached by the client and its clones; it is the same key Privy signs
+//! access tokens with. A token whose signature does not match has the key
+//! fetched again, at most once a minute, in case it was rotated. To verify
+//! tokens without ever calling the API, set the key, as shown in the Privy
+//! dashboard, with `PrivyClient::with_verification_key`.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use p256::{
+    PublicKey,
+    ecdsa::{Signature, VerifyingKey, signature::Verifier},
+    pkcs8::DecodePublicKey,
+};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{IdentityTokenError, PrivyApiError, PrivyClient, generated::ClientInfo};
+
+/// The issuer of Privy's tokens.
+const ISSUER: &str = "privy.io";
+
+/// How long after fetching the verification key a token that does not
+/// match it has the key fetched again, so that bad tokens can not make
+/// every verification call the API.
+const MIN_REFETCH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// What an identity token says about its user.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct IdentityClaims {
+    /// The id of the user, such as `did:privy:...`.
+    pub user_id: String,
+    /// The session the token was issued in, if it names one.
+    pub session_id: Option<String>,
+    /// The accounts linked to the user.
+    pub linked_accounts: Vec<LinkedAccountClaim>,
+    /// When the token was issued, in seconds since the unix epoch.
+    pub issued_at: u64,
+    /// When the token expires, in seconds since the unix epoch.
+    pub expires_at: u64,
+}
+
+/// An account linked to the user of an identity token.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[non_exhaustive]
+pub struct LinkedAccountClaim {
+    /// The kind of account, such as `email`, `wallet` or `google_oauth`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The address of email, phone and wallet accounts.
+    #[serde(default)]
+    pub address: Option<String>,
+    /// The chain of wallet accounts, such as `ethereum`.
+    #[serde(default)]
+    pub chain_type: Option<String>,
+    /// The other fields of the account, which depend on its kind.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct Header {
+    alg: String,
+}
+
+#[derive(Deserialize)]
+struct Claims {
+    iss: String,
+    aud: String,
+    sub: String,
+    #[serde(default)]
+    sid: Option<String>,
+    iat: u64,
+    exp: u64,
+    /// Privy encodes the accounts as a JSON string.
+    #[serde(default)]
+    linked_accounts: Option<serde_json::Value>,
+}
+
+/// The app's verification key, fetched when first needed and shared by the
+/// clones of a client, with when it was fetched. A key set with
+/// `PrivyClient::with_verification_key` is never fetched.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct VerificationKey {
+    key: Arc<Mutex<Option<(VerifyingKey, Instant)>>>,
+    pinned: bool,
+}
+
+impl VerificationKey {
+    fn from_pem(pem: &str) -> Result<Self, IdentityTokenError> {
+        let key = parse_pem(pem)?;
+        Ok(Self {
+            key: Arc::new(Mutex::new(Some((key, Instant::now())))),
+            pinned: true,
+        })
+    }
+
+    async fn get(&self, client: &PrivyClient) -> Result<VerifyingKey, IdentityTokenError> {
+        let mut key = self.key.lock().await;
+        if let Some((key, _)) = &*key {
+            return Ok(*key);
+        }
+        let fetched = fetch(client).await?;
+        *key = Some((fetched, Instant::now()));
+        Ok(fetched)
+    }
+
+    /// A newer key than `stale`, which a token did not match, if the key
+    /// was rotated. The key is fetched again unless it is pinned, was
+    /// fetched less than `MIN_REFETCH_INTERVAL` ago, or another
+    /// verification already replaced `stale`.
+    async fn refresh(
+        &self,
+        client: &PrivyClient,
+        stale: &VerifyingKey,
+    ) -> Result<Option<VerifyingKey>, IdentityTokenError> {
+        if self.pinned {
+            return Ok(None);
+        }
+        let mut key = self.key.lock().await;
+        match &*key {
+            Some((key, _)) if key != stale => return Ok(Some(*key)),
+            Some((_, fetched_at)) if fetched_at.elapsed() < MIN_REFETCH_INTERVAL => {
+                return Ok(None);
+            }
+            _ => {}
+        }
+        let fetched = fetch(client).await?;
+        *key = Some((fetched, Instant::now()));
+        Ok((fetched != *stale).then_some(fetched))
+    }
+}
+
+/// Fetch the app's verification key from the API.
+async fn fetch(client: &PrivyClient) -> Result<VerifyingKey, IdentityTokenError> {
+    #[derive(Deserialize)]
+    struct App {
+        verification_key: String,
+    }
+
+    let url = format!("{}/v1/apps/{}", client.base_url, client.app_id);
+    let app: App = client
+        .client
+        .client()
+        .get(url)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(PrivyApiError::CommunicationError)?
+        .json()
+        .await
+        .map_err(PrivyApiError::CommunicationError)?;
+    parse_pem(&app.verification_key)
+}
+
+fn parse_pem(pem: &str) -> Result<VerifyingKey, IdentityTokenError> {
+    PublicKey::from_public_key_pem(pem.trim())
+        .map(VerifyingKey::from)
+        .map_err(|_| IdentityTokenError::InvalidVerificationKey)
+}
+
+/// Verify `token` with `key`, for the app `app_id`, at `now` in seconds
+/// since the unix epoch.
+fn verify(
+    token: &str,
+    key: &VerifyingKey,
+    app_id: &str,
+    now: u64,
+) -> Result<IdentityClaims, IdentityTokenError> {
+    let malformed = |what: &str| IdentityTokenError::Malformed(what.to_string());
+    let Some((signed, signature)) = token.rsplit_once('.') else {
+        return Err(malformed("not a JWT"));
+    };
+    let Some((header, claims)) = signed.split_once('.').filter(|(_, c)| !c.contains('.')) else {
+        return Err(malformed("not a JWT"));
+    };
+
+    let decode = |part: &str| {
+        URL_SAFE_NO_PAD
+            .decode(part)
+            .map_err(|_| malformed("not base64url"))
+    };
+    let header: Header =
+        serde_json::from_slice(&decode(header)?).map_err(|e| malformed(&e.to_string()))?;
+    if header.alg != "ES256" {
+        return Err(IdentityTokenError::UnsupportedAlgorithm(header.alg));
+    }
+    let signature = Signature::from_slice(&decode(signature)?)
+        .map_err(|_| IdentityTokenError::InvalidSignature)?;
+    key.verify(signed.as_bytes(), &signature)
+        .map_err(|_| IdentityTokenError::InvalidSignature)?;
+
+    let claims: Claims =
+        serde_json::from_slice(&decode(claims)?).map_err(|e| malformed(&e.to_string()))?;
+    if claims.iss != ISSUER || claims.aud != app_id {
+        return Err(IdentityTokenError::WrongIssuer {
+            issuer: claims.iss,
+            audience: claims.aud,
+        });
+    }
+    if claims.exp <= now {
+        return Err(IdentityTokenError::Expired);
+    }
+
+    let linked_accounts = match claims.linked_accounts {
+        Some(serde_json::Value::String(accounts)) => {
+            serde_json::from_str(&accounts).map_err(|e| malformed(&e.to_string()))?
+        }
+        Some(accounts) => {
+            serde_json::from_value(accounts).map_err(|e| malformed(&e.to_string()))?
+        }
+        None => Vec::new(),
+    };
+    Ok(IdentityClaims {
+        user_id: claims.sub,
+        session_id: claims.sid,
+        linked_accounts,
+        issued_at: claims.iat,
+        expires_at: claims.exp,
+    })
+}
+
+impl PrivyClient {
+    /// Verify an identity token of this app, returning what it says about
+    /// its user. See the [`identity`](crate::identity) module.
+    ///
+    /// # Errors
+    ///
+    /// If the token is malformed, not signed by the app's verification key,
+    /// not issued for this app, or expired, or if the key could not be
+    /// fetched.
+    pub async fn verify_identity_token(
+        &self,
+        token: &str,
+    ) -> Result<IdentityClaims, IdentityTokenError> {
+        let key = self.verification_key.get(self).await?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        match verify(token, &key, &self.app_id, now) {
+            // the app's key may have been rotated since it was cached
+            Err(IdentityTokenError::InvalidSignature) => {
+                match self.verification_key.refresh(self, &key).await? {
+                    Some(key) => verify(token, &key, &self.app_id, now),
+                    None => Err(IdentityTokenError::InvalidSignature),
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Verify identity tokens with `pem`, the app's verification key as
+    /// shown in the Privy dashboard, rather than fetching it from the API.
+    ///
+    /// # Errors
+    ///
+    /// If `pem` is not a PEM encoded P-256 public key.
+    pub fn with_verification_key(mut self, pem: &str) -> Result<Self, IdentityTokenError> {
+        self.verification_key = VerificationKey::from_pem(pem)?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use p256::{
+        ecdsa::{SigningKey, signature::Signer},
+        pkcs8::{EncodePublicKey, LineEnding},
+    };
+
+    use super::*;
+    use crate::client::PrivyClientOptions;
+
+    fn token(key: &SigningKey, claims: serde_json::Value) -> String {
+        let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"ES256","typ":"JWT"}"#);
+        let claims = URL_SAFE_NO_PAD.encode(claims.to_string());
+        let signature: Signature = key.sign(format!("{header}.{claims}").as_bytes());
+        format!(
+            "{header}.{claims}.{}",
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        )
+    }
+
+    #[tokio::test]
+    async fn test_identity_tokens_are_verified_with_the_fetched_key() {
+        let key = SigningKey::from_bytes(&[7; 32].into()).unwrap();
+        let pem = key
+            .verifying_key()
+            .to_public_key_pem(LineEnding::LF)
+            .unwrap();
+
+        let server = MockServer::start_async().await;
+        let app = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/apps/app_id");
+                then.status(200)
+                    .json_body(serde_json::json!({ "id": "app_id", "verification_key": pem }));
+            })
+            .await;
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let claims = serde_json::json!({
+            "iss": "privy.io",
+            "aud": "app_id",
+            "sub": "did:privy:alice",
+            "sid": "session_1",
+            "iat": 1_700_000_000,
+            "exp": 4_000_000_000u64,
+            "linked_accounts": r#"[{"type":"wallet","address":"0x12","chain_type":"ethereum","lv":1}]"#,
+        });
+        for _ in 0..2 {
+            let verified = client
+                .verify_identity_token(&token(&key, claims.clone()))
+                .await
+                .unwrap();
+            assert_eq!(verified.user_id, "did:privy:alice");
+            assert_eq!(verified.session_id.as_deref(), Some("session_1"));
+            assert_eq!(verified.linked_accounts[0].kind, "wallet");
+            assert_eq!(verified.linked_accounts[0].address.as_deref(), Some("0x12"));
+            assert_eq!(verified.linked_accounts[0].extra["lv"], 1);
+        }
+        // the key is fetched once, and cached for the second token
+        app.assert_calls_async(1).await;
+
+        // a key fetched moments ago is not fetched again for a bad token
+        let other = SigningKey::from_bytes(&[8; 32].into()).unwrap();
+        assert!(matches!(
+            client.verify_identity_token(&token(&other, claims)).await,
+            Err(IdentityTokenError::InvalidSignature)
+        ));
+        app.assert_calls_async(1).await;
+    }
+
+    #[tokio::test]
+    async fn test_a_rotated_key_is_fetched_again() {
+        let pem = |key: &SigningKey| {
+            key.verifying_key()
+                .to_public_key_pem(LineEnding::LF)
+                .unwrap()
+        };
+        let old = SigningKey::from_bytes(&[7; 32].into()).unwrap();
+        let new = SigningKey::from_bytes(&[8; 32].into()).unwrap();
+
+        let server = MockServer::start_async().await;
+        let old_app = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/apps/app_id");
+                then.status(200)
+                    .json_body(serde_json::json!({ "verification_key": pem(&old) }));
+            })
+            .await;
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let claims = serde_json::json!({
+            "iss": "privy.io", "aud": "app_id", "sub": "did:privy:alice", "iat": 0,
+            "exp": 4_000_000_000u64,
+        });
+        client
+            .verify_identity_token(&token(&old, claims.clone()))
+            .await
+            .unwrap();
+
+        // the key is rotated, after the cached one was fetched a while ago
+        old_app.delete_async().await;
+        let new_app = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/apps/app_id");
+                then.status(200)
+                    .json_body(serde_json::json!({ "verification_key": pem(&new) }));
+            })
+            .await;
+        if let Some((_, fetched_at)) = &mut *client.verification_key.key.lock().await {
+            *fetched_at -= MIN_REFETCH_INTERVAL;
+        }
+
+        let verified = client
+            .verify_identity_token(&token(&new, claims.clone()))
+            .await
+            .unwrap();
+        assert_eq!(verified.user_id, "did:privy:alice");
+        new_app.assert_calls_async(1).await;
+
+        // a pinned key is never fetched again
+        let pinned = client.with_verification_key(&pem(&old)).unwrap();
+        assert!(matches!(
+            pinned.verify_identity_token(&token(&new, claims)).await,
+            Err(IdentityTokenError::InvalidSignature)
+        ));
+        new_app.assert_calls_async(1).await;
+    }
+
+    #[test]
+    fn test_wrong_apps_and_expired_tokens_are_rejected() {
+        let key = SigningKey::from_bytes(&[7; 32].into()).unwrap();
+        let claims = |aud: &str, exp: u64| {
+            serde_json::json!({
+                "iss": "privy.io", "aud": aud, "sub": "did:privy:alice", "iat": 0, "exp": exp,
+            })
+        };
+
+        let verified = verify(
+            &token(&key, claims("app_id", 100)),
+            key.verifying_key(),
+            "app_id",
+            50,
+        );
+        assert!(verified.unwrap().linked_accounts.is_empty());
+        assert!(matches!(
+            verify(
+                &token(&key, claims("other_app", 100)),
+                key.verifying_key(),
+                "app_id",
+                50
+            ),
+            Err(IdentityTokenError::WrongIssuer { .. })
+        ));
+        assert!(matches!(
+            verify(
+                &token(&key, claims("app_id", 100)),
+                key.verifying_key(),
+                "app_id",
+                100
+            ),
+            Err(IdentityTokenError::Expired)
+        ));
+        assert!(matches!(
+            verify("not.a.jwt.at.all", key.verifying_key(), "app_id", 50),
+            Err(IdentityTokenError::Malformed(_))
+        ));
+    }
+}
@@ -0,0 +1,237 @@
+//! Strongly-typed identifiers for Privy resources.
+//!
+//! The API identifies everything with plain strings, which makes it easy to
+//! pass a user id where a wallet id was expected. These newtypes make the
+//! kind of id part of the signature of the hand-written wrappers.
+//!
+//! Plain strings do not convert to ids implicitly, so that a string of one
+//! kind cannot silently become an id of another. Ids are made explicitly,
+//! either with `parse`, which validates the format of the id, or with `new`,
+//! which does not and leaves a malformed id to be rejected by the API:
+//!
+//! ```
+//! use privy_rs::{UserId, WalletId};
+//!
+//! let wallet_id = WalletId::parse("clz2rqy4500061234abcd1234").unwrap();
+//! assert!(WalletId::parse("did:privy:clz2rqy4500061234abcd1234").is_err());
+//! assert!(UserId::parse("did:privy:clz2rqy4500061234abcd1234").is_ok());
+//!
+//! // An id read from a trusted source, such as a database column of wallet ids
+//! let stored = WalletId::new("clz2rqy4500061234abcd1234");
+//! assert_eq!(stored, wallet_id);
+//! ```
+//!
+//! The wrappers take anything that is `Into` the id type, which are the id
+//! itself, a reference to it, and the matching per-endpoint types generated
+//! from the OpenAPI spec.
+
+use std::{fmt, ops::Deref, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{InvalidIdError, generated::types};
+
+/// The prefix of every Privy user id.
+const USER_ID_PREFIX: &str = "did:privy:";
+
+/// Whether `id` looks like a cuid, the format Privy uses for resource ids.
+fn is_cuid(id: &str) -> bool {
+    (20..=32).contains(&id.len())
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+}
+
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident, $kind:literal, $valid:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// `id` as an id of this kind, without checking its format. A
+            /// malformed id is rejected by the API instead; use
+            /// [`parse`](Self::parse) to check it up front.
+            #[must_use]
+            pub fn new(id: impl Into<String>) -> Self {
+                Self(id.into())
+            }
+
+            /// `id` as an id of this kind, if its format is valid.
+            ///
+            /// # Errors
+            /// If `id` is not a valid id of this kind.
+            pub fn parse(id: &str) -> Result<Self, InvalidIdError> {
+                id.parse()
+            }
+
+            /// The id as a string slice.
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = InvalidIdError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let valid: fn(&str) -> bool = $valid;
+                if valid(s) {
+                    Ok(Self(s.to_owned()))
+                } else {
+                    Err(InvalidIdError {
+                        kind: $kind,
+                        value: s.to_owned(),
+                    })
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(value: &$name) -> Self {
+                value.clone()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+    };
+}
+
+/// Implement conversions from the per-endpoint id types generated from the
+/// OpenAPI spec, so existing callers keep compiling.
+macro_rules! from_generated {
+    ($name:ident: $($generated:ty),+ $(,)?) => {
+        $(
+            impl From<&$generated> for $name {
+                fn from(value: &$generated) -> Self {
+                    Self(value.to_string())
+                }
+            }
+
+            impl From<$generated> for $name {
+                fn from(value: $generated) -> Self {
+                    Self(value.to_string())
+                }
+            }
+        )+
+    };
+}
+
+id_type!(
+    /// The id of a wallet, e.g. `clz2rqy4500061234abcd1234`.
+    WalletId,
+    "wallet id",
+    is_cuid
+);
+
+id_type!(
+    /// The id of a user, e.g. `did:privy:clz2rqy4500061234abcd1234`.
+    UserId,
+    "user id",
+    |id| id.strip_prefix(USER_ID_PREFIX).is_some_and(is_cuid)
+);
+
+id_type!(
+    /// The id of a policy, which is always 24 characters long.
+    PolicyId,
+    "policy id",
+    |id| id.len() == 24 && is_cuid(id)
+);
+
+id_type!(
+    /// The id of a key quorum.
+    KeyQuorumId,
+    "key quorum id",
+    is_cuid
+);
+
+from_generated!(
+    PolicyId: types::PolicyId,
+    types::GetPolicyPolicyId,
+    types::UpdatePolicyPolicyId,
+    types::DeletePolicyPolicyId,
+    types::GetRulePolicyId,
+    types::CreateRulePolicyId,
+    types::UpdateRulePolicyId,
+    types::DeleteRulePolicyId,
+);
+
+from_generated!(KeyQuorumId: types::KeyQuorumId);
+
+impl From<&KeyQuorumId> for types::KeyQuorumId {
+    fn from(value: &KeyQuorumId) -> Self {
+        Self(value.0.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("clz2rqy4500061234abcd1234", true ; "cuid")]
+    #[test_case("xdeor1731y8gme1utsldxynv", true ; "short cuid")]
+    #[test_case("", false ; "empty")]
+    #[test_case("did:privy:clz2rqy4500061234abcd1234", false ; "user id")]
+    #[test_case("CLZ2RQY4500061234ABCD1234", false ; "uppercase")]
+    #[test_case("0x742d35Cc6634C0532925a3b844Bc454e4438f44e", false ; "address")]
+    fn test_wallet_id(id: &str, valid: bool) {
+        assert_eq!(id.parse::<WalletId>().is_ok(), valid);
+    }
+
+    #[test_case("did:privy:clz2rqy4500061234abcd1234", true ; "user id")]
+    #[test_case("clz2rqy4500061234abcd1234", false ; "missing prefix")]
+    #[test_case("did:privy:", false ; "missing cuid")]
+    fn test_user_id(id: &str, valid: bool) {
+        assert_eq!(id.parse::<UserId>().is_ok(), valid);
+    }
+
+    #[test]
+    fn test_policy_id_length() {
+        assert!("xdeor1731y8gme1utsldxynv".parse::<PolicyId>().is_ok());
+        assert!("clz2rqy4500061234abcd1234".parse::<PolicyId>().is_err());
+    }
+
+    #[test]
+    fn test_display_and_serde_round_trip() {
+        let id: WalletId = "clz2rqy4500061234abcd1234".parse().unwrap();
+        assert_eq!(id.to_string(), "clz2rqy4500061234abcd1234");
+
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"clz2rqy4500061234abcd1234\"");
+        assert_eq!(serde_json::from_str::<WalletId>(&json).unwrap(), id);
+    }
+
+    #[test]
+    fn test_invalid_id_error_names_kind() {
+        let error = "nope".parse::<UserId>().unwrap_err();
+        assert_eq!(error.to_string(), "`nope` is not a valid user id");
+    }
+}
@@ -0,0 +1,305 @@
+//! Policy hooks that run before transactions are signed.
+//!
+//! A [`TransactionInterceptor`] registered with
+//! [`PrivyClient::with_interceptor`] sees every transaction before it is
+//! signed, whichever path it takes: the ethereum and solana services,
+//! `WalletsClient::rpc` and `rpc_batch`, `WalletsClient::raw_sign`,
+//! `WalletsClient::transfer` and so `PrivyClient::send`, and (with the
+//! `alloy` feature) the alloy signer. It can inspect the transaction,
+//! modify it, or reject it,
+//! which makes it the one place to enforce org-wide rules such as contract
+//! allowlists and value caps.
+//!
+//! ```no_run
+//! # use privy_rs::{
+//! #     PrivyClient, TransactionRejected,
+//! #     generated::types::UnsignedEthereumTransaction,
+//! #     interceptor::{FnInterceptor, InterceptedTransaction, TransactionRequest},
+//! # };
+//! # fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! const ALLOWED: &[&str] = &["0x742d35cc6634c0532925a3b844bc454e4438f44e"];
+//!
+//! let client = PrivyClient::new_from_env()?.with_interceptor(FnInterceptor(|request: &mut TransactionRequest| {
+//!     let InterceptedTransaction::Ethereum(tx) = &request.transaction else {
+//!         return Ok(());
+//!     };
+//!     let UnsignedEthereumTransaction::StandardEthereumTransaction(tx) = &**tx else {
+//!         return Err(TransactionRejected::new("only standard transactions are allowed"));
+//!     };
+//!     match &tx.to {
+//!         Some(to) if ALLOWED.contains(&to.to_lowercase().as_str()) => Ok(()),
+//!         to => Err(TransactionRejected::new(format!("{to:?} is not allowlisted"))),
+//!     }
+//! }));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Interceptors run in the order they were registered, each seeing the
+//! changes of the ones before it, and the first rejection stops the
+//! transaction from being signed. They run right before the request is
+//! signed, so solana simulation and ethereum preflight checks see the
+//! transaction as it was passed in. Transactions signed through alloy are
+//! signed as a hash, so changes to them cannot be applied, and an
+//! interceptor that changes one rejects it instead.
+
+use std::{fmt, future::Future, pin::Pin, sync::Arc};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+use crate::{
+    TransactionRejected, WalletId,
+    generated::{
+        Error,
+        types::{
+            RawSignInput, TransferRequestBody, UnsignedEthereumTransaction, WalletRpcRequestBody,
+        },
+    },
+};
+
+/// A transaction as seen by a [`TransactionInterceptor`].
+#[derive(Clone, Debug)]
+pub enum InterceptedTransaction {
+    /// An unsigned ethereum transaction.
+    Ethereum(Box<UnsignedEthereumTransaction>),
+    /// A serialized solana transaction.
+    Solana(Vec<u8>),
+    /// A transfer from `WalletsClient::transfer` or `PrivyClient::send`,
+    /// which Privy builds into a transaction before signing it.
+    Transfer(Box<TransferRequestBody>),
+    /// The bytes or hash to sign with `WalletsClient::raw_sign`.
+    RawSign(Box<RawSignInput>),
+}
+
+/// A transaction that is about to be signed.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct TransactionRequest {
+    /// The wallet that will sign the transaction.
+    pub wallet_id: WalletId,
+    /// The chain the transaction is for, if the request names one.
+    pub caip2: Option<String>,
+    /// Whether the transaction is broadcast after signing, or only signed.
+    pub broadcast: bool,
+    /// The transaction itself. Changes to it are signed instead of the
+    /// original.
+    pub transaction: InterceptedTransaction,
+}
+
+impl TransactionRequest {
+    pub(crate) fn new(
+        wallet_id: WalletId,
+        caip2: Option<String>,
+        broadcast: bool,
+        transaction: InterceptedTransaction,
+    ) -> Self {
+        Self {
+            wallet_id,
+            caip2,
+            broadcast,
+            transaction,
+        }
+    }
+}
+
+/// A hook that inspects, modifies or rejects transactions before they are
+/// signed. See the [module docs](self) for details.
+pub trait TransactionInterceptor {
+    /// Check `request`, modifying it in place if needed. Returning an error
+    /// rejects the transaction.
+    fn intercept(
+        &self,
+        request: &mut TransactionRequest,
+    ) -> impl Future<Output = Result<(), TransactionRejected>> + Send;
+}
+
+/// A wrapper for a closure that implements `TransactionInterceptor`.
+/// This uses the newtype pattern to avoid conflicting blanket impls.
+pub struct FnInterceptor<F>(pub F);
+
+impl<F> TransactionInterceptor for FnInterceptor<F>
+where
+    F: Fn(&mut TransactionRequest) -> Result<(), TransactionRejected>,
+{
+    fn intercept(
+        &self,
+        request: &mut TransactionRequest,
+    ) -> impl Future<Output = Result<(), TransactionRejected>> + Send {
+        std::future::ready((self.0)(request))
+    }
+}
+
+/// The object safe counterpart of `TransactionInterceptor`, see
+/// `IntoSignatureBoxed` for why this exists.
+trait TransactionInterceptorBoxed {
+    fn intercept_boxed<'a>(
+        &'a self,
+        request: &'a mut TransactionRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<(), TransactionRejected>> + Send + 'a>>;
+}
+
+impl<T: TransactionInterceptor + 'static> TransactionInterceptorBoxed for T {
+    fn intercept_boxed<'a>(
+        &'a self,
+        request: &'a mut TransactionRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<(), TransactionRejected>> + Send + 'a>> {
+        Box::pin(self.intercept(request))
+    }
+}
+
+/// The interceptors registered with a client.
+#[derive(Clone, Default)]
+pub(crate) struct Interceptors(Vec<Arc<dyn TransactionInterceptorBoxed + Send + Sync>>);
+
+impl fmt::Debug for Interceptors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Interceptors").field(&self.0.len()).finish()
+    }
+}
+
+impl Interceptors {
+    pub(crate) fn push(
+        &mut self,
+        interceptor: impl TransactionInterceptor + Send + Sync + 'static,
+    ) {
+        self.0.push(Arc::new(interceptor));
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Run every interceptor on `request`, in order.
+    pub(crate) async fn run(
+        &self,
+        request: &mut TransactionRequest,
+    ) -> Result<(), TransactionRejected> {
+        for interceptor in &self.0 {
+            interceptor.intercept_boxed(request).await?;
+        }
+        Ok(())
+    }
+
+    /// Run the interceptors on the transaction in a wallet rpc body.
+    /// Returns the body with their changes applied, or `None` if there are
+    /// no interceptors or the body does not carry a transaction.
+    pub(crate) async fn intercept_rpc(
+        &self,
+        wallet_id: &WalletId,
+        body: &WalletRpcRequestBody,
+    ) -> Result<Option<WalletRpcRequestBody>, crate::PrivySignedApiError> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        // decoding errors are small, and only turned into an api error when returned
+        let decode = |transaction: &str| STANDARD.decode(transaction);
+        let invalid_transaction = |e: base64::DecodeError| {
+            Error::InvalidRequest(format!("Invalid solana transaction: {e}"))
+        };
+        let (caip2, broadcast, transaction) = match body {
+            WalletRpcRequestBody::EthereumSignTransactionRpcInput(input) => (
+                None,
+                false,
+                InterceptedTransaction::Ethereum(Box::new(input.params.transaction.clone())),
+            ),
+            WalletRpcRequestBody::EthereumSendTransactionRpcInput(input) => (
+                Some(input.caip2.to_string()),
+                true,
+                InterceptedTransaction::Ethereum(Box::new(input.params.transaction.clone())),
+            ),
+            WalletRpcRequestBody::SolanaSignTransactionRpcInput(input) => (
+                None,
+                false,
+                InterceptedTransaction::Solana(
+                    decode(&input.params.transaction).map_err(invalid_transaction)?,
+                ),
+            ),
+            WalletRpcRequestBody::SolanaSignAndSendTransactionRpcInput(input) => (
+                Some(input.caip2.to_string()),
+                true,
+                InterceptedTransaction::Solana(
+                    decode(&input.params.transaction).map_err(invalid_transaction)?,
+                ),
+            ),
+            _ => return Ok(None),
+        };
+
+        let mut request = TransactionRequest::new(wallet_id.clone(), caip2, broadcast, transaction);
+        self.run(&mut request).await?;
+
+        let mut body = body.clone();
+        let invalid = |e: crate::ConversionError| Error::InvalidRequest(e.to_string());
+        match (&mut body, request.transaction) {
+            (
+                WalletRpcRequestBody::EthereumSignTransactionRpcInput(input),
+                InterceptedTransaction::Ethereum(transaction),
+            ) => input.params.transaction = *transaction,
+            (
+                WalletRpcRequestBody::EthereumSendTransactionRpcInput(input),
+                InterceptedTransaction::Ethereum(transaction),
+            ) => input.params.transaction = *transaction,
+            (
+                WalletRpcRequestBody::SolanaSignTransactionRpcInput(input),
+                InterceptedTransaction::Solana(transaction),
+            ) => {
+                input.params.transaction = STANDARD.encode(transaction).parse().map_err(invalid)?
+            }
+            (
+                WalletRpcRequestBody::SolanaSignAndSendTransactionRpcInput(input),
+                InterceptedTransaction::Solana(transaction),
+            ) => {
+                input.params.transaction = STANDARD.encode(transaction).parse().map_err(invalid)?
+            }
+            _ => return Err(changed_kind().into()),
+        }
+
+        Ok(Some(body))
+    }
+
+    /// Run the interceptors on a transfer. Returns the body with their
+    /// changes applied, or `None` if there are no interceptors.
+    pub(crate) async fn intercept_transfer(
+        &self,
+        wallet_id: &WalletId,
+        body: &TransferRequestBody,
+    ) -> Result<Option<TransferRequestBody>, TransactionRejected> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        let transaction = InterceptedTransaction::Transfer(Box::new(body.clone()));
+        let mut request = TransactionRequest::new(wallet_id.clone(), None, true, transaction);
+        self.run(&mut request).await?;
+
+        match request.transaction {
+            InterceptedTransaction::Transfer(body) => Ok(Some(*body)),
+            _ => Err(changed_kind()),
+        }
+    }
+
+    /// Run the interceptors on a raw sign request. Returns the body with
+    /// their changes applied, or `None` if there are no interceptors.
+    pub(crate) async fn intercept_raw_sign(
+        &self,
+        wallet_id: &WalletId,
+        body: &RawSignInput,
+    ) -> Result<Option<RawSignInput>, TransactionRejected> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        let transaction = InterceptedTransaction::RawSign(Box::new(body.clone()));
+        let mut request = TransactionRequest::new(wallet_id.clone(), None, false, transaction);
+        self.run(&mut request).await?;
+
+        match request.transaction {
+            InterceptedTransaction::RawSign(body) => Ok(Some(*body)),
+            _ => Err(changed_kind()),
+        }
+    }
+}
+
+fn changed_kind() -> TransactionRejected {
+    TransactionRejected::new("an interceptor changed the kind of the transaction")
+}
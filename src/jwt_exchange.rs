@@ -1,67 +1,143 @@
 use std::{
+    collections::HashMap,
     num::NonZeroUsize,
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, PoisonError},
     time::{Duration, SystemTime},
 };
 
 use p256::{NistP256, elliptic_curve::SecretKey};
+use rand::Rng;
+use reqwest::StatusCode;
 
 use crate::{
-    JwtUser, KeyError, PrivyHpke,
+    JwtExchangeError, JwtUser, KeyError, PrivyApiError, PrivyApiErrorExt, PrivyHpke, Sensitive,
     generated::types::{
         WalletAuthenticateRequestBody, WalletAuthenticateRequestBodyEncryptionType,
+        WalletAuthenticateWithJwtResponse,
     },
 };
 
 const EXPIRY_BUFFER: Duration = Duration::from_secs(60);
 
+/// How many times the authenticate request is attempted before giving up
+/// on a transient failure.
+const MAX_ATTEMPTS: u32 = 3;
+
+/// The backoff before the first retry, doubled on every subsequent retry.
+const BASE_BACKOFF: Duration = Duration::from_millis(200);
+
 type JwtCache = lru::LruCache<String, (SystemTime, SecretKey<NistP256>)>;
 
+type InFlightLocks = Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>;
+
 /// This needs interior mutability so that we don't have to lock the cache for the
 /// entire duration of the network request. Otherwise, in a multi-threaded context,
 /// you would only be able to sign a single signature at a time.
 #[derive(Debug, Clone)]
 pub struct JwtExchange {
     cache: Arc<Mutex<JwtCache>>,
+    /// A lock per JWT that is being exchanged, so that concurrent signatures
+    /// with a JWT that is not cached yet wait for one exchange rather than
+    /// each making their own.
+    in_flight: Arc<InFlightLocks>,
+}
+
+/// A claim on the lock for a JWT in `JwtExchange::in_flight`. The lock is
+/// removed when the last claim on it is dropped, including when the future
+/// holding it is cancelled mid-exchange.
+struct InFlight<'a> {
+    locks: &'a InFlightLocks,
+    jwt: &'a str,
+    lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl<'a> InFlight<'a> {
+    fn claim(locks: &'a InFlightLocks, jwt: &'a str) -> Self {
+        let lock = locks
+            .lock()
+            .expect("lock poisoned")
+            .entry(jwt.to_owned())
+            .or_default()
+            .clone();
+        Self { locks, jwt, lock }
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        let mut locks = self.locks.lock().unwrap_or_else(PoisonError::into_inner);
+        // the map holds one reference and this claim another, any more are
+        // other callers still waiting on the lock
+        if let Some(lock) = locks.get(self.jwt) {
+            if Arc::ptr_eq(lock, &self.lock) && Arc::strong_count(lock) == 2 {
+                locks.remove(self.jwt);
+            }
+        }
+    }
 }
 
 impl JwtExchange {
     pub fn new(capacity: NonZeroUsize) -> Self {
         JwtExchange {
             cache: Arc::new(Mutex::new(lru::LruCache::new(capacity))),
+            in_flight: Arc::default(),
         }
     }
 
+    /// The authorization key for `jwt_user`, from the cache if it holds one
+    /// that is not about to expire, and exchanged with the API otherwise.
     pub async fn exchange_jwt_for_authorization_key(
         &self,
         jwt_user: &JwtUser,
     ) -> Result<SecretKey<NistP256>, KeyError> {
-        let client = &jwt_user.0;
         let jwt = &jwt_user.1;
+        if let Some(key) = self.cached(jwt) {
+            return Ok(key);
+        }
 
-        {
-            let mut cache = self.cache.lock().expect("lock poisoned");
-            let expired = if let Some((expiry, key)) = cache.get(jwt) {
-                let buffer = *expiry - EXPIRY_BUFFER;
-                if buffer > SystemTime::now() {
-                    return Ok(key.clone());
-                }
-                true
-            } else {
-                false
-            };
+        let in_flight = InFlight::claim(&self.in_flight, jwt);
+        let _guard = in_flight.lock.lock().await;
 
-            if expired {
-                // if it was expired, demote it so it is evicted ASAP
-                // it costs the same to check if it exists so just mark it unconditionally
-                cache.demote(jwt);
-            }
+        // the exchange we waited for may have cached the key
+        if let Some(key) = self.cached(jwt) {
+            return Ok(key);
         }
 
-        #[cfg(all(feature = "unsafe_debug", debug_assertions))]
-        {
-            tracing::debug!("Starting HPKE JWT exchange for user JWT: {}", jwt);
+        self.exchange(jwt_user).await
+    }
+
+    /// The cached key for `jwt`, unless it is within `EXPIRY_BUFFER` of
+    /// expiring.
+    fn cached(&self, jwt: &str) -> Option<SecretKey<NistP256>> {
+        let mut cache = self.cache.lock().expect("lock poisoned");
+        let expired = if let Some((expiry, key)) = cache.get(jwt) {
+            let buffer = *expiry - EXPIRY_BUFFER;
+            if buffer > SystemTime::now() {
+                return Some(key.clone());
+            }
+            true
+        } else {
+            false
+        };
+
+        if expired {
+            // if it was expired, demote it so it is evicted ASAP
+            // it costs the same to check if it exists so just mark it unconditionally
+            cache.demote(jwt);
         }
+        None
+    }
+
+    /// Exchange the JWT of `jwt_user` for an authorization key with the API,
+    /// caching the key until the expiry the API returns with it.
+    async fn exchange(&self, jwt_user: &JwtUser) -> Result<SecretKey<NistP256>, KeyError> {
+        let client = &jwt_user.0;
+        let jwt = &jwt_user.1;
+
+        tracing::debug!(
+            "Starting HPKE JWT exchange for user JWT: {}",
+            Sensitive::new(jwt)
+        );
 
         // Get the HPKE manager and format the public key for the API request
         let hpke_manager = PrivyHpke::new();
@@ -79,18 +155,35 @@ impl JwtExchange {
             recipient_public_key: public_key_b64,
         };
 
-        // Send the authentication request
-        let auth = match client.wallets().authenticate_with_jwt(&body).await {
-            Ok(r) => r.into_inner(),
-            Err(e) => {
-                tracing::error!("failed to fetch authorization key: {:?}", e);
-                return Err(KeyError::Other(Box::new(e)));
+        // Send the authentication request, retrying transient failures
+        let mut attempt = 1;
+        let auth = loop {
+            let error = match client.wallets().authenticate_with_jwt(&body).await {
+                Ok(r) => break r.into_inner(),
+                Err(e) => classify(e, attempt),
+            };
+
+            if error.is_permanent() || attempt >= MAX_ATTEMPTS {
+                tracing::error!("failed to fetch authorization key: {:?}", error);
+                return Err(error.into());
             }
+
+            let delay = backoff(attempt);
+            crate::telemetry::retry("jwt_exchange", attempt, MAX_ATTEMPTS, delay, &error);
+            tracing::warn!(
+                "transient failure fetching authorization key (attempt {}/{}), retrying in {:?}: {}",
+                attempt,
+                MAX_ATTEMPTS,
+                delay,
+                error
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
         };
 
         // Process the response based on encryption type
         let (key, expiry) = match auth {
-            crate::generated::types::WalletAuthenticateWithJwtResponse::WithEncryption {
+            WalletAuthenticateWithJwtResponse::WithEncryption {
                 encrypted_authorization_key,
                 expires_at,
                 ..
@@ -105,9 +198,7 @@ impl JwtExchange {
                 let expiry = SystemTime::UNIX_EPOCH + Duration::from_secs_f64(expires_at);
                 (key, expiry)
             }
-            crate::generated::types::WalletAuthenticateWithJwtResponse::WithoutEncryption {
-                ..
-            } => {
+            WalletAuthenticateWithJwtResponse::WithoutEncryption { .. } => {
                 tracing::warn!("Received unencrypted authorization key (fallback mode)");
                 unimplemented!()
             }
@@ -128,4 +219,269 @@ impl JwtExchange {
         tracing::info!("Successfully obtained and parsed authorization key");
         Ok(key)
     }
+
+    /// Drop every cached key that is within `EXPIRY_BUFFER` of expiring,
+    /// returning how many were dropped.
+    pub fn evict_expired(&self) -> usize {
+        let mut cache = self.cache.lock().expect("lock poisoned");
+        let now = SystemTime::now();
+        let expired: Vec<String> = cache
+            .iter()
+            .filter(|(_, (expiry, _))| *expiry - EXPIRY_BUFFER <= now)
+            .map(|(jwt, _)| jwt.clone())
+            .collect();
+
+        for jwt in &expired {
+            cache.pop(jwt);
+        }
+        expired.len()
+    }
+
+    /// Drop every cached key, returning how many were dropped.
+    pub fn clear(&self) -> usize {
+        let mut cache = self.cache.lock().expect("lock poisoned");
+        let cleared = cache.len();
+        cache.clear();
+        cleared
+    }
+
+    /// How many keys the cache holds at most.
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.cache.lock().expect("lock poisoned").cap()
+    }
+}
+
+/// Sort a failed authenticate request into a permanent or transient failure.
+/// Client errors mean the JWT itself was refused, except for timeouts and
+/// rate limiting. Anything else, such as server errors and connection
+/// failures, is assumed to be transient and worth retrying.
+fn classify(error: PrivyApiError, attempts: u32) -> JwtExchangeError {
+    let status = match &error {
+        PrivyApiError::CommunicationError(_) | PrivyApiError::ResponseBodyError(_) => None,
+        e => e.status().filter(|s| s.is_client_error()),
+    };
+
+    match status {
+        Some(StatusCode::REQUEST_TIMEOUT | StatusCode::TOO_MANY_REQUESTS) | None => {
+            JwtExchangeError::Transient {
+                attempts,
+                source: Box::new(error),
+            }
+        }
+        Some(status) => {
+            let message = error
+                .details()
+                .map(|d| d.body.clone())
+                .unwrap_or_else(|| error.to_string());
+
+            if status == StatusCode::NOT_FOUND {
+                JwtExchangeError::NoLinkedUser(message)
+            } else {
+                JwtExchangeError::InvalidJwt { status, message }
+            }
+        }
+    }
+}
+
+/// Exponential backoff with full jitter: a random delay of up to
+/// `BASE_BACKOFF * 2^(attempt - 1)`.
+fn backoff(attempt: u32) -> Duration {
+    let cap = BASE_BACKOFF * 2u32.pow(attempt - 1);
+    cap.mul_f64(rand::thread_rng().r#gen::<f64>())
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use super::*;
+    use crate::{PrivyClient, client::PrivyClientOptions};
+
+    async fn exchange(status: u16) -> (KeyError, usize) {
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/wallets/authenticate");
+                then.status(status).body("{\"error\":\"nope\"}");
+            })
+            .await;
+
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let user = JwtUser(client.clone(), "jwt".to_string());
+
+        let err = client
+            .jwt_exchange
+            .exchange_jwt_for_authorization_key(&user)
+            .await
+            .unwrap_err();
+
+        (err, mock.calls_async().await)
+    }
+
+    #[test]
+    fn test_evict_expired() {
+        let exchange = JwtExchange::new(NonZeroUsize::new(10).unwrap());
+        let key = SecretKey::<NistP256>::from_bytes(&[7; 32].into()).unwrap();
+        {
+            let mut cache = exchange.cache.lock().unwrap();
+            let now = SystemTime::now();
+            cache.push("fresh".to_string(), (now + EXPIRY_BUFFER * 2, key.clone()));
+            cache.push("stale".to_string(), (now + EXPIRY_BUFFER / 2, key.clone()));
+            cache.push("expired".to_string(), (now - EXPIRY_BUFFER, key));
+        }
+
+        assert_eq!(exchange.evict_expired(), 2);
+        let cache = exchange.cache.lock().unwrap();
+        assert!(cache.contains("fresh"));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_signatures_share_one_exchange() {
+        use crate::privy_hpke::testing::seal_p256;
+
+        let key = SecretKey::<NistP256>::from_bytes(&[7; 32].into()).unwrap();
+        let sealed_key = key.clone();
+        let expires_at = (SystemTime::now() + Duration::from_secs(3600))
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs_f64();
+
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/wallets/authenticate");
+                then.respond_with(move |request: &HttpMockRequest| {
+                    // encrypt the key to the recipient, as the API does
+                    let body: serde_json::Value =
+                        serde_json::from_slice(request.body().as_ref()).unwrap();
+                    let recipient = body["recipient_public_key"].as_str().unwrap();
+                    let sealed = seal_p256(recipient, &sealed_key, 0).unwrap();
+
+                    HttpMockResponse::builder()
+                        .status(200)
+                        .header("content-type", "application/json")
+                        .body(
+                            serde_json::json!({
+                                "encrypted_authorization_key": {
+                                    "encryption_type": "HPKE",
+                                    "encapsulated_key": sealed.encapsulated_key,
+                                    "ciphertext": sealed.ciphertext,
+                                },
+                                "expires_at": expires_at,
+                                "wallets": [],
+                            })
+                            .to_string(),
+                        )
+                        .build()
+                });
+            })
+            .await;
+
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let user = JwtUser(client.clone(), "jwt".to_string());
+
+        let keys = futures::future::try_join_all((0..5).map(|_| {
+            client
+                .jwt_exchange
+                .exchange_jwt_for_authorization_key(&user)
+        }))
+        .await
+        .unwrap();
+        let again = client
+            .jwt_exchange
+            .exchange_jwt_for_authorization_key(&user)
+            .await
+            .unwrap();
+
+        assert!(keys.iter().chain([&again]).all(|k| *k == key));
+        assert_eq!(mock.calls_async().await, 1);
+        assert!(client.jwt_exchange.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_exchange_releases_its_lock() {
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/wallets/authenticate");
+                then.status(503).delay(Duration::from_secs(5));
+            })
+            .await;
+
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let user = JwtUser(client.clone(), "jwt".to_string());
+
+        let exchange = client
+            .jwt_exchange
+            .exchange_jwt_for_authorization_key(&user);
+        let waiter = client
+            .jwt_exchange
+            .exchange_jwt_for_authorization_key(&user);
+        let cancelled = tokio::time::timeout(
+            Duration::from_millis(100),
+            futures::future::join(exchange, waiter),
+        )
+        .await;
+
+        assert!(cancelled.is_err());
+        assert!(client.jwt_exchange.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_jwt_is_not_retried() {
+        let (err, calls) = exchange(401).await;
+        assert_eq!(calls, 1);
+        assert!(matches!(
+            err,
+            KeyError::JwtExchange(JwtExchangeError::InvalidJwt { status, .. }) if status == 401
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_missing_user_is_not_retried() {
+        let (err, calls) = exchange(404).await;
+        assert_eq!(calls, 1);
+        assert!(matches!(
+            err,
+            KeyError::JwtExchange(JwtExchangeError::NoLinkedUser(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_transient_failure_is_retried() {
+        let (err, calls) = exchange(503).await;
+        assert_eq!(calls, MAX_ATTEMPTS as usize);
+        match err {
+            KeyError::JwtExchange(e @ JwtExchangeError::Transient { attempts, .. }) => {
+                assert_eq!(attempts, MAX_ATTEMPTS);
+                assert!(!e.is_permanent());
+            }
+            e => panic!("unexpected error: {e:?}"),
+        }
+    }
 }
@@ -0,0 +1,360 @@
+//! Signing with keys that live in AWS KMS
+//!
+//! A `KmsKey` never sees the private key. It sends the SHA-256 digest of the
+//! message to the KMS `Sign` action and uses the DER signature that comes
+//! back. Requests are signed with AWS Signature Version 4 using static
+//! credentials, so this does not pull in the AWS SDK.
+
+use std::fmt;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use hmac::{Hmac, Mac};
+use p256::ecdsa::Signature;
+use sha2::{Digest, Sha256};
+
+use super::IntoSignature;
+use crate::{KeyError, KmsError, SigningError};
+
+const SIGN_TARGET: &str = "TrentService.Sign";
+const CONTENT_TYPE: &str = "application/x-amz-json-1.1";
+
+/// Credentials for signing requests to AWS.
+#[derive(Clone)]
+pub struct AwsCredentials {
+    access_key_id: String,
+    secret_access_key: zeroize::Zeroizing<String>,
+    session_token: Option<String>,
+}
+
+impl AwsCredentials {
+    /// Long-lived credentials of an IAM user.
+    pub fn new(access_key_id: impl Into<String>, secret_access_key: impl Into<String>) -> Self {
+        Self {
+            access_key_id: access_key_id.into(),
+            secret_access_key: zeroize::Zeroizing::new(secret_access_key.into()),
+            session_token: None,
+        }
+    }
+
+    /// Add the session token of temporary credentials, e.g. from an
+    /// assumed role.
+    #[must_use]
+    pub fn with_session_token(mut self, session_token: impl Into<String>) -> Self {
+        self.session_token = Some(session_token.into());
+        self
+    }
+
+    /// Read `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and, if set,
+    /// `AWS_SESSION_TOKEN`.
+    ///
+    /// # Errors
+    /// If either of the required variables is not set.
+    pub fn from_env() -> Result<Self, KeyError> {
+        let credentials = Self::new(env("AWS_ACCESS_KEY_ID")?, env("AWS_SECRET_ACCESS_KEY")?);
+        Ok(match std::env::var("AWS_SESSION_TOKEN") {
+            Ok(token) => credentials.with_session_token(token),
+            Err(_) => credentials,
+        })
+    }
+}
+
+impl fmt::Debug for AwsCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AwsCredentials")
+            .field("access_key_id", &self.access_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A P-256 key stored in AWS KMS, which signs through the KMS `Sign`
+/// action with `ECDSA_SHA_256`.
+///
+/// The key must be an asymmetric `ECC_NIST_P256` key with the `SIGN_VERIFY`
+/// usage, and the credentials need the `kms:Sign` permission on it. Register
+/// its public key with Privy as you would any other authorization key.
+///
+/// Unlike `PrivateKey`, KMS signatures are not deterministic: signing the
+/// same request twice gives two different, equally valid, signatures.
+///
+/// ```rust,no_run
+/// use privy_rs::{AuthorizationContext, AwsCredentials, KmsKey};
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let key = KmsKey::new(
+///     "arn:aws:kms:us-east-1:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab",
+///     "us-east-1",
+///     AwsCredentials::from_env()?,
+/// );
+/// let ctx = AuthorizationContext::new().push(key);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct KmsKey {
+    key_id: String,
+    region: String,
+    credentials: AwsCredentials,
+    endpoint: String,
+    client: reqwest::Client,
+}
+
+impl KmsKey {
+    /// The key with the given id, alias or ARN in `region`.
+    pub fn new(
+        key_id: impl Into<String>,
+        region: impl Into<String>,
+        credentials: AwsCredentials,
+    ) -> Self {
+        let region = region.into();
+        Self {
+            key_id: key_id.into(),
+            endpoint: format!("https://kms.{region}.amazonaws.com"),
+            region,
+            credentials,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// The key with the given id, alias or ARN, in the region of
+    /// `AWS_REGION` (or `AWS_DEFAULT_REGION`), using the credentials of
+    /// `AwsCredentials::from_env`.
+    ///
+    /// # Errors
+    /// If the region or credentials are not set.
+    pub fn from_env(key_id: impl Into<String>) -> Result<Self, KeyError> {
+        let region = env("AWS_REGION").or_else(|_| env("AWS_DEFAULT_REGION"))?;
+        Ok(Self::new(key_id, region, AwsCredentials::from_env()?))
+    }
+
+    /// Send requests to `endpoint` instead of the public KMS endpoint of the
+    /// region, e.g. a VPC endpoint.
+    #[must_use]
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    async fn sign_digest(&self, message: &[u8]) -> Result<Signature, KmsError> {
+        let body = serde_json::json!({
+            "KeyId": self.key_id,
+            "Message": STANDARD.encode(Sha256::digest(message)),
+            "MessageType": "DIGEST",
+            "SigningAlgorithm": "ECDSA_SHA_256",
+        })
+        .to_string();
+
+        let url = reqwest::Url::parse(&format!("{}/", self.endpoint))
+            .map_err(|e| KmsError::InvalidResponse(format!("invalid endpoint: {e}")))?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => {
+                return Err(KmsError::InvalidResponse(format!(
+                    "endpoint `{}` has no host",
+                    self.endpoint
+                )));
+            }
+        };
+
+        let now = chrono::Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let authorization = self.authorization(&host, &amz_date, &body);
+
+        let mut request = self
+            .client
+            .post(url)
+            .header("content-type", CONTENT_TYPE)
+            .header("x-amz-date", &amz_date)
+            .header("x-amz-target", SIGN_TARGET)
+            .header("authorization", authorization);
+        if let Some(token) = &self.credentials.session_token {
+            request = request.header("x-amz-security-token", token);
+        }
+
+        let response = request.body(body).send().await?;
+        let status = response.status();
+        let response: serde_json::Value = response.json().await?;
+        if !status.is_success() {
+            let kind = response["__type"].as_str().unwrap_or("UnknownError");
+            let message = response["message"]
+                .as_str()
+                .or_else(|| response["Message"].as_str())
+                .unwrap_or_default();
+            return Err(KmsError::Api {
+                status,
+                message: format!("{kind}: {message}"),
+            });
+        }
+
+        let der = response["Signature"]
+            .as_str()
+            .ok_or_else(|| KmsError::InvalidResponse("missing `Signature`".to_string()))
+            .and_then(|signature| {
+                STANDARD
+                    .decode(signature)
+                    .map_err(|e| KmsError::InvalidResponse(e.to_string()))
+            })?;
+        Signature::from_der(&der).map_err(|e| KmsError::InvalidResponse(e.to_string()))
+    }
+
+    /// The SigV4 `Authorization` header of a `Sign` request with `body`.
+    fn authorization(&self, host: &str, amz_date: &str, body: &str) -> String {
+        let mut headers = vec![
+            ("content-type", CONTENT_TYPE),
+            ("host", host),
+            ("x-amz-date", amz_date),
+        ];
+        if let Some(token) = &self.credentials.session_token {
+            headers.push(("x-amz-security-token", token));
+        }
+        headers.push(("x-amz-target", SIGN_TARGET));
+
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{name}:{value}\n"))
+            .collect();
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_request = format!(
+            "POST\n/\n\n{canonical_headers}\n{signed_headers}\n{}",
+            hex::encode(Sha256::digest(body))
+        );
+
+        let date = &amz_date[..8];
+        let scope = format!("{date}/{}/kms/aws4_request", self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+            hex::encode(Sha256::digest(canonical_request))
+        );
+
+        let secret = format!("AWS4{}", self.credentials.secret_access_key.as_str());
+        let key = [date, &self.region, "kms", "aws4_request"]
+            .iter()
+            .fold(secret.into_bytes(), |key, part| hmac(&key, part.as_bytes()));
+        let signature = hex::encode(hmac(&key, string_to_sign.as_bytes()));
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+            self.credentials.access_key_id
+        )
+    }
+}
+
+impl IntoSignature for KmsKey {
+    async fn sign(&self, message: &[u8]) -> Result<Signature, SigningError> {
+        tracing::debug!(
+            "Signing {} byte message with KMS key {}",
+            message.len(),
+            self.key_id
+        );
+        self.sign_digest(message)
+            .await
+            .map_err(|e| SigningError::Other(Box::new(e)))
+    }
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("hmac accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn env(var: &str) -> Result<String, KeyError> {
+    std::env::var(var)
+        .map_err(|e| KeyError::Other(format!("environment variable `{var}`: {e}").into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use p256::ecdsa::{SigningKey, VerifyingKey, signature::Verifier};
+
+    use super::*;
+
+    const TEST_PRIVATE_KEY_PEM: &str = include_str!("../../tests/test_private_key.pem");
+
+    #[tokio::test]
+    async fn test_kms_key_signs_the_digest_of_the_message() {
+        let secret = p256::SecretKey::from_sec1_pem(TEST_PRIVATE_KEY_PEM).unwrap();
+        let signing_key = SigningKey::from(&secret);
+        let message = b"canonical request data";
+        let expected: Signature = p256::ecdsa::signature::Signer::sign(&signing_key, message);
+
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/")
+                    .header("x-amz-target", SIGN_TARGET)
+                    .header("x-amz-security-token", "session")
+                    .header_matches(
+                        "authorization",
+                        "^AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/[0-9]{8}/us-east-1/kms/aws4_request, \
+                         SignedHeaders=content-type;host;x-amz-date;x-amz-security-token;x-amz-target, \
+                         Signature=[0-9a-f]{64}$",
+                    )
+                    .json_body(serde_json::json!({
+                        "KeyId": "alias/privy",
+                        "Message": STANDARD.encode(Sha256::digest(message)),
+                        "MessageType": "DIGEST",
+                        "SigningAlgorithm": "ECDSA_SHA_256",
+                    }));
+                then.status(200).json_body(serde_json::json!({
+                    "KeyId": "alias/privy",
+                    "Signature": STANDARD.encode(expected.to_der().as_bytes()),
+                    "SigningAlgorithm": "ECDSA_SHA_256",
+                }));
+            })
+            .await;
+
+        let key = KmsKey::new(
+            "alias/privy",
+            "us-east-1",
+            AwsCredentials::new("AKIDEXAMPLE", "secret").with_session_token("session"),
+        )
+        .with_endpoint(server.base_url());
+        let signature = key.sign(message).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(signature, expected);
+        VerifyingKey::from(&signing_key)
+            .verify(message, &signature)
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_kms_errors_are_surfaced() {
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|_, then| {
+                then.status(400).json_body(serde_json::json!({
+                    "__type": "AccessDeniedException",
+                    "message": "not allowed to use kms:Sign",
+                }));
+            })
+            .await;
+
+        let key = KmsKey::new(
+            "alias/privy",
+            "us-east-1",
+            AwsCredentials::new("id", "secret"),
+        )
+        .with_endpoint(server.base_url());
+        let Err(SigningError::Other(error)) = key.sign(b"message").await else {
+            panic!("expected the KMS error");
+        };
+        match error.downcast_ref::<KmsError>() {
+            Some(KmsError::Api { status, message }) => {
+                assert_eq!(status.as_u16(), 400);
+                assert_eq!(
+                    message,
+                    "AccessDeniedException: not allowed to use kms:Sign"
+                );
+            }
+            other => panic!("unexpected error {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,328 @@
+//! Declarative signer configuration
+//!
+//! This module allows an `AuthorizationContext` to be described in a config
+//! file (toml, yaml, json, ...) rather than in code. A `SignerConfig` only ever
+//! describes _where_ a key lives, never the key material itself, so it is safe
+//! to check these configs into source control.
+//!
+//! Besides keys on disk and in the environment, signers can be keys held by
+//! AWS KMS, Google Cloud KMS or a PKCS#11 token, with the `aws-kms`,
+//! `gcp-kms` and `pkcs11` features respectively:
+//!
+//! ```toml
+//! [[signers]]
+//! type = "aws_kms"
+//! key_id = "arn:aws:kms:us-east-1:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab"
+//!
+//! [[signers]]
+//! type = "pkcs11"
+//! module = "/usr/lib/pkcs11/yubihsm_pkcs11.so"
+//! slot = 0
+//! label = "privy-owner"
+//! pin_var = "YUBIHSM_PIN"
+//! ```
+
+use std::path::PathBuf;
+
+use super::{AuthorizationContext, PrivateKey, PrivateKeyFromFile};
+use crate::KeyError;
+
+/// A serializable description of a signer that can be added to an
+/// `AuthorizationContext`. See `AuthorizationContext::from_config`.
+///
+/// ```rust
+/// # use privy_rs::SignerConfig;
+/// let config: Vec<SignerConfig> = serde_json::from_str(
+///     r#"[
+///         { "type": "file", "path": "/etc/privy/owner.pem" },
+///         { "type": "env", "var": "PRIVY_AUTHORIZATION_KEY" }
+///     ]"#,
+/// )
+/// .unwrap();
+/// assert_eq!(config.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SignerConfig {
+    /// A PEM or base64 DER encoded private key stored in a file on disk.
+    /// The file is read asynchronously each time the context signs.
+    File {
+        /// The path to the PEM file.
+        path: PathBuf,
+    },
+    /// A PEM or base64 DER encoded private key stored in an environment
+    /// variable. The variable is read when the context is created.
+    Env {
+        /// The name of the environment variable.
+        var: String,
+    },
+    /// A key held by AWS KMS, see `KmsKey::from_env`. The region and
+    /// credentials are read from the environment when the context is
+    /// created.
+    #[cfg(feature = "aws-kms")]
+    AwsKms {
+        /// The id, alias or ARN of the key.
+        key_id: String,
+    },
+    /// A key version held by Google Cloud KMS, authenticated with the
+    /// service account of the instance, see
+    /// `GcpKmsKey::from_metadata_server`.
+    #[cfg(feature = "gcp-kms")]
+    GcpKms {
+        /// The resource name of the key version, such as
+        /// `projects/p/locations/global/keyRings/r/cryptoKeys/k/cryptoKeyVersions/1`.
+        name: String,
+    },
+    /// A key on a PKCS#11 token, such as a YubiHSM, see `Pkcs11Key::open`.
+    /// The module is loaded when the context is created.
+    #[cfg(feature = "pkcs11")]
+    Pkcs11 {
+        /// The path of the PKCS#11 module.
+        module: PathBuf,
+        /// The slot of the token.
+        slot: u64,
+        /// The `CKA_LABEL` of the private key.
+        label: String,
+        /// The environment variable holding the pin, which is read when
+        /// the context is created.
+        pin_var: String,
+    },
+}
+
+impl SignerConfig {
+    /// Add the signer described by this config to the provided context.
+    fn push_into(&self, ctx: AuthorizationContext) -> Result<AuthorizationContext, KeyError> {
+        match self {
+            SignerConfig::File { path } => Ok(ctx.push(PrivateKeyFromFile(path.clone()))),
+            SignerConfig::Env { var } => Ok(ctx.push(PrivateKey::new(env(var)?))),
+            #[cfg(feature = "aws-kms")]
+            SignerConfig::AwsKms { key_id } => Ok(ctx.push(crate::KmsKey::from_env(key_id)?)),
+            #[cfg(feature = "gcp-kms")]
+            SignerConfig::GcpKms { name } => {
+                Ok(ctx.push(crate::GcpKmsKey::from_metadata_server(name)))
+            }
+            #[cfg(feature = "pkcs11")]
+            SignerConfig::Pkcs11 {
+                module,
+                slot,
+                label,
+                pin_var,
+            } => {
+                let key = crate::Pkcs11Key::open(module, *slot, label, env(pin_var)?)
+                    .map_err(|e| KeyError::Other(format!("{}: {e}", module.display()).into()))?;
+                Ok(ctx.push(key))
+            }
+        }
+    }
+}
+
+/// Read the environment variable `var`, naming it if it is not set.
+fn env(var: &str) -> Result<String, KeyError> {
+    std::env::var(var)
+        .map_err(|e| KeyError::Other(format!("environment variable `{var}`: {e}").into()))
+}
+
+impl AuthorizationContext {
+    /// Create a new `AuthorizationContext` from a list of signer configs.
+    /// Signers are added to the context in the order they are provided.
+    ///
+    /// ```rust,no_run
+    /// # use privy_rs::{AuthorizationContext, SignerConfig};
+    /// # fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config: Vec<SignerConfig> =
+    ///     serde_json::from_str(r#"[{ "type": "file", "path": "owner.pem" }]"#)?;
+    /// let ctx = AuthorizationContext::from_config(&config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// This function does not perform any blocking I/O. Key files are read
+    /// lazily when the context signs, so a missing or malformed file is only
+    /// reported at that point; use `AuthorizationContext::validate` to check
+    /// the keys eagerly.
+    ///
+    /// # Errors
+    /// Returns a `KeyError` naming the variable if an `Env` signer refers to
+    /// an environment variable that is not set, or if the environment of an
+    /// `AwsKms` signer or the module or pin of a `Pkcs11` signer can not be
+    /// loaded.
+    pub fn from_config(configs: &[SignerConfig]) -> Result<Self, KeyError> {
+        configs
+            .iter()
+            .try_fold(AuthorizationContext::new(), |ctx, config| {
+                config.push_into(ctx)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::SigningError;
+
+    const TEST_PRIVATE_KEY_PEM: &str = include_str!("../../tests/test_private_key.pem");
+
+    #[test]
+    fn test_signer_config_deserialization() {
+        let config: Vec<SignerConfig> = serde_json::from_str(
+            r#"[
+                { "type": "file", "path": "/tmp/key.pem" },
+                { "type": "env", "var": "MY_KEY" }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            vec![
+                SignerConfig::File {
+                    path: PathBuf::from("/tmp/key.pem")
+                },
+                SignerConfig::Env {
+                    var: "MY_KEY".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_signer_config_rejects_unknown_type() {
+        let result = serde_json::from_str::<SignerConfig>(r#"{ "type": "inline", "pem": "" }"#);
+        assert!(result.is_err(), "Unknown signer types should be rejected");
+    }
+
+    #[cfg(all(feature = "aws-kms", feature = "gcp-kms", feature = "pkcs11"))]
+    #[test]
+    fn test_signer_config_deserializes_kms_and_pkcs11_signers() {
+        #[derive(serde::Deserialize)]
+        struct Config {
+            signers: Vec<SignerConfig>,
+        }
+
+        let config: Config = toml::from_str(
+            r#"
+            [[signers]]
+            type = "aws_kms"
+            key_id = "arn:aws:kms:us-east-1:111122223333:key/owner"
+
+            [[signers]]
+            type = "gcp_kms"
+            name = "projects/p/locations/global/keyRings/r/cryptoKeys/k/cryptoKeyVersions/1"
+
+            [[signers]]
+            type = "pkcs11"
+            module = "/usr/lib/pkcs11/yubihsm_pkcs11.so"
+            slot = 0
+            label = "privy-owner"
+            pin_var = "YUBIHSM_PIN"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.signers,
+            vec![
+                SignerConfig::AwsKms {
+                    key_id: "arn:aws:kms:us-east-1:111122223333:key/owner".to_string()
+                },
+                SignerConfig::GcpKms {
+                    name: "projects/p/locations/global/keyRings/r/cryptoKeys/k/cryptoKeyVersions/1"
+                        .to_string()
+                },
+                SignerConfig::Pkcs11 {
+                    module: PathBuf::from("/usr/lib/pkcs11/yubihsm_pkcs11.so"),
+                    slot: 0,
+                    label: "privy-owner".to_string(),
+                    pin_var: "YUBIHSM_PIN".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "gcp-kms")]
+    #[test]
+    fn test_from_config_gcp_kms() {
+        let ctx = AuthorizationContext::from_config(&[SignerConfig::GcpKms {
+            name: "projects/p/locations/global/keyRings/r/cryptoKeys/k/cryptoKeyVersions/1"
+                .to_string(),
+        }])
+        .unwrap();
+
+        let signer = ctx.signers().next().unwrap();
+        assert!(signer.to_string().contains("GcpKmsKey"), "{signer}");
+    }
+
+    #[cfg(feature = "pkcs11")]
+    #[test]
+    fn test_from_config_pkcs11_names_missing_pin_and_module() {
+        let missing_pin = AuthorizationContext::from_config(&[SignerConfig::Pkcs11 {
+            module: PathBuf::from("/no/such/module.so"),
+            slot: 0,
+            label: "owner".to_string(),
+            pin_var: "PRIVY_RS_TEST_DEFINITELY_UNSET_PIN".to_string(),
+        }]);
+        let err = missing_pin.expect_err("unset pin variable should fail");
+        assert!(
+            err.to_string()
+                .contains("PRIVY_RS_TEST_DEFINITELY_UNSET_PIN")
+        );
+
+        // any variable that is set will do for the pin
+        let missing_module = AuthorizationContext::from_config(&[SignerConfig::Pkcs11 {
+            module: PathBuf::from("/no/such/module.so"),
+            slot: 0,
+            label: "owner".to_string(),
+            pin_var: "PATH".to_string(),
+        }]);
+        let err = missing_module.expect_err("missing module should fail");
+        assert!(err.to_string().contains("/no/such/module.so"));
+    }
+
+    #[tokio::test]
+    async fn test_from_config_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(TEST_PRIVATE_KEY_PEM.as_bytes()).unwrap();
+
+        let ctx = AuthorizationContext::from_config(&[SignerConfig::File {
+            path: file.path().to_path_buf(),
+        }])
+        .unwrap();
+
+        let signatures: Vec<_> = ctx.sign(b"test").try_collect().await.unwrap();
+        assert_eq!(signatures.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_from_config_missing_file_names_path() {
+        let path = "/definitely/not/a/real/key.pem";
+        let ctx = AuthorizationContext::from_config(&[SignerConfig::File {
+            path: PathBuf::from(path),
+        }])
+        .expect("files are read lazily");
+
+        let errors = ctx.validate().await;
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0].kind(),
+            SigningError::Key(KeyError::Io(_))
+        ));
+        assert!(errors[0].to_string().contains(path));
+    }
+
+    #[test]
+    fn test_from_config_missing_env_names_variable() {
+        let var = "PRIVY_RS_TEST_DEFINITELY_UNSET_VAR";
+        let result = AuthorizationContext::from_config(&[SignerConfig::Env {
+            var: var.to_string(),
+        }]);
+
+        let err = result.expect_err("unset variable should fail");
+        assert!(matches!(err, KeyError::Other(_)));
+        assert!(err.to_string().contains(var));
+    }
+}
@@ -0,0 +1,351 @@
+//! Signing with keys that live in Google Cloud KMS
+//!
+//! A `GcpKmsKey` sends the SHA-256 digest of the message to the Cloud KMS
+//! `asymmetricSign` method of a key version and uses the DER signature that
+//! comes back. Requests are authenticated with an OAuth access token, either
+//! one that is passed in or one fetched from the metadata server of the
+//! instance the process runs on.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use p256::ecdsa::Signature;
+use rand::Rng;
+use reqwest::StatusCode;
+use sha2::{Digest, Sha256};
+
+use super::IntoSignature;
+use crate::{GcpKmsError, SigningError};
+
+const DEFAULT_ENDPOINT: &str = "https://cloudkms.googleapis.com";
+
+/// The host of the metadata server, overridden by `GCE_METADATA_HOST` like in
+/// Google's own client libraries.
+const DEFAULT_METADATA_HOST: &str = "metadata.google.internal";
+
+/// How long before it expires a metadata server token is replaced.
+const TOKEN_EXPIRY_BUFFER: Duration = Duration::from_secs(60);
+
+/// How many times a sign request is attempted before giving up on a
+/// transient failure.
+const MAX_ATTEMPTS: u32 = 3;
+
+/// The backoff before the first retry, doubled on every subsequent retry.
+const BASE_BACKOFF: Duration = Duration::from_millis(200);
+
+#[derive(Clone)]
+enum AccessToken {
+    Static(zeroize::Zeroizing<String>),
+    MetadataServer(Arc<Mutex<Option<(String, Instant)>>>),
+}
+
+/// A P-256 key version stored in Google Cloud KMS, which signs through the
+/// Cloud KMS `asymmetricSign` method.
+///
+/// The key must have the `EC_SIGN_P256_SHA256` algorithm, and the account
+/// the token belongs to needs `cloudkms.cryptoKeyVersions.useToSign` on it.
+/// Register its public key with Privy as you would any other authorization
+/// key.
+///
+/// Requests that fail with a transient error, such as rate limiting, a
+/// server error or a dropped connection, are retried with backoff.
+///
+/// Unlike `PrivateKey`, KMS signatures are not deterministic: signing the
+/// same request twice gives two different, equally valid, signatures.
+///
+/// ```rust,no_run
+/// use privy_rs::{AuthorizationContext, GcpKmsKey};
+///
+/// let key = GcpKmsKey::from_metadata_server(
+///     "projects/my-project/locations/global/keyRings/privy/cryptoKeys/owner/cryptoKeyVersions/1",
+/// );
+/// let ctx = AuthorizationContext::new().push(key);
+/// ```
+#[derive(Clone)]
+pub struct GcpKmsKey {
+    key_version: String,
+    token: AccessToken,
+    endpoint: String,
+    client: reqwest::Client,
+}
+
+impl std::fmt::Debug for GcpKmsKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcpKmsKey")
+            .field("key_version", &self.key_version)
+            .field("endpoint", &self.endpoint)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GcpKmsKey {
+    /// The key version with the given resource name, authenticated with
+    /// `access_token`, e.g. the output of `gcloud auth print-access-token`.
+    pub fn new(key_version: impl Into<String>, access_token: impl Into<String>) -> Self {
+        Self::with_token(
+            key_version.into(),
+            AccessToken::Static(zeroize::Zeroizing::new(access_token.into())),
+        )
+    }
+
+    /// The key version with the given resource name, authenticated with the
+    /// service account of the instance, as reported by its metadata server.
+    /// Tokens are cached until shortly before they expire.
+    pub fn from_metadata_server(key_version: impl Into<String>) -> Self {
+        Self::with_token(
+            key_version.into(),
+            AccessToken::MetadataServer(Arc::new(Mutex::new(None))),
+        )
+    }
+
+    fn with_token(key_version: String, token: AccessToken) -> Self {
+        Self {
+            key_version,
+            token,
+            endpoint: DEFAULT_ENDPOINT.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Send requests to `endpoint` instead of the public Cloud KMS endpoint,
+    /// e.g. a Private Service Connect endpoint.
+    #[must_use]
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    async fn access_token(&self) -> Result<String, GcpKmsError> {
+        let cache = match &self.token {
+            AccessToken::Static(token) => return Ok(token.to_string()),
+            AccessToken::MetadataServer(cache) => cache,
+        };
+
+        if let Some((token, expiry)) = &*cache.lock().expect("lock poisoned") {
+            if *expiry > Instant::now() + TOKEN_EXPIRY_BUFFER {
+                return Ok(token.clone());
+            }
+        }
+
+        let host = std::env::var("GCE_METADATA_HOST")
+            .unwrap_or_else(|_| DEFAULT_METADATA_HOST.to_string());
+        let response = self
+            .client
+            .get(format!(
+                "http://{host}/computeMetadata/v1/instance/service-accounts/default/token"
+            ))
+            .header("Metadata-Flavor", "Google")
+            .send()
+            .await?;
+        let response: serde_json::Value = check_status(response).await?.json().await?;
+
+        let token = response["access_token"]
+            .as_str()
+            .ok_or_else(|| {
+                GcpKmsError::InvalidResponse("metadata server returned no access token".into())
+            })?
+            .to_string();
+        let expiry =
+            Instant::now() + Duration::from_secs(response["expires_in"].as_u64().unwrap_or(0));
+        *cache.lock().expect("lock poisoned") = Some((token.clone(), expiry));
+        Ok(token)
+    }
+
+    async fn try_sign(&self, digest: &str) -> Result<Signature, GcpKmsError> {
+        let token = self.access_token().await?;
+        let response = self
+            .client
+            .post(format!(
+                "{}/v1/{}:asymmetricSign",
+                self.endpoint, self.key_version
+            ))
+            .bearer_auth(token)
+            .json(&serde_json::json!({ "digest": { "sha256": digest } }))
+            .send()
+            .await?;
+        let response: serde_json::Value = check_status(response).await?.json().await?;
+
+        let der = response["signature"]
+            .as_str()
+            .ok_or_else(|| GcpKmsError::InvalidResponse("missing `signature`".to_string()))
+            .and_then(|signature| {
+                STANDARD
+                    .decode(signature)
+                    .map_err(|e| GcpKmsError::InvalidResponse(e.to_string()))
+            })?;
+        Signature::from_der(&der).map_err(|e| GcpKmsError::InvalidResponse(e.to_string()))
+    }
+
+    async fn sign_digest(&self, message: &[u8]) -> Result<Signature, GcpKmsError> {
+        let digest = STANDARD.encode(Sha256::digest(message));
+
+        let mut attempt = 1;
+        loop {
+            let error = match self.try_sign(&digest).await {
+                Ok(signature) => return Ok(signature),
+                Err(error) => error,
+            };
+
+            if !error.is_transient() {
+                return Err(error);
+            }
+            if attempt >= MAX_ATTEMPTS {
+                return Err(GcpKmsError::Transient {
+                    attempts: attempt,
+                    source: Box::new(error),
+                });
+            }
+
+            let delay = backoff(attempt);
+            crate::telemetry::retry("gcp_kms_sign", attempt, MAX_ATTEMPTS, delay, &error);
+            tracing::warn!(
+                "transient failure signing with KMS key {} (attempt {}/{}), retrying in {:?}: {}",
+                self.key_version,
+                attempt,
+                MAX_ATTEMPTS,
+                delay,
+                error
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+impl IntoSignature for GcpKmsKey {
+    async fn sign(&self, message: &[u8]) -> Result<Signature, SigningError> {
+        tracing::debug!(
+            "Signing {} byte message with KMS key {}",
+            message.len(),
+            self.key_version
+        );
+        self.sign_digest(message)
+            .await
+            .map_err(|e| SigningError::Other(Box::new(e)))
+    }
+}
+
+/// Turn a response with an error status into `GcpKmsError::Api`.
+async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, GcpKmsError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let body = response.text().await.unwrap_or_default();
+    let message = serde_json::from_str::<serde_json::Value>(&body)
+        .ok()
+        .and_then(|error| error["error"]["message"].as_str().map(str::to_string))
+        .unwrap_or(body);
+    Err(GcpKmsError::Api { status, message })
+}
+
+impl GcpKmsError {
+    /// Rate limiting, server errors and failed connections are worth
+    /// retrying, anything else will fail the same way again.
+    fn is_transient(&self) -> bool {
+        match self {
+            GcpKmsError::Request(_) => true,
+            GcpKmsError::Api { status, .. } => {
+                *status == StatusCode::REQUEST_TIMEOUT
+                    || *status == StatusCode::TOO_MANY_REQUESTS
+                    || status.is_server_error()
+            }
+            GcpKmsError::InvalidResponse(_) | GcpKmsError::Transient { .. } => false,
+        }
+    }
+}
+
+/// Exponential backoff with full jitter: a random delay of up to
+/// `BASE_BACKOFF * 2^(attempt - 1)`.
+fn backoff(attempt: u32) -> Duration {
+    let cap = BASE_BACKOFF * 2u32.pow(attempt - 1);
+    cap.mul_f64(rand::thread_rng().r#gen::<f64>())
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use p256::ecdsa::SigningKey;
+
+    use super::*;
+
+    const TEST_PRIVATE_KEY_PEM: &str = include_str!("../../tests/test_private_key.pem");
+    const KEY_VERSION: &str =
+        "projects/p/locations/global/keyRings/r/cryptoKeys/k/cryptoKeyVersions/1";
+
+    #[tokio::test]
+    async fn test_gcp_kms_key_signs_the_digest_of_the_message() {
+        let secret = p256::SecretKey::from_sec1_pem(TEST_PRIVATE_KEY_PEM).unwrap();
+        let message = b"canonical request data";
+        let expected: Signature =
+            p256::ecdsa::signature::Signer::sign(&SigningKey::from(&secret), message);
+
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path(format!("/v1/{KEY_VERSION}:asymmetricSign"))
+                    .header("authorization", "Bearer token")
+                    .json_body(serde_json::json!({
+                        "digest": { "sha256": STANDARD.encode(Sha256::digest(message)) },
+                    }));
+                then.status(200).json_body(serde_json::json!({
+                    "name": KEY_VERSION,
+                    "signature": STANDARD.encode(expected.to_der().as_bytes()),
+                }));
+            })
+            .await;
+
+        let key = GcpKmsKey::new(KEY_VERSION, "token").with_endpoint(server.base_url());
+        assert_eq!(key.sign(message).await.unwrap(), expected);
+        mock.assert_async().await;
+    }
+
+    async fn sign_with_status(status: u16) -> (GcpKmsError, usize) {
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|_, then| {
+                then.status(status).json_body(serde_json::json!({
+                    "error": { "code": status, "message": "nope" },
+                }));
+            })
+            .await;
+
+        let key = GcpKmsKey::new(KEY_VERSION, "token").with_endpoint(server.base_url());
+        let Err(SigningError::Other(error)) = key.sign(b"message").await else {
+            panic!("expected the KMS error");
+        };
+        let error = *error.downcast::<GcpKmsError>().expect("a KMS error");
+        (error, mock.calls_async().await)
+    }
+
+    #[tokio::test]
+    async fn test_transient_errors_are_retried() {
+        let (error, calls) = sign_with_status(503).await;
+        assert_eq!(calls, MAX_ATTEMPTS as usize);
+        assert!(matches!(
+            error,
+            GcpKmsError::Transient {
+                attempts: MAX_ATTEMPTS,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_permanent_errors_are_not_retried() {
+        let (error, calls) = sign_with_status(403).await;
+        assert_eq!(calls, 1);
+        match error {
+            GcpKmsError::Api { status, message } => {
+                assert_eq!(status, StatusCode::FORBIDDEN);
+                assert_eq!(message, "nope");
+            }
+            other => panic!("unexpected error {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,486 @@
+//! Signing with keys on a Ledger hardware wallet
+//!
+//! A `LedgerKey` has a Ledger device sign the SHA-256 digest of the
+//! canonical request with a P-256 key derived on the device, once its
+//! holder has confirmed the digest on the device screen. The private key
+//! never leaves the device.
+//!
+//! Ledger's Bitcoin and Ethereum apps only sign with secp256k1, so the
+//! device must have the SSH/PGP Agent app open, which derives and signs
+//! with P-256 keys. Its commands are sent as APDUs, over USB through Linux
+//! hidraw, or over TCP to the Speculos emulator. Other transports, such as
+//! USB on other platforms, can be plugged in with `LedgerTransport`.
+
+use std::{
+    fmt,
+    io::{self, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    sync::{Arc, Mutex},
+};
+
+use p256::ecdsa::Signature;
+use sha2::{Digest, Sha256};
+
+use super::IntoSignature;
+use crate::{LedgerError, SigningError};
+
+/// The class of the SSH/PGP Agent app's commands.
+const CLA: u8 = 0x80;
+const INS_GET_PUBLIC_KEY: u8 = 0x02;
+const INS_SIGN_DIRECT_HASH: u8 = 0x08;
+/// Marks the first, and here only, chunk of a command's data.
+const P1_FIRST: u8 = 0x00;
+const P1_LAST: u8 = 0x80;
+/// Selects the NIST P-256 curve.
+const P2_PRIME256V1: u8 = 0x01;
+
+const SW_OK: u16 = 0x9000;
+/// The holder rejected the request on the device.
+const SW_DENIED: u16 = 0x6985;
+/// The app open on the device does not know the class or instruction.
+const SW_APP_NOT_OPEN: [u16; 3] = [0x6d00, 0x6e00, 0x6511];
+
+/// A way to exchange APDUs with a Ledger device.
+pub trait LedgerTransport {
+    /// Send the command `apdu` and wait for the answer, which ends with the
+    /// two bytes of the status word.
+    ///
+    /// # Errors
+    /// If the device can not be reached.
+    fn exchange(&self, apdu: &[u8]) -> io::Result<Vec<u8>>;
+}
+
+/// A P-256 key on a Ledger device, derived at a BIP-32 path by the SSH/PGP
+/// Agent app. See the [module docs](self).
+///
+/// Every signature must be confirmed on the device, which shows the
+/// digest being signed; the digest is logged at the `info` level so the
+/// holder can check that they match. Register the key's public key, from
+/// [`LedgerKey::public_key`], with Privy as you would any other
+/// authorization key.
+///
+/// The exchange with the device runs on the blocking thread pool of the
+/// tokio runtime.
+///
+/// ```rust,no_run
+/// use privy_rs::{AuthorizationContext, LedgerKey};
+///
+/// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+/// let key = LedgerKey::hid("44'/535348'/0'/0/0")?;
+/// println!("owner key: {:?}", key.public_key().await?);
+/// let ctx = AuthorizationContext::new().push(key);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct LedgerKey {
+    transport: Arc<dyn LedgerTransport + Send + Sync>,
+    path: Vec<u32>,
+}
+
+impl fmt::Debug for LedgerKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LedgerKey")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LedgerKey {
+    /// Use the key at the BIP-32 `path`, such as `44'/535348'/0'/0/0`, of
+    /// the first Ledger device connected over USB.
+    ///
+    /// # Errors
+    /// If `path` is not a BIP-32 path, or no Ledger device is connected.
+    #[cfg(target_os = "linux")]
+    pub fn hid(path: &str) -> Result<Self, LedgerError> {
+        let path = parse_path(path)?;
+        Ok(Self::with_path(HidrawTransport::open()?, path))
+    }
+
+    /// Use the key at the BIP-32 `path` of the Speculos emulator listening
+    /// for APDUs at `addr`, which is port 9999 by default.
+    ///
+    /// # Errors
+    /// If `path` is not a BIP-32 path, or the emulator can not be reached.
+    pub fn speculos(addr: impl ToSocketAddrs, path: &str) -> Result<Self, LedgerError> {
+        let path = parse_path(path)?;
+        let stream = TcpStream::connect(addr)?;
+        Ok(Self::with_path(SpeculosTransport(Mutex::new(stream)), path))
+    }
+
+    /// Use the key at the BIP-32 `path` of the device behind `transport`.
+    ///
+    /// # Errors
+    /// If `path` is not a BIP-32 path.
+    pub fn with_transport(
+        transport: impl LedgerTransport + Send + Sync + 'static,
+        path: &str,
+    ) -> Result<Self, LedgerError> {
+        Ok(Self::with_path(transport, parse_path(path)?))
+    }
+
+    fn with_path(transport: impl LedgerTransport + Send + Sync + 'static, path: Vec<u32>) -> Self {
+        Self {
+            transport: Arc::new(transport),
+            path,
+        }
+    }
+
+    /// The public key of this key, to register with Privy.
+    ///
+    /// # Errors
+    /// If the device can not be reached, or does not have the SSH/PGP Agent
+    /// app open.
+    pub async fn public_key(&self) -> Result<p256::PublicKey, LedgerError> {
+        let key = self.clone();
+        let answer = tokio::task::spawn_blocking(move || {
+            key.command(INS_GET_PUBLIC_KEY, &key.encoded_path())
+        })
+        .await
+        .map_err(|e| LedgerError::InvalidResponse(e.to_string()))??;
+
+        // the point may be prefixed with its length
+        let point = match answer.split_first() {
+            Some((&len, point)) if usize::from(len) == point.len() => point,
+            _ => &answer[..],
+        };
+        p256::PublicKey::from_sec1_bytes(point)
+            .map_err(|e| LedgerError::InvalidResponse(format!("invalid public key: {e}")))
+    }
+
+    fn sign_digest(&self, digest: &[u8]) -> Result<Signature, LedgerError> {
+        let mut data = self.encoded_path();
+        data.extend_from_slice(digest);
+        let answer = self.command(INS_SIGN_DIRECT_HASH, &data)?;
+
+        Signature::from_der(&answer)
+            .or_else(|_| Signature::from_slice(&answer))
+            .map_err(|e| LedgerError::InvalidResponse(format!("invalid signature: {e}")))
+    }
+
+    /// Send the single chunk command `ins` with `data`, returning the data
+    /// of the answer.
+    fn command(&self, ins: u8, data: &[u8]) -> Result<Vec<u8>, LedgerError> {
+        let len = u8::try_from(data.len()).expect("commands fit in a single APDU");
+        let mut apdu = vec![CLA, ins, P1_FIRST | P1_LAST, P2_PRIME256V1, len];
+        apdu.extend_from_slice(data);
+
+        let mut answer = self.transport.exchange(&apdu)?;
+        if answer.len() < 2 {
+            return Err(LedgerError::InvalidResponse(
+                "the answer has no status word".into(),
+            ));
+        }
+        let status = answer.split_off(answer.len() - 2);
+        match u16::from_be_bytes([status[0], status[1]]) {
+            SW_OK => Ok(answer),
+            SW_DENIED => Err(LedgerError::Rejected),
+            sw if SW_APP_NOT_OPEN.contains(&sw) => Err(LedgerError::AppNotOpen),
+            sw => Err(LedgerError::Status(sw)),
+        }
+    }
+
+    /// The path as the app expects it: the number of components, then each
+    /// component big endian.
+    fn encoded_path(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(1 + 4 * self.path.len());
+        encoded.push(self.path.len() as u8);
+        for component in &self.path {
+            encoded.extend_from_slice(&component.to_be_bytes());
+        }
+        encoded
+    }
+}
+
+impl IntoSignature for LedgerKey {
+    async fn sign(&self, message: &[u8]) -> Result<Signature, SigningError> {
+        let key = self.clone();
+        let digest = Sha256::digest(message);
+        tracing::info!(
+            "Confirm the request with digest {} on the Ledger",
+            hex::encode(digest)
+        );
+        tokio::task::spawn_blocking(move || key.sign_digest(&digest))
+            .await
+            .map_err(|e| SigningError::Other(Box::new(e)))?
+            .map_err(|e| SigningError::Other(Box::new(e)))
+    }
+}
+
+/// Parse a BIP-32 path like `44'/535348'/0'/0/0`, where `'` or `h` marks a
+/// hardened component. A leading `m/` is allowed.
+fn parse_path(path: &str) -> Result<Vec<u32>, LedgerError> {
+    let invalid = || LedgerError::InvalidPath(path.to_owned());
+    let components: Vec<u32> = path
+        .strip_prefix("m/")
+        .unwrap_or(path)
+        .split('/')
+        .map(|component| {
+            let (index, hardened) = match component.strip_suffix(['\'', 'h']) {
+                Some(index) => (index, true),
+                None => (component, false),
+            };
+            let index: u32 = index.parse().map_err(|_| invalid())?;
+            if index >= 1 << 31 {
+                return Err(invalid());
+            }
+            Ok(if hardened { index | 1 << 31 } else { index })
+        })
+        .collect::<Result<_, _>>()?;
+    // the app reads at most 10 components
+    if components.len() > 10 {
+        return Err(invalid());
+    }
+    Ok(components)
+}
+
+/// APDUs over TCP to the Speculos emulator: each command is prefixed with
+/// its length, and each answer with the length of its data, without the
+/// status word.
+struct SpeculosTransport(Mutex<TcpStream>);
+
+impl LedgerTransport for SpeculosTransport {
+    fn exchange(&self, apdu: &[u8]) -> io::Result<Vec<u8>> {
+        let mut stream = self.0.lock().expect("lock poisoned");
+        stream.write_all(&(apdu.len() as u32).to_be_bytes())?;
+        stream.write_all(apdu)?;
+
+        let mut len = [0; 4];
+        stream.read_exact(&mut len)?;
+        let mut answer = vec![0; u32::from_be_bytes(len) as usize + 2];
+        stream.read_exact(&mut answer)?;
+        Ok(answer)
+    }
+}
+
+/// The size of the HID reports Ledger devices exchange.
+const HID_PACKET_SIZE: usize = 64;
+/// The channel and tag of the Ledger HID framing.
+const HID_HEADER: [u8; 3] = [0x01, 0x01, 0x05];
+
+/// Split `apdu` into HID reports: each starts with the channel, tag and
+/// its sequence number, and the first also holds the length of the APDU.
+fn hid_packets(apdu: &[u8]) -> Vec<[u8; HID_PACKET_SIZE]> {
+    let mut payload = (apdu.len() as u16).to_be_bytes().to_vec();
+    payload.extend_from_slice(apdu);
+
+    payload
+        .chunks(HID_PACKET_SIZE - 5)
+        .enumerate()
+        .map(|(sequence, chunk)| {
+            let mut packet = [0; HID_PACKET_SIZE];
+            packet[..3].copy_from_slice(&HID_HEADER);
+            packet[3..5].copy_from_slice(&(sequence as u16).to_be_bytes());
+            packet[5..5 + chunk.len()].copy_from_slice(chunk);
+            packet
+        })
+        .collect()
+}
+
+/// Reassemble an answer from the HID reports returned by `read_packet`.
+fn read_hid_answer(
+    mut read_packet: impl FnMut() -> io::Result<[u8; HID_PACKET_SIZE]>,
+) -> io::Result<Vec<u8>> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_owned());
+    let mut answer = Vec::new();
+    let mut expected = None;
+    for sequence in 0u16.. {
+        let packet = read_packet()?;
+        if packet[..3] != HID_HEADER || packet[3..5] != sequence.to_be_bytes() {
+            return Err(invalid("unexpected HID packet header"));
+        }
+        let data = match expected {
+            None => {
+                expected = Some(usize::from(u16::from_be_bytes([packet[5], packet[6]])));
+                &packet[7..]
+            }
+            Some(_) => &packet[5..],
+        };
+        let expected = expected.expect("set by the first packet");
+        let take = data.len().min(expected - answer.len());
+        answer.extend_from_slice(&data[..take]);
+        if answer.len() == expected {
+            return Ok(answer);
+        }
+    }
+    Err(invalid("HID answer too long"))
+}
+
+/// APDUs over USB HID through the Linux hidraw driver, which needs no
+/// library, only read and write access to the `/dev/hidraw*` device, which
+/// Ledger's udev rules grant.
+#[cfg(target_os = "linux")]
+struct HidrawTransport(Mutex<std::fs::File>);
+
+#[cfg(target_os = "linux")]
+impl HidrawTransport {
+    /// The vendor id of Ledger devices.
+    const LEDGER_VENDOR_ID: &'static str = "00002C97";
+
+    /// Open the first Ledger device. Ledger devices expose several USB
+    /// interfaces; APDUs go over the first.
+    fn open() -> Result<Self, LedgerError> {
+        let mut devices: Vec<_> = std::fs::read_dir("/sys/class/hidraw")?
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                let device = entry.path().join("device");
+                let is_ledger = std::fs::read_to_string(device.join("uevent"))
+                    .is_ok_and(|uevent| uevent.contains(Self::LEDGER_VENDOR_ID));
+                // the device's parent is the USB interface, like `1-1:1.0`
+                let first_interface = std::fs::canonicalize(&device).is_ok_and(|path| {
+                    path.parent()
+                        .and_then(|interface| interface.file_name())
+                        .is_some_and(|name| name.to_string_lossy().ends_with(".0"))
+                });
+                is_ledger && first_interface
+            })
+            .map(|entry| entry.file_name())
+            .collect();
+        devices.sort();
+
+        let name = devices.first().ok_or(LedgerError::NoDevice)?;
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(std::path::Path::new("/dev").join(name))?;
+        Ok(Self(Mutex::new(file)))
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl LedgerTransport for HidrawTransport {
+    fn exchange(&self, apdu: &[u8]) -> io::Result<Vec<u8>> {
+        let mut file = self.0.lock().expect("lock poisoned");
+        for packet in hid_packets(apdu) {
+            // hidraw expects the report id first, which is 0 for Ledgers
+            let mut report = [0; HID_PACKET_SIZE + 1];
+            report[1..].copy_from_slice(&packet);
+            file.write_all(&report)?;
+        }
+        read_hid_answer(|| {
+            let mut packet = [0; HID_PACKET_SIZE];
+            file.read_exact(&mut packet)?;
+            Ok(packet)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p256::{
+        ecdsa::{SigningKey, signature::Verifier, signature::hazmat::PrehashSigner},
+        elliptic_curve::SecretKey,
+    };
+
+    use super::*;
+
+    const TEST_PRIVATE_KEY_PEM: &str = include_str!("../../tests/test_private_key.pem");
+
+    /// The SSH/PGP Agent app, signing with a software key.
+    struct FakeApp {
+        key: SigningKey,
+        status: u16,
+    }
+
+    impl LedgerTransport for FakeApp {
+        fn exchange(&self, apdu: &[u8]) -> io::Result<Vec<u8>> {
+            assert_eq!(apdu[..4], [CLA, apdu[1], P1_LAST, P2_PRIME256V1]);
+            let data = &apdu[5..];
+            assert_eq!(usize::from(apdu[4]), data.len());
+            // 44'/535348'/0'/0/0
+            assert_eq!(data[0], 5);
+            assert_eq!(data[1..5], (44 | 1u32 << 31).to_be_bytes());
+
+            let mut answer = match apdu[1] {
+                INS_GET_PUBLIC_KEY => {
+                    let point = self.key.verifying_key().to_encoded_point(false);
+                    let mut answer = vec![point.len() as u8];
+                    answer.extend_from_slice(point.as_bytes());
+                    answer
+                }
+                INS_SIGN_DIRECT_HASH => {
+                    let signature: Signature = self.key.sign_prehash(&data[21..]).unwrap();
+                    signature.to_der().as_bytes().to_vec()
+                }
+                _ => Vec::new(),
+            };
+            if self.status != SW_OK {
+                answer.clear();
+            }
+            answer.extend_from_slice(&self.status.to_be_bytes());
+            Ok(answer)
+        }
+    }
+
+    fn fake_key(status: u16) -> (LedgerKey, SigningKey) {
+        let key = SigningKey::from(SecretKey::from_sec1_pem(TEST_PRIVATE_KEY_PEM).unwrap());
+        let app = FakeApp {
+            key: key.clone(),
+            status,
+        };
+        let ledger = LedgerKey::with_transport(app, "44'/535348'/0'/0/0").unwrap();
+        (ledger, key)
+    }
+
+    #[tokio::test]
+    async fn test_ledger_key_signs_the_message_digest() {
+        let (ledger, key) = fake_key(SW_OK);
+
+        assert_eq!(
+            ledger.public_key().await.unwrap(),
+            key.verifying_key().into()
+        );
+        let signature = ledger.sign(b"canonical request").await.unwrap();
+        assert!(
+            key.verifying_key()
+                .verify(b"canonical request", &signature)
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ledger_key_reports_rejections() {
+        let (ledger, _) = fake_key(SW_DENIED);
+        let SigningError::Other(error) = ledger.sign(b"canonical request").await.unwrap_err()
+        else {
+            panic!("expected a ledger error");
+        };
+        assert!(matches!(
+            error.downcast_ref::<LedgerError>(),
+            Some(LedgerError::Rejected)
+        ));
+
+        let (ledger, _) = fake_key(0x6e00);
+        assert!(matches!(
+            ledger.public_key().await,
+            Err(LedgerError::AppNotOpen)
+        ));
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(
+            parse_path("m/44'/535348h/0'/0/1").unwrap(),
+            [44 | 1 << 31, 535348 | 1 << 31, 1 << 31, 0, 1]
+        );
+        for invalid in ["", "44'/x", "2147483648", "0/0/0/0/0/0/0/0/0/0/0"] {
+            assert!(matches!(
+                parse_path(invalid),
+                Err(LedgerError::InvalidPath(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_hid_framing_round_trips() {
+        let apdu: Vec<u8> = (0..=150).collect();
+        let packets = hid_packets(&apdu);
+        assert_eq!(packets.len(), 3);
+        assert_eq!(packets[1][..5], [0x01, 0x01, 0x05, 0x00, 0x01]);
+
+        let mut packets = packets.into_iter();
+        let answer = read_hid_answer(|| Ok(packets.next().unwrap())).unwrap();
+        assert_eq!(answer, apdu);
+    }
+}
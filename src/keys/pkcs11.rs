@@ -0,0 +1,580 @@
+//! Signing with keys that live on a PKCS#11 token
+//!
+//! A `Pkcs11Key` loads the PKCS#11 module of an HSM or hardware token, such
+//! as `yubihsm_pkcs11.so`, and has the token sign the SHA-256 digest of the
+//! message with `CKM_ECDSA`. The private key never leaves the token. The
+//! module is loaded at runtime, so only the few functions used for signing
+//! are declared here rather than depending on a full PKCS#11 binding.
+
+use std::{
+    ffi::{OsStr, c_ulong, c_void},
+    fmt, ptr,
+    sync::Arc,
+};
+
+use p256::ecdsa::Signature;
+use sha2::{Digest, Sha256};
+
+use super::IntoSignature;
+use crate::{Pkcs11Error, SigningError};
+
+/// The subset of the PKCS#11 2.40 C API used for signing.
+#[allow(non_camel_case_types)]
+mod ffi {
+    use std::ffi::{c_ulong, c_void};
+
+    pub type CK_ULONG = c_ulong;
+    pub type CK_RV = CK_ULONG;
+
+    pub const CKR_OK: CK_RV = 0x0;
+    pub const CKR_FUNCTION_NOT_SUPPORTED: CK_RV = 0x54;
+    pub const CKR_USER_ALREADY_LOGGED_IN: CK_RV = 0x100;
+    pub const CKR_CRYPTOKI_ALREADY_INITIALIZED: CK_RV = 0x191;
+
+    pub const CKF_OS_LOCKING_OK: CK_ULONG = 0x2;
+    pub const CKF_SERIAL_SESSION: CK_ULONG = 0x4;
+    pub const CKU_USER: CK_ULONG = 1;
+    pub const CKA_CLASS: CK_ULONG = 0x0;
+    pub const CKA_LABEL: CK_ULONG = 0x3;
+    pub const CKO_PRIVATE_KEY: CK_ULONG = 0x3;
+    pub const CKM_ECDSA: CK_ULONG = 0x1041;
+
+    // PKCS#11 structs are packed on Windows only.
+    #[cfg_attr(windows, repr(C, packed))]
+    #[cfg_attr(not(windows), repr(C))]
+    pub struct Attribute {
+        pub kind: CK_ULONG,
+        pub value: *const c_void,
+        pub len: CK_ULONG,
+    }
+
+    #[cfg_attr(windows, repr(C, packed))]
+    #[cfg_attr(not(windows), repr(C))]
+    pub struct Mechanism {
+        pub mechanism: CK_ULONG,
+        pub parameter: *const c_void,
+        pub len: CK_ULONG,
+    }
+
+    #[cfg_attr(windows, repr(C, packed))]
+    #[cfg_attr(not(windows), repr(C))]
+    pub struct InitializeArgs {
+        pub create_mutex: *const c_void,
+        pub destroy_mutex: *const c_void,
+        pub lock_mutex: *const c_void,
+        pub unlock_mutex: *const c_void,
+        pub flags: CK_ULONG,
+        pub reserved: *const c_void,
+    }
+
+    type Unused = Option<unsafe extern "C" fn()>;
+
+    /// `CK_FUNCTION_LIST` up to `C_Sign`, the functions after it are never
+    /// read so they are left out.
+    #[cfg_attr(windows, repr(C, packed))]
+    #[cfg_attr(not(windows), repr(C))]
+    #[derive(Default)]
+    pub struct FunctionList {
+        pub _version: [u8; 2],
+        pub initialize: Option<unsafe extern "C" fn(*const InitializeArgs) -> CK_RV>,
+        pub _finalize: Unused,
+        pub _get_info: Unused,
+        pub _get_function_list: Unused,
+        pub _get_slot_list: Unused,
+        pub _get_slot_info: Unused,
+        pub _get_token_info: Unused,
+        pub _get_mechanism_list: Unused,
+        pub _get_mechanism_info: Unused,
+        pub _init_token: Unused,
+        pub _init_pin: Unused,
+        pub _set_pin: Unused,
+        pub open_session: Option<
+            unsafe extern "C" fn(
+                CK_ULONG,
+                CK_ULONG,
+                *const c_void,
+                *const c_void,
+                *mut CK_ULONG,
+            ) -> CK_RV,
+        >,
+        pub close_session: Option<unsafe extern "C" fn(CK_ULONG) -> CK_RV>,
+        pub _close_all_sessions: Unused,
+        pub _get_session_info: Unused,
+        pub _get_operation_state: Unused,
+        pub _set_operation_state: Unused,
+        pub login: Option<unsafe extern "C" fn(CK_ULONG, CK_ULONG, *const u8, CK_ULONG) -> CK_RV>,
+        pub _logout: Unused,
+        pub _create_object: Unused,
+        pub _copy_object: Unused,
+        pub _destroy_object: Unused,
+        pub _get_object_size: Unused,
+        pub _get_attribute_value: Unused,
+        pub _set_attribute_value: Unused,
+        pub find_objects_init:
+            Option<unsafe extern "C" fn(CK_ULONG, *const Attribute, CK_ULONG) -> CK_RV>,
+        pub find_objects:
+            Option<unsafe extern "C" fn(CK_ULONG, *mut CK_ULONG, CK_ULONG, *mut CK_ULONG) -> CK_RV>,
+        pub find_objects_final: Option<unsafe extern "C" fn(CK_ULONG) -> CK_RV>,
+        pub _encrypt_init: Unused,
+        pub _encrypt: Unused,
+        pub _encrypt_update: Unused,
+        pub _encrypt_final: Unused,
+        pub _decrypt_init: Unused,
+        pub _decrypt: Unused,
+        pub _decrypt_update: Unused,
+        pub _decrypt_final: Unused,
+        pub _digest_init: Unused,
+        pub _digest: Unused,
+        pub _digest_update: Unused,
+        pub _digest_key: Unused,
+        pub _digest_final: Unused,
+        pub sign_init: Option<unsafe extern "C" fn(CK_ULONG, *const Mechanism, CK_ULONG) -> CK_RV>,
+        pub sign: Option<
+            unsafe extern "C" fn(CK_ULONG, *const u8, CK_ULONG, *mut u8, *mut CK_ULONG) -> CK_RV,
+        >,
+    }
+
+    pub type GetFunctionList = unsafe extern "C" fn(*mut *const FunctionList) -> CK_RV;
+}
+
+/// Call a function of the module, turning a missing function or a return
+/// value other than `CKR_OK` (or one of `$ok`) into `Pkcs11Error::Function`.
+macro_rules! call {
+    ($module:expr, $name:literal, $function:ident($($arg:expr),* $(,)?) $(, ok: $ok:expr)?) => {{
+        let name = $name;
+        // SAFETY: the function list outlives the module, see `Module`.
+        let function = unsafe { (*$module.functions).$function }
+            .ok_or(function_error(name, ffi::CKR_FUNCTION_NOT_SUPPORTED))?;
+        // SAFETY: the arguments are valid for the duration of the call.
+        let rv = unsafe { function($($arg),*) };
+        if rv != ffi::CKR_OK $(&& rv != $ok)? {
+            return Err(function_error(name, rv));
+        }
+    }};
+}
+
+#[allow(clippy::useless_conversion)] // `CK_ULONG` is 32 bits on Windows
+fn function_error(function: &'static str, rv: ffi::CK_RV) -> Pkcs11Error {
+    Pkcs11Error::Function {
+        function,
+        rv: u64::from(rv),
+    }
+}
+
+/// A loaded and initialized PKCS#11 module.
+struct Module {
+    /// Keeps the function list loaded, `None` for an in-process module.
+    _library: Option<libloading::Library>,
+    functions: *const ffi::FunctionList,
+}
+
+// SAFETY: the module is initialized with `CKF_OS_LOCKING_OK`, which
+// requires it to be safe to call from several threads at once.
+unsafe impl Send for Module {}
+unsafe impl Sync for Module {}
+
+impl Module {
+    fn load(path: &OsStr) -> Result<Self, Pkcs11Error> {
+        // SAFETY: loading a PKCS#11 module runs no initialization routines
+        // beyond those of any shared library, and `C_GetFunctionList` has
+        // this signature in every PKCS#11 version.
+        let (library, functions) = unsafe {
+            let library = libloading::Library::new(path)?;
+            let get_function_list = library.get::<ffi::GetFunctionList>(b"C_GetFunctionList\0")?;
+            let mut functions = ptr::null();
+            let rv = get_function_list(&mut functions);
+            if rv != ffi::CKR_OK || functions.is_null() {
+                return Err(function_error("C_GetFunctionList", rv));
+            }
+            (library, functions)
+        };
+
+        Self::initialize(Some(library), functions)
+    }
+
+    fn initialize(
+        library: Option<libloading::Library>,
+        functions: *const ffi::FunctionList,
+    ) -> Result<Self, Pkcs11Error> {
+        let module = Self {
+            _library: library,
+            functions,
+        };
+        let args = ffi::InitializeArgs {
+            create_mutex: ptr::null(),
+            destroy_mutex: ptr::null(),
+            lock_mutex: ptr::null(),
+            unlock_mutex: ptr::null(),
+            flags: ffi::CKF_OS_LOCKING_OK,
+            reserved: ptr::null(),
+        };
+        // another key may have initialized the module already
+        call!(module, "C_Initialize", initialize(&args), ok: ffi::CKR_CRYPTOKI_ALREADY_INITIALIZED);
+        Ok(module)
+    }
+}
+
+/// An open session, closed when dropped.
+struct Session<'a> {
+    module: &'a Module,
+    handle: ffi::CK_ULONG,
+}
+
+impl<'a> Session<'a> {
+    fn open(module: &'a Module, slot: ffi::CK_ULONG) -> Result<Self, Pkcs11Error> {
+        let mut handle = 0;
+        call!(
+            module,
+            "C_OpenSession",
+            open_session(
+                slot,
+                ffi::CKF_SERIAL_SESSION,
+                ptr::null(),
+                ptr::null(),
+                &mut handle,
+            )
+        );
+        Ok(Self { module, handle })
+    }
+}
+
+impl Drop for Session<'_> {
+    fn drop(&mut self) {
+        // SAFETY: the session was opened on this module and is not used
+        // after this.
+        if let Some(close_session) = unsafe { (*self.module.functions).close_session } {
+            let rv = unsafe { close_session(self.handle) };
+            if rv != ffi::CKR_OK {
+                tracing::warn!("failed to close PKCS#11 session: CKR 0x{rv:x}");
+            }
+        }
+    }
+}
+
+/// A P-256 private key on a PKCS#11 token, such as a YubiHSM, which signs
+/// with `CKM_ECDSA` without the key ever leaving the token.
+///
+/// The key is found by its `CKA_LABEL` on the token in `slot`, logging in
+/// as the normal user with `pin`. For a YubiHSM 2 the pin is the four digit
+/// id of the authentication key followed by its password, e.g.
+/// `0001password`. Register its public key with Privy as you would any
+/// other authorization key.
+///
+/// Every signature opens and closes its own session, and the call into the
+/// module runs on the blocking thread pool of the tokio runtime.
+///
+/// ```rust,no_run
+/// use privy_rs::{AuthorizationContext, Pkcs11Key};
+///
+/// # fn foo() -> Result<(), Box<dyn std::error::Error>> {
+/// let key = Pkcs11Key::open(
+///     "/usr/lib/pkcs11/yubihsm_pkcs11.so",
+///     0,
+///     "privy-owner",
+///     std::env::var("YUBIHSM_PIN")?,
+/// )?;
+/// let ctx = AuthorizationContext::new().push(key);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Pkcs11Key {
+    module: Arc<Module>,
+    slot: ffi::CK_ULONG,
+    label: String,
+    pin: zeroize::Zeroizing<String>,
+}
+
+impl fmt::Debug for Pkcs11Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pkcs11Key")
+            .field("slot", &self.slot)
+            .field("label", &self.label)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Pkcs11Key {
+    /// Load the PKCS#11 module at `module_path` and use the private key
+    /// labelled `label` on the token in `slot`.
+    ///
+    /// The token is not contacted until the first signature, so a wrong
+    /// pin or label only shows up then.
+    ///
+    /// # Errors
+    /// If the module cannot be loaded or initialized.
+    pub fn open(
+        module_path: impl AsRef<OsStr>,
+        slot: u64,
+        label: impl Into<String>,
+        pin: impl Into<String>,
+    ) -> Result<Self, Pkcs11Error> {
+        let module = Module::load(module_path.as_ref())?;
+        Ok(Self::with_module(module, slot, label.into(), pin.into()))
+    }
+
+    fn with_module(module: Module, slot: u64, label: String, pin: String) -> Self {
+        Self {
+            module: Arc::new(module),
+            slot: slot as c_ulong,
+            label,
+            pin: zeroize::Zeroizing::new(pin),
+        }
+    }
+
+    fn sign_digest(&self, digest: &[u8]) -> Result<Signature, Pkcs11Error> {
+        let module = &*self.module;
+        let session = Session::open(module, self.slot)?;
+
+        call!(
+            module,
+            "C_Login", login(
+                session.handle,
+                ffi::CKU_USER,
+                self.pin.as_ptr(),
+                self.pin.len() as c_ulong,
+            ),
+            ok: ffi::CKR_USER_ALREADY_LOGGED_IN
+        );
+
+        let class = ffi::CKO_PRIVATE_KEY;
+        let template = [
+            ffi::Attribute {
+                kind: ffi::CKA_CLASS,
+                value: (&raw const class).cast::<c_void>(),
+                len: size_of_val(&class) as c_ulong,
+            },
+            ffi::Attribute {
+                kind: ffi::CKA_LABEL,
+                value: self.label.as_ptr().cast::<c_void>(),
+                len: self.label.len() as c_ulong,
+            },
+        ];
+        let mut key = 0;
+        let mut count = 0;
+        call!(
+            module,
+            "C_FindObjectsInit",
+            find_objects_init(session.handle, template.as_ptr(), template.len() as c_ulong)
+        );
+        call!(
+            module,
+            "C_FindObjects",
+            find_objects(session.handle, &mut key, 1, &mut count)
+        );
+        call!(
+            module,
+            "C_FindObjectsFinal",
+            find_objects_final(session.handle)
+        );
+        if count == 0 {
+            return Err(Pkcs11Error::KeyNotFound(self.label.clone()));
+        }
+
+        let mechanism = ffi::Mechanism {
+            mechanism: ffi::CKM_ECDSA,
+            parameter: ptr::null(),
+            len: 0,
+        };
+        let mut signature = [0u8; 64];
+        let mut len = signature.len() as c_ulong;
+        call!(
+            module,
+            "C_SignInit",
+            sign_init(session.handle, &mechanism, key)
+        );
+        call!(
+            module,
+            "C_Sign",
+            sign(
+                session.handle,
+                digest.as_ptr(),
+                digest.len() as c_ulong,
+                signature.as_mut_ptr(),
+                &mut len,
+            )
+        );
+
+        // CKM_ECDSA signatures are r and s concatenated, not DER
+        Signature::from_slice(&signature[..len as usize])
+            .map_err(|e| Pkcs11Error::InvalidSignature(e.to_string()))
+    }
+}
+
+impl IntoSignature for Pkcs11Key {
+    async fn sign(&self, message: &[u8]) -> Result<Signature, SigningError> {
+        tracing::debug!(
+            "Signing {} byte message with PKCS#11 key {}",
+            message.len(),
+            self.label
+        );
+        let key = self.clone();
+        let digest = Sha256::digest(message);
+        tokio::task::spawn_blocking(move || key.sign_digest(&digest))
+            .await
+            .map_err(|e| SigningError::Other(Box::new(e)))?
+            .map_err(|e| SigningError::Other(Box::new(e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use p256::ecdsa::{SigningKey, signature::Verifier, signature::hazmat::PrehashSigner};
+
+    use super::*;
+
+    const TEST_PRIVATE_KEY_PEM: &str = include_str!("../../tests/test_private_key.pem");
+    const KEY_HANDLE: ffi::CK_ULONG = 7;
+    const CKR_PIN_INCORRECT: ffi::CK_RV = 0xa0;
+
+    thread_local! {
+        static LABEL: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from(p256::SecretKey::from_sec1_pem(TEST_PRIVATE_KEY_PEM).unwrap())
+    }
+
+    // An in-process token holding the test key, labelled `privy-owner`,
+    // behind the pin `1234`.
+
+    unsafe extern "C" fn initialize(_: *const ffi::InitializeArgs) -> ffi::CK_RV {
+        ffi::CKR_OK
+    }
+
+    unsafe extern "C" fn open_session(
+        _: ffi::CK_ULONG,
+        _: ffi::CK_ULONG,
+        _: *const c_void,
+        _: *const c_void,
+        session: *mut ffi::CK_ULONG,
+    ) -> ffi::CK_RV {
+        unsafe { *session = 1 };
+        ffi::CKR_OK
+    }
+
+    unsafe extern "C" fn close_session(_: ffi::CK_ULONG) -> ffi::CK_RV {
+        ffi::CKR_OK
+    }
+
+    unsafe extern "C" fn login(
+        _: ffi::CK_ULONG,
+        _: ffi::CK_ULONG,
+        pin: *const u8,
+        len: ffi::CK_ULONG,
+    ) -> ffi::CK_RV {
+        match unsafe { std::slice::from_raw_parts(pin, len as usize) } {
+            b"1234" => ffi::CKR_OK,
+            _ => CKR_PIN_INCORRECT,
+        }
+    }
+
+    unsafe extern "C" fn find_objects_init(
+        _: ffi::CK_ULONG,
+        template: *const ffi::Attribute,
+        count: ffi::CK_ULONG,
+    ) -> ffi::CK_RV {
+        let template = unsafe { std::slice::from_raw_parts(template, count as usize) };
+        for attribute in template.iter().filter(|a| a.kind == ffi::CKA_LABEL) {
+            let label = unsafe {
+                std::slice::from_raw_parts(attribute.value.cast::<u8>(), attribute.len as usize)
+            };
+            LABEL.set(label.to_vec());
+        }
+        ffi::CKR_OK
+    }
+
+    unsafe extern "C" fn find_objects(
+        _: ffi::CK_ULONG,
+        objects: *mut ffi::CK_ULONG,
+        _: ffi::CK_ULONG,
+        count: *mut ffi::CK_ULONG,
+    ) -> ffi::CK_RV {
+        let found = LABEL.with_borrow(|label| label == b"privy-owner");
+        unsafe {
+            *objects = KEY_HANDLE;
+            *count = found.into();
+        }
+        ffi::CKR_OK
+    }
+
+    unsafe extern "C" fn sign_init(
+        _: ffi::CK_ULONG,
+        mechanism: *const ffi::Mechanism,
+        key: ffi::CK_ULONG,
+    ) -> ffi::CK_RV {
+        assert_eq!(unsafe { (*mechanism).mechanism }, ffi::CKM_ECDSA);
+        assert_eq!(key, KEY_HANDLE);
+        ffi::CKR_OK
+    }
+
+    unsafe extern "C" fn sign(
+        _: ffi::CK_ULONG,
+        data: *const u8,
+        len: ffi::CK_ULONG,
+        signature: *mut u8,
+        signature_len: *mut ffi::CK_ULONG,
+    ) -> ffi::CK_RV {
+        let digest = unsafe { std::slice::from_raw_parts(data, len as usize) };
+        let signed: Signature = signing_key().sign_prehash(digest).unwrap();
+        let bytes = signed.to_bytes();
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), signature, bytes.len());
+            *signature_len = bytes.len() as ffi::CK_ULONG;
+        }
+        ffi::CKR_OK
+    }
+
+    fn key(label: &str, pin: &str) -> Pkcs11Key {
+        let functions = Box::leak(Box::new(ffi::FunctionList {
+            initialize: Some(initialize),
+            open_session: Some(open_session),
+            close_session: Some(close_session),
+            login: Some(login),
+            find_objects_init: Some(find_objects_init),
+            find_objects: Some(find_objects),
+            find_objects_final: Some(close_session),
+            sign_init: Some(sign_init),
+            sign: Some(sign),
+            ..Default::default()
+        }));
+        let module = Module::initialize(None, functions).unwrap();
+        Pkcs11Key::with_module(module, 0, label.to_string(), pin.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_pkcs11_key_signs_the_digest_of_the_message() {
+        let message = b"canonical request data";
+        let signature = key("privy-owner", "1234").sign(message).await.unwrap();
+        assert!(
+            signing_key()
+                .verifying_key()
+                .verify(message, &signature)
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pkcs11_errors() {
+        let error = |key: Pkcs11Key| async move {
+            let Err(SigningError::Other(error)) = key.sign(b"message").await else {
+                panic!("expected a PKCS#11 error");
+            };
+            *error.downcast::<Pkcs11Error>().expect("a PKCS#11 error")
+        };
+
+        assert!(matches!(
+            error(key("privy-owner", "4321")).await,
+            Pkcs11Error::Function {
+                function: "C_Login",
+                rv: 0xa0,
+            }
+        ));
+        assert!(matches!(
+            error(key("someone-else", "1234")).await,
+            Pkcs11Error::KeyNotFound(label) if label == "someone-else"
+        ));
+    }
+}
@@ -0,0 +1,214 @@
+//! Splitting authorization keys with Shamir secret sharing
+//!
+//! For disaster recovery, the owner key of an app can be split into `n`
+//! shares, of which any `threshold` rebuild the key while fewer reveal
+//! nothing about it. The shares are handed to different people or stored
+//! in different places, and brought back together with `ShamirKey` when
+//! the key is needed. The sharing itself is done by `vsss-rs`, over the
+//! scalar field of P-256.
+
+use std::{fmt, str::FromStr};
+
+use p256::{NonZeroScalar, Scalar, elliptic_curve::PrimeField};
+use zeroize::Zeroizing;
+
+use super::{IntoKey, Key};
+use crate::{KeyError, ShamirError};
+
+/// The length of a share: its identifier, then its value.
+const SHARE_LEN: usize = 33;
+
+/// One share of a key split with `ShamirKey::split`.
+///
+/// A share is written and read as 66 hex characters with
+/// `KeyShare::to_hex` and `str::parse`. Like the key it comes from, it is
+/// a secret: it is zeroized when dropped, and its `Debug` output only shows
+/// its identifier.
+#[derive(Clone)]
+pub struct KeyShare(Zeroizing<[u8; SHARE_LEN]>);
+
+impl KeyShare {
+    /// The identifier of the share, from 1 to the number of shares the key
+    /// was split into.
+    #[must_use]
+    pub fn id(&self) -> u8 {
+        self.0[0]
+    }
+
+    /// The share as hex, to hand to its holder.
+    #[must_use]
+    pub fn to_hex(&self) -> Zeroizing<String> {
+        Zeroizing::new(hex::encode(*self.0))
+    }
+}
+
+impl fmt::Debug for KeyShare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyShare")
+            .field("id", &self.id())
+            .finish_non_exhaustive()
+    }
+}
+
+impl FromStr for KeyShare {
+    type Err = ShamirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut share = Zeroizing::new([0; SHARE_LEN]);
+        hex::decode_to_slice(s.trim(), &mut *share).map_err(|_| ShamirError::InvalidShare)?;
+        if share[0] == 0 {
+            return Err(ShamirError::InvalidShare);
+        }
+        Ok(Self(share))
+    }
+}
+
+/// An authorization key rebuilt from the shares it was split into, see the
+/// [module docs](self).
+///
+/// Fewer shares than the threshold still combine to a key, just not the
+/// right one. Compare the `IntoKey::fingerprint` of the rebuilt key with
+/// the one recorded when the key was split before relying on it.
+///
+/// ```rust
+/// # use privy_rs::{IntoKey, KeyShare, PrivateKey, ShamirKey};
+/// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+/// # let owner_key = include_str!("../../tests/test_private_key.pem").to_string();
+/// let key = PrivateKey::new(owner_key);
+/// let shares = ShamirKey::split(&key, 3, 5).await?;
+/// let written: Vec<_> = shares.iter().map(KeyShare::to_hex).collect();
+///
+/// // later, with any three of the five shares
+/// let shares = written[1..4]
+///     .iter()
+///     .map(|share| share.parse())
+///     .collect::<Result<Vec<KeyShare>, _>>()?;
+/// let rebuilt = ShamirKey::combine(shares)?;
+/// assert_eq!(rebuilt.fingerprint().await?, key.fingerprint().await?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ShamirKey(Key);
+
+impl fmt::Debug for ShamirKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShamirKey").finish_non_exhaustive()
+    }
+}
+
+impl ShamirKey {
+    /// Split `key` into `shares` shares, any `threshold` of which rebuild
+    /// it.
+    ///
+    /// # Errors
+    /// If `key` can not be loaded, or `threshold` is less than 2 or more
+    /// than `shares`.
+    pub async fn split(
+        key: &impl IntoKey,
+        threshold: u8,
+        shares: u8,
+    ) -> Result<Vec<KeyShare>, ShamirError> {
+        if threshold < 2 || threshold > shares {
+            return Err(ShamirError::InvalidThreshold { threshold, shares });
+        }
+        let key = key.get_key().await?;
+        let secret = *key.to_nonzero_scalar();
+
+        let split = vsss_rs::shamir::split_secret::<Scalar, u8, [u8; SHARE_LEN]>(
+            threshold.into(),
+            shares.into(),
+            secret,
+            rand::rngs::OsRng,
+        )
+        .map_err(|e| ShamirError::Sharing(e.to_string()))?;
+        Ok(split
+            .into_iter()
+            .map(|share| KeyShare(Zeroizing::new(share)))
+            .collect())
+    }
+
+    /// Rebuild a key from its shares. The shares are zeroized once the key
+    /// is rebuilt.
+    ///
+    /// # Errors
+    /// If fewer than two shares are given, two shares have the same
+    /// identifier, or they combine to an invalid key.
+    pub fn combine(shares: Vec<KeyShare>) -> Result<Self, ShamirError> {
+        if shares.len() < 2 {
+            return Err(ShamirError::NotEnoughShares(shares.len()));
+        }
+        let shares: Zeroizing<Vec<[u8; SHARE_LEN]>> =
+            Zeroizing::new(shares.iter().map(|share| *share.0).collect());
+
+        let secret: Scalar =
+            vsss_rs::combine_shares(&shares).map_err(|e| ShamirError::Sharing(e.to_string()))?;
+        let secret = Option::<NonZeroScalar>::from(NonZeroScalar::from_repr(secret.to_repr()))
+            .ok_or(ShamirError::InvalidShare)?;
+        Ok(Self(Key::from(secret)))
+    }
+}
+
+impl IntoKey for ShamirKey {
+    async fn get_key(&self) -> Result<Key, KeyError> {
+        Ok(self.0.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+
+    const TEST_PRIVATE_KEY_PEM: &str = include_str!("../../tests/test_private_key.pem");
+
+    #[tokio::test]
+    async fn test_any_threshold_of_shares_rebuild_the_key() {
+        let key = PrivateKey::new(TEST_PRIVATE_KEY_PEM.to_string());
+        let expected = key.get_key().await.unwrap();
+        let shares = ShamirKey::split(&key, 2, 3).await.unwrap();
+        assert_eq!(
+            shares.iter().map(KeyShare::id).collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+
+        for pair in [[0, 1], [1, 2], [2, 0]] {
+            let written = pair.map(|i| shares[i].to_hex());
+            let parsed = written.iter().map(|share| share.parse().unwrap()).collect();
+            let rebuilt = ShamirKey::combine(parsed).unwrap();
+            assert_eq!(rebuilt.get_key().await.unwrap(), expected);
+        }
+
+        // a single share reveals nothing, and is refused
+        assert!(matches!(
+            ShamirKey::combine(vec![shares[0].clone()]),
+            Err(ShamirError::NotEnoughShares(1))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_splits_and_shares() {
+        let key = PrivateKey::new(TEST_PRIVATE_KEY_PEM.to_string());
+        assert!(matches!(
+            ShamirKey::split(&key, 4, 3).await,
+            Err(ShamirError::InvalidThreshold {
+                threshold: 4,
+                shares: 3
+            })
+        ));
+        assert!(matches!(
+            ShamirKey::split(&key, 1, 3).await,
+            Err(ShamirError::InvalidThreshold { .. })
+        ));
+
+        assert!("not hex".parse::<KeyShare>().is_err());
+        assert!("00".repeat(SHARE_LEN).parse::<KeyShare>().is_err());
+
+        let shares = ShamirKey::split(&key, 2, 2).await.unwrap();
+        assert!(format!("{:?}", shares[0]).starts_with("KeyShare { id: 1"));
+        assert!(
+            ShamirKey::combine(vec![shares[0].clone(), shares[0].clone()]).is_err(),
+            "duplicate shares must not combine"
+        );
+    }
+}
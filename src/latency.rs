@@ -0,0 +1,194 @@
+//! Per-endpoint latency tracking.
+//!
+//! Every `PrivyClient` keeps an exponential moving average of the latency
+//! of each API endpoint it calls. When the average for an endpoint rises
+//! above the client's slow call threshold, a `tracing` warning is emitted
+//! with the id of the request that pushed it over, and another event once
+//! it recovers. This catches degradation on the Privy side early, without
+//! any metrics infrastructure.
+//!
+//! The threshold is set with `PrivyClientOptions::slow_call_threshold`, and
+//! the averages can be read back through [`PrivyClient::latency`]:
+//!
+//! ```no_run
+//! # use privy_rs::PrivyClient;
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! client.wallets().get("wallet_id").await?;
+//!
+//! for (endpoint, latency) in client.latency().endpoints() {
+//!     println!("{endpoint}: {latency:?}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    PrivyApiError, PrivyApiErrorExt, PrivyClient, errors::request_id, generated::ResponseValue,
+};
+
+/// How much weight the latest call has in the moving average.
+const DEFAULT_SMOOTHING: f64 = 0.2;
+
+#[derive(Debug)]
+struct EndpointLatency {
+    average: f64,
+    slow: bool,
+}
+
+/// Moving averages of the latency of each API endpoint, see the
+/// [module docs](self).
+///
+/// Cloning is cheap, and clones share their averages.
+#[derive(Clone, Debug)]
+pub struct LatencyTracker {
+    threshold: Option<Duration>,
+    smoothing: f64,
+    endpoints: Arc<Mutex<HashMap<&'static str, EndpointLatency>>>,
+}
+
+impl LatencyTracker {
+    pub(crate) fn new(threshold: Option<Duration>) -> Self {
+        Self {
+            threshold,
+            smoothing: DEFAULT_SMOOTHING,
+            endpoints: Arc::default(),
+        }
+    }
+
+    /// The average latency above which calls are reported as slow.
+    pub(crate) fn threshold(&self) -> Option<Duration> {
+        self.threshold
+    }
+
+    /// The average latency of `endpoint`, named after its operation, e.g.
+    /// `wallet_rpc`. `None` if it has not been called yet.
+    #[must_use]
+    pub fn average(&self, endpoint: &str) -> Option<Duration> {
+        self.endpoints
+            .lock()
+            .expect("lock poisoned")
+            .get(endpoint)
+            .map(|latency| Duration::from_secs_f64(latency.average))
+    }
+
+    /// The average latency of every endpoint called so far, slowest first.
+    #[must_use]
+    pub fn endpoints(&self) -> Vec<(&'static str, Duration)> {
+        let mut endpoints: Vec<_> = self
+            .endpoints
+            .lock()
+            .expect("lock poisoned")
+            .iter()
+            .map(|(endpoint, latency)| (*endpoint, Duration::from_secs_f64(latency.average)))
+            .collect();
+        endpoints.sort_by_key(|endpoint| std::cmp::Reverse(endpoint.1));
+        endpoints
+    }
+
+    /// Record a call to `endpoint` that took `elapsed`.
+    pub(crate) fn record<T>(
+        &self,
+        endpoint: &'static str,
+        elapsed: Duration,
+        result: &Result<ResponseValue<T>, PrivyApiError>,
+    ) {
+        let mut endpoints = self.endpoints.lock().expect("lock poisoned");
+        let latency = endpoints.entry(endpoint).or_insert(EndpointLatency {
+            average: elapsed.as_secs_f64(),
+            slow: false,
+        });
+        latency.average += self.smoothing * (elapsed.as_secs_f64() - latency.average);
+
+        let Some(threshold) = self.threshold else {
+            return;
+        };
+        let average = Duration::from_secs_f64(latency.average);
+        let slow = average > threshold;
+        if slow == latency.slow {
+            return;
+        }
+        latency.slow = slow;
+
+        if slow {
+            let request_id = match result {
+                Ok(response) => request_id(response.headers()),
+                Err(e) => e.details().and_then(|details| details.request_id.clone()),
+            };
+            tracing::warn!(
+                endpoint,
+                ?average,
+                ?threshold,
+                request_id,
+                "Privy API endpoint is responding slowly"
+            );
+        } else {
+            tracing::info!(endpoint, ?average, "Privy API endpoint latency recovered");
+        }
+    }
+}
+
+impl PrivyClient {
+    /// The latency averages of this client, see the
+    /// [`latency`](crate::latency) module.
+    #[must_use]
+    pub fn latency(&self) -> &LatencyTracker {
+        &self.shared.latency
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing_test::traced_test;
+
+    use super::*;
+
+    #[allow(clippy::result_large_err)]
+    fn ok() -> Result<ResponseValue<()>, PrivyApiError> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("privy-request-id", "req_123".parse().unwrap());
+        Ok(ResponseValue::new((), reqwest::StatusCode::OK, headers))
+    }
+
+    #[test]
+    fn test_average_moves_towards_latest_call() {
+        let tracker = LatencyTracker::new(None);
+        tracker.record("wallet_rpc", Duration::from_millis(100), &ok());
+        assert_eq!(
+            tracker.average("wallet_rpc"),
+            Some(Duration::from_millis(100))
+        );
+
+        tracker.record("wallet_rpc", Duration::from_millis(600), &ok());
+        assert_eq!(
+            tracker.average("wallet_rpc"),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(tracker.average("get_wallet"), None);
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_warns_once_when_average_exceeds_threshold() {
+        let tracker = LatencyTracker::new(Some(Duration::from_secs(1)));
+        tracker.record("wallet_rpc", Duration::from_millis(100), &ok());
+        tracker.record("get_wallet", Duration::from_secs(2), &ok());
+        tracker.record("get_wallet", Duration::from_secs(2), &ok());
+
+        logs_assert(|lines: &[&str]| {
+            let warnings: Vec<_> = lines.iter().filter(|l| l.contains("WARN")).collect();
+            match warnings.as_slice() {
+                [warning] if warning.contains("get_wallet") && warning.contains("req_123") => {
+                    Ok(())
+                }
+                _ => Err(format!("expected one warning, got {warnings:?}")),
+            }
+        });
+    }
+}
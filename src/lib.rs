@@ -6,10 +6,55 @@
 
 use base64::{Engine, engine::general_purpose::STANDARD};
 
+pub mod activity;
+pub mod address;
+pub mod any_wallet;
+pub mod chain;
 pub mod client;
+#[cfg(feature = "config")]
+pub mod config_sources;
+pub mod connection;
+pub mod dns;
+#[cfg(feature = "ethereum")]
 pub mod ethereum;
+#[cfg(feature = "events")]
+pub mod events;
+pub mod explorer;
+pub mod identity;
+pub mod ids;
+pub mod interceptor;
+pub mod keygen;
+pub mod latency;
+pub mod maintenance;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod middleware;
+pub mod pagination;
+pub mod policy_condition;
 pub mod privy_hpke;
+#[cfg(feature = "reports")]
+pub mod report;
+pub mod request_options;
+pub mod response;
+pub mod response_sink;
+pub mod retry;
+#[cfg(feature = "ethereum")]
+pub mod revert;
+pub mod rollout;
+#[cfg(any(feature = "ethereum", feature = "solana"))]
+pub mod safe_send;
+pub mod send;
+pub mod sensitive;
+pub mod service_status;
+pub mod signature;
+#[cfg(feature = "solana")]
 pub mod solana;
+pub mod telemetry;
+pub mod transport;
+pub mod usage;
+pub mod wallet_hooks;
+#[cfg(feature = "webhooks")]
+pub mod webhooks;
 
 /// Generated types from privy's openapi spec
 pub mod generated {
@@ -21,6 +66,31 @@ pub mod subclients;
 #[cfg(feature = "alloy")]
 pub mod alloy;
 
+#[cfg(feature = "ens")]
+pub mod ens;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
+
+#[cfg(feature = "sns")]
+pub mod sns;
+
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+
+#[cfg(feature = "strict-validation")]
+pub mod validation;
+
+#[cfg(feature = "vcr")]
+pub mod vcr;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
+
+pub(crate) mod dry_run;
 pub(crate) mod errors;
 pub(crate) mod import;
 pub(crate) mod jwt_exchange;
@@ -29,9 +99,15 @@ pub(crate) mod utils;
 
 pub use client::PrivyClient;
 pub use errors::*;
+#[cfg(feature = "ethereum")]
 pub use ethereum::SendTransactionOptions;
+pub use ids::{KeyQuorumId, PolicyId, UserId, WalletId};
 pub use keys::*;
+pub use policy_condition::PolicyConditionExt;
 pub use privy_hpke::PrivyHpke;
+pub use response::ResponseValueExt;
+pub use sensitive::{SecretBytes, SecretString, Sensitive};
+#[cfg(feature = "solana")]
 pub use solana::SignAndSendTransactionOptions;
 pub use utils::{
     Method, Utils, WalletApiRequestSignatureInput, format_request_for_authorization_signature,
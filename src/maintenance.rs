@@ -0,0 +1,259 @@
+//! Background upkeep for a `PrivyClient`.
+//!
+//! The client never spawns tasks on its own. Applications that want its
+//! caches and health state kept fresh call
+//! [`PrivyClient::spawn_maintenance`] once, on a runtime of their
+//! choosing, and hold on to the returned [`BackgroundTasks`] for as long
+//! as the loops should run. The loops are:
+//!
+//! - evicting expired authorization keys from the JWT exchange cache, so
+//!   the cache does not hold on to keys that can no longer be used
+//! - health checking the pinned addresses of the client's
+//!   [`PinnedResolver`](crate::dns::PinnedResolver), if it has one
+//! - polling Privy's status page, if the client has
+//!   `PrivyClientOptions::service_status` set, see the
+//!   [`service_status`](crate::service_status) module
+//!
+//! ```no_run
+//! # use privy_rs::PrivyClient;
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let tasks = client.spawn_maintenance(tokio::runtime::Handle::current());
+//!
+//! // ... use the client ...
+//!
+//! tasks.shutdown().await;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The loops also stop once the client and all its clones are dropped, or
+//! when [`PrivyClient::close`] is called, so processes that create many
+//! short-lived clients, such as one per tenant, do not pile up loops, nor
+//! the connections and caches the loops hold on to.
+
+use std::{future::Future, sync::Arc, time::Duration};
+
+use tokio::{runtime::Handle, sync::watch, task::JoinHandle};
+
+use crate::PrivyClient;
+
+/// How often expired keys are evicted from the JWT exchange cache.
+const JWT_EVICTION_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How often pinned addresses are health checked.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The maintenance loops of a client, see the [module docs](self).
+///
+/// Dropping this stops the loops the next time they wake up. Use
+/// [`shutdown`](Self::shutdown) to also wait for them to finish.
+#[derive(Debug)]
+pub struct BackgroundTasks {
+    shutdown: watch::Sender<()>,
+    closed: Option<watch::Receiver<()>>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+/// Tells the maintenance loops of a client when it is closed, or when the
+/// client and all its clones are dropped.
+#[derive(Clone, Debug)]
+pub(crate) struct Lifetime(Arc<watch::Sender<()>>);
+
+impl Default for Lifetime {
+    fn default() -> Self {
+        Self(Arc::new(watch::channel(()).0))
+    }
+}
+
+impl Lifetime {
+    fn close(&self) {
+        self.0.send_replace(());
+    }
+}
+
+impl BackgroundTasks {
+    pub(crate) fn new() -> Self {
+        Self {
+            shutdown: watch::channel(()).0,
+            closed: None,
+            handles: Vec::new(),
+        }
+    }
+
+    /// Tasks that also stop when the client of `lifetime` is closed.
+    pub(crate) fn of_client(lifetime: &Lifetime) -> Self {
+        Self {
+            closed: Some(lifetime.0.subscribe()),
+            ..Self::new()
+        }
+    }
+
+    /// Stop the loops and wait for them to finish. A loop that is in the
+    /// middle of an iteration finishes it first.
+    pub async fn shutdown(self) {
+        let Self {
+            shutdown, handles, ..
+        } = self;
+        drop(shutdown);
+
+        for handle in handles {
+            if let Err(e) = handle.await {
+                tracing::warn!("background task failed: {e}");
+            }
+        }
+    }
+
+    /// Spawn `f` to run every `interval` until shutdown, or until the
+    /// client is closed for tasks of a client.
+    pub(crate) fn spawn<F, Fut>(
+        &mut self,
+        runtime: &Handle,
+        name: &'static str,
+        interval: Duration,
+        mut f: F,
+    ) where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        let mut shutdown = self.shutdown.subscribe();
+        let closed = self.closed.clone();
+        self.handles.push(runtime.spawn(async move {
+            // resolves once the client is closed, or once it is dropped
+            let closed = async move {
+                match closed {
+                    Some(mut closed) => {
+                        let _ = closed.changed().await;
+                    }
+                    None => std::future::pending().await,
+                }
+            };
+            tokio::pin!(closed);
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => f().await,
+                    // resolves with an error once the sender is dropped
+                    _ = shutdown.changed() => break,
+                    _ = &mut closed => break,
+                }
+            }
+            tracing::debug!(name, "background task stopped");
+        }));
+    }
+}
+
+impl PrivyClient {
+    /// Spawn the client's maintenance loops on `runtime`. See the
+    /// [`maintenance`](crate::maintenance) module for what they do.
+    ///
+    /// Clones of the client share their caches, so one call covers all of
+    /// them.
+    pub fn spawn_maintenance(&self, runtime: Handle) -> BackgroundTasks {
+        let mut tasks = BackgroundTasks::of_client(&self.lifetime);
+
+        let jwt_exchange = self.jwt_exchange.clone();
+        tasks.spawn(&runtime, "jwt eviction", JWT_EVICTION_INTERVAL, move || {
+            let evicted = jwt_exchange.evict_expired();
+            if evicted > 0 {
+                tracing::debug!(evicted, "evicted expired authorization keys");
+            }
+            std::future::ready(())
+        });
+
+        if let Some(resolver) = self.resolver.clone() {
+            tasks.spawn(
+                &runtime,
+                "health checks",
+                HEALTH_CHECK_INTERVAL,
+                move || {
+                    let resolver = resolver.clone();
+                    async move { resolver.check_health().await }
+                },
+            );
+        }
+
+        if let Some(interval) = self.shared.service_status.interval() {
+            let service_status = self.shared.service_status.clone();
+            tasks.spawn(&runtime, "service status", interval, move || {
+                let service_status = service_status.clone();
+                async move { service_status.refresh().await }
+            });
+        }
+
+        tasks
+    }
+
+    /// Close the client: stop the maintenance loops spawned from it or any
+    /// of its clones, and drop the authorization keys they cached.
+    ///
+    /// Dropping the client and all its clones stops the loops too; `close`
+    /// is for when clones, such as those held by subclients or
+    /// authorization contexts, may outlive it. Those clones can still make
+    /// calls, exchanging JWTs for keys again as needed. The client's
+    /// pooled connections are closed once the last clone, and the last
+    /// subclient, is dropped.
+    pub fn close(self) {
+        self.lifetime.close();
+        let cleared = self.jwt_exchange.clear();
+        tracing::debug!(cleared, "closed client");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::PrivyClientOptions, dns::PinnedResolver};
+
+    #[tokio::test]
+    async fn test_shutdown_stops_all_loops() {
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                resolver: Some(PinnedResolver::new()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let tasks = client.spawn_maintenance(Handle::current());
+        assert_eq!(tasks.handles.len(), 2);
+
+        tokio::time::timeout(Duration::from_secs(5), tasks.shutdown())
+            .await
+            .expect("loops stop promptly");
+    }
+
+    #[tokio::test]
+    async fn test_loops_stop_when_the_client_goes_away() {
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions::default(),
+        )
+        .unwrap();
+
+        // closed, while a clone is still around
+        let clone = client.clone();
+        let tasks = client.spawn_maintenance(Handle::current());
+        client.close();
+        for handle in tasks.handles {
+            tokio::time::timeout(Duration::from_secs(5), handle)
+                .await
+                .expect("loops stop promptly")
+                .unwrap();
+        }
+
+        // dropped, with the tasks still held
+        let tasks = clone.spawn_maintenance(Handle::current());
+        drop(clone);
+        for handle in tasks.handles {
+            tokio::time::timeout(Duration::from_secs(5), handle)
+                .await
+                .expect("loops stop promptly")
+                .unwrap();
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Metrics for API calls, signing and HPKE, recorded through the
+//! [`metrics`](https://docs.rs/metrics) crate.
+//!
+//! With the `metrics` feature, the SDK records these to whichever recorder
+//! the application installed, such as `metrics-exporter-prometheus`:
+//!
+//! | Metric | Kind | Labels |
+//! |--------|------|--------|
+//! | `privy_requests_total` | counter | `operation`, `status` |
+//! | `privy_request_errors_total` | counter | `operation`, `status` |
+//! | `privy_request_duration_seconds` | histogram | `operation` |
+//! | `privy_signing_duration_seconds` | histogram | `signer`, `outcome` |
+//! | `privy_hpke_decrypt_duration_seconds` | histogram | `outcome` |
+//!
+//! `operation` is the operation of an API call as named in the API spec,
+//! such as `wallet_rpc`, and `status` the HTTP status it was answered with,
+//! or `none` if it failed before one, such as on a network failure. A call
+//! is counted once, however often it was retried. `signer` is the Rust type
+//! of a signer of an `AuthorizationContext`, and `outcome` is `ok` or
+//! `error`.
+//!
+//! ```ignore
+//! let handle = metrics_exporter_prometheus::PrometheusBuilder::new().install_recorder()?;
+//! privy_rs::metrics::describe();
+//! // serve `handle.render()` on the scrape endpoint
+//! ```
+//!
+//! Without a recorder installed, recording does nothing.
+
+use std::time::Duration;
+
+use metrics::{Unit, counter, describe_counter, describe_histogram, histogram};
+
+const REQUESTS: &str = "privy_requests_total";
+const REQUEST_ERRORS: &str = "privy_request_errors_total";
+const REQUEST_DURATION: &str = "privy_request_duration_seconds";
+const SIGNING_DURATION: &str = "privy_signing_duration_seconds";
+const HPKE_DECRYPT_DURATION: &str = "privy_hpke_decrypt_duration_seconds";
+
+/// Describe the SDK's metrics to the installed recorder, so that exporters
+/// that support it, such as Prometheus, show their units and help texts.
+/// Call it once after installing the recorder.
+pub fn describe() {
+    describe_counter!(REQUESTS, Unit::Count, "Calls to the Privy API");
+    describe_counter!(
+        REQUEST_ERRORS,
+        Unit::Count,
+        "Calls to the Privy API that failed"
+    );
+    describe_histogram!(
+        REQUEST_DURATION,
+        Unit::Seconds,
+        "How long calls to the Privy API took, retries included"
+    );
+    describe_histogram!(
+        SIGNING_DURATION,
+        Unit::Seconds,
+        "How long signing a request with an authorization key took"
+    );
+    describe_histogram!(
+        HPKE_DECRYPT_DURATION,
+        Unit::Seconds,
+        "How long decrypting an HPKE encrypted key or wallet took"
+    );
+}
+
+fn outcome(ok: bool) -> &'static str {
+    if ok { "ok" } else { "error" }
+}
+
+/// Record a call to `operation`, answered with `status`.
+pub(crate) fn request(operation: &'static str, status: Option<u16>, ok: bool, elapsed: Duration) {
+    let status = status.map_or_else(|| "none".to_string(), |status| status.to_string());
+    counter!(REQUESTS, "operation" => operation, "status" => status.clone()).increment(1);
+    if !ok {
+        counter!(REQUEST_ERRORS, "operation" => operation, "status" => status).increment(1);
+    }
+    histogram!(REQUEST_DURATION, "operation" => operation).record(elapsed);
+}
+
+/// Record a signature by a signer of type `signer`.
+pub(crate) fn signature(signer: &'static str, ok: bool, elapsed: Duration) {
+    histogram!(SIGNING_DURATION, "signer" => signer, "outcome" => outcome(ok)).record(elapsed);
+}
+
+/// Record an HPKE decryption.
+pub(crate) fn hpke_decrypt(ok: bool, elapsed: Duration) {
+    histogram!(HPKE_DECRYPT_DURATION, "outcome" => outcome(ok)).record(elapsed);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use httpmock::prelude::*;
+    use metrics::{
+        Counter, CounterFn, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder,
+        SharedString,
+    };
+
+    use crate::{PrivyClient, client::PrivyClientOptions};
+
+    /// Records every increment and observation as the metric's key.
+    #[derive(Default)]
+    struct TestRecorder(Arc<Mutex<Vec<String>>>);
+
+    struct Handle(String, Arc<Mutex<Vec<String>>>);
+
+    impl CounterFn for Handle {
+        fn increment(&self, _: u64) {
+            self.1.lock().unwrap().push(self.0.clone());
+        }
+
+        fn absolute(&self, _: u64) {}
+    }
+
+    impl HistogramFn for Handle {
+        fn record(&self, _: f64) {
+            self.1.lock().unwrap().push(self.0.clone());
+        }
+    }
+
+    impl TestRecorder {
+        fn handle(&self, key: &Key) -> Arc<Handle> {
+            let labels: Vec<_> = key
+                .labels()
+                .map(|label| format!("{}={}", label.key(), label.value()))
+                .collect();
+            let key = format!("{}{{{}}}", key.name(), labels.join(","));
+            Arc::new(Handle(key, self.0.clone()))
+        }
+    }
+
+    impl Recorder for TestRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<metrics::Unit>, _: SharedString) {}
+        fn describe_gauge(&self, _: KeyName, _: Option<metrics::Unit>, _: SharedString) {}
+        fn describe_histogram(&self, _: KeyName, _: Option<metrics::Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            Counter::from_arc(self.handle(key))
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::from_arc(self.handle(key))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_requests_and_errors_are_counted_by_status() {
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/wallets/missing");
+                then.status(404);
+            })
+            .await;
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let recorder = TestRecorder::default();
+        let recorded = recorder.0.clone();
+        // the test runtime is single threaded, so the recorder is used
+        // across the await
+        let _guard = metrics::set_default_local_recorder(&recorder);
+        let _ = client.wallets().get("missing").await;
+        let _ = crate::PrivyHpke::new().decrypt_p256("not base64", "!!");
+
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(
+            *recorded,
+            [
+                "privy_requests_total{operation=get_wallet,status=404}",
+                "privy_request_errors_total{operation=get_wallet,status=404}",
+                "privy_request_duration_seconds{operation=get_wallet}",
+                "privy_hpke_decrypt_duration_seconds{outcome=error}",
+            ]
+        );
+    }
+}
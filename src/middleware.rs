@@ -0,0 +1,300 @@
+//! Hooks around every request the client sends.
+//!
+//! A [`RequestHook`] registered with [`PrivyClientOptions::with_middleware`]
+//! sees every request of every subclient before it is sent, and its
+//! response after, which makes it the one place for audit logging, request
+//! accounting and headers of its own, like a trace id:
+//!
+//! ```no_run
+//! # use privy_rs::{
+//! #     PrivyClient,
+//! #     client::PrivyClientOptions,
+//! #     middleware::{RequestHook, RequestInfo, ResponseInfo},
+//! # };
+//! struct AuditLog;
+//!
+//! impl RequestHook for AuditLog {
+//!     fn on_request(
+//!         &self,
+//!         _request: &RequestInfo,
+//!         outgoing: &mut reqwest::Request,
+//!     ) -> Result<(), String> {
+//!         outgoing
+//!             .headers_mut()
+//!             .insert("x-trace-id", reqwest::header::HeaderValue::from_static("trace_1"));
+//!         Ok(())
+//!     }
+//!
+//!     fn on_response(&self, request: &RequestInfo, response: &ResponseInfo) {
+//!         println!(
+//!             "{} {} -> {:?} in {:?} (request id {:?})",
+//!             request.method, request.path, response.status, response.elapsed, response.request_id
+//!         );
+//!     }
+//! }
+//!
+//! # fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_with_options(
+//!     "app_id".into(),
+//!     "app_secret".into(),
+//!     PrivyClientOptions::default().with_middleware(AuditLog),
+//! )?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Hooks run in the order they were registered, around every attempt of a
+//! request, so a request retried under a `RetryPolicy` is seen once per
+//! attempt. A hook can stop a request from being sent by returning an error
+//! from [`RequestHook::on_request`], which fails the call with
+//! `PrivyApiError::Custom`; the first hook to do so stops the others from
+//! seeing it. Every hook still sees the stopped request in
+//! [`RequestHook::on_response`], without a status.
+//!
+//! Hooks get the request as it is about to be sent, after it was signed, so
+//! changing its body or the `privy-` headers a signature covers makes the
+//! API reject it. Transactions are changed before signing by a
+//! `TransactionInterceptor` instead.
+
+use std::{fmt, sync::Arc, time::Duration};
+
+use crate::{PrivyApiError, PrivyApiErrorExt, errors::request_id, generated::ResponseValue};
+
+tokio::task_local! {
+    /// The request a subclient call is making, for the hooks the generated
+    /// client runs before sending it.
+    static CURRENT_REQUEST: RequestInfo;
+}
+
+/// Make `call`, a call of the generated client, as `request`, so that its
+/// hooks see which request it is.
+pub(crate) async fn scope<F: Future>(request: RequestInfo, call: F) -> F::Output {
+    CURRENT_REQUEST.scope(request, call).await
+}
+
+/// A request as seen by a [`RequestHook`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RequestInfo {
+    /// The operation, as named in the API spec, such as `wallet_rpc`.
+    pub operation: &'static str,
+    /// The HTTP method, such as `POST`.
+    pub method: &'static str,
+    /// The path of the endpoint, with its parameters unfilled, such as
+    /// `/v1/wallets/{wallet_id}/rpc`.
+    pub path: &'static str,
+}
+
+/// The response to a request, as seen by a [`RequestHook`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ResponseInfo {
+    /// The status code of the response, or `None` if there was no response,
+    /// such as when the API could not be reached.
+    pub status: Option<reqwest::StatusCode>,
+    /// The id of the request, if the API provided one.
+    pub request_id: Option<String>,
+    /// How long the request took.
+    pub elapsed: Duration,
+}
+
+/// A hook around every request a client sends. See the
+/// [module docs](self) for details.
+///
+/// Both methods run on the request path, so they must return quickly,
+/// handing slow work such as writing to an audit store off elsewhere.
+pub trait RequestHook {
+    /// See `request` before it is sent, and change what is sent through
+    /// `outgoing`, such as its headers. Returning an error stops it from
+    /// being sent, failing the call with the error as its reason.
+    fn on_request(
+        &self,
+        request: &RequestInfo,
+        outgoing: &mut reqwest::Request,
+    ) -> Result<(), String> {
+        let _ = (request, outgoing);
+        Ok(())
+    }
+
+    /// See the response to `request`, whether it succeeded or not.
+    fn on_response(&self, request: &RequestInfo, response: &ResponseInfo) {
+        let _ = (request, response);
+    }
+}
+
+/// The request hooks registered with a client, see
+/// `PrivyClientOptions::with_middleware`.
+#[derive(Clone, Default)]
+pub struct Middleware(Vec<Arc<dyn RequestHook + Send + Sync>>);
+
+impl fmt::Debug for Middleware {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Middleware").field(&self.0.len()).finish()
+    }
+}
+
+impl Middleware {
+    pub(crate) fn push(&mut self, hook: impl RequestHook + Send + Sync + 'static) {
+        self.0.push(Arc::new(hook));
+    }
+
+    /// The hook the generated client runs just before sending a request,
+    /// see `scripts/add-request-hook.sh`, running every hook of this.
+    pub(crate) fn request_hook(&self) -> crate::generated::RequestHook {
+        self.request_hook_with(reqwest::header::HeaderMap::new())
+    }
+
+    /// Like `request_hook`, adding `headers` to every request before the
+    /// hooks see it, see the `with_request_options` of the subclients.
+    pub(crate) fn request_hook_with(
+        &self,
+        headers: reqwest::header::HeaderMap,
+    ) -> crate::generated::RequestHook {
+        if self.0.is_empty() && headers.is_empty() {
+            return crate::generated::RequestHook::default();
+        }
+        let middleware = self.clone();
+        crate::generated::RequestHook::new(move |outgoing| {
+            for (name, value) in &headers {
+                outgoing.headers_mut().insert(name.clone(), value.clone());
+            }
+            middleware.prepare(outgoing)
+        })
+    }
+
+    /// Run every hook on `outgoing`, the request of the subclient call in
+    /// progress, stopping at the first that rejects it. Requests made
+    /// outside of a subclient call are not seen by hooks.
+    fn prepare(&self, outgoing: &mut reqwest::Request) -> Result<(), String> {
+        let Ok(request) = CURRENT_REQUEST.try_with(RequestInfo::clone) else {
+            return Ok(());
+        };
+
+        for hook in &self.0 {
+            hook.on_request(&request, outgoing).map_err(|reason| {
+                format!(
+                    "{} was stopped by a request hook: {reason}",
+                    request.operation
+                )
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Hand the response to `request`, which took `elapsed`, to every hook.
+    pub(crate) fn after<T>(
+        &self,
+        request: &RequestInfo,
+        elapsed: Duration,
+        result: &Result<ResponseValue<T>, PrivyApiError>,
+    ) {
+        if self.0.is_empty() {
+            return;
+        }
+
+        let (status, request_id) = match result {
+            Ok(response) => (Some(response.status()), request_id(response.headers())),
+            Err(error) => match error.details() {
+                Some(details) => (Some(details.status), details.request_id.clone()),
+                None => (error.status(), None),
+            },
+        };
+        let response = ResponseInfo {
+            status,
+            request_id,
+            elapsed,
+        };
+        for hook in &self.0 {
+            hook.on_response(request, &response);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use httpmock::prelude::*;
+
+    use super::*;
+    use crate::{PrivyClient, UserId, client::PrivyClientOptions};
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl RequestHook for Recorder {
+        fn on_request(
+            &self,
+            request: &RequestInfo,
+            outgoing: &mut reqwest::Request,
+        ) -> Result<(), String> {
+            let mut seen = self.0.lock().unwrap();
+            seen.push(format!("{} {}", request.method, request.path));
+            if request.method == "DELETE" {
+                return Err("deletes are not allowed".to_string());
+            }
+            outgoing.headers_mut().insert(
+                "x-audit-id",
+                reqwest::header::HeaderValue::from_static("audit_1"),
+            );
+            Ok(())
+        }
+
+        fn on_response(&self, request: &RequestInfo, response: &ResponseInfo) {
+            let mut seen = self.0.lock().unwrap();
+            seen.push(format!(
+                "{} {:?} {:?}",
+                request.operation, response.status, response.request_id
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hooks_see_every_request_and_can_change_or_stop_them() {
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.path_prefix("/v1/wallets")
+                    .header("x-audit-id", "audit_1");
+                then.status(404).header("privy-request-id", "req_1");
+            })
+            .await;
+
+        let recorder = Recorder::default();
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            }
+            .with_middleware(recorder.clone()),
+        )
+        .unwrap();
+
+        client.wallets().get("wallet_id").await.unwrap_err();
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [
+                "GET /v1/wallets/{wallet_id}",
+                "get_wallet Some(404) Some(\"req_1\")"
+            ]
+        );
+
+        recorder.0.lock().unwrap().clear();
+        let error = client
+            .users()
+            .delete(UserId::new("user_id"))
+            .await
+            .unwrap_err();
+        assert!(
+            error.to_string().contains("deletes are not allowed"),
+            "{error}"
+        );
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            ["DELETE /v1/users/{user_id}", "delete_user None None"]
+        );
+        assert_eq!(mock.calls_async().await, 1);
+    }
+}
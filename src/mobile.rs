@@ -0,0 +1,229 @@
+//! UniFFI bindings for Kotlin and Swift.
+//!
+//! This module wraps the core client, a handful of typed wallet operations
+//! and HPKE decryption in an interface that UniFFI can export, so that
+//! server components written in Kotlin (including KMP) or Swift can use the
+//! SDK directly. Build the crate as a C library and generate bindings with
+//! the bundled `uniffi-bindgen` binary:
+//!
+//! ```bash
+//! cargo rustc --release --lib --features uniffi --crate-type cdylib
+//! cargo run --features uniffi --bin uniffi-bindgen -- generate \
+//!     --library target/release/libprivy_rs.so --language kotlin --out-dir out
+//! ```
+//!
+//! Async methods are driven by tokio and surface as `suspend` functions in
+//! Kotlin and `async` functions in Swift.
+
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    AuthorizationContext, PrivateKey, PrivyClient, PrivyHpke, WalletId,
+    generated::types::WalletRpcResponse,
+};
+
+/// Errors surfaced to foreign callers. Only the message is carried across
+/// the boundary, since the underlying error types are not exported.
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum MobileError {
+    /// Creating the client failed.
+    #[error("{0}")]
+    Client(String),
+    /// The Privy API returned an error or could not be reached.
+    #[error("{0}")]
+    Api(String),
+    /// A key could not be loaded or used.
+    #[error("{0}")]
+    Key(String),
+    /// The API returned a response of an unexpected shape.
+    #[error("{0}")]
+    UnexpectedResponse(String),
+}
+
+/// A wallet, with the fields most commonly needed by mobile backends.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MobileWallet {
+    /// The ID of the wallet.
+    pub id: String,
+    /// The on-chain address of the wallet.
+    pub address: String,
+    /// The chain type of the wallet, e.g. `ethereum` or `solana`.
+    pub chain_type: String,
+    /// The key quorum that owns the wallet, if any.
+    pub owner_id: Option<String>,
+    /// The policies applied to the wallet.
+    pub policy_ids: Vec<String>,
+    /// When the wallet was created, as a unix timestamp.
+    pub created_at: f64,
+}
+
+/// The set of keys used to authorize wallet operations.
+///
+/// Contexts are immutable; `push_private_key` returns a new context.
+#[derive(uniffi::Object)]
+pub struct MobileAuthorizationContext(AuthorizationContext);
+
+#[uniffi::export]
+impl MobileAuthorizationContext {
+    /// Create an empty authorization context.
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self(AuthorizationContext::new()))
+    }
+
+    /// Return a new context that also signs with the given PEM-encoded
+    /// P-256 private key.
+    pub fn push_private_key(&self, pem: String) -> Arc<Self> {
+        Arc::new(Self(self.0.clone().push(PrivateKey::new(pem))))
+    }
+}
+
+/// The client used to interact with the Privy API.
+#[derive(uniffi::Object)]
+pub struct MobileClient(PrivyClient);
+
+#[uniffi::export(async_runtime = "tokio")]
+impl MobileClient {
+    /// Create a new client for the given app.
+    #[uniffi::constructor]
+    pub fn new(app_id: String, app_secret: String) -> Result<Arc<Self>, MobileError> {
+        PrivyClient::new(app_id, app_secret)
+            .map(|client| Arc::new(Self(client)))
+            .map_err(|e| MobileError::Client(e.to_string()))
+    }
+
+    /// Fetch a wallet by ID.
+    pub async fn get_wallet(&self, wallet_id: String) -> Result<MobileWallet, MobileError> {
+        let wallet = self
+            .0
+            .wallets()
+            .get(&wallet_id)
+            .await
+            .map_err(|e| MobileError::Api(e.to_string()))?
+            .into_inner();
+
+        Ok(MobileWallet {
+            id: wallet.id,
+            address: wallet.address,
+            chain_type: wallet.chain_type.to_string(),
+            owner_id: wallet.owner_id,
+            policy_ids: wallet.policy_ids,
+            created_at: wallet.created_at,
+        })
+    }
+
+    /// Sign a UTF-8 message with an Ethereum wallet using `personal_sign`,
+    /// returning the hex-encoded signature.
+    pub async fn ethereum_sign_message(
+        &self,
+        wallet_id: String,
+        message: String,
+        ctx: Arc<MobileAuthorizationContext>,
+    ) -> Result<String, MobileError> {
+        let response = self
+            .0
+            .wallets()
+            .ethereum()
+            .sign_message(WalletId::new(wallet_id), &message, &ctx.0, None)
+            .await
+            .map_err(|e| MobileError::Api(e.to_string()))?;
+
+        match response.into_inner() {
+            WalletRpcResponse::EthereumPersonalSignRpcResponse(r) => Ok(r.data.signature),
+            other => Err(MobileError::UnexpectedResponse(format!("{other:?}"))),
+        }
+    }
+
+    /// Sign a base64-encoded message with a Solana wallet, returning the
+    /// base64-encoded signature.
+    pub async fn solana_sign_message(
+        &self,
+        wallet_id: String,
+        message: String,
+        ctx: Arc<MobileAuthorizationContext>,
+    ) -> Result<String, MobileError> {
+        let response = self
+            .0
+            .wallets()
+            .solana()
+            .sign_message(WalletId::new(wallet_id), &message, &ctx.0, None)
+            .await
+            .map_err(|e| MobileError::Api(e.to_string()))?;
+
+        match response.into_inner() {
+            WalletRpcResponse::SolanaSignMessageRpcResponse(r) => Ok(r.data.signature),
+            other => Err(MobileError::UnexpectedResponse(format!("{other:?}"))),
+        }
+    }
+}
+
+/// An ephemeral HPKE keypair for receiving encrypted keys from Privy, such
+/// as exported wallet keys or authorization keys.
+///
+/// The keypair can only be used to decrypt a single payload.
+#[derive(uniffi::Object)]
+pub struct MobileHpke {
+    hpke: Mutex<Option<PrivyHpke>>,
+    public_key: String,
+}
+
+#[uniffi::export]
+impl MobileHpke {
+    /// Generate a new keypair.
+    #[uniffi::constructor]
+    pub fn new() -> Result<Arc<Self>, MobileError> {
+        let hpke = PrivyHpke::new();
+        let public_key = hpke
+            .public_key()
+            .map_err(|e| MobileError::Key(e.to_string()))?;
+
+        Ok(Arc::new(Self {
+            hpke: Mutex::new(Some(hpke)),
+            public_key,
+        }))
+    }
+
+    /// The base64-encoded SPKI public key to send to Privy as the
+    /// `recipient_public_key`.
+    pub fn public_key(&self) -> String {
+        self.public_key.clone()
+    }
+
+    /// Decrypt a payload encrypted to this keypair, given the base64-encoded
+    /// encapsulated key and ciphertext from the API response.
+    pub fn decrypt(
+        &self,
+        encapsulated_key: String,
+        ciphertext: String,
+    ) -> Result<Vec<u8>, MobileError> {
+        let hpke = self
+            .hpke
+            .lock()
+            .expect("lock poisoned")
+            .take()
+            .ok_or_else(|| MobileError::Key("HPKE keypair has already been used".to_string()))?;
+
+        hpke.decrypt_raw(&encapsulated_key, &ciphertext)
+            .map(|plaintext| plaintext.expose().to_vec())
+            .map_err(|e| MobileError::Key(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hpke_keypair_is_single_use() {
+        let hpke = MobileHpke::new().unwrap();
+        assert!(!hpke.public_key().is_empty());
+
+        // garbage input still consumes the keypair
+        assert!(hpke.decrypt("AA==".into(), "AA==".into()).is_err());
+        assert!(matches!(
+            hpke.decrypt("AA==".into(), "AA==".into()),
+            Err(MobileError::Key(msg)) if msg.contains("already been used")
+        ));
+    }
+}
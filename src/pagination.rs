@@ -0,0 +1,177 @@
+//! Streams over the cursor-paginated lists of the API.
+//!
+//! The `list_all` methods, such as `WalletsClient::list_all` and
+//! `UsersClient::list_all`, return a [`Paginated`] stream, which fetches
+//! the first page when it is first polled, and each next page once the
+//! items of the one before were yielded, following its `next_cursor`.
+//! Stopping early, by dropping the stream or taking from it, fetches no
+//! further pages:
+//!
+//! ```no_run
+//! # use futures::StreamExt;
+//! # use privy_rs::PrivyClient;
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let users = client.users();
+//! let mut first_thousand = users.list_all().take(1000);
+//! while let Some(user) = first_thousand.next().await.transpose()? {
+//!     println!("{}", user.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A failed page is yielded as an error, after which the stream ends.
+
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    str::FromStr,
+    task::{Context, Poll},
+};
+
+use futures::{Stream, StreamExt, TryStreamExt, stream::BoxStream};
+
+use crate::{
+    PrivyApiError,
+    generated::{
+        Error, ResponseValue,
+        types::{GetUsersResponse, GetWalletsResponse, WalletTransactionsResponse},
+    },
+};
+
+/// A page of a cursor-paginated list.
+pub(crate) trait Page {
+    /// What the list is of.
+    type Item;
+
+    /// The items on the page, and the cursor of the next page.
+    fn into_parts(self) -> (Vec<Self::Item>, Option<String>);
+}
+
+impl Page for GetWalletsResponse {
+    type Item = crate::generated::types::Wallet;
+
+    fn into_parts(self) -> (Vec<Self::Item>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl Page for GetUsersResponse {
+    type Item = crate::generated::types::User;
+
+    fn into_parts(self) -> (Vec<Self::Item>, Option<String>) {
+        (self.data, self.next_cursor)
+    }
+}
+
+impl Page for WalletTransactionsResponse {
+    type Item = crate::generated::types::WalletTransactionsResponseTransactionsItem;
+
+    fn into_parts(self) -> (Vec<Self::Item>, Option<String>) {
+        (self.transactions, self.next_cursor)
+    }
+}
+
+/// The pages of a list, fetched with `fetch` as the stream is polled,
+/// starting at `cursor`, `None` for the first page. Each page comes with
+/// the cursor of the page after it, `None` on the last page.
+///
+/// This is the one place that follows cursors. Callers that work a page
+/// at a time, or resume from a cursor, use it directly, and everything
+/// else goes through `Paginated`.
+pub(crate) fn pages<'a, P, C, F, Fut>(
+    fetch: F,
+    cursor: Option<C>,
+) -> impl Stream<Item = Result<(Vec<P::Item>, Option<String>), PrivyApiError>> + 'a
+where
+    P: Page + 'a,
+    C: FromStr + 'a,
+    F: FnMut(Option<C>) -> Fut + 'a,
+    Fut: Future<Output = Result<ResponseValue<P>, PrivyApiError>> + 'a,
+{
+    // the cursor is `None` once the last page was fetched
+    let first: Option<Option<C>> = Some(cursor);
+    futures::stream::try_unfold((fetch, first), |(mut fetch, cursor)| async move {
+        let Some(cursor) = cursor else {
+            return Ok(None);
+        };
+        let (items, next_cursor) = fetch(cursor).await?.into_inner().into_parts();
+
+        let next_cursor = next_cursor.filter(|_| !items.is_empty());
+        let next = match &next_cursor {
+            Some(next) => {
+                Some(Some(next.parse().map_err(|_| {
+                    Error::InvalidRequest("Invalid cursor".to_string())
+                })?))
+            }
+            None => None,
+        };
+        Ok(Some(((items, next_cursor), (fetch, next))))
+    })
+}
+
+/// Every item of a paginated list, fetched page by page as the stream is
+/// polled. See the [module docs](self) for details.
+#[must_use = "streams do nothing unless polled"]
+pub struct Paginated<'a, T> {
+    items: BoxStream<'a, Result<T, PrivyApiError>>,
+}
+
+impl<'a, T: Send + 'a> Paginated<'a, T> {
+    /// Page through a list, fetching each page with `fetch`, passed the
+    /// cursor of the page, `None` for the first one.
+    pub(crate) fn new<P, C, F, Fut>(fetch: F) -> Self
+    where
+        P: Page<Item = T> + Send + 'a,
+        C: FromStr + Send + 'a,
+        F: FnMut(Option<C>) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<ResponseValue<P>, PrivyApiError>> + Send + 'a,
+    {
+        Self::from_pages(pages(fetch, None))
+    }
+
+    /// The items of the pages from `pages`.
+    pub(crate) fn from_pages(
+        pages: impl Stream<Item = Result<(Vec<T>, Option<String>), PrivyApiError>> + Send + 'a,
+    ) -> Self {
+        Self::from_stream(
+            pages
+                .map_ok(|(items, _)| futures::stream::iter(items.into_iter().map(Ok)))
+                .try_flatten(),
+        )
+    }
+
+    /// A list put together from other calls, for lists the API has no
+    /// endpoint for.
+    pub(crate) fn from_stream(
+        items: impl Stream<Item = Result<T, PrivyApiError>> + Send + 'a,
+    ) -> Self {
+        Self {
+            items: items.boxed(),
+        }
+    }
+
+    /// Stop after `limit` items, without fetching further pages.
+    pub(crate) fn limit(self, limit: Option<usize>) -> Self {
+        match limit {
+            Some(limit) => Self::from_stream(self.items.take(limit)),
+            None => self,
+        }
+    }
+}
+
+impl<T> Stream for Paginated<'_, T> {
+    type Item = Result<T, PrivyApiError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.items.poll_next_unpin(cx)
+    }
+}
+
+impl<T> fmt::Debug for Paginated<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Paginated").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,318 @@
+//! Policy conditions from JSON.
+//!
+//! Policies are often kept as JSON or YAML files, and applied by a
+//! pipeline. The generated [`PolicyCondition`] deserializes with serde,
+//! but as an untagged union of every kind of condition, so a typo only
+//! says that the condition matched none of them. [`PolicyConditionExt`]
+//! picks the kind of condition from its `field_source` first, and reports
+//! what is wrong with it:
+//!
+//! ```
+//! # use privy_rs::{PolicyConditionError, PolicyConditionExt};
+//! # use privy_rs::generated::types::PolicyCondition;
+//! # use serde_json::json;
+//! let condition = PolicyCondition::from_json_value(json!({
+//!     "field_source": "ethereum_transaction",
+//!     "field": "to",
+//!     "operator": "in",
+//!     "value": ["0x742d35Cc6634C0532925a3b844Bc454e4438f44e"],
+//! }))?;
+//!
+//! let error = PolicyCondition::from_json_value(json!({
+//!     "field_source": "ethereum_transaction",
+//!     "field": "to",
+//!     "operator": "gt",
+//!     "value": "0x742d35Cc6634C0532925a3b844Bc454e4438f44e",
+//! }))
+//! .unwrap_err();
+//! assert_eq!(error.to_string(), "Operator `gt` compares numbers, but `to` is not a number");
+//! # Ok::<(), PolicyConditionError>(())
+//! ```
+//!
+//! YAML files can be read into a `serde_json::Value` with any YAML crate
+//! that supports serde, and then converted the same way.
+//!
+//! A condition converted with [`PolicyConditionExt::to_json_value`] and
+//! back is the same condition.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{PolicyConditionError, generated::types::PolicyCondition};
+
+/// Every `field_source`, with the fields of that source the ordering
+/// operators apply to. `None` for sources whose fields are free-form, such
+/// as calldata arguments, which are not checked.
+const FIELD_SOURCES: &[(&str, Option<&[&str]>)] = &[
+    ("ethereum_transaction", Some(&["chain_id", "value"])),
+    ("ethereum_calldata", None),
+    ("ethereum_typed_data_domain", Some(&["chainId", "chain_id"])),
+    ("ethereum_typed_data_message", None),
+    ("ethereum_7702_authorization", Some(&[])),
+    (
+        "tempo_transaction",
+        Some(&["nonce_key", "valid_after", "valid_before"]),
+    ),
+    ("solana_program_instruction", Some(&[])),
+    (
+        "solana_system_program_instruction",
+        Some(&["Transfer.lamports"]),
+    ),
+    (
+        "solana_token_program_instruction",
+        Some(&[
+            "Burn.amount",
+            "MintTo.amount",
+            "Transfer.amount",
+            "TransferChecked.amount",
+        ]),
+    ),
+    ("system", Some(&["current_unix_timestamp"])),
+    (
+        "tron_transaction",
+        Some(&[
+            "TransferContract.amount",
+            "TriggerSmartContract.call_token_value",
+            "TriggerSmartContract.call_value",
+            "TriggerSmartContract.token_id",
+        ]),
+    ),
+    ("tron_trigger_smart_contract_data", None),
+    ("sui_transaction_command", Some(&[])),
+    ("sui_transfer_objects_command", Some(&["amount"])),
+    ("action_request_body", None),
+    ("reference", None),
+];
+
+/// Converting a `PolicyCondition` to and from JSON, see the
+/// [module docs](self).
+pub trait PolicyConditionExt: Sized {
+    /// Read a condition from JSON, checking that its `field_source` is
+    /// known and that its operator fits its field and value.
+    ///
+    /// # Errors
+    /// If `value` is not a valid condition, with the first problem found.
+    fn from_json_value(value: Value) -> Result<Self, PolicyConditionError>;
+
+    /// The condition as JSON, in the form the API accepts.
+    fn to_json_value(&self) -> Value;
+}
+
+impl PolicyConditionExt for PolicyCondition {
+    fn from_json_value(value: Value) -> Result<Self, PolicyConditionError> {
+        let Value::Object(object) = &value else {
+            return Err(PolicyConditionError::NotAnObject);
+        };
+        let field_source = required_str(object, "field_source")?.to_owned();
+        let Some((_, ordered_fields)) = FIELD_SOURCES
+            .iter()
+            .find(|(source, _)| *source == field_source)
+        else {
+            return Err(PolicyConditionError::UnknownFieldSource {
+                found: field_source,
+                expected: FIELD_SOURCES.iter().map(|(source, _)| *source).collect(),
+            });
+        };
+        check_operator(object, *ordered_fields)?;
+
+        match field_source.as_str() {
+            "ethereum_transaction" => {
+                parse(value, &field_source, Self::EthereumTransactionCondition)
+            }
+            "ethereum_calldata" => parse(value, &field_source, Self::EthereumCalldataCondition),
+            "ethereum_typed_data_domain" => {
+                parse(value, &field_source, Self::EthereumTypedDataDomainCondition)
+            }
+            "ethereum_typed_data_message" => parse(
+                value,
+                &field_source,
+                Self::EthereumTypedDataMessageCondition,
+            ),
+            "ethereum_7702_authorization" => parse(
+                value,
+                &field_source,
+                Self::Ethereum7702AuthorizationCondition,
+            ),
+            "tempo_transaction" => parse(value, &field_source, Self::TempoTransactionCondition),
+            "solana_program_instruction" => parse(
+                value,
+                &field_source,
+                Self::SolanaProgramInstructionCondition,
+            ),
+            "solana_system_program_instruction" => parse(
+                value,
+                &field_source,
+                Self::SolanaSystemProgramInstructionCondition,
+            ),
+            "solana_token_program_instruction" => parse(
+                value,
+                &field_source,
+                Self::SolanaTokenProgramInstructionCondition,
+            ),
+            "system" => parse(value, &field_source, Self::SystemCondition),
+            "tron_transaction" => parse(value, &field_source, Self::TronTransactionCondition),
+            "tron_trigger_smart_contract_data" => {
+                parse(value, &field_source, Self::TronCalldataCondition)
+            }
+            "sui_transaction_command" => {
+                parse(value, &field_source, Self::SuiTransactionCommandCondition)
+            }
+            "sui_transfer_objects_command" => parse(
+                value,
+                &field_source,
+                Self::SuiTransferObjectsCommandCondition,
+            ),
+            "action_request_body" => parse(value, &field_source, Self::ActionRequestBodyCondition),
+            "reference" => parse(value, &field_source, Self::AggregationCondition),
+            _ => unreachable!("every field source in FIELD_SOURCES is matched"),
+        }
+    }
+
+    fn to_json_value(&self) -> Value {
+        serde_json::to_value(self).expect("policy conditions serialize to JSON")
+    }
+}
+
+/// The string at `key` of `object`.
+fn required_str<'a>(
+    object: &'a serde_json::Map<String, Value>,
+    key: &'static str,
+) -> Result<&'a str, PolicyConditionError> {
+    match object.get(key) {
+        Some(Value::String(value)) => Ok(value),
+        Some(_) => Err(PolicyConditionError::NotAString(key)),
+        None => Err(PolicyConditionError::MissingField(key)),
+    }
+}
+
+/// Check that the operator of a condition fits its value, and, for sources
+/// with known fields, that ordering operators are only used on the fields
+/// in `ordered_fields`.
+fn check_operator(
+    object: &serde_json::Map<String, Value>,
+    ordered_fields: Option<&[&str]>,
+) -> Result<(), PolicyConditionError> {
+    let operator = required_str(object, "operator")?;
+    let field = required_str(object, "field")?;
+    let invalid = |reason: String| PolicyConditionError::InvalidOperator {
+        operator: operator.to_owned(),
+        reason,
+    };
+
+    match (operator, object.get("value")) {
+        ("in", Some(value)) if !value.is_array() => {
+            return Err(invalid(
+                "matches a list of values, but `value` is not a list".into(),
+            ));
+        }
+        ("eq" | "gt" | "gte" | "lt" | "lte" | "in_condition_set", Some(Value::Array(_))) => {
+            return Err(invalid(
+                "matches a single value, but `value` is a list".into(),
+            ));
+        }
+        _ => {}
+    }
+
+    let ordering = matches!(operator, "gt" | "gte" | "lt" | "lte");
+    match ordered_fields {
+        Some(fields) if ordering && !fields.contains(&field) => Err(invalid(format!(
+            "compares numbers, but `{field}` is not a number"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Deserialize `value` as the condition type of `field_source`.
+fn parse<T: DeserializeOwned>(
+    value: Value,
+    field_source: &str,
+    variant: fn(T) -> PolicyCondition,
+) -> Result<PolicyCondition, PolicyConditionError> {
+    serde_json::from_value(value)
+        .map(variant)
+        .map_err(|source| PolicyConditionError::Invalid {
+            field_source: field_source.to_owned(),
+            source,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_conditions_round_trip() {
+        let conditions = [
+            json!({
+                "field_source": "ethereum_transaction",
+                "field": "value",
+                "operator": "lte",
+                "value": "1000000000000000000",
+            }),
+            json!({
+                "field_source": "solana_system_program_instruction",
+                "field": "Transfer.to",
+                "operator": "in",
+                "value": ["9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"],
+            }),
+            json!({
+                "field_source": "action_request_body",
+                "field": "amount",
+                "operator": "gt",
+                "value": "10",
+            }),
+            json!({
+                "field_source": "sui_transaction_command",
+                "field": "commandName",
+                "operator": "eq",
+                "value": "TransferObjects",
+            }),
+        ];
+
+        for json in conditions {
+            let condition = PolicyCondition::from_json_value(json.clone()).unwrap();
+            assert_eq!(condition.to_json_value(), json);
+            let again = PolicyCondition::from_json_value(condition.to_json_value()).unwrap();
+            assert_eq!(again.to_json_value(), json);
+        }
+    }
+
+    #[test]
+    fn test_invalid_conditions_say_what_is_wrong() {
+        let error = |json| PolicyCondition::from_json_value(json).unwrap_err();
+
+        assert!(matches!(
+            error(json!({"field_source": "ethereum_tx", "field": "to", "operator": "eq", "value": "0x"})),
+            PolicyConditionError::UnknownFieldSource { found, expected }
+                if found == "ethereum_tx" && expected.contains(&"ethereum_transaction")
+        ));
+        assert!(matches!(
+            error(json!({"field": "to", "operator": "eq", "value": "0x"})),
+            PolicyConditionError::MissingField("field_source")
+        ));
+        assert!(matches!(
+            error(json!(["not", "an", "object"])),
+            PolicyConditionError::NotAnObject
+        ));
+        assert_eq!(
+            error(json!({"field_source": "system", "field": "current_unix_timestamp", "operator": "in", "value": "1"}))
+                .to_string(),
+            "Operator `in` matches a list of values, but `value` is not a list"
+        );
+
+        // the error of the condition type names the offending field
+        let unknown_field = error(json!({
+            "field_source": "ethereum_transaction",
+            "field": "gas",
+            "operator": "eq",
+            "value": "1",
+        }));
+        assert!(matches!(
+            &unknown_field,
+            PolicyConditionError::Invalid { field_source, .. } if field_source == "ethereum_transaction"
+        ));
+        assert!(unknown_field.to_string().contains("unknown variant `gas`"));
+    }
+}
@@ -12,7 +12,7 @@ use hpke::{
 use p256::{PublicKey, elliptic_curve::SecretKey, pkcs8::DecodePrivateKey};
 use spki::EncodePublicKey;
 
-use crate::KeyError;
+use crate::{KeyError, SecretBytes, SecretString};
 
 /// An ephemeral HPKE (Hybrid Public Key Encryption) manager for secure key exchange with the Privy API.
 ///
@@ -96,12 +96,17 @@ impl PrivyHpke {
         }
     }
 
-    /// Replace the cryptographic entropy source with a custom seed and a fast PRNG.
+    /// Replace the cryptographic entropy source with a custom seed and a fast PRNG,
+    /// so that the same seed always gives the same keypair.
+    ///
+    /// # Feature Flag
+    /// Requires the `testing` feature to be enabled.
     ///
     /// # Security
     /// This should only be used for testing purposes.
-    #[cfg(test)]
-    pub(crate) fn new_with_seed(seed: u64) -> Self {
+    #[cfg(any(test, feature = "testing"))]
+    #[must_use]
+    pub fn new_with_seed(seed: u64) -> Self {
         use hpke::rand_core::SeedableRng;
         let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
         let (private_key, public_key) = DhP256HkdfSha256::gen_keypair(&mut rng);
@@ -166,25 +171,16 @@ impl PrivyHpke {
     ) -> Result<SecretKey<p256::NistP256>, KeyError> {
         let decrypted_key_bytes = self.decrypt_raw(encapsulated_key, ciphertext)?;
         // Parse the decrypted bytes as a UTF-8 base64 DER string, then parse as a private key
-        let key_b64 =
-            zeroize::Zeroizing::new(String::from_utf8(decrypted_key_bytes.to_vec()).map_err(
-                |_| KeyError::InvalidFormat("decrypted key is not valid UTF-8".to_string()),
-            )?);
-
-        #[cfg(all(feature = "unsafe_debug", debug_assertions))]
-        {
-            // Safe truncation that won't panic on short strings or UTF-8 boundaries
-            let truncated_key: String = key_b64.chars().take(6).collect();
-            tracing::debug!(
-                "Decrypted authorization key (base64 DER): {}",
-                truncated_key
-            );
-        }
+        let key_b64 = SecretString::from(
+            String::from_utf8(decrypted_key_bytes.expose().to_vec()).map_err(|_| {
+                KeyError::InvalidFormat("decrypted key is not valid UTF-8".to_string())
+            })?,
+        );
 
         // Decode the base64 to get DER bytes
         let der_bytes = zeroize::Zeroizing::new(
             base64::engine::general_purpose::STANDARD
-                .decode(key_b64.as_str())
+                .decode(key_b64.expose().as_str())
                 .map_err(|_| {
                     KeyError::InvalidFormat("decrypted key is not valid base64".to_string())
                 })?,
@@ -252,7 +248,11 @@ impl PrivyHpke {
         self,
         encapsulated_key: &str,
         ciphertext: &str,
-    ) -> Result<zeroize::Zeroizing<Vec<u8>>, KeyError> {
+    ) -> Result<SecretBytes, KeyError> {
+        crate::telemetry::hpke_decrypt(move || self.open(encapsulated_key, ciphertext))
+    }
+
+    fn open(self, encapsulated_key: &str, ciphertext: &str) -> Result<SecretBytes, KeyError> {
         let encapped_key_bytes = base64::engine::general_purpose::STANDARD
             .decode(encapsulated_key)
             .map_err(|_| KeyError::InvalidFormat("base64 encapsulated key".to_string()))?;
@@ -281,9 +281,7 @@ impl PrivyHpke {
         )?;
 
         // Decrypt the authorization key using the ciphertext
-        Ok(zeroize::Zeroizing::new(
-            context.open(&ciphertext_bytes, &[])?,
-        ))
+        Ok(SecretBytes::from(context.open(&ciphertext_bytes, &[])?))
     }
 }
 
@@ -293,6 +291,103 @@ impl Default for PrivyHpke {
     }
 }
 
+/// Deterministic HPKE encryption, for mocking the API in tests.
+///
+/// Endpoints like wallet export and JWT authentication answer with a secret
+/// encrypted to the `recipient_public_key` of the request. A mock of them
+/// can [`seal`] its answer to that key, with a fixed seed so the response is
+/// the same from run to run:
+///
+/// ```
+/// use privy_rs::{PrivyHpke, privy_hpke::testing::seal};
+///
+/// let hpke = PrivyHpke::new_with_seed(1);
+/// let sealed = seal(&hpke.public_key()?, b"exported key", 2)?;
+/// assert_eq!(sealed, seal(&hpke.public_key()?, b"exported key", 2)?);
+///
+/// let opened = hpke.decrypt_raw(&sealed.encapsulated_key, &sealed.ciphertext)?;
+/// assert_eq!(opened.expose().as_slice(), b"exported key");
+/// # Ok::<(), privy_rs::KeyError>(())
+/// ```
+///
+/// # Feature Flag
+/// Requires the `testing` feature to be enabled.
+#[cfg(any(test, feature = "testing"))]
+pub mod testing {
+    use base64::{Engine, engine::general_purpose::STANDARD};
+    use hpke::{Kem, OpModeS, rand_core::SeedableRng};
+    use p256::{
+        elliptic_curve::{SecretKey, sec1::ToEncodedPoint},
+        pkcs8::EncodePrivateKey,
+    };
+    use spki::DecodePublicKey;
+
+    use super::*;
+
+    /// A secret encrypted with [`seal`], in the format of the API.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Sealed {
+        /// The base64 encoded HPKE encapsulated key.
+        pub encapsulated_key: String,
+        /// The base64 encoded ciphertext.
+        pub ciphertext: String,
+    }
+
+    /// Encrypt `plaintext` to `recipient_public_key`, a base64 encoded SPKI
+    /// as returned by `PrivyHpke::public_key`, as the API does. The same
+    /// `seed` always gives the same encapsulated key and ciphertext.
+    ///
+    /// # Errors
+    /// If `recipient_public_key` is not a P-256 SPKI.
+    pub fn seal(
+        recipient_public_key: &str,
+        plaintext: &[u8],
+        seed: u64,
+    ) -> Result<Sealed, KeyError> {
+        let der = STANDARD
+            .decode(recipient_public_key)
+            .map_err(|_| KeyError::InvalidFormat("base64 recipient public key".to_string()))?;
+        let recipient = PublicKey::from_public_key_der(&der)
+            .map_err(|_| KeyError::InvalidFormat("SPKI recipient public key".to_string()))?;
+        let recipient = <DhP256HkdfSha256 as Kem>::PublicKey::from_bytes(
+            recipient.to_encoded_point(false).as_bytes(),
+        )
+        .map_err(|_| KeyError::InvalidFormat("recipient public key".to_string()))?;
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+        let (encapsulated_key, mut context) = hpke::setup_sender::<
+            ChaCha20Poly1305,
+            HkdfSha256,
+            DhP256HkdfSha256,
+            _,
+        >(&OpModeS::Base, &recipient, &[], &mut rng)?;
+        let ciphertext = context.seal(plaintext, &[])?;
+
+        Ok(Sealed {
+            encapsulated_key: STANDARD.encode(encapsulated_key.to_bytes()),
+            ciphertext: STANDARD.encode(ciphertext),
+        })
+    }
+
+    /// Encrypt the authorization key `key` to `recipient_public_key` as the
+    /// JWT authentication endpoint does, to be read back by
+    /// `PrivyHpke::decrypt_p256`.
+    ///
+    /// # Errors
+    /// If `recipient_public_key` is not a P-256 SPKI.
+    pub fn seal_p256(
+        recipient_public_key: &str,
+        key: &SecretKey<p256::NistP256>,
+        seed: u64,
+    ) -> Result<Sealed, KeyError> {
+        let der = key
+            .to_pkcs8_der()
+            .map_err(|_| KeyError::InvalidFormat("PKCS#8 DER key".to_string()))?;
+        let plaintext = zeroize::Zeroizing::new(STANDARD.encode(der.as_bytes()));
+        seal(recipient_public_key, plaintext.as_bytes(), seed)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use spki::DecodePublicKey;
@@ -437,7 +532,7 @@ mod tests {
             .decrypt_raw(&encapped_key_b64, &ciphertext_b64)
             .expect("Failed to decrypt raw");
         assert_eq!(
-            &*decrypted_raw,
+            decrypted_raw.expose().as_slice(),
             test_key_b64.as_bytes(),
             "Decrypted raw bytes should match original"
         );
@@ -497,6 +592,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_seal_is_deterministic() {
+        let key = SecretKey::<p256::NistP256>::from_bytes(&[7; 32].into()).unwrap();
+        let recipient = PrivyHpke::new_with_seed(5).public_key().unwrap();
+
+        let sealed = super::testing::seal_p256(&recipient, &key, 9).unwrap();
+        assert_eq!(
+            sealed,
+            super::testing::seal_p256(&recipient, &key, 9).unwrap()
+        );
+        assert_ne!(
+            sealed,
+            super::testing::seal_p256(&recipient, &key, 10).unwrap()
+        );
+
+        let opened = PrivyHpke::new_with_seed(5)
+            .decrypt_p256(&sealed.encapsulated_key, &sealed.ciphertext)
+            .unwrap();
+        assert_eq!(opened, key);
+
+        assert!(super::testing::seal("not a key", b"", 9).is_err());
+    }
+
     #[test]
     fn test_hpke_decrypt_invalid_encapsulated_key() {
         let receiver = PrivyHpke::new_with_seed(200);
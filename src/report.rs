@@ -0,0 +1,497 @@
+//! Transaction reports for finance and accounting.
+//!
+//! `TransactionsClient::report` pages through the transaction history of a
+//! set of wallets and writes one [`ReportRow`] per transfer, as CSV or, with
+//! the `parquet` feature, as Parquet. Rows are written as each page arrives,
+//! so a report over many wallets never holds more than a page in memory:
+//!
+//! ```no_run
+//! # use privy_rs::{PrivyClient, WalletId, activity::ActivityPeriod, report::ReportFormat};
+//! # use privy_rs::generated::types::WalletTransactionsChain;
+//! # use std::time::Duration;
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let file = std::fs::File::create("transactions.csv")?;
+//!
+//! let rows = client
+//!     .transactions()
+//!     .report(
+//!         [
+//!             (WalletId::new("treasury_wallet_id"), WalletTransactionsChain::Base),
+//!             (WalletId::new("payouts_wallet_id"), WalletTransactionsChain::Solana),
+//!         ],
+//!         ActivityPeriod::last(Duration::from_secs(30 * 24 * 60 * 60)),
+//!         ReportFormat::Csv,
+//!         file,
+//!     )
+//!     .await?;
+//! println!("wrote {rows} transactions");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Amounts are reported twice: `raw_amount` in the asset's base units (wei,
+//! lamports, ...) exactly as Privy returns them, and `amount` in display
+//! units, normalized with the decimals Privy reports for the asset.
+//!
+//! # Feature Flag
+//! Requires the `reports` feature to be enabled.
+
+use std::io::Write;
+
+use serde::Serialize;
+
+use crate::{
+    activity::{ActivityPeriod, asset_name},
+    generated::types::{
+        WalletTransactionsResponseTransactionsItem,
+        WalletTransactionsResponseTransactionsItemDetails,
+    },
+};
+
+/// The file format of a transaction report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReportFormat {
+    /// Comma separated values, with a header row.
+    Csv,
+    /// Apache Parquet, with one row group per page of transactions.
+    ///
+    /// # Feature Flag
+    /// Requires the `parquet` feature to be enabled.
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+/// A single transfer in a transaction report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReportRow {
+    /// The wallet the transfer was made from or to.
+    pub wallet_id: String,
+    /// Privy's id for the transaction.
+    pub transaction_id: String,
+    /// When the transaction was created, in milliseconds since the unix epoch.
+    pub created_at: i64,
+    /// The status of the transaction, e.g. `confirmed`.
+    pub status: String,
+    /// The CAIP-2 identifier of the chain the transaction is on.
+    pub caip2: String,
+    /// The onchain hash, once the transaction has been broadcast.
+    pub transaction_hash: Option<String>,
+    /// `sent` or `received`, from the point of view of the wallet.
+    pub direction: &'static str,
+    /// The asset transferred, e.g. `eth` or `usdc`.
+    pub asset: String,
+    /// The amount in display units, e.g. `1.5` for 1.5 ETH. Empty if the
+    /// raw amount is not a non-negative integer.
+    pub amount: Option<String>,
+    /// The amount in the asset's base units, as returned by the API.
+    pub raw_amount: String,
+    /// The number of decimals used to normalize `raw_amount`.
+    pub decimals: u32,
+    /// The address the transfer was sent from.
+    pub sender: String,
+    /// The address the transfer was sent to.
+    pub recipient: String,
+}
+
+impl ReportRow {
+    /// The row for `transaction`, if it was created inside `period`.
+    pub(crate) fn new(
+        transaction: &WalletTransactionsResponseTransactionsItem,
+        period: &ActivityPeriod,
+    ) -> Option<Self> {
+        if !period.contains_millis(transaction.created_at) {
+            return None;
+        }
+
+        let (direction, asset, raw_value, decimals, sender, recipient) = match &transaction.details
+        {
+            WalletTransactionsResponseTransactionsItemDetails::Variant0 {
+                asset,
+                raw_value,
+                raw_value_decimals,
+                sender,
+                recipient,
+                ..
+            } => (
+                "sent",
+                asset_name(asset),
+                raw_value,
+                raw_value_decimals,
+                sender,
+                recipient,
+            ),
+            WalletTransactionsResponseTransactionsItemDetails::Variant1 {
+                asset,
+                raw_value,
+                raw_value_decimals,
+                sender,
+                recipient,
+                ..
+            } => (
+                "received",
+                asset_name(asset),
+                raw_value,
+                raw_value_decimals,
+                sender,
+                recipient,
+            ),
+        };
+        let decimals = *decimals as u32;
+
+        Some(Self {
+            wallet_id: transaction.wallet_id.clone(),
+            transaction_id: transaction.privy_transaction_id.clone(),
+            created_at: transaction.created_at as i64,
+            status: transaction.status.to_string(),
+            caip2: transaction.caip2.clone(),
+            transaction_hash: transaction.transaction_hash.clone(),
+            direction,
+            asset,
+            amount: normalize_amount(raw_value, decimals),
+            raw_amount: raw_value.clone(),
+            decimals,
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+        })
+    }
+}
+
+/// Shift the decimal point of the integer `raw` left by `decimals` places,
+/// dropping trailing zeros, so `normalize_amount("1500", 3)` is `1.5`.
+fn normalize_amount(raw: &str, decimals: u32) -> Option<String> {
+    if raw.is_empty() || !raw.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let decimals = decimals as usize;
+    let digits = raw.trim_start_matches('0');
+    let digits = format!("{digits:0>width$}", width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+
+    Some(if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{whole}.{fraction}")
+    })
+}
+
+/// A failure to write a report, kept apart from `ReportError` so that the
+/// writer does not carry the size of an API error around.
+#[derive(Debug)]
+pub(crate) enum WriteError {
+    Csv(csv::Error),
+    #[cfg(feature = "parquet")]
+    Parquet(parquet::errors::ParquetError),
+}
+
+impl From<csv::Error> for WriteError {
+    fn from(error: csv::Error) -> Self {
+        Self::Csv(error)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<parquet::errors::ParquetError> for WriteError {
+    fn from(error: parquet::errors::ParquetError) -> Self {
+        Self::Parquet(error)
+    }
+}
+
+impl From<WriteError> for crate::ReportError {
+    fn from(error: WriteError) -> Self {
+        match error {
+            WriteError::Csv(error) => Self::Csv(error),
+            #[cfg(feature = "parquet")]
+            WriteError::Parquet(error) => Self::Parquet(error),
+        }
+    }
+}
+
+/// Writes rows in a `ReportFormat`.
+pub(crate) enum ReportWriter<W: Write + Send> {
+    Csv(csv::Writer<W>),
+    #[cfg(feature = "parquet")]
+    Parquet(parquet_writer::ParquetWriter<W>),
+}
+
+impl<W: Write + Send> ReportWriter<W> {
+    pub(crate) fn new(format: ReportFormat, writer: W) -> Result<Self, WriteError> {
+        Ok(match format {
+            ReportFormat::Csv => Self::Csv(csv::Writer::from_writer(writer)),
+            #[cfg(feature = "parquet")]
+            ReportFormat::Parquet => Self::Parquet(parquet_writer::ParquetWriter::new(writer)?),
+        })
+    }
+
+    pub(crate) fn write(&mut self, rows: &[ReportRow]) -> Result<(), WriteError> {
+        match self {
+            Self::Csv(writer) => {
+                for row in rows {
+                    writer.serialize(row)?;
+                }
+                writer.flush().map_err(csv::Error::from)?;
+            }
+            #[cfg(feature = "parquet")]
+            Self::Parquet(writer) => writer.write(rows)?,
+        }
+        Ok(())
+    }
+
+    pub(crate) fn finish(self) -> Result<(), WriteError> {
+        match self {
+            Self::Csv(mut writer) => writer.flush().map_err(csv::Error::from)?,
+            #[cfg(feature = "parquet")]
+            Self::Parquet(writer) => writer.finish()?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "parquet")]
+mod parquet_writer {
+    use std::{io::Write, sync::Arc};
+
+    use parquet::{
+        data_type::{ByteArray, ByteArrayType, Int32Type, Int64Type},
+        errors::ParquetError,
+        file::{
+            properties::WriterProperties,
+            writer::{SerializedColumnWriter, SerializedFileWriter},
+        },
+        schema::parser::parse_message_type,
+    };
+
+    use super::ReportRow;
+
+    const SCHEMA: &str = "
+        message transaction_report {
+            REQUIRED BYTE_ARRAY wallet_id (UTF8);
+            REQUIRED BYTE_ARRAY transaction_id (UTF8);
+            REQUIRED INT64 created_at (TIMESTAMP(MILLIS, true));
+            REQUIRED BYTE_ARRAY status (UTF8);
+            REQUIRED BYTE_ARRAY caip2 (UTF8);
+            OPTIONAL BYTE_ARRAY transaction_hash (UTF8);
+            REQUIRED BYTE_ARRAY direction (UTF8);
+            REQUIRED BYTE_ARRAY asset (UTF8);
+            OPTIONAL BYTE_ARRAY amount (UTF8);
+            REQUIRED BYTE_ARRAY raw_amount (UTF8);
+            REQUIRED INT32 decimals (UINT_32);
+            REQUIRED BYTE_ARRAY sender (UTF8);
+            REQUIRED BYTE_ARRAY recipient (UTF8);
+        }
+    ";
+
+    pub(crate) struct ParquetWriter<W: Write + Send>(SerializedFileWriter<W>);
+
+    impl<W: Write + Send> ParquetWriter<W> {
+        pub(crate) fn new(writer: W) -> Result<Self, ParquetError> {
+            let schema = Arc::new(parse_message_type(SCHEMA)?);
+            let properties = Arc::new(WriterProperties::builder().build());
+            Ok(Self(SerializedFileWriter::new(writer, schema, properties)?))
+        }
+
+        /// Write `rows` as a row group, in the order of the columns in
+        /// `SCHEMA`.
+        pub(crate) fn write(&mut self, rows: &[ReportRow]) -> Result<(), ParquetError> {
+            if rows.is_empty() {
+                return Ok(());
+            }
+
+            let mut group = self.0.next_row_group()?;
+            let mut index = 0;
+            while let Some(mut column) = group.next_column()? {
+                match index {
+                    0 => strings(&mut column, rows, |row| &row.wallet_id)?,
+                    1 => strings(&mut column, rows, |row| &row.transaction_id)?,
+                    2 => {
+                        let values: Vec<i64> = rows.iter().map(|row| row.created_at).collect();
+                        column
+                            .typed::<Int64Type>()
+                            .write_batch(&values, None, None)?;
+                    }
+                    3 => strings(&mut column, rows, |row| &row.status)?,
+                    4 => strings(&mut column, rows, |row| &row.caip2)?,
+                    5 => {
+                        optional_strings(&mut column, rows, |row| row.transaction_hash.as_deref())?
+                    }
+                    6 => strings(&mut column, rows, |row| row.direction)?,
+                    7 => strings(&mut column, rows, |row| &row.asset)?,
+                    8 => optional_strings(&mut column, rows, |row| row.amount.as_deref())?,
+                    9 => strings(&mut column, rows, |row| &row.raw_amount)?,
+                    10 => {
+                        let values: Vec<i32> = rows.iter().map(|row| row.decimals as i32).collect();
+                        column
+                            .typed::<Int32Type>()
+                            .write_batch(&values, None, None)?;
+                    }
+                    11 => strings(&mut column, rows, |row| &row.sender)?,
+                    12 => strings(&mut column, rows, |row| &row.recipient)?,
+                    _ => unreachable!("the schema has 13 columns"),
+                }
+                column.close()?;
+                index += 1;
+            }
+            group.close()?;
+            Ok(())
+        }
+
+        pub(crate) fn finish(self) -> Result<(), ParquetError> {
+            self.0.close()?;
+            Ok(())
+        }
+    }
+
+    fn strings<'a>(
+        column: &mut SerializedColumnWriter<'_>,
+        rows: &'a [ReportRow],
+        field: impl Fn(&'a ReportRow) -> &'a str,
+    ) -> Result<(), ParquetError> {
+        let values: Vec<ByteArray> = rows.iter().map(|row| field(row).into()).collect();
+        column
+            .typed::<ByteArrayType>()
+            .write_batch(&values, None, None)?;
+        Ok(())
+    }
+
+    fn optional_strings<'a>(
+        column: &mut SerializedColumnWriter<'_>,
+        rows: &'a [ReportRow],
+        field: impl Fn(&'a ReportRow) -> Option<&'a str>,
+    ) -> Result<(), ParquetError> {
+        let levels: Vec<i16> = rows.iter().map(|row| field(row).is_some().into()).collect();
+        let values: Vec<ByteArray> = rows.iter().filter_map(field).map(Into::into).collect();
+        column
+            .typed::<ByteArrayType>()
+            .write_batch(&values, Some(&levels), None)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn transaction(
+        created_at_ms: f64,
+        kind: &str,
+        raw_value: &str,
+        hash: Option<&str>,
+    ) -> WalletTransactionsResponseTransactionsItem {
+        serde_json::from_value(json!({
+            "caip2": "eip155:8453",
+            "created_at": created_at_ms,
+            "privy_transaction_id": "tx",
+            "status": "confirmed",
+            "wallet_id": "wallet",
+            "transaction_hash": hash,
+            "details": {
+                "type": kind,
+                "asset": "usdc",
+                "chain": "base",
+                "display_values": {},
+                "raw_value": raw_value,
+                "raw_value_decimals": 6,
+                "sender": "0xsender",
+                "recipient": "0xrecipient",
+            }
+        }))
+        .unwrap()
+    }
+
+    fn period() -> ActivityPeriod {
+        ActivityPeriod::new(
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_000),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(2_000),
+        )
+    }
+
+    #[test]
+    fn test_normalize_amount() {
+        assert_eq!(normalize_amount("1500000", 6).as_deref(), Some("1.5"));
+        assert_eq!(normalize_amount("1", 6).as_deref(), Some("0.000001"));
+        assert_eq!(normalize_amount("2000", 3).as_deref(), Some("2"));
+        assert_eq!(normalize_amount("0", 18).as_deref(), Some("0"));
+        assert_eq!(normalize_amount("0042", 0).as_deref(), Some("42"));
+        assert_eq!(normalize_amount("-1", 6), None);
+        assert_eq!(normalize_amount("1.5", 6), None);
+        assert_eq!(normalize_amount("", 6), None);
+    }
+
+    #[test]
+    fn test_row_is_normalized_and_filtered_by_period() {
+        let row = ReportRow::new(
+            &transaction(1_500_000.0, "transfer_received", "2500000", Some("0xhash")),
+            &period(),
+        )
+        .unwrap();
+        assert_eq!(row.direction, "received");
+        assert_eq!(row.asset, "usdc");
+        assert_eq!(row.amount.as_deref(), Some("2.5"));
+        assert_eq!(row.raw_amount, "2500000");
+        assert_eq!(row.created_at, 1_500_000);
+
+        assert!(
+            ReportRow::new(
+                &transaction(2_500_000.0, "transfer_sent", "1", None),
+                &period()
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_csv_report() {
+        let rows: Vec<_> = [
+            transaction(1_100_000.0, "transfer_sent", "1000000", Some("0xa")),
+            transaction(1_200_000.0, "transfer_received", "250000", None),
+        ]
+        .iter()
+        .filter_map(|tx| ReportRow::new(tx, &period()))
+        .collect();
+
+        let mut out = Vec::new();
+        let mut writer = ReportWriter::new(ReportFormat::Csv, &mut out).unwrap();
+        writer.write(&rows).unwrap();
+        writer.finish().unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "wallet_id,transaction_id,created_at,status,caip2,transaction_hash,direction,asset,amount,raw_amount,decimals,sender,recipient\n\
+             wallet,tx,1100000,confirmed,eip155:8453,0xa,sent,usdc,1,1000000,6,0xsender,0xrecipient\n\
+             wallet,tx,1200000,confirmed,eip155:8453,,received,usdc,0.25,250000,6,0xsender,0xrecipient\n"
+        );
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_parquet_report() {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+
+        let rows: Vec<_> = [
+            transaction(1_100_000.0, "transfer_sent", "1000000", Some("0xa")),
+            transaction(1_200_000.0, "transfer_received", "x", None),
+        ]
+        .iter()
+        .filter_map(|tx| ReportRow::new(tx, &period()))
+        .collect();
+
+        let file = tempfile::tempfile().unwrap();
+        let mut writer = ReportWriter::new(ReportFormat::Parquet, &file).unwrap();
+        writer.write(&rows).unwrap();
+        writer.write(&rows[..1]).unwrap();
+        writer.finish().unwrap();
+
+        let reader = SerializedFileReader::new(file).unwrap();
+        let metadata = reader.metadata();
+        assert_eq!(metadata.num_row_groups(), 2);
+        assert_eq!(metadata.file_metadata().num_rows(), 3);
+        assert_eq!(metadata.file_metadata().schema_descr().num_columns(), 13);
+    }
+}
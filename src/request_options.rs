@@ -0,0 +1,193 @@
+//! Extra HTTP headers sent with every request of a client.
+//!
+//! Gateways in front of the Privy API often route on a header of their own,
+//! like a tenant id. [`RequestOptions`] adds such headers to the requests of
+//! a client, including the ones made by the generated subclients:
+//!
+//! ```no_run
+//! # use privy_rs::{PrivyClient, request_options::RequestOptions};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?
+//!     .with_request_options(RequestOptions::new().header("x-tenant-id", "acme")?)?;
+//! client.wallets().get("wallet_id").await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Options for a single subclient, such as a header naming the end user a
+//! batch of calls is made for, are added with the subclient's
+//! `with_request_options`, on top of those of the client:
+//!
+//! ```no_run
+//! # use privy_rs::{PrivyClient, request_options::RequestOptions};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! # let client = PrivyClient::new_from_env()?;
+//! let wallets = client
+//!     .wallets()
+//!     .with_request_options(RequestOptions::new().header("x-end-user", "user_1")?);
+//! wallets.get("wallet_id").await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Headers are not part of the payload that authorization signatures cover,
+//! unless added with [`RequestOptions::signed_header`]. Privy only verifies
+//! signatures over its own `privy-` headers, so only those can be signed.
+//!
+//! The headers the SDK sets itself, `authorization`, `content-type` and the
+//! `privy-` headers in [`RESERVED_HEADERS`], cannot be set through options.
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::PrivyCreateError;
+
+/// The headers the SDK sets itself, which options cannot set.
+pub const RESERVED_HEADERS: &[&str] = &[
+    "authorization",
+    "content-type",
+    "privy-app-id",
+    "privy-authorization-signature",
+    "privy-client",
+    "privy-idempotency-key",
+    "privy-request-expiry",
+];
+
+/// `name` as a header name, if it is valid and not reserved.
+fn header_name(name: &str) -> Result<HeaderName, PrivyCreateError> {
+    let name = HeaderName::try_from(name)?;
+    if RESERVED_HEADERS.contains(&name.as_str()) {
+        return Err(PrivyCreateError::ReservedHeader(name.as_str().to_owned()));
+    }
+    Ok(name)
+}
+
+/// Headers to add to every request, see the [module docs](self).
+#[derive(Clone, Debug, Default)]
+pub struct RequestOptions {
+    headers: HeaderMap,
+    signed: Vec<(String, String)>,
+}
+
+impl RequestOptions {
+    /// No extra headers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send `name: value` with every request.
+    ///
+    /// # Errors
+    /// If `name` or `value` are not valid in a header, or if `name` is one
+    /// of the [`RESERVED_HEADERS`].
+    pub fn header(mut self, name: &str, value: &str) -> Result<Self, PrivyCreateError> {
+        let name = header_name(name)?;
+        self.headers
+            .insert(name.clone(), HeaderValue::try_from(value)?);
+        self.signed.retain(|(signed, _)| signed != name.as_str());
+        Ok(self)
+    }
+
+    /// Send `name: value` with every request, and include it in the headers
+    /// of the canonical payload that authorization signatures are made over.
+    ///
+    /// # Errors
+    /// If `name` or `value` are not valid in a header, if `name` does not
+    /// start with `privy-`, or if it is one of the [`RESERVED_HEADERS`].
+    pub fn signed_header(mut self, name: &str, value: &str) -> Result<Self, PrivyCreateError> {
+        let name = header_name(name)?;
+        if !name.as_str().starts_with("privy-") {
+            return Err(PrivyCreateError::UnsignableHeader(name.as_str().to_owned()));
+        }
+        self.headers
+            .insert(name.clone(), HeaderValue::try_from(value)?);
+        self.signed.retain(|(signed, _)| signed != name.as_str());
+        self.signed
+            .push((name.as_str().to_owned(), value.to_owned()));
+        Ok(self)
+    }
+
+    /// Every extra header.
+    pub(crate) fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The headers to include in the canonical payload, by lowercase name.
+    pub(crate) fn signed_headers(&self) -> &[(String, String)] {
+        &self.signed
+    }
+
+    /// These options with the headers of `other` added, replacing any of
+    /// the same name.
+    pub(crate) fn merged(&self, other: &RequestOptions) -> RequestOptions {
+        let mut merged = self.clone();
+        for (name, value) in &other.headers {
+            merged.headers.insert(name.clone(), value.clone());
+            merged.signed.retain(|(signed, _)| signed != name.as_str());
+        }
+        merged.signed.extend(other.signed.iter().cloned());
+        merged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_privy_headers_can_be_signed() {
+        let options = RequestOptions::new()
+            .signed_header("Privy-Region", "eu")
+            .unwrap();
+        assert_eq!(
+            options.signed_headers(),
+            [("privy-region".to_owned(), "eu".to_owned())]
+        );
+
+        assert!(matches!(
+            RequestOptions::new().signed_header("x-region", "eu"),
+            Err(PrivyCreateError::UnsignableHeader(name)) if name == "x-region"
+        ));
+        assert!(matches!(
+            RequestOptions::new().signed_header("privy", "eu"),
+            Err(PrivyCreateError::UnsignableHeader(_))
+        ));
+    }
+
+    #[test]
+    fn test_headers_set_by_the_sdk_are_reserved() {
+        for name in ["Authorization", "privy-app-id", "privy-idempotency-key"] {
+            assert!(matches!(
+                RequestOptions::new().header(name, "value"),
+                Err(PrivyCreateError::ReservedHeader(_))
+            ));
+        }
+        assert!(matches!(
+            RequestOptions::new().signed_header("privy-authorization-signature", "value"),
+            Err(PrivyCreateError::ReservedHeader(name)) if name == "privy-authorization-signature"
+        ));
+        assert!(matches!(
+            RequestOptions::new().signed_header("Privy-Request-Expiry", "0"),
+            Err(PrivyCreateError::ReservedHeader(_))
+        ));
+    }
+
+    #[test]
+    fn test_merged_options_replace_headers_of_the_same_name() {
+        let client = RequestOptions::new()
+            .header("x-tenant-id", "acme")
+            .unwrap()
+            .signed_header("privy-region", "eu")
+            .unwrap();
+        let call = RequestOptions::new()
+            .header("x-tenant-id", "globex")
+            .unwrap()
+            .header("privy-region", "us")
+            .unwrap();
+
+        let merged = client.merged(&call);
+        assert_eq!(merged.headers()["x-tenant-id"], "globex");
+        assert_eq!(merged.headers()["privy-region"], "us");
+        assert!(merged.signed_headers().is_empty());
+    }
+}
@@ -0,0 +1,81 @@
+//! Combinators for `ResponseValue`.
+//!
+//! Every API call returns a [`ResponseValue`], which carries the status
+//! and headers of the response alongside its body. Calling `into_inner`
+//! drops them, so [`ResponseValueExt`] adds ways to work with the body
+//! while keeping them around:
+//!
+//! ```no_run
+//! # use privy_rs::{PrivyClient, ResponseValueExt};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let (address, status, headers) = client
+//!     .wallets()
+//!     .get("wallet_id")
+//!     .await?
+//!     .inspect_status(|status| tracing::debug!(%status, "fetched wallet"))
+//!     .map_inner(|wallet| wallet.address)
+//!     .into_parts();
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::{StatusCode, header::HeaderMap};
+
+use crate::generated::ResponseValue;
+
+/// Combinators for `ResponseValue`, see the [module docs](self).
+pub trait ResponseValueExt<T> {
+    /// Transform the body, keeping the status and headers.
+    fn map_inner<U>(self, f: impl FnOnce(T) -> U) -> ResponseValue<U>;
+
+    /// Call `f` with the status, then return the response unchanged.
+    #[must_use]
+    fn inspect_status(self, f: impl FnOnce(StatusCode)) -> Self;
+
+    /// Split the response into its body, status and headers.
+    fn into_parts(self) -> (T, StatusCode, HeaderMap);
+}
+
+impl<T> ResponseValueExt<T> for ResponseValue<T> {
+    fn map_inner<U>(self, f: impl FnOnce(T) -> U) -> ResponseValue<U> {
+        let (inner, status, headers) = self.into_parts();
+        ResponseValue::new(f(inner), status, headers)
+    }
+
+    fn inspect_status(self, f: impl FnOnce(StatusCode)) -> Self {
+        f(self.status());
+        self
+    }
+
+    fn into_parts(self) -> (T, StatusCode, HeaderMap) {
+        let status = self.status();
+        let headers = self.headers().clone();
+        (self.into_inner(), status, headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response() -> ResponseValue<u32> {
+        let mut headers = HeaderMap::new();
+        headers.insert("privy-request-id", "req_123".parse().unwrap());
+        ResponseValue::new(21, StatusCode::CREATED, headers)
+    }
+
+    #[test]
+    fn test_map_inner_keeps_status_and_headers() {
+        let mut seen = None;
+        let (inner, status, headers) = response()
+            .inspect_status(|status| seen = Some(status))
+            .map_inner(|n| n * 2)
+            .into_parts();
+
+        assert_eq!(inner, 42);
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(seen, Some(StatusCode::CREATED));
+        assert_eq!(headers["privy-request-id"], "req_123");
+    }
+}
@@ -0,0 +1,230 @@
+//! Recording wallet action responses for reconciliation.
+//!
+//! A [`ResponseSink`] registered with [`PrivyClient::with_response_sink`]
+//! is handed every successful response of the wallet actions that sign or
+//! send: `WalletsClient::rpc` (and so `rpc_batch` and the ethereum and
+//! solana services), `raw_sign` and `transfer`. Each [`RecordedResponse`]
+//! carries the response body as JSON, along with what is needed to match
+//! it to the request that produced it: the wallet, the rpc method, the
+//! idempotency key and the id Privy assigned to the request.
+//!
+//! Sinks are called inline, after the response is received and before it
+//! is returned, so they must not block. [`ChannelSink`] hands responses to
+//! a bounded channel for a separate task to persist:
+//!
+//! ```no_run
+//! # use privy_rs::{PrivyClient, response_sink::ChannelSink};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let (sink, mut responses) = ChannelSink::new(1024);
+//! let client = PrivyClient::new_from_env()?.with_response_sink(sink);
+//!
+//! tokio::spawn(async move {
+//!     while let Some(response) = responses.recv().await {
+//!         // write `response.body` to the ledger
+//!         println!("{:?} {}", response.request_id, response.body);
+//!     }
+//! });
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Dry run responses are not recorded, and neither are failed requests,
+//! whose details are on the error, see `ApiErrorDetails`.
+
+use std::{
+    fmt,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::SystemTime,
+};
+
+use serde::Serialize;
+use tokio::sync::mpsc;
+
+use crate::{WalletId, errors::request_id, generated::ResponseValue};
+
+/// The response of a wallet action, as seen by a [`ResponseSink`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RecordedResponse {
+    /// The action, one of `rpc`, `raw_sign` or `transfer`.
+    pub endpoint: &'static str,
+    /// The wallet the action was taken with.
+    pub wallet_id: WalletId,
+    /// The rpc method, such as `eth_sendTransaction`, for `rpc` calls.
+    pub method: Option<String>,
+    /// The idempotency key the request was sent with, if any.
+    pub idempotency_key: Option<String>,
+    /// The id of the request, if the API provided one.
+    pub request_id: Option<String>,
+    /// The status code of the response.
+    pub status: reqwest::StatusCode,
+    /// The response body.
+    pub body: serde_json::Value,
+    /// When the response was received.
+    pub received_at: SystemTime,
+}
+
+/// A hook that is handed the response of every wallet action. See the
+/// [module docs](self) for details.
+pub trait ResponseSink {
+    /// Take `response`. This runs on the request path, so it must return
+    /// quickly, handing slow work such as database writes off elsewhere.
+    fn record(&self, response: RecordedResponse);
+}
+
+/// A wrapper for a closure that implements `ResponseSink`.
+/// This uses the newtype pattern to avoid conflicting blanket impls.
+pub struct FnSink<F>(pub F);
+
+impl<F: Fn(RecordedResponse)> ResponseSink for FnSink<F> {
+    fn record(&self, response: RecordedResponse) {
+        (self.0)(response);
+    }
+}
+
+/// A `ResponseSink` that sends responses into a bounded channel.
+///
+/// When the channel is full the response is dropped with a warning rather
+/// than waiting for room, and counted in [`ChannelSink::dropped`]. Clones
+/// send into the same channel and share the count.
+#[derive(Clone, Debug)]
+pub struct ChannelSink {
+    sender: mpsc::Sender<RecordedResponse>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl ChannelSink {
+    /// Create a sink holding up to `capacity` responses that have not been
+    /// received yet, and the receiving end of its channel.
+    ///
+    /// # Panics
+    /// If `capacity` is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> (Self, mpsc::Receiver<RecordedResponse>) {
+        let (sender, receiver) = mpsc::channel(capacity);
+        let sink = Self {
+            sender,
+            dropped: Arc::default(),
+        };
+        (sink, receiver)
+    }
+
+    /// The number of responses dropped because the channel was full or
+    /// the receiver was gone.
+    #[must_use]
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl ResponseSink for ChannelSink {
+    fn record(&self, response: RecordedResponse) {
+        if let Err(e) = self.sender.try_send(response) {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            let response = match &e {
+                mpsc::error::TrySendError::Full(response)
+                | mpsc::error::TrySendError::Closed(response) => response,
+            };
+            tracing::warn!(
+                request_id = ?response.request_id,
+                "dropping recorded {} response: {}",
+                response.endpoint,
+                e
+            );
+        }
+    }
+}
+
+/// The response sinks registered with a client.
+#[derive(Clone, Default)]
+pub(crate) struct ResponseSinks(Vec<Arc<dyn ResponseSink + Send + Sync>>);
+
+impl fmt::Debug for ResponseSinks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ResponseSinks").field(&self.0.len()).finish()
+    }
+}
+
+impl ResponseSinks {
+    pub(crate) fn push(&mut self, sink: impl ResponseSink + Send + Sync + 'static) {
+        self.0.push(Arc::new(sink));
+    }
+
+    /// Hand `response`, the result of a request with `body` to `endpoint`,
+    /// to every sink. Nothing is serialized if there are no sinks.
+    pub(crate) fn record<B: Serialize, T: Serialize>(
+        &self,
+        endpoint: &'static str,
+        wallet_id: &WalletId,
+        idempotency_key: Option<&str>,
+        body: &B,
+        response: &ResponseValue<T>,
+    ) {
+        if self.0.is_empty() {
+            return;
+        }
+
+        let json = match serde_json::to_value(&**response) {
+            Ok(json) => json,
+            Err(e) => {
+                tracing::warn!("unable to record {} response: {}", endpoint, e);
+                return;
+            }
+        };
+        let method = serde_json::to_value(body).ok().and_then(|body| {
+            body.get("method")
+                .and_then(|method| method.as_str())
+                .map(str::to_owned)
+        });
+        let recorded = RecordedResponse {
+            endpoint,
+            wallet_id: wallet_id.clone(),
+            method,
+            idempotency_key: idempotency_key.map(str::to_owned),
+            request_id: request_id(response.headers()),
+            status: response.status(),
+            body: json,
+            received_at: SystemTime::now(),
+        };
+        for sink in &self.0 {
+            sink.record(recorded.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorded(request_id: &str) -> RecordedResponse {
+        RecordedResponse {
+            endpoint: "rpc",
+            wallet_id: WalletId::new("wallet_id"),
+            method: None,
+            idempotency_key: None,
+            request_id: Some(request_id.to_string()),
+            status: reqwest::StatusCode::OK,
+            body: serde_json::Value::Null,
+            received_at: SystemTime::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_channel_sink_drops_when_full() {
+        let (sink, mut receiver) = ChannelSink::new(1);
+
+        sink.record(recorded("first"));
+        sink.record(recorded("second"));
+        assert_eq!(sink.dropped(), 1);
+
+        let received = receiver.recv().await.unwrap();
+        assert_eq!(received.request_id.as_deref(), Some("first"));
+
+        drop(receiver);
+        sink.record(recorded("third"));
+        assert_eq!(sink.dropped(), 2);
+    }
+}
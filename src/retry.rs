@@ -0,0 +1,266 @@
+//! Retrying rate limited and failed API calls.
+//!
+//! With a [`RetryPolicy`] set as `PrivyClientOptions::retry`, every call of
+//! every subclient that the API answers with `429 Too Many Requests` or a
+//! `5xx` status is retried, with exponential backoff, up to
+//! `RetryPolicy::max_attempts` attempts in all:
+//!
+//! ```no_run
+//! # use privy_rs::{PrivyClient, client::PrivyClientOptions, retry::RetryPolicy};
+//! # fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_with_options(
+//!     "app_id".into(),
+//!     "app_secret".into(),
+//!     PrivyClientOptions {
+//!         retry: Some(RetryPolicy {
+//!             max_attempts: 5,
+//!             ..Default::default()
+//!         }),
+//!         ..Default::default()
+//!     },
+//! )?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A rate limited call waits for the delay in its `Retry-After` header, if
+//! there is one, rather than the backoff.
+//!
+//! A `5xx` answer does not say whether the request took effect, so `POST`
+//! and `PATCH` calls, such as wallet rpcs, are only retried on `5xx` when
+//! they carry an idempotency key, which makes the API perform them at most
+//! once. Rate limited calls were not performed, and are always retried.
+
+use std::{future::Future, time::Duration};
+
+use rand::Rng;
+
+use crate::{ErrorKind, PrivyApiError, PrivyApiErrorExt};
+
+/// How failed calls are retried, see the [module docs](self).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The most times a call is attempted, counting the first attempt. `1`
+    /// disables retries.
+    pub max_attempts: u32,
+    /// The backoff before the first retry, doubled for every retry after
+    /// it.
+    pub base_delay: Duration,
+    /// The longest backoff between two attempts. A call rate limited for
+    /// longer than this by its `Retry-After` header is not retried.
+    pub max_delay: Duration,
+    /// Wait a random delay of up to the backoff, rather than the backoff
+    /// itself, so that clients rate limited together do not retry together.
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// How long to wait before attempt `attempt + 1` of a call that failed
+    /// with `error`, or `None` if it should not be retried. `idempotent`
+    /// says whether the call can safely be performed twice.
+    fn delay(&self, attempt: u32, error: &PrivyApiError, idempotent: bool) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        match error.kind() {
+            ErrorKind::RateLimited => match retry_after(error) {
+                Some(delay) => (delay <= self.max_delay).then_some(delay),
+                None => Some(self.backoff(attempt)),
+            },
+            ErrorKind::Server if idempotent => Some(self.backoff(attempt)),
+            _ => None,
+        }
+    }
+
+    /// The backoff after attempt `attempt`, starting at 1.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(self.max_delay);
+        if self.jitter {
+            backoff.mul_f64(rand::thread_rng().r#gen::<f64>())
+        } else {
+            backoff
+        }
+    }
+}
+
+/// The delay asked for by the `Retry-After` header of `error`, in seconds.
+fn retry_after(error: &PrivyApiError) -> Option<Duration> {
+    error
+        .details()?
+        .headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Run `call`, the API call `operation`, retrying it as `policy` says.
+/// Without a policy, `call` is run once.
+pub(crate) async fn run<T, F, Fut>(
+    policy: Option<&RetryPolicy>,
+    operation: &'static str,
+    idempotent: bool,
+    mut call: F,
+) -> Result<T, PrivyApiError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, PrivyApiError>>,
+{
+    let mut attempt = 1;
+    loop {
+        let error = match call().await {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+        let Some(delay) = policy.and_then(|policy| policy.delay(attempt, &error, idempotent))
+        else {
+            return Err(error);
+        };
+
+        let max_attempts = policy.map_or(1, |policy| policy.max_attempts);
+        crate::telemetry::retry(operation, attempt, max_attempts, delay, &error);
+        tracing::warn!(
+            "{} failed (attempt {}/{}), retrying in {:?}: {}",
+            operation,
+            attempt,
+            max_attempts,
+            delay,
+            error
+        );
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::{PrivyClient, client::PrivyClientOptions};
+
+    fn client(server: &MockServer, retry: RetryPolicy) -> PrivyClient {
+        PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                retry: Some(retry),
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    }
+
+    fn fast() -> RetryPolicy {
+        RetryPolicy {
+            base_delay: Duration::from_millis(1),
+            jitter: false,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_server_errors_are_retried_up_to_max_attempts() {
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/wallets/wallet_id");
+                then.status(503);
+            })
+            .await;
+
+        let result = client(&server, fast()).wallets().get("wallet_id").await;
+        assert_eq!(
+            result.unwrap_err().status(),
+            Some(StatusCode::SERVICE_UNAVAILABLE)
+        );
+        assert_eq!(mock.calls_async().await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_is_honored() {
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/wallets/wallet_id");
+                then.status(429).header("retry-after", "1");
+            })
+            .await;
+
+        let policy = RetryPolicy {
+            max_attempts: 2,
+            ..fast()
+        };
+        let started = std::time::Instant::now();
+        let result = client(&server, policy.clone())
+            .wallets()
+            .get("wallet_id")
+            .await;
+        assert!(result.is_err());
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert_eq!(mock.calls_async().await, 2);
+
+        // a longer delay than max_delay is not waited for
+        let policy = RetryPolicy {
+            max_delay: Duration::from_millis(500),
+            ..policy
+        };
+        client(&server, policy)
+            .wallets()
+            .get("wallet_id")
+            .await
+            .unwrap_err();
+        assert_eq!(mock.calls_async().await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_non_idempotent_calls_are_not_retried_on_server_errors() {
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/wallets");
+                then.status(500);
+            })
+            .await;
+
+        let client = client(&server, fast());
+        let body = crate::generated::types::CreateWalletBody {
+            chain_type: crate::generated::types::WalletChainType::Ethereum,
+            additional_signers: None,
+            display_name: None,
+            external_id: None,
+            owner: None,
+            owner_id: None,
+            policy_ids: None,
+        };
+        client.wallets().create(None, &body).await.unwrap_err();
+        assert_eq!(mock.calls_async().await, 1);
+
+        // with an idempotency key, the API performs it at most once
+        client
+            .wallets()
+            .create(Some("idempotency-key"), &body)
+            .await
+            .unwrap_err();
+        assert_eq!(mock.calls_async().await, 4);
+    }
+}
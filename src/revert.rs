@@ -0,0 +1,287 @@
+//! Decoding of EVM revert data.
+//!
+//! When a contract call reverts, the node returns the ABI-encoded reason.
+//! Solidity's `require(cond, "message")` and `revert("message")` encode an
+//! `Error(string)`, failed assertions and arithmetic errors encode a
+//! `Panic(uint256)`, and custom errors encode their own selector and
+//! arguments. [`RevertReason::decode`] turns all of these into something
+//! readable:
+//!
+//! ```
+//! use privy_rs::revert::RevertReason;
+//!
+//! let data = hex::decode(concat!(
+//!     "4e487b71",
+//!     "0000000000000000000000000000000000000000000000000000000000000011",
+//! ))
+//! .unwrap();
+//! let reason = RevertReason::decode(&data, &[]);
+//! assert_eq!(reason.to_string(), "panic 0x11: arithmetic overflow or underflow");
+//! ```
+//!
+//! Custom errors can only be decoded if their signature is known, such as
+//! `InsufficientBalance(uint256,uint256)`. Arguments of static types,
+//! `string` and `bytes` are decoded; errors with array or tuple arguments
+//! are reported by name only.
+
+use std::fmt;
+
+use sha3::{Digest, Keccak256};
+
+/// The selector of `Error(string)`.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// The selector of `Panic(uint256)`.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Why a call reverted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RevertReason {
+    /// A revert with a message, e.g. `ERC20: transfer amount exceeds balance`.
+    Error(String),
+    /// A panic, such as a failed assertion or an arithmetic overflow.
+    Panic(u64),
+    /// One of the custom errors passed to [`RevertReason::decode`].
+    Custom {
+        /// The name of the error.
+        name: String,
+        /// The decoded arguments, or `None` if they could not be decoded.
+        args: Option<Vec<String>>,
+    },
+    /// Revert data that could not be decoded, which is empty for a bare
+    /// `revert()`.
+    Unknown(Vec<u8>),
+}
+
+impl RevertReason {
+    /// Decode revert data, trying `custom_errors` if it is neither an
+    /// `Error(string)` nor a `Panic(uint256)`. Custom errors are given as
+    /// Solidity signatures without spaces or parameter names, e.g.
+    /// `InsufficientBalance(uint256,uint256)`.
+    #[must_use]
+    pub fn decode(data: &[u8], custom_errors: &[String]) -> Self {
+        let Some((selector, args)) = data.split_first_chunk::<4>() else {
+            return Self::Unknown(data.to_vec());
+        };
+
+        match *selector {
+            ERROR_SELECTOR => {
+                if let Some(message) = decode_arg("string", args, 0) {
+                    return Self::Error(message);
+                }
+            }
+            PANIC_SELECTOR => {
+                if let Some(code) = word(args, 0).and_then(word_to_u64) {
+                    return Self::Panic(code);
+                }
+            }
+            _ => {}
+        }
+
+        for signature in custom_errors {
+            if Keccak256::digest(signature.as_bytes())[..4] != *selector {
+                continue;
+            }
+
+            let Some((name, params)) = signature.strip_suffix(')').and_then(|s| s.split_once('('))
+            else {
+                continue;
+            };
+
+            let args = if params.is_empty() {
+                Some(Vec::new())
+            } else {
+                params
+                    .split(',')
+                    .enumerate()
+                    .map(|(i, ty)| decode_arg(ty, args, i))
+                    .collect()
+            };
+
+            return Self::Custom {
+                name: name.to_owned(),
+                args,
+            };
+        }
+
+        Self::Unknown(data.to_vec())
+    }
+
+    /// A description of a panic code, as documented by Solidity.
+    #[must_use]
+    pub fn panic_description(code: u64) -> &'static str {
+        match code {
+            0x00 => "generic panic",
+            0x01 => "assertion failed",
+            0x11 => "arithmetic overflow or underflow",
+            0x12 => "division or modulo by zero",
+            0x21 => "invalid enum value",
+            0x22 => "invalid storage byte array",
+            0x31 => "pop on empty array",
+            0x32 => "array index out of bounds",
+            0x41 => "out of memory",
+            0x51 => "call to uninitialized function",
+            _ => "unknown panic",
+        }
+    }
+}
+
+impl fmt::Display for RevertReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error(message) => f.write_str(message),
+            Self::Panic(code) => write!(f, "panic {code:#04x}: {}", Self::panic_description(*code)),
+            Self::Custom {
+                name,
+                args: Some(args),
+            } => write!(f, "{name}({})", args.join(", ")),
+            Self::Custom { name, args: None } => write!(f, "{name}(..)"),
+            Self::Unknown(data) if data.is_empty() => f.write_str("execution reverted"),
+            Self::Unknown(data) => write!(f, "execution reverted with 0x{}", hex::encode(data)),
+        }
+    }
+}
+
+/// The `index`th 32 byte word of `data`.
+fn word(data: &[u8], index: usize) -> Option<&[u8; 32]> {
+    data.get(index * 32..)?.first_chunk()
+}
+
+fn word_to_u64(word: &[u8; 32]) -> Option<u64> {
+    let (high, low) = word.split_last_chunk::<8>()?;
+    high.iter()
+        .all(|b| *b == 0)
+        .then(|| u64::from_be_bytes(*low))
+}
+
+fn word_to_usize(word: &[u8; 32]) -> Option<usize> {
+    word_to_u64(word).and_then(|n| usize::try_from(n).ok())
+}
+
+/// Format a big-endian unsigned 256 bit integer in decimal.
+fn word_to_decimal(word: &[u8; 32]) -> String {
+    let mut value = *word;
+    let mut digits = Vec::new();
+    while value.iter().any(|b| *b != 0) {
+        let mut remainder = 0u16;
+        for byte in &mut value {
+            let current = (remainder << 8) | u16::from(*byte);
+            *byte = (current / 10) as u8;
+            remainder = current % 10;
+        }
+        digits.push(b'0' + remainder as u8);
+    }
+
+    if digits.is_empty() {
+        return "0".to_owned();
+    }
+    digits.reverse();
+    String::from_utf8(digits).expect("digits are ascii")
+}
+
+/// Decode the `index`th argument of ABI-encoded `data` as `ty`.
+fn decode_arg(ty: &str, data: &[u8], index: usize) -> Option<String> {
+    let head = word(data, index)?;
+
+    match ty {
+        "address" => Some(format!("0x{}", hex::encode(&head[12..]))),
+        "bool" => Some((head[31] != 0).to_string()),
+        "string" | "bytes" => {
+            let offset = word_to_usize(head)?;
+            let len = word_to_usize(word(data.get(offset..)?, 0)?)?;
+            let bytes = data.get(offset.checked_add(32)?..)?.get(..len)?;
+            if ty == "string" {
+                String::from_utf8(bytes.to_vec()).ok()
+            } else {
+                Some(format!("0x{}", hex::encode(bytes)))
+            }
+        }
+        ty if ty.starts_with("uint") => Some(word_to_decimal(head)),
+        ty if ty.starts_with("int") => {
+            if head[0] & 0x80 == 0 {
+                return Some(word_to_decimal(head));
+            }
+            // two's complement: negate by inverting and adding one
+            let mut magnitude = head.map(|b| !b);
+            for byte in magnitude.iter_mut().rev() {
+                let (sum, overflow) = byte.overflowing_add(1);
+                *byte = sum;
+                if !overflow {
+                    break;
+                }
+            }
+            Some(format!("-{}", word_to_decimal(&magnitude)))
+        }
+        ty if ty.starts_with("bytes") => {
+            let len: usize = ty["bytes".len()..].parse().ok()?;
+            Some(format!("0x{}", hex::encode(head.get(..len)?)))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    fn encode(selector: &str, words: &[&str]) -> Vec<u8> {
+        let mut data = hex::decode(selector).unwrap();
+        for word in words {
+            data.extend(hex::decode(format!("{word:0>64}")).unwrap());
+        }
+        data
+    }
+
+    #[test]
+    fn test_decode_error_string() {
+        let message = "ERC20: transfer amount exceeds balance";
+        let data = encode(
+            "08c379a0",
+            &[
+                "20",
+                &format!("{:x}", message.len()),
+                &format!("{:0<128}", hex::encode(message)),
+            ],
+        );
+        assert_eq!(
+            RevertReason::decode(&data, &[]),
+            RevertReason::Error(message.to_owned())
+        );
+    }
+
+    #[test]
+    fn test_decode_custom_error() {
+        let signature = "InsufficientBalance(address,uint256,int8)".to_owned();
+        let selector = hex::encode(&Keccak256::digest(signature.as_bytes())[..4]);
+        let data = encode(
+            &selector,
+            &[
+                "742d35cc6634c0532925a3b844bc454e4438f44e",
+                "de0b6b3a7640000",
+                &"f".repeat(64),
+            ],
+        );
+
+        let reason = RevertReason::decode(&data, &[signature]);
+        assert_eq!(
+            reason.to_string(),
+            "InsufficientBalance(0x742d35cc6634c0532925a3b844bc454e4438f44e, 1000000000000000000, -1)"
+        );
+    }
+
+    #[test_case("", &[] => "execution reverted" ; "bare revert")]
+    #[test_case("4e487b71", &["1"] => "panic 0x01: assertion failed" ; "assertion")]
+    #[test_case("12345678", &["1"] => "execution reverted with 0x123456780000000000000000000000000000000000000000000000000000000000000001" ; "unknown selector")]
+    #[test_case("08c379a0", &["20"] => "execution reverted with 0x08c379a00000000000000000000000000000000000000000000000000000000000000020" ; "truncated error")]
+    fn test_decode_display(selector: &str, words: &[&str]) -> String {
+        RevertReason::decode(&encode(selector, words), &[]).to_string()
+    }
+
+    #[test_case([0; 32] => "0" ; "zero")]
+    #[test_case([0xff; 32] => "115792089237316195423570985008687907853269984665640564039457584007913129639935" ; "max")]
+    fn test_word_to_decimal(word: [u8; 32]) -> String {
+        word_to_decimal(&word)
+    }
+}
@@ -0,0 +1,234 @@
+//! Attaching a policy to many wallets at once.
+//!
+//! `PoliciesClient::rollout` pages through the wallets of the app, keeps
+//! those matching a filter, and sets the policy on each of them with a
+//! signed wallet update, several at a time. Wallets that already have the
+//! policy are left alone, so a rollout can be run again safely.
+//!
+//! ```no_run
+//! # use privy_rs::{AuthorizationContext, PolicyId, PrivyClient, rollout::RolloutOptions};
+//! # use privy_rs::generated::types::WalletChainType;
+//! # async fn foo(ctx: &AuthorizationContext) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let report = client
+//!     .policies()
+//!     .rollout(
+//!         PolicyId::new("policy_id"),
+//!         |wallet| wallet.chain_type == WalletChainType::Ethereum,
+//!         ctx,
+//!     )
+//!     .await?;
+//! println!(
+//!     "updated {}, unchanged {}, failed {}",
+//!     report.updated.len(),
+//!     report.unchanged.len(),
+//!     report.failed.len()
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Rate limits
+//!
+//! When the API rate limits a request, every request of the rollout waits
+//! for the `Retry-After` delay, or an exponential backoff if there is none,
+//! before the rate limited one is retried, up to
+//! `RolloutOptions::max_retries` times.
+//!
+//! # Resuming
+//!
+//! Wallets are processed a page at a time, and once every wallet of a page
+//! has been updated, `RolloutOptions::on_checkpoint` is called with the
+//! position of the next page. A rollout that could not list a page returns
+//! the position it stopped at in `RolloutError::report`. Either can be
+//! passed to `RolloutOptions::resume_from` to carry on from there.
+//!
+//! The API currently supports a single policy per wallet, so the policy
+//! replaces any policy a matching wallet already has.
+
+use std::{
+    fmt,
+    future::Future,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::{PrivyApiError, PrivyApiErrorExt, PrivySignedApiError, WalletId};
+
+/// The default number of wallets updated at the same time.
+const DEFAULT_CONCURRENCY: usize = 8;
+
+/// The default number of times a rate limited request is retried.
+const DEFAULT_MAX_RETRIES: u32 = 5;
+
+/// The delay before the first retry of a rate limited request without a
+/// `Retry-After` header, doubling with every retry.
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+
+/// The position of a rollout in the list of wallets, to resume it from.
+///
+/// This serializes as a string, so it can be persisted between runs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RolloutCheckpoint(String);
+
+impl RolloutCheckpoint {
+    pub(crate) fn new(cursor: String) -> Self {
+        Self(cursor)
+    }
+
+    /// The wallet list cursor of the next page to process.
+    pub fn cursor(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A callback for the checkpoints of a rollout.
+pub type CheckpointFn = Arc<dyn Fn(&RolloutCheckpoint) + Send + Sync>;
+
+/// How to run a rollout, see `PoliciesClient::rollout_with_options`.
+#[derive(Clone)]
+pub struct RolloutOptions {
+    /// The number of wallets updated at the same time.
+    pub concurrency: usize,
+    /// The number of times a rate limited request is retried before the
+    /// rate limit is treated as a failure.
+    pub max_retries: u32,
+    /// Skip the wallets before this checkpoint, left by an earlier run.
+    pub resume_from: Option<RolloutCheckpoint>,
+    /// Called after each page of wallets has been processed, with the
+    /// checkpoint to resume from if the rollout is interrupted.
+    pub on_checkpoint: Option<CheckpointFn>,
+}
+
+impl Default for RolloutOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: DEFAULT_CONCURRENCY,
+            max_retries: DEFAULT_MAX_RETRIES,
+            resume_from: None,
+            on_checkpoint: None,
+        }
+    }
+}
+
+impl fmt::Debug for RolloutOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RolloutOptions")
+            .field("concurrency", &self.concurrency)
+            .field("max_retries", &self.max_retries)
+            .field("resume_from", &self.resume_from)
+            .field("on_checkpoint", &self.on_checkpoint.is_some())
+            .finish()
+    }
+}
+
+/// What a rollout did to each wallet it saw.
+#[derive(Debug, Default)]
+pub struct RolloutReport {
+    /// The wallets the policy was set on.
+    pub updated: Vec<WalletId>,
+    /// The matching wallets that already had the policy.
+    pub unchanged: Vec<WalletId>,
+    /// The matching wallets that could not be updated.
+    pub failed: Vec<RolloutFailure>,
+    /// The number of wallets that did not match the filter.
+    pub unmatched: usize,
+    /// The number of requests that were rate limited, including retries.
+    pub rate_limited: u32,
+    /// Where to resume the rollout from, if it did not reach the last
+    /// wallet.
+    pub checkpoint: Option<RolloutCheckpoint>,
+}
+
+/// A wallet a rollout could not update.
+#[derive(Debug)]
+pub struct RolloutFailure {
+    /// The wallet.
+    pub wallet_id: WalletId,
+    /// Why the update failed.
+    pub error: PrivySignedApiError,
+}
+
+/// Holds back every request of a rollout while the API is rate limiting
+/// it, and counts the rate limited requests.
+#[derive(Clone, Default)]
+pub(crate) struct RateLimitGate {
+    until: Arc<Mutex<Option<Instant>>>,
+    hits: Arc<AtomicU32>,
+}
+
+impl RateLimitGate {
+    pub(crate) fn hits(&self) -> u32 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Run `call`, retrying it up to `max_retries` times while the API
+    /// error `api_error` extracts from its result is a rate limit.
+    pub(crate) async fn call<T, E, F, Fut>(
+        &self,
+        max_retries: u32,
+        api_error: fn(&E) -> Option<&PrivyApiError>,
+        mut call: F,
+    ) -> Result<T, E>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let until = *self.until.lock().expect("lock poisoned");
+            if let Some(until) = until {
+                tokio::time::sleep_until(until.into()).await;
+            }
+
+            let error = match call().await {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+            let Some(delay) = api_error(&error).and_then(|e| rate_limit_delay(e, attempt)) else {
+                return Err(error);
+            };
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            if attempt >= max_retries {
+                return Err(error);
+            }
+
+            tracing::warn!("rate limited, pausing rollout for {:?}", delay);
+            let resume_at = Instant::now() + delay;
+            let mut until = self.until.lock().expect("lock poisoned");
+            *until = Some(until.map_or(resume_at, |until| until.max(resume_at)));
+            attempt += 1;
+        }
+    }
+}
+
+/// How long to wait before retrying a request that failed with `error`, if
+/// it was rate limited: the `Retry-After` delay, or an exponential backoff.
+fn rate_limit_delay(error: &PrivyApiError, attempt: u32) -> Option<Duration> {
+    if error.status() != Some(StatusCode::TOO_MANY_REQUESTS) {
+        return None;
+    }
+    let retry_after = error
+        .details()
+        .and_then(|details| details.headers.get(reqwest::header::RETRY_AFTER))
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs);
+    Some(retry_after.unwrap_or_else(|| BASE_BACKOFF * 2u32.saturating_pow(attempt)))
+}
+
+/// The API error behind a failed wallet update, if it got as far as the API.
+pub(crate) fn signed_api_error(error: &PrivySignedApiError) -> Option<&PrivyApiError> {
+    match error {
+        PrivySignedApiError::Api(error)
+        | PrivySignedApiError::PolicyDenied { source: error, .. } => Some(error),
+        _ => None,
+    }
+}
@@ -0,0 +1,396 @@
+//! Crash-safe transaction sending for payout systems.
+//!
+//! Privy deduplicates requests that carry the same `privy-idempotency-key`,
+//! so a send that is retried with its original key can never broadcast
+//! twice. The hard part is making sure the retry after a crash really uses
+//! the original key, and that a key is never reused for a different
+//! transaction. [`SafeSender`] handles both: before anything is sent, it
+//! persists the key together with a hash of the request in an
+//! [`IdempotencyStore`], and it refuses to send a request whose key was
+//! already recorded for a different request.
+//!
+//! ```no_run
+//! # use privy_rs::{AuthorizationContext, PrivyClient, WalletId, generated::types::UnsignedEthereumTransaction};
+//! use privy_rs::safe_send::{FileIdempotencyStore, SafeSender};
+//!
+//! # async fn foo(
+//! #     transaction: UnsignedEthereumTransaction,
+//! # ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let store = FileIdempotencyStore::open("payouts.journal").await?;
+//! let sender = SafeSender::new(client, store);
+//!
+//! // derive the key from something stable, like the payout's id
+//! sender
+//!     .send_transaction(
+//!         "payout-1234",
+//!         WalletId::new("wallet_id"),
+//!         "eip155:1",
+//!         transaction,
+//!         &AuthorizationContext::new(),
+//!     )
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    future::Future,
+    path::Path,
+    sync::Mutex,
+};
+
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use crate::{
+    AuthorizationContext, PrivyClient, SafeSendError, WalletId,
+    generated::{ResponseValue, types::WalletRpcResponse},
+};
+
+/// An error from an [`IdempotencyStore`] implementation.
+pub type StoreError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Durable storage for the idempotency keys a [`SafeSender`] has used.
+///
+/// Implementations must have persisted the key by the time `reserve`
+/// returns, since the request is sent immediately afterwards.
+pub trait IdempotencyStore {
+    /// Record that `key` is being used for the request with `request_hash`,
+    /// unless `key` has been recorded before. Returns the previously
+    /// recorded hash if there is one, and `None` if `key` is new.
+    fn reserve(
+        &self,
+        key: &str,
+        request_hash: &str,
+    ) -> impl Future<Output = Result<Option<String>, StoreError>> + Send;
+}
+
+/// An [`IdempotencyStore`] that keeps keys in memory. It does not survive
+/// restarts, so it is only useful in tests or in front of another store.
+#[derive(Debug, Default)]
+pub struct InMemoryIdempotencyStore {
+    keys: Mutex<HashMap<String, String>>,
+}
+
+impl IdempotencyStore for InMemoryIdempotencyStore {
+    async fn reserve(&self, key: &str, request_hash: &str) -> Result<Option<String>, StoreError> {
+        let mut keys = self.keys.lock().expect("lock poisoned");
+        Ok(match keys.entry(key.to_owned()) {
+            Entry::Occupied(entry) => Some(entry.get().clone()),
+            Entry::Vacant(entry) => {
+                entry.insert(request_hash.to_owned());
+                None
+            }
+        })
+    }
+}
+
+/// An [`IdempotencyStore`] backed by an append-only journal file, with one
+/// JSON `[key, request_hash]` pair per line. Every new key is flushed to disk
+/// before `reserve` returns. If the process crashed while appending an
+/// entry, that unterminated last line is discarded when the journal is
+/// reopened; its key was never reserved, as `reserve` had not returned.
+///
+/// The journal is only safe to use from one process at a time.
+#[derive(Debug)]
+pub struct FileIdempotencyStore {
+    state: tokio::sync::Mutex<(HashMap<String, String>, tokio::fs::File)>,
+}
+
+impl FileIdempotencyStore {
+    /// Open the journal at `path`, creating it if it does not exist.
+    ///
+    /// # Errors
+    /// Fails if the file cannot be opened, or contains a malformed complete
+    /// entry.
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self, StoreError> {
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)
+            .await?;
+
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents).await?;
+        let complete = contents
+            .iter()
+            .rposition(|&byte| byte == b'\n')
+            .map_or(0, |newline| newline + 1);
+        if complete < contents.len() {
+            tracing::warn!("discarding a torn entry at the end of the idempotency journal");
+            file.set_len(complete as u64).await?;
+        }
+
+        let mut keys = HashMap::new();
+        for line in contents[..complete].split(|&byte| byte == b'\n') {
+            if line.is_empty() {
+                continue;
+            }
+            let (key, request_hash): (String, String) = serde_json::from_slice(line)?;
+            keys.insert(key, request_hash);
+        }
+
+        Ok(Self {
+            state: tokio::sync::Mutex::new((keys, file)),
+        })
+    }
+}
+
+impl IdempotencyStore for FileIdempotencyStore {
+    async fn reserve(&self, key: &str, request_hash: &str) -> Result<Option<String>, StoreError> {
+        let mut state = self.state.lock().await;
+        let (keys, file) = &mut *state;
+
+        if let Some(existing) = keys.get(key) {
+            return Ok(Some(existing.clone()));
+        }
+
+        let mut line = serde_json::to_string(&(key, request_hash))?;
+        line.push('\n');
+        file.write_all(line.as_bytes()).await?;
+        file.sync_data().await?;
+
+        keys.insert(key.to_owned(), request_hash.to_owned());
+        Ok(None)
+    }
+}
+
+/// Sends transactions only after their idempotency key has been persisted.
+/// See the [module docs](self) for details.
+#[derive(Debug)]
+pub struct SafeSender<S> {
+    client: PrivyClient,
+    store: S,
+}
+
+impl<S: IdempotencyStore> SafeSender<S> {
+    /// Create a sender that journals keys in `store`.
+    pub fn new(client: PrivyClient, store: S) -> Self {
+        Self { client, store }
+    }
+
+    /// Reserve `idempotency_key` for the request, failing if it was already
+    /// used for a different one.
+    async fn reserve(
+        &self,
+        idempotency_key: &str,
+        request: &serde_json::Value,
+    ) -> Result<(), SafeSendError> {
+        let canonical = serde_json_canonicalizer::to_string(request)?;
+        let request_hash = hex::encode(Sha256::digest(canonical.as_bytes()));
+
+        match self
+            .store
+            .reserve(idempotency_key, &request_hash)
+            .await
+            .map_err(SafeSendError::Store)?
+        {
+            Some(existing) if existing != request_hash => Err(SafeSendError::KeyReused {
+                key: idempotency_key.to_owned(),
+            }),
+            Some(_) => {
+                tracing::info!(idempotency_key, "retrying previously journaled send");
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Sign and broadcast an Ethereum transaction, as
+    /// [`EthereumService::send_transaction`](crate::ethereum::EthereumService::send_transaction).
+    ///
+    /// Calling this again with the same key and request after a crash is
+    /// safe; the API returns the result of the original send.
+    ///
+    /// # Errors
+    /// Fails if the key cannot be journaled, if it was previously used for
+    /// a different request, or if the send itself fails.
+    #[cfg(feature = "ethereum")]
+    pub async fn send_transaction(
+        &self,
+        idempotency_key: &str,
+        wallet_id: impl Into<WalletId>,
+        caip2: &str,
+        transaction: crate::generated::types::UnsignedEthereumTransaction,
+        authorization_context: &AuthorizationContext,
+    ) -> Result<ResponseValue<WalletRpcResponse>, SafeSendError> {
+        let wallet_id = wallet_id.into();
+        let request = serde_json::json!({
+            "wallet_id": wallet_id,
+            "caip2": caip2,
+            "transaction": transaction,
+        });
+        self.reserve(idempotency_key, &request).await?;
+
+        Ok(self
+            .client
+            .wallets()
+            .ethereum()
+            .send_transaction(
+                wallet_id,
+                caip2,
+                transaction,
+                authorization_context,
+                Some(idempotency_key),
+            )
+            .await?)
+    }
+
+    /// Sign and broadcast a base64-encoded Solana transaction, as
+    /// [`SolanaService::sign_and_send_transaction`](crate::solana::SolanaService::sign_and_send_transaction).
+    ///
+    /// # Errors
+    /// Fails if the key cannot be journaled, if it was previously used for
+    /// a different request, or if the send itself fails.
+    #[cfg(feature = "solana")]
+    pub async fn sign_and_send_transaction(
+        &self,
+        idempotency_key: &str,
+        wallet_id: impl Into<WalletId>,
+        caip2: &str,
+        transaction: &str,
+        authorization_context: &AuthorizationContext,
+    ) -> Result<ResponseValue<WalletRpcResponse>, SafeSendError> {
+        let wallet_id = wallet_id.into();
+        let request = serde_json::json!({
+            "wallet_id": wallet_id,
+            "caip2": caip2,
+            "transaction": transaction,
+        });
+        self.reserve(idempotency_key, &request).await?;
+
+        Ok(self
+            .client
+            .wallets()
+            .solana()
+            .sign_and_send_transaction(
+                wallet_id,
+                caip2,
+                transaction,
+                authorization_context,
+                Some(idempotency_key),
+            )
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_file_store_survives_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+
+        let store = FileIdempotencyStore::open(&path).await.unwrap();
+        assert_eq!(store.reserve("a", "hash").await.unwrap(), None);
+        assert_eq!(
+            store.reserve("a", "other").await.unwrap(),
+            Some("hash".to_string())
+        );
+        drop(store);
+
+        let store = FileIdempotencyStore::open(&path).await.unwrap();
+        assert_eq!(
+            store.reserve("a", "other").await.unwrap(),
+            Some("hash".to_string())
+        );
+        assert_eq!(store.reserve("b", "hash").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_file_store_discards_a_torn_last_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal");
+
+        let store = FileIdempotencyStore::open(&path).await.unwrap();
+        assert_eq!(store.reserve("a", "hash").await.unwrap(), None);
+        drop(store);
+        // a crash part way through appending the entry for "b"
+        let mut file = tokio::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .await
+            .unwrap();
+        file.write_all(br#"["b","ha"#).await.unwrap();
+        drop(file);
+
+        let store = FileIdempotencyStore::open(&path).await.unwrap();
+        assert_eq!(
+            store.reserve("a", "other").await.unwrap(),
+            Some("hash".to_string())
+        );
+        assert_eq!(store.reserve("b", "hash").await.unwrap(), None);
+        drop(store);
+
+        let store = FileIdempotencyStore::open(&path).await.unwrap();
+        assert_eq!(
+            store.reserve("b", "other").await.unwrap(),
+            Some("hash".to_string())
+        );
+
+        // a malformed complete entry is still an error
+        tokio::fs::write(&path, "not json\n").await.unwrap();
+        assert!(FileIdempotencyStore::open(&path).await.is_err());
+    }
+
+    #[cfg(feature = "solana")]
+    #[tokio::test]
+    async fn test_key_reuse_is_rejected_before_sending() {
+        use httpmock::prelude::*;
+
+        use crate::client::PrivyClientOptions;
+
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/wallets/wallet_id/rpc")
+                    .header("privy-idempotency-key", "payout-1");
+                then.status(200).json_body(serde_json::json!({
+                    "method": "signAndSendTransaction",
+                    "data": { "caip2": "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1", "hash": "abc" },
+                }));
+            })
+            .await;
+
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let sender = SafeSender::new(client, InMemoryIdempotencyStore::default());
+        let ctx = AuthorizationContext::new();
+        let caip2 = "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1";
+
+        // a retry of the same request is sent with the same key
+        for _ in 0..2 {
+            sender
+                .sign_and_send_transaction(
+                    "payout-1",
+                    WalletId::new("wallet_id"),
+                    caip2,
+                    "AA==",
+                    &ctx,
+                )
+                .await
+                .unwrap();
+        }
+        mock.assert_calls_async(2).await;
+
+        let error = sender
+            .sign_and_send_transaction("payout-1", WalletId::new("wallet_id"), caip2, "AQ==", &ctx)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, SafeSendError::KeyReused { key } if key == "payout-1"));
+        mock.assert_calls_async(2).await;
+    }
+}
@@ -0,0 +1,319 @@
+//! Unified asset transfers across chains.
+//!
+//! This module provides `PrivyClient::send`, which sends native currency
+//! or tokens from a wallet on any chain supported by the Privy transfer
+//! API. Transaction encoding, token decimals and fees are handled by Privy,
+//! so callers only need to say what to send and to whom.
+//!
+//! ```rust,no_run
+//! # use privy_rs::{AuthorizationContext, PrivyClient, WalletId, send::{SendAsset, SendRequest}};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let ctx = AuthorizationContext::new();
+//!
+//! let result = client
+//!     .send(
+//!         WalletId::new("wallet_id"),
+//!         SendRequest {
+//!             chain: "base".to_string(),
+//!             to: "0xB00F0759DbeeF5E543Cc3E3B07A6442F5f3928a2".to_string(),
+//!             amount: "10.5".to_string(),
+//!             asset: SendAsset::Named("usdc".to_string()),
+//!         },
+//!         &ctx,
+//!         None,
+//!     )
+//!     .await?;
+//!
+//! println!("submitted transfer {}", result.tx_id);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    AuthorizationContext, ConversionError, InvalidAddressForChain, PrivyClient,
+    PrivySignedApiError, WalletId,
+    address::validate_address,
+    explorer::explorer_url_for_tx,
+    generated::{
+        Error,
+        types::{
+            CustomTokenTransferSource, CustomTokenTransferSourceAssetAddress,
+            NamedTokenTransferSource, TokenTransferDestination, TokenTransferDestinationAddress,
+            TransferActionResponse, TransferRequestBody, WalletActionStatus, WalletActionStep,
+        },
+    },
+};
+
+/// The asset to send in a `SendRequest`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SendAsset {
+    /// The native currency of the chain, see `NATIVE_ASSETS`: `eth` on
+    /// Ethereum and its rollups, `pol` on Polygon and `sol` on Solana.
+    /// Sending it on any other chain fails, name the asset with
+    /// `SendAsset::Named` instead.
+    Native,
+    /// An asset maintained by Privy, identified by name (e.g. `usdc`).
+    Named(String),
+    /// Any other token, identified by its contract address (ERC-20) or
+    /// mint address (SPL).
+    Token(String),
+}
+
+/// The native asset of each network, by the first part of its name, so that
+/// testnets like `base-sepolia` are included.
+const NATIVE_ASSETS: &[(&str, &str)] = &[
+    ("ethereum", "eth"),
+    ("sepolia", "eth"),
+    ("holesky", "eth"),
+    ("base", "eth"),
+    ("arbitrum", "eth"),
+    ("optimism", "eth"),
+    ("polygon", "pol"),
+    ("solana", "sol"),
+];
+
+/// The native asset of `chain`, or `None` if it is not known.
+fn native_asset(chain: &str) -> Option<&'static str> {
+    let family = chain
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    NATIVE_ASSETS
+        .iter()
+        .find(|(network, _)| *network == family)
+        .map(|(_, asset)| *asset)
+}
+
+/// A request to send an asset from a wallet to a recipient.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SendRequest {
+    /// The network to send on, e.g. `ethereum`, `base` or `solana`.
+    pub chain: String,
+    /// The recipient address.
+    pub to: String,
+    /// The amount as a decimal string in the asset's display unit, e.g.
+    /// `"1.5"` for 1.5 USDC. This is _not_ in wei or lamports.
+    pub amount: String,
+    /// The asset to send.
+    pub asset: SendAsset,
+}
+
+/// The outcome of `PrivyClient::send`.
+#[derive(Debug, Clone)]
+pub struct SendResult {
+    /// The ID of the wallet action tracking this transfer.
+    pub tx_id: String,
+    /// The status of the transfer at the time the request returned.
+    pub status: WalletActionStatus,
+    /// The on-chain transaction hash (or signature on Solana), if it has
+    /// already been broadcast.
+    pub tx_hash: Option<String>,
+    /// A link to the transaction on a block explorer, if the hash is known
+    /// and the network has a known explorer.
+    pub explorer_url: Option<String>,
+}
+
+impl From<TransferActionResponse> for SendResult {
+    fn from(response: TransferActionResponse) -> Self {
+        let broadcast = response.steps.iter().find_map(|step| match step {
+            WalletActionStep::EvmTransactionWalletActionStep(step) => step
+                .transaction_hash
+                .as_ref()
+                .map(|hash| (step.caip2.as_str(), hash)),
+            WalletActionStep::SvmTransactionWalletActionStep(step) => step
+                .transaction_signature
+                .as_ref()
+                .map(|sig| (step.caip2.as_str(), sig)),
+            _ => None,
+        });
+
+        Self {
+            explorer_url: broadcast.and_then(|(caip2, hash)| explorer_url_for_tx(caip2, hash)),
+            tx_hash: broadcast.map(|(_, hash)| hash.clone()),
+            tx_id: response.id,
+            status: response.status,
+        }
+    }
+}
+
+impl SendRequest {
+    /// Check the recipient, and the token address if any, against the
+    /// chain, see `privy_rs::address`.
+    fn validate_addresses(&self) -> Result<(), InvalidAddressForChain> {
+        validate_address(&self.chain, &self.to)?;
+        if let SendAsset::Token(address) = &self.asset {
+            validate_address(&self.chain, address)?;
+        }
+        Ok(())
+    }
+
+    /// The transfer body of the request, or why it can not be sent.
+    fn into_body(self) -> Result<TransferRequestBody, String> {
+        let invalid = |e: ConversionError| e.to_string();
+        let source = match self.asset {
+            SendAsset::Native => {
+                let asset = native_asset(&self.chain).ok_or_else(|| {
+                    format!(
+                        "The native asset of {} is not known, send it with SendAsset::Named",
+                        self.chain
+                    )
+                })?;
+                NamedTokenTransferSource {
+                    amount: self.amount.parse().map_err(invalid)?,
+                    asset: asset.to_string(),
+                    chain: self.chain,
+                }
+                .into()
+            }
+            SendAsset::Named(asset) => NamedTokenTransferSource {
+                amount: self.amount.parse().map_err(invalid)?,
+                asset,
+                chain: self.chain,
+            }
+            .into(),
+            SendAsset::Token(address) => CustomTokenTransferSource {
+                amount: self.amount.parse().map_err(invalid)?,
+                asset_address: CustomTokenTransferSourceAssetAddress::Variant0(address),
+                chain: self.chain,
+            }
+            .into(),
+        };
+
+        Ok(TransferRequestBody {
+            amount_type: None,
+            destination: TokenTransferDestination {
+                address: TokenTransferDestinationAddress::Variant0(self.to),
+                asset: None,
+                chain: None,
+            },
+            fee_configuration: None,
+            slippage_bps: None,
+            source,
+        })
+    }
+}
+
+impl PrivyClient {
+    /// Send native currency or tokens from a wallet.
+    ///
+    /// This covers Ethereum and EVM networks (native and ERC-20) as well as
+    /// Solana (native and SPL) through a single call. Privy builds, signs
+    /// and broadcasts the transaction, using the network's default fees.
+    /// See the [module docs](crate::send) for an example.
+    ///
+    /// # Errors
+    ///
+    /// Can fail if the amount is not a valid decimal string, if the native
+    /// asset of the chain is not known, with
+    /// `PrivySignedApiError::InvalidAddress` if the recipient or token
+    /// address is not valid on the chain, with
+    /// `PrivySignedApiError::Rejected` if one of the client's interceptors
+    /// rejects the transfer, if the authorization signature could not be
+    /// generated, or if the transfer is rejected by the Privy API.
+    pub async fn send(
+        &self,
+        wallet_id: impl Into<WalletId>,
+        request: SendRequest,
+        ctx: &AuthorizationContext,
+        idempotency_key: Option<&str>,
+    ) -> Result<SendResult, PrivySignedApiError> {
+        request.validate_addresses()?;
+        let body = request.into_body().map_err(Error::InvalidRequest)?;
+
+        let response = self
+            .wallets()
+            .transfer(wallet_id, ctx, idempotency_key, &body)
+            .await?;
+
+        Ok(response.into_inner().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use test_case::test_case;
+
+    use super::*;
+
+    fn request(chain: &str, asset: SendAsset) -> SendRequest {
+        SendRequest {
+            chain: chain.to_string(),
+            to: "recipient".to_string(),
+            amount: "1.5".to_string(),
+            asset,
+        }
+    }
+
+    #[test_case(request("base", SendAsset::Native) => json!({"amount": "1.5", "asset": "eth", "chain": "base"}) ; "evm native")]
+    #[test_case(request("polygon", SendAsset::Native) => json!({"amount": "1.5", "asset": "pol", "chain": "polygon"}) ; "polygon native")]
+    #[test_case(request("arbitrum-sepolia", SendAsset::Native) => json!({"amount": "1.5", "asset": "eth", "chain": "arbitrum-sepolia"}) ; "evm testnet native")]
+    #[test_case(request("solana", SendAsset::Native) => json!({"amount": "1.5", "asset": "sol", "chain": "solana"}) ; "solana native")]
+    #[test_case(request("base", SendAsset::Named("usdc".into())) => json!({"amount": "1.5", "asset": "usdc", "chain": "base"}) ; "named asset")]
+    #[test_case(request("solana", SendAsset::Token("mint".into())) => json!({"amount": "1.5", "asset_address": "mint", "chain": "solana"}) ; "custom token")]
+    fn test_send_request_source(request: SendRequest) -> serde_json::Value {
+        let body = request.into_body().unwrap();
+        assert_eq!(
+            serde_json::to_value(&body.destination).unwrap(),
+            json!({"address": "recipient"})
+        );
+        serde_json::to_value(&body.source).unwrap()
+    }
+
+    #[test]
+    fn test_native_asset_of_unknown_chain_is_an_error() {
+        let error = request("avalanche", SendAsset::Native)
+            .into_body()
+            .unwrap_err();
+        assert!(error.contains("avalanche"), "{error}");
+    }
+
+    #[test]
+    fn test_send_request_addresses_are_checked_against_the_chain() {
+        let mut request = request("base", SendAsset::Native);
+        request.to = "0xB00F0759DbeeF5E543Cc3E3B07A6442F5f3928a2".to_string();
+        assert!(request.validate_addresses().is_ok());
+
+        request.asset = SendAsset::Token("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".into());
+        let error = request.validate_addresses().unwrap_err();
+        assert_eq!(error.chain, "base");
+        assert_eq!(
+            error.address,
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+        );
+
+        request.chain = "solana".to_string();
+        assert!(request.validate_addresses().is_err());
+    }
+
+    #[test]
+    fn test_send_result_from_broadcast_response() {
+        let response: TransferActionResponse = serde_json::from_value(json!({
+            "id": "action_id",
+            "type": "transfer",
+            "status": "pending",
+            "wallet_id": "wallet",
+            "created_at": "2025-01-01T00:00:00Z",
+            "destination_address": "0xB00F0759DbeeF5E543Cc3E3B07A6442F5f3928a2",
+            "source_chain": "ethereum",
+            "steps": [{
+                "type": "evm_transaction",
+                "caip2": "eip155:1",
+                "status": "pending",
+                "transaction_hash": "0xabc"
+            }]
+        }))
+        .unwrap();
+
+        let result = SendResult::from(response);
+        assert_eq!(result.tx_id, "action_id");
+        assert_eq!(result.status, WalletActionStatus::Pending);
+        assert_eq!(result.tx_hash.as_deref(), Some("0xabc"));
+        assert_eq!(
+            result.explorer_url.as_deref(),
+            Some("https://etherscan.io/tx/0xabc")
+        );
+    }
+}
@@ -0,0 +1,346 @@
+//! Privy's service status, for telling Privy incidents apart from bugs.
+//!
+//! With [`ServiceStatusOptions`] set as `PrivyClientOptions::service_status`,
+//! [`PrivyClient::spawn_maintenance`] polls Privy's status page, and the
+//! latest status is available from [`PrivyClient::service_status`]:
+//!
+//! ```no_run
+//! # use privy_rs::{PrivyClient, client::PrivyClientOptions, service_status::ServiceStatusOptions};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_with_options(
+//!     "app_id".into(),
+//!     "app_secret".into(),
+//!     PrivyClientOptions {
+//!         service_status: Some(ServiceStatusOptions::default()),
+//!         ..Default::default()
+//!     },
+//! )?;
+//! let _tasks = client.spawn_maintenance(tokio::runtime::Handle::current());
+//!
+//! if let Some(status) = client.service_status() {
+//!     for incident in &status.incidents {
+//!         println!("{}: {} ({})", incident.impact, incident.name, incident.status);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! While Privy reports an incident or a maintenance in progress, calls that
+//! fail in a way an outage would explain, with a network failure, rate
+//! limiting or a `5xx` status, are logged at warn level along with the
+//! incidents, and carry them in `ApiErrorDetails::incidents`, which
+//! `hints` then leads with. Other failures, such as validation errors, are
+//! left as they are.
+//!
+//! The status page is fetched without the app's credentials.
+//!
+//! [`PrivyClient::spawn_maintenance`]: crate::PrivyClient::spawn_maintenance
+
+use std::{
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+use serde::Deserialize;
+
+use crate::{ErrorKind, PrivyApiError, PrivyApiErrorExt, PrivyClient, errors::ApiErrorDetails};
+
+/// The summary endpoint of Privy's status page.
+pub const DEFAULT_STATUS_URL: &str = "https://status.privy.io/api/v2/summary.json";
+
+/// How long to wait for the status page.
+const STATUS_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How the service status is polled, see the [module docs](self).
+#[derive(Clone, Debug)]
+pub struct ServiceStatusOptions {
+    /// The status page summary to poll, in the Statuspage v2 format.
+    pub url: String,
+    /// How often to poll it.
+    pub interval: Duration,
+}
+
+impl Default for ServiceStatusOptions {
+    fn default() -> Self {
+        Self {
+            url: DEFAULT_STATUS_URL.to_string(),
+            interval: Duration::from_secs(60),
+        }
+    }
+}
+
+/// How severely Privy is affected, as the status page rates it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusIndicator {
+    /// All systems are operational.
+    None,
+    /// A minor outage or degradation.
+    Minor,
+    /// A major outage.
+    Major,
+    /// A critical outage.
+    Critical,
+    /// A maintenance is in progress.
+    Maintenance,
+    /// A rating this SDK does not know.
+    #[serde(other)]
+    Unknown,
+}
+
+/// An incident, or a scheduled maintenance, on Privy's status page.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[non_exhaustive]
+pub struct Incident {
+    /// The id of the incident.
+    pub id: String,
+    /// The title of the incident.
+    pub name: String,
+    /// Where the incident is at, such as `investigating` or `monitoring`,
+    /// or, for maintenances, `scheduled` or `in_progress`.
+    pub status: String,
+    /// How severe the incident is, such as `minor` or `major`.
+    pub impact: String,
+    /// A link to the incident on the status page.
+    #[serde(default)]
+    pub shortlink: Option<String>,
+    /// When a maintenance is scheduled to start, as an RFC 3339 timestamp.
+    #[serde(default)]
+    pub scheduled_for: Option<String>,
+    /// When a maintenance is scheduled to end, as an RFC 3339 timestamp.
+    #[serde(default)]
+    pub scheduled_until: Option<String>,
+}
+
+/// Privy's service status, as last fetched from its status page.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct ServiceStatus {
+    /// How severely Privy is affected overall.
+    pub indicator: StatusIndicator,
+    /// The overall status, such as `All Systems Operational`.
+    pub description: String,
+    /// The incidents that are not resolved yet.
+    pub incidents: Vec<Incident>,
+    /// The maintenances that are scheduled or in progress.
+    pub maintenances: Vec<Incident>,
+    /// When the status was fetched.
+    pub fetched_at: SystemTime,
+}
+
+impl ServiceStatus {
+    /// The maintenances that are in progress.
+    pub fn maintenances_in_progress(&self) -> impl Iterator<Item = &Incident> {
+        self.maintenances
+            .iter()
+            .filter(|maintenance| maintenance.status == "in_progress")
+    }
+
+    /// The incidents, and maintenances in progress, that may explain a
+    /// failing call.
+    fn ongoing(&self) -> Vec<Incident> {
+        self.incidents
+            .iter()
+            .chain(self.maintenances_in_progress())
+            .cloned()
+            .collect()
+    }
+}
+
+/// The summary document of a Statuspage status page.
+#[derive(Deserialize)]
+struct Summary {
+    status: SummaryStatus,
+    #[serde(default)]
+    incidents: Vec<Incident>,
+    #[serde(default)]
+    scheduled_maintenances: Vec<Incident>,
+}
+
+#[derive(Deserialize)]
+struct SummaryStatus {
+    indicator: StatusIndicator,
+    description: String,
+}
+
+/// Polls and holds the service status of a client, shared by its clones
+/// and subclients. Does nothing unless the client has
+/// `PrivyClientOptions::service_status` set.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct StatusTracker(Option<Arc<Tracker>>);
+
+#[derive(Debug)]
+struct Tracker {
+    options: ServiceStatusOptions,
+    http: reqwest::Client,
+    latest: RwLock<Option<ServiceStatus>>,
+}
+
+impl StatusTracker {
+    pub(crate) fn new(options: Option<ServiceStatusOptions>) -> Self {
+        Self(options.map(|options| {
+            Arc::new(Tracker {
+                options,
+                http: reqwest::Client::builder()
+                    .timeout(STATUS_TIMEOUT)
+                    .build()
+                    .unwrap_or_default(),
+                latest: RwLock::new(None),
+            })
+        }))
+    }
+
+    /// How often to poll, `None` if polling is disabled.
+    pub(crate) fn interval(&self) -> Option<Duration> {
+        self.0.as_ref().map(|tracker| tracker.options.interval)
+    }
+
+    /// How the status is polled, `None` if polling is disabled.
+    pub(crate) fn options(&self) -> Option<&ServiceStatusOptions> {
+        self.0.as_ref().map(|tracker| &tracker.options)
+    }
+
+    fn latest(&self) -> Option<ServiceStatus> {
+        let tracker = self.0.as_ref()?;
+        tracker.latest.read().expect("lock poisoned").clone()
+    }
+
+    /// Fetch the status page, keeping the status as the latest. A failed
+    /// fetch is logged, and keeps the previous status.
+    pub(crate) async fn refresh(&self) {
+        let Some(tracker) = &self.0 else {
+            return;
+        };
+        let summary = match tracker.http.get(&tracker.options.url).send().await {
+            Ok(response) => match response.error_for_status() {
+                Ok(response) => response.json::<Summary>().await,
+                Err(e) => Err(e),
+            },
+            Err(e) => Err(e),
+        };
+        match summary {
+            Ok(summary) => {
+                let status = ServiceStatus {
+                    indicator: summary.status.indicator,
+                    description: summary.status.description,
+                    incidents: summary.incidents,
+                    maintenances: summary.scheduled_maintenances,
+                    fetched_at: SystemTime::now(),
+                };
+                *tracker.latest.write().expect("lock poisoned") = Some(status);
+            }
+            Err(e) => tracing::warn!("unable to fetch the Privy service status: {}", e),
+        }
+    }
+
+    /// Note the ongoing incidents, if any, on `error`, if it failed in a
+    /// way an outage would explain. See the [module docs](self).
+    pub(crate) fn annotate(&self, mut error: PrivyApiError) -> PrivyApiError {
+        if !matches!(
+            error.kind(),
+            ErrorKind::Network | ErrorKind::RateLimited | ErrorKind::Server
+        ) {
+            return error;
+        }
+        let Some(status) = self.latest() else {
+            return error;
+        };
+        let incidents = status.ongoing();
+        if incidents.is_empty() {
+            return error;
+        }
+
+        let names: Vec<_> = incidents.iter().map(|i| i.name.as_str()).collect();
+        tracing::warn!(
+            "request failed while Privy reports ongoing incidents ({}): {}",
+            names.join(", "),
+            error
+        );
+        if let PrivyApiError::UnexpectedResponse(response) = &mut error {
+            if let Some(details) = response.extensions_mut().get_mut::<ApiErrorDetails>() {
+                details.incidents = incidents;
+            }
+        }
+        error
+    }
+}
+
+impl PrivyClient {
+    /// Privy's service status, as last polled, or `None` if it is not
+    /// polled or was not fetched yet. See the
+    /// [`service_status`](crate::service_status) module.
+    pub fn service_status(&self) -> Option<ServiceStatus> {
+        self.shared.service_status.latest()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use super::*;
+    use crate::client::PrivyClientOptions;
+
+    #[tokio::test]
+    async fn test_incidents_are_surfaced_on_server_errors() {
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/api/v2/summary.json");
+                then.status(200).json_body(serde_json::json!({
+                    "page": { "id": "page" },
+                    "status": { "indicator": "major", "description": "Partial System Outage" },
+                    "incidents": [{
+                        "id": "inc_1",
+                        "name": "Elevated signing errors",
+                        "status": "investigating",
+                        "impact": "major",
+                        "shortlink": "https://stspg.io/1",
+                    }],
+                    "scheduled_maintenances": [],
+                }));
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.path("/v1/wallets/failing");
+                then.status(503);
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.path("/v1/wallets/missing");
+                then.status(404);
+            })
+            .await;
+
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                service_status: Some(ServiceStatusOptions {
+                    url: server.url("/api/v2/summary.json"),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(client.service_status(), None);
+
+        client.shared.service_status.refresh().await;
+        let status = client.service_status().unwrap();
+        assert_eq!(status.indicator, StatusIndicator::Major);
+        assert_eq!(status.incidents[0].name, "Elevated signing errors");
+
+        let error = client.wallets().get("failing").await.unwrap_err();
+        assert_eq!(error.details().unwrap().incidents, status.incidents);
+        assert!(error.hints()[0].contains("Elevated signing errors"));
+
+        // failures an outage would not explain are left alone
+        let error = client.wallets().get("missing").await.unwrap_err();
+        assert!(error.details().unwrap().incidents.is_empty());
+    }
+}
@@ -0,0 +1,307 @@
+//! Typed signatures from the `raw_sign` endpoint.
+//!
+//! [`RawSignResponse`] carries the signature as a hex string whose layout
+//! depends on the curve of the wallet that produced it. [`RawSignature`]
+//! splits it into its components, and converts into the signature types of
+//! the `p256` crate and, with the `alloy` and `solana_sdk` features, of
+//! alloy and the solana sdk.
+//!
+//! ```no_run
+//! # use privy_rs::{AuthorizationContext, PrivyClient, WalletId, generated::types::RawSignInput};
+//! use privy_rs::signature::{RawSignature, SignatureCurve};
+//!
+//! # async fn foo(body: RawSignInput) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let response = client
+//!     .wallets()
+//!     .raw_sign(WalletId::new("wallet_id"), &AuthorizationContext::new(), None, &body)
+//!     .await?;
+//!
+//! let RawSignature::Secp256k1 { r, s, v } =
+//!     RawSignature::parse(&response, SignatureCurve::Secp256k1)?
+//! else {
+//!     unreachable!()
+//! };
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use crate::{RawSignatureError, generated::types::RawSignResponse};
+
+/// The curve a raw signature was produced on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SignatureCurve {
+    /// ECDSA over secp256k1, used by ethereum and other EVM wallets.
+    Secp256k1,
+    /// EdDSA over curve25519, used by solana wallets.
+    Ed25519,
+    /// ECDSA over NIST P-256.
+    P256,
+}
+
+impl fmt::Display for SignatureCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Secp256k1 => "secp256k1",
+            Self::Ed25519 => "ed25519",
+            Self::P256 => "p256",
+        })
+    }
+}
+
+/// A signature returned by `raw_sign`, split into its components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RawSignature {
+    /// A recoverable ECDSA signature. `v` is returned as the API sent it,
+    /// which is either the y parity (`0` or `1`) or the legacy `27` or `28`.
+    Secp256k1 {
+        /// The `r` component, big-endian.
+        r: [u8; 32],
+        /// The `s` component, big-endian.
+        s: [u8; 32],
+        /// The recovery id.
+        v: u8,
+    },
+    /// An EdDSA signature.
+    Ed25519([u8; 64]),
+    /// An ECDSA signature.
+    P256 {
+        /// The `r` component, big-endian.
+        r: [u8; 32],
+        /// The `s` component, big-endian.
+        s: [u8; 32],
+    },
+}
+
+impl RawSignature {
+    /// Parse the signature in a `raw_sign` response produced on `curve`.
+    ///
+    /// # Errors
+    /// Fails if the signature is not hex, or has the wrong length or
+    /// recovery id for `curve`.
+    pub fn parse(
+        response: &RawSignResponse,
+        curve: SignatureCurve,
+    ) -> Result<Self, RawSignatureError> {
+        Self::from_hex(&response.data.signature, curve)
+    }
+
+    /// Parse a hex-encoded signature produced on `curve`, with or without a
+    /// `0x` prefix.
+    ///
+    /// # Errors
+    /// Fails if the signature is not hex, or has the wrong length or
+    /// recovery id for `curve`.
+    pub fn from_hex(signature: &str, curve: SignatureCurve) -> Result<Self, RawSignatureError> {
+        let bytes = hex::decode(signature.strip_prefix("0x").unwrap_or(signature))?;
+        let expected = match curve {
+            SignatureCurve::Secp256k1 => 65,
+            SignatureCurve::Ed25519 | SignatureCurve::P256 => 64,
+        };
+        if bytes.len() != expected {
+            return Err(RawSignatureError::Length {
+                curve,
+                expected,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut r = [0; 32];
+        let mut s = [0; 32];
+        r.copy_from_slice(&bytes[..32]);
+        s.copy_from_slice(&bytes[32..64]);
+
+        Ok(match curve {
+            SignatureCurve::Secp256k1 => {
+                let v = bytes[64];
+                if !matches!(v, 0 | 1 | 27 | 28) {
+                    return Err(RawSignatureError::RecoveryId(v));
+                }
+                Self::Secp256k1 { r, s, v }
+            }
+            SignatureCurve::Ed25519 => {
+                let mut signature = [0; 64];
+                signature.copy_from_slice(&bytes);
+                Self::Ed25519(signature)
+            }
+            SignatureCurve::P256 => Self::P256 { r, s },
+        })
+    }
+
+    /// The curve this signature was produced on.
+    #[must_use]
+    pub fn curve(&self) -> SignatureCurve {
+        match self {
+            Self::Secp256k1 { .. } => SignatureCurve::Secp256k1,
+            Self::Ed25519(_) => SignatureCurve::Ed25519,
+            Self::P256 { .. } => SignatureCurve::P256,
+        }
+    }
+
+    /// The signature in the layout the API returned it in: `r || s || v`
+    /// for secp256k1, and `r || s` otherwise.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Secp256k1 { r, s, v } => [r.as_slice(), s, &[*v]].concat(),
+            Self::Ed25519(signature) => signature.to_vec(),
+            Self::P256 { r, s } => [r.as_slice(), s].concat(),
+        }
+    }
+
+    fn mismatch(&self, expected: SignatureCurve) -> RawSignatureError {
+        RawSignatureError::CurveMismatch {
+            expected,
+            actual: self.curve(),
+        }
+    }
+}
+
+impl TryFrom<RawSignature> for p256::ecdsa::Signature {
+    type Error = RawSignatureError;
+
+    fn try_from(signature: RawSignature) -> Result<Self, Self::Error> {
+        match signature {
+            RawSignature::P256 { r, s } => {
+                Self::from_scalars(r, s).map_err(|_| RawSignatureError::InvalidScalar)
+            }
+            _ => Err(signature.mismatch(SignatureCurve::P256)),
+        }
+    }
+}
+
+#[cfg(feature = "alloy")]
+impl TryFrom<RawSignature> for alloy_primitives::Signature {
+    type Error = RawSignatureError;
+
+    fn try_from(signature: RawSignature) -> Result<Self, Self::Error> {
+        match signature {
+            RawSignature::Secp256k1 { r, s, v } => Ok(Self::new(
+                alloy_primitives::U256::from_be_bytes(r),
+                alloy_primitives::U256::from_be_bytes(s),
+                // 0 and 27 are even parity, 1 and 28 odd
+                v % 27 == 1,
+            )),
+            _ => Err(signature.mismatch(SignatureCurve::Secp256k1)),
+        }
+    }
+}
+
+#[cfg(feature = "solana_sdk")]
+impl TryFrom<RawSignature> for solana_transaction::Signature {
+    type Error = RawSignatureError;
+
+    fn try_from(signature: RawSignature) -> Result<Self, Self::Error> {
+        match signature {
+            RawSignature::Ed25519(bytes) => Ok(Self::from(bytes)),
+            _ => Err(signature.mismatch(SignatureCurve::Ed25519)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    fn hex_signature(len: usize, last: u8) -> String {
+        let mut bytes: Vec<u8> = (1..=len as u8).collect();
+        bytes[len - 1] = last;
+        format!("0x{}", hex::encode(bytes))
+    }
+
+    #[test_case(SignatureCurve::Secp256k1, 65 ; "secp256k1")]
+    #[test_case(SignatureCurve::Ed25519, 64 ; "ed25519")]
+    #[test_case(SignatureCurve::P256, 64 ; "p256")]
+    fn test_round_trip(curve: SignatureCurve, len: usize) {
+        let hex = hex_signature(len, 27);
+        let signature = RawSignature::from_hex(&hex, curve).unwrap();
+        assert_eq!(signature.curve(), curve);
+        assert_eq!(format!("0x{}", hex::encode(signature.to_bytes())), hex);
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let response: RawSignResponse = serde_json::from_value(serde_json::json!({
+            "method": "raw_sign",
+            "data": { "signature": hex_signature(65, 1), "encoding": "hex" },
+        }))
+        .unwrap();
+
+        let RawSignature::Secp256k1 { r, s, v } =
+            RawSignature::parse(&response, SignatureCurve::Secp256k1).unwrap()
+        else {
+            panic!("expected a secp256k1 signature");
+        };
+        assert_eq!(r[0], 1);
+        assert_eq!(s[0], 33);
+        assert_eq!(v, 1);
+    }
+
+    #[test]
+    fn test_rejects_malformed_signatures() {
+        assert!(matches!(
+            RawSignature::from_hex(&hex_signature(64, 0), SignatureCurve::Secp256k1),
+            Err(RawSignatureError::Length {
+                expected: 65,
+                actual: 64,
+                ..
+            })
+        ));
+        assert!(matches!(
+            RawSignature::from_hex(&hex_signature(65, 2), SignatureCurve::Secp256k1),
+            Err(RawSignatureError::RecoveryId(2))
+        ));
+        assert!(matches!(
+            RawSignature::from_hex("0xzz", SignatureCurve::Ed25519),
+            Err(RawSignatureError::Encoding(_))
+        ));
+    }
+
+    #[test]
+    fn test_into_p256() {
+        use p256::ecdsa::{SigningKey, signature::Signer};
+
+        let key = SigningKey::from_slice(&[7; 32]).unwrap();
+        let expected: p256::ecdsa::Signature = key.sign(b"message");
+
+        let raw = RawSignature::from_hex(&hex::encode(expected.to_bytes()), SignatureCurve::P256)
+            .unwrap();
+        assert_eq!(p256::ecdsa::Signature::try_from(raw).unwrap(), expected);
+
+        let ed25519 = RawSignature::Ed25519([1; 64]);
+        assert!(matches!(
+            p256::ecdsa::Signature::try_from(ed25519),
+            Err(RawSignatureError::CurveMismatch {
+                expected: SignatureCurve::P256,
+                actual: SignatureCurve::Ed25519,
+            })
+        ));
+    }
+
+    #[cfg(feature = "alloy")]
+    #[test_case(0, false ; "parity 0")]
+    #[test_case(1, true ; "parity 1")]
+    #[test_case(27, false ; "legacy 27")]
+    #[test_case(28, true ; "legacy 28")]
+    fn test_into_alloy(v: u8, parity: bool) {
+        let raw = RawSignature::Secp256k1 {
+            r: [1; 32],
+            s: [2; 32],
+            v,
+        };
+        let signature = alloy_primitives::Signature::try_from(raw).unwrap();
+        assert_eq!(signature.v(), parity);
+        assert_eq!(signature.r().to_be_bytes::<32>(), [1; 32]);
+    }
+
+    #[cfg(feature = "solana_sdk")]
+    #[test]
+    fn test_into_solana() {
+        let signature = solana_transaction::Signature::try_from(RawSignature::Ed25519([3; 64]));
+        assert_eq!(signature.unwrap().as_ref(), [3; 64].as_slice());
+    }
+}
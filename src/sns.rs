@@ -0,0 +1,404 @@
+//! SNS (`.sol`) name resolution for Solana recipients.
+//!
+//! An `SnsResolver` resolves Solana Name Service domains like `bonfida.sol`
+//! to the address of their current owner by reading the domain's name
+//! registry account through a Solana JSON-RPC endpoint of your choosing.
+//! Since Solana transactions are built before they are handed to the
+//! `SolanaService`, attach a resolver to the service and resolve the
+//! recipient while building the transaction:
+//!
+//! ```rust,no_run
+//! # use privy_rs::{PrivyClient, sns::SnsResolver};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let resolver = SnsResolver::new("https://api.mainnet-beta.solana.com").with_strict(true);
+//! let solana = client.wallets().solana().with_sns(resolver);
+//!
+//! let recipient = solana.resolve_recipient("bonfida.sol").await?;
+//! // build a transfer to `recipient` and pass it to `sign_and_send_transaction`
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Owners are read from the registry on every lookup that misses the cache,
+//! and a registry account is only trusted if it is owned by the name service
+//! program, is registered under the expected parent, and has a non-default
+//! owner. Tokenized domains resolve to the tokenizer's escrow, not the NFT
+//! holder.
+
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use sha2::{Digest, Sha256};
+
+use crate::NameResolutionError;
+
+/// The SPL name service program.
+const NAME_PROGRAM_ID: &str = "namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX";
+
+/// The registry account of the `.sol` top level domain.
+const SOL_TLD_AUTHORITY: &str = "58PwtjSDuFHuUkYjH9BYnnQKHfwo9reZhC2zMJv9JPkx";
+
+const HASH_PREFIX: &str = "SPL Name Service";
+
+/// The registry header is `parent_name`, `owner` and `class`, 32 bytes each.
+const HEADER_LEN: usize = 96;
+
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60);
+
+type Pubkey = [u8; 32];
+
+type SnsCache = lru::LruCache<String, (Instant, Option<String>)>;
+
+/// Resolves SNS domains to Solana addresses, caching the results.
+///
+/// Cloning an `SnsResolver` is cheap, and clones share the same cache.
+#[derive(Debug, Clone)]
+pub struct SnsResolver {
+    rpc_url: String,
+    http: reqwest::Client,
+    cache: Arc<Mutex<SnsCache>>,
+    cache_ttl: Duration,
+    strict: bool,
+}
+
+impl SnsResolver {
+    /// Create a new resolver that queries the given Solana JSON-RPC endpoint.
+    pub fn new(rpc_url: impl Into<String>) -> Self {
+        Self {
+            rpc_url: rpc_url.into(),
+            http: reqwest::Client::new(),
+            cache: Arc::new(Mutex::new(lru::LruCache::new(
+                NonZeroUsize::new(1000).expect("non-zero"),
+            ))),
+            cache_ttl: DEFAULT_CACHE_TTL,
+            strict: false,
+        }
+    }
+
+    /// In strict mode, resolving a recipient that cannot be resolved fails.
+    /// Otherwise, the name is passed through unchanged.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// How long resolved (and unresolvable) domains are cached for. Since
+    /// domains can change hands, this defaults to a short one minute.
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Resolve a `.sol` domain (or subdomain) to the base58 address of its
+    /// current owner. Returns `None` if the domain is not registered.
+    ///
+    /// # Errors
+    /// Fails if the rpc endpoint could not be reached or returned an
+    /// unexpected response, or if the registry account is not valid.
+    pub async fn resolve(&self, name: &str) -> Result<Option<String>, NameResolutionError> {
+        let name = name.to_lowercase();
+
+        {
+            let mut cache = self.cache.lock().expect("lock poisoned");
+            if let Some((resolved_at, address)) = cache.get(&name) {
+                if resolved_at.elapsed() < self.cache_ttl {
+                    return Ok(address.clone());
+                }
+                cache.pop(&name);
+            }
+        }
+
+        let (key, parent) = domain_key(&name).ok_or_else(|| {
+            NameResolutionError::InvalidResponse(format!("`{name}` is not a .sol domain"))
+        })?;
+        let owner = match self.get_account(&key).await? {
+            Some((program, data)) => Some(registry_owner(&program, &data, &parent)?),
+            None => None,
+        };
+
+        tracing::debug!("Resolved SNS name {} to {:?}", name, owner);
+
+        self.cache
+            .lock()
+            .expect("lock poisoned")
+            .put(name, (Instant::now(), owner.clone()));
+
+        Ok(owner)
+    }
+
+    /// Resolve a transaction recipient. Values that are not `.sol` domains
+    /// are returned as-is, and unresolvable domains are rejected in strict
+    /// mode.
+    pub(crate) async fn resolve_recipient(&self, to: &str) -> Result<String, NameResolutionError> {
+        if !is_sns_name(to) {
+            return Ok(to.to_owned());
+        }
+
+        match self.resolve(to).await {
+            Ok(Some(address)) => Ok(address),
+            Ok(None) if self.strict => Err(NameResolutionError::Unresolvable(to.to_owned())),
+            Err(e) if self.strict => Err(e),
+            Ok(None) | Err(_) => {
+                tracing::warn!("Unable to resolve SNS name {}, using it as-is", to);
+                Ok(to.to_owned())
+            }
+        }
+    }
+
+    /// Fetch an account, returning its owning program and data.
+    async fn get_account(
+        &self,
+        key: &Pubkey,
+    ) -> Result<Option<(String, Vec<u8>)>, NameResolutionError> {
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getAccountInfo",
+            "params": [bs58::encode(key).into_string(), { "encoding": "base64" }],
+        });
+
+        let response: serde_json::Value = self
+            .http
+            .post(&self.rpc_url)
+            .json(&body)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        if let Some(error) = response.get("error") {
+            return Err(NameResolutionError::InvalidResponse(error.to_string()));
+        }
+
+        let value = &response["result"]["value"];
+        if value.is_null() {
+            return Ok(None);
+        }
+
+        let invalid = || NameResolutionError::InvalidResponse(value.to_string());
+        let program = value["owner"].as_str().ok_or_else(invalid)?;
+        let data = value["data"][0].as_str().ok_or_else(invalid)?;
+        let data = STANDARD
+            .decode(data)
+            .map_err(|e| NameResolutionError::InvalidResponse(e.to_string()))?;
+
+        Ok(Some((program.to_owned(), data)))
+    }
+}
+
+/// Whether a recipient looks like an SNS domain rather than an address.
+fn is_sns_name(to: &str) -> bool {
+    to.to_lowercase().ends_with(".sol")
+}
+
+/// Extract the owner from a name registry account, checking that the
+/// account is a live registration under the expected parent.
+fn registry_owner(
+    program: &str,
+    data: &[u8],
+    parent: &Pubkey,
+) -> Result<String, NameResolutionError> {
+    if program != NAME_PROGRAM_ID {
+        return Err(NameResolutionError::InvalidResponse(format!(
+            "registry account is owned by {program}, not the name service"
+        )));
+    }
+
+    if data.len() < HEADER_LEN {
+        return Err(NameResolutionError::InvalidResponse(
+            "registry account is too small".to_string(),
+        ));
+    }
+
+    if &data[..32] != parent {
+        return Err(NameResolutionError::InvalidResponse(
+            "registry account has an unexpected parent".to_string(),
+        ));
+    }
+
+    let owner = &data[32..64];
+    if owner.iter().all(|b| *b == 0) {
+        return Err(NameResolutionError::InvalidResponse(
+            "registry account has no owner".to_string(),
+        ));
+    }
+
+    Ok(bs58::encode(owner).into_string())
+}
+
+/// Derive the registry account of a `.sol` domain or subdomain, returning
+/// it along with the registry account of its parent.
+fn domain_key(name: &str) -> Option<(Pubkey, Pubkey)> {
+    let tld = decode_pubkey(SOL_TLD_AUTHORITY);
+    let labels: Vec<_> = name.strip_suffix(".sol")?.split('.').collect();
+
+    match labels.as_slice() {
+        [domain] if !domain.is_empty() => Some((name_account_key(domain, &tld), tld)),
+        [sub, domain] if !sub.is_empty() && !domain.is_empty() => {
+            let parent = name_account_key(domain, &tld);
+            Some((name_account_key(&format!("\0{sub}"), &parent), parent))
+        }
+        _ => None,
+    }
+}
+
+fn name_account_key(name: &str, parent: &Pubkey) -> Pubkey {
+    let hashed_name = Sha256::digest(format!("{HASH_PREFIX}{name}"));
+    find_program_address(
+        &[&hashed_name, &[0u8; 32], parent],
+        &decode_pubkey(NAME_PROGRAM_ID),
+    )
+}
+
+/// Solana's `find_program_address`: the first bump seed, counting down,
+/// that produces an address off the ed25519 curve.
+fn find_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Pubkey {
+    for bump in (0..=u8::MAX).rev() {
+        let mut hasher = Sha256::new();
+        for seed in seeds {
+            hasher.update(seed);
+        }
+        hasher.update([bump]);
+        hasher.update(program_id);
+        hasher.update(b"ProgramDerivedAddress");
+        let candidate: Pubkey = hasher.finalize().into();
+
+        if CompressedEdwardsY(candidate).decompress().is_none() {
+            return candidate;
+        }
+    }
+
+    unreachable!("a valid bump seed is found with overwhelming probability")
+}
+
+fn decode_pubkey(key: &str) -> Pubkey {
+    let mut out = [0u8; 32];
+    bs58::decode(key)
+        .onto(&mut out)
+        .expect("constant is a valid pubkey");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test]
+    fn test_domain_key() {
+        let (key, parent) = domain_key("bonfida.sol").unwrap();
+        assert_eq!(
+            bs58::encode(key).into_string(),
+            "Crf8hzfthWGbGbLTVCiqRqV5MVnbpHB1L9KQMd6gsinb"
+        );
+        assert_eq!(parent, decode_pubkey(SOL_TLD_AUTHORITY));
+    }
+
+    #[test_case("bonfida.sol" => true ; "domain")]
+    #[test_case("dex.bonfida.sol" => true ; "subdomain")]
+    #[test_case("HN7cABqLq46Es1jh92dQQisAq662SmxELLLsHHe4YWrH" => false ; "address")]
+    fn test_is_sns_name(to: &str) -> bool {
+        is_sns_name(to)
+    }
+
+    #[test_case("sol" => None ; "bare tld")]
+    #[test_case(".sol" => None ; "empty domain")]
+    #[test_case("a.b.c.sol" => None ; "too deep")]
+    fn test_domain_key_invalid(name: &str) -> Option<(Pubkey, Pubkey)> {
+        domain_key(name)
+    }
+
+    fn registry(parent: &Pubkey, owner: &Pubkey) -> Vec<u8> {
+        [parent.as_slice(), owner.as_slice(), &[0u8; 32]].concat()
+    }
+
+    #[test]
+    fn test_registry_owner_validation() {
+        let parent = decode_pubkey(SOL_TLD_AUTHORITY);
+        let owner = [7u8; 32];
+
+        assert_eq!(
+            registry_owner(NAME_PROGRAM_ID, &registry(&parent, &owner), &parent).unwrap(),
+            bs58::encode(owner).into_string()
+        );
+        assert!(
+            registry_owner(
+                "11111111111111111111111111111111",
+                &registry(&parent, &owner),
+                &parent
+            )
+            .is_err(),
+            "accounts not owned by the name service are rejected"
+        );
+        assert!(
+            registry_owner(NAME_PROGRAM_ID, &registry(&[1u8; 32], &owner), &parent).is_err(),
+            "accounts under another parent are rejected"
+        );
+        assert!(
+            registry_owner(NAME_PROGRAM_ID, &registry(&parent, &[0u8; 32]), &parent).is_err(),
+            "accounts without an owner are rejected"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_recipient() {
+        let server = MockServer::start_async().await;
+        let parent = decode_pubkey(SOL_TLD_AUTHORITY);
+        let owner = [7u8; 32];
+
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .body_includes("Crf8hzfthWGbGbLTVCiqRqV5MVnbpHB1L9KQMd6gsinb");
+                then.status(200).json_body(serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "result": { "value": {
+                        "owner": NAME_PROGRAM_ID,
+                        "data": [STANDARD.encode(registry(&parent, &owner)), "base64"],
+                    }}
+                }));
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST);
+                then.status(200).json_body(serde_json::json!({
+                    "jsonrpc": "2.0", "id": 1, "result": { "value": null }
+                }));
+            })
+            .await;
+
+        let resolver = SnsResolver::new(server.base_url()).with_strict(true);
+        assert_eq!(
+            resolver.resolve_recipient("bonfida.sol").await.unwrap(),
+            bs58::encode(owner).into_string()
+        );
+        assert_eq!(
+            resolver.resolve_recipient("Bonfida.sol").await.unwrap(),
+            bs58::encode(owner).into_string()
+        );
+        mock.assert_calls_async(1).await;
+
+        assert!(matches!(
+            resolver.resolve_recipient("unregistered.sol").await,
+            Err(NameResolutionError::Unresolvable(_))
+        ));
+        assert_eq!(
+            SnsResolver::new(server.base_url())
+                .resolve_recipient("unregistered.sol")
+                .await
+                .unwrap(),
+            "unregistered.sol"
+        );
+    }
+}
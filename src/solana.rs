@@ -7,8 +7,13 @@
 
 use std::str::FromStr;
 
+#[cfg(feature = "solana_sdk")]
+pub mod fee_payer;
+#[cfg(feature = "solana_sdk")]
+pub mod lookup_table;
+
 use crate::{
-    AuthorizationContext, PrivySignedApiError,
+    AuthorizationContext, PrivySignedApiError, SimulationError, WalletId,
     generated::{
         Error, ResponseValue,
         types::{
@@ -44,6 +49,11 @@ pub struct SignAndSendTransactionOptions {
     /// - `Some(false)` — explicitly disable gas sponsorship
     /// - `None` — use the server default
     pub sponsor: Option<bool>,
+    /// A Solana JSON-RPC endpoint to simulate the transaction with before
+    /// sending it. If the simulation fails, the transaction is not sent.
+    /// - `Some(url)` — simulate with `simulateTransaction` on `url`
+    /// - `None` — send without simulating
+    pub simulate_rpc_url: Option<String>,
 }
 
 impl SignAndSendTransactionOptions {
@@ -57,6 +67,55 @@ impl SignAndSendTransactionOptions {
         self.sponsor = Some(sponsor);
         self
     }
+
+    /// Simulates the transaction on the given rpc endpoint before sending.
+    pub fn with_simulation(mut self, rpc_url: impl Into<String>) -> Self {
+        self.simulate_rpc_url = Some(rpc_url.into());
+        self
+    }
+}
+
+/// Simulate a base64-encoded transaction with `simulateTransaction`.
+///
+/// The transaction is not signed yet, so signatures are not verified.
+async fn simulate(rpc_url: &str, transaction: &str) -> Result<(), SimulationError> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "simulateTransaction",
+        "params": [transaction, { "encoding": "base64", "sigVerify": false }],
+    });
+
+    let response: serde_json::Value = crate::utils::RPC_HTTP
+        .post(rpc_url)
+        .json(&body)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    if let Some(error) = response.get("error") {
+        return Err(SimulationError::InvalidResponse(error.to_string()));
+    }
+
+    let value = &response["result"]["value"];
+    if !value.is_object() {
+        return Err(SimulationError::InvalidResponse(response.to_string()));
+    }
+
+    match &value["err"] {
+        serde_json::Value::Null => Ok(()),
+        err => Err(SimulationError::Failed {
+            err: err.clone(),
+            logs: value["logs"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|log| log.as_str().map(str::to_owned))
+                .collect(),
+        }),
+    }
 }
 
 /// Service for Solana-specific wallet operations.
@@ -75,7 +134,7 @@ impl SignAndSendTransactionOptions {
 ///
 /// ```rust,no_run
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-/// use privy_rs::{AuthorizationContext, PrivyClient};
+/// use privy_rs::{AuthorizationContext, PrivyClient, WalletId};
 ///
 /// let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
 /// let solana_service = client.wallets().solana();
@@ -84,7 +143,7 @@ impl SignAndSendTransactionOptions {
 /// // Sign a Base64-encoded message
 /// let result = solana_service
 ///     .sign_message(
-///         "wallet_id",
+///         WalletId::new("wallet_id"),
 ///         "SGVsbG8sIFNvbGFuYSE=", // "Hello, Solana!" in Base64
 ///         &auth_ctx,
 ///         None, // no idempotency key
@@ -95,6 +154,11 @@ impl SignAndSendTransactionOptions {
 /// ```
 pub struct SolanaService {
     wallets_client: crate::subclients::WalletsClient,
+    context: Option<AuthorizationContext>,
+    #[cfg(feature = "sns")]
+    sns: Option<crate::sns::SnsResolver>,
+    #[cfg(feature = "solana_sdk")]
+    fee_payer: Option<fee_payer::FeePayer>,
 }
 
 impl SolanaService {
@@ -102,7 +166,69 @@ impl SolanaService {
     ///
     /// This is typically called internally by `WalletsClient::solana()`.
     pub(crate) fn new(wallets_client: crate::subclients::WalletsClient) -> Self {
-        Self { wallets_client }
+        Self {
+            wallets_client,
+            context: None,
+            #[cfg(feature = "sns")]
+            sns: None,
+            #[cfg(feature = "solana_sdk")]
+            fee_payer: None,
+        }
+    }
+
+    /// Bind a default authorization context, used by every call on this
+    /// service that is passed `None` in place of one.
+    ///
+    /// ```rust,no_run
+    /// # use privy_rs::{AuthorizationContext, PrivyClient, WalletId};
+    /// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PrivyClient::new_from_env()?;
+    /// let ctx = AuthorizationContext::new();
+    /// let other_ctx = AuthorizationContext::new();
+    ///
+    /// let solana = client.wallets().solana().with_context(ctx);
+    /// solana
+    ///     .sign_message(WalletId::new("wallet_id"), "aGVsbG8=", None, None)
+    ///     .await?;
+    /// // a call can still override it
+    /// solana
+    ///     .sign_message(WalletId::new("other_wallet_id"), "aGVsbG8=", &other_ctx, None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_context(mut self, ctx: AuthorizationContext) -> Self {
+        self.context = Some(ctx);
+        self
+    }
+
+    /// Resolve SNS (`.sol`) domains passed to `resolve_recipient`. See the
+    /// [`sns`](crate::sns) module for details.
+    ///
+    /// # Feature Flag
+    /// Requires the `sns` feature to be enabled.
+    #[cfg(feature = "sns")]
+    pub fn with_sns(mut self, resolver: crate::sns::SnsResolver) -> Self {
+        self.sns = Some(resolver);
+        self
+    }
+
+    /// Resolve the recipient of a transfer to a base58 address. `.sol`
+    /// domains are resolved to their current owner if a resolver was
+    /// attached with `with_sns`, and anything else is returned unchanged.
+    ///
+    /// # Feature Flag
+    /// Requires the `sns` feature to be enabled.
+    ///
+    /// # Errors
+    /// In strict mode, fails if the domain cannot be resolved.
+    #[cfg(feature = "sns")]
+    pub async fn resolve_recipient(&self, to: &str) -> Result<String, crate::NameResolutionError> {
+        match &self.sns {
+            Some(sns) => sns.resolve_recipient(to).await,
+            None => Ok(to.to_owned()),
+        }
     }
 
     /// Signs a Base64 encoded message for a Solana wallet.
@@ -116,7 +242,8 @@ impl SolanaService {
     ///
     /// * `wallet_id` - The ID of the wallet to use for signing
     /// * `message` - The message string to be signed (expected to be Base64 encoded)
-    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing
+    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing,
+    ///   or `None` to use the one bound with `with_context`
     /// * `idempotency_key` - Optional idempotency key for the request to prevent duplicate operations
     ///
     /// # Returns
@@ -127,7 +254,7 @@ impl SolanaService {
     ///
     /// ```rust,no_run
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// use privy_rs::{AuthorizationContext, PrivyClient};
+    /// use privy_rs::{AuthorizationContext, PrivyClient, WalletId};
     ///
     /// let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
     /// let solana_service = client.wallets().solana();
@@ -137,7 +264,7 @@ impl SolanaService {
     /// let message = base64::encode("Hello, Solana!");
     /// let signature = solana_service
     ///     .sign_message(
-    ///         "clz2rqy4500061234abcd1234",
+    ///         WalletId::new("clz2rqy4500061234abcd1234"),
     ///         &message,
     ///         &auth_ctx,
     ///         Some("unique-request-id-456"),
@@ -165,11 +292,13 @@ impl SolanaService {
     /// decoded message bytes.
     pub async fn sign_message(
         &self,
-        wallet_id: &str,
+        wallet_id: impl Into<WalletId>,
         message: &str,
-        authorization_context: &AuthorizationContext,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
         idempotency_key: Option<&str>,
     ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
         let rpc_body = WalletRpcRequestBody::SolanaSignMessageRpcInput(SolanaSignMessageRpcInput {
             address: None,
             chain_type: None,
@@ -198,7 +327,8 @@ impl SolanaService {
     ///
     /// * `wallet_id` - The ID of the wallet to use for signing
     /// * `transaction` - The transaction string to be signed (expected to be Base64 encoded)
-    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing
+    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing,
+    ///   or `None` to use the one bound with `with_context`
     /// * `idempotency_key` - Optional idempotency key for the request
     ///
     /// # Returns
@@ -209,7 +339,7 @@ impl SolanaService {
     ///
     /// ```rust,no_run
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// use privy_rs::{AuthorizationContext, PrivyClient};
+    /// use privy_rs::{AuthorizationContext, PrivyClient, WalletId};
     ///
     /// let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
     /// let solana_service = client.wallets().solana();
@@ -219,7 +349,7 @@ impl SolanaService {
     /// let transaction = "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAEDArczbMia1tLmq7zz4DinMNN0pJ1JtLdqIJPUw3YrGCzYAMHBsgN27lcgB6H2WQvFgyZuJYHa46puOQo9yQ8CVQbd9uHXZaGT2cvhRs7reawctIXtX1s3kTqM9YV+/wCp20C7Wj2aiuk5TReAXo+VTVg8QTHjs0UjNMMKCvpzZ+ABAgEBARU=";
     ///
     /// let signed_tx = solana_service.sign_transaction(
-    ///     "clz2rqy4500061234abcd1234",
+    ///     WalletId::new("clz2rqy4500061234abcd1234"),
     ///     transaction,
     ///     &auth_ctx,
     ///     None
@@ -237,11 +367,18 @@ impl SolanaService {
     /// - This method only signs the transaction; use `sign_and_send_transaction` to also broadcast it
     pub async fn sign_transaction(
         &self,
-        wallet_id: &str,
+        wallet_id: impl Into<WalletId>,
         transaction: &str,
-        authorization_context: &AuthorizationContext,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
         idempotency_key: Option<&str>,
     ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
+        #[cfg(feature = "solana_sdk")]
+        let paid = self.pay_fees(transaction, None)?;
+        #[cfg(feature = "solana_sdk")]
+        let transaction = paid.as_deref().unwrap_or(transaction);
+
         let rpc_body =
             WalletRpcRequestBody::SolanaSignTransactionRpcInput(SolanaSignTransactionRpcInput {
                 address: None,
@@ -271,7 +408,8 @@ impl SolanaService {
     /// * `wallet_id` - The ID of the wallet used for the transaction
     /// * `caip2` - The CAIP-2 chain ID of the Solana network (e.g., "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp" for mainnet-beta)
     /// * `transaction` - The transaction string to be signed and sent (expected to be Base64 encoded)
-    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing
+    /// * `authorization_context` - The authorization context containing JWT or private keys for request signing,
+    ///   or `None` to use the one bound with `with_context`
     /// * `idempotency_key` - Optional idempotency key for the request
     ///
     /// # Returns
@@ -282,7 +420,7 @@ impl SolanaService {
     ///
     /// ```rust,no_run
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// use privy_rs::{AuthorizationContext, PrivyClient};
+    /// use privy_rs::{AuthorizationContext, PrivyClient, WalletId};
     ///
     /// let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
     /// let solana_service = client.wallets().solana();
@@ -292,7 +430,7 @@ impl SolanaService {
     /// let transaction = "AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAEDRpb0mdmKftapwzzqUtlcDnuWbw8vwlyiyuWyyieQFKESezu52HWNss0SAcb60ftz7DSpgTwUmfUSl1CYHJ91GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAScgJ7J0AXFr1azCEvB1Y5zpiF4eXR+yTW0UB7am+E/MBAgIAAQwCAAAAQEIPAAAAAAA=";
     ///
     /// let result = solana_service.sign_and_send_transaction(
-    ///     "clz2rqy4500061234abcd1234",
+    ///     WalletId::new("clz2rqy4500061234abcd1234"),
     ///     "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp", // Solana mainnet-beta
     ///     transaction,
     ///     &auth_ctx,
@@ -325,12 +463,14 @@ impl SolanaService {
     ///   - "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1" (devnet)
     pub async fn sign_and_send_transaction(
         &self,
-        wallet_id: &str,
+        wallet_id: impl Into<WalletId>,
         caip2: &str,
         transaction: &str,
-        authorization_context: &AuthorizationContext,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
         idempotency_key: Option<&str>,
     ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
         self.sign_and_send_transaction_with_options(
             wallet_id,
             caip2,
@@ -342,7 +482,8 @@ impl SolanaService {
         .await
     }
 
-    /// Signs and sends a Solana transaction with additional options such as gas sponsorship.
+    /// Signs and sends a Solana transaction with additional options such as gas sponsorship
+    /// or simulating the transaction first.
     ///
     /// This method is identical to [`sign_and_send_transaction`](Self::sign_and_send_transaction)
     /// but accepts a [`SignAndSendTransactionOptions`] struct for additional configuration.
@@ -351,7 +492,7 @@ impl SolanaService {
     ///
     /// ```rust,no_run
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// use privy_rs::{AuthorizationContext, PrivyClient, solana::SignAndSendTransactionOptions};
+    /// use privy_rs::{AuthorizationContext, PrivyClient, WalletId, solana::SignAndSendTransactionOptions};
     ///
     /// let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
     /// let solana_service = client.wallets().solana();
@@ -360,7 +501,7 @@ impl SolanaService {
     /// let options = SignAndSendTransactionOptions::new().with_sponsor(true);
     ///
     /// let result = solana_service.sign_and_send_transaction_with_options(
-    ///     "wallet_id",
+    ///     WalletId::new("wallet_id"),
     ///     "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp",
     ///     "base64-encoded-transaction",
     ///     &auth_ctx,
@@ -372,15 +513,25 @@ impl SolanaService {
     /// ```
     pub async fn sign_and_send_transaction_with_options(
         &self,
-        wallet_id: &str,
+        wallet_id: impl Into<WalletId>,
         caip2: &str,
         transaction: &str,
-        authorization_context: &AuthorizationContext,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
         idempotency_key: Option<&str>,
         options: &SignAndSendTransactionOptions,
     ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
         let caip2_parsed = Caip2::from_str(caip2)
             .map_err(|_| Error::InvalidRequest("Invalid CAIP-2 format".to_string()))?;
+        #[cfg(feature = "solana_sdk")]
+        let paid = self.pay_fees(transaction, Some(options))?;
+        #[cfg(feature = "solana_sdk")]
+        let transaction = paid.as_deref().unwrap_or(transaction);
+
+        if let Some(rpc_url) = &options.simulate_rpc_url {
+            simulate(rpc_url, transaction).await?;
+        }
 
         let rpc_body = WalletRpcRequestBody::SolanaSignAndSendTransactionRpcInput(
             SolanaSignAndSendTransactionRpcInput {
@@ -404,4 +555,303 @@ impl SolanaService {
             .rpc(wallet_id, authorization_context, idempotency_key, &rpc_body)
             .await
     }
+
+    /// Adds this wallet's signature to a transaction that is also signed by
+    /// other parties, such as a local fee payer.
+    ///
+    /// The serialized message is signed with `signMessage`, and the signature
+    /// is written to the slot matching the wallet's position among the
+    /// message's required signers. All other signature slots are left as
+    /// they are, so it does not matter whether the other parties sign before
+    /// or after this call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn example(
+    /// #     mut transaction: solana_transaction::versioned::VersionedTransaction,
+    /// # ) -> Result<(), Box<dyn std::error::Error>> {
+    /// use privy_rs::{AuthorizationContext, PrivyClient, WalletId};
+    ///
+    /// let client = PrivyClient::new("app_id".to_string(), "app_secret".to_string())?;
+    /// let ctx = AuthorizationContext::new();
+    ///
+    /// client
+    ///     .wallets()
+    ///     .solana()
+    ///     .partial_sign(WalletId::new("clz2rqy4500061234abcd1234"), &mut transaction, &ctx, None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The wallet cannot be fetched, or its address is not a required signer
+    ///   of the transaction
+    /// - The authorization context is invalid
+    /// - The signing operation fails on the server
+    #[cfg(feature = "solana_sdk")]
+    pub async fn partial_sign(
+        &self,
+        wallet_id: impl Into<WalletId>,
+        transaction: &mut solana_transaction::versioned::VersionedTransaction,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
+        idempotency_key: Option<&str>,
+    ) -> Result<(), PrivySignedApiError> {
+        let authorization_context =
+            AuthorizationContext::or_bound(authorization_context.into(), self.context.as_ref())?;
+        use base64::{Engine, engine::general_purpose::STANDARD};
+
+        let wallet_id = wallet_id.into();
+        let wallet = self.wallets_client.get(&wallet_id).await?.into_inner();
+        let address: [u8; 32] = bs58::decode(&wallet.address)
+            .into_vec()
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| {
+                Error::InvalidRequest(format!("Invalid wallet address: {}", wallet.address))
+            })?;
+
+        let num_signers = usize::from(transaction.message.header().num_required_signatures);
+        let index = transaction
+            .message
+            .static_account_keys()
+            .iter()
+            .take(num_signers)
+            .position(|key| key.as_array() == &address)
+            .ok_or_else(|| {
+                Error::InvalidRequest(format!(
+                    "Wallet {} is not a required signer of the transaction",
+                    wallet.address
+                ))
+            })?;
+
+        let message = STANDARD.encode(transaction.message.serialize());
+        let signature = match self
+            .sign_message(&wallet_id, &message, authorization_context, idempotency_key)
+            .await?
+            .into_inner()
+        {
+            WalletRpcResponse::SolanaSignMessageRpcResponse(response) => response.data.signature,
+            other => {
+                return Err(Error::InvalidRequest(format!(
+                    "Unexpected response to signMessage: {other:?}"
+                ))
+                .into());
+            }
+        };
+        let signature: [u8; 64] = STANDARD
+            .decode(&signature)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| Error::InvalidRequest(format!("Invalid signature: {signature}")))?;
+
+        if transaction.signatures.len() < num_signers {
+            transaction
+                .signatures
+                .resize(num_signers, solana_transaction::Signature::default());
+        }
+        transaction.signatures[index] = signature.into();
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "solana_sdk"))]
+mod tests {
+    use base64::{Engine, engine::general_purpose::STANDARD};
+    use httpmock::prelude::*;
+    use solana_sdk::{
+        hash::Hash,
+        message::{Message, VersionedMessage},
+        signature::{Keypair, Signer},
+        transaction::VersionedTransaction,
+    };
+    use solana_system_interface::instruction::transfer;
+
+    use crate::{
+        AuthorizationContext, PrivyClient, WalletId, client::PrivyClientOptions,
+        solana::fee_payer::FeePayer,
+    };
+
+    #[tokio::test]
+    async fn test_partial_sign_fills_wallet_slot() {
+        let fee_payer = Keypair::new();
+        let privy_wallet = Keypair::new();
+
+        let message = Message::new_with_blockhash(
+            &[transfer(&privy_wallet.pubkey(), &fee_payer.pubkey(), 1)],
+            Some(&fee_payer.pubkey()),
+            &Hash::default(),
+        );
+        let message = VersionedMessage::Legacy(message);
+        let mut transaction = VersionedTransaction {
+            signatures: vec![],
+            message: message.clone(),
+        };
+        let fee_payer_signature = fee_payer.sign_message(&message.serialize());
+        transaction.signatures = vec![fee_payer_signature, Default::default()];
+        let wallet_signature = privy_wallet.sign_message(&message.serialize());
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/wallets/wallet_id");
+                then.status(200).json_body(serde_json::json!({
+                    "id": "wallet_id",
+                    "address": privy_wallet.pubkey().to_string(),
+                    "chain_type": "solana",
+                    "policy_ids": [],
+                    "additional_signers": [],
+                    "created_at": 0,
+                    "exported_at": null,
+                    "imported_at": null,
+                    "owner_id": null,
+                }));
+            })
+            .await;
+        let rpc = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/wallets/wallet_id/rpc")
+                    .json_body_includes(
+                        serde_json::json!({
+                            "params": { "message": STANDARD.encode(message.serialize()) }
+                        })
+                        .to_string(),
+                    );
+                then.status(200).json_body(serde_json::json!({
+                    "method": "signMessage",
+                    "data": {
+                        "signature": STANDARD.encode(wallet_signature.as_ref()),
+                        "encoding": "base64",
+                    },
+                }));
+            })
+            .await;
+
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        client
+            .wallets()
+            .solana()
+            .partial_sign(
+                WalletId::new("wallet_id"),
+                &mut transaction,
+                &AuthorizationContext::new(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        rpc.assert_async().await;
+        assert_eq!(transaction.signatures[0], fee_payer_signature);
+        assert_eq!(transaction.signatures[1], wallet_signature);
+    }
+
+    #[tokio::test]
+    async fn test_fee_payer_signs_before_privy() {
+        let fee_payer = Keypair::new();
+        let privy_wallet = Keypair::new();
+
+        let message = VersionedMessage::Legacy(Message::new_with_blockhash(
+            &[transfer(&privy_wallet.pubkey(), &fee_payer.pubkey(), 1)],
+            Some(&fee_payer.pubkey()),
+            &Hash::default(),
+        ));
+        let unsigned = VersionedTransaction {
+            signatures: vec![],
+            message: message.clone(),
+        };
+        let mut expected = unsigned.clone();
+        expected.signatures = vec![
+            fee_payer.sign_message(&message.serialize()),
+            Default::default(),
+        ];
+
+        let server = MockServer::start_async().await;
+        let rpc = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/wallets/wallet_id/rpc")
+                    .json_body_includes(
+                    serde_json::json!({
+                        "params": {
+                            "transaction": STANDARD.encode(bincode::serialize(&expected).unwrap())
+                        }
+                    })
+                    .to_string(),
+                );
+                then.status(200).json_body(serde_json::json!({
+                    "method": "signTransaction",
+                    "data": { "signed_transaction": "", "encoding": "base64" },
+                }));
+            })
+            .await;
+
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let solana = client
+            .wallets()
+            .solana()
+            .with_fee_payer(FeePayer::new(fee_payer));
+        let ctx = AuthorizationContext::new();
+
+        solana
+            .sign_transaction(
+                WalletId::new("wallet_id"),
+                &STANDARD.encode(bincode::serialize(&unsigned).unwrap()),
+                &ctx,
+                None,
+            )
+            .await
+            .unwrap();
+        rpc.assert_async().await;
+
+        // the wallet, not the fee payer, pays for this one
+        let message = VersionedMessage::Legacy(Message::new_with_blockhash(
+            &[transfer(
+                &privy_wallet.pubkey(),
+                &Keypair::new().pubkey(),
+                1,
+            )],
+            Some(&privy_wallet.pubkey()),
+            &Hash::default(),
+        ));
+        let misordered = VersionedTransaction {
+            signatures: vec![],
+            message,
+        };
+        let error = solana
+            .sign_transaction(
+                WalletId::new("wallet_id"),
+                &STANDARD.encode(bincode::serialize(&misordered).unwrap()),
+                &ctx,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(
+            format!("{error:?}").contains("not the first signer"),
+            "{error:?}"
+        );
+        rpc.assert_calls_async(1).await;
+    }
 }
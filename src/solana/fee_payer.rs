@@ -0,0 +1,138 @@
+//! Paying transaction fees with a local keypair instead of Privy sponsorship.
+//!
+//! A Solana transaction's fees are paid by its first account, which must
+//! also sign it. Apps that do not use Privy's gas sponsorship often keep a
+//! separate fee payer keypair, so the Privy wallet does not need to hold
+//! SOL. A [`FeePayer`] bound to a `SolanaService` with
+//! [`SolanaService::with_fee_payer`] signs every transaction passed to
+//! `sign_transaction` and `sign_and_send_transaction` before it is sent to
+//! Privy for the wallet's signature:
+//!
+//! ```no_run
+//! # use privy_rs::{AuthorizationContext, PrivyClient, WalletId, solana::fee_payer::FeePayer};
+//! # async fn foo(
+//! #     keypair: impl solana_signer::Signer + Send + Sync + 'static,
+//! #     transaction: &str,
+//! # ) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let solana = client
+//!     .wallets()
+//!     .solana()
+//!     .with_fee_payer(FeePayer::new(keypair));
+//!
+//! solana
+//!     .sign_and_send_transaction(
+//!         WalletId::new("wallet_id"),
+//!         "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1",
+//!         transaction,
+//!         &AuthorizationContext::new(),
+//!         None,
+//!     )
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The signatures cover the message only, so the fee payer and the wallet
+//! can sign in either order; the fee payer signs first so that the
+//! transaction Privy broadcasts is complete. Before signing, the message
+//! header is checked: the fee payer must be the first account, and a
+//! required signer. Transactions with a fee payer cannot also be sponsored.
+
+use std::{fmt, sync::Arc};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use solana_signer::Signer;
+use solana_transaction::{Signature, versioned::VersionedTransaction};
+
+use crate::{
+    FeePayerError,
+    solana::{SignAndSendTransactionOptions, SolanaService},
+};
+
+/// A local signer that pays the fees of transactions signed through a
+/// `SolanaService`. See the [module docs](self).
+///
+/// Cloning is cheap, and clones share the signer.
+#[derive(Clone)]
+pub struct FeePayer(Arc<dyn Signer + Send + Sync>);
+
+impl fmt::Debug for FeePayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FeePayer").field(&self.0.pubkey()).finish()
+    }
+}
+
+impl FeePayer {
+    /// Pay fees with `signer`, typically a `solana_keypair::Keypair`.
+    pub fn new(signer: impl Signer + Send + Sync + 'static) -> Self {
+        Self(Arc::new(signer))
+    }
+
+    /// Sign the base64 encoded `transaction` as its fee payer, returning
+    /// it re-encoded with the signature in the first slot. The other
+    /// signatures are left as they are.
+    ///
+    /// # Errors
+    /// If the transaction cannot be decoded, if the fee payer is not its
+    /// first signer, or if signing fails.
+    pub fn sign(&self, transaction: &str) -> Result<String, FeePayerError> {
+        let bytes = STANDARD
+            .decode(transaction)
+            .map_err(|e| FeePayerError::InvalidTransaction(e.to_string()))?;
+        let mut transaction: VersionedTransaction = bincode::deserialize(&bytes)
+            .map_err(|e| FeePayerError::InvalidTransaction(e.to_string()))?;
+
+        let pubkey = self.0.try_pubkey()?;
+        let num_signers = usize::from(transaction.message.header().num_required_signatures);
+        match transaction.message.static_account_keys().first() {
+            Some(first) if num_signers > 0 && *first == pubkey => {}
+            first => {
+                return Err(FeePayerError::NotFirstSigner {
+                    fee_payer: pubkey.to_string(),
+                    found: first.map_or_else(|| "no accounts".to_string(), ToString::to_string),
+                });
+            }
+        }
+
+        let signature = self.0.try_sign_message(&transaction.message.serialize())?;
+        if transaction.signatures.len() < num_signers {
+            transaction
+                .signatures
+                .resize(num_signers, Signature::default());
+        }
+        transaction.signatures[0] = signature;
+
+        let bytes = bincode::serialize(&transaction)
+            .map_err(|e| FeePayerError::InvalidTransaction(e.to_string()))?;
+        Ok(STANDARD.encode(bytes))
+    }
+}
+
+impl SolanaService {
+    /// Pay the fees of transactions signed through this service with
+    /// `fee_payer`. See the [`fee_payer`](self) module for details.
+    ///
+    /// # Feature Flag
+    /// Requires the `solana_sdk` feature to be enabled.
+    #[must_use]
+    pub fn with_fee_payer(mut self, fee_payer: FeePayer) -> Self {
+        self.fee_payer = Some(fee_payer);
+        self
+    }
+
+    /// Sign `transaction` with the bound fee payer, if there is one.
+    pub(crate) fn pay_fees(
+        &self,
+        transaction: &str,
+        options: Option<&SignAndSendTransactionOptions>,
+    ) -> Result<Option<String>, FeePayerError> {
+        let Some(fee_payer) = &self.fee_payer else {
+            return Ok(None);
+        };
+        if options.is_some_and(|options| options.sponsor == Some(true)) {
+            return Err(FeePayerError::Sponsored);
+        }
+        fee_payer.sign(transaction).map(Some)
+    }
+}
@@ -0,0 +1,410 @@
+//! Address lookup tables with a Privy wallet as their authority.
+//!
+//! Versioned (v0) transactions that touch many accounts reference most of
+//! them through an address lookup table. [`LookupTables`] creates and extends
+//! such tables, with the Privy wallet as both the authority and the payer,
+//! by building the instruction, signing it through Privy, and sending it:
+//!
+//! ```no_run
+//! # use privy_rs::{AuthorizationContext, PrivyClient, WalletId};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let ctx = AuthorizationContext::new();
+//! let tables = client.wallets().solana().lookup_tables(
+//!     "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1",
+//!     "https://api.devnet.solana.com",
+//! );
+//!
+//! let table = tables.create(WalletId::new("wallet_id"), &ctx).await?;
+//! tables
+//!     .extend(
+//!         WalletId::new("wallet_id"),
+//!         &table.address,
+//!         &["TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"],
+//!         &ctx,
+//!     )
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The recent slot and blockhash the transactions need are read from the
+//! `rpc_url` given to [`SolanaService::lookup_tables`].
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use solana_address_lookup_table_interface::instruction::{
+    create_lookup_table, extend_lookup_table,
+};
+use solana_transaction::{Address, Hash, Instruction, Message};
+
+use crate::{
+    AuthorizationContext, LookupTableError, WalletId, generated::types::WalletRpcResponse,
+    solana::SolanaService,
+};
+
+/// The most addresses `LookupTables::extend` adds in one transaction, so
+/// that it stays below the transaction size limit.
+const MAX_ADDRESSES_PER_EXTEND: usize = 20;
+
+/// A lookup table created by `LookupTables::create`.
+#[derive(Debug, Clone)]
+pub struct CreatedLookupTable {
+    /// The address of the table, base58 encoded.
+    pub address: String,
+    /// The hash of the transaction that created it.
+    pub transaction_hash: String,
+}
+
+/// Creates and extends address lookup tables, see the [module docs](self).
+pub struct LookupTables {
+    service: SolanaService,
+    caip2: String,
+    rpc_url: String,
+}
+
+impl SolanaService {
+    /// Manage address lookup tables on the chain `caip2`, reading chain
+    /// state from `rpc_url`. See the [`lookup_table`](crate::solana::lookup_table)
+    /// module.
+    #[must_use]
+    pub fn lookup_tables(
+        self,
+        caip2: impl Into<String>,
+        rpc_url: impl Into<String>,
+    ) -> LookupTables {
+        LookupTables {
+            service: self,
+            caip2: caip2.into(),
+            rpc_url: rpc_url.into(),
+        }
+    }
+}
+
+impl LookupTables {
+    /// Create a lookup table with the wallet as its authority, paying for
+    /// it from the wallet.
+    ///
+    /// # Errors
+    /// If the chain state can not be read from the rpc endpoint, or the
+    /// transaction can not be signed or sent.
+    pub async fn create(
+        &self,
+        wallet_id: impl Into<WalletId>,
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
+    ) -> Result<CreatedLookupTable, LookupTableError> {
+        let wallet_id = wallet_id.into();
+        let authority = self.wallet_address(&wallet_id).await?;
+        let slot = self
+            .rpc(
+                "getSlot",
+                serde_json::json!([{ "commitment": "finalized" }]),
+            )
+            .await?
+            .as_u64()
+            .ok_or_else(|| LookupTableError::InvalidResponse("slot is not a number".into()))?;
+
+        let (instruction, address) = create_lookup_table(authority, authority, slot);
+        let transaction_hash = self
+            .send(
+                &wallet_id,
+                authority,
+                instruction,
+                authorization_context.into(),
+            )
+            .await?;
+
+        Ok(CreatedLookupTable {
+            address: address.to_string(),
+            transaction_hash,
+        })
+    }
+
+    /// Add `addresses` to the lookup table at `lookup_table`, which must
+    /// have the wallet as its authority. The wallet pays for the extra
+    /// space.
+    ///
+    /// Addresses are added up to `MAX_ADDRESSES_PER_EXTEND` per transaction,
+    /// in order, and the hashes of those transactions are returned.
+    ///
+    /// # Errors
+    /// If an address is invalid, the chain state can not be read from the
+    /// rpc endpoint, or a transaction can not be signed or sent. Addresses
+    /// in the transactions sent before the failing one have been added.
+    pub async fn extend(
+        &self,
+        wallet_id: impl Into<WalletId>,
+        lookup_table: &str,
+        addresses: &[&str],
+        authorization_context: impl Into<Option<&AuthorizationContext>>,
+    ) -> Result<Vec<String>, LookupTableError> {
+        let wallet_id = wallet_id.into();
+        let authorization_context = authorization_context.into();
+        let lookup_table = parse_address(lookup_table)?;
+        let addresses = addresses
+            .iter()
+            .map(|address| parse_address(address))
+            .collect::<Result<Vec<_>, _>>()?;
+        let authority = self.wallet_address(&wallet_id).await?;
+
+        let mut hashes = Vec::new();
+        for chunk in addresses.chunks(MAX_ADDRESSES_PER_EXTEND) {
+            let instruction =
+                extend_lookup_table(lookup_table, authority, Some(authority), chunk.to_vec());
+            hashes.push(
+                self.send(&wallet_id, authority, instruction, authorization_context)
+                    .await?,
+            );
+        }
+        Ok(hashes)
+    }
+
+    async fn wallet_address(&self, wallet_id: &WalletId) -> Result<Address, LookupTableError> {
+        let wallet = self
+            .service
+            .wallets_client
+            .get(wallet_id)
+            .await
+            .map_err(crate::PrivySignedApiError::from)?
+            .into_inner();
+        parse_address(&wallet.address)
+    }
+
+    /// Sign and send `instruction` in a transaction paid for by `payer`.
+    async fn send(
+        &self,
+        wallet_id: &WalletId,
+        payer: Address,
+        instruction: Instruction,
+        authorization_context: Option<&AuthorizationContext>,
+    ) -> Result<String, LookupTableError> {
+        let blockhash = self
+            .rpc(
+                "getLatestBlockhash",
+                serde_json::json!([{ "commitment": "finalized" }]),
+            )
+            .await?;
+        let blockhash: Hash = blockhash["value"]["blockhash"]
+            .as_str()
+            .and_then(|hash| hash.parse().ok())
+            .ok_or_else(|| LookupTableError::InvalidResponse(blockhash.to_string()))?;
+
+        // an unsigned transaction is its signature count, as a compact-u16, and
+        // that many zeroed signatures, followed by the message
+        let message = Message::new_with_blockhash(&[instruction], Some(&payer), &blockhash);
+        let signatures = message.header.num_required_signatures;
+        let mut transaction = vec![signatures];
+        transaction.resize(1 + 64 * usize::from(signatures), 0);
+        transaction.extend(message.serialize());
+
+        let response = self
+            .service
+            .sign_and_send_transaction(
+                wallet_id,
+                &self.caip2,
+                &STANDARD.encode(transaction),
+                authorization_context,
+                None,
+            )
+            .await?;
+        match response.into_inner() {
+            WalletRpcResponse::SolanaSignAndSendTransactionRpcResponse(response) => {
+                Ok(response.data.hash)
+            }
+            other => Err(LookupTableError::InvalidResponse(format!(
+                "unexpected response to signAndSendTransaction: {other:?}"
+            ))),
+        }
+    }
+
+    async fn rpc(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, LookupTableError> {
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let mut response: serde_json::Value = crate::utils::RPC_HTTP
+            .post(&self.rpc_url)
+            .json(&body)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        if let Some(error) = response.get("error") {
+            return Err(LookupTableError::InvalidResponse(error.to_string()));
+        }
+        Ok(response["result"].take())
+    }
+}
+
+fn parse_address(address: &str) -> Result<Address, LookupTableError> {
+    address
+        .parse()
+        .map_err(|_| LookupTableError::InvalidAddress(address.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{Engine, engine::general_purpose::STANDARD};
+    use httpmock::prelude::*;
+    use solana_address_lookup_table_interface::instruction::{
+        create_lookup_table, derive_lookup_table_address,
+    };
+    use solana_sdk::{
+        hash::Hash,
+        message::Message,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        transaction::Transaction,
+    };
+
+    use crate::{AuthorizationContext, PrivyClient, WalletId, client::PrivyClientOptions};
+
+    const SLOT: u64 = 1234;
+
+    async fn mock_chain(server: &MockServer, authority: &Pubkey, blockhash: &Hash) {
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/wallets/wallet_id");
+                then.status(200).json_body(serde_json::json!({
+                    "id": "wallet_id",
+                    "address": authority.to_string(),
+                    "chain_type": "solana",
+                    "policy_ids": [],
+                    "additional_signers": [],
+                    "created_at": 0,
+                }));
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/solana")
+                    .json_body_includes(r#"{"method": "getSlot"}"#);
+                then.status(200)
+                    .json_body(serde_json::json!({ "jsonrpc": "2.0", "id": 1, "result": SLOT }));
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/solana")
+                    .json_body_includes(r#"{"method": "getLatestBlockhash"}"#);
+                then.status(200).json_body(serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "result": { "value": { "blockhash": blockhash.to_string() } },
+                }));
+            })
+            .await;
+    }
+
+    fn client(server: &MockServer) -> PrivyClient {
+        PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_sends_unsigned_create_instruction() {
+        let authority = Keypair::new().pubkey();
+        let blockhash = Hash::new_unique();
+        let server = MockServer::start_async().await;
+        mock_chain(&server, &authority, &blockhash).await;
+
+        let (instruction, _) = create_lookup_table(authority, authority, SLOT);
+        let expected = Transaction::new_unsigned(Message::new_with_blockhash(
+            &[instruction],
+            Some(&authority),
+            &blockhash,
+        ));
+        let rpc = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/wallets/wallet_id/rpc")
+                    .json_body_includes(
+                        serde_json::json!({
+                            "params": {
+                                "transaction": STANDARD.encode(bincode::serialize(&expected).unwrap())
+                            }
+                        })
+                        .to_string(),
+                    );
+                then.status(200).json_body(serde_json::json!({
+                    "method": "signAndSendTransaction",
+                    "data": { "caip2": "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1", "hash": "tx_hash" },
+                }));
+            })
+            .await;
+
+        let client = client(&server);
+        let table = client
+            .wallets()
+            .solana()
+            .lookup_tables(
+                "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1",
+                format!("{}/solana", server.base_url()),
+            )
+            .create(WalletId::new("wallet_id"), &AuthorizationContext::new())
+            .await
+            .unwrap();
+
+        rpc.assert_async().await;
+        assert_eq!(
+            table.address,
+            derive_lookup_table_address(&authority, SLOT).0.to_string()
+        );
+        assert_eq!(table.transaction_hash, "tx_hash");
+    }
+
+    #[tokio::test]
+    async fn test_extend_splits_addresses_across_transactions() {
+        let authority = Keypair::new().pubkey();
+        let server = MockServer::start_async().await;
+        mock_chain(&server, &authority, &Hash::new_unique()).await;
+        let rpc = server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/wallets/wallet_id/rpc");
+                then.status(200).json_body(serde_json::json!({
+                    "method": "signAndSendTransaction",
+                    "data": { "caip2": "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1", "hash": "tx_hash" },
+                }));
+            })
+            .await;
+
+        let addresses: Vec<_> = (0..25).map(|_| Pubkey::new_unique().to_string()).collect();
+        let addresses: Vec<_> = addresses.iter().map(String::as_str).collect();
+        let client = client(&server);
+        let hashes = client
+            .wallets()
+            .solana()
+            .lookup_tables(
+                "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1",
+                format!("{}/solana", server.base_url()),
+            )
+            .extend(
+                WalletId::new("wallet_id"),
+                &Pubkey::new_unique().to_string(),
+                &addresses,
+                &AuthorizationContext::new(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(hashes.len(), 2);
+        rpc.assert_calls_async(2).await;
+    }
+}
@@ -0,0 +1,129 @@
+use std::{path::PathBuf, time::Instant};
+
+use reqwest::multipart::{Form, Part};
+use tokio_util::io::ReaderStream;
+
+use super::ResponseValue;
+use crate::{
+    DocumentUploadError, PrivyApiError, UserId,
+    errors::enrich_api_error,
+    generated::{Client, ClientInfo, Error},
+    subclients::FiatClient,
+};
+
+/// A KYC document to upload with `FiatClient::upload_document`.
+#[derive(Clone, Debug)]
+pub struct KycDocument {
+    path: PathBuf,
+    document_type: String,
+    content_type: String,
+}
+
+impl KycDocument {
+    /// The file at `path`, submitted as a document of `document_type`, e.g.
+    /// `identity_document` or `residence_document`.
+    pub fn new(path: impl Into<PathBuf>, document_type: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            document_type: document_type.into(),
+            content_type: "application/octet-stream".to_string(),
+        }
+    }
+
+    /// Set the content type of the file, `application/octet-stream` by
+    /// default.
+    #[must_use]
+    pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = content_type.into();
+        self
+    }
+}
+
+impl FiatClient {
+    /// Upload a KYC document for a user.
+    ///
+    /// The file is streamed to the API rather than read into memory. The
+    /// endpoint is authenticated with the app secret alone, so the upload
+    /// carries no authorization signature.
+    ///
+    /// ```rust,no_run
+    /// # use privy_rs::{PrivyClient, UserId, subclients::KycDocument};
+    /// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PrivyClient::new_from_env()?;
+    /// let passport = KycDocument::new("passport.jpg", "identity_document")
+    ///     .with_content_type("image/jpeg");
+    ///
+    /// client.fiat().upload_document(UserId::new("user_id"), &passport).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Can fail if the file could not be read, or if the api call fails. In
+    /// dry run mode, nothing is sent and the upload fails with
+    /// `PrivyApiError::InvalidRequest`.
+    pub async fn upload_document(
+        &self,
+        user_id: impl Into<UserId>,
+        document: &KycDocument,
+    ) -> Result<ResponseValue<serde_json::Map<String, serde_json::Value>>, DocumentUploadError>
+    {
+        let url = format!(
+            "{}/v1/kraken_embed/users/{}/verifications",
+            self.base_url,
+            user_id.into().as_str()
+        );
+        if self.shared.dry_run {
+            tracing::info!(
+                operation = "submit_user_verification",
+                "dry run: skipping call"
+            );
+            return Err(DocumentUploadError::Upload(PrivyApiError::InvalidRequest(
+                "submit_user_verification is not supported in dry run mode, only wallet rpc and raw sign calls are".to_string(),
+            )));
+        }
+
+        let file = tokio::fs::File::open(&document.path).await?;
+        let length = file.metadata().await?.len();
+        let file_name = document
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let part =
+            Part::stream_with_length(reqwest::Body::wrap_stream(ReaderStream::new(file)), length)
+                .file_name(file_name)
+                .mime_str(&document.content_type)
+                .map_err(|e| PrivyApiError::InvalidRequest(e.to_string()))?;
+        let form = Form::new()
+            .text("type", document.document_type.clone())
+            .part("document", part);
+
+        let started = Instant::now();
+        let result = match self
+            .client
+            .client()
+            .post(url)
+            .header("api-version", Client::api_version())
+            .multipart(form)
+            .send()
+            .await
+        {
+            Ok(response) if response.status().is_success() => {
+                ResponseValue::from_response(response).await
+            }
+            Ok(response) => Err(Error::UnexpectedResponse(response)),
+            Err(e) => Err(Error::CommunicationError(e)),
+        };
+        let result = match result {
+            Ok(value) => Ok(value),
+            Err(error) => Err(enrich_api_error(error, None).await),
+        };
+        self.shared
+            .latency
+            .record("submit_user_verification", started.elapsed(), &result);
+
+        Ok(result?)
+    }
+}
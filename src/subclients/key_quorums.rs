@@ -1,10 +1,149 @@
-use super::ResponseValue;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use super::{Error, ResponseValue};
 use crate::{
-    AuthorizationContext, PrivySignedApiError, generate_authorization_signatures,
-    generated::types::KeyQuorumId,
-    subclients::KeyQuorumsClient,
+    AuthorizationContext, ConversionError, KeyQuorumId, PrivyApiError, PrivySignedApiError,
+    generated::types, subclients::KeyQuorumsClient, utils::sign_request,
 };
 
+/// The display names of the key quorums a client knows about, see
+/// `KeyQuorumsClient::find_by_name`. Shared by clones of the client.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct KeyQuorumNames(Arc<RwLock<HashMap<String, KeyQuorumId>>>);
+
+impl KeyQuorumNames {
+    fn get(&self, name: &str) -> Option<KeyQuorumId> {
+        self.0.read().expect("lock poisoned").get(name).cloned()
+    }
+
+    /// Record the current display name of `quorum`, replacing any name it
+    /// was previously known by.
+    fn record(&self, quorum: &types::KeyQuorum) {
+        let mut names = self.0.write().expect("lock poisoned");
+        names.retain(|_, id| id.as_str() != quorum.id);
+        if let Some(name) = &quorum.display_name {
+            names.insert(name.to_string(), KeyQuorumId::new(&quorum.id));
+        }
+    }
+
+    fn forget(&self, key_quorum_id: &str) {
+        self.0
+            .write()
+            .expect("lock poisoned")
+            .retain(|_, id| id.as_str() != key_quorum_id);
+    }
+}
+
+/// Name-based lookup.
+///
+/// The API cannot list key quorums, so names are only known for quorums
+/// this client (or a clone of it) created with
+/// [`create_named`](Self::create_named), updated, or loaded with
+/// [`load_names`](Self::load_names). Display names are not unique on the
+/// API side; a client only remembers the last quorum it saw with a name.
+///
+/// ```no_run
+/// # use privy_rs::{KeyQuorumId, PrivyClient};
+/// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = PrivyClient::new_from_env()?;
+/// let quorums = client.key_quorums();
+///
+/// // once, at startup
+/// quorums.load_names(["kq_treasury_id", "kq_ops_id"].map(KeyQuorumId::new)).await?;
+///
+/// let treasury = quorums.id_by_name("treasury").ok_or("unknown quorum")?;
+/// # Ok(())
+/// # }
+/// ```
+impl KeyQuorumsClient {
+    /// Create a key quorum named `display_name`, and remember its name.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the name is longer than the API allows, if this client
+    /// already knows a quorum with the same name, or if the api call fails.
+    pub async fn create_named(
+        &self,
+        display_name: &str,
+        mut body: types::KeyQuorumCreateRequestBody,
+    ) -> Result<ResponseValue<types::KeyQuorum>, PrivyApiError> {
+        if let Some(existing) = self.shared.key_quorum_names.get(display_name) {
+            return Err(Error::InvalidRequest(format!(
+                "key quorum {existing} is already named `{display_name}`"
+            )));
+        }
+        body.display_name = Some(display_name.parse().map_err(|e: ConversionError| {
+            Error::InvalidRequest(format!("Invalid display name: {e}"))
+        })?);
+
+        let response = self.create(&body).await?;
+        self.shared.key_quorum_names.record(&response);
+        Ok(response)
+    }
+
+    /// Fetch the given key quorums and remember their names.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the quorums cannot be fetched. The names of the
+    /// quorums that were fetched are remembered regardless.
+    pub async fn load_names<I>(&self, key_quorum_ids: I) -> Result<(), PrivyApiError>
+    where
+        I: IntoIterator,
+        I::Item: Into<KeyQuorumId>,
+    {
+        let fetches = key_quorum_ids.into_iter().map(|id| {
+            let id = types::KeyQuorumId::from(&id.into());
+            async move {
+                let quorum = self.get(&id).await?;
+                self.shared.key_quorum_names.record(&quorum);
+                Ok::<_, PrivyApiError>(())
+            }
+        });
+        futures::future::try_join_all(fetches).await?;
+        Ok(())
+    }
+
+    /// The id of the key quorum named `name`, as far as this client knows.
+    /// This does not call the API, see [`find_by_name`](Self::find_by_name)
+    /// for a lookup that checks the quorum still has that name.
+    #[must_use]
+    pub fn id_by_name(&self, name: &str) -> Option<KeyQuorumId> {
+        self.shared.key_quorum_names.get(name)
+    }
+
+    /// Fetch the key quorum named `name`, or `None` if no known quorum has
+    /// that name any more.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the quorum cannot be fetched for a reason other than it
+    /// having been deleted.
+    pub async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<types::KeyQuorum>, PrivyApiError> {
+        let Some(id) = self.shared.key_quorum_names.get(name) else {
+            return Ok(None);
+        };
+
+        let quorum = match self.get(&types::KeyQuorumId::from(&id)).await {
+            Ok(quorum) => quorum.into_inner(),
+            Err(e) if e.status() == Some(reqwest::StatusCode::NOT_FOUND) => {
+                self.shared.key_quorum_names.forget(&id);
+                return Ok(None);
+            }
+            Err(e) => return Err(e),
+        };
+
+        self.shared.key_quorum_names.record(&quorum);
+        Ok((quorum.display_name.as_deref().map(String::as_str) == Some(name)).then_some(quorum))
+    }
+}
+
 impl KeyQuorumsClient {
     /// Update a key quorum
     ///
@@ -15,21 +154,29 @@ impl KeyQuorumsClient {
     /// or the Privy API returning an error.
     pub async fn update<'a>(
         &'a self,
-        key_quorum_id: &'a KeyQuorumId,
+        key_quorum_id: impl Into<KeyQuorumId>,
         ctx: &'a AuthorizationContext,
         body: &'a crate::generated::types::KeyQuorumUpdateRequestBody,
     ) -> Result<ResponseValue<crate::generated::types::KeyQuorum>, PrivySignedApiError> {
-        let sig = generate_authorization_signatures(
+        let key_quorum_id = types::KeyQuorumId::from(&key_quorum_id.into());
+        let sig = sign_request(
             ctx,
             &self.app_id,
             crate::Method::PATCH,
-            format!("{}/v1/key_quorums/{}", self.base_url, key_quorum_id.as_str()),
+            &format!(
+                "{}/v1/key_quorums/{}",
+                self.base_url,
+                key_quorum_id.as_str()
+            ),
             body,
             None,
+            &self.shared.request_options,
         )
         .await?;
 
-        Ok(self._update(key_quorum_id, Some(&sig), None, body).await?)
+        let response = self._update(&key_quorum_id, Some(&sig), None, body).await?;
+        self.shared.key_quorum_names.record(&response);
+        Ok(response)
     }
 
     /// Delete a key quorum
@@ -41,20 +188,27 @@ impl KeyQuorumsClient {
     /// or the Privy API returning an error.
     pub async fn delete<'a>(
         &'a self,
-        key_quorum_id: &'a KeyQuorumId,
+        key_quorum_id: impl Into<KeyQuorumId>,
         ctx: &'a AuthorizationContext,
-    ) -> Result<ResponseValue<crate::generated::types::SuccessResponse>, PrivySignedApiError>
-    {
-        let sig = generate_authorization_signatures(
+    ) -> Result<ResponseValue<crate::generated::types::SuccessResponse>, PrivySignedApiError> {
+        let key_quorum_id = types::KeyQuorumId::from(&key_quorum_id.into());
+        let sig = sign_request(
             ctx,
             &self.app_id,
             crate::Method::DELETE,
-            format!("{}/v1/key_quorums/{}", self.base_url, key_quorum_id.as_str()),
+            &format!(
+                "{}/v1/key_quorums/{}",
+                self.base_url,
+                key_quorum_id.as_str()
+            ),
             &serde_json::json!({}),
             None,
+            &self.shared.request_options,
         )
         .await?;
 
-        Ok(self._delete(key_quorum_id, Some(&sig), None).await?)
+        let response = self._delete(&key_quorum_id, Some(&sig), None).await?;
+        self.shared.key_quorum_names.forget(&key_quorum_id);
+        Ok(response)
     }
 }
@@ -6,6 +6,15 @@
 
 include!(concat!(env!("OUT_DIR"), "/subclients.rs"));
 
+mod fiat;
 mod key_quorums;
 mod policies;
+mod transactions;
+mod user_wallets;
+mod users;
 mod wallets;
+
+pub use fiat::KycDocument;
+pub(crate) use key_quorums::KeyQuorumNames;
+pub use user_wallets::UserWalletsClient;
+pub use wallets::{ListWalletsOptions, MessageSignature};
@@ -1,8 +1,22 @@
+use std::collections::HashSet;
+
+use futures::{StreamExt, TryStreamExt};
+
 use super::ResponseValue;
 use crate::{
-    AuthorizationContext, PrivySignedApiError, generate_authorization_signatures,
-    generated::types::{Policy, UpdatePolicyBody, UpdatePolicyPolicyId},
-    subclients::PoliciesClient,
+    AuthorizationContext, PolicyId, PrivyApiError, PrivySignedApiError, RolloutError, WalletId,
+    generated::types::{
+        CreateRulePolicyId, DeletePolicyPolicyId, DeleteRulePolicyId, GetPolicyPolicyId,
+        GetWalletsCursor, Policy, UpdatePolicyBody, UpdatePolicyPolicyId, UpdateRulePolicyId,
+        Wallet, WalletUpdateRequestBody, WalletUpdateRequestBodyPolicyIdsItem,
+    },
+    pagination::{self, Paginated},
+    rollout::{
+        RateLimitGate, RolloutCheckpoint, RolloutFailure, RolloutOptions, RolloutReport,
+        signed_api_error,
+    },
+    subclients::{PoliciesClient, WalletsClient},
+    utils::sign_request,
 };
 
 impl PoliciesClient {
@@ -15,21 +29,24 @@ impl PoliciesClient {
     /// or the Privy API returning an error.
     pub async fn update<'a>(
         &'a self,
-        policy_id: &'a UpdatePolicyPolicyId,
+        policy_id: impl Into<PolicyId>,
         ctx: &'a AuthorizationContext,
         body: &'a UpdatePolicyBody,
     ) -> Result<ResponseValue<Policy>, PrivySignedApiError> {
-        let sig = generate_authorization_signatures(
+        let policy_id = UpdatePolicyPolicyId::try_from(policy_id.into().as_str())
+            .map_err(|e| PrivyApiError::InvalidRequest(e.to_string()))?;
+        let sig = sign_request(
             ctx,
             &self.app_id,
             crate::Method::PATCH,
-            format!("{}/v1/policies/{}", self.base_url, policy_id.as_str()),
+            &format!("{}/v1/policies/{}", self.base_url, policy_id.as_str()),
             body,
             None,
+            &self.shared.request_options,
         )
         .await?;
 
-        Ok(self._update(policy_id, Some(&sig), None, body).await?)
+        Ok(self._update(&policy_id, Some(&sig), None, body).await?)
     }
 
     /// Delete a policy
@@ -41,21 +58,23 @@ impl PoliciesClient {
     /// or the Privy API returning an error.
     pub async fn delete<'a>(
         &'a self,
-        policy_id: &'a crate::generated::types::DeletePolicyPolicyId,
+        policy_id: impl Into<PolicyId>,
         ctx: &'a AuthorizationContext,
-    ) -> Result<ResponseValue<crate::generated::types::SuccessResponse>, PrivySignedApiError>
-    {
-        let sig = generate_authorization_signatures(
+    ) -> Result<ResponseValue<crate::generated::types::SuccessResponse>, PrivySignedApiError> {
+        let policy_id = DeletePolicyPolicyId::try_from(policy_id.into().as_str())
+            .map_err(|e| PrivyApiError::InvalidRequest(e.to_string()))?;
+        let sig = sign_request(
             ctx,
             &self.app_id,
             crate::Method::DELETE,
-            format!("{}/v1/policies/{}", self.base_url, policy_id.as_str()),
+            &format!("{}/v1/policies/{}", self.base_url, policy_id.as_str()),
             &serde_json::json!({}),
             None,
+            &self.shared.request_options,
         )
         .await?;
 
-        Ok(self._delete(policy_id, Some(&sig), None).await?)
+        Ok(self._delete(&policy_id, Some(&sig), None).await?)
     }
 
     /// Create a rule for a policy
@@ -67,22 +86,27 @@ impl PoliciesClient {
     /// or the Privy API returning an error.
     pub async fn create_rule<'a>(
         &'a self,
-        policy_id: &'a crate::generated::types::CreateRulePolicyId,
+        policy_id: impl Into<PolicyId>,
         ctx: &'a AuthorizationContext,
         body: &'a crate::generated::types::PolicyRuleRequestBody,
     ) -> Result<ResponseValue<crate::generated::types::PolicyRuleResponse>, PrivySignedApiError>
     {
-        let sig = generate_authorization_signatures(
+        let policy_id = CreateRulePolicyId::try_from(policy_id.into().as_str())
+            .map_err(|e| PrivyApiError::InvalidRequest(e.to_string()))?;
+        let sig = sign_request(
             ctx,
             &self.app_id,
             crate::Method::POST,
-            format!("{}/v1/policies/{}/rules", self.base_url, policy_id.as_str()),
+            &format!("{}/v1/policies/{}/rules", self.base_url, policy_id.as_str()),
             body,
             None,
+            &self.shared.request_options,
         )
         .await?;
 
-        Ok(self._create_rule(policy_id, Some(&sig), None, body).await?)
+        Ok(self
+            ._create_rule(&policy_id, Some(&sig), None, body)
+            .await?)
     }
 
     /// Update a rule for a policy
@@ -94,17 +118,19 @@ impl PoliciesClient {
     /// or the Privy API returning an error.
     pub async fn update_rule<'a>(
         &'a self,
-        policy_id: &'a crate::generated::types::UpdateRulePolicyId,
+        policy_id: impl Into<PolicyId>,
         rule_id: &'a crate::generated::types::UpdateRuleRuleId,
         ctx: &'a AuthorizationContext,
         body: &'a crate::generated::types::PolicyRuleRequestBody,
     ) -> Result<ResponseValue<crate::generated::types::PolicyRuleResponse>, PrivySignedApiError>
     {
-        let sig = generate_authorization_signatures(
+        let policy_id = UpdateRulePolicyId::try_from(policy_id.into().as_str())
+            .map_err(|e| PrivyApiError::InvalidRequest(e.to_string()))?;
+        let sig = sign_request(
             ctx,
             &self.app_id,
             crate::Method::PATCH,
-            format!(
+            &format!(
                 "{}/v1/policies/{}/rules/{}",
                 self.base_url,
                 policy_id.as_str(),
@@ -112,11 +138,12 @@ impl PoliciesClient {
             ),
             body,
             None,
+            &self.shared.request_options,
         )
         .await?;
 
         Ok(self
-            ._update_rule(policy_id, rule_id, Some(&sig), None, body)
+            ._update_rule(&policy_id, rule_id, Some(&sig), None, body)
             .await?)
     }
 
@@ -129,16 +156,17 @@ impl PoliciesClient {
     /// or the Privy API returning an error.
     pub async fn delete_rule<'a>(
         &'a self,
-        policy_id: &'a crate::generated::types::DeleteRulePolicyId,
+        policy_id: impl Into<PolicyId>,
         rule_id: &'a crate::generated::types::DeleteRuleRuleId,
         ctx: &'a AuthorizationContext,
-    ) -> Result<ResponseValue<crate::generated::types::SuccessResponse>, PrivySignedApiError>
-    {
-        let sig = generate_authorization_signatures(
+    ) -> Result<ResponseValue<crate::generated::types::SuccessResponse>, PrivySignedApiError> {
+        let policy_id = DeleteRulePolicyId::try_from(policy_id.into().as_str())
+            .map_err(|e| PrivyApiError::InvalidRequest(e.to_string()))?;
+        let sig = sign_request(
             ctx,
             &self.app_id,
             crate::Method::DELETE,
-            format!(
+            &format!(
                 "{}/v1/policies/{}/rules/{}",
                 self.base_url,
                 policy_id.as_str(),
@@ -146,9 +174,193 @@ impl PoliciesClient {
             ),
             &serde_json::json!({}),
             None,
+            &self.shared.request_options,
         )
         .await?;
 
-        Ok(self._delete_rule(policy_id, rule_id, Some(&sig), None).await?)
+        Ok(self
+            ._delete_rule(&policy_id, rule_id, Some(&sig), None)
+            .await?)
+    }
+
+    /// Set the policy `policy_id` on every wallet of the app for which
+    /// `wallet_filter` returns true, with the default `RolloutOptions`. See
+    /// the [`rollout`](crate::rollout) module for details.
+    ///
+    /// # Errors
+    ///
+    /// Wallets that can not be updated are recorded in the report rather
+    /// than stopping the rollout. It only fails if a page of wallets can
+    /// not be listed, with a `RolloutError` holding the progress so far.
+    pub async fn rollout(
+        &self,
+        policy_id: impl Into<PolicyId>,
+        wallet_filter: impl Fn(&Wallet) -> bool,
+        ctx: &AuthorizationContext,
+    ) -> Result<RolloutReport, RolloutError> {
+        self.rollout_with_options(policy_id, wallet_filter, ctx, RolloutOptions::default())
+            .await
+    }
+
+    /// Like `PoliciesClient::rollout`, with control over concurrency, rate
+    /// limit retries and checkpoints.
+    ///
+    /// # Errors
+    ///
+    /// See `PoliciesClient::rollout`.
+    pub async fn rollout_with_options(
+        &self,
+        policy_id: impl Into<PolicyId>,
+        wallet_filter: impl Fn(&Wallet) -> bool,
+        ctx: &AuthorizationContext,
+        options: RolloutOptions,
+    ) -> Result<RolloutReport, RolloutError> {
+        let policy_id = policy_id.into();
+        let mut report = RolloutReport::default();
+        let policy_ids = match WalletUpdateRequestBodyPolicyIdsItem::try_from(policy_id.as_str()) {
+            Ok(id) => vec![id],
+            Err(e) => {
+                return Err(RolloutError {
+                    report: Box::new(report),
+                    source: PrivyApiError::InvalidRequest(e.to_string()),
+                });
+            }
+        };
+        let body = WalletUpdateRequestBody {
+            policy_ids,
+            ..Default::default()
+        };
+
+        let wallets = self.wallets();
+        let gate = RateLimitGate::default();
+        let mut checkpoint = options.resume_from.clone();
+        let resume_from = match checkpoint
+            .as_ref()
+            .map(|c| c.cursor().parse::<GetWalletsCursor>())
+            .transpose()
+        {
+            Ok(cursor) => cursor,
+            Err(e) => {
+                report.checkpoint = checkpoint;
+                return Err(RolloutError {
+                    report: Box::new(report),
+                    source: PrivyApiError::InvalidRequest(format!("Invalid checkpoint: {e}")),
+                });
+            }
+        };
+        let pages = pagination::pages(
+            |cursor: Option<GetWalletsCursor>| {
+                let (wallets, gate) = (&wallets, &gate);
+                async move {
+                    gate.call(
+                        options.max_retries,
+                        |error| Some(error),
+                        || wallets.list(None, None, cursor.as_ref(), None, Some(100.0), None),
+                    )
+                    .await
+                }
+            },
+            resume_from,
+        );
+        let mut pages = std::pin::pin!(pages);
+
+        while let Some(page) = pages.next().await {
+            let (page, next_cursor) = match page {
+                Ok(page) => page,
+                Err(source) => {
+                    report.rate_limited = gate.hits();
+                    report.checkpoint = checkpoint;
+                    return Err(RolloutError {
+                        report: Box::new(report),
+                        source,
+                    });
+                }
+            };
+
+            let mut targets = Vec::new();
+            for wallet in &page {
+                let wallet_id = WalletId::new(&wallet.id);
+                if !wallet_filter(wallet) {
+                    report.unmatched += 1;
+                } else if wallet.policy_ids == [policy_id.as_str()] {
+                    report.unchanged.push(wallet_id);
+                } else {
+                    targets.push(wallet_id);
+                }
+            }
+
+            let results: Vec<_> = futures::stream::iter(targets)
+                .map(|wallet_id| {
+                    let (wallets, gate, body) = (&wallets, &gate, &body);
+                    async move {
+                        let result = gate
+                            .call(options.max_retries, signed_api_error, || {
+                                wallets.update(&wallet_id, ctx, body)
+                            })
+                            .await;
+                        (wallet_id, result)
+                    }
+                })
+                .buffer_unordered(options.concurrency.max(1))
+                .collect()
+                .await;
+            for (wallet_id, result) in results {
+                match result {
+                    Ok(_) => report.updated.push(wallet_id),
+                    Err(error) => report.failed.push(RolloutFailure { wallet_id, error }),
+                }
+            }
+
+            checkpoint = next_cursor.map(RolloutCheckpoint::new);
+            if let (Some(on_checkpoint), Some(checkpoint)) = (&options.on_checkpoint, &checkpoint) {
+                on_checkpoint(checkpoint);
+            }
+        }
+
+        report.rate_limited = gate.hits();
+        Ok(report)
+    }
+
+    /// Every policy attached to a wallet of the app, each fetched once.
+    ///
+    /// The API has no endpoint listing policies, so this pages through
+    /// every wallet, and fetches each policy the first time a wallet names
+    /// it, as the stream is polled. Policies attached to no wallet are not
+    /// listed. See the [`pagination`](crate::pagination) module.
+    ///
+    /// # Errors
+    ///
+    /// Yields an error, and ends, if a page of wallets or a policy could
+    /// not be fetched.
+    pub fn list_all(&self) -> Paginated<'_, Policy> {
+        let wallets = self.wallets();
+        let mut seen = HashSet::new();
+        let policy_ids = Paginated::new(move |cursor: Option<GetWalletsCursor>| {
+            let wallets = wallets.clone();
+            async move {
+                wallets
+                    .list(None, None, cursor.as_ref(), None, Some(100.0), None)
+                    .await
+            }
+        })
+        .map_ok(|wallet| futures::stream::iter(wallet.policy_ids.into_iter().map(Ok)))
+        .try_flatten()
+        .try_filter(move |policy_id| std::future::ready(seen.insert(policy_id.clone())));
+
+        Paginated::from_stream(policy_ids.and_then(move |policy_id| async move {
+            let policy_id = GetPolicyPolicyId::try_from(policy_id.as_str())
+                .map_err(|e| PrivyApiError::InvalidRequest(e.to_string()))?;
+            Ok(self.get(&policy_id).await?.into_inner())
+        }))
+    }
+
+    /// A wallets client sharing this client's configuration.
+    fn wallets(&self) -> WalletsClient {
+        WalletsClient::new(
+            self.client.clone(),
+            self.app_id.clone(),
+            self.base_url.clone(),
+        )
+        .with_shared(self.shared.clone())
     }
 }
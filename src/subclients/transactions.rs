@@ -0,0 +1,148 @@
+#[cfg(feature = "reports")]
+use std::io::Write;
+
+#[cfg(feature = "reports")]
+use futures::TryStreamExt;
+use futures::{Stream, stream::BoxStream};
+
+use crate::{
+    PrivyApiError, WalletId,
+    generated::types::{
+        Transaction, WalletTransactionsAsset, WalletTransactionsChain, WalletTransactionsCursor,
+        WalletTransactionsResponseTransactionsItem,
+    },
+    pagination::{self, Paginated},
+    subclients::{TransactionsClient, WalletsTransactionsClient},
+};
+#[cfg(feature = "reports")]
+use crate::{
+    ReportError,
+    activity::ActivityPeriod,
+    report::{ReportFormat, ReportRow, ReportWriter},
+};
+
+impl TransactionsClient {
+    /// The updates to `transaction_id`: the transaction as it is now, then
+    /// every time its status changes, until it is confirmed or has failed.
+    /// Updates come through the client's transaction transport, see the
+    /// [`transport`](crate::transport) module.
+    ///
+    /// # Errors
+    ///
+    /// Yields an error, and ends, if the transaction could not be fetched.
+    pub fn watch(
+        &self,
+        transaction_id: impl Into<String>,
+    ) -> BoxStream<'_, Result<Transaction, PrivyApiError>> {
+        self.shared.transport.updates(self, transaction_id.into())
+    }
+
+    /// Write every transfer made by `wallet_ids` during `range` to `writer`,
+    /// as a report in `format`. Each wallet is paired with the chain to read
+    /// its history on. Rows are written page by page, in the order the API
+    /// returns them. See the [`report`](crate::report) module for the
+    /// columns and an example.
+    ///
+    /// Returns the number of rows written.
+    ///
+    /// # Errors
+    ///
+    /// Can fail if any of the underlying transaction pages could not be
+    /// fetched, or if the report could not be written. Rows written before
+    /// the failure are left in `writer`.
+    ///
+    /// # Feature Flag
+    /// Requires the `reports` feature to be enabled.
+    #[cfg(feature = "reports")]
+    pub async fn report<I, W>(
+        &self,
+        wallet_ids: impl IntoIterator<Item = (I, WalletTransactionsChain)>,
+        range: ActivityPeriod,
+        format: ReportFormat,
+        writer: W,
+    ) -> Result<usize, ReportError>
+    where
+        I: Into<WalletId>,
+        W: Write + Send,
+    {
+        let transactions = WalletsTransactionsClient::new(
+            self.client.clone(),
+            self.app_id.clone(),
+            self.base_url.clone(),
+        )
+        .with_shared(self.shared.clone());
+        let mut writer = ReportWriter::new(format, writer)?;
+        let mut written = 0;
+
+        for (wallet_id, chain) in wallet_ids {
+            let mut pages = std::pin::pin!(transactions.pages(wallet_id.into(), chain, None));
+
+            while let Some((page, _)) = pages.try_next().await? {
+                let rows: Vec<ReportRow> = page
+                    .iter()
+                    .filter_map(|transaction| ReportRow::new(transaction, &range))
+                    .collect();
+                writer.write(&rows)?;
+                written += rows.len();
+            }
+        }
+
+        writer.finish()?;
+        Ok(written)
+    }
+}
+
+impl WalletsTransactionsClient {
+    /// Every transaction of `wallet_id` on `chain`, optionally only those
+    /// of `asset`, the latest first, fetched with the largest page size as
+    /// the stream is polled. See the [`pagination`](crate::pagination)
+    /// module.
+    ///
+    /// # Errors
+    ///
+    /// Yields an error, and ends, if a page could not be fetched.
+    pub fn list_all(
+        &self,
+        wallet_id: impl Into<WalletId>,
+        chain: WalletTransactionsChain,
+        asset: Option<WalletTransactionsAsset>,
+    ) -> Paginated<'_, WalletTransactionsResponseTransactionsItem> {
+        Paginated::from_pages(self.pages(wallet_id.into(), chain, asset))
+    }
+
+    /// The pages of `list_all`, each with the cursor of the next page.
+    pub(crate) fn pages(
+        &self,
+        wallet_id: WalletId,
+        chain: WalletTransactionsChain,
+        asset: Option<WalletTransactionsAsset>,
+    ) -> impl Stream<
+        Item = Result<
+            (
+                Vec<WalletTransactionsResponseTransactionsItem>,
+                Option<String>,
+            ),
+            PrivyApiError,
+        >,
+    > + Send
+    + '_ {
+        pagination::pages(
+            move |cursor: Option<WalletTransactionsCursor>| {
+                let (wallet_id, asset) = (wallet_id.clone(), asset.clone());
+                async move {
+                    self.get(
+                        &wallet_id,
+                        asset.as_ref(),
+                        chain,
+                        cursor.as_ref(),
+                        Some(100.0),
+                        None,
+                        None,
+                    )
+                    .await
+                }
+            },
+            None,
+        )
+    }
+}
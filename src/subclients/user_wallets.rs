@@ -0,0 +1,179 @@
+use crate::{
+    AuthorizationContext, JwtUser, PrivyClient, PrivySignedApiError, WalletId,
+    generated::{
+        ResponseValue,
+        types::{WalletRpcRequestBody, WalletRpcResponse},
+    },
+};
+
+/// A client for acting on the embedded wallets of users with their JWTs,
+/// rather than with keys held by the app.
+///
+/// Each call takes the JWT of the user whose wallet it acts on. The JWT is
+/// exchanged for the user's authorization key, which signs the request, so
+/// the API only allows calls on wallets owned by that user. Keys are cached
+/// by the client until shortly before they expire, see `JwtUser`.
+///
+/// ```rust,no_run
+/// # use privy_rs::{PrivyClient, WalletId};
+/// # async fn foo(user_jwt: String) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = PrivyClient::new_from_env()?;
+/// let signature = client
+///     .user_wallets()
+///     .ethereum(user_jwt)
+///     .sign_message(WalletId::new("wallet_id"), "hello", None, None)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UserWalletsClient {
+    client: PrivyClient,
+}
+
+impl UserWalletsClient {
+    /// An authorization context signing with the key of the user of
+    /// `user_jwt`.
+    pub fn context(&self, user_jwt: impl Into<String>) -> AuthorizationContext {
+        AuthorizationContext::new().push(JwtUser(self.client.clone(), user_jwt.into()))
+    }
+
+    /// Make a wallet rpc call on a wallet of the user of `user_jwt`.
+    ///
+    /// # Errors
+    ///
+    /// Can fail if the JWT could not be exchanged for the user's key, or if
+    /// the api call fails, such as when the wallet is not the user's.
+    pub async fn rpc(
+        &self,
+        user_jwt: impl Into<String>,
+        wallet_id: impl Into<WalletId>,
+        privy_idempotency_key: Option<&str>,
+        body: &WalletRpcRequestBody,
+    ) -> Result<ResponseValue<WalletRpcResponse>, PrivySignedApiError> {
+        let ctx = self.context(user_jwt);
+        self.client
+            .wallets()
+            .rpc(wallet_id, &ctx, privy_idempotency_key, body)
+            .await
+    }
+
+    /// The ethereum helpers of `WalletsClient::ethereum`, signing with the
+    /// key of the user of `user_jwt` whenever a call is passed `None` in
+    /// place of an authorization context.
+    #[cfg(feature = "ethereum")]
+    pub fn ethereum(&self, user_jwt: impl Into<String>) -> crate::ethereum::EthereumService {
+        self.client
+            .wallets()
+            .ethereum()
+            .with_context(self.context(user_jwt))
+    }
+
+    /// The solana helpers of `WalletsClient::solana`, signing with the key
+    /// of the user of `user_jwt` whenever a call is passed `None` in place
+    /// of an authorization context.
+    #[cfg(feature = "solana")]
+    pub fn solana(&self, user_jwt: impl Into<String>) -> crate::solana::SolanaService {
+        self.client
+            .wallets()
+            .solana()
+            .with_context(self.context(user_jwt))
+    }
+}
+
+impl PrivyClient {
+    /// Access the user wallets client, which acts on the wallets of users
+    /// with their JWTs. See `UserWalletsClient`.
+    pub fn user_wallets(&self) -> UserWalletsClient {
+        UserWalletsClient {
+            client: self.clone(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ethereum"))]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use httpmock::prelude::*;
+    use p256::{NistP256, elliptic_curve::SecretKey};
+
+    use crate::{client::PrivyClientOptions, privy_hpke::testing::seal_p256};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_calls_are_signed_with_the_users_key() {
+        let key = SecretKey::<NistP256>::from_bytes(&[7; 32].into()).unwrap();
+        let expires_at = (SystemTime::now() + Duration::from_secs(3600))
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs_f64();
+
+        let server = MockServer::start_async().await;
+        let authenticate = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/wallets/authenticate")
+                    .json_body_includes(r#"{"user_jwt":"user_jwt"}"#);
+                then.respond_with(move |request: &HttpMockRequest| {
+                    let body: serde_json::Value =
+                        serde_json::from_slice(request.body().as_ref()).unwrap();
+                    let recipient = body["recipient_public_key"].as_str().unwrap();
+                    let sealed = seal_p256(recipient, &key, 0).unwrap();
+
+                    HttpMockResponse::builder()
+                        .status(200)
+                        .header("content-type", "application/json")
+                        .body(
+                            serde_json::json!({
+                                "encrypted_authorization_key": {
+                                    "encryption_type": "HPKE",
+                                    "encapsulated_key": sealed.encapsulated_key,
+                                    "ciphertext": sealed.ciphertext,
+                                },
+                                "expires_at": expires_at,
+                                "wallets": [],
+                            })
+                            .to_string(),
+                        )
+                        .build()
+                });
+            })
+            .await;
+        let rpc = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/wallets/wallet_id/rpc")
+                    .header_exists("privy-authorization-signature");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .json_body(serde_json::json!({
+                        "method": "personal_sign",
+                        "data": { "signature": "0x1234", "encoding": "hex" },
+                    }));
+            })
+            .await;
+
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let ethereum = client.user_wallets().ethereum("user_jwt");
+        for _ in 0..2 {
+            ethereum
+                .sign_message(WalletId::new("wallet_id"), "hello", None, None)
+                .await
+                .unwrap();
+        }
+        assert_eq!(rpc.calls_async().await, 2);
+        // the key is exchanged once, and cached for the second call
+        assert_eq!(authenticate.calls_async().await, 1);
+    }
+}
@@ -0,0 +1,136 @@
+use futures::TryStreamExt;
+
+use super::ResponseValue;
+use crate::{
+    PrivyApiError, UserId,
+    generated::types::{
+        CreateUserWalletBody, GetUsersCursor, LookUpUserByEmailBody, UnlinkUserLinkedAccountBody,
+        UpdateUserCustomMetadataBody, User,
+    },
+    pagination::Paginated,
+    subclients::UsersClient,
+};
+
+/// The largest page `UsersClient::list` returns.
+const MAX_PAGE_SIZE: f64 = 100.0;
+
+impl UsersClient {
+    /// Get the user `user_id`.
+    ///
+    /// # Errors
+    ///
+    /// Can fail if the api call fails, whether due to network issues, auth
+    /// problems, or the Privy API returning an error.
+    pub async fn get(
+        &self,
+        user_id: impl Into<UserId>,
+    ) -> Result<ResponseValue<User>, PrivyApiError> {
+        self._get(user_id.into().as_str()).await
+    }
+
+    /// Delete the user `user_id`.
+    ///
+    /// # Errors
+    ///
+    /// Can fail if the api call fails, whether due to network issues, auth
+    /// problems, or the Privy API returning an error.
+    pub async fn delete(
+        &self,
+        user_id: impl Into<UserId>,
+    ) -> Result<ResponseValue<()>, PrivyApiError> {
+        self._delete(user_id.into().as_str()).await
+    }
+
+    /// Replace the custom metadata of the user `user_id`.
+    ///
+    /// # Errors
+    ///
+    /// Can fail if the api call fails, whether due to network issues, auth
+    /// problems, or the Privy API returning an error.
+    pub async fn set_custom_metadata(
+        &self,
+        user_id: impl Into<UserId>,
+        body: &UpdateUserCustomMetadataBody,
+    ) -> Result<ResponseValue<User>, PrivyApiError> {
+        self._set_custom_metadata(user_id.into().as_str(), body)
+            .await
+    }
+
+    /// Unlink an account from the user `user_id`.
+    ///
+    /// # Errors
+    ///
+    /// Can fail if the api call fails, whether due to network issues, auth
+    /// problems, or the Privy API returning an error.
+    pub async fn unlink_linked_account(
+        &self,
+        user_id: impl Into<UserId>,
+        body: &UnlinkUserLinkedAccountBody,
+    ) -> Result<ResponseValue<User>, PrivyApiError> {
+        self._unlink_linked_account(user_id.into().as_str(), body)
+            .await
+    }
+
+    /// Create wallets for the user `user_id` ahead of their first login.
+    ///
+    /// # Errors
+    ///
+    /// Can fail if the api call fails, whether due to network issues, auth
+    /// problems, or the Privy API returning an error.
+    pub async fn pregenerate_wallets(
+        &self,
+        user_id: impl Into<UserId>,
+        body: &CreateUserWalletBody,
+    ) -> Result<ResponseValue<User>, PrivyApiError> {
+        self._pregenerate_wallets(user_id.into().as_str(), body)
+            .await
+    }
+
+    /// Whether a user with the email address `email` exists, without
+    /// returning the user.
+    ///
+    /// # Errors
+    ///
+    /// Can fail if the lookup fails for any reason other than the user not
+    /// existing.
+    pub async fn exists_by_email(&self, email: impl Into<String>) -> Result<bool, PrivyApiError> {
+        let body = LookUpUserByEmailBody {
+            address: email.into(),
+        };
+        match self.get_by_email_address(&body).await {
+            Ok(_) => Ok(true),
+            Err(e) if e.status() == Some(reqwest::StatusCode::NOT_FOUND) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The number of users in the app.
+    ///
+    /// This is not a cheap count. The API reports no total, and has no way
+    /// to list users without their full payloads, so this downloads every
+    /// user of the app, in one request per 100 users, and discards each
+    /// page once it is counted. Use `exists_by_email`, or a single `list`
+    /// page with a limit of 1, when only presence matters, and call this
+    /// for periodic monitoring rather than on every request.
+    ///
+    /// # Errors
+    ///
+    /// Can fail if any of the pages could not be fetched.
+    pub async fn count(&self) -> Result<usize, PrivyApiError> {
+        self.list_all()
+            .try_fold(0, |count, _| std::future::ready(Ok(count + 1)))
+            .await
+    }
+
+    /// Every user in the app, fetched with the largest page size as the
+    /// stream is polled. See the [`pagination`](crate::pagination) module.
+    ///
+    /// # Errors
+    ///
+    /// Yields an error, and ends, if a page could not be fetched.
+    pub fn list_all(&self) -> Paginated<'_, User> {
+        Paginated::new(move |cursor: Option<GetUsersCursor>| async move {
+            self.list(cursor.as_ref(), Some(MAX_PAGE_SIZE)).await
+        })
+    }
+}
@@ -1,75 +1,341 @@
+use futures::{StreamExt, TryStreamExt};
+
 use super::{Error, ResponseValue, types};
 use crate::{
     AuthorizationContext, PrivyApiError, PrivyExportError, PrivyHpke, PrivySignedApiError,
-    ethereum::EthereumService,
-    generate_authorization_signatures,
+    SecretBytes, Sensitive,
+    activity::{ActivityPeriod, WalletActivitySummary},
+    any_wallet::UnknownWallet,
     generated::types::{
         AdditionalSignerInput, HpkeEncryption, OwnerInput, PrivateKeyInitInput, Wallet,
-        WalletExportRequestBody, WalletImportSupportedChains,
+        WalletExportRequestBody, WalletImportSupportedChains, WalletTransactionsAsset,
+        WalletTransactionsChain,
     },
+    ids::WalletId,
     import::WalletImport,
-    solana::SolanaService,
+    pagination::Paginated,
     subclients::WalletsClient,
+    utils::sign_request,
 };
 
+/// The number of requests `WalletsClient::rpc_batch` keeps in flight.
+const RPC_BATCH_CONCURRENCY: usize = 10;
+
+/// The largest page size the wallets endpoint accepts.
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// Which wallets `WalletsClient::list_all` yields, and how it pages
+/// through them.
+///
+/// Fields may be added in future releases, so construct it with
+/// `..Default::default()`:
+///
+/// ```rust
+/// use privy_rs::subclients::ListWalletsOptions;
+///
+/// let options = ListWalletsOptions::new().with_page_size(20).with_limit(50);
+/// ```
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ListWalletsOptions {
+    /// Only list wallets on this chain type.
+    pub chain_type: Option<types::WalletChainType>,
+    /// Only list the wallets of this user.
+    pub user_id: Option<String>,
+    /// Only list wallets with this external id.
+    pub external_id: Option<String>,
+    /// How many wallets to fetch per request, up to 100. `None` fetches
+    /// 100.
+    pub page_size: Option<u32>,
+    /// Stop after this many wallets, without fetching further pages.
+    /// `None` yields every wallet.
+    pub limit: Option<usize>,
+}
+
+impl ListWalletsOptions {
+    /// Creates a new `ListWalletsOptions` listing every wallet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only list wallets on `chain_type`.
+    pub fn with_chain_type(mut self, chain_type: types::WalletChainType) -> Self {
+        self.chain_type = Some(chain_type);
+        self
+    }
+
+    /// Only list the wallets of `user_id`.
+    pub fn with_user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.user_id = Some(user_id.into());
+        self
+    }
+
+    /// Fetch `page_size` wallets per request.
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Stop after `limit` wallets.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// A signature from `WalletsClient::sign_message_auto`, tagged with the
+/// method that produced it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MessageSignature {
+    /// A `personal_sign` signature from an ethereum wallet, hex encoded.
+    PersonalSign(String),
+    /// A `signMessage` signature from a solana wallet, base64 encoded.
+    SignMessage(String),
+    /// A `raw_sign` signature of the SHA-256 digest of the message, from a
+    /// wallet on any other chain, hex encoded.
+    Raw(String),
+}
+
+impl MessageSignature {
+    /// The encoded signature.
+    #[must_use]
+    pub fn signature(&self) -> &str {
+        match self {
+            Self::PersonalSign(signature) | Self::SignMessage(signature) | Self::Raw(signature) => {
+                signature
+            }
+        }
+    }
+}
+
 impl WalletsClient {
+    /// Create a wallet
+    ///
+    /// With an idempotency key, the API creates the wallet at most once
+    /// however many times the request is sent.
+    ///
+    /// # Errors
+    ///
+    /// Can fail if the api call fails, whether that be due to network issues,
+    /// auth problems, or the Privy API returning an error.
+    pub async fn create<'a>(
+        &'a self,
+        privy_idempotency_key: Option<&'a str>,
+        body: &'a types::CreateWalletBody,
+    ) -> Result<ResponseValue<Wallet>, PrivyApiError> {
+        let response = self._create(privy_idempotency_key, body).await?;
+        self.shared.wallet_hooks.created(&response);
+        Ok(response)
+    }
+
     /// Make a wallet rpc call
     ///
+    /// The client's interceptors see the transaction in the body, if any,
+    /// before it is signed, see [`interceptor`](crate::interceptor).
+    ///
     /// # Errors
     ///
-    /// Can fail either if the authorization signature could not be generated,
+    /// Can fail with `PrivySignedApiError::Rejected` if an interceptor rejects
+    /// the request, if the authorization signature could not be generated,
     /// or if the api call fails whether than be due to network issues, auth problems,
     /// or the Privy API returning an error.
     pub async fn rpc<'a>(
         &'a self,
-        wallet_id: &'a str,
+        wallet_id: impl Into<WalletId>,
         ctx: &'a AuthorizationContext,
         privy_idempotency_key: Option<&'a str>,
         body: &'a crate::generated::types::WalletRpcRequestBody,
     ) -> Result<ResponseValue<crate::generated::types::WalletRpcResponse>, PrivySignedApiError>
     {
-        let sig = generate_authorization_signatures(
+        let wallet_id = wallet_id.into();
+        let intercepted = self
+            .shared
+            .interceptors
+            .intercept_rpc(&wallet_id, body)
+            .await?;
+        let body = intercepted.as_ref().unwrap_or(body);
+
+        let sig = sign_request(
             ctx,
             &self.app_id,
             crate::Method::POST,
-            format!("{}/v1/wallets/{}/rpc", self.base_url, wallet_id),
+            &format!("{}/v1/wallets/{}/rpc", self.base_url, wallet_id),
             body,
-            privy_idempotency_key.map(|k| k.to_owned()),
+            privy_idempotency_key,
+            &self.shared.request_options,
         )
         .await?;
 
-        Ok(self
-            ._rpc(wallet_id, Some(&sig), privy_idempotency_key, None, body)
-            .await?)
+        if self.shared.dry_run {
+            tracing::info!(%wallet_id, ?body, "dry run: skipping wallet rpc");
+            let response = crate::dry_run::rpc_response(body).ok_or_else(|| {
+                Error::InvalidRequest("rpc method is not supported in dry run mode".to_string())
+            })?;
+            return Ok(ResponseValue::new(
+                response,
+                reqwest::StatusCode::OK,
+                Default::default(),
+            ));
+        }
+
+        let response = self
+            ._rpc(&wallet_id, Some(&sig), privy_idempotency_key, None, body)
+            .await?;
+        self.shared.response_sinks.record(
+            "rpc",
+            &wallet_id,
+            privy_idempotency_key,
+            body,
+            &response,
+        );
+        Ok(response)
+    }
+
+    /// Make several wallet rpc calls against one wallet, returning the
+    /// results in the same order as `bodies`.
+    ///
+    /// The API has no batch rpc endpoint, so each body is still signed and
+    /// sent as its own request, but up to `RPC_BATCH_CONCURRENCY` of them
+    /// are signed and in flight at once. If `privy_idempotency_key` is set,
+    /// the request at index `i` uses `{privy_idempotency_key}:{i}`, so
+    /// retrying the whole batch with the same key is safe.
+    ///
+    /// # Errors
+    ///
+    /// Each call fails independently, for the same reasons as [`Self::rpc`].
+    pub async fn rpc_batch(
+        &self,
+        wallet_id: impl Into<WalletId>,
+        ctx: &AuthorizationContext,
+        privy_idempotency_key: Option<&str>,
+        bodies: &[crate::generated::types::WalletRpcRequestBody],
+    ) -> Vec<Result<ResponseValue<crate::generated::types::WalletRpcResponse>, PrivySignedApiError>>
+    {
+        let wallet_id = wallet_id.into();
+
+        futures::stream::iter(bodies.iter().enumerate())
+            .map(|(index, body)| {
+                let wallet_id = &wallet_id;
+                let idempotency_key = privy_idempotency_key.map(|key| format!("{key}:{index}"));
+                async move {
+                    self.rpc(wallet_id, ctx, idempotency_key.as_deref(), body)
+                        .await
+                }
+            })
+            .buffered(RPC_BATCH_CONCURRENCY)
+            .collect()
+            .await
     }
 
     /// Make a wallet raw sign call
     ///
+    /// The client's interceptors see the body before it is signed, see
+    /// [`interceptor`](crate::interceptor).
+    ///
     /// # Errors
     ///
-    /// Can fail either if the authorization signature could not be generated,
+    /// Can fail with `PrivySignedApiError::Rejected` if an interceptor rejects
+    /// the request, if the authorization signature could not be generated,
     /// or if the api call fails whether than be due to network issues, auth problems,
     /// or the Privy API returning an error.
     pub async fn raw_sign<'a>(
         &'a self,
-        wallet_id: &'a str,
+        wallet_id: impl Into<WalletId>,
         ctx: &'a AuthorizationContext,
         privy_idempotency_key: Option<&'a str>,
         body: &'a crate::generated::types::RawSignInput,
     ) -> Result<ResponseValue<crate::generated::types::RawSignResponse>, PrivySignedApiError> {
-        let sig = generate_authorization_signatures(
+        let wallet_id = wallet_id.into();
+        let intercepted = self
+            .shared
+            .interceptors
+            .intercept_raw_sign(&wallet_id, body)
+            .await?;
+        let body = intercepted.as_ref().unwrap_or(body);
+
+        let sig = sign_request(
+            ctx,
+            &self.app_id,
+            crate::Method::POST,
+            &format!("{}/v1/wallets/{}/raw_sign", self.base_url, wallet_id),
+            body,
+            privy_idempotency_key,
+            &self.shared.request_options,
+        )
+        .await?;
+
+        if self.shared.dry_run {
+            tracing::info!(%wallet_id, ?body, "dry run: skipping wallet raw sign");
+            let response = crate::dry_run::raw_sign_response(body);
+            return Ok(ResponseValue::new(
+                response,
+                reqwest::StatusCode::OK,
+                Default::default(),
+            ));
+        }
+
+        let response = self
+            ._raw_sign(&wallet_id, Some(&sig), privy_idempotency_key, None, body)
+            .await?;
+        self.shared.response_sinks.record(
+            "raw_sign",
+            &wallet_id,
+            privy_idempotency_key,
+            body,
+            &response,
+        );
+        Ok(response)
+    }
+
+    /// Transfer an asset from a wallet
+    ///
+    /// The client's interceptors see the transfer before it is signed, see
+    /// [`interceptor`](crate::interceptor).
+    ///
+    /// # Errors
+    ///
+    /// Can fail with `PrivySignedApiError::Rejected` if an interceptor rejects
+    /// the request, if the authorization signature could not be generated,
+    /// or if the api call fails whether than be due to network issues, auth problems,
+    /// or the Privy API returning an error.
+    pub async fn transfer<'a>(
+        &'a self,
+        wallet_id: impl Into<WalletId>,
+        ctx: &'a AuthorizationContext,
+        privy_idempotency_key: Option<&'a str>,
+        body: &'a crate::generated::types::TransferRequestBody,
+    ) -> Result<ResponseValue<crate::generated::types::TransferActionResponse>, PrivySignedApiError>
+    {
+        let wallet_id = wallet_id.into();
+        let intercepted = self
+            .shared
+            .interceptors
+            .intercept_transfer(&wallet_id, body)
+            .await?;
+        let body = intercepted.as_ref().unwrap_or(body);
+
+        let sig = sign_request(
             ctx,
             &self.app_id,
             crate::Method::POST,
-            format!("{}/v1/wallets/{}/raw_sign", self.base_url, wallet_id),
+            &format!("{}/v1/wallets/{}/transfer", self.base_url, wallet_id),
             body,
-            privy_idempotency_key.map(|k| k.to_owned()),
+            privy_idempotency_key,
+            &self.shared.request_options,
         )
         .await?;
 
-        Ok(self
-            ._raw_sign(wallet_id, Some(&sig), privy_idempotency_key, None, body)
-            .await?)
+        let response = self
+            ._transfer(&wallet_id, Some(&sig), privy_idempotency_key, None, body)
+            .await?;
+        self.shared.response_sinks.record(
+            "transfer",
+            &wallet_id,
+            privy_idempotency_key,
+            body,
+            &response,
+        );
+        Ok(response)
     }
 
     /// Update a wallet
@@ -80,21 +346,25 @@ impl WalletsClient {
     /// or if the api call fails whether than be due to
     pub async fn update<'a>(
         &'a self,
-        wallet_id: &'a str,
+        wallet_id: impl Into<WalletId>,
         ctx: &'a AuthorizationContext,
         body: &'a crate::generated::types::WalletUpdateRequestBody,
     ) -> Result<ResponseValue<crate::generated::types::Wallet>, PrivySignedApiError> {
-        let sig = generate_authorization_signatures(
+        let wallet_id = wallet_id.into();
+        let sig = sign_request(
             ctx,
             &self.app_id,
             crate::Method::PATCH,
-            format!("{}/v1/wallets/{}", self.base_url, wallet_id),
+            &format!("{}/v1/wallets/{}", self.base_url, wallet_id),
             body,
             None,
+            &self.shared.request_options,
         )
         .await?;
 
-        Ok(self._update(wallet_id, Some(&sig), None, body).await?)
+        let response = self._update(&wallet_id, Some(&sig), None, body).await?;
+        self.shared.wallet_hooks.updated(&response);
+        Ok(response)
     }
 
     /// Export a wallet
@@ -108,9 +378,10 @@ impl WalletsClient {
     /// a `PrivyExportError::Key` will be returned.
     pub async fn export<'a>(
         &'a self,
-        wallet_id: &'a str,
+        wallet_id: impl Into<WalletId>,
         ctx: &'a AuthorizationContext,
-    ) -> Result<zeroize::Zeroizing<Vec<u8>>, PrivyExportError> {
+    ) -> Result<SecretBytes, PrivyExportError> {
+        let wallet_id = wallet_id.into();
         let privy_hpke = PrivyHpke::new();
         let body = WalletExportRequestBody {
             encryption_type: HpkeEncryption::Hpke,
@@ -118,26 +389,38 @@ impl WalletsClient {
             recipient_public_key: privy_hpke.public_key()?,
         };
 
-        let sig = generate_authorization_signatures(
+        let sig = sign_request(
             ctx,
             &self.app_id,
             crate::Method::POST,
-            format!("{}/v1/wallets/{}/export", self.base_url, wallet_id),
+            &format!("{}/v1/wallets/{}/export", self.base_url, wallet_id),
             &body,
             None,
+            &self.shared.request_options,
         )
         .await?;
 
-        let resp = self._export(wallet_id, Some(&sig), None, &body).await?;
+        let resp = self._export(&wallet_id, Some(&sig), None, &body).await?;
 
-        tracing::debug!("Encapsulated key: {:?}", resp);
+        tracing::debug!(
+            "Received export of wallet {}, encapsulated key {}, ciphertext {}",
+            wallet_id,
+            Sensitive::new(&resp.encapsulated_key),
+            Sensitive::new(&resp.ciphertext)
+        );
 
-        Ok(privy_hpke.decrypt_raw(&resp.encapsulated_key, &resp.ciphertext)?)
+        let key = privy_hpke.decrypt_raw(&resp.encapsulated_key, &resp.ciphertext)?;
+        self.shared.wallet_hooks.exported(&wallet_id);
+        Ok(key)
     }
 
     /// Import a wallet into the Privy app
     ///
     /// # Errors
+    ///
+    /// Fails with `PrivyApiError::InvalidRequest` if `address` is not an
+    /// address on `chain_type`, see `privy_rs::address`, and otherwise if
+    /// the Privy API rejects the import.
     pub async fn import(
         &self,
         address: String,
@@ -147,7 +430,9 @@ impl WalletsClient {
         policy_ids: Vec<String>,
         additional_signers: Option<AdditionalSignerInput>,
     ) -> Result<ResponseValue<Wallet>, PrivyApiError> {
-        WalletImport::new(
+        crate::address::validate_address(&chain_type.to_string(), &address)
+            .map_err(|e| Error::InvalidRequest(e.to_string()))?;
+        let wallet = WalletImport::new(
             self.clone(),
             crate::generated::types::WalletImportInitBody::PrivateKeyInitInput(
                 PrivateKeyInitInput {
@@ -161,7 +446,9 @@ impl WalletsClient {
         )
         .await?
         .submit(private_key_hex, owner, policy_ids, additional_signers)
-        .await
+        .await?;
+        self.shared.wallet_hooks.imported(&wallet);
+        Ok(wallet)
     }
 
     pub(crate) async fn submit_import<'a>(
@@ -171,13 +458,273 @@ impl WalletsClient {
         self._submit_import(body).await
     }
 
+    /// Fetch a wallet like `get`, but return a wallet on a chain type that
+    /// the bundled spec does not list yet as an `UnknownWallet` rather than
+    /// failing to deserialize it.
+    pub(crate) async fn get_any(
+        &self,
+        wallet_id: &WalletId,
+    ) -> Result<Result<Wallet, UnknownWallet>, PrivyApiError> {
+        let started = std::time::Instant::now();
+        let result = match self.client.get_wallet(wallet_id).await {
+            Ok(value) => Ok(value),
+            Err(error) => Err(crate::errors::enrich_api_error(error, None).await),
+        };
+        self.shared
+            .latency
+            .record("get_wallet", started.elapsed(), &result);
+
+        match result {
+            Ok(wallet) => Ok(Ok(wallet.into_inner())),
+            Err(error) => match UnknownWallet::from_error(&error) {
+                Some(wallet) => Ok(Err(wallet)),
+                #[cfg(feature = "strict-validation")]
+                None => Err(crate::validation::check_response("get_wallet", error)),
+                #[cfg(not(feature = "strict-validation"))]
+                None => Err(error),
+            },
+        }
+    }
+
+    /// Summarize a wallet's activity over a period of time
+    ///
+    /// This pages through the wallet's transaction history for the given
+    /// chain and asset(s) and aggregates the transactions created within
+    /// `period` into a `WalletActivitySummary`, containing the transaction
+    /// count, the volume sent and received per asset, and the set of unique
+    /// counterparties. The history is returned newest first, so paging stops
+    /// at the first page reaching back before the start of `period`.
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # use privy_rs::{PrivyClient, WalletId, activity::ActivityPeriod, generated::types::*};
+    /// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PrivyClient::new_from_env()?;
+    /// let summary = client
+    ///     .wallets()
+    ///     .activity_summary(
+    ///         WalletId::new("wallet_id"),
+    ///         WalletTransactionsChain::Base,
+    ///         &WalletEthereumAsset::Usdc.into(),
+    ///         ActivityPeriod::last(Duration::from_secs(7 * 24 * 60 * 60)),
+    ///     )
+    ///     .await?;
+    /// println!("{} transactions this week", summary.transaction_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Can fail if any of the underlying transaction pages could not be fetched.
+    pub async fn activity_summary(
+        &self,
+        wallet_id: impl Into<WalletId>,
+        chain: WalletTransactionsChain,
+        asset: &WalletTransactionsAsset,
+        period: ActivityPeriod,
+    ) -> Result<WalletActivitySummary, PrivyApiError> {
+        let wallet_id = wallet_id.into();
+        let mut summary = WalletActivitySummary::new(wallet_id.to_string(), period);
+        let transactions = self.transactions();
+        let mut pages = std::pin::pin!(transactions.pages(wallet_id, chain, Some(asset.clone())));
+
+        while let Some((page, _)) = pages.try_next().await? {
+            for transaction in &page {
+                summary.record(transaction);
+            }
+            let reached_start = page
+                .iter()
+                .any(|transaction| period.starts_after_millis(transaction.created_at));
+            if reached_start {
+                break;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// The number of wallets in the app, optionally only those on
+    /// `chain_type`.
+    ///
+    /// The API does not report a total, so this pages through every wallet
+    /// with the largest page size and discards each page once it is
+    /// counted.
+    ///
+    /// # Errors
+    ///
+    /// Can fail if any of the pages could not be fetched.
+    pub async fn count(
+        &self,
+        chain_type: Option<types::WalletChainType>,
+    ) -> Result<usize, PrivyApiError> {
+        let options = ListWalletsOptions {
+            chain_type,
+            ..Default::default()
+        };
+        self.list_all(options)
+            .try_fold(0, |count, _| std::future::ready(Ok(count + 1)))
+            .await
+    }
+
+    /// Every wallet in the app matching `options`, following the cursor of
+    /// each page to the next.
+    ///
+    /// Pages are fetched lazily, as the stream is polled, so stopping
+    /// early, by dropping the stream, taking from it, or with
+    /// `ListWalletsOptions::limit`, fetches no further pages. See the
+    /// [`pagination`](crate::pagination) module:
+    ///
+    /// ```rust,no_run
+    /// # use privy_rs::{PrivyClient, subclients::ListWalletsOptions};
+    /// # use futures::TryStreamExt;
+    /// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PrivyClient::new_from_env()?;
+    /// let wallets = client.wallets();
+    /// let mut stream = wallets.list_all(ListWalletsOptions::new().with_user_id("user_id"));
+    /// while let Some(wallet) = stream.try_next().await? {
+    ///     println!("{} {}", wallet.id, wallet.address);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Yields an error, and ends, if a page could not be fetched.
+    pub fn list_all(&self, options: ListWalletsOptions) -> Paginated<'_, Wallet> {
+        let page_size = f64::from(
+            options
+                .page_size
+                .unwrap_or(MAX_PAGE_SIZE)
+                .min(MAX_PAGE_SIZE),
+        );
+        let limit = options.limit;
+
+        Paginated::new(move |cursor: Option<types::GetWalletsCursor>| {
+            let options = options.clone();
+            async move {
+                self.list(
+                    None,
+                    options.chain_type,
+                    cursor.as_ref(),
+                    options.external_id.as_deref(),
+                    Some(page_size),
+                    options.user_id.as_deref(),
+                )
+                .await
+            }
+        })
+        .limit(limit)
+    }
+
+    /// Sign `message` with a wallet on any chain, for example to prove
+    /// ownership of it, using the message signing method of its chain:
+    /// `personal_sign` for ethereum, `signMessage` for solana, and
+    /// otherwise `raw_sign` of the SHA-256 digest of the message.
+    ///
+    /// Ethereum and solana wallets fall back to `raw_sign` as well when the
+    /// `ethereum` or `solana` feature is disabled, and so do wallets on chain
+    /// types the bundled spec does not list yet.
+    ///
+    /// ```rust,no_run
+    /// # use privy_rs::{AuthorizationContext, PrivyClient, WalletId};
+    /// # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = PrivyClient::new_from_env()?;
+    /// let ctx = AuthorizationContext::new();
+    ///
+    /// let signature = client
+    ///     .wallets()
+    ///     .sign_message_auto(WalletId::new("wallet_id"), b"I own this wallet", &ctx)
+    ///     .await?;
+    /// println!("{signature:?}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Can fail if the wallet could not be fetched, or if signing fails.
+    pub async fn sign_message_auto(
+        &self,
+        wallet_id: impl Into<WalletId>,
+        message: &[u8],
+        ctx: &AuthorizationContext,
+    ) -> Result<MessageSignature, PrivySignedApiError> {
+        let wallet_id = wallet_id.into();
+        let chain_type = self.get_any(&wallet_id).await?.ok().map(|w| w.chain_type);
+
+        match chain_type {
+            #[cfg(feature = "ethereum")]
+            Some(types::WalletChainType::Ethereum) => {
+                match self
+                    .ethereum()
+                    .sign_message_bytes(&wallet_id, message, ctx, None)
+                    .await?
+                    .into_inner()
+                {
+                    types::WalletRpcResponse::EthereumPersonalSignRpcResponse(response) => {
+                        Ok(MessageSignature::PersonalSign(response.data.signature))
+                    }
+                    other => Err(Error::InvalidRequest(format!(
+                        "Unexpected response to personal_sign: {other:?}"
+                    ))
+                    .into()),
+                }
+            }
+            #[cfg(feature = "solana")]
+            Some(types::WalletChainType::Solana) => {
+                use base64::{Engine, engine::general_purpose::STANDARD};
+
+                match self
+                    .solana()
+                    .sign_message(&wallet_id, &STANDARD.encode(message), ctx, None)
+                    .await?
+                    .into_inner()
+                {
+                    types::WalletRpcResponse::SolanaSignMessageRpcResponse(response) => {
+                        Ok(MessageSignature::SignMessage(response.data.signature))
+                    }
+                    other => Err(Error::InvalidRequest(format!(
+                        "Unexpected response to signMessage: {other:?}"
+                    ))
+                    .into()),
+                }
+            }
+            _ => {
+                let body = types::RawSignInput {
+                    params: types::RawSignBytesParams {
+                        bytes: hex::encode(message).parse().map_err(
+                            |e: types::error::ConversionError| Error::InvalidRequest(e.to_string()),
+                        )?,
+                        encoding: types::RawSignBytesEncoding::Hex,
+                        hash_function: types::RawSignBytesHashFunction::Sha256,
+                    }
+                    .into(),
+                };
+                let response = self.raw_sign(&wallet_id, ctx, None, &body).await?;
+                Ok(MessageSignature::Raw(
+                    response.into_inner().data.signature.to_string(),
+                ))
+            }
+        }
+    }
+
     /// Returns an `EthereumService` instance for interacting with the Ethereum API
-    pub fn ethereum(&self) -> EthereumService {
-        EthereumService::new(self.clone())
+    #[cfg(feature = "ethereum")]
+    pub fn ethereum(&self) -> crate::ethereum::EthereumService {
+        crate::ethereum::EthereumService::new(self.clone())
     }
 
     /// Returns an `SolanaService` instance for interacting with the Solana API
-    pub fn solana(&self) -> SolanaService {
-        SolanaService::new(self.clone())
+    #[cfg(feature = "solana")]
+    pub fn solana(&self) -> crate::solana::SolanaService {
+        crate::solana::SolanaService::new(self.clone())
+    }
+
+    /// The interceptors of the client this was created from.
+    #[cfg_attr(not(feature = "alloy"), allow(dead_code))]
+    pub(crate) fn interceptors(&self) -> &crate::interceptor::Interceptors {
+        &self.shared.interceptors
     }
 }
@@ -0,0 +1,268 @@
+//! `tracing` spans and OpenTelemetry metrics for API calls and the signing
+//! pipeline.
+//!
+//! Every call a subclient makes to the API runs in a `privy.request` span,
+//! whatever the features, with the `operation`, such as `wallet_rpc`, the
+//! `http.request.method`, the `url.template` of the endpoint, the
+//! `wallet_id` and `idempotency_key` of calls that have them, and the
+//! `http.response.status_code` once the call is done. Retries, signing and
+//! the SDK's own logs for the call are nested in it, so a slow signing
+//! request can be followed end-to-end.
+//!
+//! With the `otel` feature, the SDK also opens spans around the steps of a
+//! request whose performance is worth watching, shaped so that
+//! `tracing-opentelemetry` exports them as OpenTelemetry spans and span
+//! events:
+//!
+//! - a `privy.sign` span around each signer of an `AuthorizationContext`,
+//!   with the Rust type of the `signer`
+//! - a `privy.hpke.decrypt` span around decrypting an authorization key or
+//!   exported wallet
+//! - a `privy.retry` event on the current span for every retried attempt
+//!   of a request, with the `operation`, the `attempt` that failed, the
+//!   `max_attempts`, the `delay_ms` before the next one and the `error`
+//!
+//! These spans, and `privy.request`, end with a `privy.completed` event
+//! carrying the `duration_ms` of the step, and record an `otel.status_code`
+//! of `OK` or `ERROR`, with the `exception.message` of failures. Each API
+//! call also records its duration in the `privy.request.duration_ms`
+//! histogram, by `operation` and `http.response.status_code`, which the
+//! `MetricsLayer` of `tracing-opentelemetry` exports as an OpenTelemetry
+//! histogram. All of these are emitted at the `INFO` level, so the
+//! subscriber that exports them has to let that through for the
+//! `privy_rs` target.
+//!
+//! Without the feature none of these are emitted, and the SDK only logs
+//! retries as warnings.
+//!
+//! For counters and histograms in the `metrics` crate ecosystem, such as
+//! for Prometheus, see `privy_rs::metrics`, enabled by the `metrics`
+//! feature.
+
+use std::{
+    fmt,
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use tracing::{Instrument, Span, field::Empty};
+
+use crate::{PrivyApiError, generated::ResponseValue, middleware::RequestInfo};
+
+/// Whether the spans are emitted, see the [module docs](self).
+const ENABLED: bool = cfg!(feature = "otel");
+
+/// Run `future`, a call to the API described by `request`, in a
+/// `privy.request` span, recording its duration with the `otel` feature.
+pub(crate) async fn request<T>(
+    request: RequestInfo,
+    wallet_id: Option<&str>,
+    idempotency_key: Option<&str>,
+    future: impl Future<Output = Result<ResponseValue<T>, PrivyApiError>>,
+) -> Result<ResponseValue<T>, PrivyApiError> {
+    let span = tracing::info_span!(
+        "privy.request",
+        operation = request.operation,
+        http.request.method = request.method,
+        url.template = request.path,
+        wallet_id,
+        idempotency_key,
+        http.response.status_code = Empty,
+        otel.status_code = Empty,
+        exception.message = Empty,
+    );
+    let started = Instant::now();
+    let result = future.instrument(span.clone()).await;
+
+    let status = match &result {
+        Ok(response) => Some(response.status()),
+        Err(error) => error.status(),
+    };
+    if let Some(status) = status {
+        span.record("http.response.status_code", status.as_u16());
+    }
+    if ENABLED {
+        tracing::info!(
+            parent: &span,
+            histogram.privy.request.duration_ms = started.elapsed().as_secs_f64() * 1000.0,
+            operation = request.operation,
+            http.response.status_code = status.map(|status| status.as_u16()),
+        );
+        complete(&span, started, &result);
+    }
+    #[cfg(feature = "metrics")]
+    crate::metrics::request(
+        request.operation,
+        status.map(|status| status.as_u16()),
+        result.is_ok(),
+        started.elapsed(),
+    );
+    result
+}
+
+/// Run `future`, a signature by a signer of type `signer`, in a
+/// `privy.sign` span.
+pub(crate) async fn sign<T, E: fmt::Display>(
+    signer: &'static str,
+    future: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    let started = Instant::now();
+    let result = if ENABLED {
+        let span = tracing::info_span!(
+            "privy.sign",
+            signer,
+            otel.status_code = Empty,
+            exception.message = Empty,
+        );
+        let result = future.instrument(span.clone()).await;
+        complete(&span, started, &result);
+        result
+    } else {
+        future.await
+    };
+    #[cfg(feature = "metrics")]
+    crate::metrics::signature(signer, result.is_ok(), started.elapsed());
+    result
+}
+
+/// Run `decrypt` in a `privy.hpke.decrypt` span.
+pub(crate) fn hpke_decrypt<T, E: fmt::Display>(
+    decrypt: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    let started = Instant::now();
+    let result = if ENABLED {
+        let span = tracing::info_span!(
+            "privy.hpke.decrypt",
+            otel.status_code = Empty,
+            exception.message = Empty,
+        );
+        let result = span.in_scope(decrypt);
+        complete(&span, started, &result);
+        result
+    } else {
+        decrypt()
+    };
+    #[cfg(feature = "metrics")]
+    crate::metrics::hpke_decrypt(result.is_ok(), started.elapsed());
+    result
+}
+
+/// Record a `privy.retry` event for `attempt` of `operation`, which failed
+/// with `error` and is retried after `delay`.
+pub(crate) fn retry(
+    operation: &'static str,
+    attempt: u32,
+    max_attempts: u32,
+    delay: Duration,
+    error: &dyn fmt::Display,
+) {
+    if ENABLED {
+        tracing::info!(
+            name: "privy.retry",
+            operation,
+            attempt,
+            max_attempts,
+            delay_ms = delay.as_secs_f64() * 1000.0,
+            error = %error,
+        );
+    }
+}
+
+fn complete<T, E: fmt::Display>(span: &Span, started: Instant, result: &Result<T, E>) {
+    match result {
+        Ok(_) => {
+            span.record("otel.status_code", "OK");
+        }
+        Err(error) => {
+            span.record("otel.status_code", "ERROR");
+            span.record("exception.message", tracing::field::display(error));
+        }
+    }
+    tracing::info!(
+        name: "privy.completed",
+        parent: span,
+        duration_ms = started.elapsed().as_secs_f64() * 1000.0,
+    );
+}
+
+#[cfg(all(test, feature = "otel"))]
+mod tests {
+    use tracing_test::traced_test;
+
+    use super::*;
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_spans_record_duration_and_status() {
+        let _ = sign("PrivateKey", async { Ok::<_, String>(()) }).await;
+        let _ = hpke_decrypt(|| Err::<(), _>("bad ciphertext"));
+        retry("jwt_exchange", 1, 3, Duration::from_millis(200), &"503");
+
+        logs_assert(|lines: &[&str]| {
+            let find = |needle: &str| {
+                lines
+                    .iter()
+                    .find(|line| line.contains(needle))
+                    .ok_or_else(|| format!("no line with {needle:?} in {lines:?}"))
+            };
+            let signed = find("privy.sign{signer=\"PrivateKey\"")?;
+            if !signed.contains("duration_ms") {
+                return Err(format!("no duration in {signed}"));
+            }
+            find("otel.status_code=\"ERROR\" exception.message=bad ciphertext")?;
+            find("operation=\"jwt_exchange\" attempt=1 max_attempts=3 delay_ms=200")?;
+            Ok(())
+        });
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_api_calls_are_spanned_and_timed() {
+        use httpmock::prelude::*;
+
+        use crate::{PrivyClient, client::PrivyClientOptions};
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/wallets/wallet_1");
+                then.status(404);
+            })
+            .await;
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let _ = client.wallets().get("wallet_1").await;
+
+        logs_assert(|lines: &[&str]| {
+            let completed = lines
+                .iter()
+                .find(|line| line.contains("privy.request{") && line.contains(": duration_ms"))
+                .ok_or_else(|| format!("no completed request in {lines:?}"))?;
+            for field in [
+                "operation=\"get_wallet\"",
+                "url.template=\"/v1/wallets/{wallet_id}\"",
+                "wallet_id=\"wallet_1\"",
+                "http.response.status_code=404",
+                "otel.status_code=\"ERROR\"",
+            ] {
+                if !completed.contains(field) {
+                    return Err(format!("no {field} in {completed}"));
+                }
+            }
+            if !lines
+                .iter()
+                .any(|line| line.contains("histogram.privy.request.duration_ms"))
+            {
+                return Err(format!("no duration histogram in {lines:?}"));
+            }
+            Ok(())
+        });
+    }
+}
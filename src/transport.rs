@@ -0,0 +1,318 @@
+//! How a client follows transactions as their status changes.
+//!
+//! Privy serves transaction status over plain request/response HTTP, and
+//! is expected to add endpoints that push status changes instead. A
+//! [`TransactionTransport`] turns whichever of these a client uses into a
+//! stream of updates, so `TransactionsClient::watch` keeps its signature
+//! when a streaming transport is added:
+//!
+//! ```no_run
+//! # use futures::TryStreamExt;
+//! # use privy_rs::PrivyClient;
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?;
+//! let transactions = client.transactions();
+//! let mut updates = transactions.watch("transaction_id");
+//! while let Some(transaction) = updates.try_next().await? {
+//!     println!("{} is {}", transaction.id, transaction.status);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`HttpTransport`], the default, polls `GET /v1/transactions/{id}` at a
+//! fixed interval. With the experimental `experimental-long-poll` feature,
+//! `LongPollTransport` holds each update open until the status changes,
+//! polling quickly at first and backing off, and gives a heartbeat when
+//! nothing changed for a while. Use another transport with
+//! [`PrivyClient::with_transaction_transport`].
+//!
+//! Either way, a stream yields the transaction as it is when the stream
+//! starts, then every time its status changes, and ends once the
+//! transaction is confirmed or has failed.
+//!
+//! [`PrivyClient::with_transaction_transport`]: crate::PrivyClient::with_transaction_transport
+
+use std::{fmt, sync::Arc, time::Duration};
+
+use futures::{StreamExt, stream::BoxStream};
+use tokio::time::Instant;
+
+use crate::{
+    PrivyApiError, PrivyClient,
+    generated::types::{Transaction, TransactionStatus},
+    subclients::TransactionsClient,
+};
+
+/// Turns a way of learning about transactions into a stream of their
+/// updates. See the [module docs](self) for details.
+pub trait TransactionTransport: fmt::Debug {
+    /// The updates to `transaction_id`, fetched through `transactions`.
+    ///
+    /// The stream must yield the transaction as it is when the stream
+    /// starts, then every time its status changes, and end after yielding
+    /// a final status. Failures are yielded as errors; the stream may go
+    /// on after one.
+    fn updates<'a>(
+        &'a self,
+        transactions: &'a TransactionsClient,
+        transaction_id: String,
+    ) -> BoxStream<'a, Result<Transaction, PrivyApiError>>;
+}
+
+/// Follows transactions by polling `GET /v1/transactions/{id}` every
+/// `interval`.
+#[derive(Clone, Debug)]
+pub struct HttpTransport {
+    /// How long to wait between polls.
+    pub interval: Duration,
+}
+
+impl Default for HttpTransport {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(2),
+        }
+    }
+}
+
+impl TransactionTransport for HttpTransport {
+    fn updates<'a>(
+        &'a self,
+        transactions: &'a TransactionsClient,
+        transaction_id: String,
+    ) -> BoxStream<'a, Result<Transaction, PrivyApiError>> {
+        poll(
+            transactions,
+            transaction_id,
+            self.interval,
+            self.interval,
+            None,
+        )
+    }
+}
+
+/// Follows transactions by holding each update open until the status
+/// changes, or until `wait` passes, in which case the unchanged
+/// transaction is yielded again as a heartbeat.
+///
+/// Privy has no endpoint that holds requests open yet, so for now this
+/// polls, every `min_interval` after an update, backing off to every
+/// `max_interval`. It is experimental, and will move to such an endpoint
+/// once there is one.
+#[cfg(feature = "experimental-long-poll")]
+#[derive(Clone, Debug)]
+pub struct LongPollTransport {
+    /// How long to wait between polls right after an update.
+    pub min_interval: Duration,
+    /// How long to wait between polls at most.
+    pub max_interval: Duration,
+    /// How long to hold an update open before giving a heartbeat.
+    pub wait: Duration,
+}
+
+#[cfg(feature = "experimental-long-poll")]
+impl Default for LongPollTransport {
+    fn default() -> Self {
+        Self {
+            min_interval: Duration::from_millis(250),
+            max_interval: Duration::from_secs(5),
+            wait: Duration::from_secs(30),
+        }
+    }
+}
+
+#[cfg(feature = "experimental-long-poll")]
+impl TransactionTransport for LongPollTransport {
+    fn updates<'a>(
+        &'a self,
+        transactions: &'a TransactionsClient,
+        transaction_id: String,
+    ) -> BoxStream<'a, Result<Transaction, PrivyApiError>> {
+        poll(
+            transactions,
+            transaction_id,
+            self.min_interval,
+            self.max_interval,
+            Some(self.wait),
+        )
+    }
+}
+
+/// Whether a transaction will not change status anymore.
+fn is_final(status: TransactionStatus) -> bool {
+    !matches!(
+        status,
+        TransactionStatus::Pending | TransactionStatus::Broadcasted
+    )
+}
+
+/// Where a `poll` stream is at.
+struct Polling {
+    last: Option<TransactionStatus>,
+    /// Polls since the last update, for backing off.
+    attempt: u32,
+    updated_at: Instant,
+    done: bool,
+}
+
+/// Poll `transaction_id`, waiting `min_interval` after an update and
+/// doubling the wait up to `max_interval`, yielding on every change of
+/// status, and when `heartbeat` passes without one.
+fn poll(
+    transactions: &TransactionsClient,
+    transaction_id: String,
+    min_interval: Duration,
+    max_interval: Duration,
+    heartbeat: Option<Duration>,
+) -> BoxStream<'_, Result<Transaction, PrivyApiError>> {
+    let polling = Polling {
+        last: None,
+        attempt: 0,
+        updated_at: Instant::now(),
+        done: false,
+    };
+    futures::stream::try_unfold(polling, move |mut polling| {
+        let transaction_id = transaction_id.clone();
+        async move {
+            if polling.done {
+                return Ok::<_, PrivyApiError>(None);
+            }
+            loop {
+                if polling.last.is_some() {
+                    let backoff = min_interval.saturating_mul(1 << polling.attempt.min(16));
+                    tokio::time::sleep(backoff.min(max_interval)).await;
+                    polling.attempt += 1;
+                }
+
+                let transaction = transactions.get(&transaction_id).await?.into_inner();
+                let changed = polling.last != Some(transaction.status);
+                let heartbeat_due =
+                    heartbeat.is_some_and(|after| polling.updated_at.elapsed() >= after);
+                if changed || heartbeat_due {
+                    if changed {
+                        polling.attempt = 0;
+                    }
+                    polling.last = Some(transaction.status);
+                    polling.updated_at = Instant::now();
+                    polling.done = is_final(transaction.status);
+                    return Ok(Some((transaction, polling)));
+                }
+            }
+        }
+    })
+    .boxed()
+}
+
+/// The transaction transport of a client, `HttpTransport` unless another
+/// was set with `PrivyClient::with_transaction_transport`.
+#[derive(Clone)]
+pub(crate) struct Transport(Arc<dyn TransactionTransport + Send + Sync>);
+
+impl Default for Transport {
+    fn default() -> Self {
+        Self(Arc::new(HttpTransport::default()))
+    }
+}
+
+impl fmt::Debug for Transport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Transport").field(&self.0).finish()
+    }
+}
+
+impl Transport {
+    pub(crate) fn updates<'a>(
+        &'a self,
+        transactions: &'a TransactionsClient,
+        transaction_id: String,
+    ) -> BoxStream<'a, Result<Transaction, PrivyApiError>> {
+        self.0.updates(transactions, transaction_id)
+    }
+}
+
+impl PrivyClient {
+    /// Follow transactions with `transport` rather than `HttpTransport`.
+    /// See the [`transport`](crate::transport) module for details.
+    #[must_use]
+    pub fn with_transaction_transport(
+        mut self,
+        transport: impl TransactionTransport + Send + Sync + 'static,
+    ) -> Self {
+        Arc::make_mut(&mut self.shared).transport = Transport(Arc::new(transport));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::TryStreamExt;
+    use httpmock::prelude::*;
+
+    use super::*;
+    use crate::client::PrivyClientOptions;
+
+    #[tokio::test]
+    async fn test_updates_are_yielded_on_change_until_final() {
+        let statuses = ["pending", "pending", "broadcasted", "confirmed"];
+        let polls = Arc::new(AtomicUsize::new(0));
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                let polls = polls.clone();
+                when.method(GET).path("/v1/transactions/tx_1");
+                then.respond_with(move |_: &HttpMockRequest| {
+                    let poll = polls.fetch_add(1, Ordering::SeqCst);
+                    HttpMockResponse::builder()
+                        .status(200)
+                        .header("content-type", "application/json")
+                        .body(
+                            serde_json::json!({
+                                "id": "tx_1",
+                                "caip2": "eip155:8453",
+                                "created_at": 0,
+                                "status": statuses[poll.min(statuses.len() - 1)],
+                                "wallet_id": "wallet_id",
+                            })
+                            .to_string(),
+                        )
+                        .build()
+                });
+            })
+            .await;
+
+        let client = PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url: server.base_url(),
+                ..Default::default()
+            },
+        )
+        .unwrap()
+        .with_transaction_transport(HttpTransport {
+            interval: Duration::from_millis(10),
+        });
+
+        let transactions = client.transactions();
+        let updates: Vec<_> = transactions
+            .watch("tx_1")
+            .map_ok(|transaction| transaction.status)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            updates,
+            [
+                TransactionStatus::Pending,
+                TransactionStatus::Broadcasted,
+                TransactionStatus::Confirmed
+            ]
+        );
+        // the unchanged second poll is not yielded, and nothing is polled
+        // after the final status
+        assert_eq!(mock.calls_async().await, 4);
+    }
+}
@@ -0,0 +1,49 @@
+//! Usage of a Privy app.
+//!
+//! The Privy API does not expose billing metrics such as monthly active
+//! users or signing volume, so [`PrivyClient::usage`] reports what can be
+//! counted through the API itself: the users and wallets of the app. It
+//! pages through both, so it is meant for periodic monitoring rather than
+//! for every request.
+//!
+//! ```no_run
+//! # use privy_rs::PrivyClient;
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! const WALLET_LIMIT: usize = 10_000;
+//!
+//! let client = PrivyClient::new_from_env()?;
+//! let usage = client.usage().await?;
+//! if usage.wallets > WALLET_LIMIT * 9 / 10 {
+//!     tracing::warn!(?usage, "approaching the wallet limit of the plan");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{PrivyApiError, PrivyClient};
+
+/// The number of users and wallets in an app, see the
+/// [module docs](self).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Usage {
+    /// The number of users.
+    pub users: usize,
+    /// The number of wallets, on any chain.
+    pub wallets: usize,
+}
+
+impl PrivyClient {
+    /// Count the users and wallets of the app, see the
+    /// [`usage`](crate::usage) module.
+    ///
+    /// # Errors
+    /// Fails if any page of users or wallets could not be fetched.
+    pub async fn usage(&self) -> Result<Usage, PrivyApiError> {
+        let users = self.users();
+        let wallets = self.wallets();
+        let (users, wallets) = futures::try_join!(users.count(), wallets.count(None))?;
+
+        Ok(Usage { users, wallets })
+    }
+}
@@ -1,8 +1,11 @@
+use std::sync::Mutex;
+
 use base64::{Engine, engine::general_purpose::STANDARD};
 use futures::TryStreamExt;
 use serde::Serialize;
+use tracing::Instrument;
 
-use crate::{AuthorizationContext, SignatureGenerationError};
+use crate::{AuthorizationContext, SignatureGenerationError, request_options::RequestOptions};
 
 /// A convenience wrapper used as a namespace for utility functions
 pub struct Utils {
@@ -70,24 +73,139 @@ pub fn format_request_for_authorization_signature<S: Serialize>(
     body: S,
     idempotency_key: Option<String>,
 ) -> Result<String, serde_json::Error> {
-    let mut headers = serde_json::Map::new();
-    headers.insert(
-        "privy-app-id".into(),
-        serde_json::Value::String(app_id.to_owned()),
-    );
-    if let Some(key) = idempotency_key {
-        headers.insert(
-            "privy-idempotency-key".to_string(),
-            serde_json::Value::String(key),
-        );
+    let mut canonical = Vec::new();
+    canonicalize_request(
+        &mut canonical,
+        app_id,
+        method,
+        &url,
+        &body,
+        idempotency_key.as_deref(),
+        &[],
+    )?;
+    Ok(String::from_utf8(canonical).expect("canonical JSON is UTF-8"))
+}
+
+/// Serialize the canonical form of a request into `buf`, like
+/// `format_request_for_authorization_signature`, additionally signing over
+/// `signed_headers`, see `RequestOptions::signed_header`.
+fn canonicalize_request<S: Serialize>(
+    buf: &mut Vec<u8>,
+    app_id: &str,
+    method: Method,
+    url: &str,
+    body: &S,
+    idempotency_key: Option<&str>,
+    signed_headers: &[(String, String)],
+) -> Result<(), serde_json::Error> {
+    let request = CanonicalRequest {
+        version: 1,
+        method,
+        url,
+        body,
+        headers: CanonicalHeaders {
+            app_id,
+            idempotency_key,
+            signed: signed_headers,
+        },
+    };
+    serde_json_canonicalizer::to_writer(&request, buf)
+}
+
+/// The same payload as `WalletApiRequestSignatureInput`, borrowing every
+/// part of the request instead of owning it.
+#[derive(Serialize)]
+struct CanonicalRequest<'a, S> {
+    version: u32,
+    method: Method,
+    url: &'a str,
+    body: &'a S,
+    headers: CanonicalHeaders<'a>,
+}
+
+/// The headers of a canonical request. The `privy-` headers take the place
+/// of signed headers of the same name.
+struct CanonicalHeaders<'a> {
+    app_id: &'a str,
+    idempotency_key: Option<&'a str>,
+    signed: &'a [(String, String)],
+}
+
+impl Serialize for CanonicalHeaders<'_> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+        for (name, value) in self.signed {
+            let replaced = name == "privy-app-id"
+                || (name == "privy-idempotency-key" && self.idempotency_key.is_some());
+            if !replaced {
+                map.serialize_entry(name, value)?;
+            }
+        }
+        map.serialize_entry("privy-app-id", self.app_id)?;
+        if let Some(key) = self.idempotency_key {
+            map.serialize_entry("privy-idempotency-key", key)?;
+        }
+        map.end()
     }
+}
 
-    WalletApiRequestSignatureInput::new(method, url)
-        .headers(serde_json::Value::Object(headers))
-        .body(body)
-        .canonicalize()
+/// The most canonical payload buffers kept for reuse.
+const MAX_POOLED_BUFFERS: usize = 32;
+
+/// Buffers that grew past this size for an unusually large body are freed
+/// rather than kept around.
+const MAX_POOLED_CAPACITY: usize = 64 * 1024;
+
+static CANONICAL_BUFFERS: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+
+/// A buffer for the canonical payload of a request, taken from a shared
+/// pool and returned to it on drop, so that signing a steady stream of
+/// requests does not allocate a new payload for each of them.
+struct CanonicalBuffer(Vec<u8>);
+
+impl CanonicalBuffer {
+    fn take() -> Self {
+        let buf = CANONICAL_BUFFERS
+            .lock()
+            .expect("lock poisoned")
+            .pop()
+            .unwrap_or_default();
+        Self(buf)
+    }
 }
 
+impl Drop for CanonicalBuffer {
+    fn drop(&mut self) {
+        if self.0.capacity() > MAX_POOLED_CAPACITY {
+            return;
+        }
+        let mut buf = std::mem::take(&mut self.0);
+        buf.clear();
+        let mut pool = CANONICAL_BUFFERS.lock().expect("lock poisoned");
+        if pool.len() < MAX_POOLED_BUFFERS {
+            pool.push(buf);
+        }
+    }
+}
+
+/// How long a call to a third party JSON-RPC endpoint, such as a preflight
+/// or a simulation, may take before it is given up on.
+#[cfg(any(feature = "ethereum", feature = "solana"))]
+const RPC_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// The HTTP client for third party JSON-RPC endpoints, built once so that
+/// its connections are reused. It is kept apart from the API client, whose
+/// default headers carry the app credentials.
+#[cfg(any(feature = "ethereum", feature = "solana"))]
+pub(crate) static RPC_HTTP: std::sync::LazyLock<reqwest::Client> = std::sync::LazyLock::new(|| {
+    reqwest::Client::builder()
+        .timeout(RPC_TIMEOUT)
+        .build()
+        .unwrap_or_default()
+});
+
 /// Generates an authorization signature for a given request
 ///
 /// # Arguments
@@ -112,23 +230,68 @@ pub async fn generate_authorization_signatures<S: Serialize>(
     body: S,
     idempotency_key: Option<String>,
 ) -> Result<String, SignatureGenerationError> {
-    let canonical =
-        format_request_for_authorization_signature(app_id, method, url, body, idempotency_key)?;
+    sign_request(
+        ctx,
+        app_id,
+        method,
+        &url,
+        &body,
+        idempotency_key.as_deref(),
+        &RequestOptions::default(),
+    )
+    .await
+}
+
+/// Like `generate_authorization_signatures`, additionally signing over the
+/// signed headers of `options`. The subclients sign through this.
+pub(crate) async fn sign_request<S: Serialize>(
+    ctx: &AuthorizationContext,
+    app_id: &str,
+    method: Method,
+    url: &str,
+    body: &S,
+    idempotency_key: Option<&str>,
+    options: &RequestOptions,
+) -> Result<String, SignatureGenerationError> {
+    let span = tracing::debug_span!("authorization_signatures", %url);
+    let mut canonical = CanonicalBuffer::take();
+    canonicalize_request(
+        &mut canonical.0,
+        app_id,
+        method,
+        url,
+        body,
+        idempotency_key,
+        options.signed_headers(),
+    )?;
 
     #[cfg(all(feature = "unsafe_debug", debug_assertions))]
     {
-        tracing::debug!("canonical request data: {}", canonical);
-    }
-
-    Ok(ctx
-        .sign(canonical.as_bytes())
-        .map_ok(|s| {
-            let der_bytes = s.to_der();
-            STANDARD.encode(&der_bytes)
-        })
-        .try_collect::<Vec<_>>()
-        .await?
-        .join(","))
+        tracing::debug!(
+            "canonical request data: {}",
+            String::from_utf8_lossy(&canonical.0)
+        );
+    }
+
+    // a base64 encoded DER signature is at most 96 characters
+    let (signatures, count) = ctx
+        .sign(&canonical.0)
+        .try_fold(
+            (String::with_capacity(97), 0usize),
+            |(mut signatures, count), s| async move {
+                if count > 0 {
+                    signatures.push(',');
+                }
+                STANDARD.encode_string(s.to_der(), &mut signatures);
+                Ok((signatures, count + 1))
+            },
+        )
+        .instrument(span.clone())
+        .await?;
+
+    span.in_scope(|| tracing::debug!("Attaching {} authorization signatures", count));
+
+    Ok(signatures)
 }
 
 /// The HTTP method used in the request.
@@ -520,6 +683,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_idempotency_key_is_a_signed_header() {
+        let canonical = format_request_for_authorization_signature(
+            "test_app_id",
+            Method::POST,
+            "https://api.privy.io/v1/wallets/wallet_id/rpc".to_string(),
+            json!({"method": "personal_sign"}),
+            Some("unique-key-123".to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            canonical,
+            r#"{"body":{"method":"personal_sign"},"headers":{"privy-app-id":"test_app_id","privy-idempotency-key":"unique-key-123"},"method":"POST","url":"https://api.privy.io/v1/wallets/wallet_id/rpc","version":1}"#
+        );
+    }
+
+    #[test]
+    fn test_only_signed_request_options_are_canonicalized() {
+        let options = RequestOptions::new()
+            .header("x-tenant-id", "acme")
+            .unwrap()
+            .signed_header("Privy-Region", "eu")
+            .unwrap();
+
+        let mut canonical = Vec::new();
+        canonicalize_request(
+            &mut canonical,
+            "test_app_id",
+            Method::POST,
+            "https://api.privy.io/v1/wallets",
+            &json!({}),
+            None,
+            options.signed_headers(),
+        )
+        .unwrap();
+        let canonical = String::from_utf8(canonical).unwrap();
+
+        assert_eq!(
+            canonical,
+            r#"{"body":{},"headers":{"privy-app-id":"test_app_id","privy-region":"eu"},"method":"POST","url":"https://api.privy.io/v1/wallets","version":1}"#
+        );
+    }
+
+    #[test]
+    fn test_canonical_request_matches_signature_input() {
+        let options = RequestOptions::new()
+            .signed_header("privy-region", "eu")
+            .unwrap();
+        let body = json!({"method": "personal_sign", "params": {"message": "hi"}});
+
+        let expected = WalletApiRequestSignatureInput::new(
+            Method::POST,
+            "https://api.privy.io/v1/wallets/wallet_id/rpc".to_string(),
+        )
+        .headers(json!({
+            "privy-app-id": "test_app_id",
+            "privy-idempotency-key": "key",
+            "privy-region": "eu",
+        }))
+        .body(&body)
+        .canonicalize()
+        .unwrap();
+
+        // a reused buffer is cleared before it is handed out again
+        for _ in 0..2 {
+            let mut buffer = CanonicalBuffer::take();
+            canonicalize_request(
+                &mut buffer.0,
+                "test_app_id",
+                Method::POST,
+                "https://api.privy.io/v1/wallets/wallet_id/rpc",
+                &body,
+                Some("key"),
+                options.signed_headers(),
+            )
+            .unwrap();
+            assert_eq!(std::str::from_utf8(&buffer.0).unwrap(), expected);
+        }
+    }
+
     #[tokio::test]
     #[traced_test]
     async fn test_sign_canonical_request() {
@@ -0,0 +1,384 @@
+//! Validation of API responses against the bundled OpenAPI spec.
+//!
+//! With the `strict-validation` feature, a response that the generated types
+//! fail to deserialize is checked against the schema `openapi.json` gives for
+//! it, and the error is replaced by one listing every mismatch by its JSON
+//! pointer. serde only reports the first problem, by line and column, which
+//! makes it hard to tell which field Privy changed.
+//!
+//! The spec is parsed on first use, so this is meant for debug builds and
+//! integration tests rather than production. Responses that deserialize are
+//! not checked again, the generated types already enforce their schema.
+//!
+//! [`validate_response`] can also be called directly:
+//!
+//! ```
+//! # use privy_rs::validation::validate_response;
+//! let body = serde_json::json!({ "id": "wallet_id", "chain_type": "dogecoin" });
+//! let mismatches = validate_response("get_wallet", &body).expect("operation exists");
+//! assert!(mismatches.iter().any(|m| m.pointer == "/chain_type"));
+//! ```
+
+use std::{fmt, sync::LazyLock};
+
+use serde_json::{Map, Value};
+
+use crate::PrivyApiError;
+
+static SPEC: LazyLock<Value> = LazyLock::new(|| {
+    serde_json::from_str(include_str!("../openapi.json")).expect("bundled spec is valid json")
+});
+
+/// A place where a response does not match its schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaMismatch {
+    /// The JSON pointer to the offending value, empty for the whole body.
+    pub pointer: String,
+    /// What the schema expected there.
+    pub message: String,
+}
+
+impl fmt::Display for SchemaMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pointer = if self.pointer.is_empty() {
+            "/"
+        } else {
+            &self.pointer
+        };
+        write!(f, "{pointer}: {}", self.message)
+    }
+}
+
+/// Validate `body` against the success response schema of `operation`,
+/// named like the generated client methods, e.g. `get_wallet`.
+///
+/// Returns `None` if the spec has no such operation, or it has no JSON
+/// response, and an empty list if `body` matches.
+#[must_use]
+pub fn validate_response(operation: &str, body: &Value) -> Option<Vec<SchemaMismatch>> {
+    let schema = response_schema(operation)?;
+    let mut mismatches = Vec::new();
+    validate(schema, body, &mut String::new(), &mut mismatches);
+    Some(mismatches)
+}
+
+/// Replace a deserialization error of `operation` with the mismatches
+/// between the response and its schema, if there are any.
+pub(crate) fn check_response(operation: &str, error: PrivyApiError) -> PrivyApiError {
+    let PrivyApiError::InvalidResponsePayload(bytes, _) = &error else {
+        return error;
+    };
+    let Ok(body) = serde_json::from_slice::<Value>(bytes) else {
+        return error;
+    };
+    let mismatches = match validate_response(operation, &body) {
+        Some(mismatches) if !mismatches.is_empty() => mismatches,
+        _ => return error,
+    };
+
+    let report = mismatches
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ");
+    tracing::error!(
+        operation,
+        "Privy API response does not match the OpenAPI spec: {report}"
+    );
+    PrivyApiError::Custom(format!(
+        "the response of `{operation}` does not match the OpenAPI spec: {report}"
+    ))
+}
+
+fn response_schema(operation: &str) -> Option<&'static Value> {
+    SPEC["paths"]
+        .as_object()?
+        .values()
+        .filter_map(Value::as_object)
+        .flat_map(|methods| methods.values())
+        .find(|op| {
+            op["operationId"]
+                .as_str()
+                .is_some_and(|id| snake_case(id) == operation)
+        })?["responses"]
+        .as_object()?
+        .iter()
+        .find(|(status, _)| status.starts_with('2'))
+        .and_then(|(_, response)| response["content"]["application/json"].get("schema"))
+}
+
+/// `getWallet` -> `get_wallet`, the way progenitor names its methods.
+fn snake_case(id: &str) -> String {
+    let mut name = String::with_capacity(id.len() + 4);
+    for c in id.chars() {
+        if c.is_ascii_uppercase() {
+            name.push('_');
+            name.push(c.to_ascii_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
+fn resolve(schema: &Value) -> &Value {
+    match schema["$ref"].as_str() {
+        Some(reference) => SPEC
+            .pointer(reference.trim_start_matches('#'))
+            .map_or(schema, resolve),
+        None => schema,
+    }
+}
+
+fn mismatch(pointer: &str, message: impl Into<String>, mismatches: &mut Vec<SchemaMismatch>) {
+    mismatches.push(SchemaMismatch {
+        pointer: pointer.to_string(),
+        message: message.into(),
+    });
+}
+
+fn validate(schema: &Value, value: &Value, pointer: &mut String, out: &mut Vec<SchemaMismatch>) {
+    let schema = resolve(schema);
+
+    if value.is_null() && schema["nullable"].as_bool() == Some(true) {
+        return;
+    }
+
+    if let Some(variants) = schema["allOf"].as_array() {
+        for variant in variants {
+            validate(variant, value, pointer, out);
+        }
+    }
+
+    for (keyword, exactly_one) in [("oneOf", true), ("anyOf", false)] {
+        if let Some(variants) = schema[keyword].as_array() {
+            validate_variants(variants, exactly_one, value, pointer, out);
+        }
+    }
+
+    if let Some(allowed) = schema["enum"].as_array() {
+        if !allowed.contains(value) {
+            mismatch(
+                pointer,
+                format!("expected one of {allowed:?}, got {value}"),
+                out,
+            );
+            return;
+        }
+    }
+
+    match (schema["type"].as_str(), value) {
+        (None, _) => {}
+        (Some("object"), Value::Object(object)) => validate_object(schema, object, pointer, out),
+        (Some("array"), Value::Array(items)) => validate_array(schema, items, pointer, out),
+        (Some("string"), Value::String(string)) => validate_string(schema, string, pointer, out),
+        (Some("number"), Value::Number(number)) => validate_number(schema, number, pointer, out),
+        (Some("integer"), Value::Number(number)) if number.is_i64() || number.is_u64() => {
+            validate_number(schema, number, pointer, out);
+        }
+        (Some("boolean"), Value::Bool(_)) => {}
+        (Some(expected), _) => mismatch(pointer, format!("expected {expected}, got {value}"), out),
+    }
+}
+
+/// Report the mismatches of the closest variant if none match, so the
+/// pointers lead to the field that broke it.
+fn validate_variants(
+    variants: &[Value],
+    exactly_one: bool,
+    value: &Value,
+    pointer: &mut String,
+    out: &mut Vec<SchemaMismatch>,
+) {
+    let results: Vec<_> = variants
+        .iter()
+        .map(|variant| {
+            let mut mismatches = Vec::new();
+            validate(variant, value, pointer, &mut mismatches);
+            mismatches
+        })
+        .collect();
+
+    let matching = results.iter().filter(|m| m.is_empty()).count();
+    if matching == 0 {
+        if let Some(closest) = results.into_iter().min_by_key(Vec::len) {
+            out.extend(closest);
+        }
+    } else if exactly_one && matching > 1 {
+        mismatch(
+            pointer,
+            format!("matches {matching} variants, expected exactly one"),
+            out,
+        );
+    }
+}
+
+fn validate_object(
+    schema: &Value,
+    object: &Map<String, Value>,
+    pointer: &mut String,
+    out: &mut Vec<SchemaMismatch>,
+) {
+    let properties = schema["properties"].as_object();
+    let required: Vec<_> = schema["required"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+
+    for name in &required {
+        if !object.contains_key(*name) {
+            mismatch(pointer, format!("missing required property `{name}`"), out);
+        }
+    }
+
+    for (name, value) in object {
+        // optional properties are `Option`s in the generated types, so null is fine
+        if value.is_null() && !required.contains(&name.as_str()) {
+            continue;
+        }
+
+        let len = pointer.len();
+        pointer.push('/');
+        pointer.push_str(&name.replace('~', "~0").replace('/', "~1"));
+
+        match (
+            properties.and_then(|p| p.get(name)),
+            &schema["additionalProperties"],
+        ) {
+            (Some(property), _) => validate(property, value, pointer, out),
+            (None, Value::Bool(false)) => mismatch(pointer, "unexpected property", out),
+            (None, additional @ Value::Object(_)) => validate(additional, value, pointer, out),
+            (None, _) => {}
+        }
+
+        pointer.truncate(len);
+    }
+}
+
+fn validate_array(
+    schema: &Value,
+    items: &[Value],
+    pointer: &mut String,
+    out: &mut Vec<SchemaMismatch>,
+) {
+    let length = items.len() as u64;
+    if schema["minItems"].as_u64().is_some_and(|min| length < min) {
+        mismatch(
+            pointer,
+            format!("expected at least {} items", schema["minItems"]),
+            out,
+        );
+    }
+    if schema["maxItems"].as_u64().is_some_and(|max| length > max) {
+        mismatch(
+            pointer,
+            format!("expected at most {} items", schema["maxItems"]),
+            out,
+        );
+    }
+
+    let Some(item_schema) = schema.get("items") else {
+        return;
+    };
+    for (index, item) in items.iter().enumerate() {
+        let len = pointer.len();
+        pointer.push('/');
+        pointer.push_str(&index.to_string());
+        validate(item_schema, item, pointer, out);
+        pointer.truncate(len);
+    }
+}
+
+fn validate_string(schema: &Value, string: &str, pointer: &str, out: &mut Vec<SchemaMismatch>) {
+    let length = string.chars().count() as u64;
+    if schema["minLength"].as_u64().is_some_and(|min| length < min) {
+        mismatch(
+            pointer,
+            format!("expected at least {} characters", schema["minLength"]),
+            out,
+        );
+    }
+    if schema["maxLength"].as_u64().is_some_and(|max| length > max) {
+        mismatch(
+            pointer,
+            format!("expected at most {} characters", schema["maxLength"]),
+            out,
+        );
+    }
+    if let Some(pattern) = schema["pattern"].as_str() {
+        let matches =
+            regress::Regex::new(pattern).map_or(true, |regex| regex.find(string).is_some());
+        if !matches {
+            mismatch(pointer, format!("expected to match `{pattern}`"), out);
+        }
+    }
+}
+
+fn validate_number(
+    schema: &Value,
+    number: &serde_json::Number,
+    pointer: &str,
+    out: &mut Vec<SchemaMismatch>,
+) {
+    let Some(number) = number.as_f64() else {
+        return;
+    };
+    if schema["minimum"].as_f64().is_some_and(|min| number < min) {
+        mismatch(
+            pointer,
+            format!("expected at least {}", schema["minimum"]),
+            out,
+        );
+    }
+    if schema["maximum"].as_f64().is_some_and(|max| number > max) {
+        mismatch(
+            pointer,
+            format!("expected at most {}", schema["maximum"]),
+            out,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn wallet() -> Value {
+        json!({
+            "id": "id2tptkqrxd39qo9j423etij",
+            "address": "0xF1DBff66C993EE895C8cb176c30b07A559d76496",
+            "chain_type": "ethereum",
+            "created_at": 1_741_834_854_578.0,
+            "exported_at": null,
+            "imported_at": null,
+            "owner_id": null,
+            "additional_signers": [],
+            "policy_ids": []
+        })
+    }
+
+    #[test]
+    fn test_valid_response_has_no_mismatches() {
+        assert_eq!(validate_response("get_wallet", &wallet()), Some(vec![]));
+        assert_eq!(validate_response("no_such_operation", &wallet()), None);
+    }
+
+    #[test]
+    fn test_mismatches_are_reported_by_pointer() {
+        let mut body = wallet();
+        body["chain_type"] = json!("dogecoin");
+        body["additional_signers"] = json!([{ "override_policy_ids": [] }]);
+        body.as_object_mut().unwrap().remove("address");
+
+        let pointers: Vec<_> = validate_response("get_wallet", &body)
+            .unwrap()
+            .into_iter()
+            .map(|mismatch| mismatch.pointer)
+            .collect();
+        assert_eq!(pointers, ["", "/additional_signers/0", "/chain_type"]);
+    }
+}
@@ -0,0 +1,415 @@
+//! Record and replay HTTP interactions with the Privy API.
+//!
+//! A [`Cassette`] starts a local server that the client is pointed at. When
+//! recording, requests are forwarded to the real API and every interaction
+//! is written to a YAML cassette file. When replaying, the server answers
+//! from the cassette instead, so tests run deterministically without
+//! credentials or network access.
+//!
+//! ```no_run
+//! # use privy_rs::{PrivyClient, client::PrivyClientOptions, vcr::{Cassette, VcrMode}};
+//! # async fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let cassette = Cassette::new("tests/cassettes/list_users.yaml", VcrMode::Replay);
+//! let users = cassette
+//!     .run("https://api.privy.io", |base_url| async move {
+//!         let client = PrivyClient::new_with_options(
+//!             "app_id".into(),
+//!             "app_secret".into(),
+//!             PrivyClientOptions { base_url, ..Default::default() },
+//!         )?;
+//!         Ok::<_, Box<dyn std::error::Error>>(client.users().list(None, None).await?)
+//!     })
+//!     .await??;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Cassettes are sanitized before they are written: no request headers are
+//! recorded, so the app secret and authorization signatures never reach
+//! disk, and only the `content-type` response header is kept. Secret body
+//! fields, such as a `user_jwt` sent or an `authorization_key` returned, are
+//! left out of recorded requests and replaced with `[redacted]` in recorded
+//! responses. Requests are matched on method, path, query and body during
+//! replay, so a test must send the same bodies it sent while recording,
+//! except for the secret fields, which are not matched.
+
+use std::{future::Future, path::PathBuf};
+
+use httpmock::MockServer;
+use serde_yaml::Value;
+
+use crate::VcrError;
+
+/// The environment variable read by [`VcrMode::from_env`].
+const MODE_ENV_VAR: &str = "PRIVY_VCR";
+
+/// Request body fields that carry credentials, or signatures made with them,
+/// which are left out of recorded requests.
+const SECRET_REQUEST_FIELDS: &[&str] = &[
+    "authorization_key",
+    "private_key",
+    "signature",
+    "signatures",
+    "user_jwt",
+];
+
+/// Response body fields that carry credentials or key material, which are
+/// redacted in recorded responses.
+const SECRET_RESPONSE_FIELDS: &[&str] = &[
+    "access_token",
+    "authorization_key",
+    "ciphertext",
+    "encrypted_authorization_key",
+    "identity_token",
+    "private_key",
+    "refresh_token",
+    "session_token",
+];
+
+/// What secret response fields are replaced with.
+const REDACTED: &str = "[redacted]";
+
+/// Whether a [`Cassette`] talks to the real API or to its recording.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VcrMode {
+    /// Forward requests to the API and overwrite the cassette.
+    Record,
+    /// Answer requests from the cassette.
+    Replay,
+}
+
+impl VcrMode {
+    /// Read the mode from the `PRIVY_VCR` environment variable, which may be
+    /// `record` or `replay`. Returns `None` if it is unset or has any other
+    /// value, in which case callers should talk to the API directly.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        match std::env::var(MODE_ENV_VAR).ok()?.as_str() {
+            "record" => Some(Self::Record),
+            "replay" => Some(Self::Replay),
+            _ => None,
+        }
+    }
+}
+
+/// A recording of the HTTP interactions made during one test.
+#[derive(Clone, Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: VcrMode,
+}
+
+impl Cassette {
+    /// Create a cassette stored at `path`.
+    pub fn new(path: impl Into<PathBuf>, mode: VcrMode) -> Self {
+        Self {
+            path: path.into(),
+            mode,
+        }
+    }
+
+    /// The mode this cassette runs in.
+    #[must_use]
+    pub fn mode(&self) -> VcrMode {
+        self.mode
+    }
+
+    /// Run `f` against the cassette. `f` receives the base url to configure
+    /// the client with; when recording, requests to it are forwarded to
+    /// `upstream`.
+    ///
+    /// # Errors
+    /// Fails if the cassette cannot be read while replaying, or cannot be
+    /// exported or written after recording.
+    pub async fn run<F, Fut, T>(&self, upstream: &str, f: F) -> Result<T, VcrError>
+    where
+        F: FnOnce(String) -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let server = MockServer::start_async().await;
+
+        match self.mode {
+            VcrMode::Replay => {
+                let cassette = tokio::fs::read_to_string(&self.path).await?;
+                server.playback_from_yaml_async(cassette).await;
+                Ok(f(server.base_url()).await)
+            }
+            VcrMode::Record => {
+                server
+                    .forward_to_async(upstream, |rule| {
+                        rule.filter(|when| {
+                            when.any_request();
+                        });
+                    })
+                    .await;
+                let recording = server
+                    .record_async(|rule| {
+                        rule.record_response_delays(false).filter(|when| {
+                            when.any_request();
+                        });
+                    })
+                    .await;
+
+                let output = f(server.base_url()).await;
+
+                let yaml = recording
+                    .export_async()
+                    .await
+                    .map_err(|e| VcrError::Recording(e.to_string()))?
+                    .ok_or_else(|| VcrError::Recording("no interactions recorded".to_string()))?;
+                let yaml = sanitize(std::str::from_utf8(&yaml).map_err(|e| {
+                    VcrError::Recording(format!("recording is not valid utf-8: {e}"))
+                })?)?;
+
+                if let Some(dir) = self.path.parent() {
+                    tokio::fs::create_dir_all(dir).await?;
+                }
+                tokio::fs::write(&self.path, yaml).await?;
+                tracing::info!(path = %self.path.display(), "recorded cassette");
+
+                Ok(output)
+            }
+        }
+    }
+}
+
+/// Strip everything but the `content-type` header from recorded responses,
+/// and the secret fields from recorded bodies.
+fn sanitize(yaml: &str) -> Result<String, VcrError> {
+    let mut documents = Vec::new();
+
+    for document in serde_yaml::Deserializer::from_str(yaml) {
+        let mut value: Value = serde::Deserialize::deserialize(document)?;
+
+        if let Some(when) = value.get_mut("when").and_then(Value::as_mapping_mut) {
+            let body = when
+                .get("body")
+                .and_then(Value::as_str)
+                .and_then(|body| serde_json::from_str(body).ok());
+            if let Some(mut body) = body {
+                if remove_fields(&mut body, SECRET_REQUEST_FIELDS) {
+                    // match on the rest of the body, as the secrets are gone
+                    when.remove("body");
+                    when.insert("json_body_includes".into(), serde_yaml::to_value([body])?);
+                }
+            }
+        }
+
+        if let Some(then) = value.get_mut("then").and_then(Value::as_mapping_mut) {
+            let headers = then
+                .get_mut("header")
+                .and_then(Value::as_sequence_mut)
+                .map(std::mem::take)
+                .unwrap_or_default();
+            let headers: Vec<Value> = headers
+                .into_iter()
+                .filter(|header| {
+                    header
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .is_some_and(|name| name.eq_ignore_ascii_case("content-type"))
+                })
+                .collect();
+
+            if headers.is_empty() {
+                then.remove("header");
+            } else {
+                then.insert("header".into(), headers.into());
+            }
+
+            let body = then
+                .get("body")
+                .and_then(Value::as_str)
+                .and_then(|body| serde_json::from_str(body).ok());
+            if let Some(mut body) = body {
+                if redact_fields(&mut body, SECRET_RESPONSE_FIELDS) {
+                    then.insert("body".into(), body.to_string().into());
+                }
+            }
+        }
+
+        documents.push(serde_yaml::to_string(&value)?);
+    }
+
+    Ok(documents.join("---\n"))
+}
+
+/// Remove the `fields` from every object in `value`, returning whether any
+/// were found.
+fn remove_fields(value: &mut serde_json::Value, fields: &[&str]) -> bool {
+    match value {
+        serde_json::Value::Object(object) => {
+            let before = object.len();
+            object.retain(|name, _| !fields.contains(&name.as_str()));
+            let mut removed = object.len() != before;
+            for value in object.values_mut() {
+                removed |= remove_fields(value, fields);
+            }
+            removed
+        }
+        serde_json::Value::Array(values) => values.iter_mut().fold(false, |removed, value| {
+            remove_fields(value, fields) | removed
+        }),
+        _ => false,
+    }
+}
+
+/// Replace the values of the `fields` of every object in `value` with
+/// [`REDACTED`], returning whether any were found.
+fn redact_fields(value: &mut serde_json::Value, fields: &[&str]) -> bool {
+    match value {
+        serde_json::Value::Object(object) => {
+            let mut redacted = false;
+            for (name, value) in object.iter_mut() {
+                if fields.contains(&name.as_str()) {
+                    *value = REDACTED.into();
+                    redacted = true;
+                } else {
+                    redacted |= redact_fields(value, fields);
+                }
+            }
+            redacted
+        }
+        serde_json::Value::Array(values) => values.iter_mut().fold(false, |redacted, value| {
+            redact_fields(value, fields) | redacted
+        }),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use super::*;
+    use crate::{PrivyClient, client::PrivyClientOptions};
+
+    fn client(base_url: String) -> PrivyClient {
+        PrivyClient::new_with_options(
+            "app_id".to_string(),
+            "app_secret".to_string(),
+            PrivyClientOptions {
+                base_url,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let upstream = MockServer::start_async().await;
+        let mock = upstream
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/wallets/wallet_id");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .header("set-cookie", "session=secret")
+                    .json_body(serde_json::json!({
+                        "id": "wallet_id",
+                        "address": "0x0000000000000000000000000000000000000000",
+                        "chain_type": "ethereum",
+                        "policy_ids": [],
+                        "additional_signers": [],
+                        "created_at": 0,
+                        "exported_at": null,
+                        "imported_at": null,
+                        "owner_id": null,
+                    }));
+            })
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("get_wallet.yaml");
+
+        let recorded = Cassette::new(&path, VcrMode::Record)
+            .run(&upstream.base_url(), |base_url| async move {
+                client(base_url).wallets().get("wallet_id").await
+            })
+            .await
+            .unwrap()
+            .unwrap();
+        mock.assert_async().await;
+
+        let cassette = std::fs::read_to_string(&path).unwrap();
+        assert!(!cassette.to_lowercase().contains("authorization"));
+        assert!(!cassette.contains("set-cookie"));
+        assert!(cassette.contains("content-type"));
+
+        // the upstream is gone, so this can only be answered from the cassette
+        drop(upstream);
+        let replayed = Cassette::new(&path, VcrMode::Replay)
+            .run("http://unused", |base_url| async move {
+                client(base_url).wallets().get("wallet_id").await
+            })
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(recorded.into_inner().id, replayed.into_inner().id);
+    }
+
+    #[tokio::test]
+    async fn test_secret_body_fields_are_not_recorded() {
+        use crate::generated::types::{
+            WalletAuthenticateRequestBody, WalletAuthenticateWithJwtResponse,
+        };
+
+        let upstream = MockServer::start_async().await;
+        upstream
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/wallets/authenticate");
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .json_body(serde_json::json!({
+                        "authorization_key": "secret-session-key",
+                        "expires_at": 0,
+                        "wallets": [],
+                    }));
+            })
+            .await;
+
+        let body: WalletAuthenticateRequestBody = serde_json::from_value(serde_json::json!({
+            "encryption_type": "HPKE",
+            "recipient_public_key": "recipient-public-key",
+            "user_jwt": "secret.user.jwt",
+        }))
+        .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("authenticate.yaml");
+        Cassette::new(&path, VcrMode::Record)
+            .run(&upstream.base_url(), |base_url| async {
+                client(base_url)
+                    .wallets()
+                    .authenticate_with_jwt(&body)
+                    .await
+            })
+            .await
+            .unwrap()
+            .unwrap();
+
+        let cassette = std::fs::read_to_string(&path).unwrap();
+        assert!(!cassette.contains("secret.user.jwt"));
+        assert!(!cassette.contains("secret-session-key"));
+        assert!(cassette.contains("recipient-public-key"));
+
+        // the request still matches without its secrets
+        drop(upstream);
+        let replayed = Cassette::new(&path, VcrMode::Replay)
+            .run("http://unused", |base_url| async {
+                client(base_url)
+                    .wallets()
+                    .authenticate_with_jwt(&body)
+                    .await
+            })
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            replayed.into_inner(),
+            WalletAuthenticateWithJwtResponse::WithoutEncryption { authorization_key, .. }
+                if authorization_key == REDACTED
+        ));
+    }
+}
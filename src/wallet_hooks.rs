@@ -0,0 +1,106 @@
+//! Callbacks on the wallets a client creates and changes.
+//!
+//! A [`WalletLifecycleHook`] registered with
+//! [`PrivyClient::with_wallet_hook`] is told about every wallet created,
+//! imported, updated or exported through the `WalletsClient` of the
+//! client, so provisioning pipelines can persist wallet records or emit
+//! domain events in one place rather than at every call site:
+//!
+//! ```no_run
+//! # use privy_rs::{PrivyClient, WalletId, generated::types::Wallet, wallet_hooks::WalletLifecycleHook};
+//! struct Provisioning;
+//!
+//! impl WalletLifecycleHook for Provisioning {
+//!     fn on_created(&self, wallet: &Wallet) {
+//!         println!("provisioned {} at {}", wallet.id, wallet.address);
+//!     }
+//!
+//!     fn on_exported(&self, wallet_id: &WalletId) {
+//!         println!("the key of {wallet_id} left Privy");
+//!     }
+//! }
+//!
+//! # fn foo() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = PrivyClient::new_from_env()?.with_wallet_hook(Provisioning);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Hooks are called inline, after the API confirmed the change and before
+//! the call returns, in the order they were registered. Failed calls are
+//! not reported, and neither are wallets created through other endpoints,
+//! such as with a user.
+//!
+//! [`PrivyClient::with_wallet_hook`]: crate::PrivyClient::with_wallet_hook
+
+use std::{fmt, sync::Arc};
+
+use crate::{WalletId, generated::types::Wallet};
+
+/// Callbacks on the wallets a client creates and changes. See the
+/// [module docs](self) for details.
+///
+/// Every method runs on the request path, so it must return quickly,
+/// handing slow work such as database writes off elsewhere.
+pub trait WalletLifecycleHook {
+    /// See `wallet`, just created with `WalletsClient::create`.
+    fn on_created(&self, wallet: &Wallet) {
+        let _ = wallet;
+    }
+
+    /// See `wallet`, just imported with `WalletsClient::import`.
+    fn on_imported(&self, wallet: &Wallet) {
+        let _ = wallet;
+    }
+
+    /// See `wallet` as it is after `WalletsClient::update`.
+    fn on_updated(&self, wallet: &Wallet) {
+        let _ = wallet;
+    }
+
+    /// See that the private key of `wallet_id` was exported with
+    /// `WalletsClient::export`.
+    fn on_exported(&self, wallet_id: &WalletId) {
+        let _ = wallet_id;
+    }
+}
+
+/// The wallet lifecycle hooks registered with a client.
+#[derive(Clone, Default)]
+pub(crate) struct WalletHooks(Vec<Arc<dyn WalletLifecycleHook + Send + Sync>>);
+
+impl fmt::Debug for WalletHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WalletHooks").field(&self.0.len()).finish()
+    }
+}
+
+impl WalletHooks {
+    pub(crate) fn push(&mut self, hook: impl WalletLifecycleHook + Send + Sync + 'static) {
+        self.0.push(Arc::new(hook));
+    }
+
+    pub(crate) fn created(&self, wallet: &Wallet) {
+        for hook in &self.0 {
+            hook.on_created(wallet);
+        }
+    }
+
+    pub(crate) fn imported(&self, wallet: &Wallet) {
+        for hook in &self.0 {
+            hook.on_imported(wallet);
+        }
+    }
+
+    pub(crate) fn updated(&self, wallet: &Wallet) {
+        for hook in &self.0 {
+            hook.on_updated(wallet);
+        }
+    }
+
+    pub(crate) fn exported(&self, wallet_id: &WalletId) {
+        for hook in &self.0 {
+            hook.on_exported(wallet_id);
+        }
+    }
+}
@@ -0,0 +1,323 @@
+//! Verifying Privy's webhooks.
+//!
+//! Privy signs the webhooks it sends with the signing secret of the
+//! webhook endpoint (`whsec_...`, from the dashboard). The signature, and
+//! what it covers, is carried in three headers: `svix-id`, `svix-timestamp`
+//! and `svix-signature`. A [`WebhookVerifier`] checks them against the raw
+//! request body, before the body is parsed:
+//!
+//! ```no_run
+//! # use privy_rs::webhooks::{WebhookHeaders, WebhookVerifier};
+//! # fn foo(headers: &reqwest::header::HeaderMap, body: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+//! let verifier = WebhookVerifier::new(&std::env::var("PRIVY_WEBHOOK_SECRET")?)?;
+//! verifier.verify(body, &WebhookHeaders::from_headers(headers)?)?;
+//! let event: serde_json::Value = serde_json::from_slice(body)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A webhook sent more than five minutes before or after it is verified is
+//! rejected, so a captured webhook cannot be replayed later. Queue consumers
+//! working through a backlog of webhooks that were verified when they were
+//! received, or stored with their headers, can widen or drop the tolerance
+//! with [`WebhookVerifier::with_tolerance`], and verify them in bulk with
+//! [`WebhookVerifier::verify_batch`].
+
+use std::{
+    fmt,
+    num::NonZeroUsize,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use crate::WebhookError;
+
+/// How far from now a webhook may have been sent, by default.
+const DEFAULT_TOLERANCE: Duration = Duration::from_secs(5 * 60);
+
+/// Batches smaller than this are verified on the calling thread, as
+/// spreading them over threads costs more than it saves.
+const MIN_PARALLEL_BATCH: usize = 64;
+
+/// The signature headers of a webhook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookHeaders {
+    /// The `svix-id` header, the id of the message.
+    pub id: String,
+    /// The `svix-timestamp` header, when the message was sent, in seconds
+    /// since the unix epoch.
+    pub timestamp: String,
+    /// The `svix-signature` header, a space separated list of `v1,`
+    /// prefixed base64 signatures.
+    pub signature: String,
+}
+
+impl WebhookHeaders {
+    /// Read the signature headers from the headers of a webhook request.
+    ///
+    /// # Errors
+    /// If one of them is missing.
+    pub fn from_headers(headers: &reqwest::header::HeaderMap) -> Result<Self, WebhookError> {
+        let header = |name: &'static str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned)
+                .ok_or(WebhookError::MissingHeader(name))
+        };
+        Ok(Self {
+            id: header("svix-id")?,
+            timestamp: header("svix-timestamp")?,
+            signature: header("svix-signature")?,
+        })
+    }
+}
+
+/// Verifies the signatures of Privy's webhooks, see the
+/// [module docs](self).
+///
+/// Clones share the secret, so a verifier can be cloned into every task
+/// that handles webhooks.
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    secret: Arc<Zeroizing<Vec<u8>>>,
+    tolerance: Option<Duration>,
+    parallelism: NonZeroUsize,
+}
+
+impl fmt::Debug for WebhookVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookVerifier")
+            .field("tolerance", &self.tolerance)
+            .field("parallelism", &self.parallelism)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookVerifier {
+    /// Create a verifier for the signing secret of a webhook endpoint.
+    ///
+    /// # Errors
+    /// If `secret` is not a `whsec_` prefixed base64 string.
+    pub fn new(secret: &str) -> Result<Self, WebhookError> {
+        let secret = secret.trim();
+        let secret = secret.strip_prefix("whsec_").unwrap_or(secret);
+        let secret = STANDARD
+            .decode(secret)
+            .map_err(|_| WebhookError::InvalidSecret)?;
+        if secret.is_empty() {
+            return Err(WebhookError::InvalidSecret);
+        }
+        Ok(Self {
+            secret: Arc::new(Zeroizing::new(secret)),
+            tolerance: Some(DEFAULT_TOLERANCE),
+            parallelism: std::thread::available_parallelism().unwrap_or(NonZeroUsize::MIN),
+        })
+    }
+
+    /// Accept webhooks sent up to `tolerance` before or after they are
+    /// verified, five minutes by default. `None` accepts webhooks sent at
+    /// any time, which leaves replays undetected: only use it for webhooks
+    /// whose freshness was checked when they were received.
+    #[must_use]
+    pub fn with_tolerance(mut self, tolerance: Option<Duration>) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Spread `verify_batch` over at most `parallelism` threads, the number
+    /// of CPUs by default. `1` verifies batches on the calling thread.
+    #[must_use]
+    pub fn with_parallelism(mut self, parallelism: NonZeroUsize) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
+
+    /// Verify that `payload`, the raw body of a webhook, was signed by
+    /// Privy as `headers` say, and was sent within the tolerance of the
+    /// verifier.
+    ///
+    /// # Errors
+    /// If the webhook is too old or too new, or none of its signatures
+    /// match.
+    pub fn verify(&self, payload: &[u8], headers: &WebhookHeaders) -> Result<(), WebhookError> {
+        self.verify_at(payload, headers, SystemTime::now())
+    }
+
+    /// Verify a batch of webhooks, each a raw body and its headers, as
+    /// `verify` does, returning the result of each in the order they were
+    /// given.
+    ///
+    /// Large batches are split over up to `parallelism` threads, which this
+    /// waits for, so call it from `tokio::task::spawn_blocking` rather than
+    /// directly from async code.
+    pub fn verify_batch<P>(&self, batch: &[(P, WebhookHeaders)]) -> Vec<Result<(), WebhookError>>
+    where
+        P: AsRef<[u8]> + Sync,
+    {
+        let now = SystemTime::now();
+        let verify_chunk = |chunk: &[(P, WebhookHeaders)]| {
+            chunk
+                .iter()
+                .map(|(payload, headers)| self.verify_at(payload.as_ref(), headers, now))
+                .collect::<Vec<_>>()
+        };
+
+        let threads = self.parallelism.get().min(batch.len() / MIN_PARALLEL_BATCH);
+        if threads <= 1 {
+            return verify_chunk(batch);
+        }
+
+        let chunk_size = batch.len().div_ceil(threads);
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = batch
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || verify_chunk(chunk)))
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("verifying webhooks does not panic"))
+                .collect()
+        })
+    }
+
+    fn verify_at(
+        &self,
+        payload: &[u8],
+        headers: &WebhookHeaders,
+        now: SystemTime,
+    ) -> Result<(), WebhookError> {
+        let timestamp: u64 = headers
+            .timestamp
+            .trim()
+            .parse()
+            .map_err(|_| WebhookError::InvalidTimestamp(headers.timestamp.clone()))?;
+        if let Some(tolerance) = self.tolerance {
+            let sent_at = SystemTime::UNIX_EPOCH
+                .checked_add(Duration::from_secs(timestamp))
+                .ok_or_else(|| WebhookError::InvalidTimestamp(headers.timestamp.clone()))?;
+            let skew = now.duration_since(sent_at).unwrap_or_else(|e| e.duration());
+            if skew > tolerance {
+                return Err(WebhookError::Expired);
+            }
+        }
+
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("hmac accepts keys of any length");
+        mac.update(headers.id.as_bytes());
+        mac.update(b".");
+        mac.update(headers.timestamp.trim().as_bytes());
+        mac.update(b".");
+        mac.update(payload);
+
+        // the header lists several signatures while the secret is rotated
+        let matches = headers
+            .signature
+            .split_whitespace()
+            .filter_map(|signature| signature.strip_prefix("v1,"))
+            .filter_map(|signature| STANDARD.decode(signature).ok())
+            .any(|signature| mac.clone().verify_slice(&signature).is_ok());
+        if matches {
+            Ok(())
+        } else {
+            Err(WebhookError::InvalidSignature)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whsec_MfKQ9r8GKYqrTwjUPD8ILPZIo2LaLaSw";
+
+    fn headers(payload: &[u8], sent_at: SystemTime) -> WebhookHeaders {
+        let timestamp = sent_at
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            .to_string();
+        let secret = STANDARD.decode(&SECRET["whsec_".len()..]).unwrap();
+        let mut mac = Hmac::<Sha256>::new_from_slice(&secret).unwrap();
+        mac.update(format!("msg_1.{timestamp}.").as_bytes());
+        mac.update(payload);
+        let signature = STANDARD.encode(mac.finalize().into_bytes());
+
+        WebhookHeaders {
+            id: "msg_1".to_string(),
+            timestamp,
+            signature: format!("v1,c3RhbGU= v1,{signature}"),
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let verifier = WebhookVerifier::new(SECRET).unwrap();
+        let payload = br#"{"type":"transaction.confirmed"}"#;
+        let now = SystemTime::now();
+
+        assert_eq!(verifier.verify(payload, &headers(payload, now)), Ok(()));
+        assert_eq!(
+            verifier.verify(b"{}", &headers(payload, now)),
+            Err(WebhookError::InvalidSignature)
+        );
+
+        let old = headers(payload, now - Duration::from_secs(3600));
+        assert_eq!(verifier.verify(payload, &old), Err(WebhookError::Expired));
+        let verifier = verifier.with_tolerance(None);
+        assert_eq!(verifier.verify(payload, &old), Ok(()));
+
+        assert_eq!(
+            WebhookVerifier::new("whsec_not base64").unwrap_err(),
+            WebhookError::InvalidSecret
+        );
+        assert_eq!(
+            WebhookHeaders::from_headers(&reqwest::header::HeaderMap::new()),
+            Err(WebhookError::MissingHeader("svix-id"))
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_timestamp_is_invalid() {
+        let verifier = WebhookVerifier::new(SECRET).unwrap();
+        let headers = WebhookHeaders {
+            timestamp: u64::MAX.to_string(),
+            ..headers(b"{}", SystemTime::now())
+        };
+
+        assert_eq!(
+            verifier.verify(b"{}", &headers),
+            Err(WebhookError::InvalidTimestamp(u64::MAX.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_verify_batch_keeps_order() {
+        let now = SystemTime::now();
+        let batch: Vec<_> = (0..1000)
+            .map(|i| {
+                let payload = format!(r#"{{"n":{i}}}"#).into_bytes();
+                let headers = headers(&payload, now);
+                // every seventh payload is tampered with
+                let payload = if i % 7 == 0 { b"{}".to_vec() } else { payload };
+                (payload, headers)
+            })
+            .collect();
+
+        for parallelism in [1, 4] {
+            let verifier = WebhookVerifier::new(SECRET)
+                .unwrap()
+                .with_parallelism(NonZeroUsize::new(parallelism).unwrap());
+            let results = verifier.verify_batch(&batch);
+            assert_eq!(results.len(), batch.len());
+            for (i, result) in results.iter().enumerate() {
+                assert_eq!(result.is_ok(), i % 7 != 0, "webhook {i}");
+            }
+        }
+    }
+}
@@ -1,7 +1,9 @@
+mod common;
+
 #[tokio::test]
 async fn test_requests_include_privy_client_header() {
     use httpmock::prelude::*;
-    use privy_rs::{PrivyClient, client::PrivyClientOptions};
+    use privy_rs::client::PrivyClientOptions;
 
     // Start a mock server
     let server = MockServer::start();
@@ -34,21 +36,2159 @@ async fn test_requests_include_privy_client_header() {
     });
 
     // Create a PrivyClient pointing to the mock server
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    // Make a request to trigger the mock using the public users() API
+    // The list method takes (order_by: Option<&str>, order_direction: Option<&str>)
+    let _ = client.users().list(None, None).await;
+
+    // If we get here without the mock failing, it means the header was present
+    // and had the correct format. Now verify the mock was actually called.
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_unexpected_responses_include_error_details() {
+    use httpmock::prelude::*;
+    use privy_rs::{PrivyApiError, PrivyApiErrorExt, client::PrivyClientOptions};
+
+    let server = MockServer::start();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/wallets/missing");
+        then.status(404)
+            .header("content-type", "application/json")
+            .header("x-request-id", "req_123")
+            .json_body(serde_json::json!({
+                "error": "Wallet not found"
+            }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    let err = client
+        .wallets()
+        .get("missing")
+        .await
+        .expect_err("wallet should not exist");
+
+    let details = err.details().expect("details should be attached");
+    assert_eq!(details.status, 404);
+    assert_eq!(details.request_id.as_deref(), Some("req_123"));
+    assert!(details.body.contains("Wallet not found"));
+    assert!(!details.truncated);
+
+    // the response itself should still be readable
+    let PrivyApiError::UnexpectedResponse(response) = err else {
+        panic!("expected an unexpected response error");
+    };
+    assert_eq!(response.status(), 404);
+    assert!(response.url().as_str().ends_with("/v1/wallets/missing"));
+    assert!(response.text().await.unwrap().contains("Wallet not found"));
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_error_details_truncate_large_bodies() {
+    use httpmock::prelude::*;
+    use privy_rs::{PrivyApiErrorExt, client::PrivyClientOptions};
+
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/wallets/large");
+        then.status(500).body("x".repeat(1024 * 1024));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    let err = client.wallets().get("large").await.expect_err("500");
+    let details = err.details().expect("details should be attached");
+    assert!(details.truncated);
+    assert_eq!(details.body.len(), 16 * 1024);
+}
+
+#[cfg(feature = "ethereum")]
+#[tokio::test]
+async fn test_dry_run_does_not_send_wallet_actions() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, WalletId, client::PrivyClientOptions,
+        generated::types::WalletRpcResponse,
+    };
+
+    let server = MockServer::start();
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/v1/wallets/wallet_id/rpc");
+        then.status(500);
+    });
+
+    let client = common::mock_client(
+        &server,
+        PrivyClientOptions {
+            dry_run: true,
+            ..Default::default()
+        },
+    );
+
+    let response = client
+        .wallets()
+        .ethereum()
+        .sign_message(
+            WalletId::new("wallet_id"),
+            "hello",
+            &AuthorizationContext::new(),
+            None,
+        )
+        .await
+        .expect("dry run succeeds")
+        .into_inner();
+
+    let WalletRpcResponse::EthereumPersonalSignRpcResponse(response) = response else {
+        panic!("unexpected response: {response:?}");
+    };
+    assert!(response.data.signature.starts_with("0x"));
+    assert_eq!(response.data.signature.len(), 2 + 65 * 2);
+    mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_dry_run_does_not_send_mutating_calls() {
+    use httpmock::prelude::*;
+    use privy_rs::{UserId, client::PrivyClientOptions, generated::Error};
+
+    let server = MockServer::start();
+
+    let delete = server.mock(|when, then| {
+        when.method(DELETE).path("/v1/users/user_id");
+        then.status(204);
+    });
+    let lookup = server.mock(|when, then| {
+        when.method(POST).path("/v1/users/email/address");
+        then.status(404);
+    });
+
+    let client = common::mock_client(
+        &server,
+        PrivyClientOptions {
+            dry_run: true,
+            ..Default::default()
+        },
+    );
+
+    let deleted = client.users().delete(UserId::new("user_id")).await;
+    assert!(
+        matches!(deleted, Err(Error::InvalidRequest(_))),
+        "{deleted:?}"
+    );
+    delete.assert_calls(0);
+
+    let body = serde_json::from_value(serde_json::json!({ "address": "a@example.com" })).unwrap();
+    let _ = client.users().get_by_email_address(&body).await;
+    lookup.assert_calls(1);
+}
+
+#[tokio::test]
+async fn test_rpc_batch_preserves_order_and_derives_idempotency_keys() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, WalletId,
+        client::PrivyClientOptions,
+        generated::types::{WalletRpcRequestBody, WalletRpcResponse},
+    };
+
+    let server = MockServer::start();
+
+    let mocks: Vec<_> = (0..3)
+        .map(|i| {
+            server.mock(|when, then| {
+                when.method(POST)
+                    .path("/v1/wallets/wallet_id/rpc")
+                    .header("privy-idempotency-key", format!("batch:{i}"));
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .json_body(serde_json::json!({
+                        "method": "personal_sign",
+                        "data": { "signature": format!("0x{i}"), "encoding": "hex" },
+                    }));
+            })
+        })
+        .collect();
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    let bodies: Vec<WalletRpcRequestBody> = (0..3)
+        .map(|i| {
+            serde_json::from_value(serde_json::json!({
+                "method": "personal_sign",
+                "params": { "message": format!("message {i}"), "encoding": "utf-8" },
+            }))
+            .unwrap()
+        })
+        .collect();
+
+    let results = client
+        .wallets()
+        .rpc_batch(
+            WalletId::new("wallet_id"),
+            &AuthorizationContext::new(),
+            Some("batch"),
+            &bodies,
+        )
+        .await;
+
+    for (i, result) in results.into_iter().enumerate() {
+        let WalletRpcResponse::EthereumPersonalSignRpcResponse(response) =
+            result.expect("rpc succeeds").into_inner()
+        else {
+            panic!("unexpected response");
+        };
+        assert_eq!(response.data.signature, format!("0x{i}"));
+    }
+    for mock in mocks {
+        mock.assert();
+    }
+}
+
+#[cfg(all(feature = "ethereum", feature = "solana"))]
+#[tokio::test]
+async fn test_wallet_dyn_dispatches_on_chain_type() {
+    use httpmock::prelude::*;
+    use privy_rs::{WalletId, any_wallet::AnyWallet, chain::ChainType, client::PrivyClientOptions};
+
+    let server = MockServer::start();
+    for (id, chain_type) in [
+        ("eth_wallet", "ethereum"),
+        ("sol_wallet", "solana"),
+        ("btc_wallet", "bitcoin-segwit"),
+        ("doge_wallet", "dogecoin"),
+    ] {
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/v1/wallets/{id}"));
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(serde_json::json!({
+                    "id": id,
+                    "address": "address",
+                    "chain_type": chain_type,
+                    "policy_ids": [],
+                    "additional_signers": [],
+                    "created_at": 0,
+                    "exported_at": null,
+                    "imported_at": null,
+                    "owner_id": null,
+                }));
+        });
+    }
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    let wallet = client
+        .wallet_dyn(WalletId::new("eth_wallet"))
+        .await
+        .unwrap();
+    assert!(matches!(&wallet, AnyWallet::Ethereum(w) if w.id().as_str() == "eth_wallet"));
+
+    let wallet = client
+        .wallet_dyn(WalletId::new("sol_wallet"))
+        .await
+        .unwrap();
+    assert!(matches!(&wallet, AnyWallet::Solana(w) if w.address() == "address"));
+
+    let wallet = client
+        .wallet_dyn(WalletId::new("btc_wallet"))
+        .await
+        .unwrap();
+    assert!(matches!(wallet, AnyWallet::Raw(_)));
+    assert_eq!(wallet.id().as_str(), "btc_wallet");
+
+    let wallet = client
+        .wallet_dyn(WalletId::new("doge_wallet"))
+        .await
+        .unwrap();
+    assert!(matches!(&wallet, AnyWallet::Unknown(w) if w.address == "address"));
+    assert_eq!(wallet.id().as_str(), "doge_wallet");
+    assert_eq!(
+        wallet.chain_type(),
+        ChainType::Other("dogecoin".to_string())
+    );
+}
+
+#[cfg(feature = "ethereum")]
+#[tokio::test]
+async fn test_verified_wallet_checks_chain_type() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        TypedWalletError, WalletId, any_wallet::TypedWallet, chain::ChainType,
+        client::PrivyClientOptions, ethereum::EthereumService, generated::types::WalletChainType,
+    };
+
+    let server = MockServer::start();
+    for (id, chain_type) in [
+        ("eth_wallet", "ethereum"),
+        ("sol_wallet", "solana"),
+        ("doge_wallet", "dogecoin"),
+    ] {
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/v1/wallets/{id}"));
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(serde_json::json!({
+                    "id": id,
+                    "address": "address",
+                    "chain_type": chain_type,
+                    "policy_ids": [],
+                    "additional_signers": [],
+                    "created_at": 0,
+                    "exported_at": null,
+                    "imported_at": null,
+                    "owner_id": null,
+                }));
+        });
+    }
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    let wallet = TypedWallet::<EthereumService>::verified(&client, WalletId::new("eth_wallet"))
+        .await
+        .unwrap();
+    assert_eq!(wallet.id().as_str(), "eth_wallet");
+
+    let error = TypedWallet::<EthereumService>::verified(&client, WalletId::new("sol_wallet"))
+        .await
+        .err()
+        .expect("wallet is on solana");
+    assert!(matches!(
+        error,
+        TypedWalletError::ChainMismatch {
+            expected: WalletChainType::Ethereum,
+            actual: ChainType::Known(WalletChainType::Solana),
+        }
+    ));
+
+    let error = TypedWallet::<EthereumService>::verified(&client, WalletId::new("doge_wallet"))
+        .await
+        .err()
+        .expect("wallet is on dogecoin");
+    assert!(matches!(
+        error,
+        TypedWalletError::ChainMismatch {
+            expected: WalletChainType::Ethereum,
+            actual: ChainType::Other(name),
+        } if name == "dogecoin"
+    ));
+}
+
+#[tokio::test]
+async fn test_pinned_resolver_routes_to_pinned_address() {
+    use httpmock::prelude::*;
+    use privy_rs::{PrivyClient, UserId, client::PrivyClientOptions, dns::PinnedResolver};
+
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/users/did:privy:user");
+        then.status(404);
+    });
+
+    // `.invalid` names never resolve through DNS, so the request can only
+    // reach the server through the pinned address
     let client = PrivyClient::new_with_options(
         "test_app_id".to_string(),
         "test_app_secret".to_string(),
         PrivyClientOptions {
-            base_url: server.base_url(),
+            base_url: format!("http://api.privy.invalid:{}", server.port()),
+            resolver: Some(PinnedResolver::new().pin("api.privy.invalid", [server.address().ip()])),
             ..Default::default()
         },
     )
     .expect("Failed to create client");
 
-    // Make a request to trigger the mock using the public users() API
-    // The list method takes (order_by: Option<&str>, order_direction: Option<&str>)
-    let _ = client.users().list(None, None).await;
-
-    // If we get here without the mock failing, it means the header was present
-    // and had the correct format. Now verify the mock was actually called.
+    let _ = client.users().get(UserId::new("did:privy:user")).await;
     mock.assert();
 }
+
+#[cfg(feature = "solana")]
+#[tokio::test]
+async fn test_failed_simulation_prevents_sending() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, PrivySignedApiError, SimulationError, WalletId,
+        client::PrivyClientOptions, solana::SignAndSendTransactionOptions,
+    };
+
+    let server = MockServer::start();
+    let send = server.mock(|when, then| {
+        when.method(POST).path("/v1/wallets/wallet_id/rpc");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "method": "signAndSendTransaction",
+                "data": { "caip2": "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1", "hash": "abc" },
+            }));
+    });
+    let simulate = |transaction: &'static str, err: serde_json::Value| {
+        server.mock(|when, then| {
+            when.method(POST).path("/solana").json_body_includes(
+                serde_json::json!({
+                    "method": "simulateTransaction",
+                    "params": [transaction],
+                })
+                .to_string(),
+            );
+            then.status(200).json_body(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "context": { "slot": 1 },
+                    "value": { "err": err, "logs": ["Program log: insufficient funds"] },
+                },
+            }));
+        })
+    };
+    let failing = simulate(
+        "AA==",
+        serde_json::json!({ "InstructionError": [0, { "Custom": 1 }] }),
+    );
+    let passing = simulate("AQ==", serde_json::Value::Null);
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+    let solana = client.wallets().solana();
+    let ctx = AuthorizationContext::new();
+    let options = SignAndSendTransactionOptions::new().with_simulation(server.url("/solana"));
+    let caip2 = "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1";
+
+    let error = solana
+        .sign_and_send_transaction_with_options(
+            WalletId::new("wallet_id"),
+            caip2,
+            "AA==",
+            &ctx,
+            None,
+            &options,
+        )
+        .await
+        .unwrap_err();
+    let PrivySignedApiError::Simulation(SimulationError::Failed { err, logs }) = error else {
+        panic!("unexpected error: {error:?}");
+    };
+    assert_eq!(err["InstructionError"][0], 0);
+    assert_eq!(logs, ["Program log: insufficient funds"]);
+    failing.assert();
+    send.assert_calls(0);
+
+    solana
+        .sign_and_send_transaction_with_options(
+            WalletId::new("wallet_id"),
+            caip2,
+            "AQ==",
+            &ctx,
+            None,
+            &options,
+        )
+        .await
+        .expect("simulation passes");
+    passing.assert();
+    send.assert();
+}
+
+#[cfg(feature = "ethereum")]
+#[tokio::test]
+async fn test_reverting_preflight_prevents_sending() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, PreflightError, PrivySignedApiError, WalletId,
+        client::PrivyClientOptions,
+        ethereum::SendTransactionOptions,
+        generated::types::{UnsignedEthereumTransaction, UnsignedStandardEthereumTransaction},
+        revert::RevertReason,
+    };
+
+    let server = MockServer::start();
+    let send = server.mock(|when, then| {
+        when.method(POST).path("/v1/wallets/wallet_id/rpc");
+        then.status(500);
+    });
+    let wallet = server.mock(|when, then| {
+        when.method(GET).path("/v1/wallets/wallet_id");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "id": "wallet_id",
+                "address": "0x742d35cc6634c0532925a3b844bc454e4438f44e",
+                "chain_type": "ethereum",
+                "policy_ids": [],
+                "additional_signers": [],
+                "created_at": 0,
+                "exported_at": null,
+                "imported_at": null,
+                "owner_id": null,
+            }));
+    });
+    // Error("ERC20: transfer amount exceeds balance")
+    let revert_data = concat!(
+        "0x08c379a0",
+        "0000000000000000000000000000000000000000000000000000000000000020",
+        "0000000000000000000000000000000000000000000000000000000000000026",
+        "45524332303a207472616e7366657220616d6f756e7420657863656564732062",
+        "616c616e63650000000000000000000000000000000000000000000000000000",
+    );
+    let eth_call = server.mock(|when, then| {
+        when.method(POST).path("/ethereum").json_body_includes(
+            serde_json::json!({
+                "method": "eth_call",
+                "params": [{
+                    "from": "0x742d35cc6634c0532925a3b844bc454e4438f44e",
+                    "to": "0x0000000000000000000000000000000000000001",
+                    "value": "0x64",
+                }, "latest"],
+            })
+            .to_string(),
+        );
+        then.status(200).json_body(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": { "code": 3, "message": "execution reverted", "data": revert_data },
+        }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    let transaction: UnsignedEthereumTransaction = UnsignedStandardEthereumTransaction {
+        to: Some("0x0000000000000000000000000000000000000001".to_string()),
+        value: Some(100.into()),
+        ..Default::default()
+    }
+    .into();
+
+    let error = client
+        .wallets()
+        .ethereum()
+        .send_transaction_with_options(
+            WalletId::new("wallet_id"),
+            "eip155:1",
+            transaction,
+            &AuthorizationContext::new(),
+            None,
+            &SendTransactionOptions::new().with_preflight(server.url("/ethereum")),
+        )
+        .await
+        .unwrap_err();
+
+    let PrivySignedApiError::Preflight(PreflightError::Reverted(reason)) = error else {
+        panic!("unexpected error: {error:?}");
+    };
+    assert_eq!(
+        reason,
+        RevertReason::Error("ERC20: transfer amount exceeds balance".to_string())
+    );
+    wallet.assert();
+    eth_call.assert();
+    send.assert_calls(0);
+}
+
+#[cfg(feature = "ethereum")]
+#[tokio::test]
+async fn test_interceptors_reject_and_modify_transactions() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, PrivySignedApiError, TransactionRejected, WalletId,
+        client::PrivyClientOptions,
+        generated::types::{
+            Quantity, UnsignedEthereumTransaction, UnsignedStandardEthereumTransaction,
+        },
+        interceptor::{FnInterceptor, InterceptedTransaction, TransactionRequest},
+    };
+
+    const ALLOWED: &str = "0x0000000000000000000000000000000000000001";
+
+    let server = MockServer::start();
+    let send = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/wallets/wallet_id/rpc")
+            .json_body_includes(
+                serde_json::json!({
+                    "method": "eth_sendTransaction",
+                    "params": { "transaction": { "to": ALLOWED, "value": 100 } },
+                })
+                .to_string(),
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "method": "eth_sendTransaction",
+                "data": { "caip2": "eip155:1", "hash": "0xabc" },
+            }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default()).with_interceptor(
+        FnInterceptor(|request: &mut TransactionRequest| {
+            let InterceptedTransaction::Ethereum(tx) = &mut request.transaction else {
+                return Ok(());
+            };
+            let UnsignedEthereumTransaction::StandardEthereumTransaction(tx) = &mut **tx else {
+                return Ok(());
+            };
+            if tx.to.as_deref() != Some(ALLOWED) {
+                return Err(TransactionRejected::new("recipient is not allowlisted"));
+            }
+            // cap the value at 100 wei
+            if matches!(tx.value, Some(Quantity::Integer(value)) if value > 100) {
+                tx.value = Some(100.into());
+            }
+            Ok(())
+        }),
+    );
+    let ethereum = client.wallets().ethereum();
+    let ctx = AuthorizationContext::new();
+    let transaction = |to: &str| -> UnsignedEthereumTransaction {
+        UnsignedStandardEthereumTransaction {
+            to: Some(to.to_string()),
+            value: Some(1000.into()),
+            ..Default::default()
+        }
+        .into()
+    };
+
+    let error = ethereum
+        .send_transaction(
+            WalletId::new("wallet_id"),
+            "eip155:1",
+            transaction("0x0000000000000000000000000000000000000002"),
+            &ctx,
+            None,
+        )
+        .await
+        .unwrap_err();
+    let PrivySignedApiError::Rejected(rejected) = error else {
+        panic!("unexpected error: {error:?}");
+    };
+    assert_eq!(rejected.reason, "recipient is not allowlisted");
+    send.assert_calls(0);
+
+    ethereum
+        .send_transaction(
+            WalletId::new("wallet_id"),
+            "eip155:1",
+            transaction(ALLOWED),
+            &ctx,
+            None,
+        )
+        .await
+        .expect("allowlisted transaction is sent");
+    send.assert();
+}
+
+#[tokio::test]
+async fn test_interceptors_can_reject_transfers_made_with_send() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, PrivySignedApiError, TransactionRejected, WalletId,
+        client::PrivyClientOptions,
+        generated::types::TokenTransferSource,
+        interceptor::{FnInterceptor, InterceptedTransaction, TransactionRequest},
+        send::{SendAsset, SendRequest},
+    };
+
+    let server = MockServer::start();
+    let transfer = server.mock(|when, then| {
+        when.method(POST).path("/v1/wallets/wallet_id/transfer");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "id": "action_id",
+                "type": "transfer",
+                "status": "pending",
+                "wallet_id": "wallet_id",
+                "created_at": "2025-01-01T00:00:00Z",
+                "destination_address": "0xB00F0759DbeeF5E543Cc3E3B07A6442F5f3928a2",
+                "source_chain": "ethereum",
+                "steps": [],
+            }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default()).with_interceptor(
+        FnInterceptor(|request: &mut TransactionRequest| {
+            let InterceptedTransaction::Transfer(transfer) = &request.transaction else {
+                return Ok(());
+            };
+            let TokenTransferSource::NamedTokenTransferSource(source) = &transfer.source else {
+                return Ok(());
+            };
+            // cap native transfers at 1 eth
+            match source.amount.parse::<f64>() {
+                Ok(amount) if source.asset != "eth" || amount <= 1.0 => Ok(()),
+                _ => Err(TransactionRejected::new("transfer is over the cap")),
+            }
+        }),
+    );
+    let ctx = AuthorizationContext::new();
+    let request = |amount: &str| SendRequest {
+        chain: "ethereum".to_string(),
+        to: "0xB00F0759DbeeF5E543Cc3E3B07A6442F5f3928a2".to_string(),
+        amount: amount.to_string(),
+        asset: SendAsset::Native,
+    };
+
+    let error = client
+        .send(WalletId::new("wallet_id"), request("2"), &ctx, None)
+        .await
+        .unwrap_err();
+    let PrivySignedApiError::Rejected(rejected) = error else {
+        panic!("unexpected error: {error:?}");
+    };
+    assert_eq!(rejected.reason, "transfer is over the cap");
+    transfer.assert_calls(0);
+
+    client
+        .send(WalletId::new("wallet_id"), request("0.5"), &ctx, None)
+        .await
+        .expect("transfer under the cap is sent");
+    transfer.assert();
+}
+
+#[cfg(feature = "ethereum")]
+#[tokio::test]
+async fn test_response_sinks_record_wallet_rpc_responses() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, WalletId, client::PrivyClientOptions,
+        generated::types::UnsignedStandardEthereumTransaction, response_sink::ChannelSink,
+    };
+
+    let server = MockServer::start();
+    let body = serde_json::json!({
+        "method": "eth_sendTransaction",
+        "data": { "caip2": "eip155:1", "hash": "0xabc" },
+    });
+    server.mock(|when, then| {
+        when.method(POST).path("/v1/wallets/wallet_id/rpc");
+        then.status(200)
+            .header("content-type", "application/json")
+            .header("privy-request-id", "req_123")
+            .json_body(body.clone());
+    });
+
+    let (sink, mut responses) = ChannelSink::new(8);
+    let client = common::mock_client(&server, PrivyClientOptions::default())
+        .with_response_sink(sink.clone());
+
+    client
+        .wallets()
+        .ethereum()
+        .send_transaction(
+            WalletId::new("wallet_id"),
+            "eip155:1",
+            UnsignedStandardEthereumTransaction::default().into(),
+            &AuthorizationContext::new(),
+            Some("idem_1"),
+        )
+        .await
+        .expect("transaction is sent");
+
+    let recorded = responses.try_recv().expect("response was recorded");
+    assert_eq!(recorded.endpoint, "rpc");
+    assert_eq!(recorded.wallet_id.as_str(), "wallet_id");
+    assert_eq!(recorded.method.as_deref(), Some("eth_sendTransaction"));
+    assert_eq!(recorded.idempotency_key.as_deref(), Some("idem_1"));
+    assert_eq!(recorded.request_id.as_deref(), Some("req_123"));
+    assert_eq!(recorded.body, body);
+    assert_eq!(sink.dropped(), 0);
+}
+
+#[tokio::test]
+async fn test_key_quorums_can_be_looked_up_by_name() {
+    use httpmock::prelude::*;
+    use privy_rs::{client::PrivyClientOptions, generated::types::KeyQuorumCreateRequestBody};
+
+    let server = MockServer::start();
+    let quorum = |name: &str| {
+        serde_json::json!({
+            "id": "kq_treasury",
+            "display_name": name,
+            "authorization_keys": [],
+            "authorization_threshold": 1,
+            "user_ids": [],
+            "key_quorum_ids": [],
+        })
+    };
+    let create = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/key_quorums")
+            .json_body_includes(serde_json::json!({ "display_name": "treasury" }).to_string());
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(quorum("treasury"));
+    });
+    let mut get = server.mock(|when, then| {
+        when.method(GET).path("/v1/key_quorums/kq_treasury");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(quorum("treasury"));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+    let body = KeyQuorumCreateRequestBody {
+        public_keys: vec!["key".to_string()],
+        ..Default::default()
+    };
+
+    client
+        .key_quorums()
+        .create_named("treasury", body.clone())
+        .await
+        .expect("quorum is created");
+    create.assert();
+
+    // names are shared by every subclient of the client
+    let quorums = client.key_quorums();
+    assert_eq!(
+        quorums.id_by_name("treasury").as_deref(),
+        Some("kq_treasury")
+    );
+    assert!(quorums.create_named("treasury", body).await.is_err());
+    create.assert_calls(1);
+
+    let found = quorums.find_by_name("treasury").await.unwrap();
+    assert_eq!(found.map(|q| q.id), Some("kq_treasury".to_string()));
+    assert!(quorums.find_by_name("ops").await.unwrap().is_none());
+
+    // a quorum deleted elsewhere is forgotten
+    get.delete();
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/key_quorums/kq_treasury");
+        then.status(404);
+    });
+    assert!(quorums.find_by_name("treasury").await.unwrap().is_none());
+    assert_eq!(quorums.id_by_name("treasury"), None);
+}
+
+#[tokio::test]
+async fn test_latency_is_tracked_per_endpoint() {
+    use httpmock::prelude::*;
+    use privy_rs::client::PrivyClientOptions;
+
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/wallets/wallet_id");
+        then.status(500).delay(std::time::Duration::from_millis(50));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    assert!(client.wallets().get("wallet_id").await.is_err());
+
+    let average = client
+        .latency()
+        .average("get_wallet")
+        .expect("call is recorded");
+    assert!(average >= std::time::Duration::from_millis(50));
+    assert_eq!(client.latency().endpoints(), [("get_wallet", average)]);
+}
+
+#[tokio::test]
+async fn test_signature_failures_suggest_likely_causes() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, KeyQuorumId, PrivyApiErrorExt, client::PrivyClientOptions,
+    };
+
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(DELETE).path("/v1/key_quorums/kq_treasury");
+        then.status(401)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "error": "Invalid authorization signature" }));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/wallets/wallet_missing");
+        then.status(404);
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    let error = client
+        .key_quorums()
+        .delete(
+            KeyQuorumId::new("kq_treasury"),
+            &AuthorizationContext::new(),
+        )
+        .await
+        .expect_err("signature is rejected");
+    let hints = error.hints();
+    assert_eq!(hints.len(), 3, "{hints:?}");
+    assert!(hints[0].contains("authorization context"));
+    assert!(hints[2].contains(&server.url("/v1/key_quorums/kq_treasury")));
+
+    let error = client
+        .wallets()
+        .get("wallet_missing")
+        .await
+        .expect_err("wallet does not exist");
+    assert_eq!(error.hints().len(), 1);
+}
+
+#[tokio::test]
+async fn test_server_error_hints_only_call_retries_safe_with_an_idempotency_key() {
+    use httpmock::prelude::*;
+    use privy_rs::{PrivyApiErrorExt, client::PrivyClientOptions};
+
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(POST).path("/v1/policies");
+        then.status(503);
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+    let body = serde_json::from_value(serde_json::json!({
+        "version": "1.0",
+        "name": "policy",
+        "chain_type": "ethereum",
+        "rules": [],
+    }))
+    .unwrap();
+
+    let error = client
+        .policies()
+        .create(Some("idempotency-key"), &body)
+        .await
+        .expect_err("server fails");
+    assert!(error.hints()[0].contains("retrying with the same idempotency key is safe"));
+
+    let error = client
+        .policies()
+        .create(None, &body)
+        .await
+        .expect_err("server fails");
+    assert!(error.hints()[0].contains("check its outcome before retrying"));
+}
+
+#[cfg(feature = "ethereum")]
+#[tokio::test]
+async fn test_policy_denials_are_told_apart_from_other_errors() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, ErrorKind, PrivySignedApiError, WalletId, client::PrivyClientOptions,
+    };
+
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(POST).path("/v1/wallets/wallet_denied/rpc");
+        then.status(400).json_body(serde_json::json!({
+            "error": "Transaction denied by policy",
+            "code": "policy_violation",
+            "details": { "policy_id": "pol_123", "rule_name": "Allowlist USDC" },
+        }));
+    });
+    // without the code, a message mentioning a policy is not a denial
+    server.mock(|when, then| {
+        when.method(POST).path("/v1/wallets/wallet_uncoded/rpc");
+        then.status(400)
+            .json_body(serde_json::json!({ "error": "Transaction denied by policy" }));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/wallets/wallet_unauthorized/rpc");
+        then.status(401)
+            .json_body(serde_json::json!({ "error": "Invalid authorization signature" }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+    let ctx = AuthorizationContext::new();
+
+    let error = client
+        .wallets()
+        .ethereum()
+        .sign_message(WalletId::new("wallet_denied"), "hello", &ctx, None)
+        .await
+        .expect_err("policy denies the request");
+    let PrivySignedApiError::PolicyDenied {
+        policy_id,
+        rule_name,
+        ..
+    } = &error
+    else {
+        panic!("expected a policy denial, got {error:?}");
+    };
+    assert_eq!(policy_id.as_deref(), Some("pol_123"));
+    assert_eq!(rule_name.as_deref(), Some("Allowlist USDC"));
+    assert_eq!(
+        error.to_string(),
+        "Denied by rule `Allowlist USDC` of policy pol_123"
+    );
+    assert_eq!(error.details().map(|d| d.status.as_u16()), Some(400));
+    assert_eq!(error.error_code(), Some("policy_violation"));
+    let body = error.error_body().expect("the body is an error payload");
+    assert_eq!(
+        body.message.as_deref(),
+        Some("Transaction denied by policy")
+    );
+    assert_eq!(
+        body.details,
+        Some(serde_json::json!({ "policy_id": "pol_123", "rule_name": "Allowlist USDC" }))
+    );
+    assert!(error.hints()[0].contains("denied again"));
+    assert_eq!(error.kind(), ErrorKind::PolicyDenied);
+    assert!(!error.is_retryable());
+
+    let error = client
+        .wallets()
+        .ethereum()
+        .sign_message(WalletId::new("wallet_unauthorized"), "hello", &ctx, None)
+        .await
+        .expect_err("signature is rejected");
+    assert!(matches!(error, PrivySignedApiError::Api(_)), "{error:?}");
+    assert_eq!(error.kind(), ErrorKind::Auth);
+    assert_eq!(error.error_code(), None);
+    assert_eq!(
+        error.error_body().and_then(|body| body.message.as_deref()),
+        Some("Invalid authorization signature")
+    );
+
+    let error = client
+        .wallets()
+        .ethereum()
+        .sign_message(WalletId::new("wallet_uncoded"), "hello", &ctx, None)
+        .await
+        .expect_err("request is refused");
+    assert!(matches!(error, PrivySignedApiError::Api(_)), "{error:?}");
+}
+
+#[tokio::test]
+async fn test_users_exist_and_count_without_keeping_pages() {
+    use httpmock::prelude::*;
+    use privy_rs::client::PrivyClientOptions;
+
+    let server = MockServer::start();
+    let user = |id: &str| {
+        serde_json::json!({
+            "id": id,
+            "created_at": 0,
+            "has_accepted_terms": true,
+            "is_guest": false,
+            "linked_accounts": [],
+            "mfa_methods": [],
+        })
+    };
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/users/email/address")
+            .json_body(serde_json::json!({ "address": "alice@example.com" }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(user("alice"));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/users/email/address")
+            .json_body(serde_json::json!({ "address": "bob@example.com" }));
+        then.status(404);
+    });
+    let first = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/users")
+            .query_param("limit", "100.0")
+            .query_param_missing("cursor");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "data": [user("alice"), user("carol")],
+                "next_cursor": "carol",
+            }));
+    });
+    let second = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/users")
+            .query_param("limit", "100.0")
+            .query_param("cursor", "carol");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "data": [user("dave")], "next_cursor": null }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+    let users = client.users();
+
+    assert!(users.exists_by_email("alice@example.com").await.unwrap());
+    assert!(!users.exists_by_email("bob@example.com").await.unwrap());
+
+    assert_eq!(users.count().await.unwrap(), 3);
+    first.assert();
+    second.assert();
+}
+
+#[tokio::test]
+async fn test_kyc_documents_are_uploaded_as_multipart() {
+    use std::io::Write;
+
+    use httpmock::prelude::*;
+    use privy_rs::{
+        DocumentUploadError, PrivyApiError, UserId, client::PrivyClientOptions,
+        subclients::KycDocument,
+    };
+
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(b"passport scan").unwrap();
+
+    let server = MockServer::start();
+    let upload = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/kraken_embed/users/user_1/verifications")
+            .header_missing("privy-authorization-signature")
+            .header_includes("content-type", "multipart/form-data; boundary=")
+            .body_includes("name=\"type\"\r\n\r\nidentity_document")
+            .body_includes("Content-Type: image/jpeg\r\n\r\npassport scan");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({}));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    let document =
+        KycDocument::new(file.path(), "identity_document").with_content_type("image/jpeg");
+    client
+        .fiat()
+        .upload_document(UserId::new("user_1"), &document)
+        .await
+        .expect("document is uploaded");
+    upload.assert();
+
+    let missing = KycDocument::new(file.path().with_extension("missing"), "identity_document");
+    assert!(matches!(
+        client
+            .fiat()
+            .upload_document(UserId::new("user_1"), &missing)
+            .await,
+        Err(DocumentUploadError::Read(_))
+    ));
+    upload.assert_calls(1);
+
+    let dry_run = common::mock_client(
+        &server,
+        PrivyClientOptions {
+            dry_run: true,
+            ..Default::default()
+        },
+    );
+    assert!(matches!(
+        dry_run
+            .fiat()
+            .upload_document(UserId::new("user_1"), &document)
+            .await,
+        Err(DocumentUploadError::Upload(PrivyApiError::InvalidRequest(
+            _
+        )))
+    ));
+    upload.assert_calls(1);
+}
+
+#[cfg(feature = "ethereum")]
+#[tokio::test]
+async fn test_services_sign_with_bound_context() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, PrivateKey, PrivyApiError, PrivySignedApiError, WalletId,
+        client::PrivyClientOptions,
+    };
+
+    let server = MockServer::start();
+    let rpc = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/wallets/wallet_id/rpc")
+            .header_exists("privy-authorization-signature");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "method": "personal_sign",
+                "data": { "signature": "0x1234", "encoding": "hex" },
+            }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+    let ctx = AuthorizationContext::new().push(PrivateKey::new(
+        include_str!("./test_private_key.pem").to_string(),
+    ));
+
+    client
+        .wallets()
+        .ethereum()
+        .with_context(ctx)
+        .sign_message(WalletId::new("wallet_id"), "hello", None, None)
+        .await
+        .expect("message is signed with the bound context");
+    rpc.assert();
+
+    let error = client
+        .wallets()
+        .ethereum()
+        .sign_message(WalletId::new("wallet_id"), "hello", None, None)
+        .await
+        .expect_err("there is no context to sign with");
+    assert!(matches!(
+        error,
+        PrivySignedApiError::Api(PrivyApiError::InvalidRequest(message))
+            if message.contains("with_context")
+    ));
+    rpc.assert_calls(1);
+}
+
+#[cfg(all(feature = "ethereum", feature = "solana"))]
+#[tokio::test]
+async fn test_sign_message_auto_dispatches_on_chain_type() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, WalletId, client::PrivyClientOptions, subclients::MessageSignature,
+    };
+
+    let server = MockServer::start();
+    for (id, chain_type) in [
+        ("eth_wallet", "ethereum"),
+        ("sol_wallet", "solana"),
+        ("cosmos_wallet", "cosmos"),
+    ] {
+        server.mock(|when, then| {
+            when.method(GET).path(format!("/v1/wallets/{id}"));
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(serde_json::json!({
+                    "id": id,
+                    "address": "address",
+                    "chain_type": chain_type,
+                    "policy_ids": [],
+                    "additional_signers": [],
+                    "created_at": 0,
+                    "exported_at": null,
+                    "imported_at": null,
+                    "owner_id": null,
+                }));
+        });
+    }
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/wallets/eth_wallet/rpc")
+            .json_body_includes(
+                serde_json::json!({
+                    "method": "personal_sign",
+                    "params": { "message": "0x6869", "encoding": "hex" },
+                })
+                .to_string(),
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "method": "personal_sign",
+                "data": { "signature": "0xeth", "encoding": "hex" },
+            }));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/wallets/sol_wallet/rpc")
+            .json_body_includes(
+                serde_json::json!({
+                    "method": "signMessage",
+                    "params": { "message": "aGk=", "encoding": "base64" },
+                })
+                .to_string(),
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "method": "signMessage",
+                "data": { "signature": "c29s", "encoding": "base64" },
+            }));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/wallets/cosmos_wallet/raw_sign")
+            .json_body(serde_json::json!({
+                "params": { "bytes": "6869", "encoding": "hex", "hash_function": "sha256" },
+            }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "method": "raw_sign",
+                "data": { "signature": "0x1234", "encoding": "hex" },
+            }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+    let wallets = client.wallets();
+    let ctx = AuthorizationContext::new();
+
+    assert_eq!(
+        wallets
+            .sign_message_auto(WalletId::new("eth_wallet"), b"hi", &ctx)
+            .await
+            .unwrap(),
+        MessageSignature::PersonalSign("0xeth".to_string())
+    );
+    assert_eq!(
+        wallets
+            .sign_message_auto(WalletId::new("sol_wallet"), b"hi", &ctx)
+            .await
+            .unwrap(),
+        MessageSignature::SignMessage("c29s".to_string())
+    );
+    assert_eq!(
+        wallets
+            .sign_message_auto(WalletId::new("cosmos_wallet"), b"hi", &ctx)
+            .await
+            .unwrap(),
+        MessageSignature::Raw("0x1234".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_usage_counts_users_and_wallets() {
+    use httpmock::prelude::*;
+    use privy_rs::client::PrivyClientOptions;
+
+    let server = MockServer::start();
+    let wallet = |id: &str| {
+        serde_json::json!({
+            "id": id,
+            "address": "address",
+            "chain_type": "ethereum",
+            "policy_ids": [],
+            "additional_signers": [],
+            "created_at": 0,
+            "exported_at": null,
+            "imported_at": null,
+            "owner_id": null,
+        })
+    };
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/users");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "data": [], "next_cursor": null }));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/wallets")
+            .query_param_missing("cursor");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "data": [wallet("wallet_1"), wallet("wallet_2")],
+                "next_cursor": "wallet_2",
+            }));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/wallets")
+            .query_param("cursor", "wallet_2");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "data": [wallet("wallet_3")], "next_cursor": null }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    let usage = client.usage().await.unwrap();
+    assert_eq!((usage.users, usage.wallets), (0, 3));
+}
+
+#[cfg(feature = "strict-validation")]
+#[tokio::test]
+async fn test_strict_validation_reports_mismatches_by_pointer() {
+    use httpmock::prelude::*;
+    use privy_rs::{PrivyApiError, client::PrivyClientOptions};
+
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/wallets/wallet_id");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "id": "wallet_id",
+                "address": "0xF1DBff66C993EE895C8cb176c30b07A559d76496",
+                "chain_type": "dogecoin",
+                "created_at": 1_741_834_854_578.0,
+                "policy_ids": [],
+                "additional_signers": [],
+            }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    match client.wallets().get("wallet_id").await {
+        Err(PrivyApiError::Custom(report)) => assert!(
+            report.contains("/chain_type: expected one of"),
+            "unexpected report: {report}"
+        ),
+        other => panic!("expected a schema mismatch, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_rpc_signs_the_idempotency_key_it_sends() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, Method, PrivateKey, WalletId, client::PrivyClientOptions,
+        generate_authorization_signatures, generated::types::WalletRpcRequestBody,
+    };
+
+    let server = MockServer::start();
+    let ctx = AuthorizationContext::new().push(PrivateKey::new(
+        include_str!("./test_private_key.pem").to_string(),
+    ));
+    let body: WalletRpcRequestBody = serde_json::from_value(serde_json::json!({
+        "method": "personal_sign",
+        "params": { "message": "hello", "encoding": "utf-8" },
+    }))
+    .unwrap();
+
+    let url = format!("{}/v1/wallets/wallet_id/rpc", server.base_url());
+    let signed = |idempotency_key: Option<&str>| {
+        generate_authorization_signatures(
+            &ctx,
+            "test_app_id",
+            Method::POST,
+            url.clone(),
+            &body,
+            idempotency_key.map(str::to_string),
+        )
+    };
+    let with_key = signed(Some("unique-key-123")).await.unwrap();
+    let without_key = signed(None).await.unwrap();
+    assert_ne!(with_key, without_key);
+
+    let rpc = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/wallets/wallet_id/rpc")
+            .header("privy-idempotency-key", "unique-key-123")
+            .header("privy-authorization-signature", &with_key);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "method": "personal_sign",
+                "data": { "signature": "0x1234", "encoding": "hex" },
+            }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    client
+        .wallets()
+        .rpc(
+            WalletId::new("wallet_id"),
+            &ctx,
+            Some("unique-key-123"),
+            &body,
+        )
+        .await
+        .expect("the signature covers the idempotency key");
+    rpc.assert();
+}
+
+#[tokio::test]
+async fn test_request_options_headers_are_sent_but_not_signed() {
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, Method, PrivateKey, WalletId, client::PrivyClientOptions,
+        generate_authorization_signatures, generated::types::WalletRpcRequestBody,
+        request_options::RequestOptions,
+    };
+
+    let server = MockServer::start();
+    let ctx = AuthorizationContext::new().push(PrivateKey::new(
+        include_str!("./test_private_key.pem").to_string(),
+    ));
+    let body: WalletRpcRequestBody = serde_json::from_value(serde_json::json!({
+        "method": "personal_sign",
+        "params": { "message": "hello", "encoding": "utf-8" },
+    }))
+    .unwrap();
+    let signature = generate_authorization_signatures(
+        &ctx,
+        "test_app_id",
+        Method::POST,
+        format!("{}/v1/wallets/wallet_id/rpc", server.base_url()),
+        &body,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let rpc = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/wallets/wallet_id/rpc")
+            .header("x-tenant-id", "acme")
+            .header("privy-app-id", "test_app_id")
+            .header("privy-authorization-signature", &signature);
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "method": "personal_sign",
+                "data": { "signature": "0x1234", "encoding": "hex" },
+            }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default())
+        .with_request_options(RequestOptions::new().header("x-tenant-id", "acme").unwrap())
+        .expect("Failed to apply request options");
+
+    client
+        .wallets()
+        .rpc(WalletId::new("wallet_id"), &ctx, None, &body)
+        .await
+        .expect("the tenant header is sent and left out of the signature");
+    rpc.assert();
+}
+
+#[tokio::test]
+async fn test_subclient_request_options_add_to_those_of_the_client() {
+    use httpmock::prelude::*;
+    use privy_rs::{client::PrivyClientOptions, request_options::RequestOptions};
+
+    let server = MockServer::start();
+    let with_options = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/wallets/with_options")
+            .header("x-tenant-id", "acme")
+            .header("x-end-user", "user_1");
+        then.status(404);
+    });
+    let without_options = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/wallets/without_options")
+            .header("x-tenant-id", "acme")
+            .header_missing("x-end-user");
+        then.status(404);
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default())
+        .with_request_options(RequestOptions::new().header("x-tenant-id", "acme").unwrap())
+        .expect("Failed to apply request options");
+
+    let end_user = RequestOptions::new()
+        .header("x-end-user", "user_1")
+        .unwrap();
+    let _ = client
+        .wallets()
+        .with_request_options(end_user)
+        .get("with_options")
+        .await;
+    let _ = client.wallets().get("without_options").await;
+
+    with_options.assert();
+    without_options.assert();
+}
+
+#[cfg(feature = "alloy")]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sign_blob_transaction_signs_the_versioned_hashes() {
+    use alloy_consensus::{
+        BlobTransactionSidecar, Bytes48, SignableTransaction, TxEip4844, TxEip4844WithSidecar,
+    };
+    use alloy_primitives::{Address, B256, Signature, U256};
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, BlobTransactionError, PrivateKey, WalletId,
+        client::PrivyClientOptions,
+    };
+    use sha3::Digest;
+
+    let secret = secp256k1::SecretKey::from_slice(&[7; 32]).unwrap();
+    let public = secret
+        .public_key(secp256k1::SECP256K1)
+        .serialize_uncompressed();
+    let address = Address::from_slice(&sha3::Keccak256::digest(&public[1..])[12..]);
+
+    let sidecar = BlobTransactionSidecar::new(
+        vec![Default::default()],
+        vec![Bytes48::repeat_byte(1)],
+        vec![Bytes48::ZERO],
+    );
+    let transaction = TxEip4844WithSidecar::from_tx_and_sidecar(
+        TxEip4844 {
+            chain_id: 1,
+            nonce: 3,
+            gas_limit: 21_000,
+            max_fee_per_gas: 30_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            max_fee_per_blob_gas: 1_000_000,
+            blob_versioned_hashes: sidecar.versioned_hashes().collect(),
+            ..Default::default()
+        },
+        sidecar,
+    );
+    let hash = transaction.signature_hash();
+    let compact = secp256k1::SECP256K1
+        .sign_ecdsa(&secp256k1::Message::from_digest(hash.0), &secret)
+        .serialize_compact();
+    let signature = [false, true]
+        .into_iter()
+        .map(|parity| {
+            Signature::new(
+                U256::from_be_slice(&compact[..32]),
+                U256::from_be_slice(&compact[32..]),
+                parity,
+            )
+        })
+        .find(|signature| signature.recover_address_from_prehash(&hash).ok() == Some(address))
+        .unwrap();
+
+    let server = MockServer::start();
+    let wallet = server.mock(|when, then| {
+        when.method(GET).path("/v1/wallets/wallet_id");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "id": "wallet_id",
+                "address": address.to_string(),
+                "chain_type": "ethereum",
+                "created_at": 1_741_834_854_578.0,
+                "exported_at": null,
+                "imported_at": null,
+                "owner_id": null,
+                "additional_signers": [],
+                "policy_ids": []
+            }));
+    });
+    let rpc = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/wallets/wallet_id/rpc")
+            .json_body(serde_json::json!({
+                "method": "secp256k1_sign",
+                "params": { "hash": format!("{hash:#x}") },
+            }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "method": "secp256k1_sign",
+                "data": { "signature": signature.to_string(), "encoding": "hex" },
+            }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+    let ctx = AuthorizationContext::new().push(PrivateKey::new(
+        include_str!("./test_private_key.pem").to_string(),
+    ));
+    let ethereum = client.wallets().ethereum();
+
+    let mut mismatched = transaction.clone();
+    mismatched.tx.blob_versioned_hashes[0] = B256::repeat_byte(1);
+    let error = ethereum
+        .sign_blob_transaction(WalletId::new("wallet_id"), mismatched, &ctx)
+        .await
+        .expect_err("the sidecar does not match");
+    assert!(matches!(error, BlobTransactionError::InvalidSidecar(_)));
+    wallet.assert_calls(0);
+
+    let signed = ethereum
+        .sign_blob_transaction(WalletId::new("wallet_id"), transaction.clone(), &ctx)
+        .await
+        .expect("the transaction is signed");
+    wallet.assert();
+    rpc.assert();
+    assert_eq!(signed.tx(), &transaction);
+    assert_eq!(signed.signature(), &signature);
+}
+
+#[tokio::test]
+async fn test_policy_rollout_retries_rate_limits_and_resumes() {
+    use std::sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, PolicyId,
+        client::PrivyClientOptions,
+        generated::types::WalletChainType,
+        rollout::{RolloutCheckpoint, RolloutOptions},
+    };
+
+    let policy_id = "aaaaaaaaaaaaaaaaaaaaaaaa";
+    let wallet = |id: &str, chain_type: &str, policy_ids: &[&str]| {
+        serde_json::json!({
+            "id": id,
+            "address": "address",
+            "chain_type": chain_type,
+            "policy_ids": policy_ids,
+            "additional_signers": [],
+            "created_at": 0,
+            "exported_at": null,
+            "imported_at": null,
+            "owner_id": null,
+        })
+    };
+
+    let server = MockServer::start();
+    let second_page = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/wallets")
+            .query_param("cursor", "page_2");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "data": [wallet("eth_2", "ethereum", &[])],
+                "next_cursor": null,
+            }));
+    });
+    let first_page = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/wallets")
+            .query_param_missing("cursor");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "data": [
+                    wallet("eth_1", "ethereum", &["old_policy"]),
+                    wallet("sol_1", "solana", &[]),
+                    wallet("eth_done", "ethereum", &[policy_id]),
+                ],
+                "next_cursor": "page_2",
+            }));
+    });
+    let updates = Arc::new(AtomicUsize::new(0));
+    let update = server.mock(|when, then| {
+        let updates = updates.clone();
+        when.method(PATCH)
+            .path_prefix("/v1/wallets/eth_")
+            .json_body(serde_json::json!({ "policy_ids": [policy_id] }));
+        then.respond_with(move |_request: &HttpMockRequest| {
+            // the first update is rate limited
+            if updates.fetch_add(1, Ordering::SeqCst) == 0 {
+                return HttpMockResponse::builder()
+                    .status(429)
+                    .header("retry-after", "0")
+                    .build();
+            }
+            HttpMockResponse::builder()
+                .status(200)
+                .header("content-type", "application/json")
+                .body(wallet("eth", "ethereum", &[policy_id]).to_string())
+                .build()
+        });
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+    let ctx = AuthorizationContext::new();
+    let is_ethereum = |wallet: &privy_rs::generated::types::Wallet| {
+        wallet.chain_type == WalletChainType::Ethereum
+    };
+
+    let checkpoints = Arc::new(Mutex::new(Vec::new()));
+    let options = RolloutOptions {
+        on_checkpoint: Some(Arc::new({
+            let checkpoints = checkpoints.clone();
+            move |checkpoint: &RolloutCheckpoint| {
+                checkpoints.lock().unwrap().push(checkpoint.clone())
+            }
+        })),
+        ..Default::default()
+    };
+    let report = client
+        .policies()
+        .rollout_with_options(PolicyId::new(policy_id), is_ethereum, &ctx, options)
+        .await
+        .unwrap();
+
+    let mut updated: Vec<_> = report.updated.iter().map(|id| id.as_str()).collect();
+    updated.sort_unstable();
+    assert_eq!(updated, ["eth_1", "eth_2"]);
+    assert_eq!(report.unchanged.len(), 1);
+    assert_eq!(report.unmatched, 1);
+    assert!(report.failed.is_empty());
+    assert_eq!(report.rate_limited, 1);
+    assert!(report.checkpoint.is_none());
+    update.assert_calls(3);
+
+    let checkpoints = checkpoints.lock().unwrap().clone();
+    assert_eq!(checkpoints.len(), 1);
+    assert_eq!(checkpoints[0].cursor(), "page_2");
+
+    // resuming skips the wallets before the checkpoint
+    let options = RolloutOptions {
+        resume_from: Some(checkpoints[0].clone()),
+        ..Default::default()
+    };
+    let report = client
+        .policies()
+        .rollout_with_options(PolicyId::new(policy_id), is_ethereum, &ctx, options)
+        .await
+        .unwrap();
+    assert_eq!(report.updated.len(), 1);
+    first_page.assert_calls(1);
+    second_page.assert_calls(2);
+}
+
+#[tokio::test]
+async fn test_wallet_hooks_see_created_and_updated_wallets() {
+    use std::sync::{Arc, Mutex};
+
+    use httpmock::prelude::*;
+    use privy_rs::{
+        AuthorizationContext, WalletId,
+        client::PrivyClientOptions,
+        generated::types::{CreateWalletBody, Wallet, WalletChainType, WalletUpdateRequestBody},
+        wallet_hooks::WalletLifecycleHook,
+    };
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl WalletLifecycleHook for Recorder {
+        fn on_created(&self, wallet: &Wallet) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("created {}", wallet.id));
+        }
+
+        fn on_updated(&self, wallet: &Wallet) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("updated {}", wallet.id));
+        }
+    }
+
+    let server = MockServer::start_async().await;
+    let wallet = serde_json::json!({
+        "id": "wallet_1",
+        "address": "address",
+        "chain_type": "ethereum",
+        "policy_ids": [],
+        "additional_signers": [],
+        "created_at": 0,
+        "exported_at": null,
+        "imported_at": null,
+        "owner_id": null,
+    });
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/v1/wallets");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(wallet.clone());
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(PATCH).path("/v1/wallets/wallet_1");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(wallet.clone());
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(PATCH).path("/v1/wallets/wallet_2");
+            then.status(404);
+        })
+        .await;
+
+    let recorder = Recorder::default();
+    let client = common::mock_client(&server, PrivyClientOptions::default())
+        .with_wallet_hook(recorder.clone());
+
+    let body = CreateWalletBody {
+        chain_type: WalletChainType::Ethereum,
+        additional_signers: None,
+        display_name: None,
+        external_id: None,
+        owner: None,
+        owner_id: None,
+        policy_ids: None,
+    };
+    client.wallets().create(None, &body).await.unwrap();
+
+    let ctx = AuthorizationContext::new();
+    let update = WalletUpdateRequestBody::default();
+    client
+        .wallets()
+        .update(WalletId::new("wallet_1"), &ctx, &update)
+        .await
+        .unwrap();
+    // failed calls are not reported
+    client
+        .wallets()
+        .update(WalletId::new("wallet_2"), &ctx, &update)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        ["created wallet_1", "updated wallet_1"]
+    );
+}
+
+#[tokio::test]
+async fn test_errors_are_classified_by_kind() {
+    use httpmock::prelude::*;
+    use privy_rs::{ErrorKind, PrivyApiErrorExt, PrivyClient, client::PrivyClientOptions};
+
+    let server = MockServer::start_async().await;
+    for (wallet_id, status) in [("throttled", 429), ("invalid", 422), ("failed", 503)] {
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path(format!("/v1/wallets/{wallet_id}"));
+                then.status(status);
+            })
+            .await;
+    }
+    let client = |base_url: String| {
+        PrivyClient::new_with_options(
+            "test_app_id".to_string(),
+            "test_app_secret".to_string(),
+            PrivyClientOptions {
+                base_url,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    };
+
+    let wallets = client(server.base_url()).wallets();
+    for (wallet_id, kind, retryable) in [
+        ("throttled", ErrorKind::RateLimited, true),
+        ("invalid", ErrorKind::Validation, false),
+        ("failed", ErrorKind::Server, true),
+    ] {
+        let error = wallets.get(wallet_id).await.unwrap_err();
+        assert_eq!(error.kind(), kind, "{wallet_id}");
+        assert_eq!(error.is_retryable(), retryable, "{wallet_id}");
+    }
+
+    // nothing listens on port 1
+    let error = client("http://127.0.0.1:1".to_string())
+        .wallets()
+        .get("wallet_id")
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Network);
+    assert!(error.is_retryable());
+}
+
+#[tokio::test]
+async fn test_list_all_follows_cursors_and_stops_early() {
+    use futures::{StreamExt, TryStreamExt};
+    use httpmock::prelude::*;
+    use privy_rs::{client::PrivyClientOptions, subclients::ListWalletsOptions};
+
+    let server = MockServer::start();
+    let wallet = |id: &str| {
+        serde_json::json!({
+            "id": id,
+            "address": "address",
+            "chain_type": "ethereum",
+            "policy_ids": [],
+            "additional_signers": [],
+            "created_at": 0,
+            "exported_at": null,
+            "imported_at": null,
+            "owner_id": null,
+        })
+    };
+    let first = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/wallets")
+            .query_param("limit", "2.0")
+            .query_param("user_id", "alice")
+            .query_param_missing("cursor");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "data": [wallet("wallet_1"), wallet("wallet_2")],
+                "next_cursor": "wallet_2",
+            }));
+    });
+    let second = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/wallets")
+            .query_param("limit", "2.0")
+            .query_param("cursor", "wallet_2");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "data": [wallet("wallet_3")], "next_cursor": null }));
+    });
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+    let wallets = client.wallets();
+    let options = ListWalletsOptions::new()
+        .with_user_id("alice")
+        .with_page_size(2);
+
+    let ids: Vec<String> = wallets
+        .list_all(options.clone())
+        .map_ok(|wallet| wallet.id)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(ids, ["wallet_1", "wallet_2", "wallet_3"]);
+    first.assert_calls(1);
+    second.assert_calls(1);
+
+    // stopping within the first page fetches no further pages, whether
+    // with a limit or by taking from the stream
+    let limited: Vec<_> = wallets
+        .list_all(options.clone().with_limit(2))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(limited.len(), 2);
+    let taken: Vec<_> = wallets.list_all(options).take(1).collect().await;
+    assert_eq!(taken.len(), 1);
+    first.assert_calls(3);
+    second.assert_calls(1);
+}
+
+#[tokio::test]
+async fn test_users_and_policies_are_listed_in_full() {
+    use futures::TryStreamExt;
+    use httpmock::prelude::*;
+    use privy_rs::client::PrivyClientOptions;
+
+    const FIRST_POLICY: &str = "xdeor1731y8gme1utsldxynv";
+    const SECOND_POLICY: &str = "clz2rqy4500061234abcd123";
+
+    let server = MockServer::start();
+    let user = |id: &str| {
+        serde_json::json!({
+            "id": id,
+            "created_at": 0,
+            "has_accepted_terms": true,
+            "is_guest": false,
+            "linked_accounts": [],
+            "mfa_methods": [],
+        })
+    };
+    let wallet = |id: &str, policy_ids: &[&str]| {
+        serde_json::json!({
+            "id": id,
+            "address": "address",
+            "chain_type": "ethereum",
+            "policy_ids": policy_ids,
+            "additional_signers": [],
+            "created_at": 0,
+            "exported_at": null,
+            "imported_at": null,
+            "owner_id": null,
+        })
+    };
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/users")
+            .query_param_missing("cursor");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "data": [user("alice"), user("bob")],
+                "next_cursor": "bob",
+            }));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/users")
+            .query_param("cursor", "bob");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({ "data": [user("carol")], "next_cursor": null }));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/wallets")
+            .query_param_missing("cursor");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "data": [wallet("wallet_1", &[FIRST_POLICY]), wallet("wallet_2", &[])],
+                "next_cursor": "wallet_2",
+            }));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/wallets")
+            .query_param("cursor", "wallet_2");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "data": [wallet("wallet_3", &[SECOND_POLICY, FIRST_POLICY])],
+                "next_cursor": null,
+            }));
+    });
+    let policies: Vec<_> = [FIRST_POLICY, SECOND_POLICY]
+        .into_iter()
+        .map(|id| {
+            server.mock(|when, then| {
+                when.method(GET).path(format!("/v1/policies/{id}"));
+                then.status(200)
+                    .header("content-type", "application/json")
+                    .json_body(serde_json::json!({
+                        "id": id,
+                        "name": "policy",
+                        "chain_type": "ethereum",
+                        "created_at": 0,
+                        "rules": [],
+                        "version": "1.0",
+                    }));
+            })
+        })
+        .collect();
+
+    let client = common::mock_client(&server, PrivyClientOptions::default());
+
+    let users = client.users();
+    let ids: Vec<String> = users
+        .list_all()
+        .map_ok(|user| user.id)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(ids, ["alice", "bob", "carol"]);
+
+    // each policy is fetched once, however many wallets name it
+    let policies_client = client.policies();
+    let ids: Vec<String> = policies_client
+        .list_all()
+        .map_ok(|policy| policy.id.to_string())
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(ids, [FIRST_POLICY, SECOND_POLICY]);
+    for policy in policies {
+        policy.assert_calls(1);
+    }
+}
+
+#[test]
+fn test_wallets_and_assets_can_be_compared() {
+    use std::collections::HashSet;
+
+    use privy_rs::generated::types::{Wallet, WalletAsset, WalletChainType};
+
+    let wallet = |id: &str| -> Wallet {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "address": "address",
+            "chain_type": "ethereum",
+            "policy_ids": [],
+            "additional_signers": [],
+            "created_at": 0,
+            "exported_at": null,
+            "imported_at": null,
+            "owner_id": null,
+        }))
+        .unwrap()
+    };
+    assert_eq!(wallet("wallet_1"), wallet("wallet_1"));
+    assert_ne!(wallet("wallet_1"), wallet("wallet_2"));
+
+    let chain_types: HashSet<WalletChainType> = [wallet("wallet_1"), wallet("wallet_2")]
+        .iter()
+        .map(|wallet| wallet.chain_type)
+        .collect();
+    assert_eq!(chain_types.len(), 1);
+
+    let assets: HashSet<WalletAsset> = ["eth", "sol", "eth"]
+        .into_iter()
+        .map(|asset| serde_json::from_value(serde_json::json!(asset)).unwrap())
+        .collect();
+    assert_eq!(assets.len(), 2);
+}
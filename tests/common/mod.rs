@@ -21,8 +21,16 @@ pub(crate) trait IntoApi: Sized {
 impl IntoApi for PrivySignedApiError {
     fn into_api(self) -> Result<PrivyApiError, Self> {
         match self {
-            PrivySignedApiError::Api(e) => Ok(e),
-            PrivySignedApiError::SignatureGeneration(_) => Err(self),
+            PrivySignedApiError::Api(e) | PrivySignedApiError::PolicyDenied { source: e, .. } => {
+                Ok(e)
+            }
+            PrivySignedApiError::SignatureGeneration(_)
+            | PrivySignedApiError::Rejected(_)
+            | PrivySignedApiError::InvalidAddress(_) => Err(self),
+            #[cfg(feature = "solana")]
+            PrivySignedApiError::Simulation(_) => Err(self),
+            #[cfg(feature = "ethereum")]
+            PrivySignedApiError::Preflight(_) => Err(self),
         }
     }
 }
@@ -118,6 +126,20 @@ pub fn get_test_client() -> Result<PrivyClient> {
     Ok(client)
 }
 
+/// A client that sends its requests to `server`, with `options` but for
+/// the base url.
+pub fn mock_client(server: &httpmock::MockServer, options: PrivyClientOptions) -> PrivyClient {
+    PrivyClient::new_with_options(
+        "test_app_id".to_string(),
+        "test_app_secret".to_string(),
+        PrivyClientOptions {
+            base_url: server.base_url(),
+            ..options
+        },
+    )
+    .expect("Failed to create client")
+}
+
 pub async fn get_test_wallet_by_type(
     client: &PrivyClient,
     chain_type: WalletChainType,
@@ -1,7 +1,9 @@
+#![cfg(feature = "ethereum")]
+
 use anyhow::Result;
 use common::get_test_client;
 use p256::elliptic_curve::SecretKey;
-use privy_rs::{AuthorizationContext, PrivateKey, generated::types::*};
+use privy_rs::{AuthorizationContext, PrivateKey, WalletId, generated::types::*};
 use tracing_test::traced_test;
 use uuid::Uuid;
 
@@ -84,7 +86,10 @@ async fn test_wallets_e2e_quorum_workflow() -> Result<()> {
 
     tracing::info!("Testing transaction signing with only one key (should fail)");
 
-    let single_key_result = client.wallets().export(&wallet.id, &single_key_ctx).await;
+    let single_key_result = client
+        .wallets()
+        .export(WalletId::new(&wallet.id), &single_key_ctx)
+        .await;
 
     match single_key_result {
         Err(err) => {
@@ -114,8 +119,12 @@ async fn test_wallets_e2e_quorum_workflow() -> Result<()> {
             ))
     };
 
-    let three_key_result =
-        debug_response!(client.wallets().export(&wallet.id, &three_key_ctx)).await;
+    let three_key_result = debug_response!(
+        client
+            .wallets()
+            .export(WalletId::new(&wallet.id), &three_key_ctx)
+    )
+    .await;
 
     tracing::info!("Three key signing successful: {:?}", three_key_result);
     tracing::info!("E2E quorum workflow test completed successfully!");
@@ -209,7 +218,7 @@ async fn test_rpc_e2e_quorum_workflow() -> Result<()> {
         .wallets()
         .ethereum()
         .sign_message(
-            &wallet.id,
+            WalletId::new(&wallet.id),
             "hello world",
             &single_key_ctx,
             Some(&sign1_idempotency_key),
@@ -245,7 +254,7 @@ async fn test_rpc_e2e_quorum_workflow() -> Result<()> {
     };
 
     let three_key_result = debug_response!(client.wallets().ethereum().sign_message(
-        &wallet.id,
+        WalletId::new(&wallet.id),
         "hello world",
         &three_key_ctx,
         Some(&sign2_idempotency_key)
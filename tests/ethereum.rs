@@ -1,9 +1,10 @@
 #![allow(unnameable_test_items)]
+#![cfg(feature = "ethereum")]
 
 use anyhow::Result;
 use common::{get_test_client, get_test_wallet_id_by_type, mint_staging_jwt};
 use hex::ToHex;
-use privy_rs::{AuthorizationContext, JwtUser, generated::types::*};
+use privy_rs::{AuthorizationContext, JwtUser, WalletId, generated::types::*};
 use sha2::Digest;
 use tracing_test::traced_test;
 
@@ -29,7 +30,7 @@ async fn test_ethereum_sign_message() -> Result<()> {
         });
 
     let result = debug_response!(client.wallets().rpc(
-        &wallet_id,
+        WalletId::new(&wallet_id),
         &AuthorizationContext::new(),
         None,
         &rpc_body
@@ -143,7 +144,7 @@ async fn test_ethereum_sign_typed_data() -> Result<()> {
     });
 
     let result = debug_response!(client.wallets().rpc(
-        &wallet_id,
+        WalletId::new(&wallet_id),
         &AuthorizationContext::new(),
         None,
         &rpc_body
@@ -176,7 +177,7 @@ async fn test_ethereum_sign_secp256k1() -> Result<()> {
     });
 
     let result = debug_response!(client.wallets().rpc(
-        &wallet_id,
+        WalletId::new(&wallet_id),
         &AuthorizationContext::new(),
         None,
         &rpc_body
@@ -214,7 +215,7 @@ async fn test_ethereum_sign_7702_authorization() -> Result<()> {
     );
 
     let result = debug_response!(client.wallets().rpc(
-        &wallet_id,
+        WalletId::new(&wallet_id),
         &AuthorizationContext::new(),
         None,
         &rpc_body
@@ -250,7 +251,7 @@ async fn test_ethereum_sign_transaction() -> Result<()> {
     );
 
     let result = debug_response!(client.wallets().rpc(
-        &wallet_id,
+        WalletId::new(&wallet_id),
         &AuthorizationContext::new(),
         None,
         &rpc_body
@@ -315,7 +316,7 @@ async fn test_ethereum_send_transaction() -> Result<()> {
     ));
 
     let result = debug_response!(client.wallets().rpc(
-        &funded_ethereum_wallet_id,
+        WalletId::new(&funded_ethereum_wallet_id),
         &ctx,
         None,
         &rpc_body
@@ -370,7 +371,7 @@ async fn test_ethereum_send_transaction_with_options_sponsored() -> Result<()> {
     ));
 
     let result = debug_response!(client.wallets().ethereum().send_transaction_with_options(
-        &funded_ethereum_wallet_id,
+        WalletId::new(&funded_ethereum_wallet_id),
         "eip155:11155111",
         transaction.into(),
         &ctx,
@@ -1,11 +1,12 @@
 #![allow(unnameable_test_items)]
+#![cfg(feature = "solana")]
 
 use std::str::FromStr;
 
 use anyhow::Result;
 use base64::{Engine, engine::general_purpose::STANDARD};
 use common::{get_test_client, get_test_wallet_id_by_type, mint_staging_jwt};
-use privy_rs::{AuthorizationContext, JwtUser, generated::types::*};
+use privy_rs::{AuthorizationContext, JwtUser, WalletId, generated::types::*};
 use solana_sdk::{pubkey::Pubkey, transaction::Transaction};
 use tracing_test::traced_test;
 
@@ -30,7 +31,7 @@ async fn test_solana_sign_message() -> Result<()> {
     });
 
     let result = debug_response!(client.wallets().rpc(
-        &wallet_id,
+        WalletId::new(&wallet_id),
         &AuthorizationContext::new(),
         None,
         &rpc_body
@@ -67,7 +68,7 @@ async fn test_solana_sign_transaction() -> Result<()> {
     });
 
     let result = debug_response!(client.wallets().rpc(
-        &wallet_id,
+        WalletId::new(&wallet_id),
         &AuthorizationContext::new(),
         None,
         &rpc_body
@@ -136,7 +137,7 @@ async fn test_solana_sign_and_send_transaction() -> Result<()> {
     ));
 
     let result = debug_response!(client.wallets().rpc(
-        &funded_solana_wallet_id,
+        WalletId::new(&funded_solana_wallet_id),
         &ctx,
         None,
         &rpc_body
@@ -190,14 +191,17 @@ async fn test_solana_sign_and_send_transaction_with_options_sponsored() -> Resul
     ));
 
     let result = debug_response!(
-        client.wallets().solana().sign_and_send_transaction_with_options(
-            &funded_solana_wallet_id,
-            "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1",
-            &transaction,
-            &ctx,
-            None,
-            &options,
-        )
+        client
+            .wallets()
+            .solana()
+            .sign_and_send_transaction_with_options(
+                WalletId::new(&funded_solana_wallet_id),
+                "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1",
+                &transaction,
+                &ctx,
+                None,
+                &options,
+            )
     )
     .await?;
 
@@ -1,6 +1,7 @@
 use std::{collections::HashMap, time::SystemTime};
 
 use anyhow::Result;
+use privy_rs::UserId;
 use privy_rs::generated::types::{
     CreateUserBody, CreateUserWalletBody, CustomMetadataValue, LinkedAccount,
     LinkedAccountEmailInput, LinkedAccountEmailInputType, LinkedAccountInput, SearchUsersBody,
@@ -52,7 +53,7 @@ async fn test_users_get() -> Result<()> {
 
     let user = common::ensure_test_user(&client).await?;
 
-    let result = client.users().get(&user.id).await?;
+    let result = client.users().get(UserId::new(&user.id)).await?;
     println!("Retrieved user: {result:?}");
     assert_eq!(result.into_inner().id, user.id);
 
@@ -78,7 +79,7 @@ async fn test_users_delete() -> Result<()> {
     let user_id = create_result.into_inner().id;
 
     // Now delete the user
-    let result = client.users().delete(&user_id).await?;
+    let result = client.users().delete(UserId::new(&user_id)).await?;
 
     println!("Deleted user response: {result:?}");
 
@@ -108,7 +109,7 @@ async fn test_users_set_custom_metadata() -> Result<()> {
 
     let result = client
         .users()
-        .set_custom_metadata(&user_id, &metadata_body)
+        .set_custom_metadata(UserId::new(&user_id), &metadata_body)
         .await;
     assert!(result.is_ok(), "Failed to set custom metadata: {result:?}");
 
@@ -135,7 +136,7 @@ async fn test_users_pregenerate_wallets() -> Result<()> {
     let result = debug_response!(
         client
             .users()
-            .pregenerate_wallets(&user_id, &pregenerate_body)
+            .pregenerate_wallets(UserId::new(&user_id), &pregenerate_body)
     )
     .await;
     assert!(result.is_ok(), "Failed to pregenerate wallets: {result:?}");
@@ -4,7 +4,7 @@ use anyhow::Result;
 use common::{ensure_test_user, get_test_client, get_test_wallet_id_by_type, mint_staging_jwt};
 use p256::elliptic_curve::SecretKey;
 use privy_rs::{
-    AuthorizationContext, IntoKey, JwtUser, PrivateKey, PrivyHpke, generated::types::*,
+    AuthorizationContext, IntoKey, JwtUser, PrivateKey, PrivyHpke, WalletId, generated::types::*,
 };
 use tracing_test::traced_test;
 
@@ -112,11 +112,12 @@ async fn test_wallets_raw_sign_with_auth_context() -> Result<()> {
 
     let ctx = AuthorizationContext::new();
 
-    let result = debug_response!(
-        client
-            .wallets()
-            .raw_sign(&wallet_id, &ctx, None, &raw_sign_body)
-    )
+    let result = debug_response!(client.wallets().raw_sign(
+        WalletId::new(&wallet_id),
+        &ctx,
+        None,
+        &raw_sign_body
+    ))
     .await?;
 
     let sig = result.into_inner().data.signature;
@@ -148,7 +149,12 @@ async fn test_wallets_update_with_auth_context() -> Result<()> {
     let ctx = AuthorizationContext::new();
 
     // add an owner to the wallet
-    let _wallet = debug_response!(client.wallets().update(&wallet_id, &ctx, &update_body)).await?;
+    let _wallet = debug_response!(client.wallets().update(
+        WalletId::new(&wallet_id),
+        &ctx,
+        &update_body
+    ))
+    .await?;
 
     let mut rng = rand::thread_rng();
     let other = SecretKey::<p256::NistP256>::random(&mut rng);
@@ -163,7 +169,7 @@ async fn test_wallets_update_with_auth_context() -> Result<()> {
     // we now have an owner, lets try to update, and expect an error
     if client
         .wallets()
-        .update(&wallet_id, &ctx, &update_body)
+        .update(WalletId::new(&wallet_id), &ctx, &update_body)
         .await
         .is_ok()
     {
@@ -172,7 +178,12 @@ async fn test_wallets_update_with_auth_context() -> Result<()> {
 
     let ctx = ctx.push(private_key);
 
-    let wallet = debug_response!(client.wallets().update(&wallet_id, &ctx, &update_body)).await?;
+    let wallet = debug_response!(client.wallets().update(
+        WalletId::new(&wallet_id),
+        &ctx,
+        &update_body
+    ))
+    .await?;
 
     assert_eq!(wallet.id, wallet_id);
     println!("Updated wallet owner for: {wallet_id}");
@@ -200,7 +211,8 @@ async fn test_wallets_export() -> Result<()> {
     let jwt = mint_staging_jwt(sub)?;
     let ctx = AuthorizationContext::new().push(JwtUser(client.clone(), jwt));
 
-    let exported = debug_response!(client.wallets().export(&wallet_id, &ctx)).await?;
+    let exported =
+        debug_response!(client.wallets().export(WalletId::new(&wallet_id), &ctx)).await?;
 
     println!("Wallet exported successfully {exported:?}");
 
@@ -0,0 +1,228 @@
+//! Snapshots of the canonical JSON of the request bodies the SDK sends, so
+//! that regenerating the OpenAPI bindings can not silently change what goes
+//! over the wire (and so what is signed).
+//!
+//! Each snapshot lives in `tests/snapshots/<name>.json`. After an
+//! intentional change, rewrite them with
+//! `UPDATE_SNAPSHOTS=1 cargo test --test wire_format` and review the diff.
+
+use std::path::PathBuf;
+
+use privy_rs::generated::types::*;
+use serde::Serialize;
+use test_case::test_case;
+
+fn assert_snapshot(name: &str, body: &impl Serialize) {
+    let actual = serde_json_canonicalizer::to_string(body).expect("body serializes");
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{name}.json"));
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&path, format!("{actual}\n")).expect("snapshot is written");
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "no snapshot at {}, run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+    assert_eq!(
+        expected.trim_end(),
+        actual,
+        "the wire format of `{name}` changed, run with UPDATE_SNAPSHOTS=1 if this is intended"
+    );
+}
+
+fn ethereum_transaction() -> UnsignedEthereumTransaction {
+    UnsignedStandardEthereumTransaction {
+        to: Some("0x742d35Cc6635C0532925a3b8c17d6d1E9C2F7ca".to_string()),
+        value: Some("0x2386f26fc10000".parse().unwrap()),
+        chain_id: Some(Quantity::Integer(1)),
+        data: Some("0x".parse().unwrap()),
+        ..Default::default()
+    }
+    .into()
+}
+
+fn ethereum_personal_sign_utf8() -> WalletRpcRequestBody {
+    EthereumPersonalSignRpcInput {
+        address: None,
+        chain_type: None,
+        method: EthereumPersonalSignRpcInputMethod::PersonalSign,
+        params: EthereumPersonalSignRpcInputParams {
+            encoding: EthereumPersonalSignRpcInputParamsEncoding::Utf8,
+            message: "Hello, Ethereum!".parse().unwrap(),
+        },
+        wallet_id: None,
+    }
+    .into()
+}
+
+fn ethereum_personal_sign_hex() -> WalletRpcRequestBody {
+    EthereumPersonalSignRpcInput {
+        address: None,
+        chain_type: None,
+        method: EthereumPersonalSignRpcInputMethod::PersonalSign,
+        params: EthereumPersonalSignRpcInputParams {
+            encoding: EthereumPersonalSignRpcInputParamsEncoding::Hex,
+            message: "0x48656c6c6f".parse().unwrap(),
+        },
+        wallet_id: None,
+    }
+    .into()
+}
+
+fn ethereum_secp256k1_sign() -> WalletRpcRequestBody {
+    EthereumSecp256k1SignRpcInput {
+        address: None,
+        chain_type: None,
+        method: EthereumSecp256k1SignRpcInputMethod::Secp256k1Sign,
+        params: EthereumSecp256k1SignRpcInputParams {
+            hash: "0x12345678901234567890123456789012345678901234567890123456789012ab"
+                .parse()
+                .unwrap(),
+        },
+        wallet_id: None,
+    }
+    .into()
+}
+
+fn ethereum_sign_transaction() -> WalletRpcRequestBody {
+    EthereumSignTransactionRpcInput {
+        address: None,
+        chain_type: None,
+        method: EthereumSignTransactionRpcInputMethod::EthSignTransaction,
+        params: EthereumSignTransactionRpcInputParams {
+            transaction: ethereum_transaction(),
+        },
+        wallet_id: None,
+    }
+    .into()
+}
+
+fn ethereum_send_transaction() -> WalletRpcRequestBody {
+    EthereumSendTransactionRpcInput {
+        address: None,
+        caip2: "eip155:1".parse().unwrap(),
+        chain_type: None,
+        experimental_data_suffix: None,
+        method: EthereumSendTransactionRpcInputMethod::EthSendTransaction,
+        params: EthereumSendTransactionRpcInputParams {
+            transaction: ethereum_transaction(),
+        },
+        reference_id: None,
+        sponsor: Some(true),
+        wallet_id: None,
+    }
+    .into()
+}
+
+fn solana_sign_message() -> WalletRpcRequestBody {
+    SolanaSignMessageRpcInput {
+        address: None,
+        chain_type: None,
+        method: SolanaSignMessageRpcInputMethod::SignMessage,
+        params: SolanaSignMessageRpcInputParams {
+            encoding: SolanaSignMessageRpcInputParamsEncoding::Base64,
+            message: "SGVsbG8sIFNvbGFuYSE=".parse().unwrap(),
+        },
+        wallet_id: None,
+    }
+    .into()
+}
+
+fn solana_sign_and_send_transaction() -> WalletRpcRequestBody {
+    SolanaSignAndSendTransactionRpcInput {
+        address: None,
+        caip2: "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1".parse().unwrap(),
+        chain_type: None,
+        method: SolanaSignAndSendTransactionRpcInputMethod::SignAndSendTransaction,
+        optimistic_broadcast: None,
+        params: SolanaSignAndSendTransactionRpcInputParams {
+            encoding: SolanaSignAndSendTransactionRpcInputParamsEncoding::Base64,
+            transaction: "AQAAAA==".parse().unwrap(),
+        },
+        reference_id: None,
+        sponsor: None,
+        wallet_id: None,
+    }
+    .into()
+}
+
+#[test_case(ethereum_personal_sign_utf8(), "rpc_ethereum_personal_sign_utf8")]
+#[test_case(ethereum_personal_sign_hex(), "rpc_ethereum_personal_sign_hex")]
+#[test_case(ethereum_secp256k1_sign(), "rpc_ethereum_secp256k1_sign")]
+#[test_case(ethereum_sign_transaction(), "rpc_ethereum_sign_transaction")]
+#[test_case(ethereum_send_transaction(), "rpc_ethereum_send_transaction")]
+#[test_case(solana_sign_message(), "rpc_solana_sign_message")]
+#[test_case(
+    solana_sign_and_send_transaction(),
+    "rpc_solana_sign_and_send_transaction"
+)]
+fn test_rpc_bodies(body: WalletRpcRequestBody, name: &str) {
+    assert_snapshot(name, &body);
+}
+
+#[test]
+fn test_raw_sign_body() {
+    let body = RawSignInput {
+        params: RawSignBytesParams {
+            bytes: "48656c6c6f".parse().unwrap(),
+            encoding: RawSignBytesEncoding::Hex,
+            hash_function: RawSignBytesHashFunction::Sha256,
+        }
+        .into(),
+    };
+    assert_snapshot("raw_sign_bytes", &body);
+}
+
+#[test]
+fn test_policy_bodies() {
+    let rule = PolicyRuleRequestBody {
+        action: PolicyAction::Allow,
+        conditions: vec![PolicyCondition::SolanaSystemProgramInstructionCondition(
+            SolanaSystemProgramInstructionCondition {
+                field: SolanaSystemProgramInstructionConditionField::TransferLamports,
+                field_source:
+                    SolanaSystemProgramInstructionConditionFieldSource::SolanaSystemProgramInstruction,
+                operator: ConditionOperator::Lt,
+                value: ConditionValue::String("1000000".to_string()),
+            },
+        )],
+        method: PolicyMethod::SignTransaction,
+        name: "transfer-limit".parse().unwrap(),
+    };
+    assert_snapshot("policy_rule", &rule);
+
+    let update = UpdatePolicyBody {
+        name: Some("treasury-policy".parse().unwrap()),
+        owner: None,
+        owner_id: None,
+        rules: vec![rule],
+    };
+    assert_snapshot("policy_update", &update);
+}
+
+#[test]
+fn test_update_bodies() {
+    let public_key = include_str!("./test_public_key.pem").to_string();
+
+    let wallet = WalletUpdateRequestBody {
+        owner: Some(OwnerInput::Variant1(OwnerInputPublicKey {
+            public_key: P256PublicKey(public_key.clone()),
+        })),
+        ..Default::default()
+    };
+    assert_snapshot("wallet_update", &wallet);
+
+    let key_quorum = KeyQuorumUpdateRequestBody {
+        authorization_threshold: Some(2.0),
+        display_name: Some("treasury".parse().unwrap()),
+        public_keys: vec![public_key.clone(), public_key],
+        ..Default::default()
+    };
+    assert_snapshot("key_quorum_update", &key_quorum);
+}